@@ -15,6 +15,7 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod grpc;
+use common_tracing::AuditLogConfig;
 use common_tracing::QueryLogConfig;
 use common_tracing::TracingConfig;
 use grpc::export_meta;
@@ -206,6 +207,7 @@ async fn main() -> anyhow::Result<()> {
         },
         stderr: StderrConfig::default(),
         query: QueryLogConfig::default(),
+        audit: AuditLogConfig::default(),
         tracing: TracingConfig::from_env(),
     };
 
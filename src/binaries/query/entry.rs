@@ -244,6 +244,7 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
     println!("    file: {}", conf.log.file);
     println!("    stderr: {}", conf.log.stderr);
     println!("    query: {}", conf.log.query);
+    println!("    audit: {}", conf.log.audit);
     println!("    tracing: {}", conf.log.tracing);
     println!(
         "Meta: {}",
@@ -0,0 +1,109 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use clap::Parser;
+use common_config::Config;
+use common_config::InnerConfig;
+use common_config::DATABEND_COMMIT_VERSION;
+use common_exception::Result;
+use databend_query::sessions::SessionManager;
+use databend_query::sessions::SessionType;
+use databend_query::sessions::TableContext;
+use databend_query::storages::fuse::FuseTable;
+use databend_query::GlobalServices;
+use serde::Deserialize;
+use serde::Serialize;
+use serfig::collectors::from_file;
+use serfig::parsers::Toml;
+
+/// Check the consistency of a fuse table, and optionally exclude its corrupted blocks.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Parser)]
+#[clap(about, version = &**DATABEND_COMMIT_VERSION, author)]
+pub struct FsckConfig {
+    /// Config file of the databend-query which serves the table.
+    #[clap(long, short = 'c')]
+    pub config: String,
+
+    #[clap(long, default_value = "default")]
+    pub catalog: String,
+
+    #[clap(long, short = 'd', default_value = "default")]
+    pub database: String,
+
+    #[clap(long, short = 't')]
+    pub table: String,
+
+    /// Commit a new snapshot which excludes the lost or corrupted blocks.
+    #[clap(long)]
+    pub repair: bool,
+}
+
+async fn run(config: &FsckConfig) -> Result<bool> {
+    let mut builder: serfig::Builder<Config> = serfig::Builder::default();
+    builder = builder.collect(from_file(Toml, &config.config));
+    let inner_config: InnerConfig = builder.build()?.try_into()?;
+    GlobalServices::init(inner_config).await?;
+
+    let session = SessionManager::instance()
+        .create_session(SessionType::Local)
+        .await?;
+    let ctx = session.create_query_context().await?;
+    let table = ctx
+        .get_catalog(&config.catalog)
+        .await?
+        .get_table(&ctx.get_tenant(), &config.database, &config.table)
+        .await?;
+    let table = FuseTable::try_from_table(table.as_ref())?;
+
+    let report = table
+        .check(ctx.clone() as Arc<dyn TableContext>, config.repair)
+        .await?;
+    match &report.snapshot_location {
+        Some(location) => println!(
+            "snapshot {}: {} segments, {} blocks",
+            location, report.segment_count, report.block_count
+        ),
+        None => println!("table {} has no snapshot", config.table),
+    }
+    for issue in &report.issues {
+        println!("{}\t{}\t{}", issue.kind, issue.location, issue.detail);
+    }
+    if let Some(excluded) = report.excluded_block_count {
+        println!(
+            "repaired: {} blocks excluded, new snapshot {}",
+            excluded,
+            report
+                .repaired_snapshot_location
+                .as_deref()
+                .unwrap_or_default()
+        );
+    }
+    Ok(report.is_healthy() || report.excluded_block_count.is_some())
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let config = FsckConfig::parse();
+
+    match run(&config).await {
+        Ok(true) => Ok(()),
+        Ok(false) => std::process::exit(1),
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            std::process::exit(2)
+        }
+    }
+}
@@ -145,6 +145,11 @@ build_exceptions! {
     ColumnReferencedByComputedColumn(1117),
     // The table is not a clustered table.
     UnclusteredTable(1118),
+    UnknownRowAccessPolicy(1119),
+    ColumnReferencedByInvertedIndex(1120),
+    InvertedIndexNotBuilt(1121),
+    InvalidPartitionKeys(1121),
+    UnknownPartition(1122),
     UnknownCatalog(11119),
     UnknownCatalogType(11120),
 
@@ -217,6 +222,9 @@ build_exceptions! {
     NetworkPolicyAlreadyExists(2208),
     IllegalNetworkPolicy(2209),
     NetworkPolicyIsUsedByUser(2210),
+    UnknownTag(2211),
+    TagAlreadyExists(2212),
+    IllegalTag(2213),
 
     // Meta api error codes.
     DatabaseAlreadyExists(2301),
@@ -246,6 +254,8 @@ build_exceptions! {
     CatalogNotFound(2320),
     /// data mask error codes
     DatamaskAlreadyExists(2321),
+    /// row access policy error codes
+    RowAccessPolicyAlreadyExists(2322),
 
 
     // Cluster error codes.
@@ -12,11 +12,37 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::str::FromStr;
+
 use chrono_tz::Tz;
+use common_exception::ErrorCode;
+
+/// How values of the BINARY type are written as text.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BinaryFormat {
+    #[default]
+    Hex,
+    Base64,
+}
+
+impl FromStr for BinaryFormat {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "hex" => Ok(BinaryFormat::Hex),
+            "base64" => Ok(BinaryFormat::Base64),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Invalid binary format: {s}, must be one of hex, base64"
+            ))),
+        }
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FormatSettings {
     pub timezone: Tz,
+    pub binary_format: BinaryFormat,
 }
 
 // only used for tests
@@ -24,6 +50,7 @@ impl Default for FormatSettings {
     fn default() -> Self {
         Self {
             timezone: "UTC".parse::<Tz>().unwrap(),
+            binary_format: BinaryFormat::default(),
         }
     }
 }
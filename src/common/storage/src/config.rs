@@ -47,6 +47,10 @@ pub struct StorageConfig {
     pub num_cpus: u64,
     pub allow_insecure: bool,
     pub params: StorageParams,
+    /// Hex encoded 256-bit master key wrapping the data keys of encrypted tables.
+    pub encryption_master_key: String,
+    /// Local file of master keys, see [`crate::encryption::LocalFileKeyProvider`].
+    pub encryption_master_key_file: String,
 }
 
 // TODO: This config should be moved out of common-storage crate.
@@ -0,0 +1,1068 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Client side envelope encryption of the objects of a table.
+//!
+//! An encrypted table owns a keyring of data keys, which is kept in the table options.
+//! Data keys are wrapped (encrypted) by a master key of the [`KeyProvider`], they never
+//! leave the query node unwrapped.
+//!
+//! All objects written through the operator of the table are encrypted by the current
+//! data key with AES-256-GCM, and decrypted transparently when they are read back.
+//! The plaintext is split into segments of 64 KiB, which are encrypted on their own:
+//!
+//! ```text
+//! | "DBE1" | data key version: u32 LE | plaintext length: u64 LE | nonce prefix: 8 bytes |
+//! | segment 0 ciphertext | tag: 16 bytes | segment 1 ciphertext | tag: 16 bytes | ... |
+//! ```
+//!
+//! The nonce of a segment is the nonce prefix followed by the index of the segment (u32 BE),
+//! and the path of the object and the header are authenticated as associated data, so that
+//! neither objects nor segments can be swapped, and a truncated object is detected.
+//! A range read fetches and decrypts only the segments covering the range.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use aes_gcm::aead::Aead;
+use aes_gcm::aead::AeadCore;
+use aes_gcm::aead::KeyInit;
+use aes_gcm::aead::OsRng;
+use aes_gcm::aead::Payload;
+use aes_gcm::Aes256Gcm;
+use aes_gcm::Nonce;
+use async_trait::async_trait;
+use bytes::Bytes;
+use common_base::base::GlobalInstance;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use opendal::raw::oio;
+use opendal::raw::oio::ReadExt;
+use opendal::raw::oio::Streamer;
+use opendal::raw::Accessor;
+use opendal::raw::BytesRange;
+use opendal::raw::Layer;
+use opendal::raw::LayeredAccessor;
+use opendal::raw::OpAppend;
+use opendal::raw::OpList;
+use opendal::raw::OpRead;
+use opendal::raw::OpStat;
+use opendal::raw::OpWrite;
+use opendal::raw::RpAppend;
+use opendal::raw::RpList;
+use opendal::raw::RpRead;
+use opendal::raw::RpStat;
+use opendal::raw::RpWrite;
+use opendal::EntryMode;
+use opendal::ErrorKind;
+use opendal::Metadata;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::StorageConfig;
+
+/// The only algorithm supported by now.
+pub const ENCRYPTION_ALGORITHM_AES_256_GCM: &str = "aes_256_gcm";
+
+const MAGIC: &[u8; 4] = b"DBE1";
+const NONCE_LEN: usize = 12;
+const NONCE_PREFIX_LEN: usize = 8;
+const TAG_LEN: usize = 16;
+const HEADER_LEN: usize = MAGIC.len() + 4 + 8 + NONCE_PREFIX_LEN;
+/// Size of the plaintext segments, the last segment of an object may be shorter.
+const SEGMENT_SIZE: u64 = 64 * 1024;
+const ENCRYPTED_SEGMENT_SIZE: u64 = SEGMENT_SIZE + TAG_LEN as u64;
+
+const KEY_LEN: usize = 32;
+
+/// A 256-bit key wrapping the data keys of the tables.
+#[derive(Clone)]
+pub struct MasterKey {
+    pub id: String,
+    key: [u8; KEY_LEN],
+}
+
+impl MasterKey {
+    /// Parses a hex encoded 256-bit key.
+    pub fn from_hex(id: &str, key: &str) -> Result<Self> {
+        let bytes = hex::decode(key.trim())
+            .map_err(|e| ErrorCode::InvalidConfig(format!("invalid master key {}: {}", id, e)))?;
+        let key: [u8; KEY_LEN] = bytes.try_into().map_err(|_| {
+            ErrorCode::InvalidConfig(format!(
+                "invalid master key {}: must be {} bytes",
+                id, KEY_LEN
+            ))
+        })?;
+        Ok(MasterKey {
+            id: id.to_string(),
+            key,
+        })
+    }
+
+    fn cipher(&self) -> Aes256Gcm {
+        Aes256Gcm::new(&self.key.into())
+    }
+}
+
+/// Provides the master keys wrapping the data keys of the tables.
+pub trait KeyProvider: Send + Sync {
+    /// The key new data keys are wrapped with.
+    fn current_master_key(&self) -> Result<MasterKey>;
+
+    /// Lookups the key by id, to unwrap data keys wrapped in the past.
+    fn master_key(&self, id: &str) -> Result<MasterKey>;
+}
+
+/// The master key of `storage.encryption_master_key`.
+pub struct ConfigKeyProvider {
+    key: MasterKey,
+}
+
+impl ConfigKeyProvider {
+    pub const KEY_ID: &'static str = "config";
+
+    pub fn try_create(hex_key: &str) -> Result<Self> {
+        Ok(ConfigKeyProvider {
+            key: MasterKey::from_hex(Self::KEY_ID, hex_key)?,
+        })
+    }
+}
+
+impl KeyProvider for ConfigKeyProvider {
+    fn current_master_key(&self) -> Result<MasterKey> {
+        Ok(self.key.clone())
+    }
+
+    fn master_key(&self, id: &str) -> Result<MasterKey> {
+        if id == self.key.id {
+            Ok(self.key.clone())
+        } else {
+            Err(unknown_master_key(id))
+        }
+    }
+}
+
+/// Master keys of a local file, one `<key id> <hex encoded key>` per line.
+///
+/// The last key of the file is the current one, former keys are kept in the file
+/// as long as data keys wrapped by them have not been rotated.
+pub struct LocalFileKeyProvider {
+    keys: Vec<MasterKey>,
+}
+
+impl LocalFileKeyProvider {
+    pub fn try_create(path: &str) -> Result<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            ErrorCode::InvalidConfig(format!("failed to read master key file {}: {}", path, e))
+        })?;
+        Self::parse(&content)
+    }
+
+    fn parse(content: &str) -> Result<Self> {
+        let mut keys = vec![];
+        for line in content.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((id, key)) = line.split_once(char::is_whitespace) else {
+                return Err(ErrorCode::InvalidConfig(
+                    "invalid master key file, lines must be `<key id> <hex encoded key>`",
+                ));
+            };
+            keys.push(MasterKey::from_hex(id, key)?);
+        }
+        if keys.is_empty() {
+            return Err(ErrorCode::InvalidConfig("master key file holds no key"));
+        }
+        Ok(LocalFileKeyProvider { keys })
+    }
+}
+
+impl KeyProvider for LocalFileKeyProvider {
+    fn current_master_key(&self) -> Result<MasterKey> {
+        Ok(self.keys[self.keys.len() - 1].clone())
+    }
+
+    fn master_key(&self, id: &str) -> Result<MasterKey> {
+        self.keys
+            .iter()
+            .find(|k| k.id == id)
+            .cloned()
+            .ok_or_else(|| unknown_master_key(id))
+    }
+}
+
+fn unknown_master_key(id: &str) -> ErrorCode {
+    ErrorCode::InvalidConfig(format!("master key {} is not found", id))
+}
+
+/// The key provider configured for this node, if any.
+#[derive(Clone)]
+pub struct EncryptionKeyProvider {
+    provider: Option<Arc<dyn KeyProvider>>,
+}
+
+impl EncryptionKeyProvider {
+    pub fn init(conf: &StorageConfig) -> Result<()> {
+        GlobalInstance::set(Self::try_create(conf)?);
+        Ok(())
+    }
+
+    pub fn try_create(conf: &StorageConfig) -> Result<Self> {
+        let provider: Option<Arc<dyn KeyProvider>> = match (
+            &conf.encryption_master_key,
+            &conf.encryption_master_key_file,
+        ) {
+            (key, file) if key.is_empty() && file.is_empty() => None,
+            (key, file) if file.is_empty() => Some(Arc::new(ConfigKeyProvider::try_create(key)?)),
+            (key, file) if key.is_empty() => {
+                Some(Arc::new(LocalFileKeyProvider::try_create(file)?))
+            }
+            _ => {
+                return Err(ErrorCode::InvalidConfig(
+                    "only one of encryption_master_key and encryption_master_key_file can be set",
+                ));
+            }
+        };
+        Ok(EncryptionKeyProvider { provider })
+    }
+
+    pub fn instance() -> EncryptionKeyProvider {
+        GlobalInstance::get()
+    }
+
+    pub fn provider(&self) -> Result<Arc<dyn KeyProvider>> {
+        self.provider.clone().ok_or_else(|| {
+            ErrorCode::InvalidConfig(
+                "no master key is configured, set storage.encryption_master_key or storage.encryption_master_key_file",
+            )
+        })
+    }
+}
+
+/// A data key of a table, wrapped by a master key.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct WrappedDataKey {
+    pub version: u32,
+    pub master_key_id: String,
+    /// Hex encoded nonce and ciphertext of the data key.
+    pub wrapped_key: String,
+}
+
+impl WrappedDataKey {
+    fn wrap(master_key: &MasterKey, version: u32, data_key: &[u8; KEY_LEN]) -> Result<Self> {
+        let aad = Self::aad(version);
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = master_key
+            .cipher()
+            .encrypt(&nonce, Payload {
+                msg: data_key,
+                aad: aad.as_bytes(),
+            })
+            .map_err(|_| ErrorCode::StorageOther("failed to wrap data key"))?;
+        let mut wrapped = nonce.to_vec();
+        wrapped.extend_from_slice(&ciphertext);
+        Ok(WrappedDataKey {
+            version,
+            master_key_id: master_key.id.clone(),
+            wrapped_key: hex::encode(wrapped),
+        })
+    }
+
+    fn unwrap(&self, provider: &dyn KeyProvider) -> Result<[u8; KEY_LEN]> {
+        let master_key = provider.master_key(&self.master_key_id)?;
+        let wrapped = hex::decode(&self.wrapped_key)
+            .map_err(|e| ErrorCode::StorageOther(format!("invalid wrapped data key: {}", e)))?;
+        if wrapped.len() < NONCE_LEN {
+            return Err(ErrorCode::StorageOther("invalid wrapped data key"));
+        }
+        let (nonce, ciphertext) = wrapped.split_at(NONCE_LEN);
+        let aad = Self::aad(self.version);
+        let data_key = master_key
+            .cipher()
+            .decrypt(Nonce::from_slice(nonce), Payload {
+                msg: ciphertext,
+                aad: aad.as_bytes(),
+            })
+            .map_err(|_| {
+                ErrorCode::StorageOther(format!(
+                    "failed to unwrap data key {} with master key {}",
+                    self.version, self.master_key_id
+                ))
+            })?;
+        data_key
+            .try_into()
+            .map_err(|_| ErrorCode::StorageOther("invalid data key length"))
+    }
+
+    fn aad(version: u32) -> String {
+        format!("databend-data-key-{}", version)
+    }
+}
+
+fn parse_keyring(keyring: &str) -> Result<Vec<WrappedDataKey>> {
+    let keys: Vec<WrappedDataKey> = serde_json::from_str(keyring)
+        .map_err(|e| ErrorCode::StorageOther(format!("invalid table keyring: {}", e)))?;
+    if keys.is_empty() {
+        return Err(ErrorCode::StorageOther("table keyring holds no data key"));
+    }
+    Ok(keys)
+}
+
+fn generate_data_key() -> [u8; KEY_LEN] {
+    Aes256Gcm::generate_key(&mut OsRng).into()
+}
+
+/// Creates the keyring of a new table, which holds a single data key.
+pub fn generate_table_keyring(provider: &dyn KeyProvider) -> Result<String> {
+    let master_key = provider.current_master_key()?;
+    let keys = vec![WrappedDataKey::wrap(&master_key, 1, &generate_data_key())?];
+    Ok(serde_json::to_string(&keys)?)
+}
+
+/// Adds a new data key to the keyring, which is used for the objects written from now on.
+///
+/// Former data keys are kept to read the objects written with them, all the keys are
+/// wrapped again by the current master key, so that former master keys can be retired.
+pub fn rotate_table_keyring(provider: &dyn KeyProvider, keyring: &str) -> Result<String> {
+    let master_key = provider.current_master_key()?;
+    let mut keys = vec![];
+    let mut next_version = 1;
+    for key in parse_keyring(keyring)? {
+        let data_key = key.unwrap(provider)?;
+        keys.push(WrappedDataKey::wrap(&master_key, key.version, &data_key)?);
+        next_version = next_version.max(key.version + 1);
+    }
+    keys.push(WrappedDataKey::wrap(
+        &master_key,
+        next_version,
+        &generate_data_key(),
+    )?);
+    Ok(serde_json::to_string(&keys)?)
+}
+
+/// The unwrapped data keys of a table.
+pub struct DataKeys {
+    current: u32,
+    ciphers: HashMap<u32, Aes256Gcm>,
+}
+
+impl Debug for DataKeys {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DataKeys")
+            .field("current", &self.current)
+            .finish_non_exhaustive()
+    }
+}
+
+impl DataKeys {
+    pub fn try_create(provider: &dyn KeyProvider, keyring: &str) -> Result<Self> {
+        let mut ciphers = HashMap::new();
+        let mut current = 0;
+        for key in parse_keyring(keyring)? {
+            let data_key = key.unwrap(provider)?;
+            ciphers.insert(key.version, Aes256Gcm::new(&data_key.into()));
+            current = current.max(key.version);
+        }
+        Ok(DataKeys { current, ciphers })
+    }
+
+    pub fn encrypt(&self, path: &str, plaintext: &[u8]) -> Result<Vec<u8>> {
+        let plaintext_len = plaintext.len() as u64;
+        if num_segments(plaintext_len) > u32::MAX as u64 {
+            return Err(ErrorCode::StorageOther(format!(
+                "{} is too large to be encrypted",
+                path
+            )));
+        }
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let mut header = [0; HEADER_LEN];
+        header[..4].copy_from_slice(MAGIC);
+        header[4..8].copy_from_slice(&self.current.to_le_bytes());
+        header[8..16].copy_from_slice(&plaintext_len.to_le_bytes());
+        header[16..].copy_from_slice(&nonce[..NONCE_PREFIX_LEN]);
+        let header = Header::parse(path, &header)?;
+
+        let cipher = &self.ciphers[&self.current];
+        let aad = header.aad(path);
+        let mut data = Vec::with_capacity(encrypted_size(plaintext_len) as usize);
+        data.extend_from_slice(&header.bytes);
+        // An empty object still has a segment, which authenticates the header.
+        let segments = plaintext
+            .chunks(SEGMENT_SIZE as usize)
+            .chain(plaintext.is_empty().then_some(plaintext));
+        for (index, segment) in segments.enumerate() {
+            let ciphertext = cipher
+                .encrypt(&header.nonce(index as u64), Payload {
+                    msg: segment,
+                    aad: &aad,
+                })
+                .map_err(|_| ErrorCode::StorageOther(format!("failed to encrypt {}", path)))?;
+            data.extend_from_slice(&ciphertext);
+        }
+        Ok(data)
+    }
+
+    /// Decrypts a whole encrypted object.
+    pub fn decrypt(&self, path: &str, data: &[u8]) -> Result<Vec<u8>> {
+        let header = Header::parse(path, data)?;
+        if data.len() as u64 != encrypted_size(header.plaintext_len) {
+            return Err(ErrorCode::StorageOther(format!(
+                "encrypted object {} is truncated",
+                path
+            )));
+        }
+        self.decrypt_segments(path, &header, 0, &data[HEADER_LEN..])
+    }
+
+    /// Decrypts the consecutive segments of an object, starting from the segment `first`.
+    fn decrypt_segments(
+        &self,
+        path: &str,
+        header: &Header,
+        first: u64,
+        data: &[u8],
+    ) -> Result<Vec<u8>> {
+        let cipher = self.ciphers.get(&header.version).ok_or_else(|| {
+            ErrorCode::StorageOther(format!(
+                "data key {} of {} is not in the table keyring",
+                header.version, path
+            ))
+        })?;
+        let aad = header.aad(path);
+        let mut plaintext = Vec::with_capacity(data.len());
+        for (i, segment) in data.chunks(ENCRYPTED_SEGMENT_SIZE as usize).enumerate() {
+            let index = first + i as u64;
+            if segment.len() as u64 != header.encrypted_segment_size(index) {
+                return Err(ErrorCode::StorageOther(format!(
+                    "encrypted object {} is truncated",
+                    path
+                )));
+            }
+            let decrypted = cipher
+                .decrypt(&header.nonce(index), Payload {
+                    msg: segment,
+                    aad: &aad,
+                })
+                .map_err(|_| ErrorCode::StorageOther(format!("failed to decrypt {}", path)))?;
+            plaintext.extend_from_slice(&decrypted);
+        }
+        Ok(plaintext)
+    }
+}
+
+/// The header of an encrypted object.
+struct Header {
+    bytes: [u8; HEADER_LEN],
+    version: u32,
+    plaintext_len: u64,
+}
+
+impl Header {
+    fn parse(path: &str, data: &[u8]) -> Result<Self> {
+        if data.len() < HEADER_LEN || &data[..MAGIC.len()] != MAGIC {
+            return Err(ErrorCode::StorageOther(format!(
+                "{} is not an encrypted object",
+                path
+            )));
+        }
+        let bytes: [u8; HEADER_LEN] = data[..HEADER_LEN].try_into().unwrap();
+        Ok(Header {
+            bytes,
+            version: u32::from_le_bytes(bytes[4..8].try_into().unwrap()),
+            plaintext_len: u64::from_le_bytes(bytes[8..16].try_into().unwrap()),
+        })
+    }
+
+    fn nonce(&self, index: u64) -> Nonce<<Aes256Gcm as AeadCore>::NonceSize> {
+        let mut nonce = [0; NONCE_LEN];
+        nonce[..NONCE_PREFIX_LEN].copy_from_slice(&self.bytes[16..]);
+        nonce[NONCE_PREFIX_LEN..].copy_from_slice(&(index as u32).to_be_bytes());
+        nonce.into()
+    }
+
+    fn aad(&self, path: &str) -> Vec<u8> {
+        let mut aad = path.as_bytes().to_vec();
+        aad.extend_from_slice(&self.bytes);
+        aad
+    }
+
+    fn encrypted_segment_size(&self, index: u64) -> u64 {
+        let start = (index * SEGMENT_SIZE).min(self.plaintext_len);
+        let end = ((index + 1) * SEGMENT_SIZE).min(self.plaintext_len);
+        end - start + TAG_LEN as u64
+    }
+
+    /// The segments covering the non-empty plaintext range `start..end`, returns the
+    /// index of the first segment and the byte range of the segments in the object.
+    fn segments(&self, start: u64, end: u64) -> (u64, u64, u64) {
+        let first = start / SEGMENT_SIZE;
+        let last = (end - 1) / SEGMENT_SIZE;
+        let offset = HEADER_LEN as u64 + first * ENCRYPTED_SEGMENT_SIZE;
+        let end = (HEADER_LEN as u64 + (last + 1) * ENCRYPTED_SEGMENT_SIZE)
+            .min(encrypted_size(self.plaintext_len));
+        (first, offset, end - offset)
+    }
+}
+
+fn num_segments(plaintext_len: u64) -> u64 {
+    ((plaintext_len + SEGMENT_SIZE - 1) / SEGMENT_SIZE).max(1)
+}
+
+/// Size of the encrypted object of a plaintext.
+pub fn encrypted_size(plaintext_len: u64) -> u64 {
+    HEADER_LEN as u64 + plaintext_len + num_segments(plaintext_len) * TAG_LEN as u64
+}
+
+/// Size of the plaintext of an encrypted object, the inverse of [`encrypted_size`].
+pub fn plaintext_size(encrypted_len: u64) -> u64 {
+    let body = encrypted_len.saturating_sub(HEADER_LEN as u64);
+    let segments = ((body + ENCRYPTED_SEGMENT_SIZE - 1) / ENCRYPTED_SEGMENT_SIZE).max(1);
+    body.saturating_sub(segments * TAG_LEN as u64)
+}
+
+/// Resolves a range read against the length of the plaintext.
+fn plaintext_range(range: BytesRange, len: u64) -> (u64, u64) {
+    let (start, end) = match (range.offset(), range.size()) {
+        (Some(offset), Some(size)) => (offset, offset + size),
+        (Some(offset), None) => (offset, len),
+        (None, Some(size)) => (len.saturating_sub(size), len),
+        (None, None) => (0, len),
+    };
+    (start.min(len), end.min(len))
+}
+
+type DataKeysResult = std::result::Result<DataKeys, String>;
+
+fn to_opendal_error(e: impl ToString) -> opendal::Error {
+    opendal::Error::new(ErrorKind::Unexpected, &e.to_string())
+}
+
+fn data_keys(keys: &DataKeysResult) -> opendal::Result<&DataKeys> {
+    keys.as_ref().map_err(to_opendal_error)
+}
+
+/// Encrypts the objects written through the operator, and decrypts the objects read.
+#[derive(Clone, Debug)]
+pub struct EncryptionLayer {
+    keys: Arc<DataKeysResult>,
+}
+
+impl EncryptionLayer {
+    /// Creates the layer of a table keyring.
+    ///
+    /// Failing to unwrap the data keys, e.g. the master key is not configured, does not
+    /// fail here but on each access, the table can still be listed and dropped.
+    pub fn create(keyring: &str) -> Self {
+        let keys = EncryptionKeyProvider::instance()
+            .provider()
+            .and_then(|provider| DataKeys::try_create(provider.as_ref(), keyring))
+            .map_err(|e| e.message());
+        EncryptionLayer {
+            keys: Arc::new(keys),
+        }
+    }
+}
+
+impl<A: Accessor> Layer<A> for EncryptionLayer {
+    type LayeredAccessor = EncryptionAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        EncryptionAccessor {
+            inner: Arc::new(inner),
+            keys: self.keys.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct EncryptionAccessor<A> {
+    inner: Arc<A>,
+    keys: Arc<DataKeysResult>,
+}
+
+impl<A: Accessor> EncryptionAccessor<A> {
+    #[async_backtrace::framed]
+    async fn read_bytes(&self, path: &str, range: BytesRange) -> opendal::Result<Vec<u8>> {
+        let (_, mut reader) = self
+            .inner
+            .read(path, OpRead::new().with_range(range))
+            .await?;
+        let mut data = vec![];
+        while let Some(bs) = reader.next().await {
+            data.extend_from_slice(&bs?);
+        }
+        Ok(data)
+    }
+
+    fn blocking_read_bytes(&self, path: &str, range: BytesRange) -> opendal::Result<Vec<u8>> {
+        let (_, mut reader) = self
+            .inner
+            .blocking_read(path, OpRead::new().with_range(range))?;
+        let mut data = vec![];
+        while let Some(bs) = oio::BlockingRead::next(&mut reader) {
+            data.extend_from_slice(&bs?);
+        }
+        Ok(data)
+    }
+}
+
+/// A read of an encrypted object, the whole object is fetched by a single read,
+/// otherwise the header is fetched first, then the segments covering the range.
+enum ReadPlan {
+    Whole,
+    Header,
+    Segments {
+        header: Header,
+        first: u64,
+        range: BytesRange,
+        /// The plaintext range to return, relative to the first segment.
+        slice: (usize, usize),
+    },
+    Done(Bytes),
+}
+
+impl ReadPlan {
+    fn new(range: BytesRange) -> Self {
+        match (range.offset(), range.size()) {
+            (None, None) => ReadPlan::Whole,
+            _ => ReadPlan::Header,
+        }
+    }
+
+    fn next(self, keys: &DataKeys, path: &str, range: BytesRange, data: &[u8]) -> Result<ReadPlan> {
+        match self {
+            ReadPlan::Whole => Ok(ReadPlan::Done(keys.decrypt(path, data)?.into())),
+            ReadPlan::Header => {
+                let header = Header::parse(path, data)?;
+                let (start, end) = plaintext_range(range, header.plaintext_len);
+                if start >= end {
+                    return Ok(ReadPlan::Done(Bytes::new()));
+                }
+                let (first, offset, size) = header.segments(start, end);
+                let skip = (start - first * SEGMENT_SIZE) as usize;
+                Ok(ReadPlan::Segments {
+                    header,
+                    first,
+                    range: BytesRange::new(Some(offset), Some(size)),
+                    slice: (skip, skip + (end - start) as usize),
+                })
+            }
+            ReadPlan::Segments {
+                header,
+                first,
+                slice: (start, end),
+                ..
+            } => {
+                let plaintext = keys.decrypt_segments(path, &header, first, data)?;
+                if plaintext.len() < end {
+                    return Err(ErrorCode::StorageOther(format!(
+                        "encrypted object {} is truncated",
+                        path
+                    )));
+                }
+                Ok(ReadPlan::Done(Bytes::from(plaintext).slice(start..end)))
+            }
+            ReadPlan::Done(_) => unreachable!("the read of {} is done", path),
+        }
+    }
+
+    /// The range of the encrypted object to fetch, none if the read is done.
+    fn range(&self) -> Option<BytesRange> {
+        match self {
+            ReadPlan::Whole => Some(BytesRange::new(None, None)),
+            ReadPlan::Header => Some(BytesRange::new(Some(0), Some(HEADER_LEN as u64))),
+            ReadPlan::Segments { range, .. } => Some(*range),
+            ReadPlan::Done(_) => None,
+        }
+    }
+}
+
+fn plaintext_metadata(rp: RpStat) -> RpStat {
+    let mut meta = rp.into_metadata();
+    if meta.mode().is_file() {
+        let len = meta.content_length();
+        meta.set_content_length(plaintext_size(len));
+    }
+    RpStat::new(meta)
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for EncryptionAccessor<A> {
+    type Inner = A;
+    type Reader = oio::Cursor;
+    type BlockingReader = oio::Cursor;
+    type Writer = EncryptionWriter<A>;
+    type BlockingWriter = EncryptionWriter<A>;
+    type Pager = EncryptionPager<A::Pager>;
+    type BlockingPager = EncryptionPager<A::BlockingPager>;
+    type Appender = A::Appender;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    #[async_backtrace::framed]
+    async fn read(&self, path: &str, args: OpRead) -> opendal::Result<(RpRead, Self::Reader)> {
+        let keys = data_keys(&self.keys)?;
+        let mut plan = ReadPlan::new(args.range());
+        while let Some(range) = plan.range() {
+            let data = self.read_bytes(path, range).await?;
+            plan = plan
+                .next(keys, path, args.range(), &data)
+                .map_err(to_opendal_error)?;
+        }
+        let ReadPlan::Done(bs) = plan else {
+            unreachable!()
+        };
+        Ok((RpRead::new(bs.len() as u64), oio::Cursor::from(bs)))
+    }
+
+    #[async_backtrace::framed]
+    async fn write(&self, path: &str, _args: OpWrite) -> opendal::Result<(RpWrite, Self::Writer)> {
+        data_keys(&self.keys)?;
+        let writer = EncryptionWriter {
+            inner: self.inner.clone(),
+            keys: self.keys.clone(),
+            path: path.to_string(),
+            buf: vec![],
+        };
+        Ok((RpWrite::default(), writer))
+    }
+
+    #[async_backtrace::framed]
+    async fn stat(&self, path: &str, args: OpStat) -> opendal::Result<RpStat> {
+        self.inner.stat(path, args).await.map(plaintext_metadata)
+    }
+
+    #[async_backtrace::framed]
+    async fn list(&self, path: &str, args: OpList) -> opendal::Result<(RpList, Self::Pager)> {
+        let (rp, pager) = self.inner.list(path, args).await?;
+        Ok((rp, EncryptionPager { inner: pager }))
+    }
+
+    async fn append(
+        &self,
+        _path: &str,
+        _args: OpAppend,
+    ) -> opendal::Result<(RpAppend, Self::Appender)> {
+        Err(opendal::Error::new(
+            ErrorKind::Unsupported,
+            "append is not supported by encrypted tables",
+        ))
+    }
+
+    fn blocking_read(
+        &self,
+        path: &str,
+        args: OpRead,
+    ) -> opendal::Result<(RpRead, Self::BlockingReader)> {
+        let keys = data_keys(&self.keys)?;
+        let mut plan = ReadPlan::new(args.range());
+        while let Some(range) = plan.range() {
+            let data = self.blocking_read_bytes(path, range)?;
+            plan = plan
+                .next(keys, path, args.range(), &data)
+                .map_err(to_opendal_error)?;
+        }
+        let ReadPlan::Done(bs) = plan else {
+            unreachable!()
+        };
+        Ok((RpRead::new(bs.len() as u64), oio::Cursor::from(bs)))
+    }
+
+    fn blocking_write(
+        &self,
+        path: &str,
+        _args: OpWrite,
+    ) -> opendal::Result<(RpWrite, Self::BlockingWriter)> {
+        data_keys(&self.keys)?;
+        let writer = EncryptionWriter {
+            inner: self.inner.clone(),
+            keys: self.keys.clone(),
+            path: path.to_string(),
+            buf: vec![],
+        };
+        Ok((RpWrite::default(), writer))
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> opendal::Result<RpStat> {
+        self.inner.blocking_stat(path, args).map(plaintext_metadata)
+    }
+
+    fn blocking_list(
+        &self,
+        path: &str,
+        args: OpList,
+    ) -> opendal::Result<(RpList, Self::BlockingPager)> {
+        let (rp, pager) = self.inner.blocking_list(path, args)?;
+        Ok((rp, EncryptionPager { inner: pager }))
+    }
+}
+
+/// Lists the objects of an encrypted table.
+///
+/// The listed sizes are the ones of the encrypted objects, and the entry metadata can
+/// not be amended in place, so only the mode of the files is kept. The other metadata
+/// is fetched by `stat`, which reports the plaintext size.
+pub struct EncryptionPager<P> {
+    inner: P,
+}
+
+fn plaintext_entries(entries: Option<Vec<oio::Entry>>) -> Option<Vec<oio::Entry>> {
+    entries.map(|entries| {
+        entries
+            .into_iter()
+            .map(|entry| match entry.mode() {
+                EntryMode::FILE => oio::Entry::new(entry.path(), Metadata::new(EntryMode::FILE)),
+                _ => entry,
+            })
+            .collect()
+    })
+}
+
+#[async_trait]
+impl<P: oio::Page> oio::Page for EncryptionPager<P> {
+    #[async_backtrace::framed]
+    async fn next(&mut self) -> opendal::Result<Option<Vec<oio::Entry>>> {
+        self.inner.next().await.map(plaintext_entries)
+    }
+}
+
+impl<P: oio::BlockingPage> oio::BlockingPage for EncryptionPager<P> {
+    fn next(&mut self) -> opendal::Result<Option<Vec<oio::Entry>>> {
+        self.inner.next().map(plaintext_entries)
+    }
+}
+
+/// Buffers the object, which is encrypted and written as a whole on close.
+pub struct EncryptionWriter<A> {
+    inner: Arc<A>,
+    keys: Arc<DataKeysResult>,
+    path: String,
+    buf: Vec<u8>,
+}
+
+impl<A> EncryptionWriter<A> {
+    fn encrypt(&mut self) -> opendal::Result<Bytes> {
+        let plaintext = std::mem::take(&mut self.buf);
+        data_keys(&self.keys)?
+            .encrypt(&self.path, &plaintext)
+            .map(Bytes::from)
+            .map_err(to_opendal_error)
+    }
+}
+
+#[async_trait]
+impl<A: Accessor> oio::Write for EncryptionWriter<A> {
+    #[async_backtrace::framed]
+    async fn write(&mut self, bs: Bytes) -> opendal::Result<()> {
+        self.buf.extend_from_slice(&bs);
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn abort(&mut self) -> opendal::Result<()> {
+        self.buf.clear();
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn sink(&mut self, _size: u64, _s: Streamer) -> opendal::Result<()> {
+        Err(opendal::Error::new(
+            ErrorKind::Unsupported,
+            "sink is not supported by encrypted tables",
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn close(&mut self) -> opendal::Result<()> {
+        let data = self.encrypt()?;
+        let (_, mut writer) = self.inner.write(&self.path, OpWrite::new()).await?;
+        oio::Write::write(&mut writer, data).await?;
+        oio::Write::close(&mut writer).await
+    }
+}
+
+impl<A: Accessor> oio::BlockingWrite for EncryptionWriter<A> {
+    fn write(&mut self, bs: Bytes) -> opendal::Result<()> {
+        self.buf.extend_from_slice(&bs);
+        Ok(())
+    }
+
+    fn close(&mut self) -> opendal::Result<()> {
+        let data = self.encrypt()?;
+        let (_, mut writer) = self.inner.blocking_write(&self.path, OpWrite::new())?;
+        oio::BlockingWrite::write(&mut writer, data)?;
+        oio::BlockingWrite::close(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY_1: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+    const KEY_2: &str = "1f1e1d1c1b1a191817161514131211100f0e0d0c0b0a09080706050403020100";
+
+    #[test]
+    fn test_local_file_key_provider() -> Result<()> {
+        let provider =
+            LocalFileKeyProvider::parse(&format!("# master keys\nk1 {}\n\nk2 {}\n", KEY_1, KEY_2))?;
+        assert_eq!(provider.current_master_key()?.id, "k2");
+        assert_eq!(provider.master_key("k1")?.id, "k1");
+        assert!(provider.master_key("k3").is_err());
+        assert!(LocalFileKeyProvider::parse("k1 0011").is_err());
+        assert!(LocalFileKeyProvider::parse("").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_encrypt_decrypt() -> Result<()> {
+        let provider = ConfigKeyProvider::try_create(KEY_1)?;
+        let keyring = generate_table_keyring(&provider)?;
+        let keys = DataKeys::try_create(&provider, &keyring)?;
+
+        let data = keys.encrypt("1/2/_b/a.parquet", b"hello world")?;
+        assert_eq!(data.len() as u64, encrypted_size(11));
+        assert_eq!(keys.decrypt("1/2/_b/a.parquet", &data)?, b"hello world");
+        // the path is authenticated
+        assert!(keys.decrypt("1/2/_b/b.parquet", &data).is_err());
+        // so is the content
+        let mut tampered = data.clone();
+        tampered[HEADER_LEN] ^= 1;
+        assert!(keys.decrypt("1/2/_b/a.parquet", &tampered).is_err());
+        assert!(keys.decrypt("1/2/_b/a.parquet", b"hello world").is_err());
+        // so is the header
+        let mut tampered = data.clone();
+        tampered[8] ^= 1;
+        assert!(keys.decrypt("1/2/_b/a.parquet", &tampered).is_err());
+
+        let data = keys.encrypt("empty", b"")?;
+        assert_eq!(data.len() as u64, encrypted_size(0));
+        assert_eq!(keys.decrypt("empty", &data)?, b"");
+        Ok(())
+    }
+
+    #[test]
+    fn test_encrypted_size() {
+        for len in [
+            0,
+            1,
+            SEGMENT_SIZE - 1,
+            SEGMENT_SIZE,
+            SEGMENT_SIZE + 1,
+            3 * SEGMENT_SIZE,
+        ] {
+            assert_eq!(plaintext_size(encrypted_size(len)), len);
+        }
+        assert_eq!(
+            encrypted_size(SEGMENT_SIZE + 1),
+            HEADER_LEN as u64 + SEGMENT_SIZE + 1 + 2 * TAG_LEN as u64
+        );
+    }
+
+    #[test]
+    fn test_read_segments() -> Result<()> {
+        let provider = ConfigKeyProvider::try_create(KEY_1)?;
+        let keyring = generate_table_keyring(&provider)?;
+        let keys = DataKeys::try_create(&provider, &keyring)?;
+
+        let plaintext: Vec<u8> = (0..3 * SEGMENT_SIZE + 100).map(|i| i as u8).collect();
+        let data = keys.encrypt("a", &plaintext)?;
+        assert_eq!(keys.decrypt("a", &data)?, plaintext);
+
+        // returns the plaintext of the range, and the number of the bytes fetched
+        let read = |range: BytesRange| -> Result<(Bytes, usize)> {
+            let mut plan = ReadPlan::new(range);
+            let mut fetched = 0;
+            while let Some(fetch) = plan.range() {
+                let (start, end) = plaintext_range(fetch, data.len() as u64);
+                fetched += (end - start) as usize;
+                plan = plan.next(&keys, "a", range, &data[start as usize..end as usize])?;
+            }
+            let ReadPlan::Done(bs) = plan else {
+                unreachable!()
+            };
+            Ok((bs, fetched))
+        };
+
+        let (bs, fetched) = read(BytesRange::new(None, None))?;
+        assert_eq!(bs, plaintext);
+        assert_eq!(fetched, data.len());
+
+        // only the header and the second segment are fetched
+        let start = SEGMENT_SIZE + 10;
+        let (bs, fetched) = read(BytesRange::new(Some(start), Some(100)))?;
+        assert_eq!(bs, plaintext[start as usize..start as usize + 100]);
+        assert_eq!(fetched, HEADER_LEN + ENCRYPTED_SEGMENT_SIZE as usize);
+
+        // a range across segments
+        let (bs, _) = read(BytesRange::new(Some(SEGMENT_SIZE - 1), Some(2)))?;
+        assert_eq!(
+            bs,
+            plaintext[SEGMENT_SIZE as usize - 1..SEGMENT_SIZE as usize + 1]
+        );
+
+        // a suffix of the last, shorter segment
+        let (bs, fetched) = read(BytesRange::new(None, Some(10)))?;
+        assert_eq!(bs, plaintext[plaintext.len() - 10..]);
+        assert_eq!(fetched, HEADER_LEN + 100 + TAG_LEN);
+
+        // out of the plaintext
+        let (bs, _) = read(BytesRange::new(Some(10 * SEGMENT_SIZE), None))?;
+        assert!(bs.is_empty());
+
+        // segments can not be swapped
+        let header = Header::parse("a", &data)?;
+        let segment = |i: usize| {
+            let offset = HEADER_LEN + i * ENCRYPTED_SEGMENT_SIZE as usize;
+            &data[offset..offset + ENCRYPTED_SEGMENT_SIZE as usize]
+        };
+        assert!(keys.decrypt_segments("a", &header, 1, segment(1)).is_ok());
+        assert!(keys.decrypt_segments("a", &header, 1, segment(2)).is_err());
+        // nor truncated
+        assert!(
+            keys.decrypt("a", &data[..data.len() - 100 - TAG_LEN])
+                .is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_rotate_keyring() -> Result<()> {
+        let old_provider = LocalFileKeyProvider::parse(&format!("k1 {}", KEY_1))?;
+        let keyring = generate_table_keyring(&old_provider)?;
+        let keys = DataKeys::try_create(&old_provider, &keyring)?;
+        let data = keys.encrypt("a", b"before rotation")?;
+
+        let provider = LocalFileKeyProvider::parse(&format!("k1 {}\nk2 {}", KEY_1, KEY_2))?;
+        let rotated = rotate_table_keyring(&provider, &keyring)?;
+        let wrapped = parse_keyring(&rotated)?;
+        assert_eq!(wrapped.len(), 2);
+        assert!(wrapped.iter().all(|k| k.master_key_id == "k2"));
+
+        // k1 can be retired, objects written with the former data key are still readable.
+        let new_provider = LocalFileKeyProvider::parse(&format!("k2 {}", KEY_2))?;
+        let keys = DataKeys::try_create(&new_provider, &rotated)?;
+        assert_eq!(keys.current, 2);
+        assert_eq!(keys.decrypt("a", &data)?, b"before rotation");
+        let data = keys.encrypt("b", b"after rotation")?;
+        assert_eq!(u32::from_le_bytes(data[4..8].try_into().unwrap()), 2);
+        assert_eq!(keys.decrypt("b", &data)?, b"after rotation");
+        Ok(())
+    }
+}
@@ -45,6 +45,10 @@ pub use common_metrics::StorageMetricsLayer;
 
 mod runtime_layer;
 
+pub mod encryption;
+pub use encryption::EncryptionKeyProvider;
+pub use encryption::EncryptionLayer;
+
 mod column_node;
 pub use column_node::ColumnNode;
 pub use column_node::ColumnNodes;
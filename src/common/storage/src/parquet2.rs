@@ -21,6 +21,7 @@ use common_base::runtime::execute_futures_in_parallel;
 use common_base::runtime::GLOBAL_MEM_STAT;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::ARROW_EXT_TYPE_BINARY;
 use opendal::Operator;
 
 #[async_backtrace::framed]
@@ -34,7 +35,7 @@ pub async fn read_parquet_schema_async(operator: &Operator, path: &str) -> Resul
 }
 
 pub fn infer_schema_with_extension(meta: &FileMetaData) -> Result<ArrowSchema> {
-    let arrow_schema = pread::infer_schema(meta)?;
+    let mut arrow_schema = pread::infer_schema(meta)?;
     // Convert data types to extension types using meta information.
     // Mainly used for types such as Variant and Bitmap,
     // as they have the same physical type as String.
@@ -55,10 +56,20 @@ pub fn infer_schema_with_extension(meta: &FileMetaData) -> Result<ArrowSchema> {
                 }
             }
         }
-        Ok(new_fields.into())
-    } else {
-        Ok(arrow_schema)
+        arrow_schema = new_fields.into();
+    }
+    // Byte arrays without a string annotation are read as Binary. Strings written by
+    // databend are `LargeBinary` in the arrow schema kept in the file, so they stay String.
+    for field in arrow_schema.fields.iter_mut() {
+        if field.data_type == ArrowType::Binary {
+            field.data_type = ArrowType::Extension(
+                ARROW_EXT_TYPE_BINARY.to_string(),
+                Box::new(ArrowType::Binary),
+                None,
+            );
+        }
     }
+    Ok(arrow_schema)
 }
 
 async fn read_parquet_metas_batch(
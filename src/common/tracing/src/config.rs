@@ -21,6 +21,7 @@ pub struct Config {
     pub file: FileConfig,
     pub stderr: StderrConfig,
     pub query: QueryLogConfig,
+    pub audit: AuditLogConfig,
     pub tracing: TracingConfig,
 }
 
@@ -43,6 +44,12 @@ impl Config {
                 on: true,
                 dir: "./.databend/logs/query-details".to_string(),
             },
+            audit: AuditLogConfig {
+                on: true,
+                dir: "./.databend/logs/audit".to_string(),
+                sinks: vec!["tracing".to_string()],
+                table: "default.audit_log".to_string(),
+            },
             tracing: TracingConfig {
                 on: true,
                 capture_log_level: "TRACE".to_string(),
@@ -136,6 +143,51 @@ impl Default for QueryLogConfig {
     }
 }
 
+/// Config for the audit log of DDL, DCL and data access statements.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct AuditLogConfig {
+    pub on: bool,
+    /// The dir of the JSON files written by the `file` sink.
+    pub dir: String,
+    /// Where audit events go, any of `file`, `table` and `tracing`.
+    pub sinks: Vec<String>,
+    /// The fuse table written by the `table` sink, as `database.table`.
+    pub table: String,
+}
+
+impl AuditLogConfig {
+    pub const SINKS: [&'static str; 3] = ["file", "table", "tracing"];
+
+    /// Returns true if audit events should be written to the sink named `name`.
+    pub fn sink_enabled(&self, name: &str) -> bool {
+        self.on && self.sinks.iter().any(|s| s == name)
+    }
+}
+
+impl Display for AuditLogConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "enabled={}, dir={}, sinks={}, table={}",
+            self.on,
+            self.dir,
+            self.sinks.join(","),
+            self.table
+        )
+    }
+}
+
+impl Default for AuditLogConfig {
+    fn default() -> Self {
+        Self {
+            on: false,
+            dir: "./.databend/logs/audit".to_string(),
+            sinks: vec!["file".to_string()],
+            table: "default.audit_log".to_string(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
 pub struct TracingConfig {
     pub on: bool,
@@ -20,6 +20,7 @@ mod config;
 mod minitrace;
 mod panic_hook;
 
+pub use crate::config::AuditLogConfig;
 pub use crate::config::Config;
 pub use crate::config::FileConfig;
 pub use crate::config::QueryLogConfig;
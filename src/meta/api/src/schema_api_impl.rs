@@ -49,6 +49,8 @@ use common_meta_app::app_error::WrongShare;
 use common_meta_app::app_error::WrongShareObject;
 use common_meta_app::data_mask::MaskpolicyTableIdList;
 use common_meta_app::data_mask::MaskpolicyTableIdListKey;
+use common_meta_app::row_access_policy::RowAccessPolicyTableIdList;
+use common_meta_app::row_access_policy::RowAccessPolicyTableIdListKey;
 use common_meta_app::schema::CatalogId;
 use common_meta_app::schema::CatalogIdToName;
 use common_meta_app::schema::CatalogInfo;
@@ -123,6 +125,9 @@ use common_meta_app::schema::RenameTableReq;
 use common_meta_app::schema::SetTableColumnMaskPolicyAction;
 use common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use common_meta_app::schema::SetTableRowAccessPolicyAction;
+use common_meta_app::schema::SetTableRowAccessPolicyReply;
+use common_meta_app::schema::SetTableRowAccessPolicyReq;
 use common_meta_app::schema::TableCopiedFileInfo;
 use common_meta_app::schema::TableCopiedFileNameIdent;
 use common_meta_app::schema::TableId;
@@ -141,12 +146,16 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateDatabaseCommentReply;
+use common_meta_app::schema::UpdateDatabaseCommentReq;
 use common_meta_app::schema::UpdateIndexReply;
 use common_meta_app::schema::UpdateIndexReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReply;
 use common_meta_app::schema::UpdateVirtualColumnReq;
+use common_meta_app::schema::UpsertDatabaseOptionReply;
+use common_meta_app::schema::UpsertDatabaseOptionReq;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_meta_app::schema::UpsertTableOptionReply;
 use common_meta_app::schema::UpsertTableOptionReq;
@@ -747,6 +756,106 @@ impl<KV: kvapi::KVApi<Error = MetaError>> SchemaApi for KV {
         )))
     }
 
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn update_database_comment(
+        &self,
+        req: UpdateDatabaseCommentReq,
+    ) -> Result<UpdateDatabaseCommentReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            let (_, db_id, db_meta_seq, mut db_meta) =
+                get_db_or_err(self, &req.name_ident, "update_database_comment").await?;
+            db_meta.comment = req.comment.clone();
+            db_meta.updated_on = Utc::now();
+
+            let id_key = DatabaseId { db_id };
+            let txn_req = TxnRequest {
+                condition: vec![
+                    // db meta is not changed
+                    txn_cond_seq(&id_key, Eq, db_meta_seq),
+                ],
+                if_then: vec![
+                    txn_op_put(&id_key, serialize_struct(&db_meta)?), // (db_id) -> db_meta
+                ],
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                name = as_debug!(&req.name_ident),
+                succ = succ;
+                "update_database_comment"
+            );
+
+            if succ {
+                return Ok(UpdateDatabaseCommentReply {});
+            }
+        }
+
+        Err(KVAppError::AppError(AppError::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("update_database_comment", TXN_MAX_RETRY_TIMES),
+        )))
+    }
+
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn upsert_database_option(
+        &self,
+        req: UpsertDatabaseOptionReq,
+    ) -> Result<UpsertDatabaseOptionReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            let (_, db_id, db_meta_seq, mut db_meta) =
+                get_db_or_err(self, &req.name_ident, "upsert_database_option").await?;
+            for (k, v) in req.options.iter() {
+                if let Some(value) = v {
+                    db_meta.options.insert(k.to_string(), value.to_string());
+                } else {
+                    db_meta.options.remove(k);
+                }
+            }
+            db_meta.updated_on = Utc::now();
+
+            let id_key = DatabaseId { db_id };
+            let txn_req = TxnRequest {
+                condition: vec![
+                    // db meta is not changed
+                    txn_cond_seq(&id_key, Eq, db_meta_seq),
+                ],
+                if_then: vec![
+                    txn_op_put(&id_key, serialize_struct(&db_meta)?), // (db_id) -> db_meta
+                ],
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                name = as_debug!(&req.name_ident),
+                succ = succ;
+                "upsert_database_option"
+            );
+
+            if succ {
+                return Ok(UpsertDatabaseOptionReply {});
+            }
+        }
+
+        Err(KVAppError::AppError(AppError::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("upsert_database_option", TXN_MAX_RETRY_TIMES),
+        )))
+    }
+
     #[logcall::logcall("debug")]
     #[minitrace::trace]
     async fn get_database(&self, req: GetDatabaseReq) -> Result<Arc<DatabaseInfo>, KVAppError> {
@@ -2905,6 +3014,90 @@ impl<KV: kvapi::KVApi<Error = MetaError>> SchemaApi for KV {
         )))
     }
 
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn set_table_row_access_policy(
+        &self,
+        req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+        let tbid = TableId {
+            table_id: req.table_id,
+        };
+        let req_seq = req.seq;
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+            let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
+                get_pb_value(self, &tbid).await?;
+
+            debug!(ident = as_display!(&tbid); "set_table_row_access_policy");
+
+            if tb_meta_seq == 0 || table_meta.is_none() {
+                return Err(KVAppError::AppError(AppError::UnknownTableId(
+                    UnknownTableId::new(req.table_id, "set_table_row_access_policy"),
+                )));
+            }
+            if req_seq.match_seq(tb_meta_seq).is_err() {
+                return Err(KVAppError::AppError(AppError::from(
+                    TableVersionMismatched::new(
+                        req.table_id,
+                        req.seq,
+                        tb_meta_seq,
+                        "set_table_row_access_policy",
+                    ),
+                )));
+            }
+
+            let mut new_table_meta = table_meta.unwrap();
+            new_table_meta.row_access_policy = match &req.action {
+                SetTableRowAccessPolicyAction::Set(policy, _old_policy_name) => {
+                    Some(policy.clone())
+                }
+                SetTableRowAccessPolicyAction::Unset(_) => None,
+            };
+
+            let mut txn_req = TxnRequest {
+                condition: vec![
+                    // table is not changed
+                    txn_cond_seq(&tbid, Eq, tb_meta_seq),
+                ],
+                if_then: vec![
+                    txn_op_put(&tbid, serialize_struct(&new_table_meta)?), // tb_id -> tb_meta
+                ],
+                else_then: vec![],
+            };
+
+            update_row_access_policy(
+                self,
+                &req.action,
+                &mut txn_req.condition,
+                &mut txn_req.if_then,
+                req.tenant.clone(),
+                req.table_id,
+            )
+            .await?;
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                id = as_debug!(&tbid),
+                succ = succ;
+                "set_table_row_access_policy"
+            );
+
+            if succ {
+                return Ok(SetTableRowAccessPolicyReply {
+                    share_table_info: get_share_table_info_map(self, &new_table_meta).await?,
+                });
+            }
+        }
+
+        Err(KVAppError::AppError(AppError::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("set_table_row_access_policy", TXN_MAX_RETRY_TIMES),
+        )))
+    }
+
     #[logcall::logcall("debug")]
     #[minitrace::trace]
     async fn get_drop_table_infos(
@@ -4361,3 +4554,80 @@ async fn update_mask_policy(
 
     Ok(())
 }
+
+async fn update_row_access_policy_table_id_list(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    condition: &mut Vec<TxnCondition>,
+    if_then: &mut Vec<TxnOp>,
+    tenant: String,
+    name: String,
+    table_id: u64,
+    add: bool,
+) -> Result<(), KVAppError> {
+    let id_list_key = RowAccessPolicyTableIdListKey { tenant, name };
+
+    let (id_list_seq, id_list_opt): (_, Option<RowAccessPolicyTableIdList>) =
+        get_pb_value(kv_api, &id_list_key).await?;
+    if let Some(mut id_list) = id_list_opt {
+        if add {
+            id_list.id_list.insert(table_id);
+        } else {
+            id_list.id_list.remove(&table_id);
+        }
+
+        condition.push(txn_cond_seq(&id_list_key, Eq, id_list_seq));
+        if_then.push(txn_op_put(&id_list_key, serialize_struct(&id_list)?));
+    }
+
+    Ok(())
+}
+
+async fn update_row_access_policy(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    action: &SetTableRowAccessPolicyAction,
+    condition: &mut Vec<TxnCondition>,
+    if_then: &mut Vec<TxnOp>,
+    tenant: String,
+    table_id: u64,
+) -> Result<(), KVAppError> {
+    match action {
+        SetTableRowAccessPolicyAction::Set(new_policy, old_policy_name_opt) => {
+            if let Some(old_policy_name) = old_policy_name_opt {
+                update_row_access_policy_table_id_list(
+                    kv_api,
+                    condition,
+                    if_then,
+                    tenant.clone(),
+                    old_policy_name.clone(),
+                    table_id,
+                    false,
+                )
+                .await?;
+            }
+            update_row_access_policy_table_id_list(
+                kv_api,
+                condition,
+                if_then,
+                tenant.clone(),
+                new_policy.policy.clone(),
+                table_id,
+                true,
+            )
+            .await?;
+        }
+        SetTableRowAccessPolicyAction::Unset(policy_name) => {
+            update_row_access_policy_table_id_list(
+                kv_api,
+                condition,
+                if_then,
+                tenant.clone(),
+                policy_name.clone(),
+                table_id,
+                false,
+            )
+            .await?;
+        }
+    }
+
+    Ok(())
+}
@@ -29,6 +29,11 @@ use common_meta_app::data_mask::DatamaskNameIdent;
 use common_meta_app::data_mask::DropDatamaskReq;
 use common_meta_app::data_mask::MaskpolicyTableIdList;
 use common_meta_app::data_mask::MaskpolicyTableIdListKey;
+use common_meta_app::row_access_policy::CreateRowAccessPolicyReq;
+use common_meta_app::row_access_policy::DropRowAccessPolicyReq;
+use common_meta_app::row_access_policy::RowAccessPolicyNameIdent;
+use common_meta_app::row_access_policy::RowAccessPolicyTableIdList;
+use common_meta_app::row_access_policy::RowAccessPolicyTableIdListKey;
 use common_meta_app::schema::CatalogMeta;
 use common_meta_app::schema::CatalogNameIdent;
 use common_meta_app::schema::CatalogOption;
@@ -79,6 +84,8 @@ use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReq;
 use common_meta_app::schema::SetTableColumnMaskPolicyAction;
 use common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use common_meta_app::schema::SetTableRowAccessPolicyAction;
+use common_meta_app::schema::SetTableRowAccessPolicyReq;
 use common_meta_app::schema::TableCopiedFileInfo;
 use common_meta_app::schema::TableCopiedFileNameIdent;
 use common_meta_app::schema::TableId;
@@ -90,12 +97,15 @@ use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableInfoFilter;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableNameIdent;
+use common_meta_app::schema::TableRowAccessPolicy;
 use common_meta_app::schema::TableStatistics;
 use common_meta_app::schema::TruncateTableReq;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateDatabaseCommentReq;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReq;
+use common_meta_app::schema::UpsertDatabaseOptionReq;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_meta_app::schema::UpsertTableOptionReq;
 use common_meta_app::schema::VirtualColumnNameIdent;
@@ -121,6 +131,7 @@ use crate::kv_app_error::KVAppError;
 use crate::serialize_struct;
 use crate::testing::get_kv_data;
 use crate::DatamaskApi;
+use crate::RowAccessPolicyApi;
 use crate::SchemaApi;
 use crate::ShareApi;
 
@@ -251,7 +262,11 @@ impl SchemaApiTestSuite {
     pub async fn test_single_node<B, MT>(b: B) -> anyhow::Result<()>
     where
         B: kvapi::ApiBuilder<MT>,
-        MT: ShareApi + kvapi::AsKVApi<Error = MetaError> + SchemaApi + DatamaskApi,
+        MT: ShareApi
+            + kvapi::AsKVApi<Error = MetaError>
+            + SchemaApi
+            + DatamaskApi
+            + RowAccessPolicyApi,
     {
         let suite = SchemaApiTestSuite {};
 
@@ -266,6 +281,8 @@ impl SchemaApiTestSuite {
         suite.database_list(&b.build().await).await?;
         suite.database_list_in_diff_tenant(&b.build().await).await?;
         suite.database_rename(&b.build().await).await?;
+        suite.database_update_comment(&b.build().await).await?;
+        suite.database_upsert_option(&b.build().await).await?;
         suite
             .database_drop_undrop_list_history(&b.build().await)
             .await?;
@@ -277,6 +294,9 @@ impl SchemaApiTestSuite {
         suite.table_rename(&b.build().await).await?;
         suite.table_update_meta(&b.build().await).await?;
         suite.table_update_mask_policy(&b.build().await).await?;
+        suite
+            .table_update_row_access_policy(&b.build().await)
+            .await?;
         suite.table_upsert_option(&b.build().await).await?;
         suite.table_list(&b.build().await).await?;
         suite.table_list_all(&b.build().await).await?;
@@ -987,6 +1007,118 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[minitrace::trace]
+    async fn database_update_comment<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let name_ident = DatabaseNameIdent {
+            tenant: tenant.to_string(),
+            db_name: db_name.to_string(),
+        };
+
+        info!("--- update comment of not exists db1");
+        {
+            let req = UpdateDatabaseCommentReq {
+                name_ident: name_ident.clone(),
+                comment: "db comment".to_string(),
+            };
+
+            let res = mt.update_database_comment(req).await;
+            assert!(res.is_err());
+            assert_eq!(
+                ErrorCode::UnknownDatabase("").code(),
+                ErrorCode::from(res.unwrap_err()).code()
+            );
+        }
+
+        info!("--- update comment of db1");
+        {
+            self.create_database(mt, tenant, db_name, "eng1").await?;
+
+            let req = UpdateDatabaseCommentReq {
+                name_ident: name_ident.clone(),
+                comment: "db comment".to_string(),
+            };
+            mt.update_database_comment(req).await?;
+
+            let db = mt
+                .get_database(GetDatabaseReq::new(tenant, db_name))
+                .await?;
+            assert_eq!("db comment", db.meta.comment);
+        }
+
+        Ok(())
+    }
+
+    #[minitrace::trace]
+    async fn database_upsert_option<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let name_ident = DatabaseNameIdent {
+            tenant: tenant.to_string(),
+            db_name: db_name.to_string(),
+        };
+
+        info!("--- upsert option of not exists db1");
+        {
+            let req = UpsertDatabaseOptionReq {
+                name_ident: name_ident.clone(),
+                options: maplit::hashmap! {"k1".into() => Some("v1".into())},
+            };
+
+            let res = mt.upsert_database_option(req).await;
+            assert!(res.is_err());
+            assert_eq!(
+                ErrorCode::UnknownDatabase("").code(),
+                ErrorCode::from(res.unwrap_err()).code()
+            );
+        }
+
+        self.create_database(mt, tenant, db_name, "eng1").await?;
+
+        info!("--- add options of db1");
+        {
+            let req = UpsertDatabaseOptionReq {
+                name_ident: name_ident.clone(),
+                options: maplit::hashmap! {
+                    "k1".into() => Some("v1".into()),
+                    "k2".into() => Some("v2".into()),
+                },
+            };
+            mt.upsert_database_option(req).await?;
+
+            let db = mt
+                .get_database(GetDatabaseReq::new(tenant, db_name))
+                .await?;
+            assert_eq!(
+                maplit::btreemap! {"k1".into() => "v1".into(), "k2".into() => "v2".into()},
+                db.meta.options
+            );
+        }
+
+        info!("--- update and remove options of db1");
+        {
+            let req = UpsertDatabaseOptionReq {
+                name_ident: name_ident.clone(),
+                options: maplit::hashmap! {
+                    "k1".into() => Some("v3".into()),
+                    "k2".into() => None,
+                },
+            };
+            mt.upsert_database_option(req).await?;
+
+            let db = mt
+                .get_database(GetDatabaseReq::new(tenant, db_name))
+                .await?;
+            assert_eq!(
+                maplit::btreemap! {"k1".into() => "v3".into()},
+                db.meta.options
+            );
+        }
+
+        Ok(())
+    }
+
     #[minitrace::trace]
     async fn database_rename<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
         let tenant = "tenant1";
@@ -2507,6 +2639,166 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[minitrace::trace]
+    async fn table_update_row_access_policy<
+        MT: SchemaApi + RowAccessPolicyApi + kvapi::AsKVApi<Error = MetaError>,
+    >(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_name = "tb1";
+        let policy_name_1 = "policy1";
+        let policy_name_2 = "policy2";
+
+        let schema = || {
+            Arc::new(TableSchema::new(vec![TableField::new(
+                "number",
+                TableDataType::Number(NumberDataType::UInt64),
+            )]))
+        };
+
+        let table_meta = |created_on| TableMeta {
+            schema: schema(),
+            engine: "JSON".to_string(),
+            options: Default::default(),
+            created_on,
+            ..TableMeta::default()
+        };
+
+        let get_table = || GetTableReq {
+            inner: TableNameIdent {
+                tenant: tenant.to_string(),
+                db_name: db_name.to_string(),
+                table_name: tbl_name.to_string(),
+            },
+        };
+
+        let id_list_of = |name: &str| RowAccessPolicyTableIdListKey {
+            tenant: tenant.to_string(),
+            name: name.to_string(),
+        };
+
+        info!("--- prepare db and table");
+        let created_on = Utc::now();
+        {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta {
+                    engine: "".to_string(),
+                    ..DatabaseMeta::default()
+                },
+            };
+            mt.create_database(plan).await?;
+
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: tbl_name.to_string(),
+                },
+                table_meta: table_meta(created_on),
+            };
+            mt.create_table(req).await?;
+        }
+
+        info!("--- create row access policies");
+        for name in [policy_name_1, policy_name_2] {
+            let req = CreateRowAccessPolicyReq {
+                if_not_exists: false,
+                name: RowAccessPolicyNameIdent {
+                    tenant: tenant.to_string(),
+                    name: name.to_string(),
+                },
+                args: vec![("n".to_string(), "UInt64".to_string())],
+                body: "n > 0".to_string(),
+                comment: None,
+                create_on: created_on,
+            };
+            mt.create_row_access_policy(req).await?;
+        }
+
+        let policy_of = |name: &str| TableRowAccessPolicy {
+            policy: name.to_string(),
+            columns: vec!["number".to_string()],
+        };
+
+        let table_id;
+        info!("--- add policy1 to table and check");
+        {
+            let res = mt.get_table(get_table()).await?;
+            table_id = res.ident.table_id;
+
+            let req = SetTableRowAccessPolicyReq {
+                tenant: tenant.to_string(),
+                seq: MatchSeq::Exact(res.ident.seq),
+                table_id,
+                action: SetTableRowAccessPolicyAction::Set(policy_of(policy_name_1), None),
+            };
+            mt.set_table_row_access_policy(req).await?;
+
+            let res = mt.get_table(get_table()).await?;
+            assert_eq!(res.meta.row_access_policy, Some(policy_of(policy_name_1)));
+
+            let id_list: RowAccessPolicyTableIdList =
+                get_kv_data(mt.as_kv_api(), &id_list_of(policy_name_1)).await?;
+            assert_eq!(id_list.id_list, BTreeSet::from([table_id]));
+        }
+
+        info!("--- replace policy1 with policy2 and check");
+        {
+            let res = mt.get_table(get_table()).await?;
+
+            let req = SetTableRowAccessPolicyReq {
+                tenant: tenant.to_string(),
+                seq: MatchSeq::Exact(res.ident.seq),
+                table_id,
+                action: SetTableRowAccessPolicyAction::Set(
+                    policy_of(policy_name_2),
+                    Some(policy_name_1.to_string()),
+                ),
+            };
+            mt.set_table_row_access_policy(req).await?;
+
+            let res = mt.get_table(get_table()).await?;
+            assert_eq!(res.meta.row_access_policy, Some(policy_of(policy_name_2)));
+
+            let id_list: RowAccessPolicyTableIdList =
+                get_kv_data(mt.as_kv_api(), &id_list_of(policy_name_1)).await?;
+            assert!(id_list.id_list.is_empty());
+            let id_list: RowAccessPolicyTableIdList =
+                get_kv_data(mt.as_kv_api(), &id_list_of(policy_name_2)).await?;
+            assert_eq!(id_list.id_list, BTreeSet::from([table_id]));
+        }
+
+        info!("--- drop policy2 detaches it from the table");
+        {
+            let req = DropRowAccessPolicyReq {
+                if_exists: false,
+                name: RowAccessPolicyNameIdent {
+                    tenant: tenant.to_string(),
+                    name: policy_name_2.to_string(),
+                },
+            };
+            mt.drop_row_access_policy(req).await?;
+
+            let res = mt.get_table(get_table()).await?;
+            assert_eq!(res.meta.row_access_policy, None);
+
+            let id_list: Result<RowAccessPolicyTableIdList, KVAppError> =
+                get_kv_data(mt.as_kv_api(), &id_list_of(policy_name_2)).await;
+            assert!(id_list.is_err())
+        }
+
+        Ok(())
+    }
+
     #[minitrace::trace]
     async fn table_upsert_option<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
         let tenant = "tenant1";
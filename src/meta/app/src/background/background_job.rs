@@ -242,6 +242,18 @@ impl BackgroundJobInfo {
             created_at: Utc::now(),
         }
     }
+
+    pub fn new_purge_job(job_params: BackgroundJobParams, creator: UserIdentity) -> Self {
+        Self {
+            job_status: Option::from(BackgroundJobStatus::new(&job_params)),
+            job_params: Some(job_params),
+            task_type: BackgroundTaskType::VACUUM,
+            last_updated: Some(Utc::now()),
+            message: "".to_string(),
+            creator: Some(creator),
+            created_at: Utc::now(),
+        }
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
@@ -99,13 +99,27 @@ impl Display for CompactionStats {
     }
 }
 
-// TODO(zhihanz) provide detailed vacuum stats
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
-pub struct VacuumStats {}
+pub struct VacuumStats {
+    pub db_id: u64,
+    pub table_id: u64,
+    // the retention period the table history is purged with
+    pub data_retention_time_in_days: u64,
+    pub purged_snapshots: Option<u64>,
+    pub total_vacuum_time: Option<Duration>,
+}
 
 impl Display for VacuumStats {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "vacuum stats")
+        write!(
+            f,
+            "db_id: {}, table_id: {}, data_retention_time_in_days: {}, purged_snapshots: {:?}, total_vacuum_time: {:?}",
+            self.db_id,
+            self.table_id,
+            self.data_retention_time_in_days,
+            self.purged_snapshots,
+            self.total_vacuum_time,
+        )
     }
 }
 
@@ -151,6 +165,34 @@ impl BackgroundTaskInfo {
             created_at: now,
         }
     }
+
+    pub fn new_vacuum_task(
+        creator: BackgroundJobIdent,
+        db_id: u64,
+        tb_id: u64,
+        data_retention_time_in_days: u64,
+        manual_trigger: Option<ManualTriggerParams>,
+        message: String,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            last_updated: Some(now),
+            task_type: BackgroundTaskType::VACUUM,
+            task_state: BackgroundTaskState::STARTED,
+            message,
+            compaction_task_stats: None,
+            vacuum_stats: Some(VacuumStats {
+                db_id,
+                table_id: tb_id,
+                data_retention_time_in_days,
+                purged_snapshots: None,
+                total_vacuum_time: None,
+            }),
+            manual_trigger,
+            creator: Some(creator),
+            created_at: now,
+        }
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
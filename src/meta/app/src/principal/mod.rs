@@ -18,6 +18,7 @@ mod file_format;
 mod network_policy;
 mod principal_identity;
 mod role_info;
+mod tag;
 mod user_auth;
 mod user_defined_file_format;
 mod user_defined_function;
@@ -34,6 +35,8 @@ pub use network_policy::NetworkPolicy;
 pub use principal_identity::PrincipalIdentity;
 pub use role_info::RoleInfo;
 pub use role_info::RoleInfoSerdeError;
+pub use tag::Tag;
+pub use tag::TagReference;
 pub use user_auth::AuthInfo;
 pub use user_auth::AuthType;
 pub use user_auth::PasswordHashMethod;
@@ -0,0 +1,35 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct Tag {
+    pub name: String,
+    pub comment: String,
+    pub create_on: DateTime<Utc>,
+}
+
+/// The value of a tag set on an object.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct TagReference {
+    pub tag_name: String,
+    pub tag_value: String,
+    /// Kind of the object, such as `TABLE`, `COLUMN` or `STAGE`.
+    pub object_domain: String,
+    /// Fully qualified name of the object, such as `db.table.column`.
+    pub object_name: String,
+    pub create_on: DateTime<Utc>,
+}
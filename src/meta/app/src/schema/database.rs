@@ -14,6 +14,7 @@
 
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
+use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Display;
 use std::fmt::Formatter;
@@ -216,6 +217,49 @@ impl Display for RenameDatabaseReq {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct RenameDatabaseReply {}
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateDatabaseCommentReq {
+    pub name_ident: DatabaseNameIdent,
+    pub comment: String,
+}
+
+impl Display for UpdateDatabaseCommentReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "update_database_comment:{}/{}",
+            self.name_ident.tenant, self.name_ident.db_name
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateDatabaseCommentReply {}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpsertDatabaseOptionReq {
+    pub name_ident: DatabaseNameIdent,
+
+    /// Add or remove options
+    ///
+    /// Some(String): add or update an option.
+    /// None: delete an option.
+    pub options: HashMap<String, Option<String>>,
+}
+
+impl Display for UpsertDatabaseOptionReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "upsert_database_option:{}/{}:{:?}",
+            self.name_ident.tenant, self.name_ident.db_name, self.options
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpsertDatabaseOptionReply {}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct DropDatabaseReq {
     pub if_exists: bool,
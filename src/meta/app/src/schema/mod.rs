@@ -40,6 +40,10 @@ pub use database::RenameDatabaseReply;
 pub use database::RenameDatabaseReq;
 pub use database::UndropDatabaseReply;
 pub use database::UndropDatabaseReq;
+pub use database::UpdateDatabaseCommentReply;
+pub use database::UpdateDatabaseCommentReq;
+pub use database::UpsertDatabaseOptionReply;
+pub use database::UpsertDatabaseOptionReq;
 pub use index::*;
 pub use table::CountTablesKey;
 pub use table::CountTablesReply;
@@ -70,6 +74,9 @@ pub use table::RenameTableReq;
 pub use table::SetTableColumnMaskPolicyAction;
 pub use table::SetTableColumnMaskPolicyReply;
 pub use table::SetTableColumnMaskPolicyReq;
+pub use table::SetTableRowAccessPolicyAction;
+pub use table::SetTableRowAccessPolicyReply;
+pub use table::SetTableRowAccessPolicyReq;
 pub use table::TableCopiedFileInfo;
 pub use table::TableCopiedFileLockKey;
 pub use table::TableCopiedFileNameIdent;
@@ -78,11 +85,13 @@ pub use table::TableIdList;
 pub use table::TableIdListKey;
 pub use table::TableIdToName;
 pub use table::TableIdent;
+pub use table::TableIndex;
 pub use table::TableInfo;
 pub use table::TableInfoFilter;
 pub use table::TableLockKey;
 pub use table::TableMeta;
 pub use table::TableNameIdent;
+pub use table::TableRowAccessPolicy;
 pub use table::TableStatistics;
 pub use table::TruncateTableReply;
 pub use table::TruncateTableReq;
@@ -24,6 +24,7 @@ use std::sync::Arc;
 use chrono::DateTime;
 use chrono::Utc;
 use common_exception::Result;
+use common_expression::ColumnId;
 use common_expression::TableField;
 use common_expression::TableSchema;
 use common_meta_types::MatchSeq;
@@ -226,6 +227,8 @@ pub struct TableMeta {
     pub cluster_keys: Vec<String>,
     // The sequence number of default_cluster_key in cluster_keys.
     pub default_cluster_key_id: Option<u32>,
+    // The partition key, segments are split by its value.
+    pub partition_key: Option<String>,
     pub created_on: DateTime<Utc>,
     pub updated_on: DateTime<Utc>,
     pub comment: String,
@@ -237,6 +240,29 @@ pub struct TableMeta {
     // shared by share_id
     pub shared_by: BTreeSet<u64>,
     pub column_mask_policy: Option<BTreeMap<String, String>>,
+    pub row_access_policy: Option<TableRowAccessPolicy>,
+    // The inverted indexes of this table, keyed by index name.
+    pub indexes: BTreeMap<String, TableIndex>,
+}
+
+/// A row access policy attached to a table.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TableRowAccessPolicy {
+    /// Name of the policy.
+    pub policy: String,
+    /// The columns passed to the policy as arguments, in the order of its arguments.
+    pub columns: Vec<String>,
+}
+
+/// An inverted index defined on some string columns of a table.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TableIndex {
+    /// Name of the index, unique in the table.
+    pub name: String,
+    /// Ids of the indexed columns, they are kept across column renaming.
+    pub column_ids: Vec<ColumnId>,
+    /// Options of the index, such as `tokenizer`.
+    pub options: BTreeMap<String, String>,
 }
 
 impl TableMeta {
@@ -326,6 +352,7 @@ impl Default for TableMeta {
             default_cluster_key: None,
             cluster_keys: vec![],
             default_cluster_key_id: None,
+            partition_key: None,
             created_on: Utc::now(),
             updated_on: Utc::now(),
             comment: "".to_string(),
@@ -334,6 +361,8 @@ impl Default for TableMeta {
             statistics: Default::default(),
             shared_by: BTreeSet::new(),
             column_mask_policy: None,
+            row_access_policy: None,
+            indexes: BTreeMap::new(),
         }
     }
 }
@@ -625,6 +654,27 @@ pub struct SetTableColumnMaskPolicyReply {
     pub share_table_info: Option<Vec<ShareTableInfoMap>>,
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum SetTableRowAccessPolicyAction {
+    // new policy, old policy name(if any)
+    Set(TableRowAccessPolicy, Option<String>),
+    // prev policy name
+    Unset(String),
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SetTableRowAccessPolicyReq {
+    pub tenant: String,
+    pub table_id: u64,
+    pub seq: MatchSeq,
+    pub action: SetTableRowAccessPolicyAction,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SetTableRowAccessPolicyReply {
+    pub share_table_info: Option<Vec<ShareTableInfoMap>>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct UpsertTableOptionReply {
     pub share_table_info: Option<Vec<ShareTableInfoMap>>,
@@ -19,6 +19,7 @@ use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::ListKVReq;
 use common_meta_kvapi::kvapi::MGetKVReply;
 use common_meta_kvapi::kvapi::MGetKVReq;
+use common_meta_kvapi::kvapi::ReadConsistency;
 use common_meta_kvapi::kvapi::UpsertKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::MetaError;
@@ -37,25 +38,53 @@ impl kvapi::KVApi for ClientHandle {
     }
 
     async fn get_kv(&self, key: &str) -> Result<GetKVReply, Self::Error> {
-        let reply = self
-            .request(GetKVReq {
-                key: key.to_string(),
-            })
-            .await?;
+        let reply = self.request(GetKVReq::new(key)).await?;
         Ok(reply)
     }
 
     async fn mget_kv(&self, keys: &[String]) -> Result<MGetKVReply, Self::Error> {
         let keys = keys.to_vec();
-        let reply = self.request(MGetKVReq { keys }).await?;
+        let reply = self.request(MGetKVReq::new(keys)).await?;
         Ok(reply)
     }
 
     async fn prefix_list_kv(&self, prefix: &str) -> Result<ListKVReply, Self::Error> {
+        let reply = self.request(ListKVReq::new(prefix)).await?;
+        Ok(reply)
+    }
+
+    /// A read with a weaker consistency than [`ReadConsistency::Linearizable`]
+    /// can be served by the follower or learner this client connects to.
+    async fn get_kv_with_consistency(
+        &self,
+        key: &str,
+        consistency: ReadConsistency,
+    ) -> Result<GetKVReply, Self::Error> {
+        let reply = self
+            .request(GetKVReq::new(key).with_consistency(consistency))
+            .await?;
+        Ok(reply)
+    }
+
+    async fn mget_kv_with_consistency(
+        &self,
+        keys: &[String],
+        consistency: ReadConsistency,
+    ) -> Result<MGetKVReply, Self::Error> {
+        let keys = keys.to_vec();
+        let reply = self
+            .request(MGetKVReq::new(keys).with_consistency(consistency))
+            .await?;
+        Ok(reply)
+    }
+
+    async fn prefix_list_kv_with_consistency(
+        &self,
+        prefix: &str,
+        consistency: ReadConsistency,
+    ) -> Result<ListKVReply, Self::Error> {
         let reply = self
-            .request(ListKVReq {
-                prefix: prefix.to_string(),
-            })
+            .request(ListKVReq::new(prefix).with_consistency(consistency))
             .await?;
         Ok(reply)
     }
@@ -22,6 +22,7 @@ use crate::kvapi;
 use crate::kvapi::GetKVReply;
 use crate::kvapi::ListKVReply;
 use crate::kvapi::MGetKVReply;
+use crate::kvapi::ReadConsistency;
 use crate::kvapi::UpsertKVReply;
 use crate::kvapi::UpsertKVReq;
 
@@ -57,6 +58,35 @@ pub trait KVApi: Send + Sync {
     /// List key-value records that are starts with the specified prefix.
     async fn prefix_list_kv(&self, prefix: &str) -> Result<ListKVReply, Self::Error>;
 
+    /// Get a key-value record by key, read with the specified consistency.
+    ///
+    /// An implementation without followers to read from serves every read as a linearizable one.
+    async fn get_kv_with_consistency(
+        &self,
+        key: &str,
+        _consistency: ReadConsistency,
+    ) -> Result<GetKVReply, Self::Error> {
+        self.get_kv(key).await
+    }
+
+    /// Get several key-values by keys, read with the specified consistency.
+    async fn mget_kv_with_consistency(
+        &self,
+        keys: &[String],
+        _consistency: ReadConsistency,
+    ) -> Result<MGetKVReply, Self::Error> {
+        self.mget_kv(keys).await
+    }
+
+    /// List key-value records by prefix, read with the specified consistency.
+    async fn prefix_list_kv_with_consistency(
+        &self,
+        prefix: &str,
+        _consistency: ReadConsistency,
+    ) -> Result<ListKVReply, Self::Error> {
+        self.prefix_list_kv(prefix).await
+    }
+
     /// Run transaction: update one or more records if specified conditions are met.
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, Self::Error>;
 }
@@ -81,6 +111,34 @@ impl<U: kvapi::KVApi, T: Deref<Target = U> + Send + Sync> kvapi::KVApi for T {
         self.deref().prefix_list_kv(prefix).await
     }
 
+    async fn get_kv_with_consistency(
+        &self,
+        key: &str,
+        consistency: ReadConsistency,
+    ) -> Result<GetKVReply, Self::Error> {
+        self.deref().get_kv_with_consistency(key, consistency).await
+    }
+
+    async fn mget_kv_with_consistency(
+        &self,
+        keys: &[String],
+        consistency: ReadConsistency,
+    ) -> Result<MGetKVReply, Self::Error> {
+        self.deref()
+            .mget_kv_with_consistency(keys, consistency)
+            .await
+    }
+
+    async fn prefix_list_kv_with_consistency(
+        &self,
+        prefix: &str,
+        consistency: ReadConsistency,
+    ) -> Result<ListKVReply, Self::Error> {
+        self.deref()
+            .prefix_list_kv_with_consistency(prefix, consistency)
+            .await
+    }
+
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, Self::Error> {
         self.deref().transaction(txn).await
     }
@@ -18,19 +18,94 @@ use common_meta_types::UpsertKV;
 
 pub type UpsertKVReq = UpsertKV;
 
+/// Specifies how a read request is served by a meta-service node.
+///
+/// A read is served by the leader by default. The other levels allow a follower or a learner to
+/// serve it from its local state machine, to offload the leader.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReadConsistency {
+    /// Forward the read to the leader, which serves it from its state machine.
+    #[default]
+    Linearizable,
+
+    /// Serve the read on the receiving node, after it has applied all logs up to the read index.
+    ///
+    /// The read index is the `last_applied` of the leader, obtained after the leader confirmed it
+    /// is still the leader, either with a heartbeat round trip or with a valid leader lease.
+    /// Such a read is linearizable.
+    ReadIndex,
+
+    /// Serve the read on the receiving node, if it has caught up with a read index obtained no
+    /// earlier than `max_staleness_ms` ago.
+    ///
+    /// The result reflects every write that completed at least `max_staleness_ms` before the read.
+    BoundedStale { max_staleness_ms: u64 },
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct GetKVReq {
     pub key: String,
+
+    #[serde(default)]
+    pub consistency: ReadConsistency,
+}
+
+impl GetKVReq {
+    pub fn new(key: impl ToString) -> Self {
+        Self {
+            key: key.to_string(),
+            consistency: ReadConsistency::default(),
+        }
+    }
+
+    pub fn with_consistency(mut self, consistency: ReadConsistency) -> Self {
+        self.consistency = consistency;
+        self
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct MGetKVReq {
     pub keys: Vec<String>,
+
+    #[serde(default)]
+    pub consistency: ReadConsistency,
+}
+
+impl MGetKVReq {
+    pub fn new(keys: Vec<String>) -> Self {
+        Self {
+            keys,
+            consistency: ReadConsistency::default(),
+        }
+    }
+
+    pub fn with_consistency(mut self, consistency: ReadConsistency) -> Self {
+        self.consistency = consistency;
+        self
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct ListKVReq {
     pub prefix: String,
+
+    #[serde(default)]
+    pub consistency: ReadConsistency,
+}
+
+impl ListKVReq {
+    pub fn new(prefix: impl ToString) -> Self {
+        Self {
+            prefix: prefix.to_string(),
+            consistency: ReadConsistency::default(),
+        }
+    }
+
+    pub fn with_consistency(mut self, consistency: ReadConsistency) -> Self {
+        self.consistency = consistency;
+        self
+    }
 }
 
 pub type UpsertKVReply = Change<Vec<u8>>;
@@ -34,6 +34,7 @@ pub use message::ListKVReply;
 pub use message::ListKVReq;
 pub use message::MGetKVReply;
 pub use message::MGetKVReq;
+pub use message::ReadConsistency;
 pub use message::UpsertKVReply;
 pub use message::UpsertKVReq;
 pub use prefix::prefix_to_range;
@@ -144,13 +144,26 @@ impl FromToProto for mt::background::VacuumStats {
     fn from_pb(p: Self::PB) -> Result<Self, Incompatible>
     where Self: Sized {
         reader_check_msg(p.ver, p.min_reader_ver)?;
-        Ok(Self {})
+        Ok(Self {
+            db_id: p.db_id,
+            table_id: p.table_id,
+            data_retention_time_in_days: p.data_retention_time_in_days,
+            purged_snapshots: p.purged_snapshots,
+            total_vacuum_time: p
+                .total_vacuum_time_secs
+                .and_then(|t| Option::from(Duration::from_secs_f32(t))),
+        })
     }
 
     fn to_pb(&self) -> Result<Self::PB, Incompatible> {
         let p = pb::VacuumStats {
             ver: VER,
             min_reader_ver: MIN_READER_VER,
+            db_id: self.db_id,
+            table_id: self.table_id,
+            data_retention_time_in_days: self.data_retention_time_in_days,
+            purged_snapshots: self.purged_snapshots,
+            total_vacuum_time_secs: self.total_vacuum_time.map(|t| t.as_secs_f32()),
         };
         Ok(p)
     }
@@ -260,6 +260,10 @@ impl FromToProto for ex::TableDataType {
                         ex::TableDataType::Decimal(ex::types::decimal::DecimalDataType::from_pb(x)?)
                     }
                     Dt24::EmptyMapT(_) => ex::TableDataType::EmptyMap,
+                    Dt24::GeometryT(_) => ex::TableDataType::Geometry,
+                    Dt24::TimestampTzT(_) => ex::TableDataType::TimestampTz,
+                    Dt24::TimeT(_) => ex::TableDataType::Time,
+                    Dt24::BinaryT(_) => ex::TableDataType::Binary,
                 };
                 Ok(x)
             }
@@ -300,6 +304,10 @@ impl FromToProto for ex::TableDataType {
                 new_pb_dt24(Dt24::MapT(Box::new(x)))
             }
             TableDataType::Bitmap => new_pb_dt24(Dt24::BitmapT(pb::Empty {})),
+            TableDataType::Geometry => new_pb_dt24(Dt24::GeometryT(pb::Empty {})),
+            TableDataType::TimestampTz => new_pb_dt24(Dt24::TimestampTzT(pb::Empty {})),
+            TableDataType::Time => new_pb_dt24(Dt24::TimeT(pb::Empty {})),
+            TableDataType::Binary => new_pb_dt24(Dt24::BinaryT(pb::Empty {})),
             TableDataType::Tuple {
                 fields_name,
                 fields_type,
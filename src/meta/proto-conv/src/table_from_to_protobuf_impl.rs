@@ -200,6 +200,7 @@ impl FromToProto for mt::TableMeta {
             default_cluster_key: p.default_cluster_key,
             cluster_keys: p.cluster_keys,
             default_cluster_key_id: p.default_cluster_key_id,
+            partition_key: p.partition_key,
             created_on: DateTime::<Utc>::from_pb(p.created_on)?,
             updated_on: DateTime::<Utc>::from_pb(p.updated_on)?,
             drop_on: match p.drop_on {
@@ -219,6 +220,15 @@ impl FromToProto for mt::TableMeta {
             } else {
                 Some(p.column_mask_policy)
             },
+            row_access_policy: p.row_access_policy.map(|policy| mt::TableRowAccessPolicy {
+                policy,
+                columns: p.row_access_policy_columns,
+            }),
+            indexes: p
+                .indexes
+                .into_iter()
+                .map(|(name, index)| Ok((name, mt::TableIndex::from_pb(index)?)))
+                .collect::<Result<BTreeMap<_, _>, Incompatible>>()?,
         };
         Ok(v)
     }
@@ -244,6 +254,7 @@ impl FromToProto for mt::TableMeta {
             default_cluster_key: self.default_cluster_key.clone(),
             cluster_keys: self.cluster_keys.clone(),
             default_cluster_key_id: self.default_cluster_key_id,
+            partition_key: self.partition_key.clone(),
             created_on: self.created_on.to_pb()?,
             updated_on: self.updated_on.to_pb()?,
             drop_on: match self.drop_on {
@@ -255,6 +266,48 @@ impl FromToProto for mt::TableMeta {
             statistics: Some(self.statistics.to_pb()?),
             shared_by: Vec::from_iter(self.shared_by.clone().into_iter()),
             column_mask_policy: self.column_mask_policy.clone().unwrap_or(BTreeMap::new()),
+            row_access_policy: self
+                .row_access_policy
+                .as_ref()
+                .map(|policy| policy.policy.clone()),
+            row_access_policy_columns: self
+                .row_access_policy
+                .as_ref()
+                .map(|policy| policy.columns.clone())
+                .unwrap_or_default(),
+            indexes: self
+                .indexes
+                .iter()
+                .map(|(name, index)| Ok((name.clone(), index.to_pb()?)))
+                .collect::<Result<BTreeMap<_, _>, Incompatible>>()?,
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for mt::TableIndex {
+    type PB = pb::TableIndex;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TableIndex) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            name: p.name,
+            column_ids: p.column_ids,
+            options: p.options,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TableIndex, Incompatible> {
+        let p = pb::TableIndex {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            name: self.name.clone(),
+            column_ids: self.column_ids.clone(),
+            options: self.options.clone(),
         };
         Ok(p)
     }
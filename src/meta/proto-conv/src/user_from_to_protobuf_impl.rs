@@ -375,3 +375,59 @@ impl FromToProto for mt::principal::NetworkPolicy {
         })
     }
 }
+
+impl FromToProto for mt::principal::Tag {
+    type PB = pb::Tag;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::Tag) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        Ok(mt::principal::Tag {
+            name: p.name,
+            comment: p.comment,
+            create_on: DateTime::<Utc>::from_pb(p.create_on)?,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::Tag, Incompatible> {
+        Ok(pb::Tag {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            name: self.name.clone(),
+            comment: self.comment.clone(),
+            create_on: self.create_on.to_pb()?,
+        })
+    }
+}
+
+impl FromToProto for mt::principal::TagReference {
+    type PB = pb::TagReference;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TagReference) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        Ok(mt::principal::TagReference {
+            tag_name: p.tag_name,
+            tag_value: p.tag_value,
+            object_domain: p.object_domain,
+            object_name: p.object_name,
+            create_on: DateTime::<Utc>::from_pb(p.create_on)?,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::TagReference, Incompatible> {
+        Ok(pb::TagReference {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            tag_name: self.tag_name.clone(),
+            tag_value: self.tag_value.clone(),
+            object_domain: self.object_domain.clone(),
+            object_name: self.object_name.clone(),
+            create_on: self.create_on.to_pb()?,
+        })
+    }
+}
@@ -79,7 +79,16 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (47, "2023-07-03: Add: catalog.proto/CatalogMeta",),
     (48, "2023-07-04: Add: ManualTriggerParams on background_job", ),
     (49, "2023-07-14: Add: user.proto/NetworkPolicy", ),
-    (50, "2023-07-20: Add: user.proto/UserOption::network_policy", )
+    (50, "2023-07-20: Add: user.proto/UserOption::network_policy", ),
+    (51, "2023-07-24: Add: row_access_policy.proto and TableMeta::row_access_policy", ),
+    (52, "2023-07-26: Add: user.proto/Tag and user.proto/TagReference", ),
+    (53, "2023-07-28: Add: background.proto/VacuumStats fields", ),
+    (54, "2023-07-31: Add: table.proto/TableIndex and TableMeta::indexes", ),
+    (55, "2023-08-02: Add: table.proto/TableMeta::partition_key", ),
+    (56, "2023-08-04: Add: datatype.proto/DataType Geometry type", ),
+    (57, "2023-08-07: Add: datatype.proto/DataType TimestampTz type", ),
+    (58, "2023-08-09: Add: datatype.proto/DataType Time type", ),
+    (59, "2023-08-11: Add: datatype.proto/DataType Binary type", )
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
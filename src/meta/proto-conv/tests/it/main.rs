@@ -55,3 +55,12 @@ mod v047_catalog_meta;
 mod v048_background;
 mod v049_network_policy;
 mod v050_user_info;
+mod v051_row_access_policy;
+mod v052_tag;
+mod v053_vacuum_stats;
+mod v054_table_index;
+mod v055_table_meta_partition_key;
+mod v056_schema_geometry;
+mod v057_schema_timestamp_tz;
+mod v058_schema_time;
+mod v059_schema_binary;
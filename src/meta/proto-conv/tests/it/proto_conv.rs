@@ -195,6 +195,7 @@ fn new_table_meta() -> mt::TableMeta {
         default_cluster_key: Some("(a + 2, b)".to_string()),
         cluster_keys: vec!["(a + 2, b)".to_string()],
         default_cluster_key_id: Some(0),
+        partition_key: None,
         created_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
         updated_on: Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 10).unwrap(),
         comment: s("table_comment"),
@@ -203,6 +204,8 @@ fn new_table_meta() -> mt::TableMeta {
         statistics: Default::default(),
         shared_by: btreeset! {1},
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
+        row_access_policy: None,
+        indexes: BTreeMap::new(),
     }
 }
 
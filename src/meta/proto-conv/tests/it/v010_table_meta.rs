@@ -136,6 +136,7 @@ fn test_decode_v10_table_meta() -> anyhow::Result<()> {
         default_cluster_key: Some("(a + 2, b)".to_string()),
         cluster_keys: vec!["(a + 2, b)".to_string()],
         default_cluster_key_id: Some(0),
+        partition_key: None,
         created_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
         updated_on: Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 10).unwrap(),
         comment: s("table_comment"),
@@ -144,6 +145,8 @@ fn test_decode_v10_table_meta() -> anyhow::Result<()> {
         statistics: Default::default(),
         shared_by: BTreeSet::new(),
         column_mask_policy: None,
+        row_access_policy: None,
+        indexes: btreemap! {},
     };
 
     common::test_pb_from_to(func_name!(), want())?;
@@ -104,7 +104,7 @@ fn test_decode_v40_background_task_case_2() -> anyhow::Result<()> {
             }),
             total_compaction_time: Some(Duration::from_secs(100)),
         }),
-        vacuum_stats: Some(VacuumStats {}),
+        vacuum_stats: Some(VacuumStats::default()),
         manual_trigger: None,
         creator: Some(BackgroundJobIdent {
             tenant: "test1".to_string(),
@@ -82,7 +82,7 @@ fn test_decode_v48_background_task_case_2() -> anyhow::Result<()> {
             }),
             total_compaction_time: Some(Duration::from_secs(100)),
         }),
-        vacuum_stats: Some(VacuumStats {}),
+        vacuum_stats: Some(VacuumStats::default()),
         manual_trigger: Some(ManualTriggerParams {
             id: "1231".to_string(),
             trigger: Default::default(),
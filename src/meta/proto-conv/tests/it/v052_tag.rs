@@ -0,0 +1,66 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v52_tag() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 3, 112, 105, 105, 18, 13, 112, 101, 114, 115, 111, 110, 97, 108, 32, 100, 97, 116, 97,
+        26, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85,
+        84, 67, 160, 6, 52, 168, 6, 24,
+    ];
+
+    let want = || common_meta_app::principal::Tag {
+        name: "pii".to_string(),
+        comment: "personal data".to_string(),
+        create_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 52, want())
+}
+
+#[test]
+fn test_decode_v52_tag_reference() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 3, 112, 105, 105, 18, 5, 101, 109, 97, 105, 108, 26, 6, 67, 79, 76, 85, 77, 78, 34, 9,
+        100, 98, 49, 46, 116, 49, 46, 99, 49, 42, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32,
+        49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 160, 6, 52, 168, 6, 24,
+    ];
+
+    let want = || common_meta_app::principal::TagReference {
+        tag_name: "pii".to_string(),
+        tag_value: "email".to_string(),
+        object_domain: "COLUMN".to_string(),
+        object_name: "db1.t1.c1".to_string(),
+        create_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 52, want())
+}
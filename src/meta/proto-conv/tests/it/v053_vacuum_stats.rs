@@ -0,0 +1,47 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_meta_app::background::VacuumStats;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v53_vacuum_stats() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        8, 21, 16, 92, 24, 7, 32, 3, 45, 0, 0, 200, 66, 160, 6, 53, 168, 6, 24,
+    ];
+
+    let want = || VacuumStats {
+        db_id: 21,
+        table_id: 92,
+        data_retention_time_in_days: 7,
+        purged_snapshots: Some(3),
+        total_vacuum_time: Some(Duration::from_secs(100)),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 53, want())
+}
@@ -0,0 +1,45 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_meta_app::schema::TableIndex;
+use maplit::btreemap;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v54_table_index() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 4, 105, 100, 120, 49, 18, 2, 1, 2, 26, 20, 10, 9, 116, 111, 107, 101, 110, 105, 122,
+        101, 114, 18, 7, 99, 104, 105, 110, 101, 115, 101, 160, 6, 54, 168, 6, 24,
+    ];
+
+    let want = || TableIndex {
+        name: "idx1".to_string(),
+        column_ids: vec![1, 2],
+        options: btreemap! {"tokenizer".to_string() => "chinese".to_string()},
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 54, want())
+}
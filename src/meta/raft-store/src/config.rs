@@ -89,6 +89,11 @@ pub struct RaftConfig {
     /// The value is one or more addresses of a node in the cluster, to which this node sends a `join` request.
     pub join: Vec<String>,
 
+    /// Join the cluster as a learner, which replicates logs and serves reads but never votes.
+    ///
+    /// It will be ignored if `--join` is absent.
+    pub learner: bool,
+
     /// Do not run databend-meta, but just remove a node from its cluster.
     ///
     /// The value is one or more addresses of a node in the cluster, to which this node sends a `leave` request.
@@ -140,6 +145,7 @@ impl Default for RaftConfig {
             max_applied_log_to_keep: 1000,
             single: false,
             join: vec![],
+            learner: false,
             leave_via: vec![],
             leave_id: None,
             id: 0,
@@ -205,6 +211,16 @@ impl RaftConfig {
         (self.heartbeat_interval * 5, self.heartbeat_interval * 7)
     }
 
+    /// Returns the leader lease, in milli seconds.
+    ///
+    /// After a leader confirmed its leadership with a quorum, no other leader can be elected
+    /// before the election timeout expires on the followers.
+    /// The lease is shorter than the min election timeout by one heartbeat interval,
+    /// to tolerate clock drift between nodes.
+    pub fn leader_lease(&self) -> u64 {
+        self.election_timeout().0 - self.heartbeat_interval
+    }
+
     pub fn check(&self) -> std::result::Result<(), MetaStartupError> {
         // If just leaving, does not need to check other config
         if !self.leave_via.is_empty() {
@@ -220,6 +236,12 @@ impl RaftConfig {
             )));
         }
 
+        if self.learner && self.single {
+            return Err(MetaStartupError::InvalidConfig(String::from(
+                "`learner` can not be used with `single`",
+            )));
+        }
+
         let self_addr = self.raft_api_listen_host_string();
         if self.join.contains(&self_addr) {
             return Err(MetaStartupError::InvalidConfig(String::from(
@@ -22,7 +22,10 @@ use common_base::base::tokio::sync::mpsc;
 use common_grpc::GrpcClaim;
 use common_grpc::GrpcToken;
 use common_meta_client::MetaGrpcReq;
+use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::KVApi;
+use common_meta_kvapi::kvapi::ListKVReply;
+use common_meta_kvapi::kvapi::MGetKVReply;
 use common_meta_types::protobuf::meta_service_server::MetaService;
 use common_meta_types::protobuf::ClientInfo;
 use common_meta_types::protobuf::Empty;
@@ -174,15 +177,15 @@ impl MetaService for MetaServiceImpl {
                     RaftReply::from(res)
                 }
                 MetaGrpcReq::GetKV(a) => {
-                    let res = m.get_kv(&a.key).await;
+                    let res: Result<GetKVReply, _> = m.consistent_read(a).await;
                     RaftReply::from(res)
                 }
                 MetaGrpcReq::MGetKV(a) => {
-                    let res = m.mget_kv(&a.keys).await;
+                    let res: Result<MGetKVReply, _> = m.consistent_read(a).await;
                     RaftReply::from(res)
                 }
                 MetaGrpcReq::ListKV(a) => {
-                    let res = m.prefix_list_kv(&a.prefix).await;
+                    let res: Result<ListKVReply, _> = m.consistent_read(a).await;
                     RaftReply::from(res)
                 }
             };
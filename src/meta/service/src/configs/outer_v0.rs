@@ -19,6 +19,7 @@ use clap::Parser;
 use common_meta_raft_store::config::get_default_raft_advertise_host;
 use common_meta_raft_store::config::RaftConfig as InnerRaftConfig;
 use common_meta_types::MetaStartupError;
+use common_tracing::AuditLogConfig;
 use common_tracing::Config as InnerLogConfig;
 use common_tracing::FileConfig as InnerFileLogConfig;
 use common_tracing::QueryLogConfig;
@@ -287,6 +288,7 @@ pub struct ConfigViaEnv {
     pub raft_max_applied_log_to_keep: u64,
     pub kvsrv_single: bool,
     pub metasrv_join: Vec<String>,
+    pub metasrv_learner: bool,
     pub kvsrv_id: u64,
     pub sled_tree_prefix: String,
     pub cluster_name: String,
@@ -331,6 +333,7 @@ impl From<Config> for ConfigViaEnv {
             raft_max_applied_log_to_keep: cfg.raft_config.max_applied_log_to_keep,
             kvsrv_single: cfg.raft_config.single,
             metasrv_join: cfg.raft_config.join,
+            metasrv_learner: cfg.raft_config.learner,
             kvsrv_id: cfg.raft_config.id,
             sled_tree_prefix: cfg.raft_config.sled_tree_prefix,
             cluster_name: cfg.raft_config.cluster_name,
@@ -356,6 +359,7 @@ impl Into<Config> for ConfigViaEnv {
             max_applied_log_to_keep: self.raft_max_applied_log_to_keep,
             single: self.kvsrv_single,
             join: self.metasrv_join,
+            learner: self.metasrv_learner,
             // Do not allow to leave via environment variable
             leave_via: vec![],
             // Do not allow to leave via environment variable
@@ -471,6 +475,12 @@ pub struct RaftConfig {
     #[clap(long, multiple_occurrences = true, multiple_values = true)]
     pub join: Vec<String>,
 
+    /// Join the cluster as a learner, which replicates logs and serves reads but never votes.
+    ///
+    /// It will be ignored if `--join` is absent.
+    #[clap(long)]
+    pub learner: bool,
+
     /// Do not run databend-meta, but just remove a node from its cluster via the provided endpoints.
     ///
     /// This node will be removed by `id`.
@@ -522,6 +532,7 @@ impl From<RaftConfig> for InnerRaftConfig {
             max_applied_log_to_keep: x.max_applied_log_to_keep,
             single: x.single,
             join: x.join,
+            learner: x.learner,
             leave_via: x.leave_via,
             leave_id: x.leave_id,
             id: x.id,
@@ -547,6 +558,7 @@ impl From<InnerRaftConfig> for RaftConfig {
             max_applied_log_to_keep: inner.max_applied_log_to_keep,
             single: inner.single,
             join: inner.join,
+            learner: inner.learner,
             leave_via: inner.leave_via,
             leave_id: inner.leave_id,
             id: inner.id,
@@ -583,6 +595,7 @@ impl Into<InnerLogConfig> for LogConfig {
                 on: false,
                 dir: "".to_string(),
             },
+            audit: AuditLogConfig::default(),
             tracing: TracingConfig::from_env(),
         }
     }
@@ -18,6 +18,7 @@ use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::ListKVReq;
 use common_meta_kvapi::kvapi::MGetKVReply;
 use common_meta_kvapi::kvapi::MGetKVReq;
+use common_meta_kvapi::kvapi::ReadConsistency;
 use common_meta_types::protobuf::RaftRequest;
 use common_meta_types::AppliedState;
 use common_meta_types::Endpoint;
@@ -34,6 +35,10 @@ pub struct JoinRequest {
     pub grpc_api_addr: String,
 
     pub grpc_api_advertise_address: Option<String>,
+
+    /// Join as a learner, which replicates logs and serves reads but does not vote.
+    #[serde(default)]
+    pub as_learner: bool,
 }
 
 impl JoinRequest {
@@ -49,6 +54,11 @@ impl JoinRequest {
             ..Default::default()
         }
     }
+
+    pub fn with_learner(mut self, as_learner: bool) -> Self {
+        self.as_learner = as_learner;
+        self
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -77,6 +87,21 @@ pub enum ForwardRequestBody {
     GetKV(GetKVReq),
     MGetKV(MGetKVReq),
     ListKV(ListKVReq),
+
+    /// Ask the leader for a read index, i.e., the log index a follower has to apply before serving a read.
+    GetReadIndex,
+}
+
+impl ForwardRequestBody {
+    /// Returns the consistency level of a read request, or `None` if it is not a read request.
+    pub fn read_consistency(&self) -> Option<ReadConsistency> {
+        match self {
+            ForwardRequestBody::GetKV(req) => Some(req.consistency),
+            ForwardRequestBody::MGetKV(req) => Some(req.consistency),
+            ForwardRequestBody::ListKV(req) => Some(req.consistency),
+            _ => None,
+        }
+    }
 }
 
 /// A request that is forwarded from one raft node to another
@@ -109,6 +134,8 @@ pub enum ForwardResponse {
     GetKV(GetKVReply),
     MGetKV(MGetKVReply),
     ListKV(ListKVReply),
+
+    ReadIndex(u64),
 }
 
 impl tonic::IntoRequest<RaftRequest> for ForwardRequest {
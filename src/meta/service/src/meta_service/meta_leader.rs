@@ -13,8 +13,11 @@
 // limitations under the License.
 
 use std::collections::BTreeSet;
+use std::sync::Mutex;
+use std::time::Duration;
 
 use common_base::base::tokio::sync::RwLockReadGuard;
+use common_base::base::tokio::time::Instant;
 use common_meta_kvapi::kvapi::KVApi;
 use common_meta_raft_store::state_machine::StateMachine;
 use common_meta_sled_store::openraft::ChangeMembers;
@@ -44,6 +47,7 @@ use crate::message::ForwardResponse;
 use crate::message::JoinRequest;
 use crate::message::LeaveRequest;
 use crate::meta_service::raftmeta::MetaRaft;
+use crate::meta_service::read_lease::ReadLease;
 use crate::meta_service::MetaNode;
 use crate::metrics::server_metrics;
 use crate::metrics::ProposalPending;
@@ -56,6 +60,8 @@ use crate::store::RaftStore;
 pub struct MetaLeader<'a> {
     sto: &'a RaftStore,
     raft: &'a MetaRaft,
+    read_lease: &'a Mutex<ReadLease>,
+    leader_lease: Duration,
 }
 
 impl<'a> MetaLeader<'a> {
@@ -63,6 +69,8 @@ impl<'a> MetaLeader<'a> {
         MetaLeader {
             sto: &meta_node.sto,
             raft: &meta_node.raft,
+            read_lease: &meta_node.read_lease,
+            leader_lease: meta_node.leader_lease,
         }
     }
 
@@ -113,13 +121,61 @@ impl<'a> MetaLeader<'a> {
                     .map_err(|meta_err| MetaDataReadError::new("list_kv", "", &meta_err))?;
                 Ok(ForwardResponse::ListKV(res))
             }
+            ForwardRequestBody::GetReadIndex => {
+                let index = self.read_index().await?;
+                Ok(ForwardResponse::ReadIndex(index))
+            }
+        }
+    }
+
+    /// Returns the read index: the index of the last log applied by this leader.
+    ///
+    /// Every write that has been responded to a client is included in the read index.
+    /// Before returning, the leader confirms it is still the leader, by a heartbeat round trip to a
+    /// quorum, unless the leader lease obtained by a previous confirmation has not yet expired.
+    #[minitrace::trace]
+    pub async fn read_index(&self) -> Result<u64, MetaOperationError> {
+        let now = Instant::now();
+        let term = self.raft.metrics().borrow().current_term;
+
+        let lease_valid = {
+            let lease = self.read_lease.lock().unwrap();
+            lease.is_leader_lease_valid(term, now)
+        };
+
+        if !lease_valid {
+            self.raft.is_leader().await?;
+
+            let mut lease = self.read_lease.lock().unwrap();
+            lease.extend_leader_lease(term, now + self.leader_lease);
         }
+
+        // A newly elected leader may not yet have applied the logs committed by the previous
+        // leader. Wait until the blank log of its own term is applied.
+        let metrics = self
+            .raft
+            .wait(Some(self.leader_lease))
+            .metrics(
+                |m| {
+                    m.last_applied
+                        .map(|log_id| log_id.leader_id.term >= term)
+                        .unwrap_or(false)
+                },
+                "leader applied the log of its own term",
+            )
+            .await
+            .map_err(|e| MetaDataReadError::new("read_index", "wait for applied", &e))?;
+
+        let index = metrics.last_applied.map(|x| x.index).unwrap_or_default();
+
+        debug!("read_index: {} in term {}", index, term);
+        Ok(index)
     }
 
     /// Join a new node to the cluster.
     ///
     /// - Adds the node to cluster as a non-voter persistently and starts replication.
-    /// - Adds the node to membership to let it become a voter.
+    /// - Adds the node to membership to let it become a voter, unless it joins as a learner.
     ///
     /// If the node is already in cluster membership, it still returns Ok.
     #[minitrace::trace]
@@ -135,6 +191,10 @@ impl<'a> MetaLeader<'a> {
             return Ok(());
         }
 
+        if req.as_learner && membership.get_node(&node_id).is_some() {
+            return Ok(());
+        }
+
         let ent = LogEntry {
             txid: None,
             time_ms: None,
@@ -147,12 +207,13 @@ impl<'a> MetaLeader<'a> {
         };
         self.write(ent).await?;
 
-        self.raft
-            .change_membership(
-                ChangeMembers::AddVoters(btreemap! {node_id=>MembershipNode{}}),
-                false,
-            )
-            .await?;
+        let change = if req.as_learner {
+            ChangeMembers::AddNodes(btreemap! {node_id=>MembershipNode{}})
+        } else {
+            ChangeMembers::AddVoters(btreemap! {node_id=>MembershipNode{}})
+        };
+
+        self.raft.change_membership(change, false).await?;
         Ok(())
     }
 
@@ -178,9 +239,19 @@ impl<'a> MetaLeader<'a> {
         }
 
         // 1. Remove it from membership if needed.
-        self.raft
-            .change_membership(ChangeMembers::RemoveVoters(btreeset! {node_id}), false)
-            .await?;
+        let is_voter = {
+            let metrics = self.raft.metrics().borrow().clone();
+            let membership = metrics.membership_config.membership();
+            let voters = membership.voter_ids().collect::<BTreeSet<_>>();
+            voters.contains(&node_id)
+        };
+
+        let change = if is_voter {
+            ChangeMembers::RemoveVoters(btreeset! {node_id})
+        } else {
+            ChangeMembers::RemoveNodes(btreeset! {node_id})
+        };
+        self.raft.change_membership(change, false).await?;
 
         // 2. Remove node info
         let ent = LogEntry {
@@ -20,6 +20,7 @@ use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::ListKVReq;
 use common_meta_kvapi::kvapi::MGetKVReply;
 use common_meta_kvapi::kvapi::MGetKVReq;
+use common_meta_kvapi::kvapi::ReadConsistency;
 use common_meta_kvapi::kvapi::UpsertKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::AppliedState;
@@ -36,8 +37,7 @@ use crate::meta_service::MetaNode;
 /// Impl kvapi::KVApi for MetaNode.
 ///
 /// Write through raft-log.
-/// Read through local state machine, which may not be consistent.
-/// E.g. Read is not guaranteed to see a write.
+/// Read through the leader, i.e., with `ReadConsistency::Linearizable`, unless a weaker consistency is specified.
 #[async_trait]
 impl kvapi::KVApi for MetaNode {
     type Error = MetaAPIError;
@@ -61,33 +61,57 @@ impl kvapi::KVApi for MetaNode {
 
     #[minitrace::trace]
     async fn get_kv(&self, key: &str) -> Result<GetKVReply, Self::Error> {
-        let res = self
-            .consistent_read(GetKVReq {
-                key: key.to_string(),
-            })
-            .await?;
+        let res = self.consistent_read(GetKVReq::new(key)).await?;
 
         Ok(res)
     }
 
     #[minitrace::trace]
     async fn mget_kv(&self, keys: &[String]) -> Result<MGetKVReply, Self::Error> {
-        let res = self
-            .consistent_read(MGetKVReq {
-                keys: keys.to_vec(),
-            })
-            .await?;
+        let res = self.consistent_read(MGetKVReq::new(keys.to_vec())).await?;
 
         Ok(res)
     }
 
     #[minitrace::trace]
     async fn prefix_list_kv(&self, prefix: &str) -> Result<ListKVReply, Self::Error> {
-        let res = self
-            .consistent_read(ListKVReq {
-                prefix: prefix.to_string(),
-            })
-            .await?;
+        let res = self.consistent_read(ListKVReq::new(prefix)).await?;
+
+        Ok(res)
+    }
+
+    #[minitrace::trace]
+    async fn get_kv_with_consistency(
+        &self,
+        key: &str,
+        consistency: ReadConsistency,
+    ) -> Result<GetKVReply, Self::Error> {
+        let req = GetKVReq::new(key).with_consistency(consistency);
+        let res = self.consistent_read(req).await?;
+
+        Ok(res)
+    }
+
+    #[minitrace::trace]
+    async fn mget_kv_with_consistency(
+        &self,
+        keys: &[String],
+        consistency: ReadConsistency,
+    ) -> Result<MGetKVReply, Self::Error> {
+        let req = MGetKVReq::new(keys.to_vec()).with_consistency(consistency);
+        let res = self.consistent_read(req).await?;
+
+        Ok(res)
+    }
+
+    #[minitrace::trace]
+    async fn prefix_list_kv_with_consistency(
+        &self,
+        prefix: &str,
+        consistency: ReadConsistency,
+    ) -> Result<ListKVReply, Self::Error> {
+        let req = ListKVReq::new(prefix).with_consistency(consistency);
+        let res = self.consistent_read(req).await?;
 
         Ok(res)
     }
@@ -25,3 +25,4 @@ pub mod meta_leader;
 mod meta_node_kv_api_impl;
 pub mod meta_service_impl;
 pub mod raftmeta;
+pub mod read_lease;
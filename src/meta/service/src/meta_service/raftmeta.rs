@@ -18,6 +18,7 @@ use std::fmt::Debug;
 use std::net::Ipv4Addr;
 use std::sync::atomic::AtomicI32;
 use std::sync::Arc;
+use std::sync::Mutex as StdMutex;
 use std::time::Duration;
 
 use anyerror::AnyError;
@@ -31,6 +32,7 @@ use common_base::base::tokio::time::Instant;
 use common_grpc::ConnectionFactory;
 use common_grpc::DNSResolver;
 use common_meta_client::reply_to_api_result;
+use common_meta_kvapi::kvapi::ReadConsistency;
 use common_meta_raft_store::config::RaftConfig;
 use common_meta_raft_store::key_spaces::GenericKV;
 use common_meta_raft_store::ondisk::DataVersion;
@@ -56,6 +58,8 @@ use common_meta_types::LogEntry;
 use common_meta_types::LogId;
 use common_meta_types::MembershipNode;
 use common_meta_types::MetaAPIError;
+use common_meta_types::MetaDataError;
+use common_meta_types::MetaDataReadError;
 use common_meta_types::MetaError;
 use common_meta_types::MetaManagementError;
 use common_meta_types::MetaNetworkError;
@@ -88,6 +92,7 @@ use crate::message::JoinRequest;
 use crate::message::LeaveRequest;
 use crate::meta_service::errors::grpc_error_to_network_err;
 use crate::meta_service::meta_leader::MetaLeader;
+use crate::meta_service::read_lease::ReadLease;
 use crate::meta_service::RaftServiceImpl;
 use crate::metrics::server_metrics;
 use crate::network::Network;
@@ -166,6 +171,12 @@ pub struct MetaNode {
     pub running_rx: watch::Receiver<()>,
     pub join_handles: Mutex<Vec<JoinHandle<Result<(), AnyError>>>>,
     pub joined_tasks: AtomicI32,
+
+    /// The leader lease and the latest read index, for serving reads without the leader.
+    pub read_lease: StdMutex<ReadLease>,
+
+    /// For how long a leader assumes it is still the leader after confirming it with a quorum.
+    pub leader_lease: Duration,
 }
 
 impl Opened for MetaNode {
@@ -180,6 +191,7 @@ pub struct MetaNodeBuilder {
     sto: Option<RaftStore>,
     monitor_metrics: bool,
     endpoint: Option<Endpoint>,
+    leader_lease: Duration,
 }
 
 impl MetaNodeBuilder {
@@ -223,6 +235,8 @@ impl MetaNodeBuilder {
             running_rx: rx,
             join_handles: Mutex::new(Vec::new()),
             joined_tasks: AtomicI32::new(1),
+            read_lease: StdMutex::new(ReadLease::default()),
+            leader_lease: self.leader_lease,
         });
 
         if self.monitor_metrics {
@@ -283,6 +297,7 @@ impl MetaNode {
             sto: None,
             monitor_metrics: true,
             endpoint: None,
+            leader_lease: Duration::from_millis(config.leader_lease()),
         }
     }
 
@@ -636,7 +651,7 @@ impl MetaNode {
         // Try to join a cluster only when this node has no log.
         // Joining a node with log has risk messing up the data in this node and in the target cluster.
         let in_cluster = self
-            .is_in_cluster()
+            .is_in_cluster(conf.learner)
             .await
             .map_err(|e| MetaManagementError::Join(AnyError::new(&e)))?;
 
@@ -723,11 +738,14 @@ impl MetaNode {
 
         let req = ForwardRequest {
             forward_to_leader: 1,
-            body: ForwardRequestBody::Join(JoinRequest::new(
-                conf.id,
-                advertise_endpoint.clone(),
-                grpc_api_advertise_address.clone(),
-            )),
+            body: ForwardRequestBody::Join(
+                JoinRequest::new(
+                    conf.id,
+                    advertise_endpoint.clone(),
+                    grpc_api_advertise_address.clone(),
+                )
+                .with_learner(conf.learner),
+            ),
         };
 
         let join_res = raft_client.forward(req.clone()).await;
@@ -759,6 +777,8 @@ impl MetaNode {
 
     /// Check meta-node state to see if it's appropriate to join to a cluster.
     ///
+    /// A node joining as a learner is in the cluster if it is a member of the membership, not necessarily a voter.
+    ///
     /// If there is no StorageError, it returns a `Result`: `Ok` indicates this node is already in a cluster.
     /// `Err` explains the reason why it is not in cluster.
     ///
@@ -780,7 +800,10 @@ impl MetaNode {
     ///   Then the next leader does not know about this new node.
     ///
     ///   Only when the membership is committed, this node can be sure it is in a cluster.
-    async fn is_in_cluster(&self) -> Result<Result<String, String>, MetaStorageError> {
+    async fn is_in_cluster(
+        &self,
+        as_learner: bool,
+    ) -> Result<Result<String, String>, MetaStorageError> {
         let m = {
             let sm = self.sto.get_state_machine().await;
             sm.get_membership()?
@@ -800,6 +823,13 @@ impl MetaNode {
             return Ok(Ok(format!("node {} already in cluster", self.sto.id)));
         }
 
+        if as_learner && membership.membership().get_node(&self.sto.id).is_some() {
+            return Ok(Ok(format!(
+                "node {} already in cluster as learner",
+                self.sto.id
+            )));
+        }
+
         Ok(Err(format!(
             "node {} has membership but not in it",
             self.sto.id
@@ -964,6 +994,13 @@ impl MetaNode {
         Ok(endpoints)
     }
 
+    /// Serve a read request at the consistency level specified by the request.
+    ///
+    /// - A linearizable read is forwarded to and served by the leader.
+    /// - Other reads are served by the local state machine,
+    ///   after this node has applied logs up to a read index obtained from the leader.
+    ///   If the leader is of an older version that does not serve read index,
+    ///   e.g., during a rolling upgrade, the read is forwarded to the leader instead.
     #[minitrace::trace]
     pub async fn consistent_read<Request, Reply>(&self, req: Request) -> Result<Reply, MetaAPIError>
     where
@@ -971,12 +1008,35 @@ impl MetaNode {
         ForwardResponse: TryInto<Reply>,
         <ForwardResponse as TryInto<Reply>>::Error: std::fmt::Display,
     {
-        let res = self
-            .handle_forwardable_request(ForwardRequest {
-                forward_to_leader: 1,
-                body: req.into(),
-            })
-            .await;
+        let body: ForwardRequestBody = req.into();
+
+        let read_index = match body.read_consistency().unwrap_or_default() {
+            ReadConsistency::Linearizable => None,
+            ReadConsistency::ReadIndex => Some(self.get_read_index().await),
+            ReadConsistency::BoundedStale { max_staleness_ms } => {
+                let cached = {
+                    let lease = self.read_lease.lock().unwrap();
+                    lease.fresh_read_index(Instant::now(), Duration::from_millis(max_staleness_ms))
+                };
+
+                match cached {
+                    Some(index) => Some(Ok(index)),
+                    None => Some(self.get_read_index().await),
+                }
+            }
+        };
+
+        let res = match read_index {
+            Some(Ok(index)) => self.local_read_at(index, body).await,
+            Some(Err(e)) if !Self::is_read_index_unsupported(&e) => Err(e),
+            _ => {
+                self.handle_forwardable_request(ForwardRequest {
+                    forward_to_leader: 1,
+                    body,
+                })
+                .await
+            }
+        };
 
         match res {
             Err(e) => {
@@ -1057,6 +1117,11 @@ impl MetaNode {
             };
 
             match forward_err {
+                // The target can not decode the request, e.g., it is of an older version.
+                // Retrying does not help.
+                ForwardRPCError::NetworkError(MetaNetworkError::InvalidArgument(_)) => {
+                    return Err(MetaAPIError::from(forward_err));
+                }
                 ForwardRPCError::NetworkError(ref net_err) => {
                     warn!(
                         "{} retries left, sleep time: {:?}; forward_to {} failed: {}",
@@ -1080,6 +1145,99 @@ impl MetaNode {
         }
     }
 
+    /// Get a read index from the leader, and record it for serving bounded-stale reads.
+    ///
+    /// If this node is the leader, the read index is built locally.
+    #[minitrace::trace]
+    pub async fn get_read_index(&self) -> Result<u64, MetaAPIError> {
+        let requested_at = Instant::now();
+
+        let res = self
+            .handle_forwardable_request(ForwardRequest {
+                forward_to_leader: 1,
+                body: ForwardRequestBody::GetReadIndex,
+            })
+            .await?;
+
+        let index: u64 = res.try_into().map_err(|e| {
+            let invalid_reply =
+                InvalidReply::new("expect reply type to be ReadIndex", &AnyError::error(e));
+            MetaNetworkError::from(invalid_reply)
+        })?;
+
+        {
+            let mut lease = self.read_lease.lock().unwrap();
+            lease.update_read_index(index, requested_at);
+        }
+
+        Ok(index)
+    }
+
+    /// Returns true if the leader failed to decode a `GetReadIndex` request,
+    /// i.e., the leader is of a version that does not serve read index.
+    fn is_read_index_unsupported(err: &MetaAPIError) -> bool {
+        matches!(
+            err,
+            MetaAPIError::NetworkError(MetaNetworkError::InvalidArgument(_))
+        )
+    }
+
+    /// Serve a read request with the local state machine, after logs up to `read_index` are applied.
+    #[minitrace::trace]
+    async fn local_read_at(
+        &self,
+        read_index: u64,
+        body: ForwardRequestBody,
+    ) -> Result<ForwardResponse, MetaAPIError> {
+        let timeout = Duration::from_millis(2_000);
+
+        self.raft
+            .wait(Some(timeout))
+            .metrics(
+                |m| m.last_applied.map(|x| x.index).unwrap_or_default() >= read_index,
+                format!("applied up to read index {}", read_index),
+            )
+            .await
+            .map_err(|e| {
+                MetaDataError::ReadError(MetaDataReadError::new(
+                    "local_read",
+                    format!("wait for applied index {}", read_index),
+                    &e,
+                ))
+            })?;
+
+        let read_err = |action: &str, e: MetaStorageError| {
+            MetaAPIError::from(MetaDataError::ReadError(MetaDataReadError::new(
+                action, "", &e,
+            )))
+        };
+
+        let sm = self.sto.state_machine.read().await;
+
+        let res = match body {
+            ForwardRequestBody::GetKV(req) => ForwardResponse::GetKV(
+                sm.get_kv(&req.key)
+                    .await
+                    .map_err(|e| read_err("get_kv", e))?,
+            ),
+            ForwardRequestBody::MGetKV(req) => ForwardResponse::MGetKV(
+                sm.mget_kv(&req.keys)
+                    .await
+                    .map_err(|e| read_err("mget_kv", e))?,
+            ),
+            ForwardRequestBody::ListKV(req) => ForwardResponse::ListKV(
+                sm.prefix_list_kv(&req.prefix)
+                    .await
+                    .map_err(|e| read_err("list_kv", e))?,
+            ),
+            _ => {
+                unreachable!("expect a read request, got: {:?}", body)
+            }
+        };
+
+        Ok(res)
+    }
+
     /// Return a MetaLeader if `self` believes it is the leader.
     ///
     /// Otherwise it returns the leader in a ForwardToLeader error.
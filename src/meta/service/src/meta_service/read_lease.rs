@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_base::base::tokio::time::Instant;
+
+/// The state for serving reads without going through the raft log.
+///
+/// - On a leader, it records the lease during which the leader does not need to confirm its
+///   leadership with a quorum before returning a read index.
+///
+/// - On every node, it records the latest read index obtained from the leader,
+///   which is used to serve bounded-stale reads.
+#[derive(Debug, Default)]
+pub struct ReadLease {
+    /// The term in which the leadership is confirmed, and the time until which it is valid.
+    leader_lease: Option<(u64, Instant)>,
+
+    /// The latest read index and the time when the leader was asked for it.
+    read_index: Option<(u64, Instant)>,
+}
+
+impl ReadLease {
+    /// Returns true if the leadership in `term` was confirmed and the lease has not yet expired.
+    pub fn is_leader_lease_valid(&self, term: u64, now: Instant) -> bool {
+        match self.leader_lease {
+            Some((t, until)) => t == term && now < until,
+            None => false,
+        }
+    }
+
+    /// Extend the leader lease of `term` to `until`.
+    ///
+    /// A lease of a different term is replaced.
+    pub fn extend_leader_lease(&mut self, term: u64, until: Instant) {
+        match &mut self.leader_lease {
+            Some((t, u)) if *t == term => {
+                if until > *u {
+                    *u = until;
+                }
+            }
+            _ => {
+                self.leader_lease = Some((term, until));
+            }
+        }
+    }
+
+    /// Record a read index that was obtained by a request sent at `requested_at`.
+    ///
+    /// Only the most recently requested one is kept.
+    pub fn update_read_index(&mut self, index: u64, requested_at: Instant) {
+        match self.read_index {
+            Some((_, t)) if t >= requested_at => {}
+            _ => {
+                self.read_index = Some((index, requested_at));
+            }
+        }
+    }
+
+    /// Returns the latest read index if it was requested no more than `max_staleness` before `now`.
+    pub fn fresh_read_index(&self, now: Instant, max_staleness: Duration) -> Option<u64> {
+        match self.read_index {
+            Some((index, t)) if now.saturating_duration_since(t) <= max_staleness => Some(index),
+            _ => None,
+        }
+    }
+}
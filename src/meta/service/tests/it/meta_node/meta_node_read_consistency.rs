@@ -0,0 +1,108 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_meta_kvapi::kvapi::GetKVReply;
+use common_meta_kvapi::kvapi::GetKVReq;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_kvapi::kvapi::ListKVReply;
+use common_meta_kvapi::kvapi::ListKVReq;
+use common_meta_kvapi::kvapi::ReadConsistency;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_meta::meta_service::meta_leader::MetaLeader;
+use log::info;
+use maplit::btreeset;
+use test_harness::test;
+
+use crate::testing::meta_service_test_harness;
+use crate::tests::meta_node::start_meta_node_cluster;
+
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_meta_node_read_on_follower_and_learner() -> anyhow::Result<()> {
+    // - Start a leader, 2 followers and a learner;
+    // - Write to the leader.
+    // - Read on every node with read-index and bounded-stale consistency, expect to see the write.
+
+    let (_log_index, tcs) = start_meta_node_cluster(btreeset![0, 1, 2], btreeset![3]).await?;
+    let all = tcs.iter().map(|tc| tc.meta_node()).collect::<Vec<_>>();
+
+    let leader = all[0].clone();
+
+    for i in 0..3 {
+        let key = format!("read-consistency-{}", i);
+
+        info!("--- write {} to leader", key);
+        leader
+            .upsert_kv(UpsertKVReq::update(&key, key.as_bytes()))
+            .await?;
+
+        for consistency in [ReadConsistency::ReadIndex, ReadConsistency::BoundedStale {
+            max_staleness_ms: 0,
+        }] {
+            for mn in all.iter() {
+                info!(
+                    "--- read {} on node {} with {:?}",
+                    key, mn.sto.id, consistency
+                );
+
+                let got: GetKVReply = mn
+                    .consistent_read(GetKVReq::new(&key).with_consistency(consistency))
+                    .await?;
+                assert_eq!(Some(key.as_bytes().to_vec()), got.map(|x| x.data));
+
+                let got: ListKVReply = mn
+                    .consistent_read(
+                        ListKVReq::new("read-consistency-").with_consistency(consistency),
+                    )
+                    .await?;
+                assert_eq!(i + 1, got.len());
+
+                let got = mn.get_kv_with_consistency(&key, consistency).await?;
+                assert_eq!(Some(key.as_bytes().to_vec()), got.map(|x| x.data));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_meta_node_read_index_leader_lease() -> anyhow::Result<()> {
+    // - Start a leader and 2 followers.
+    // - Get read index twice on the leader, the second one is served within the leader lease.
+    // - Get read index on a non-leader, expect ForwardToLeader error.
+
+    let (_log_index, tcs) = start_meta_node_cluster(btreeset![0, 1, 2], btreeset![]).await?;
+    let all = tcs.iter().map(|tc| tc.meta_node()).collect::<Vec<_>>();
+
+    let leader = MetaLeader::new(&all[0]);
+
+    let index1 = leader.read_index().await?;
+    let index2 = leader.read_index().await?;
+    assert_eq!(index1, index2);
+
+    let applied = all[0].raft.metrics().borrow().last_applied;
+    assert_eq!(applied.map(|x| x.index), Some(index1));
+
+    let follower = MetaLeader::new(&all[1]);
+    let res = follower.read_index().await;
+    assert!(res.is_err(), "a follower can not build a read index");
+
+    // A follower gets the read index from the leader.
+    let index3 = all[1].get_read_index().await?;
+    assert_eq!(index1, index3);
+
+    Ok(())
+}
@@ -27,6 +27,7 @@ use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::MGetKVReply;
+use common_meta_kvapi::kvapi::ReadConsistency;
 use common_meta_kvapi::kvapi::UpsertKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::protobuf::WatchRequest;
@@ -118,6 +119,39 @@ impl kvapi::KVApi for MetaStore {
         }
     }
 
+    async fn get_kv_with_consistency(
+        &self,
+        key: &str,
+        consistency: ReadConsistency,
+    ) -> Result<GetKVReply, MetaError> {
+        match self {
+            MetaStore::L(x) => x.get_kv_with_consistency(key, consistency).await,
+            MetaStore::R(x) => x.get_kv_with_consistency(key, consistency).await,
+        }
+    }
+
+    async fn mget_kv_with_consistency(
+        &self,
+        keys: &[String],
+        consistency: ReadConsistency,
+    ) -> Result<MGetKVReply, MetaError> {
+        match self {
+            MetaStore::L(x) => x.mget_kv_with_consistency(keys, consistency).await,
+            MetaStore::R(x) => x.mget_kv_with_consistency(keys, consistency).await,
+        }
+    }
+
+    async fn prefix_list_kv_with_consistency(
+        &self,
+        prefix: &str,
+        consistency: ReadConsistency,
+    ) -> Result<ListKVReply, MetaError> {
+        match self {
+            MetaStore::L(x) => x.prefix_list_kv_with_consistency(prefix, consistency).await,
+            MetaStore::R(x) => x.prefix_list_kv_with_consistency(prefix, consistency).await,
+        }
+    }
+
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, MetaError> {
         match self {
             MetaStore::L(x) => x.transaction(txn).await,
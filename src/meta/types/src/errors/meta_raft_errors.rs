@@ -19,6 +19,7 @@ pub use openraft::error::InitializeError;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::raft_types::CheckIsLeaderError;
 use crate::raft_types::ClientWriteError;
 use crate::raft_types::ForwardToLeader;
 use crate::MetaDataError;
@@ -84,3 +85,21 @@ impl From<RaftError<ClientWriteError>> for MetaOperationError {
         }
     }
 }
+
+impl From<RaftError<CheckIsLeaderError>> for MetaOperationError {
+    fn from(e: RaftError<CheckIsLeaderError>) -> Self {
+        match e {
+            RaftError::APIError(check_err) => match check_err {
+                CheckIsLeaderError::ForwardToLeader(to_leader) => to_leader.into(),
+                // The leadership can not be confirmed by a quorum,
+                // the caller should retry after a new leader is established.
+                CheckIsLeaderError::QuorumNotEnough(_) => ForwardToLeader {
+                    leader_id: None,
+                    leader_node: None,
+                }
+                .into(),
+            },
+            RaftError::Fatal(f) => Self::DataError(MetaDataError::WriteError(f)),
+        }
+    }
+}
@@ -108,6 +108,7 @@ pub use crate::raft_types::new_log_id;
 pub use crate::raft_types::AppendEntriesRequest;
 pub use crate::raft_types::AppendEntriesResponse;
 pub use crate::raft_types::ChangeMembershipError;
+pub use crate::raft_types::CheckIsLeaderError;
 pub use crate::raft_types::ClientWriteError;
 pub use crate::raft_types::CommittedLeaderId;
 pub use crate::raft_types::Entry;
@@ -62,6 +62,7 @@ pub type ForwardToLeader = openraft::error::ForwardToLeader<NodeId, MembershipNo
 pub type Fatal = openraft::error::Fatal<NodeId>;
 pub type ChangeMembershipError = openraft::error::ChangeMembershipError<NodeId>;
 pub type ClientWriteError = openraft::error::ClientWriteError<NodeId, MembershipNode>;
+pub type CheckIsLeaderError = openraft::error::CheckIsLeaderError<NodeId, MembershipNode>;
 pub type InitializeError = openraft::error::InitializeError<NodeId, MembershipNode>;
 
 pub type AppendEntriesRequest = openraft::raft::AppendEntriesRequest<TypeConfig>;
@@ -137,6 +137,12 @@ pub enum Expr {
         expr: Box<Expr>,
         target_type: TypeName,
     },
+    /// `<expr> AT TIME ZONE <expr>`
+    AtTimeZone {
+        span: Span,
+        expr: Box<Expr>,
+        time_zone: Box<Expr>,
+    },
     /// EXTRACT(IntervalKind FROM <expr>)
     Extract {
         span: Span,
@@ -179,7 +185,7 @@ pub enum Expr {
         name: Identifier,
         args: Vec<Expr>,
         params: Vec<Literal>,
-        window: Option<Window>,
+        window: Option<WindowDesc>,
         lambda: Option<Lambda>,
     },
     /// `CASE ... WHEN ... ELSE ...` expression
@@ -295,7 +301,10 @@ pub enum TypeName {
     },
     Date,
     Timestamp,
+    TimestampTz,
+    Time,
     String,
+    Binary,
     Array(Box<TypeName>),
     Map {
         key_type: Box<TypeName>,
@@ -307,6 +316,7 @@ pub enum TypeName {
         fields_type: Vec<TypeName>,
     },
     Variant,
+    Geometry,
     Nullable(Box<TypeName>),
 }
 
@@ -327,6 +337,14 @@ pub enum TrimWhere {
     Trailing,
 }
 
+/// The window of a window function call, with its optional
+/// `IGNORE NULLS` / `RESPECT NULLS` null treatment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowDesc {
+    pub ignore_nulls: Option<bool>,
+    pub window: Window,
+}
+
 #[derive(Debug, Clone, PartialEq, EnumAsInner)]
 pub enum Window {
     WindowReference(WindowRef),
@@ -419,6 +437,10 @@ pub enum BinaryOperator {
     BitwiseXor,
     BitwiseShiftLeft,
     BitwiseShiftRight,
+    // `@>` operator
+    JsonContains,
+    // `?` operator
+    JsonKeyExists,
 }
 
 impl BinaryOperator {
@@ -445,6 +467,8 @@ impl BinaryOperator {
             BinaryOperator::BitwiseShiftLeft => "bit_shift_left".to_string(),
             BinaryOperator::BitwiseShiftRight => "bit_shift_right".to_string(),
             BinaryOperator::Caret => "pow".to_string(),
+            BinaryOperator::JsonContains => "json_contains".to_string(),
+            BinaryOperator::JsonKeyExists => "json_exists_key".to_string(),
             _ => {
                 let name = format!("{:?}", self);
                 name.to_lowercase()
@@ -492,6 +516,7 @@ impl Expr {
             | Expr::UnaryOp { span, .. }
             | Expr::Cast { span, .. }
             | Expr::TryCast { span, .. }
+            | Expr::AtTimeZone { span, .. }
             | Expr::Extract { span, .. }
             | Expr::Position { span, .. }
             | Expr::Substring { span, .. }
@@ -664,6 +689,12 @@ impl Display for BinaryOperator {
             BinaryOperator::BitwiseShiftRight => {
                 write!(f, ">>")
             }
+            BinaryOperator::JsonContains => {
+                write!(f, "@>")
+            }
+            BinaryOperator::JsonKeyExists => {
+                write!(f, "?")
+            }
         }
     }
 }
@@ -713,6 +744,15 @@ impl Display for TypeName {
             TypeName::Timestamp => {
                 write!(f, "TIMESTAMP")?;
             }
+            TypeName::TimestampTz => {
+                write!(f, "TIMESTAMP_TZ")?;
+            }
+            TypeName::Time => {
+                write!(f, "TIME")?;
+            }
+            TypeName::Binary => {
+                write!(f, "BINARY")?;
+            }
             TypeName::String => {
                 write!(f, "STRING")?;
             }
@@ -756,6 +796,9 @@ impl Display for TypeName {
             TypeName::Variant => {
                 write!(f, "VARIANT")?;
             }
+            TypeName::Geometry => {
+                write!(f, "GEOMETRY")?;
+            }
             TypeName::Nullable(ty) => {
                 write!(f, "{} NULL", ty)?;
             }
@@ -812,6 +855,17 @@ impl Display for WindowDefinition {
     }
 }
 
+impl Display for WindowDesc {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.ignore_nulls {
+            Some(true) => write!(f, "IGNORE NULLS ")?,
+            Some(false) => write!(f, "RESPECT NULLS ")?,
+            None => {}
+        }
+        write!(f, "OVER ({})", self.window)
+    }
+}
+
 impl Display for Window {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let window_fmt = match *self {
@@ -1009,6 +1063,11 @@ impl Display for Expr {
             } => {
                 write!(f, "TRY_CAST({expr} AS {target_type})")?;
             }
+            Expr::AtTimeZone {
+                expr, time_zone, ..
+            } => {
+                write!(f, "{expr} AT TIME ZONE {time_zone}")?;
+            }
             Expr::Extract {
                 kind: field, expr, ..
             } => {
@@ -1085,7 +1144,7 @@ impl Display for Expr {
                 write!(f, ")")?;
 
                 if let Some(window) = window {
-                    write!(f, " OVER ({window})")?;
+                    write!(f, " {window}")?;
                 }
             }
             Expr::Case {
@@ -331,6 +331,18 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_at_time_zone(&mut self, _span: Span, expr: &'ast Expr, time_zone: &'ast Expr) {
+        self.visit_expr(expr);
+        let expr_child = self.children.pop().unwrap();
+        self.visit_expr(time_zone);
+        let time_zone_child = self.children.pop().unwrap();
+
+        let name = "Function AtTimeZone".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![expr_child, time_zone_child]);
+        self.children.push(node);
+    }
+
     fn visit_extract(&mut self, _span: Span, kind: &'ast IntervalKind, expr: &'ast Expr) {
         self.visit_expr(expr);
         let expr_child = self.children.pop().unwrap();
@@ -430,7 +442,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         name: &'ast Identifier,
         args: &'ast [Expr],
         _params: &'ast [Literal],
-        _over: &'ast Option<Window>,
+        _over: &'ast Option<WindowDesc>,
         _lambda: &'ast Option<Lambda>,
     ) {
         let mut children = Vec::with_capacity(args.len());
@@ -1122,6 +1134,14 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterDatabaseAction::SetOptions { set_options } => {
+                let mut action_name = "Action Set Option: ".to_string();
+                for (key, value) in set_options.iter() {
+                    action_name.push_str(format!("{key} to {value}").as_str());
+                }
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
         };
 
         let name = "AlterDatabase".to_string();
@@ -1258,6 +1278,19 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 FormatTreeNode::with_children(cluster_by_format_ctx, cluster_by_children);
             children.push(cluster_by_node);
         }
+        if !stmt.partition_by.is_empty() {
+            let mut partition_by_children = Vec::with_capacity(stmt.partition_by.len());
+            for partition_by in stmt.partition_by.iter() {
+                self.visit_expr(partition_by);
+                partition_by_children.push(self.children.pop().unwrap());
+            }
+            let partition_by_name = "PartitionByList".to_string();
+            let partition_by_format_ctx =
+                AstFormatContext::with_children(partition_by_name, partition_by_children.len());
+            let partition_by_node =
+                FormatTreeNode::with_children(partition_by_format_ctx, partition_by_children);
+            children.push(partition_by_node);
+        }
         if !stmt.table_options.is_empty() {
             let mut table_options_children = Vec::with_capacity(stmt.table_options.len());
             for (k, v) in stmt.table_options.iter() {
@@ -1455,6 +1488,46 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                let action_name = format!(
+                    "Action AddRowAccessPolicy {} on {}",
+                    policy,
+                    columns
+                        .iter()
+                        .map(|column| column.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => {
+                let action_name = format!("Action DropRowAccessPolicy {}", policy);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::DropPartition { values } => {
+                let mut value_children = Vec::with_capacity(values.len());
+                for value in values.iter() {
+                    self.visit_expr(value);
+                    value_children.push(self.children.pop().unwrap());
+                }
+                let action_name = "Action DropPartition".to_string();
+                let action_format_ctx =
+                    AstFormatContext::with_children(action_name, value_children.len());
+                FormatTreeNode::with_children(action_format_ctx, value_children)
+            }
+            AlterTableAction::TruncatePartition { values } => {
+                let mut value_children = Vec::with_capacity(values.len());
+                for value in values.iter() {
+                    self.visit_expr(value);
+                    value_children.push(self.children.pop().unwrap());
+                }
+                let action_name = "Action TruncatePartition".to_string();
+                let action_format_ctx =
+                    AstFormatContext::with_children(action_name, value_children.len());
+                FormatTreeNode::with_children(action_format_ctx, value_children)
+            }
         };
 
         let name = "AlterTable".to_string();
@@ -1627,6 +1700,60 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_ngram_index(&mut self, stmt: &'ast CreateNgramIndexStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let mut columns_children = Vec::with_capacity(stmt.columns.len());
+        for column in stmt.columns.iter() {
+            self.visit_identifier(column);
+            columns_children.push(self.children.pop().unwrap());
+        }
+        let columns_name = "Columns".to_string();
+        let columns_ctx = AstFormatContext::with_children(columns_name, columns_children.len());
+        let columns_child = FormatTreeNode::with_children(columns_ctx, columns_children);
+        let children = vec![table_child, columns_child];
+
+        let name = "CreateNgramIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_create_inverted_index(&mut self, stmt: &'ast CreateInvertedIndexStmt) {
+        self.visit_identifier(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let mut columns_children = Vec::with_capacity(stmt.columns.len());
+        for column in stmt.columns.iter() {
+            self.visit_identifier(column);
+            columns_children.push(self.children.pop().unwrap());
+        }
+        let columns_name = "Columns".to_string();
+        let columns_ctx = AstFormatContext::with_children(columns_name, columns_children.len());
+        let columns_child = FormatTreeNode::with_children(columns_ctx, columns_children);
+        let children = vec![index_child, table_child, columns_child];
+
+        let name = "CreateInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 3);
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_inverted_index(&mut self, stmt: &'ast DropInvertedIndexStmt) {
+        self.visit_identifier(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "DropInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![index_child, table_child]);
+        self.children.push(node);
+    }
+
     fn visit_create_virtual_columns(&mut self, stmt: &'ast CreateVirtualColumnsStmt) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
         let table_child = self.children.pop().unwrap();
@@ -2298,6 +2425,80 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_row_access_policy(&mut self, stmt: &'ast CreateRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_row_access_policy(&mut self, stmt: &'ast DropRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_desc_row_access_policy(&mut self, stmt: &'ast DescRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DescRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_tag(&mut self, stmt: &'ast CreateTagStmt) {
+        let ctx = AstFormatContext::new(format!("TagName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateTag".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_tag(&mut self, stmt: &'ast DropTagStmt) {
+        let ctx = AstFormatContext::new(format!("TagName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropTag".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_alter_object_tag(&mut self, stmt: &'ast AlterObjectTagStmt) {
+        let object_ctx = AstFormatContext::new(format!("Object {}", stmt.object));
+        let object_child = FormatTreeNode::new(object_ctx);
+        let action_ctx = AstFormatContext::new(format!("Action {}", stmt.action));
+        let action_child = FormatTreeNode::new(action_ctx);
+
+        let name = "AlterObjectTag".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![object_child, action_child]);
+        self.children.push(node);
+    }
+
+    fn visit_comment_on(&mut self, stmt: &'ast CommentOnStmt) {
+        let object_ctx = AstFormatContext::new(format!("Object {}", stmt.object));
+        let object_child = FormatTreeNode::new(object_ctx);
+        let comment_ctx = AstFormatContext::new(format!("Comment '{}'", stmt.comment));
+        let comment_child = FormatTreeNode::new(comment_ctx);
+
+        let name = "CommentOn".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![object_child, comment_child]);
+        self.children.push(node);
+    }
+
     fn visit_create_network_policy(&mut self, stmt: &'ast CreateNetworkPolicyStmt) {
         let ctx = AstFormatContext::new(format!("NetworkPolicyName {}", stmt.name));
         let child = FormatTreeNode::new(ctx);
@@ -2644,6 +2845,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             }
             TableReference::TableFunction {
                 span: _,
+                lateral,
                 name,
                 params,
                 named_params,
@@ -2663,7 +2865,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     );
                     children.push(node);
                 }
-                let func_name = format!("TableFunction {}", name);
+                let func_name = if *lateral {
+                    format!("TableFunction LATERAL {}", name)
+                } else {
+                    format!("TableFunction {}", name)
+                };
                 let format_ctx = if let Some(alias) = alias {
                     AstFormatContext::with_children_alias(
                         func_name,
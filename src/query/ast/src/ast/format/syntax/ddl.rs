@@ -77,6 +77,15 @@ pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
         } else {
             RcDoc::nil()
         })
+        .append(if !stmt.partition_by.is_empty() {
+            RcDoc::line()
+                .append(RcDoc::text("PARTITION BY "))
+                .append(parenthesized(
+                    interweave_comma(stmt.partition_by.into_iter().map(pretty_expr)).group(),
+                ))
+        } else {
+            RcDoc::nil()
+        })
         .append(if !stmt.table_options.is_empty() {
             RcDoc::line()
                 .append(interweave_comma(stmt.table_options.iter().map(|(k, v)| {
@@ -217,6 +226,31 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
             }
             doc
         }
+        AlterTableAction::AddRowAccessPolicy { policy, columns } => RcDoc::line()
+            .append(RcDoc::text("ADD ROW ACCESS POLICY "))
+            .append(RcDoc::text(policy.to_string()))
+            .append(RcDoc::text(" ON "))
+            .append(parenthesized(
+                interweave_comma(
+                    columns
+                        .into_iter()
+                        .map(|column| RcDoc::text(column.to_string())),
+                )
+                .group(),
+            )),
+        AlterTableAction::DropRowAccessPolicy { policy } => RcDoc::line()
+            .append(RcDoc::text("DROP ROW ACCESS POLICY "))
+            .append(RcDoc::text(policy.to_string())),
+        AlterTableAction::DropPartition { values } => RcDoc::line()
+            .append(RcDoc::text("DROP PARTITION "))
+            .append(parenthesized(
+                interweave_comma(values.into_iter().map(pretty_expr)).group(),
+            )),
+        AlterTableAction::TruncatePartition { values } => RcDoc::line()
+            .append(RcDoc::text("TRUNCATE PARTITION "))
+            .append(parenthesized(
+                interweave_comma(values.into_iter().map(pretty_expr)).group(),
+            )),
     }
 }
 
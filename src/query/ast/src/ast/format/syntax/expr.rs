@@ -164,6 +164,13 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             .append(RcDoc::space())
             .append(RcDoc::text(target_type.to_string()))
             .append(RcDoc::text(")")),
+        Expr::AtTimeZone {
+            expr, time_zone, ..
+        } => pretty_expr(*expr)
+            .append(RcDoc::space())
+            .append(RcDoc::text("AT TIME ZONE"))
+            .append(RcDoc::space())
+            .append(pretty_expr(*time_zone)),
         Expr::Extract {
             kind: field, expr, ..
         } => RcDoc::text("EXTRACT(")
@@ -260,9 +267,7 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             .append(inline_comma(args.into_iter().map(pretty_expr)))
             .append(RcDoc::text(")"))
             .append(if let Some(window) = window {
-                RcDoc::text(" OVER (")
-                    .append(RcDoc::text(window.to_string()))
-                    .append(")")
+                RcDoc::space().append(RcDoc::text(window.to_string()))
             } else {
                 RcDoc::nil()
             }),
@@ -346,6 +346,7 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
         }),
         TableReference::TableFunction {
             span: _,
+            lateral,
             name,
             params,
             named_params,
@@ -356,7 +357,8 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             } else {
                 RcDoc::nil()
             };
-            RcDoc::text(name.to_string())
+            RcDoc::text(if lateral { "LATERAL " } else { "" })
+                .append(RcDoc::text(name.to_string()))
                 .append(RcDoc::text("("))
                 .append(inline_comma(params.into_iter().map(pretty_expr)))
                 .append(separator)
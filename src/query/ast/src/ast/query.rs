@@ -243,9 +243,11 @@ pub enum TableReference {
         pivot: Option<Box<Pivot>>,
         unpivot: Option<Box<Unpivot>>,
     },
-    // `TABLE(expr)[ AS alias ]`
+    // `[LATERAL] TABLE(expr)[ AS alias ]`
     TableFunction {
         span: Span,
+        /// Whether the `LATERAL` keyword is specified
+        lateral: bool,
         name: Identifier,
         params: Vec<Expr>,
         named_params: Vec<(String, Expr)>,
@@ -441,11 +443,15 @@ impl Display for TableReference {
             }
             TableReference::TableFunction {
                 span: _,
+                lateral,
                 name,
                 params,
                 named_params,
                 alias,
             } => {
+                if *lateral {
+                    write!(f, "LATERAL ")?;
+                }
                 write!(f, "{name}(")?;
                 write_comma_separated_list(f, params)?;
                 if !params.is_empty() && !named_params.is_empty() {
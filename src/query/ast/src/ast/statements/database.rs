@@ -12,13 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::fmt::Display;
 use std::fmt::Formatter;
 
 use common_meta_app::share::ShareNameIdent;
 
 use crate::ast::statements::show::ShowLimit;
+use crate::ast::write_comma_separated_list;
 use crate::ast::write_period_separated_list;
+use crate::ast::write_space_separated_map;
 use crate::ast::Identifier;
 
 #[derive(Debug, Clone, PartialEq)] // Databases
@@ -88,6 +91,15 @@ impl Display for CreateDatabaseStmt {
                 from_share.tenant, from_share.share_name
             )?;
         }
+        if !self.options.is_empty() {
+            write!(f, " ")?;
+            write_space_separated_map(
+                f,
+                self.options
+                    .iter()
+                    .map(|property| (&property.name, &property.value)),
+            )?;
+        }
         // TODO(leiysky): display rest information
         Ok(())
     }
@@ -145,6 +157,16 @@ impl Display for AlterDatabaseStmt {
             AlterDatabaseAction::RenameDatabase { new_db } => {
                 write!(f, " RENAME TO {new_db}")?;
             }
+            AlterDatabaseAction::SetOptions { set_options } => {
+                write!(f, " SET OPTIONS (")?;
+                write_comma_separated_list(
+                    f,
+                    set_options
+                        .iter()
+                        .map(|(key, value)| format!("{key} = '{value}'")),
+                )?;
+                write!(f, ")")?;
+            }
         }
 
         Ok(())
@@ -153,7 +175,12 @@ impl Display for AlterDatabaseStmt {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AlterDatabaseAction {
-    RenameDatabase { new_db: Identifier },
+    RenameDatabase {
+        new_db: Identifier,
+    },
+    SetOptions {
+        set_options: BTreeMap<String, String>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
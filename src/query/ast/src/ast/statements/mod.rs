@@ -26,11 +26,13 @@ mod kill;
 mod network_policy;
 mod presign;
 mod replace;
+mod row_access_policy;
 mod share;
 mod show;
 mod stage;
 mod statement;
 mod table;
+mod tag;
 mod unset;
 mod update;
 mod user;
@@ -51,11 +53,13 @@ pub use kill::*;
 pub use network_policy::*;
 pub use presign::*;
 pub use replace::*;
+pub use row_access_policy::*;
 pub use share::*;
 pub use show::*;
 pub use stage::*;
 pub use statement::*;
 pub use table::*;
+pub use tag::*;
 pub use unset::*;
 pub use update::*;
 pub use user::*;
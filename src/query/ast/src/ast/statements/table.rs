@@ -128,6 +128,7 @@ pub struct CreateTableStmt {
     pub engine: Option<Engine>,
     pub uri_location: Option<UriLocation>,
     pub cluster_by: Vec<Expr>,
+    pub partition_by: Vec<Expr>,
     pub table_options: BTreeMap<String, String>,
     pub as_query: Option<Box<Query>>,
     pub transient: bool,
@@ -165,6 +166,12 @@ impl Display for CreateTableStmt {
             write!(f, ")")?
         }
 
+        if !self.partition_by.is_empty() {
+            write!(f, " PARTITION BY (")?;
+            write_comma_separated_list(f, &self.partition_by)?;
+            write!(f, ")")?
+        }
+
         // Format table options
         write_space_separated_map(f, self.table_options.iter())?;
         if let Some(as_query) = &self.as_query {
@@ -351,6 +358,20 @@ pub enum AlterTableAction {
     SetOptions {
         set_options: BTreeMap<String, String>,
     },
+    AddRowAccessPolicy {
+        policy: Identifier,
+        columns: Vec<Identifier>,
+    },
+    DropRowAccessPolicy {
+        policy: Identifier,
+    },
+    DropPartition {
+        values: Vec<Expr>,
+    },
+    TruncatePartition {
+        values: Vec<Expr>,
+    },
+    RotateKey,
 }
 
 impl Display for AlterTableAction {
@@ -406,6 +427,27 @@ impl Display for AlterTableAction {
                 write!(f, "REVERT TO {}", point)?;
                 Ok(())
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                write!(f, "ADD ROW ACCESS POLICY {policy} ON (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => {
+                write!(f, "DROP ROW ACCESS POLICY {policy}")
+            }
+            AlterTableAction::DropPartition { values } => {
+                write!(f, "DROP PARTITION (")?;
+                write_comma_separated_list(f, values)?;
+                write!(f, ")")
+            }
+            AlterTableAction::TruncatePartition { values } => {
+                write!(f, "TRUNCATE PARTITION (")?;
+                write_comma_separated_list(f, values)?;
+                write!(f, ")")
+            }
+            AlterTableAction::RotateKey => {
+                write!(f, "ROTATE KEY")
+            }
         }
     }
 }
@@ -593,6 +635,7 @@ pub enum Engine {
     Fuse,
     View,
     Random,
+    Delta,
 }
 
 impl Display for Engine {
@@ -603,6 +646,7 @@ impl Display for Engine {
             Engine::Fuse => write!(f, "FUSE"),
             Engine::View => write!(f, "VIEW"),
             Engine::Random => write!(f, "RANDOM"),
+            Engine::Delta => write!(f, "DELTA"),
         }
     }
 }
@@ -0,0 +1,199 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use common_meta_app::principal::UserIdentity;
+
+use crate::ast::write_comma_separated_list;
+use crate::ast::write_period_separated_list;
+use crate::ast::Identifier;
+
+/// The catalog object that a comment or a tag is attached to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ObjectRef {
+    Database {
+        catalog: Option<Identifier>,
+        database: Identifier,
+    },
+    Table {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+    },
+    View {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        view: Identifier,
+    },
+    Column {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+        column: Identifier,
+    },
+    Stage {
+        stage: String,
+    },
+    User {
+        user: UserIdentity,
+    },
+}
+
+impl Display for ObjectRef {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ObjectRef::Database { catalog, database } => {
+                write!(f, "DATABASE ")?;
+                write_period_separated_list(f, catalog.iter().chain(Some(database)))
+            }
+            ObjectRef::Table {
+                catalog,
+                database,
+                table,
+            } => {
+                write!(f, "TABLE ")?;
+                write_period_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
+            }
+            ObjectRef::View {
+                catalog,
+                database,
+                view,
+            } => {
+                write!(f, "VIEW ")?;
+                write_period_separated_list(f, catalog.iter().chain(database).chain(Some(view)))
+            }
+            ObjectRef::Column {
+                catalog,
+                database,
+                table,
+                column,
+            } => {
+                write!(f, "COLUMN ")?;
+                write_period_separated_list(
+                    f,
+                    catalog
+                        .iter()
+                        .chain(database)
+                        .chain(Some(table))
+                        .chain(Some(column)),
+                )
+            }
+            ObjectRef::Stage { stage } => write!(f, "STAGE {stage}"),
+            ObjectRef::User { user } => write!(f, "USER {user}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateTagStmt {
+    pub if_not_exists: bool,
+    pub name: Identifier,
+    pub comment: Option<String>,
+}
+
+impl Display for CreateTagStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE TAG ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{}'", comment)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropTagStmt {
+    pub if_exists: bool,
+    pub name: Identifier,
+}
+
+impl Display for DropTagStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP TAG ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommentOnStmt {
+    pub object: ObjectRef,
+    pub comment: String,
+}
+
+impl Display for CommentOnStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "COMMENT ON {} IS '{}'", self.object, self.comment)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AlterObjectTagAction {
+    Set { tags: Vec<(Identifier, String)> },
+    Unset { tags: Vec<Identifier> },
+}
+
+impl Display for AlterObjectTagAction {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            AlterObjectTagAction::Set { tags } => {
+                write!(f, "SET TAG ")?;
+                write_comma_separated_list(
+                    f,
+                    tags.iter()
+                        .map(|(name, value)| format!("{name} = '{value}'")),
+                )
+            }
+            AlterObjectTagAction::Unset { tags } => {
+                write!(f, "UNSET TAG ")?;
+                write_comma_separated_list(f, tags)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlterObjectTagStmt {
+    pub object: ObjectRef,
+    pub action: AlterObjectTagAction,
+}
+
+impl Display for AlterObjectTagStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match &self.object {
+            ObjectRef::Column {
+                catalog,
+                database,
+                table,
+                column,
+            } => {
+                write!(f, "ALTER TABLE ")?;
+                write_period_separated_list(f, catalog.iter().chain(database).chain(Some(table)))?;
+                write!(f, " MODIFY COLUMN {column} {}", self.action)
+            }
+            object => write!(f, "ALTER {object} {}", self.action),
+        }
+    }
+}
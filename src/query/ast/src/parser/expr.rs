@@ -249,6 +249,10 @@ pub enum ExprElement {
     PgCast {
         target_type: TypeName,
     },
+    /// `AT TIME ZONE <expr>`
+    AtTimeZone {
+        time_zone: Box<Expr>,
+    },
     /// EXTRACT(IntervalKind FROM <expr>)
     Extract {
         field: IntervalKind,
@@ -292,7 +296,7 @@ pub enum ExprElement {
         name: Identifier,
         args: Vec<Expr>,
         params: Vec<Literal>,
-        window: Option<Window>,
+        window: Option<WindowDesc>,
         lambda: Option<Lambda>,
     },
     /// `CASE ... WHEN ... ELSE ...` expression
@@ -392,6 +396,8 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 BinaryOperator::RLike => Affix::Infix(Precedence(20), Associativity::Left),
                 BinaryOperator::NotRLike => Affix::Infix(Precedence(20), Associativity::Left),
                 BinaryOperator::SoundsLike => Affix::Infix(Precedence(20), Associativity::Left),
+                BinaryOperator::JsonContains => Affix::Infix(Precedence(20), Associativity::Left),
+                BinaryOperator::JsonKeyExists => Affix::Infix(Precedence(20), Associativity::Left),
 
                 BinaryOperator::BitwiseOr => Affix::Infix(Precedence(22), Associativity::Left),
                 BinaryOperator::BitwiseAnd => Affix::Infix(Precedence(22), Associativity::Left),
@@ -418,6 +424,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 BinaryOperator::Caret => Affix::Infix(Precedence(40), Associativity::Left),
             },
             ExprElement::PgCast { .. } => Affix::Postfix(Precedence(60)),
+            ExprElement::AtTimeZone { .. } => Affix::Postfix(Precedence(45)),
             _ => Affix::Nilfix,
         };
         Ok(affix)
@@ -645,6 +652,11 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 target_type,
                 pg_style: true,
             },
+            ExprElement::AtTimeZone { time_zone } => Expr::AtTimeZone {
+                span: transform_span(elem.span.0),
+                expr: Box::new(lhs),
+                time_zone,
+            },
             ExprElement::UnaryOp { op } => Expr::UnaryOp {
                 span: transform_span(elem.span.0),
                 op,
@@ -729,6 +741,14 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         },
         |(_, target_type)| ExprElement::PgCast { target_type },
     );
+    let at_time_zone = map(
+        rule! {
+            AT ~ TIME ~ ^ZONE ~ ^#subexpr(60)
+        },
+        |(_, _, _, time_zone)| ExprElement::AtTimeZone {
+            time_zone: Box::new(time_zone),
+        },
+    );
     let extract = map(
         rule! {
             EXTRACT ~ "(" ~ ^#interval_kind ~ ^FROM ~ ^#subexpr(0) ~ ^")"
@@ -837,36 +857,53 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         },
     );
 
+    let lambda_params = alt((
+        map(rule! { #ident }, |param| vec![param]),
+        map(
+            rule! { "(" ~ #comma_separated_list1(ident) ~ ")" },
+            |(_, params, _)| params,
+        ),
+    ));
     let function_call_with_lambda = map(
         rule! {
             #function_name
-            ~ "(" ~ #subexpr(0) ~ "," ~ #ident ~ "->" ~ #subexpr(0) ~ ")"
+            ~ "(" ~ #subexpr(0) ~ "," ~ #lambda_params ~ "->" ~ #subexpr(0) ~ ")"
         },
-        |(name, _, arg, _, param, _, expr, _)| ExprElement::FunctionCall {
+        |(name, _, arg, _, params, _, expr, _)| ExprElement::FunctionCall {
             distinct: false,
             name,
             args: vec![arg],
             params: vec![],
             window: None,
             lambda: Some(Lambda {
-                params: vec![param],
+                params,
                 expr: Box::new(expr),
             }),
         },
     );
 
+    let null_treatment = map(
+        rule! {
+            ( IGNORE | RESPECT ) ~ NULLS
+        },
+        |(ignore_respect, _)| ignore_respect.kind == IGNORE,
+    );
     let function_call_with_window = map(
         rule! {
             #function_name
             ~ "(" ~ DISTINCT? ~ #comma_separated_list0(subexpr(0))? ~ ")"
+            ~ #null_treatment?
             ~ (OVER ~ #window_spec_ident)
         },
-        |(name, _, opt_distinct, opt_args, _, window)| ExprElement::FunctionCall {
+        |(name, _, opt_distinct, opt_args, _, ignore_nulls, window)| ExprElement::FunctionCall {
             distinct: opt_distinct.is_some(),
             name,
             args: opt_args.unwrap_or_default(),
             params: vec![],
-            window: Some(window.1),
+            window: Some(WindowDesc {
+                ignore_nulls,
+                window: window.1,
+            }),
             lambda: None,
         },
     );
@@ -985,6 +1022,21 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             unit,
         },
     );
+    let interval_string = map_res(
+        rule! {
+            INTERVAL ~ #consumed(literal_string)
+        },
+        |(_, (span, text))| {
+            let (value, unit) = parse_interval_string(&text)?;
+            Ok(ExprElement::Interval {
+                expr: Expr::Literal {
+                    span: transform_span(span.0),
+                    lit: Literal::UInt64(value),
+                },
+                unit,
+            })
+        },
+    );
     let date_trunc = map(
         rule! {
             DATE_TRUNC ~ "(" ~ #interval_kind ~ "," ~ #subexpr(0) ~ ")"
@@ -1042,11 +1094,13 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             | #date_expr: "`DATE <str_literal>`"
             | #timestamp_expr: "`TIMESTAMP <str_literal>`"
             | #interval: "`INTERVAL ... (YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | DOY | DOW)`"
+            | #interval_string: "`INTERVAL '<number> <unit>'`"
             | #pg_cast : "`::<type_name>`"
             | #extract : "`EXTRACT((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND) FROM ...)`"
         ),
         rule!(
             #position : "`POSITION(... IN ...)`"
+            | #at_time_zone : "`... AT TIME ZONE ...`"
             | #substring : "`SUBSTRING(... [FROM ...] [FOR ...])`"
             | #trim : "`TRIM(...)`"
             | #trim_from : "`TRIM([(BOTH | LEADEING | TRAILING) ... FROM ...)`"
@@ -1117,6 +1171,8 @@ pub fn binary_op(i: Input) -> IResult<BinaryOperator> {
             value(BinaryOperator::BitwiseXor, rule! { BitWiseXor }),
             value(BinaryOperator::BitwiseShiftLeft, rule! { ShiftLeft }),
             value(BinaryOperator::BitwiseShiftRight, rule! { ShiftRight }),
+            value(BinaryOperator::JsonContains, rule! { AtArrow }),
+            value(BinaryOperator::JsonKeyExists, rule! { "?" }),
         )),
     ))(i)
 }
@@ -1332,15 +1388,26 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
         },
     );
     let ty_date = value(TypeName::Date, rule! { DATE });
+    let ty_timestamp_tz = value(TypeName::TimestampTz, rule! {
+        TIMESTAMP_TZ
+        | TIMESTAMPTZ
+        | TIMESTAMP ~ ( "(" ~ #literal_u64 ~ ")" )? ~ WITH ~ TIME ~ ZONE
+    });
+    let ty_time = value(TypeName::Time, rule! { TIME });
     let ty_datetime = map(
         rule! { (DATETIME | TIMESTAMP) ~ ( "(" ~ #literal_u64 ~ ")" )? },
         |(_, _)| TypeName::Timestamp,
     );
     let ty_string = value(
         TypeName::String,
-        rule! { ( STRING | VARCHAR | CHAR | CHARACTER | TEXT | BINARY | VARBINARY ) ~ ( "(" ~ #literal_u64 ~ ")" )? },
+        rule! { ( STRING | VARCHAR | CHAR | CHARACTER | TEXT ) ~ ( "(" ~ #literal_u64 ~ ")" )? },
+    );
+    let ty_binary = value(
+        TypeName::Binary,
+        rule! { ( BINARY | VARBINARY ) ~ ( "(" ~ #literal_u64 ~ ")" )? },
     );
     let ty_variant = value(TypeName::Variant, rule! { VARIANT | JSON });
+    let ty_geometry = value(TypeName::Geometry, rule! { GEOMETRY | GEOGRAPHY });
     map(
         alt((
             rule! {
@@ -1365,9 +1432,13 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
             },
             rule! {
             ( #ty_date
+            | #ty_timestamp_tz
+            | #ty_time
             | #ty_datetime
             | #ty_string
+            | #ty_binary
             | #ty_variant
+            | #ty_geometry
             | #ty_nullable
             ) ~ NULL? : "type name" },
         )),
@@ -1431,6 +1502,29 @@ pub fn interval_kind(i: Input) -> IResult<IntervalKind> {
     ))(i)
 }
 
+/// Parse the quoted form of an interval, such as `'7 days'` or `'1 hour'`.
+pub fn parse_interval_string(text: &str) -> Result<(u64, IntervalKind), ErrorKind> {
+    let mut parts = text.split_whitespace();
+    let (Some(value), Some(unit), None) = (parts.next(), parts.next(), parts.next()) else {
+        return Err(ErrorKind::Other("expecting `'<number> <unit>'` interval"));
+    };
+    let value = value
+        .parse::<u64>()
+        .map_err(|_| ErrorKind::Other("expecting unsigned integer in interval"))?;
+    let unit = unit.to_ascii_uppercase();
+    let unit = match unit.strip_suffix('S').unwrap_or(&unit) {
+        "YEAR" => IntervalKind::Year,
+        "QUARTER" => IntervalKind::Quarter,
+        "MONTH" => IntervalKind::Month,
+        "DAY" => IntervalKind::Day,
+        "HOUR" => IntervalKind::Hour,
+        "MINUTE" => IntervalKind::Minute,
+        "SECOND" => IntervalKind::Second,
+        _ => return Err(ErrorKind::Other("unknown interval unit")),
+    };
+    Ok((value, unit))
+}
+
 pub fn map_access(i: Input) -> IResult<MapAccessor> {
     let bracket = map(
         rule! {
@@ -18,9 +18,11 @@ pub mod expr;
 mod parser;
 pub mod query;
 pub mod quote;
+mod row_access_policy;
 mod share;
 mod stage;
 pub mod statement;
+mod tag;
 pub mod token;
 pub mod unescape;
 
@@ -36,8 +36,10 @@ use crate::parser::data_mask::data_mask_policy;
 use crate::parser::expr::subexpr;
 use crate::parser::expr::*;
 use crate::parser::query::*;
+use crate::parser::row_access_policy::row_access_policy;
 use crate::parser::share::share_endpoint_uri_location;
 use crate::parser::stage::*;
+use crate::parser::tag::*;
 use crate::parser::token::*;
 use crate::rule;
 use crate::util::*;
@@ -309,9 +311,13 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
     );
     let create_database = map(
         rule! {
-            CREATE ~ ( DATABASE | SCHEMA ) ~ ( IF ~ NOT ~ EXISTS )? ~ #period_separated_idents_1_to_2 ~ #create_database_option?
+            CREATE ~ ( DATABASE | SCHEMA ) ~ ( IF ~ NOT ~ EXISTS )? ~ #period_separated_idents_1_to_2 ~ #create_database_option? ~ #table_option
         },
-        |(_, _, opt_if_not_exists, (catalog, database), create_database_option)| {
+        |(_, _, opt_if_not_exists, (catalog, database), create_database_option, options)| {
+            let options = options
+                .into_iter()
+                .map(|(name, value)| SQLProperty { name, value })
+                .collect();
             match create_database_option {
                 Some(CreateDatabaseOption::DatabaseEngine(engine)) => {
                     Statement::CreateDatabase(CreateDatabaseStmt {
@@ -319,7 +325,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                         catalog,
                         database,
                         engine: Some(engine),
-                        options: vec![],
+                        options,
                         from_share: None,
                     })
                 }
@@ -329,7 +335,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                         catalog,
                         database,
                         engine: None,
-                        options: vec![],
+                        options,
                         from_share: Some(share_name),
                     })
                 }
@@ -338,7 +344,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                     catalog,
                     database,
                     engine: None,
-                    options: vec![],
+                    options,
                     from_share: None,
                 }),
             }
@@ -503,8 +509,9 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             ~ #period_separated_idents_1_to_3
             ~ #create_table_source?
             ~ ( #engine )?
-            ~ ( #uri_location )?
+            ~ ( ( LOCATION ~ ^"=" )? ~ #uri_location )?
             ~ ( CLUSTER ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )?
+            ~ ( PARTITION ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )?
             ~ ( #table_option )?
             ~ ( AS ~ ^#query )?
         },
@@ -518,6 +525,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             engine,
             uri_location,
             opt_cluster_by,
+            opt_partition_by,
             opt_table_options,
             opt_as_query,
         )| {
@@ -528,10 +536,13 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                 table,
                 source,
                 engine,
-                uri_location,
+                uri_location: uri_location.map(|(_, location)| location),
                 cluster_by: opt_cluster_by
                     .map(|(_, _, _, exprs, _)| exprs)
                     .unwrap_or_default(),
+                partition_by: opt_partition_by
+                    .map(|(_, _, _, exprs, _)| exprs)
+                    .unwrap_or_default(),
                 table_options: opt_table_options.unwrap_or_default(),
                 as_query: opt_as_query.map(|(_, query)| Box::new(query)),
                 transient: opt_transient.is_some(),
@@ -772,6 +783,68 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let create_ngram_index = map(
+        rule! {
+            CREATE ~ NGRAM ~ INDEX ~ ON ~ #period_separated_idents_1_to_3
+            ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, _, (catalog, database, table), _, columns, _)| {
+            Statement::CreateNgramIndex(CreateNgramIndexStmt {
+                catalog,
+                database,
+                table,
+                columns,
+            })
+        },
+    );
+
+    let create_inverted_index = map(
+        rule! {
+            CREATE ~ INVERTED ~ INDEX ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
+            ~ ON ~ #period_separated_idents_1_to_3
+            ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+            ~ #table_option
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            index_name,
+            _,
+            (catalog, database, table),
+            _,
+            columns,
+            _,
+            options,
+        )| {
+            Statement::CreateInvertedIndex(CreateInvertedIndexStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+                columns,
+                options,
+            })
+        },
+    );
+
+    let drop_inverted_index = map(
+        rule! {
+            DROP ~ INVERTED ~ INDEX ~ ( IF ~ ^EXISTS )? ~ #ident ~ ON ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, index_name, _, (catalog, database, table))| {
+            Statement::DropInvertedIndex(DropInvertedIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+
     let create_virtual_columns = map(
         rule! {
             CREATE ~ VIRTUAL ~ COLUMNS ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" ~ FOR ~ #period_separated_idents_1_to_3
@@ -1308,6 +1381,93 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             Statement::DropDatamaskPolicy(stmt)
         },
     );
+    // row access policy
+    let create_row_access_policy = map(
+        rule! {
+            CREATE ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ NOT ~ EXISTS )? ~ #ident ~ #row_access_policy
+        },
+        |(_, _, _, _, opt_if_not_exists, name, policy)| {
+            Statement::CreateRowAccessPolicy(CreateRowAccessPolicyStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                policy,
+            })
+        },
+    );
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, _, opt_if_exists, name)| {
+            Statement::DropRowAccessPolicy(DropRowAccessPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            })
+        },
+    );
+    let describe_row_access_policy = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ ROW ~ ACCESS ~ POLICY ~ #ident
+        },
+        |(_, _, _, _, name)| {
+            Statement::DescRowAccessPolicy(DescRowAccessPolicyStmt {
+                name: name.to_string(),
+            })
+        },
+    );
+    // tag and comment
+    let create_tag = map(
+        rule! {
+            CREATE ~ TAG ~ ( IF ~ NOT ~ EXISTS )? ~ #ident ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(_, _, opt_if_not_exists, name, opt_comment)| {
+            Statement::CreateTag(CreateTagStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_tag = map(
+        rule! {
+            DROP ~ TAG ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, name)| {
+            Statement::DropTag(DropTagStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+    let alter_object_tag = map(
+        rule! {
+            ALTER ~ #tag_object ~ #alter_object_tag_action
+        },
+        |(_, object, action)| Statement::AlterObjectTag(AlterObjectTagStmt { object, action }),
+    );
+    let alter_column_tag = map(
+        rule! {
+            ALTER ~ TABLE ~ #period_separated_idents_1_to_3
+            ~ MODIFY ~ COLUMN ~ #ident ~ #alter_object_tag_action
+        },
+        |(_, _, (catalog, database, table), _, _, column, action)| {
+            Statement::AlterObjectTag(AlterObjectTagStmt {
+                object: ObjectRef::Column {
+                    catalog,
+                    database,
+                    table,
+                    column,
+                },
+                action,
+            })
+        },
+    );
+    let comment_on = map(
+        rule! {
+            COMMENT ~ ON ~ #comment_object ~ IS ~ #literal_string
+        },
+        |(_, _, object, _, comment)| Statement::CommentOn(CommentOnStmt { object, comment }),
+    );
     let describe_data_mask_policy = map(
         rule! {
             ( DESC | DESCRIBE ) ~ MASKING ~ POLICY ~ #ident
@@ -1491,6 +1651,9 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             #create_index: "`CREATE AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
             | #drop_index: "`DROP AGGREGATING INDEX [IF EXISTS] <index>`"
             | #refresh_index: "`REFRESH AGGREGATING INDEX <index> [LIMIT <limit>]`"
+            | #create_ngram_index: "`CREATE NGRAM INDEX ON [<database>.]<table> (<column>, ...)`"
+            | #create_inverted_index: "`CREATE INVERTED INDEX [IF NOT EXISTS] <index> ON [<database>.]<table> (<column>, ...) [tokenizer = 'english' | 'chinese']`"
+            | #drop_inverted_index: "`DROP INVERTED INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
         ),
         rule!(
             #create_virtual_columns: "`CREATE VIRTUAL COLUMNS (expr, ...) FOR [<database>.]<table>`"
@@ -1552,6 +1715,20 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #drop_data_mask_policy: "`DROP MASKING POLICY [IF EXISTS] mask_name`"
             | #describe_data_mask_policy: "`DESC MASKING POLICY mask_name`"
         ),
+        // row access policy
+        rule!(
+            #create_row_access_policy: "`CREATE ROW ACCESS POLICY [IF NOT EXISTS] policy_name AS (arg1 arg_type1 [, arg type]) RETURNS BOOLEAN -> expr`"
+            | #drop_row_access_policy: "`DROP ROW ACCESS POLICY [IF EXISTS] policy_name`"
+            | #describe_row_access_policy: "`DESC ROW ACCESS POLICY policy_name`"
+        ),
+        // tag and comment
+        rule!(
+            #create_tag: "`CREATE TAG [IF NOT EXISTS] <tag_name> [COMMENT = '<string_literal>']`"
+            | #drop_tag: "`DROP TAG [IF EXISTS] <tag_name>`"
+            | #alter_object_tag: "`ALTER {DATABASE | TABLE | VIEW | STAGE | USER} <object> {SET TAG <tag_name> = '<value>' [, ...] | UNSET TAG <tag_name> [, ...]}`"
+            | #alter_column_tag: "`ALTER TABLE [<database>.]<table> MODIFY COLUMN <column> {SET TAG <tag_name> = '<value>' [, ...] | UNSET TAG <tag_name> [, ...]}`"
+            | #comment_on: "`COMMENT ON {TABLE | COLUMN | DATABASE | VIEW | STAGE} <object> IS '<string_literal>'`"
+        ),
         // share
         rule!(
             #create_share_endpoint: "`CREATE SHARE ENDPOINT [IF NOT EXISTS] <endpoint_name> URL=endpoint_location tenant=tenant_name ARGS=(arg=..) [ COMMENT = '<string_literal>' ]`"
@@ -1965,8 +2142,16 @@ pub fn alter_database_action(i: Input) -> IResult<AlterDatabaseAction> {
         |(_, _, new_db)| AlterDatabaseAction::RenameDatabase { new_db },
     );
 
+    let set_database_options = map(
+        rule! {
+            SET ~ OPTIONS ~ "(" ~ #set_table_option ~ ")"
+        },
+        |(_, _, _, set_options, _)| AlterDatabaseAction::SetOptions { set_options },
+    );
+
     rule!(
         #rename_database
+        | #set_database_options
     )(i)
 }
 
@@ -2099,6 +2284,39 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, _, set_options, _)| AlterTableAction::SetOptions { set_options },
     );
 
+    let add_row_access_policy = map(
+        rule! {
+            ADD ~ ROW ~ ACCESS ~ POLICY ~ #ident ~ ON ~ "(" ~ #comma_separated_list1(ident) ~ ")"
+        },
+        |(_, _, _, _, policy, _, _, columns, _)| AlterTableAction::AddRowAccessPolicy {
+            policy,
+            columns,
+        },
+    );
+
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ POLICY ~ #ident
+        },
+        |(_, _, _, _, policy)| AlterTableAction::DropRowAccessPolicy { policy },
+    );
+
+    let drop_partition = map(
+        rule! {
+            DROP ~ PARTITION ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")"
+        },
+        |(_, _, _, values, _)| AlterTableAction::DropPartition { values },
+    );
+
+    let truncate_partition = map(
+        rule! {
+            TRUNCATE ~ PARTITION ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")"
+        },
+        |(_, _, _, values, _)| AlterTableAction::TruncatePartition { values },
+    );
+
+    let rotate_key = value(AlterTableAction::RotateKey, rule! { ROTATE ~ KEY });
+
     rule!(
         #rename_table
         | #rename_column
@@ -2110,6 +2328,11 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         | #recluster_table
         | #revert_table
         | #set_table_options
+        | #add_row_access_policy
+        | #drop_row_access_policy
+        | #drop_partition
+        | #truncate_partition
+        | #rotate_key
     )(i)
 }
 
@@ -2275,6 +2498,7 @@ pub fn engine(i: Input) -> IResult<Engine> {
         value(Engine::Fuse, rule! { FUSE }),
         value(Engine::View, rule! { VIEW }),
         value(Engine::Random, rule! { RANDOM }),
+        value(Engine::Delta, rule! { DELTA }),
     ));
 
     map(
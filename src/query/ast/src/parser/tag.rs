@@ -0,0 +1,152 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use nom::branch::alt;
+use nom::combinator::map;
+
+use crate::ast::AlterObjectTagAction;
+use crate::ast::Identifier;
+use crate::ast::ObjectRef;
+use crate::input::Input;
+use crate::parser::expr::*;
+use crate::parser::statement::user_identity;
+use crate::parser::token::*;
+use crate::rule;
+use crate::util::*;
+
+/// Parse two to four idents separated by a period, the last one is the column.
+///
+/// Example: `db.table.column`
+#[allow(clippy::type_complexity)]
+fn column_ref(
+    i: Input,
+) -> IResult<(
+    Option<Identifier>,
+    Option<Identifier>,
+    Identifier,
+    Identifier,
+)> {
+    map(
+        rule! {
+            #ident ~ "." ~ #ident ~ ("." ~ #ident ~ ("." ~ #ident)?)?
+        },
+        |res| match res {
+            (ident2, _, ident3, None) => (None, None, ident2, ident3),
+            (ident1, _, ident2, Some((_, ident3, None))) => (None, Some(ident1), ident2, ident3),
+            (ident0, _, ident1, Some((_, ident2, Some((_, ident3))))) => {
+                (Some(ident0), Some(ident1), ident2, ident3)
+            }
+        },
+    )(i)
+}
+
+/// The objects that can be commented on.
+pub fn comment_object(i: Input) -> IResult<ObjectRef> {
+    let column = map(
+        rule! {
+            COLUMN ~ #column_ref
+        },
+        |(_, (catalog, database, table, column))| ObjectRef::Column {
+            catalog,
+            database,
+            table,
+            column,
+        },
+    );
+
+    alt((column, common_object))(i)
+}
+
+/// The objects that can be tagged, columns are tagged through
+/// `ALTER TABLE <table> MODIFY COLUMN <column> SET TAG ...`.
+pub fn tag_object(i: Input) -> IResult<ObjectRef> {
+    let user = map(
+        rule! {
+            USER ~ #user_identity
+        },
+        |(_, user)| ObjectRef::User { user },
+    );
+
+    alt((common_object, user))(i)
+}
+
+fn common_object(i: Input) -> IResult<ObjectRef> {
+    let database = map(
+        rule! {
+            DATABASE ~ #period_separated_idents_1_to_2
+        },
+        |(_, (catalog, database))| ObjectRef::Database { catalog, database },
+    );
+    let table = map(
+        rule! {
+            TABLE ~ #period_separated_idents_1_to_3
+        },
+        |(_, (catalog, database, table))| ObjectRef::Table {
+            catalog,
+            database,
+            table,
+        },
+    );
+    let view = map(
+        rule! {
+            VIEW ~ #period_separated_idents_1_to_3
+        },
+        |(_, (catalog, database, view))| ObjectRef::View {
+            catalog,
+            database,
+            view,
+        },
+    );
+    let stage = map(
+        rule! {
+            STAGE ~ #stage_name
+        },
+        |(_, stage)| ObjectRef::Stage {
+            stage: stage.to_string(),
+        },
+    );
+
+    rule!(
+        #database
+        | #table
+        | #view
+        | #stage
+    )(i)
+}
+
+/// `SET TAG <tag> = '<value>' [, ...]` or `UNSET TAG <tag> [, ...]`
+pub fn alter_object_tag_action(i: Input) -> IResult<AlterObjectTagAction> {
+    let set_tag = map(
+        rule! {
+            SET ~ TAG ~ #comma_separated_list1(rule! { #ident ~ "=" ~ #literal_string })
+        },
+        |(_, _, tags)| AlterObjectTagAction::Set {
+            tags: tags
+                .into_iter()
+                .map(|(name, _, value)| (name, value))
+                .collect(),
+        },
+    );
+    let unset_tag = map(
+        rule! {
+            UNSET ~ TAG ~ #comma_separated_list1(ident)
+        },
+        |(_, _, tags)| AlterObjectTagAction::Unset { tags },
+    );
+
+    rule!(
+        #set_tag
+        | #unset_tag
+    )(i)
+}
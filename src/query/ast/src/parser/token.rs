@@ -270,6 +270,9 @@ pub enum TokenKind {
     /// AtSign `@` used for PostgreSQL abs operator
     #[token("@")]
     Abs,
+    /// Json contains operator `@>` in PostgreSQL
+    #[token("@>")]
+    AtArrow,
     /// A square root math operator in PostgreSQL
     #[token("|/")]
     SquareRoot,
@@ -287,6 +290,8 @@ pub enum TokenKind {
     // 2. Search in this file to see if the new keyword is a commented
     //    out reserved keyword. If so, uncomment the keyword in the
     //    reserved list.
+    #[token("ACCESS", ignore(ascii_case))]
+    ACCESS,
     #[token("ALL", ignore(ascii_case))]
     ALL,
     #[token("ALLOWED_IP_LIST", ignore(ascii_case))]
@@ -431,6 +436,8 @@ pub enum TokenKind {
     DEFLATE,
     #[token("DELETE", ignore(ascii_case))]
     DELETE,
+    #[token("DELTA", ignore(ascii_case))]
+    DELTA,
     #[token("DESC", ignore(ascii_case))]
     DESC,
     #[token("DESCRIBE", ignore(ascii_case))]
@@ -539,6 +546,10 @@ pub enum TokenKind {
     GENERATE,
     #[token("GENERATED", ignore(ascii_case))]
     GENERATED,
+    #[token("GEOGRAPHY", ignore(ascii_case))]
+    GEOGRAPHY,
+    #[token("GEOMETRY", ignore(ascii_case))]
+    GEOMETRY,
     #[token("GLOBAL", ignore(ascii_case))]
     GLOBAL,
     #[token("GRAPH", ignore(ascii_case))]
@@ -565,6 +576,8 @@ pub enum TokenKind {
     IDENTIFIED,
     #[token("IF", ignore(ascii_case))]
     IF,
+    #[token("IGNORE", ignore(ascii_case))]
+    IGNORE,
     #[token("IN", ignore(ascii_case))]
     IN,
     #[token("INDEX", ignore(ascii_case))]
@@ -589,6 +602,8 @@ pub enum TokenKind {
     INTERVAL,
     #[token("INTO", ignore(ascii_case))]
     INTO,
+    #[token("INVERTED", ignore(ascii_case))]
+    INVERTED,
     #[token("IS", ignore(ascii_case))]
     IS,
     #[token("ISODOW", ignore(ascii_case))]
@@ -607,10 +622,14 @@ pub enum TokenKind {
     KEY,
     #[token("KILL", ignore(ascii_case))]
     KILL,
+    #[token("LOCATION", ignore(ascii_case))]
+    LOCATION,
     #[token("LOCATION_PREFIX", ignore(ascii_case))]
     LOCATION_PREFIX,
     #[token("ROLES", ignore(ascii_case))]
     ROLES,
+    #[token("LATERAL", ignore(ascii_case))]
+    LATERAL,
     #[token("LEADING", ignore(ascii_case))]
     LEADING,
     #[token("LEFT", ignore(ascii_case))]
@@ -657,6 +676,8 @@ pub enum TokenKind {
     NATURAL,
     #[token("NETWORK", ignore(ascii_case))]
     NETWORK,
+    #[token("NGRAM", ignore(ascii_case))]
+    NGRAM,
     #[token("NDJSON", ignore(ascii_case))]
     NDJSON,
     #[token("NO_PASSWORD", ignore(ascii_case))]
@@ -739,6 +760,8 @@ pub enum TokenKind {
     RENAME,
     #[token("REPLACE", ignore(ascii_case))]
     REPLACE,
+    #[token("ROTATE", ignore(ascii_case))]
+    ROTATE,
     #[token("ROW", ignore(ascii_case))]
     ROW,
     #[token("ROWS", ignore(ascii_case))]
@@ -759,6 +782,8 @@ pub enum TokenKind {
     PRIVILEGES,
     #[token("REMOVE", ignore(ascii_case))]
     REMOVE,
+    #[token("RESPECT", ignore(ascii_case))]
+    RESPECT,
     #[token("RETAIN", ignore(ascii_case))]
     RETAIN,
     #[token("REVOKE", ignore(ascii_case))]
@@ -767,6 +792,8 @@ pub enum TokenKind {
     RECURSIVE,
     #[token("RETURN", ignore(ascii_case))]
     RETURN,
+    #[token("RETURNS", ignore(ascii_case))]
+    RETURNS,
     #[token("RUN", ignore(ascii_case))]
     RUN,
     #[token("GRANTS", ignore(ascii_case))]
@@ -857,6 +884,8 @@ pub enum TokenKind {
     TABLE,
     #[token("TABLES", ignore(ascii_case))]
     TABLES,
+    #[token("TAG", ignore(ascii_case))]
+    TAG,
     #[token("TEXT", ignore(ascii_case))]
     TEXT,
     #[token("TENANTSETTING", ignore(ascii_case))]
@@ -867,8 +896,14 @@ pub enum TokenKind {
     TENANT,
     #[token("THEN", ignore(ascii_case))]
     THEN,
+    #[token("TIME", ignore(ascii_case))]
+    TIME,
     #[token("TIMESTAMP", ignore(ascii_case))]
     TIMESTAMP,
+    #[token("TIMESTAMP_TZ", ignore(ascii_case))]
+    TIMESTAMP_TZ,
+    #[token("TIMESTAMPTZ", ignore(ascii_case))]
+    TIMESTAMPTZ,
     #[token("TIMEZONE_HOUR", ignore(ascii_case))]
     TIMEZONE_HOUR,
     #[token("TIMEZONE_MINUTE", ignore(ascii_case))]
@@ -959,6 +994,8 @@ pub enum TokenKind {
     XZ,
     #[token("YEAR", ignore(ascii_case))]
     YEAR,
+    #[token("ZONE", ignore(ascii_case))]
+    ZONE,
     #[token("ZSTD", ignore(ascii_case))]
     ZSTD,
     #[token("NULLIF", ignore(ascii_case))]
@@ -1044,6 +1081,7 @@ impl TokenKind {
                 | ShiftRight
                 | DoubleExclamationMark
                 | Abs
+                | AtArrow
                 | SquareRoot
                 | CubeRoot
                 | Placeholder
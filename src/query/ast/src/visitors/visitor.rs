@@ -176,6 +176,11 @@ pub trait Visitor<'ast>: Sized {
         walk_expr(self, expr);
     }
 
+    fn visit_at_time_zone(&mut self, _span: Span, expr: &'ast Expr, time_zone: &'ast Expr) {
+        walk_expr(self, expr);
+        walk_expr(self, time_zone);
+    }
+
     fn visit_extract(&mut self, _span: Span, _kind: &'ast IntervalKind, expr: &'ast Expr) {
         walk_expr(self, expr);
     }
@@ -230,7 +235,7 @@ pub trait Visitor<'ast>: Sized {
         _name: &'ast Identifier,
         args: &'ast [Expr],
         _params: &'ast [Literal],
-        over: &'ast Option<Window>,
+        over: &'ast Option<WindowDesc>,
         lambda: &'ast Option<Lambda>,
     ) {
         for arg in args {
@@ -238,7 +243,7 @@ pub trait Visitor<'ast>: Sized {
         }
 
         if let Some(over) = over {
-            self.visit_window(over);
+            self.visit_window(&over.window);
         }
         if let Some(lambda) = lambda {
             walk_expr(self, &lambda.expr)
@@ -487,6 +492,10 @@ pub trait Visitor<'ast>: Sized {
     fn visit_drop_index(&mut self, _stmt: &'ast DropIndexStmt) {}
     fn visit_refresh_index(&mut self, _stmt: &'ast RefreshIndexStmt) {}
 
+    fn visit_create_ngram_index(&mut self, _stmt: &'ast CreateNgramIndexStmt) {}
+    fn visit_create_inverted_index(&mut self, _stmt: &'ast CreateInvertedIndexStmt) {}
+    fn visit_drop_inverted_index(&mut self, _stmt: &'ast DropInvertedIndexStmt) {}
+
     fn visit_create_virtual_columns(&mut self, _stmt: &'ast CreateVirtualColumnsStmt) {}
 
     fn visit_alter_virtual_columns(&mut self, _stmt: &'ast AlterVirtualColumnsStmt) {}
@@ -592,6 +601,20 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_desc_data_mask_policy(&mut self, _stmt: &'ast DescDatamaskPolicyStmt) {}
 
+    fn visit_create_row_access_policy(&mut self, _stmt: &'ast CreateRowAccessPolicyStmt) {}
+
+    fn visit_drop_row_access_policy(&mut self, _stmt: &'ast DropRowAccessPolicyStmt) {}
+
+    fn visit_desc_row_access_policy(&mut self, _stmt: &'ast DescRowAccessPolicyStmt) {}
+
+    fn visit_create_tag(&mut self, _stmt: &'ast CreateTagStmt) {}
+
+    fn visit_drop_tag(&mut self, _stmt: &'ast DropTagStmt) {}
+
+    fn visit_alter_object_tag(&mut self, _stmt: &'ast AlterObjectTagStmt) {}
+
+    fn visit_comment_on(&mut self, _stmt: &'ast CommentOnStmt) {}
+
     fn visit_create_network_policy(&mut self, _stmt: &'ast CreateNetworkPolicyStmt) {}
 
     fn visit_alter_network_policy(&mut self, _stmt: &'ast AlterNetworkPolicyStmt) {}
@@ -168,6 +168,11 @@ pub trait VisitorMut: Sized {
         walk_expr_mut(self, expr);
     }
 
+    fn visit_at_time_zone(&mut self, _span: Span, expr: &mut Expr, time_zone: &mut Expr) {
+        walk_expr_mut(self, expr);
+        walk_expr_mut(self, time_zone);
+    }
+
     fn visit_extract(&mut self, _span: Span, _kind: &mut IntervalKind, expr: &mut Expr) {
         walk_expr_mut(self, expr);
     }
@@ -244,7 +249,7 @@ pub trait VisitorMut: Sized {
         _name: &mut Identifier,
         args: &mut [Expr],
         _params: &mut [Literal],
-        over: &mut Option<Window>,
+        over: &mut Option<WindowDesc>,
         lambda: &mut Option<Lambda>,
     ) {
         for arg in args.iter_mut() {
@@ -252,7 +257,7 @@ pub trait VisitorMut: Sized {
         }
 
         if let Some(over) = over {
-            match over {
+            match &mut over.window {
                 Window::WindowReference(reference) => {
                     self.visit_identifier(&mut reference.window_name);
                 }
@@ -502,6 +507,10 @@ pub trait VisitorMut: Sized {
     fn visit_drop_index(&mut self, _stmt: &mut DropIndexStmt) {}
     fn visit_refresh_index(&mut self, _stmt: &mut RefreshIndexStmt) {}
 
+    fn visit_create_ngram_index(&mut self, _stmt: &mut CreateNgramIndexStmt) {}
+    fn visit_create_inverted_index(&mut self, _stmt: &mut CreateInvertedIndexStmt) {}
+    fn visit_drop_inverted_index(&mut self, _stmt: &mut DropInvertedIndexStmt) {}
+
     fn visit_create_virtual_columns(&mut self, _stmt: &mut CreateVirtualColumnsStmt) {}
 
     fn visit_alter_virtual_columns(&mut self, _stmt: &mut AlterVirtualColumnsStmt) {}
@@ -607,6 +616,20 @@ pub trait VisitorMut: Sized {
 
     fn visit_desc_data_mask_policy(&mut self, _stmt: &mut DescDatamaskPolicyStmt) {}
 
+    fn visit_create_row_access_policy(&mut self, _stmt: &mut CreateRowAccessPolicyStmt) {}
+
+    fn visit_drop_row_access_policy(&mut self, _stmt: &mut DropRowAccessPolicyStmt) {}
+
+    fn visit_desc_row_access_policy(&mut self, _stmt: &mut DescRowAccessPolicyStmt) {}
+
+    fn visit_create_tag(&mut self, _stmt: &mut CreateTagStmt) {}
+
+    fn visit_drop_tag(&mut self, _stmt: &mut DropTagStmt) {}
+
+    fn visit_alter_object_tag(&mut self, _stmt: &mut AlterObjectTagStmt) {}
+
+    fn visit_comment_on(&mut self, _stmt: &mut CommentOnStmt) {}
+
     fn visit_create_network_policy(&mut self, _stmt: &mut CreateNetworkPolicyStmt) {}
 
     fn visit_alter_network_policy(&mut self, _stmt: &mut AlterNetworkPolicyStmt) {}
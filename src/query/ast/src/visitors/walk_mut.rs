@@ -67,6 +67,11 @@ pub fn walk_expr_mut<V: VisitorMut>(visitor: &mut V, expr: &mut Expr) {
             expr,
             target_type,
         } => visitor.visit_try_cast(*span, expr, target_type),
+        Expr::AtTimeZone {
+            span,
+            expr,
+            time_zone,
+        } => visitor.visit_at_time_zone(*span, expr, time_zone),
         Expr::Extract { span, kind, expr } => visitor.visit_extract(*span, kind, expr),
         Expr::Position {
             span,
@@ -366,6 +371,9 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
         Statement::CreateIndex(stmt) => visitor.visit_create_index(stmt),
         Statement::DropIndex(stmt) => visitor.visit_drop_index(stmt),
         Statement::RefreshIndex(stmt) => visitor.visit_refresh_index(stmt),
+        Statement::CreateNgramIndex(stmt) => visitor.visit_create_ngram_index(stmt),
+        Statement::CreateInvertedIndex(stmt) => visitor.visit_create_inverted_index(stmt),
+        Statement::DropInvertedIndex(stmt) => visitor.visit_drop_inverted_index(stmt),
         Statement::CreateVirtualColumns(stmt) => visitor.visit_create_virtual_columns(stmt),
         Statement::AlterVirtualColumns(stmt) => visitor.visit_alter_virtual_columns(stmt),
         Statement::DropVirtualColumns(stmt) => visitor.visit_drop_virtual_columns(stmt),
@@ -448,6 +456,13 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
         Statement::CreateDatamaskPolicy(stmt) => visitor.visit_create_data_mask_policy(stmt),
         Statement::DropDatamaskPolicy(stmt) => visitor.visit_drop_data_mask_policy(stmt),
         Statement::DescDatamaskPolicy(stmt) => visitor.visit_desc_data_mask_policy(stmt),
+        Statement::CreateRowAccessPolicy(stmt) => visitor.visit_create_row_access_policy(stmt),
+        Statement::DropRowAccessPolicy(stmt) => visitor.visit_drop_row_access_policy(stmt),
+        Statement::DescRowAccessPolicy(stmt) => visitor.visit_desc_row_access_policy(stmt),
+        Statement::CreateTag(stmt) => visitor.visit_create_tag(stmt),
+        Statement::DropTag(stmt) => visitor.visit_drop_tag(stmt),
+        Statement::AlterObjectTag(stmt) => visitor.visit_alter_object_tag(stmt),
+        Statement::CommentOn(stmt) => visitor.visit_comment_on(stmt),
         Statement::AttachTable(_) => {}
         Statement::CreateNetworkPolicy(stmt) => visitor.visit_create_network_policy(stmt),
         Statement::AlterNetworkPolicy(stmt) => visitor.visit_alter_network_policy(stmt),
@@ -13,13 +13,16 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::HashMap;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::DataType;
+use common_expression::types::Float32Type;
 use common_expression::types::NumberDataType;
 use common_expression::types::UInt64Type;
+use common_expression::types::F32;
 use common_expression::BlockEntry;
 use common_expression::BlockMetaInfo;
 use common_expression::BlockMetaInfoDowncast;
@@ -31,6 +34,7 @@ use common_expression::TableDataType;
 use common_expression::Value;
 use common_expression::BLOCK_NAME_COLUMN_ID;
 use common_expression::ROW_ID_COLUMN_ID;
+use common_expression::SEARCH_SCORE_COLUMN_ID;
 use common_expression::SEGMENT_NAME_COLUMN_ID;
 use common_expression::SNAPSHOT_NAME_COLUMN_ID;
 
@@ -91,6 +95,8 @@ pub struct InternalColumnMeta {
     pub snapshot_location: String,
     /// The row offsets in the block.
     pub offsets: Option<Vec<usize>>,
+    /// The row offsets and BM25 scores matched by the inverted index.
+    pub search_scores: Option<Vec<(usize, F32)>>,
 }
 
 #[typetag::serde(name = "internal_column_meta")]
@@ -122,6 +128,7 @@ pub enum InternalColumnType {
     BlockName,
     SegmentName,
     SnapshotName,
+    SearchScore,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
@@ -148,6 +155,7 @@ impl InternalColumn {
             InternalColumnType::BlockName => TableDataType::String,
             InternalColumnType::SegmentName => TableDataType::String,
             InternalColumnType::SnapshotName => TableDataType::String,
+            InternalColumnType::SearchScore => TableDataType::Number(NumberDataType::Float32),
         }
     }
 
@@ -166,6 +174,7 @@ impl InternalColumn {
             InternalColumnType::BlockName => BLOCK_NAME_COLUMN_ID,
             InternalColumnType::SegmentName => SEGMENT_NAME_COLUMN_ID,
             InternalColumnType::SnapshotName => SNAPSHOT_NAME_COLUMN_ID,
+            InternalColumnType::SearchScore => SEARCH_SCORE_COLUMN_ID,
         }
     }
 
@@ -222,6 +231,32 @@ impl InternalColumn {
                     Value::Scalar(Scalar::String(builder.build_scalar())),
                 )
             }
+            InternalColumnType::SearchScore => {
+                // Rows not matched by the inverted index get a score of zero.
+                let scores: HashMap<usize, F32> = meta
+                    .search_scores
+                    .as_ref()
+                    .map(|scores| scores.iter().cloned().collect())
+                    .unwrap_or_default();
+                let mut values = Vec::with_capacity(num_rows);
+                match &meta.offsets {
+                    Some(offsets) => {
+                        for i in offsets {
+                            values.push(scores.get(i).cloned().unwrap_or_default());
+                        }
+                    }
+                    None => {
+                        for i in 0..num_rows {
+                            values.push(scores.get(&i).cloned().unwrap_or_default());
+                        }
+                    }
+                }
+
+                BlockEntry::new(
+                    DataType::Number(NumberDataType::Float32),
+                    Value::Column(Float32Type::from_data(values)),
+                )
+            }
         }
     }
 }
@@ -37,6 +37,7 @@ use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_meta_types::MetaId;
 use common_pipeline_core::Pipeline;
 use common_storage::StorageMetrics;
+use storages_common_table_meta::meta::ColumnHistogram;
 use storages_common_table_meta::meta::SnapshotId;
 
 use crate::plan::DataSourceInfo;
@@ -267,8 +268,12 @@ pub trait Table: Sync + Send {
     }
 
     #[async_backtrace::framed]
-    async fn navigate_to(&self, instant: &NavigationPoint) -> Result<Arc<dyn Table>> {
-        let _ = instant;
+    async fn navigate_to(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        navigation: &NavigationDescriptor,
+    ) -> Result<Arc<dyn Table>> {
+        let (_, _) = (ctx, navigation);
 
         Err(ErrorCode::Unimplemented(format!(
             "table {},  of engine type {}, does not support time travel",
@@ -441,6 +446,11 @@ pub trait ColumnStatisticsProvider {
     // column_id is just the index of the column in table's schema
     fn column_statistics(&self, column_id: ColumnId) -> Option<ColumnStatistics>;
 
+    // returns the histogram of the given column collected by `ANALYZE TABLE`, if any.
+    fn histogram(&self, _column_id: ColumnId) -> Option<ColumnHistogram> {
+        None
+    }
+
     // If the data type is int and max - min + 1 < ndv, then adjust ndv to max - min + 1.
     fn adjust_ndv_by_min_max(&self, mut ndv: u64, min: Scalar, max: Scalar) -> u64 {
         let mut range = match (min, max) {
@@ -32,6 +32,9 @@ pub struct BackgroundConfig {
     // Fs compaction related background config.
     #[clap(flatten)]
     pub compaction: BackgroundCompactionConfig,
+    // Purge of table history beyond the data retention period.
+    #[clap(flatten)]
+    pub purge: BackgroundPurgeConfig,
 }
 
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Args)]
@@ -67,6 +70,19 @@ pub struct BackgroundCompactionConfig {
     pub scheduled_config: BackgroundScheduledConfig,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct BackgroundPurgeConfig {
+    // only wake up purge job if it is enabled.
+    #[clap(long)]
+    pub enable_purge: bool,
+
+    // the fixed interval between two rounds of purging on all tables
+    // that have a data retention period.
+    #[clap(long, default_value = "3600")]
+    pub purge_interval_secs: u64,
+}
+
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Args)]
 #[serde(default)]
 pub struct BackgroundScheduledConfig {
@@ -106,6 +122,7 @@ impl BackgroundScheduledConfig {
 pub struct InnerBackgroundConfig {
     pub enable: bool,
     pub compaction: InnerBackgroundCompactionConfig,
+    pub purge: InnerBackgroundPurgeConfig,
 }
 
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -117,6 +134,12 @@ pub struct InnerBackgroundCompactionConfig {
     pub params: BackgroundJobParams,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InnerBackgroundPurgeConfig {
+    pub enable: bool,
+    pub params: BackgroundJobParams,
+}
+
 impl InnerBackgroundCompactionConfig {
     pub fn has_target_tables(&self) -> bool {
         self.target_tables.is_some() && !self.target_tables.as_ref().unwrap().is_empty()
@@ -130,6 +153,7 @@ impl TryInto<InnerBackgroundConfig> for BackgroundConfig {
         Ok(InnerBackgroundConfig {
             enable: self.enable,
             compaction: self.compaction.try_into()?,
+            purge: self.purge.into(),
         })
     }
 }
@@ -139,6 +163,7 @@ impl From<InnerBackgroundConfig> for BackgroundConfig {
         Self {
             enable: inner.enable,
             compaction: BackgroundCompactionConfig::from(inner.compaction),
+            purge: BackgroundPurgeConfig::from(inner.purge),
         }
     }
 }
@@ -220,6 +245,26 @@ impl From<InnerBackgroundCompactionConfig> for BackgroundCompactionConfig {
     }
 }
 
+impl From<BackgroundPurgeConfig> for InnerBackgroundPurgeConfig {
+    fn from(outer: BackgroundPurgeConfig) -> Self {
+        Self {
+            enable: outer.enable_purge,
+            params: BackgroundJobParams::new_interval_job(std::time::Duration::from_secs(
+                outer.purge_interval_secs,
+            )),
+        }
+    }
+}
+
+impl From<InnerBackgroundPurgeConfig> for BackgroundPurgeConfig {
+    fn from(inner: InnerBackgroundPurgeConfig) -> Self {
+        Self {
+            enable_purge: inner.enable,
+            purge_interval_secs: inner.params.scheduled_job_interval.as_secs(),
+        }
+    }
+}
+
 impl From<BackgroundJobParams> for BackgroundScheduledConfig {
     fn from(inner: BackgroundJobParams) -> Self {
         Self {
@@ -254,6 +299,15 @@ impl Debug for BackgroundCompactionConfig {
     }
 }
 
+impl Default for BackgroundPurgeConfig {
+    fn default() -> Self {
+        Self {
+            enable_purge: false,
+            purge_interval_secs: 3600,
+        }
+    }
+}
+
 impl Default for BackgroundScheduledConfig {
     fn default() -> Self {
         Self {
@@ -283,6 +337,7 @@ impl Default for InnerBackgroundConfig {
                 block_limit: None,
                 params: Default::default(),
             },
+            purge: BackgroundPurgeConfig::default().into(),
         }
     }
 }
@@ -291,6 +346,7 @@ impl Debug for InnerBackgroundConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("InnerBackgroundConfig")
             .field("compaction", &self.compaction)
+            .field("purge", &self.purge)
             .finish()
     }
 }
@@ -41,6 +41,7 @@ use common_meta_app::storage::StorageS3Config as InnerStorageS3Config;
 use common_meta_app::storage::StorageWebhdfsConfig as InnerStorageWebhdfsConfig;
 use common_meta_app::tenant::TenantQuota;
 use common_storage::StorageConfig as InnerStorageConfig;
+use common_tracing::AuditLogConfig as InnerAuditLogConfig;
 use common_tracing::Config as InnerLogConfig;
 use common_tracing::FileConfig as InnerFileLogConfig;
 use common_tracing::QueryLogConfig;
@@ -243,6 +244,15 @@ pub struct StorageConfig {
     #[clap(long = "storage-allow-insecure")]
     pub allow_insecure: bool,
 
+    /// Hex encoded 256-bit master key wrapping the data keys of encrypted tables.
+    #[clap(long = "storage-encryption-master-key", default_value_t)]
+    pub encryption_master_key: String,
+
+    /// Local file of master keys, one `<key id> <hex encoded key>` per line,
+    /// the last key is the current one.
+    #[clap(long = "storage-encryption-master-key-file", default_value_t)]
+    pub encryption_master_key_file: String,
+
     // Fs storage backend config.
     #[clap(flatten)]
     pub fs: FsStorageConfig,
@@ -292,6 +302,8 @@ impl From<InnerStorageConfig> for StorageConfig {
             storage_num_cpus: inner.num_cpus,
             typ: "".to_string(),
             allow_insecure: inner.allow_insecure,
+            encryption_master_key: inner.encryption_master_key,
+            encryption_master_key_file: inner.encryption_master_key_file,
             // use default for each config instead of using `..Default::default`
             // using `..Default::default` is calling `Self::default`
             // and `Self::default` relies on `InnerStorage::into()`
@@ -377,6 +389,8 @@ impl TryInto<InnerStorageConfig> for StorageConfig {
         Ok(InnerStorageConfig {
             num_cpus: self.storage_num_cpus,
             allow_insecure: self.allow_insecure,
+            encryption_master_key: self.encryption_master_key,
+            encryption_master_key_file: self.encryption_master_key_file,
             params: {
                 match self.typ.as_str() {
                     "azblob" => StorageParams::Azblob(self.azblob.try_into()?),
@@ -1683,6 +1697,9 @@ pub struct LogConfig {
 
     #[clap(flatten)]
     pub stderr: StderrLogConfig,
+
+    #[clap(flatten)]
+    pub audit: AuditLogConfig,
 }
 
 impl Default for LogConfig {
@@ -1730,6 +1747,7 @@ impl TryInto<InnerLogConfig> for LogConfig {
             file,
             stderr: self.stderr.try_into()?,
             query,
+            audit: self.audit.try_into()?,
             tracing,
         })
     }
@@ -1743,6 +1761,7 @@ impl From<InnerLogConfig> for LogConfig {
             query_enabled: false,
             file: inner.file.into(),
             stderr: inner.stderr.into(),
+            audit: inner.audit.into(),
 
             // Deprecated fields
             log_dir: None,
@@ -1850,6 +1869,84 @@ impl From<InnerStderrLogConfig> for StderrLogConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct AuditLogConfig {
+    /// Record DDL, DCL and data access statements in the audit log
+    #[clap(long = "log-audit-on")]
+    #[serde(rename = "on")]
+    pub audit_on: bool,
+
+    /// Audit log file dir, used by the `file` sink
+    #[clap(long = "log-audit-dir", default_value = "./.databend/logs/audit")]
+    #[serde(rename = "dir")]
+    pub audit_dir: String,
+
+    /// Comma separated audit sinks <file|table|tracing>
+    #[clap(long = "log-audit-sinks", default_value = "file")]
+    #[serde(rename = "sinks")]
+    pub audit_sinks: String,
+
+    /// The fuse table written by the `table` sink, as `database.table`
+    #[clap(long = "log-audit-table", default_value = "default.audit_log")]
+    #[serde(rename = "table")]
+    pub audit_table: String,
+}
+
+impl Default for AuditLogConfig {
+    fn default() -> Self {
+        InnerAuditLogConfig::default().into()
+    }
+}
+
+impl TryInto<InnerAuditLogConfig> for AuditLogConfig {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<InnerAuditLogConfig> {
+        let sinks = self
+            .audit_sinks
+            .split(',')
+            .map(|s| s.trim().to_lowercase())
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>();
+
+        for sink in &sinks {
+            if !InnerAuditLogConfig::SINKS.contains(&sink.as_str()) {
+                return Err(ErrorCode::InvalidConfig(format!(
+                    "unknown audit sink `{}`, expect one of {:?}",
+                    sink,
+                    InnerAuditLogConfig::SINKS
+                )));
+            }
+        }
+
+        if sinks.iter().any(|s| s == "table") && self.audit_table.split('.').count() != 2 {
+            return Err(ErrorCode::InvalidConfig(format!(
+                "invalid audit table `{}`, expect `database.table`",
+                self.audit_table
+            )));
+        }
+
+        Ok(InnerAuditLogConfig {
+            on: self.audit_on,
+            dir: self.audit_dir,
+            sinks,
+            table: self.audit_table,
+        })
+    }
+}
+
+impl From<InnerAuditLogConfig> for AuditLogConfig {
+    fn from(inner: InnerAuditLogConfig) -> Self {
+        Self {
+            audit_on: inner.on,
+            audit_dir: inner.dir,
+            audit_sinks: inner.sinks.join(","),
+            audit_table: inner.table,
+        }
+    }
+}
+
 /// Meta config group.
 /// deny_unknown_fields to check unknown field, like the deprecated `address`.
 /// TODO(xuanwo): All meta_xxx should be rename to xxx.
@@ -53,6 +53,7 @@ use log::warn;
 use crate::background_service::session::create_session;
 use crate::background_service::CompactionJob;
 use crate::background_service::JobScheduler;
+use crate::background_service::PurgeJob;
 
 pub struct RealBackgroundService {
     conf: InnerConfig,
@@ -149,6 +150,13 @@ impl RealBackgroundService {
                 user.identity(),
             )
             .await?;
+            Self::create_purge_job(
+                meta_api.clone(),
+                conf,
+                BackgroundJobParams::new_one_shot_job(),
+                user.identity(),
+            )
+            .await?;
             return Ok(None);
         }
 
@@ -169,6 +177,17 @@ impl RealBackgroundService {
             .await?;
             scheduler.add_job(compactor_job).await?;
         }
+        if conf.background.purge.enable {
+            let purge_job = RealBackgroundService::get_purge_job(
+                meta_api.clone(),
+                conf,
+                &user.identity(),
+                session.clone(),
+                scheduler.finish_tx.clone(),
+            )
+            .await?;
+            scheduler.add_job(purge_job).await?;
+        }
 
         let rm = RealBackgroundService {
             conf: conf.clone(),
@@ -224,6 +243,62 @@ impl RealBackgroundService {
         Ok(job)
     }
 
+    pub fn get_purge_job_name(tenant: String) -> String {
+        format!("{}-purge-job", tenant)
+    }
+
+    pub async fn create_purge_job(
+        meta: Arc<MetaStore>,
+        conf: &InnerConfig,
+        params: BackgroundJobParams,
+        creator: UserIdentity,
+    ) -> Result<BackgroundJobIdent> {
+        let name = RealBackgroundService::get_purge_job_name(conf.query.tenant_id.clone());
+        let id = BackgroundJobIdent {
+            tenant: conf.query.tenant_id.clone(),
+            name,
+        };
+        let info = BackgroundJobInfo::new_purge_job(params, creator);
+        meta.create_background_job(CreateBackgroundJobReq {
+            if_not_exists: true,
+            job_name: id.clone(),
+            job_info: info,
+        })
+        .await?;
+        Ok(id)
+    }
+
+    async fn get_purge_job(
+        meta: Arc<MetaStore>,
+        conf: &InnerConfig,
+        creator: &UserIdentity,
+        session: Arc<Session>,
+        finish_tx: Arc<Mutex<Sender<u64>>>,
+    ) -> Result<PurgeJob> {
+        let id = RealBackgroundService::create_purge_job(
+            meta.clone(),
+            conf,
+            conf.background.purge.params.clone(),
+            creator.clone(),
+        )
+        .await?;
+        let info = meta
+            .get_background_job(GetBackgroundJobReq { name: id.clone() })
+            .await?
+            .info;
+        if info.job_params.is_some() {
+            meta.update_background_job_params(UpdateBackgroundJobParamsReq {
+                job_name: id.clone(),
+                params: conf.background.purge.params.clone(),
+            })
+            .await?;
+        }
+        Self::suspend_job(meta.clone(), &id, false).await?;
+
+        let job = PurgeJob::create(conf, id.name, session, finish_tx).await;
+        Ok(job)
+    }
+
     async fn update_compaction_job_params(
         meta: Arc<MetaStore>,
         id: &BackgroundJobIdent,
@@ -16,6 +16,7 @@ mod background_service_handler;
 mod compaction_job;
 mod job;
 mod job_scheduler;
+mod purge_job;
 mod session;
 
 pub use background_service_handler::RealBackgroundService;
@@ -23,3 +24,4 @@ pub use compaction_job::should_continue_compaction;
 pub use compaction_job::CompactionJob;
 pub use job::Job;
 pub use job_scheduler::JobScheduler;
+pub use purge_job::PurgeJob;
@@ -0,0 +1,329 @@
+// Copyright 2023 Databend Cloud
+//
+// Licensed under the Elastic License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.elastic.co/licensing/elastic-license
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use arrow_array::LargeBinaryArray;
+use arrow_array::RecordBatch;
+use arrow_array::UInt64Array;
+use chrono::Duration;
+use chrono::Utc;
+use common_base::base::tokio::sync::mpsc::Sender;
+use common_base::base::tokio::sync::Mutex;
+use common_base::base::tokio::time::Instant;
+use common_base::base::uuid::Uuid;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_config::InnerConfig;
+use common_exception::Result;
+use common_meta_api::BackgroundApi;
+use common_meta_app::background::BackgroundJobIdent;
+use common_meta_app::background::BackgroundJobInfo;
+use common_meta_app::background::BackgroundJobParams;
+use common_meta_app::background::BackgroundJobStatus;
+use common_meta_app::background::BackgroundJobType::ONESHOT;
+use common_meta_app::background::BackgroundTaskIdent;
+use common_meta_app::background::BackgroundTaskInfo;
+use common_meta_app::background::BackgroundTaskState;
+use common_meta_app::background::GetBackgroundJobReq;
+use common_meta_app::background::ManualTriggerParams;
+use common_meta_app::background::UpdateBackgroundJobParamsReq;
+use common_meta_app::background::UpdateBackgroundJobStatusReq;
+use common_meta_app::background::UpdateBackgroundTaskReq;
+use common_meta_store::MetaStore;
+use common_storages_fuse::FuseTable;
+use common_users::UserApiProvider;
+use databend_query::procedures::admins::suggested_background_tasks::SuggestedBackgroundTasksProcedure;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::Session;
+use databend_query::sessions::TableContext;
+use log::as_debug;
+use log::debug;
+use log::error;
+use log::info;
+
+use crate::background_service::job::Job;
+
+const EXPIRE_SEC: u64 = 60 * 60 * 24 * 7; // 7 days
+
+/// Periodically purges the history of fuse tables which is older than
+/// their data retention period, see `FuseTable::get_data_retention_time_in_days`.
+///
+/// Each purged table is recorded as a `VACUUM` task in `system.background_tasks`.
+#[derive(Clone)]
+pub struct PurgeJob {
+    conf: InnerConfig,
+    meta_api: Arc<MetaStore>,
+    creator: BackgroundJobIdent,
+    session: Arc<Session>,
+
+    finish_tx: Arc<Mutex<Sender<u64>>>,
+}
+
+#[async_trait::async_trait]
+impl Job for PurgeJob {
+    async fn run(&mut self) {
+        info!(background = true, job_name = as_debug!(&self.creator.clone()); "Purge job started");
+        self.do_purge_job().await.expect("failed to do purge job");
+    }
+
+    async fn get_info(&self) -> Result<BackgroundJobInfo> {
+        let job = self
+            .meta_api
+            .get_background_job(GetBackgroundJobReq {
+                name: self.creator.clone(),
+            })
+            .await?;
+        Ok(job.info)
+    }
+
+    fn get_name(&self) -> BackgroundJobIdent {
+        self.creator.clone()
+    }
+
+    async fn update_job_status(&mut self, status: BackgroundJobStatus) -> Result<()> {
+        self.meta_api
+            .update_background_job_status(UpdateBackgroundJobStatusReq {
+                job_name: self.creator.clone(),
+                status: status.clone(),
+            })
+            .await?;
+        Ok(())
+    }
+
+    async fn update_job_params(&mut self, param: BackgroundJobParams) -> Result<()> {
+        self.meta_api
+            .update_background_job_params(UpdateBackgroundJobParamsReq {
+                job_name: self.creator.clone(),
+                params: param.clone(),
+            })
+            .await?;
+        Ok(())
+    }
+}
+
+impl PurgeJob {
+    pub async fn create(
+        config: &InnerConfig,
+        name: String,
+        session: Arc<Session>,
+        finish_tx: Arc<Mutex<Sender<u64>>>,
+    ) -> Self {
+        let tenant = config.query.tenant_id.clone();
+        let creator = BackgroundJobIdent { tenant, name };
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        Self {
+            conf: config.clone(),
+            meta_api,
+            creator,
+            session,
+            finish_tx,
+        }
+    }
+
+    async fn do_purge_job(&mut self) -> Result<()> {
+        let ctx = self.session.create_query_context().await?;
+        let job_info = self.get_info().await?;
+
+        let mut params = job_info.job_params.clone().unwrap();
+        let manual = params.manual_trigger_params.clone();
+        params.manual_trigger_params = None;
+        // guarantee at least once for manual job
+        self.update_job_params(params).await?;
+
+        for records in Self::do_get_target_tables(ctx).await? {
+            debug!(records = as_debug!(&records); "target_tables");
+            let db_names = records
+                .column(0)
+                .as_any()
+                .downcast_ref::<LargeBinaryArray>()
+                .unwrap();
+            let db_ids = records
+                .column(1)
+                .as_any()
+                .downcast_ref::<UInt64Array>()
+                .unwrap();
+            let tb_names = records
+                .column(2)
+                .as_any()
+                .downcast_ref::<LargeBinaryArray>()
+                .unwrap();
+            let tb_ids = records
+                .column(3)
+                .as_any()
+                .downcast_ref::<UInt64Array>()
+                .unwrap();
+            for i in 0..records.num_rows() {
+                let db_name = String::from_utf8_lossy(db_names.value(i)).to_string();
+                let tb_name = String::from_utf8_lossy(tb_names.value(i)).to_string();
+                if let Err(e) = self
+                    .purge_table(
+                        db_name.clone(),
+                        tb_name.clone(),
+                        db_ids.value(i),
+                        tb_ids.value(i),
+                        manual.clone(),
+                    )
+                    .await
+                {
+                    error!(
+                        "purge job failed, db: {}, table: {}, err: {}",
+                        db_name, tb_name, e
+                    );
+                }
+            }
+        }
+        info!(
+            job = "purge",
+            background = true;
+            "purge task is done"
+        );
+        if self.conf.background.purge.params.job_type == ONESHOT {
+            let finish_tx = self.finish_tx.clone();
+            let _ = finish_tx.lock().await.send(1).await;
+        }
+
+        Ok(())
+    }
+
+    async fn purge_table(
+        &mut self,
+        database: String,
+        table: String,
+        db_id: u64,
+        tb_id: u64,
+        manual: Option<ManualTriggerParams>,
+    ) -> Result<()> {
+        let ctx = self.session.create_query_context().await?;
+        let tbl = ctx.get_table(CATALOG_DEFAULT, &database, &table).await?;
+        let fuse_table = FuseTable::try_from_table(tbl.as_ref())?;
+        let days = match fuse_table
+            .get_data_retention_time_in_days(ctx.as_ref(), &database)
+            .await?
+        {
+            Some(days) => days,
+            None => {
+                debug!(job = "purge", background = true, database = database.clone(), table = table.clone(); "skip purge, no data retention period");
+                return Ok(());
+            }
+        };
+
+        let job_info = self.get_info().await?;
+        let id = Uuid::new_v4().to_string();
+        let mut job_status = job_info.job_status.clone().unwrap();
+        job_status.last_task_id = Some(id.clone());
+        job_status.last_task_run_at = Some(Utc::now());
+        job_status.next_task_scheduled_time = job_info
+            .job_params
+            .as_ref()
+            .unwrap()
+            .get_next_running_time(job_status.last_task_run_at.unwrap());
+        self.update_job_status(job_status).await?;
+
+        info!(job = "purge", background = true, id = id.clone(), database = database.clone(), table = table.clone(), data_retention_time_in_days = days; "start purge");
+        let task_name = BackgroundTaskIdent {
+            tenant: self.creator.tenant.clone(),
+            task_id: id,
+        };
+        let mut info = BackgroundTaskInfo::new_vacuum_task(
+            self.creator.clone(),
+            db_id,
+            tb_id,
+            days,
+            manual,
+            format!("purge history older than {} days", days),
+        );
+        self.update_task(&task_name, &info).await?;
+
+        let start = Instant::now();
+        let before = Self::do_count_snapshots(self.session.clone(), &database, &table).await?;
+        let sql = Self::get_purge_sql(&database, &table, days);
+        debug!(job = "purge", background = true, sql = sql.as_str(); "purge");
+        let ctx = self.session.create_query_context().await?;
+        match SuggestedBackgroundTasksProcedure::do_execute_sql(ctx, sql).await {
+            Ok(_) => {
+                let after =
+                    Self::do_count_snapshots(self.session.clone(), &database, &table).await?;
+                if let Some(stats) = info.vacuum_stats.as_mut() {
+                    stats.purged_snapshots = Some(before.saturating_sub(after));
+                    stats.total_vacuum_time = Some(start.elapsed());
+                }
+                info.task_state = BackgroundTaskState::DONE;
+            }
+            Err(e) => {
+                info.message = format!("purge failed: {:?}", e);
+                info.task_state = BackgroundTaskState::FAILED;
+            }
+        }
+        info.last_updated = Some(Utc::now());
+        self.update_task(&task_name, &info).await
+    }
+
+    async fn update_task(
+        &self,
+        name: &BackgroundTaskIdent,
+        info: &BackgroundTaskInfo,
+    ) -> Result<()> {
+        self.meta_api
+            .update_background_task(UpdateBackgroundTaskReq {
+                task_name: name.clone(),
+                task_info: info.clone(),
+                expire_at: Utc::now().timestamp() as u64 + EXPIRE_SEC,
+            })
+            .await?;
+        Ok(())
+    }
+
+    pub async fn do_get_target_tables(ctx: Arc<QueryContext>) -> Result<Vec<RecordBatch>> {
+        let sql = "
+        SELECT t.database as database, d.database_id as database_id, t.name as table, t.table_id as table_id
+        FROM system.tables as t
+        JOIN system.databases as d
+        ON t.database = d.name
+        WHERE t.database != 'system'
+            AND t.database != 'information_schema'
+            AND t.engine = 'FUSE'
+            ;
+        ";
+        let res = SuggestedBackgroundTasksProcedure::do_execute_sql(ctx, sql.to_string()).await?;
+        Ok(res.into_iter().collect())
+    }
+
+    async fn do_count_snapshots(session: Arc<Session>, database: &str, table: &str) -> Result<u64> {
+        let sql = format!(
+            "SELECT count(*) FROM fuse_snapshot('{}', '{}');",
+            database, table
+        );
+        let ctx = session.create_query_context().await?;
+        let res = SuggestedBackgroundTasksProcedure::do_execute_sql(ctx, sql).await?;
+        Ok(res
+            .map(|r| {
+                r.column(0)
+                    .as_any()
+                    .downcast_ref::<UInt64Array>()
+                    .unwrap()
+                    .value(0)
+            })
+            .unwrap_or_default())
+    }
+
+    pub fn get_purge_sql(database: &str, table: &str, days: u64) -> String {
+        let point = Utc::now() - Duration::days(days as i64);
+        format!(
+            "OPTIMIZE TABLE {}.{} PURGE BEFORE (TIMESTAMP => '{}'::TIMESTAMP);",
+            database,
+            table,
+            point.format("%Y-%m-%d %H:%M:%S%.6f")
+        )
+    }
+}
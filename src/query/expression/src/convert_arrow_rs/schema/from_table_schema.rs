@@ -26,9 +26,12 @@ use crate::types::NumberDataType;
 use crate::with_number_type;
 use crate::TableDataType;
 use crate::TableField;
+use crate::ARROW_EXT_TYPE_BINARY;
 use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
+use crate::ARROW_EXT_TYPE_TIMESTAMP_TZ;
 use crate::ARROW_EXT_TYPE_VARIANT;
 use crate::EXTENSION_KEY;
 
@@ -58,6 +61,21 @@ impl From<&TableField> for ArrowField {
             TableDataType::Bitmap => {
                 metadata.insert(EXTENSION_KEY.to_string(), ARROW_EXT_TYPE_BITMAP.to_string());
             }
+            TableDataType::Geometry => {
+                metadata.insert(
+                    EXTENSION_KEY.to_string(),
+                    ARROW_EXT_TYPE_GEOMETRY.to_string(),
+                );
+            }
+            TableDataType::TimestampTz => {
+                metadata.insert(
+                    EXTENSION_KEY.to_string(),
+                    ARROW_EXT_TYPE_TIMESTAMP_TZ.to_string(),
+                );
+            }
+            TableDataType::Binary => {
+                metadata.insert(EXTENSION_KEY.to_string(), ARROW_EXT_TYPE_BINARY.to_string());
+            }
             _ => Default::default(),
         };
         match ty {
@@ -134,6 +152,10 @@ impl From<&TableDataType> for ArrowDataType {
 
             TableDataType::Bitmap => ArrowDataType::LargeBinary,
             TableDataType::Variant => ArrowDataType::LargeBinary,
+            TableDataType::Geometry => ArrowDataType::LargeBinary,
+            TableDataType::TimestampTz => ArrowDataType::Decimal128(38, 0),
+            TableDataType::Time => ArrowDataType::Time64(TimeUnit::Microsecond),
+            TableDataType::Binary => ArrowDataType::LargeBinary,
         }
     }
 }
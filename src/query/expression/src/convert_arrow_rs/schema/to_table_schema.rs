@@ -23,9 +23,12 @@ use crate::types::NumberDataType;
 use crate::TableDataType;
 use crate::TableField;
 use crate::TableSchema;
+use crate::ARROW_EXT_TYPE_BINARY;
 use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
+use crate::ARROW_EXT_TYPE_TIMESTAMP_TZ;
 use crate::ARROW_EXT_TYPE_VARIANT;
 use crate::EXTENSION_KEY;
 
@@ -66,6 +69,9 @@ impl TryFrom<&ArrowField> for TableDataType {
             Some(ARROW_EXT_TYPE_EMPTY_MAP) => Some(TableDataType::EmptyMap),
             Some(ARROW_EXT_TYPE_VARIANT) => Some(TableDataType::Variant),
             Some(ARROW_EXT_TYPE_BITMAP) => Some(TableDataType::Bitmap),
+            Some(ARROW_EXT_TYPE_GEOMETRY) => Some(TableDataType::Geometry),
+            Some(ARROW_EXT_TYPE_TIMESTAMP_TZ) => Some(TableDataType::TimestampTz),
+            Some(ARROW_EXT_TYPE_BINARY) => Some(TableDataType::Binary),
             _ => None,
         };
 
@@ -95,10 +101,13 @@ impl TryFrom<&ArrowField> for TableDataType {
             ArrowDataType::Float64 => TableDataType::Number(NumberDataType::Float64),
             ArrowDataType::Timestamp(_unit, _tz) => TableDataType::Timestamp,
             ArrowDataType::Date32 | ArrowDataType::Date64 => TableDataType::Date,
-            ArrowDataType::Utf8
-            | ArrowDataType::LargeUtf8
-            | ArrowDataType::Binary
-            | ArrowDataType::LargeBinary => TableDataType::String,
+            ArrowDataType::Time32(_) | ArrowDataType::Time64(_) => TableDataType::Time,
+            ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 | ArrowDataType::LargeBinary => {
+                TableDataType::String
+            }
+            // Byte arrays without a string annotation are read as Binary. Strings written by
+            // databend are `LargeBinary` in the arrow schema kept in the file, so they stay String.
+            ArrowDataType::Binary => TableDataType::Binary,
             ArrowDataType::Decimal128(p, s) => {
                 TableDataType::Decimal(DecimalDataType::Decimal128(DecimalSize {
                     precision: *p,
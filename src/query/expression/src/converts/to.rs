@@ -15,6 +15,7 @@
 use common_datavalues::DataValue;
 use ordered_float::OrderedFloat;
 
+use crate::types::timestamp_tz::timestamp_tz_micros;
 use crate::Scalar;
 
 pub fn scalar_to_datavalue(scalar: &Scalar) -> DataValue {
@@ -37,9 +38,11 @@ pub fn scalar_to_datavalue(scalar: &Scalar) -> DataValue {
         },
         Scalar::Decimal(_) => unimplemented!("decimal type is not supported"),
         Scalar::Timestamp(x) => DataValue::Int64(*x),
+        Scalar::TimestampTz(x) => DataValue::Int64(timestamp_tz_micros(*x)),
+        Scalar::Time(x) => DataValue::Int64(*x),
         Scalar::Date(x) => DataValue::Int64(*x as i64),
         Scalar::Boolean(x) => DataValue::Boolean(*x),
-        Scalar::String(x) | Scalar::Variant(x) => DataValue::String(x.clone()),
+        Scalar::String(x) | Scalar::Variant(x) | Scalar::Binary(x) => DataValue::String(x.clone()),
         Scalar::Array(x) => {
             let values = (0..x.len())
                 .map(|idx| scalar_to_datavalue(&x.index(idx).unwrap().to_owned()))
@@ -50,6 +53,8 @@ pub fn scalar_to_datavalue(scalar: &Scalar) -> DataValue {
             let values = x.iter().map(scalar_to_datavalue).collect();
             DataValue::Struct(values)
         }
-        Scalar::EmptyMap | Scalar::Map(_) | Scalar::Bitmap(_) => unimplemented!(),
+        Scalar::EmptyMap | Scalar::Map(_) | Scalar::Bitmap(_) | Scalar::Geometry(_) => {
+            unimplemented!()
+        }
     }
 }
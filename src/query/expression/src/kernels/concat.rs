@@ -25,17 +25,21 @@ use crate::types::string::StringColumnBuilder;
 use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::ArrayType;
+use crate::types::BinaryType;
 use crate::types::BitmapType;
 use crate::types::BooleanType;
 use crate::types::DateType;
 use crate::types::EmptyArrayType;
 use crate::types::EmptyMapType;
+use crate::types::GeometryType;
 use crate::types::MapType;
 use crate::types::NullType;
 use crate::types::NullableType;
 use crate::types::NumberType;
 use crate::types::StringType;
+use crate::types::TimeType;
 use crate::types::TimestampType;
+use crate::types::TimestampTzType;
 use crate::types::ValueType;
 use crate::types::VariantType;
 use crate::with_decimal_type;
@@ -135,6 +139,14 @@ impl Column {
                 let builder = Vec::with_capacity(capacity);
                 Self::concat_value_types::<TimestampType>(builder, columns)
             }
+            Column::TimestampTz(_) => {
+                let builder = Vec::with_capacity(capacity);
+                Self::concat_value_types::<TimestampTzType>(builder, columns)
+            }
+            Column::Time(_) => {
+                let builder = Vec::with_capacity(capacity);
+                Self::concat_value_types::<TimeType>(builder, columns)
+            }
             Column::Date(_) => {
                 let builder = Vec::with_capacity(capacity);
                 Self::concat_value_types::<DateType>(builder, columns)
@@ -200,6 +212,16 @@ impl Column {
                 let builder = StringColumnBuilder::with_capacity(capacity, data_capacity);
                 Self::concat_value_types::<VariantType>(builder, columns)
             }
+            Column::Geometry(_) => {
+                let data_capacity = columns.iter().map(|c| c.memory_size() - c.len() * 8).sum();
+                let builder = StringColumnBuilder::with_capacity(capacity, data_capacity);
+                Self::concat_value_types::<GeometryType>(builder, columns)
+            }
+            Column::Binary(_) => {
+                let data_capacity = columns.iter().map(|c| c.memory_size() - c.len() * 8).sum();
+                let builder = StringColumnBuilder::with_capacity(capacity, data_capacity);
+                Self::concat_value_types::<BinaryType>(builder, columns)
+            }
         }
     }
 
@@ -30,6 +30,7 @@ use crate::types::string::StringColumnBuilder;
 use crate::types::AnyType;
 use crate::types::ArrayType;
 use crate::types::BooleanType;
+use crate::types::GeometryType;
 use crate::types::MapType;
 use crate::types::ValueType;
 use crate::types::VariantType;
@@ -127,6 +128,14 @@ impl Column {
                 let ts = Self::filter_primitive_types(column, filter);
                 Column::Timestamp(ts)
             }
+            Column::TimestampTz(column) => {
+                let ts = Self::filter_primitive_types(column, filter);
+                Column::TimestampTz(ts)
+            }
+            Column::Time(column) => {
+                let ts = Self::filter_primitive_types(column, filter);
+                Column::Time(ts)
+            }
             Column::Date(column) => {
                 let d = Self::filter_primitive_types(column, filter);
                 Column::Date(d)
@@ -187,6 +196,14 @@ impl Column {
                     filter,
                 )
             }
+            Column::Geometry(column) => {
+                let column = Self::filter_string_scalars(column, filter);
+                Column::Geometry(column)
+            }
+            Column::Binary(column) => {
+                let column = Self::filter_string_scalars(column, filter);
+                Column::Binary(column)
+            }
         }
     }
 
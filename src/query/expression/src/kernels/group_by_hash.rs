@@ -641,6 +641,8 @@ pub fn serialize_column_binary(column: &Column, row: usize, vec: &mut Vec<u8>) {
             })
         }
         Column::Timestamp(v) => vec.extend_from_slice(v[row].to_le_bytes().as_ref()),
+        Column::TimestampTz(v) => vec.extend_from_slice(v[row].to_le_bytes().as_ref()),
+        Column::Time(v) => vec.extend_from_slice(v[row].to_le_bytes().as_ref()),
         Column::Date(v) => vec.extend_from_slice(v[row].to_le_bytes().as_ref()),
         Column::Array(array) | Column::Map(array) => {
             let data = array.index(row).unwrap();
@@ -664,7 +666,7 @@ pub fn serialize_column_binary(column: &Column, row: usize, vec: &mut Vec<u8>) {
                 serialize_column_binary(inner_col, row, vec);
             }
         }
-        Column::Variant(v) => {
+        Column::Variant(v) | Column::Geometry(v) | Column::Binary(v) => {
             BinaryWrite::write_binary(vec, unsafe { v.index_unchecked(row) }).unwrap()
         }
     }
@@ -26,13 +26,17 @@ use crate::types::number::NumberColumn;
 use crate::types::string::StringColumnBuilder;
 use crate::types::AnyType;
 use crate::types::ArrayType;
+use crate::types::BinaryType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
+use crate::types::GeometryType;
 use crate::types::MapType;
 use crate::types::NumberType;
 use crate::types::StringType;
+use crate::types::TimeType;
 use crate::types::TimestampType;
+use crate::types::TimestampTzType;
 use crate::types::ValueType;
 use crate::types::VariantType;
 use crate::with_decimal_type;
@@ -181,6 +185,18 @@ impl Column {
                 indices,
                 scatter_size,
             ),
+            Column::TimestampTz(column) => Self::scatter_scalars::<TimestampTzType, _>(
+                column,
+                Vec::with_capacity(length),
+                indices,
+                scatter_size,
+            ),
+            Column::Time(column) => Self::scatter_scalars::<TimeType, _>(
+                column,
+                Vec::with_capacity(length),
+                indices,
+                scatter_size,
+            ),
             Column::Date(column) => Self::scatter_scalars::<DateType, _>(
                 column,
                 Vec::with_capacity(length),
@@ -270,6 +286,18 @@ impl Column {
                 indices,
                 scatter_size,
             ),
+            Column::Geometry(column) => Self::scatter_scalars::<GeometryType, _>(
+                column,
+                StringColumnBuilder::with_capacity(length, 0),
+                indices,
+                scatter_size,
+            ),
+            Column::Binary(column) => Self::scatter_scalars::<BinaryType, _>(
+                column,
+                StringColumnBuilder::with_capacity(length, 0),
+                indices,
+                scatter_size,
+            ),
         }
     }
 
@@ -25,10 +25,14 @@ use crate::types::number::NumberColumn;
 use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::ArrayType;
+use crate::types::BinaryType;
 use crate::types::BooleanType;
+use crate::types::GeometryType;
 use crate::types::MapType;
 use crate::types::NumberType;
 use crate::types::StringType;
+use crate::types::TimeType;
+use crate::types::TimestampTzType;
 use crate::types::ValueType;
 use crate::types::VariantType;
 use crate::with_decimal_type;
@@ -146,6 +150,12 @@ impl Column {
                 Column::Tuple(fields)
             }
             Column::Variant(column) => Self::take_arg_types::<VariantType, _>(column, indices),
+            Column::Geometry(column) => Self::take_arg_types::<GeometryType, _>(column, indices),
+            Column::Binary(column) => Self::take_arg_types::<BinaryType, _>(column, indices),
+            Column::TimestampTz(column) => {
+                Self::take_arg_types::<TimestampTzType, _>(column, indices)
+            }
+            Column::Time(column) => Self::take_arg_types::<TimeType, _>(column, indices),
         }
     }
 
@@ -24,13 +24,17 @@ use crate::types::number::NumberColumn;
 use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::ArrayType;
+use crate::types::BinaryType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
+use crate::types::GeometryType;
 use crate::types::MapType;
 use crate::types::NumberType;
 use crate::types::StringType;
+use crate::types::TimeType;
 use crate::types::TimestampType;
+use crate::types::TimestampTzType;
 use crate::types::ValueType;
 use crate::types::VariantType;
 use crate::with_decimal_type;
@@ -234,6 +238,14 @@ impl Column {
                 let builder = TimestampType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<TimestampType>(columns, builder, indices)
             }
+            Column::TimestampTz(_) => {
+                let builder = TimestampTzType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<TimestampTzType>(columns, builder, indices)
+            }
+            Column::Time(_) => {
+                let builder = TimeType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<TimeType>(columns, builder, indices)
+            }
             Column::Date(_) => {
                 let builder = DateType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<DateType>(columns, builder, indices)
@@ -331,6 +343,14 @@ impl Column {
                 let builder = VariantType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<VariantType>(columns, builder, indices)
             }
+            Column::Geometry(_) => {
+                let builder = GeometryType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<GeometryType>(columns, builder, indices)
+            }
+            Column::Binary(_) => {
+                let builder = BinaryType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<BinaryType>(columns, builder, indices)
+            }
         }
     }
 
@@ -26,10 +26,14 @@ use crate::types::number::NumberColumn;
 use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::ArrayType;
+use crate::types::BinaryType;
 use crate::types::BooleanType;
+use crate::types::GeometryType;
 use crate::types::MapType;
 use crate::types::NumberType;
 use crate::types::StringType;
+use crate::types::TimeType;
+use crate::types::TimestampTzType;
 use crate::types::ValueType;
 use crate::types::VariantType;
 use crate::with_number_mapped_type;
@@ -181,6 +185,18 @@ impl Column {
             Column::Variant(column) => {
                 Self::take_compacted_arg_types::<VariantType>(column, indices, row_num)
             }
+            Column::Geometry(column) => {
+                Self::take_compacted_arg_types::<GeometryType>(column, indices, row_num)
+            }
+            Column::Binary(column) => {
+                Self::take_compacted_arg_types::<BinaryType>(column, indices, row_num)
+            }
+            Column::TimestampTz(column) => {
+                Self::take_compacted_arg_types::<TimestampTzType>(column, indices, row_num)
+            }
+            Column::Time(column) => {
+                Self::take_compacted_arg_types::<TimeType>(column, indices, row_num)
+            }
         }
     }
 
@@ -18,6 +18,7 @@ use crate::types::boolean::BooleanDomain;
 use crate::types::decimal::Decimal128Type;
 use crate::types::decimal::Decimal256Type;
 use crate::types::decimal::DecimalDomain;
+use crate::types::geometry::GeometryDomain;
 use crate::types::nullable::NullableDomain;
 use crate::types::number::NumberDomain;
 use crate::types::number::NumberScalar;
@@ -31,10 +32,13 @@ use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
 use crate::types::DecimalDataType;
+use crate::types::GeometryType;
 use crate::types::NumberDataType;
 use crate::types::NumberType;
 use crate::types::StringType;
+use crate::types::TimeType;
 use crate::types::TimestampType;
+use crate::types::TimestampTzType;
 use crate::types::ValueType;
 use crate::with_decimal_type;
 use crate::with_number_type;
@@ -97,6 +101,8 @@ pub enum Domain {
     Boolean(BooleanDomain),
     String(StringDomain),
     Timestamp(SimpleDomain<i64>),
+    TimestampTz(SimpleDomain<i128>),
+    Time(SimpleDomain<i64>),
     Date(SimpleDomain<i32>),
     Nullable(NullableDomain<AnyType>),
     /// `Array(None)` means that the array is empty, thus there is no inner domain information.
@@ -104,6 +110,8 @@ pub enum Domain {
     /// `Map(None)` means that the map is empty, thus there is no inner domain information.
     Map(Option<(Box<Domain>, Box<Domain>)>),
     Tuple(Vec<Domain>),
+    /// The bounding box of the geometries.
+    Geometry(GeometryDomain),
     /// For certain types, like `Variant`, the domain is useless therefore is not defined.
     Undefined,
 }
@@ -175,6 +183,8 @@ impl Domain {
                 }
             },
             DataType::Timestamp => Domain::Timestamp(TimestampType::full_domain()),
+            DataType::TimestampTz => Domain::TimestampTz(TimestampTzType::full_domain()),
+            DataType::Time => Domain::Time(TimeType::full_domain()),
             DataType::Date => Domain::Date(DateType::full_domain()),
             DataType::Null => Domain::Nullable(NullableDomain {
                 has_null: true,
@@ -201,7 +211,8 @@ impl Domain {
                 };
                 Domain::Map(Some(inner_domain))
             }
-            DataType::Bitmap | DataType::Variant => Domain::Undefined,
+            DataType::Bitmap | DataType::Variant | DataType::Binary => Domain::Undefined,
+            DataType::Geometry => Domain::Geometry(GeometryType::full_domain()),
             DataType::Generic(_) => unreachable!(),
         }
     }
@@ -249,6 +260,16 @@ impl Domain {
                     max: this.max.max(other.max),
                 })
             }
+            (Domain::TimestampTz(this), Domain::TimestampTz(other)) => {
+                Domain::TimestampTz(SimpleDomain {
+                    min: this.min.min(other.min),
+                    max: this.max.max(other.max),
+                })
+            }
+            (Domain::Time(this), Domain::Time(other)) => Domain::Time(SimpleDomain {
+                min: this.min.min(other.min),
+                max: this.max.max(other.max),
+            }),
             (Domain::Date(this), Domain::Date(other)) => Domain::Date(SimpleDomain {
                 min: this.min.min(other.min),
                 max: this.max.max(other.max),
@@ -328,6 +349,9 @@ impl Domain {
                     .map(|(self_tup, other_tup)| self_tup.merge(other_tup))
                     .collect(),
             ),
+            (Domain::Geometry(this), Domain::Geometry(other)) => {
+                Domain::Geometry(this.merge(other))
+            }
             (Domain::Undefined, Domain::Undefined) => Domain::Undefined,
             (this, other) => unreachable!("unable to merge {this:?} with {other:?}"),
         }
@@ -383,6 +407,10 @@ impl Domain {
             Domain::Timestamp(SimpleDomain { min, max }) if min == max => {
                 Some(Scalar::Timestamp(*min))
             }
+            Domain::TimestampTz(SimpleDomain { min, max }) if min == max => {
+                Some(Scalar::TimestampTz(*min))
+            }
+            Domain::Time(SimpleDomain { min, max }) if min == max => Some(Scalar::Time(*min)),
             Domain::Date(SimpleDomain { min, max }) if min == max => Some(Scalar::Date(*min)),
             Domain::Nullable(NullableDomain {
                 has_null: true,
@@ -62,6 +62,7 @@ impl RowConverter {
             | DataType::EmptyMap
             | DataType::Map(_)
             | DataType::Bitmap
+            | DataType::Geometry
             | DataType::Tuple(_)
             | DataType::Generic(_) => false,
             DataType::Nullable(inner) => Self::support_data_type(inner.as_ref()),
@@ -120,6 +121,12 @@ impl RowConverter {
                 DataType::Timestamp => lengths
                     .iter_mut()
                     .for_each(|x| *x += i64::ENCODED_LEN as u64),
+                DataType::TimestampTz => lengths
+                    .iter_mut()
+                    .for_each(|x| *x += i128::ENCODED_LEN as u64),
+                DataType::Time => lengths
+                    .iter_mut()
+                    .for_each(|x| *x += i64::ENCODED_LEN as u64),
                 DataType::Date => lengths
                     .iter_mut()
                     .for_each(|x| *x += i32::ENCODED_LEN as u64),
@@ -169,6 +176,29 @@ impl RowConverter {
                             })
                     }
                 }
+                DataType::Binary => {
+                    let col = col.remove_nullable();
+                    if all_null {
+                        lengths.iter_mut().for_each(|x| *x += 1)
+                    } else if let Some(validity) = validity {
+                        col.as_binary()
+                            .unwrap()
+                            .iter()
+                            .zip(validity.iter())
+                            .zip(lengths.iter_mut())
+                            .for_each(|((bytes, v), length)| {
+                                *length += variable::encoded_len(bytes, !v) as u64
+                            })
+                    } else {
+                        col.as_binary()
+                            .unwrap()
+                            .iter()
+                            .zip(lengths.iter_mut())
+                            .for_each(|(bytes, length)| {
+                                *length += variable::encoded_len(bytes, false) as u64
+                            })
+                    }
+                }
                 _ => unimplemented!(),
             }
         }
@@ -230,9 +260,12 @@ fn encode_column(out: &mut StringColumnBuilder, column: &Column, asc: bool, null
             })
         }
         Column::Timestamp(col) => fixed::encode(out, col, validity, asc, nulls_first),
+        Column::TimestampTz(col) => fixed::encode(out, col, validity, asc, nulls_first),
+        Column::Time(col) => fixed::encode(out, col, validity, asc, nulls_first),
         Column::Date(col) => fixed::encode(out, col, validity, asc, nulls_first),
         Column::String(col) => variable::encode(out, col.iter(), validity, asc, nulls_first),
         Column::Variant(col) => variable::encode(out, col.iter(), validity, asc, nulls_first),
+        Column::Binary(col) => variable::encode(out, col.iter(), validity, asc, nulls_first),
         _ => unimplemented!(),
     }
 }
@@ -34,9 +34,12 @@ use crate::types::DataType;
 use crate::types::NumberDataType;
 use crate::with_number_type;
 use crate::Scalar;
+use crate::ARROW_EXT_TYPE_BINARY;
 use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
+use crate::ARROW_EXT_TYPE_TIMESTAMP_TZ;
 use crate::ARROW_EXT_TYPE_VARIANT;
 
 // Column id of TableField
@@ -48,15 +51,17 @@ pub const ROW_ID_COLUMN_ID: u32 = u32::MAX;
 pub const BLOCK_NAME_COLUMN_ID: u32 = u32::MAX - 1;
 pub const SEGMENT_NAME_COLUMN_ID: u32 = u32::MAX - 2;
 pub const SNAPSHOT_NAME_COLUMN_ID: u32 = u32::MAX - 3;
+pub const SEARCH_SCORE_COLUMN_ID: u32 = u32::MAX - 4;
 
 pub const ROW_ID_COL_NAME: &str = "_row_id";
 pub const SNAPSHOT_NAME_COL_NAME: &str = "_snapshot_name";
 pub const SEGMENT_NAME_COL_NAME: &str = "_segment_name";
 pub const BLOCK_NAME_COL_NAME: &str = "_block_name";
+pub const SEARCH_SCORE_COL_NAME: &str = "_search_score";
 
 #[inline]
 pub fn is_internal_column_id(column_id: ColumnId) -> bool {
-    column_id >= SNAPSHOT_NAME_COLUMN_ID
+    column_id >= SEARCH_SCORE_COLUMN_ID
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
@@ -134,6 +139,10 @@ pub enum TableDataType {
         fields_type: Vec<TableDataType>,
     },
     Variant,
+    Geometry,
+    TimestampTz,
+    Time,
+    Binary,
 }
 
 impl DataSchema {
@@ -1074,6 +1083,9 @@ impl From<&TableDataType> for DataType {
             TableDataType::Number(ty) => DataType::Number(*ty),
             TableDataType::Decimal(ty) => DataType::Decimal(*ty),
             TableDataType::Timestamp => DataType::Timestamp,
+            TableDataType::TimestampTz => DataType::TimestampTz,
+            TableDataType::Time => DataType::Time,
+            TableDataType::Binary => DataType::Binary,
             TableDataType::Date => DataType::Date,
             TableDataType::Nullable(ty) => DataType::Nullable(Box::new((&**ty).into())),
             TableDataType::Array(ty) => DataType::Array(Box::new((&**ty).into())),
@@ -1083,6 +1095,7 @@ impl From<&TableDataType> for DataType {
                 DataType::Tuple(fields_type.iter().map(Into::into).collect())
             }
             TableDataType::Variant => DataType::Variant,
+            TableDataType::Geometry => DataType::Geometry,
         }
     }
 }
@@ -1323,6 +1336,7 @@ impl From<&ArrowField> for TableDataType {
 
             ArrowDataType::Timestamp(_, _) => TableDataType::Timestamp,
             ArrowDataType::Date32 | ArrowDataType::Date64 => TableDataType::Date,
+            ArrowDataType::Time32(_) | ArrowDataType::Time64(_) => TableDataType::Time,
             ArrowDataType::Map(f, _) => {
                 let inner_ty = f.as_ref().into();
                 TableDataType::Map(Box::new(inner_ty))
@@ -1340,6 +1354,9 @@ impl From<&ArrowField> for TableDataType {
                 ARROW_EXT_TYPE_EMPTY_ARRAY => TableDataType::EmptyArray,
                 ARROW_EXT_TYPE_EMPTY_MAP => TableDataType::EmptyMap,
                 ARROW_EXT_TYPE_BITMAP => TableDataType::Bitmap,
+                ARROW_EXT_TYPE_GEOMETRY => TableDataType::Geometry,
+                ARROW_EXT_TYPE_TIMESTAMP_TZ => TableDataType::TimestampTz,
+                ARROW_EXT_TYPE_BINARY => TableDataType::Binary,
                 _ => unimplemented!("data_type: {:?}", f.data_type()),
             },
             // this is safe, because we define the datatype firstly
@@ -1425,6 +1442,12 @@ impl From<&DataType> for ArrowDataType {
                 ArrowDataType::Decimal256(s.precision.into(), s.scale.into())
             }
             DataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
+            DataType::TimestampTz => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_TIMESTAMP_TZ.to_string(),
+                Box::new(ArrowDataType::Decimal(38, 0)),
+                None,
+            ),
+            DataType::Time => ArrowDataType::Time64(TimeUnit::Microsecond),
             DataType::Date => ArrowDataType::Date32,
             DataType::Nullable(ty) => ty.as_ref().into(),
             DataType::Array(ty) => {
@@ -1473,6 +1496,16 @@ impl From<&DataType> for ArrowDataType {
                 Box::new(ArrowDataType::LargeBinary),
                 None,
             ),
+            DataType::Geometry => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_GEOMETRY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
+            DataType::Binary => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_BINARY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
 
             _ => unreachable!(),
         }
@@ -1505,6 +1538,12 @@ impl From<&TableDataType> for ArrowDataType {
                 ArrowDataType::Decimal256(size.precision as usize, size.scale as usize)
             }
             TableDataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
+            TableDataType::TimestampTz => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_TIMESTAMP_TZ.to_string(),
+                Box::new(ArrowDataType::Decimal(38, 0)),
+                None,
+            ),
+            TableDataType::Time => ArrowDataType::Time64(TimeUnit::Microsecond),
             TableDataType::Date => ArrowDataType::Date32,
             TableDataType::Nullable(ty) => ty.as_ref().into(),
             TableDataType::Array(ty) => {
@@ -1557,6 +1596,16 @@ impl From<&TableDataType> for ArrowDataType {
                 Box::new(ArrowDataType::LargeBinary),
                 None,
             ),
+            TableDataType::Geometry => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_GEOMETRY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
+            TableDataType::Binary => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_BINARY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
         }
     }
 }
@@ -1577,6 +1626,9 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
         DataType::String => Ok(TableDataType::String),
         DataType::Number(number_type) => Ok(TableDataType::Number(*number_type)),
         DataType::Timestamp => Ok(TableDataType::Timestamp),
+        DataType::TimestampTz => Ok(TableDataType::TimestampTz),
+        DataType::Time => Ok(TableDataType::Time),
+        DataType::Binary => Ok(TableDataType::Binary),
         DataType::Decimal(x) => Ok(TableDataType::Decimal(*x)),
         DataType::Date => Ok(TableDataType::Date),
         DataType::Nullable(inner_type) => Ok(TableDataType::Nullable(Box::new(infer_schema_type(
@@ -1590,6 +1642,7 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
         }
         DataType::Bitmap => Ok(TableDataType::Bitmap),
         DataType::Variant => Ok(TableDataType::Variant),
+        DataType::Geometry => Ok(TableDataType::Geometry),
         DataType::Tuple(fields) => {
             let fields_type = fields
                 .iter()
@@ -667,6 +667,8 @@ pub fn common_super_type(
 pub fn get_simple_cast_function(is_try: bool, dest_type: &DataType) -> Option<String> {
     let function_name = if dest_type.is_decimal() {
         "to_decimal".to_owned()
+    } else if dest_type == &DataType::TimestampTz {
+        "to_timestamp_tz".to_owned()
     } else {
         format!("to_{}", dest_type.to_string().to_lowercase())
     };
@@ -693,9 +695,13 @@ pub const ALL_SIMPLE_CAST_FUNCTIONS: &[&str] = &[
     "to_float64",
     "to_timestamp",
     "to_date",
+    "to_timestamp_tz",
+    "to_time",
+    "to_binary",
     "to_variant",
     "to_boolean",
     "to_decimal",
+    "to_geometry",
 ];
 
 pub fn is_simple_cast_function(name: &str) -> bool {
@@ -14,6 +14,7 @@
 
 pub mod any;
 pub mod array;
+pub mod binary;
 pub mod bitmap;
 pub mod boolean;
 pub mod date;
@@ -21,13 +22,16 @@ pub mod decimal;
 pub mod empty_array;
 pub mod empty_map;
 pub mod generic;
+pub mod geometry;
 pub mod map;
 pub mod null;
 pub mod nullable;
 pub mod number;
 pub mod number_class;
 pub mod string;
+pub mod time;
 pub mod timestamp;
+pub mod timestamp_tz;
 pub mod variant;
 
 use std::fmt::Debug;
@@ -40,6 +44,7 @@ use serde::Serialize;
 
 pub use self::any::AnyType;
 pub use self::array::ArrayType;
+pub use self::binary::BinaryType;
 pub use self::bitmap::BitmapType;
 pub use self::boolean::BooleanType;
 pub use self::date::DateType;
@@ -48,13 +53,16 @@ pub use self::decimal::DecimalSize;
 pub use self::empty_array::EmptyArrayType;
 pub use self::empty_map::EmptyMapType;
 pub use self::generic::GenericType;
+pub use self::geometry::GeometryType;
 pub use self::map::MapType;
 pub use self::null::NullType;
 pub use self::nullable::NullableType;
 pub use self::number::*;
 pub use self::number_class::*;
 pub use self::string::StringType;
+pub use self::time::TimeType;
 pub use self::timestamp::TimestampType;
+pub use self::timestamp_tz::TimestampTzType;
 pub use self::variant::VariantType;
 use crate::property::Domain;
 use crate::values::Column;
@@ -81,6 +89,9 @@ pub enum DataType {
     Bitmap,
     Tuple(Vec<DataType>),
     Variant,
+    Geometry,
+    TimestampTz,
+    Time,
     Generic(usize),
 }
 
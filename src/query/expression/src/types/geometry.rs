@@ -0,0 +1,358 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+
+use geo::BoundingRect;
+use geo::Geometry;
+use geozero::geojson::GeoJson;
+use geozero::wkb::Ewkb;
+use geozero::wkt::WktStr;
+use geozero::CoordDimensions;
+use geozero::ToGeo;
+use geozero::ToJson;
+use geozero::ToWkb;
+use geozero::ToWkt;
+
+use crate::property::Domain;
+use crate::types::string::StringColumn;
+use crate::types::string::StringColumnBuilder;
+use crate::types::string::StringIterator;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+/// EWKB of `GEOMETRYCOLLECTION EMPTY`, used as the default value of geometry.
+pub const GEOMETRY_EMPTY: &[u8] = &[1, 7, 0, 0, 0, 0, 0, 0, 0];
+
+/// Geometries are stored as EWKB, that is WKB with an optional SRID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeometryType;
+
+/// The bounding box of the geometries.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeometryDomain {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+impl GeometryDomain {
+    pub fn merge(&self, other: &GeometryDomain) -> GeometryDomain {
+        GeometryDomain {
+            min_x: self.min_x.min(other.min_x),
+            min_y: self.min_y.min(other.min_y),
+            max_x: self.max_x.max(other.max_x),
+            max_y: self.max_y.max(other.max_y),
+        }
+    }
+
+    pub fn intersects(&self, other: &GeometryDomain) -> bool {
+        self.min_x <= other.max_x
+            && other.min_x <= self.max_x
+            && self.min_y <= other.max_y
+            && other.min_y <= self.max_y
+    }
+
+    pub fn contains(&self, other: &GeometryDomain) -> bool {
+        self.min_x <= other.min_x
+            && self.min_y <= other.min_y
+            && other.max_x <= self.max_x
+            && other.max_y <= self.max_y
+    }
+
+    /// The bounding box is kept in the column statistics as two points, the lower left
+    /// corner as min and the upper right corner as max.
+    pub fn to_statistics(&self) -> Result<(Scalar, Scalar), String> {
+        let min = geometry_to_ewkb(
+            &Geometry::Point(geo::point!(x: self.min_x, y: self.min_y)),
+            None,
+        )?;
+        let max = geometry_to_ewkb(
+            &Geometry::Point(geo::point!(x: self.max_x, y: self.max_y)),
+            None,
+        )?;
+        Ok((Scalar::Geometry(min), Scalar::Geometry(max)))
+    }
+
+    pub fn from_statistics(min: &[u8], max: &[u8]) -> Option<GeometryDomain> {
+        let min = geometry_bbox(min)?;
+        let max = geometry_bbox(max)?;
+        Some(GeometryDomain {
+            min_x: min.min_x,
+            min_y: min.min_y,
+            max_x: max.max_x,
+            max_y: max.max_y,
+        })
+    }
+}
+
+impl ValueType for GeometryType {
+    type Scalar = Vec<u8>;
+    type ScalarRef<'a> = &'a [u8];
+    type Column = StringColumn;
+    type Domain = GeometryDomain;
+    type ColumnIterator<'a> = StringIterator<'a>;
+    type ColumnBuilder = StringColumnBuilder;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: Self::ScalarRef<'long>) -> Self::ScalarRef<'short> {
+        long
+    }
+
+    fn to_owned_scalar<'a>(scalar: Self::ScalarRef<'a>) -> Self::Scalar {
+        scalar.to_vec()
+    }
+
+    fn to_scalar_ref<'a>(scalar: &'a Self::Scalar) -> Self::ScalarRef<'a> {
+        scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        scalar.as_geometry().cloned()
+    }
+
+    fn try_downcast_column<'a>(col: &'a Column) -> Option<Self::Column> {
+        col.as_geometry().cloned()
+    }
+
+    fn try_downcast_builder<'a>(
+        builder: &'a mut ColumnBuilder,
+    ) -> Option<&'a mut Self::ColumnBuilder> {
+        match builder {
+            crate::ColumnBuilder::Geometry(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<Self::Domain> {
+        domain.as_geometry().cloned()
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Geometry(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Geometry(col)
+    }
+
+    fn upcast_domain(domain: Self::Domain) -> Domain {
+        Domain::Geometry(domain)
+    }
+
+    fn column_len<'a>(col: &'a Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column<'a>(col: &'a Self::Column, index: usize) -> Option<Self::ScalarRef<'a>> {
+        col.index(index)
+    }
+
+    unsafe fn index_column_unchecked<'a>(
+        col: &'a Self::Column,
+        index: usize,
+    ) -> Self::ScalarRef<'a> {
+        col.index_unchecked(index)
+    }
+
+    fn slice_column<'a>(col: &'a Self::Column, range: Range<usize>) -> Self::Column {
+        col.slice(range)
+    }
+
+    fn iter_column<'a>(col: &'a Self::Column) -> Self::ColumnIterator<'a> {
+        col.iter()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        StringColumnBuilder::from_column(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::ScalarRef<'_>) {
+        builder.put_slice(item);
+        builder.commit_row();
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.put_slice(GEOMETRY_EMPTY);
+        builder.commit_row();
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.append_column(other)
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.build()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        builder.build_scalar()
+    }
+
+    fn scalar_memory_size<'a>(scalar: &Self::ScalarRef<'a>) -> usize {
+        scalar.len()
+    }
+
+    fn column_memory_size(col: &Self::Column) -> usize {
+        col.data().len() + col.offsets().len() * 8
+    }
+}
+
+impl ArgType for GeometryType {
+    fn data_type() -> DataType {
+        DataType::Geometry
+    }
+
+    fn full_domain() -> Self::Domain {
+        GeometryDomain {
+            min_x: f64::NEG_INFINITY,
+            min_y: f64::NEG_INFINITY,
+            max_x: f64::INFINITY,
+            max_y: f64::INFINITY,
+        }
+    }
+
+    fn create_builder(capacity: usize, _: &GenericMap) -> Self::ColumnBuilder {
+        StringColumnBuilder::with_capacity(capacity, 0)
+    }
+}
+
+/// Parse a geometry from WKT, EWKT (`SRID=4326;POINT(1 2)`), GeoJSON or hex encoded
+/// WKB/EWKB, returns the EWKB of the geometry.
+pub fn parse_geometry(buf: &[u8]) -> Result<Vec<u8>, String> {
+    let s = std::str::from_utf8(buf)
+        .map_err(|e| format!("invalid geometry: {e}"))?
+        .trim();
+    if s.starts_with('{') {
+        let geo = GeoJson(s).to_geo().map_err(geometry_error)?;
+        return geometry_to_ewkb(&geo, None);
+    }
+    if s.len() >= 2 && s.len() % 2 == 0 && s.bytes().all(|b| b.is_ascii_hexdigit()) {
+        let ewkb = hex::decode(s).map_err(|e| format!("invalid geometry: {e}"))?;
+        let (geo, srid) = ewkb_to_geometry(&ewkb)?;
+        return geometry_to_ewkb(&geo, srid);
+    }
+    let (srid, wkt) = match s.get(..5) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("SRID=") => {
+            let (srid, wkt) = s[5..]
+                .split_once(';')
+                .ok_or_else(|| format!("invalid EWKT: {s}"))?;
+            let srid = srid
+                .trim()
+                .parse::<i32>()
+                .map_err(|e| format!("invalid SRID of EWKT {s}: {e}"))?;
+            (Some(srid), wkt)
+        }
+        _ => (None, s),
+    };
+    let geo = WktStr(wkt).to_geo().map_err(geometry_error)?;
+    geometry_to_ewkb(&geo, srid)
+}
+
+pub fn geometry_to_ewkb(geo: &Geometry<f64>, srid: Option<i32>) -> Result<Vec<u8>, String> {
+    geo.to_ewkb(CoordDimensions::xy(), srid)
+        .map_err(geometry_error)
+}
+
+/// Decode the geometry and the SRID from EWKB.
+pub fn ewkb_to_geometry(ewkb: &[u8]) -> Result<(Geometry<f64>, Option<i32>), String> {
+    let geo = Ewkb(ewkb).to_geo().map_err(geometry_error)?;
+    Ok((geo, read_srid(ewkb)))
+}
+
+/// Read the SRID from the header of EWKB: byte order, type with the SRID flag, SRID.
+pub fn read_srid(ewkb: &[u8]) -> Option<i32> {
+    const EWKB_SRID_FLAG: u32 = 0x20000000;
+    if ewkb.len() < 9 {
+        return None;
+    }
+    let little_endian = ewkb[0] == 1;
+    let read_u32 = |bytes: &[u8]| {
+        let bytes: [u8; 4] = bytes.try_into().unwrap();
+        if little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        }
+    };
+    if read_u32(&ewkb[1..5]) & EWKB_SRID_FLAG == 0 {
+        return None;
+    }
+    Some(read_u32(&ewkb[5..9]) as i32)
+}
+
+/// Format the geometry as EWKT if it has a SRID, otherwise as WKT.
+pub fn geometry_to_ewkt(ewkb: &[u8]) -> Result<String, String> {
+    let (geo, srid) = ewkb_to_geometry(ewkb)?;
+    let wkt = geo.to_wkt().map_err(geometry_error)?;
+    match srid {
+        Some(srid) => Ok(format!("SRID={srid};{wkt}")),
+        None => Ok(wkt),
+    }
+}
+
+/// Format the geometry for output, falls back to the hex encoded bytes if the
+/// geometry can't be decoded.
+pub fn display_geometry(ewkb: &[u8]) -> String {
+    geometry_to_ewkt(ewkb).unwrap_or_else(|_| hex::encode_upper(ewkb))
+}
+
+pub fn geometry_to_wkt(ewkb: &[u8]) -> Result<String, String> {
+    let (geo, _) = ewkb_to_geometry(ewkb)?;
+    geo.to_wkt().map_err(geometry_error)
+}
+
+pub fn geometry_to_wkb(ewkb: &[u8]) -> Result<Vec<u8>, String> {
+    let (geo, _) = ewkb_to_geometry(ewkb)?;
+    geo.to_wkb(CoordDimensions::xy()).map_err(geometry_error)
+}
+
+pub fn geometry_to_geojson(ewkb: &[u8]) -> Result<String, String> {
+    let (geo, _) = ewkb_to_geometry(ewkb)?;
+    geo.to_json().map_err(geometry_error)
+}
+
+/// The bounding box of the geometry, None if the geometry is empty or invalid.
+pub fn geometry_bbox(ewkb: &[u8]) -> Option<GeometryDomain> {
+    let (geo, _) = ewkb_to_geometry(ewkb).ok()?;
+    let rect = geo.bounding_rect()?;
+    Some(GeometryDomain {
+        min_x: rect.min().x,
+        min_y: rect.min().y,
+        max_x: rect.max().x,
+        max_y: rect.max().y,
+    })
+}
+
+/// The bounding box of all the geometries in the column, None if all of them are empty.
+pub fn column_bbox(col: &StringColumn) -> Option<GeometryDomain> {
+    col.iter()
+        .filter_map(geometry_bbox)
+        .reduce(|a, b| a.merge(&b))
+}
+
+fn geometry_error(e: geozero::error::GeozeroError) -> String {
+    format!("invalid geometry: {e}")
+}
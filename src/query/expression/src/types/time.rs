@@ -0,0 +1,227 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::ops::Range;
+
+use chrono::NaiveTime;
+use chrono::Timelike;
+use common_arrow::arrow::buffer::Buffer;
+
+use super::number::SimpleDomain;
+use super::timestamp::MICROS_IN_A_SEC;
+use crate::property::Domain;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+pub const TIME_FORMAT: &str = "%H:%M:%S%.6f";
+/// Minimum valid time, 00:00:00.000000.
+pub const TIME_MIN: i64 = 0;
+/// Maximum valid time, 23:59:59.999999.
+pub const TIME_MAX: i64 = MICROS_IN_A_DAY - 1;
+
+pub const MICROS_IN_A_DAY: i64 = 24 * 3600 * MICROS_IN_A_SEC;
+
+/// Check if the time value is valid.
+#[inline]
+pub fn check_time(micros: i64) -> Result<i64, String> {
+    if (TIME_MIN..=TIME_MAX).contains(&micros) {
+        Ok(micros)
+    } else {
+        Err("time is out of range".to_string())
+    }
+}
+
+/// A time of day without date and time zone, stored as the microsecs since midnight.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeType;
+
+impl ValueType for TimeType {
+    type Scalar = i64;
+    type ScalarRef<'a> = i64;
+    type Column = Buffer<i64>;
+    type Domain = SimpleDomain<i64>;
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, i64>>;
+    type ColumnBuilder = Vec<i64>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: i64) -> i64 {
+        long
+    }
+
+    fn to_owned_scalar<'a>(scalar: Self::ScalarRef<'a>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref<'a>(scalar: &'a Self::Scalar) -> Self::ScalarRef<'a> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::Time(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column<'a>(col: &'a Column) -> Option<Self::Column> {
+        match col {
+            Column::Time(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<SimpleDomain<i64>> {
+        domain.as_time().map(SimpleDomain::clone)
+    }
+
+    fn try_downcast_builder<'a>(
+        builder: &'a mut ColumnBuilder,
+    ) -> Option<&'a mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Time(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Time(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Time(col)
+    }
+
+    fn upcast_domain(domain: SimpleDomain<i64>) -> Domain {
+        Domain::Time(domain)
+    }
+
+    fn column_len<'a>(col: &'a Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column<'a>(col: &'a Self::Column, index: usize) -> Option<Self::ScalarRef<'a>> {
+        col.get(index).cloned()
+    }
+
+    unsafe fn index_column_unchecked<'a>(
+        col: &'a Self::Column,
+        index: usize,
+    ) -> Self::ScalarRef<'a> {
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column<'a>(col: &'a Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column<'a>(col: &'a Self::Column) -> Self::ColumnIterator<'a> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+}
+
+impl ArgType for TimeType {
+    fn data_type() -> DataType {
+        DataType::Time
+    }
+
+    fn full_domain() -> Self::Domain {
+        SimpleDomain {
+            min: TIME_MIN,
+            max: TIME_MAX,
+        }
+    }
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
+
+pub fn naive_time_to_micros(time: &NaiveTime) -> i64 {
+    time.num_seconds_from_midnight() as i64 * MICROS_IN_A_SEC + time.nanosecond() as i64 / 1_000
+}
+
+pub fn micros_to_naive_time(micros: i64) -> NaiveTime {
+    let micros = micros.rem_euclid(MICROS_IN_A_DAY);
+    NaiveTime::from_num_seconds_from_midnight_opt(
+        (micros / MICROS_IN_A_SEC) as u32,
+        (micros % MICROS_IN_A_SEC) as u32 * 1_000,
+    )
+    .unwrap()
+}
+
+/// Parse a time like `12:34:56.789`, `12:34:56` or `12:34`.
+pub fn string_to_time(time_str: impl AsRef<[u8]>) -> Option<i64> {
+    let s = std::str::from_utf8(time_str.as_ref()).ok()?.trim();
+    NaiveTime::parse_from_str(s, "%H:%M:%S%.f")
+        .or_else(|_| NaiveTime::parse_from_str(s, "%H:%M"))
+        .ok()
+        .map(|time| naive_time_to_micros(&time))
+}
+
+#[inline]
+pub fn time_to_string(micros: i64) -> impl Display {
+    micros_to_naive_time(micros).format(TIME_FORMAT)
+}
@@ -0,0 +1,254 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::ops::Range;
+
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::Offset;
+use chrono::TimeZone;
+use chrono_tz::Tz;
+use common_arrow::arrow::buffer::Buffer;
+
+use super::number::SimpleDomain;
+use super::timestamp::string_to_timestamp;
+use super::timestamp::TIMESTAMP_FORMAT;
+use super::timestamp::TIMESTAMP_MAX;
+use super::timestamp::TIMESTAMP_MIN;
+use crate::property::Domain;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::utils::date_helper::DateConverter;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+pub const TIMESTAMP_TZ_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.6f %z";
+
+/// A timestamp with time zone is stored as an `i128`, the high 64 bits are the microsecs
+/// offset from 1970-01-01 00:00:00 UTC, the low 32 bits are the offset of the time zone
+/// in seconds. So the values are ordered by the instant first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimestampTzType;
+
+impl ValueType for TimestampTzType {
+    type Scalar = i128;
+    type ScalarRef<'a> = i128;
+    type Column = Buffer<i128>;
+    type Domain = SimpleDomain<i128>;
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, i128>>;
+    type ColumnBuilder = Vec<i128>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: i128) -> i128 {
+        long
+    }
+
+    fn to_owned_scalar<'a>(scalar: Self::ScalarRef<'a>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref<'a>(scalar: &'a Self::Scalar) -> Self::ScalarRef<'a> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::TimestampTz(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column<'a>(col: &'a Column) -> Option<Self::Column> {
+        match col {
+            Column::TimestampTz(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<SimpleDomain<i128>> {
+        domain.as_timestamp_tz().map(SimpleDomain::clone)
+    }
+
+    fn try_downcast_builder<'a>(
+        builder: &'a mut ColumnBuilder,
+    ) -> Option<&'a mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::TimestampTz(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::TimestampTz(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::TimestampTz(col)
+    }
+
+    fn upcast_domain(domain: SimpleDomain<i128>) -> Domain {
+        Domain::TimestampTz(domain)
+    }
+
+    fn column_len<'a>(col: &'a Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column<'a>(col: &'a Self::Column, index: usize) -> Option<Self::ScalarRef<'a>> {
+        col.get(index).cloned()
+    }
+
+    unsafe fn index_column_unchecked<'a>(
+        col: &'a Self::Column,
+        index: usize,
+    ) -> Self::ScalarRef<'a> {
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column<'a>(col: &'a Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column<'a>(col: &'a Self::Column) -> Self::ColumnIterator<'a> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+}
+
+impl ArgType for TimestampTzType {
+    fn data_type() -> DataType {
+        DataType::TimestampTz
+    }
+
+    fn full_domain() -> Self::Domain {
+        SimpleDomain {
+            min: (TIMESTAMP_MIN as i128) << 64,
+            max: ((TIMESTAMP_MAX as i128) << 64) | u32::MAX as i128,
+        }
+    }
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
+
+/// Pack the microsecs from 1970-01-01 UTC and the offset of the time zone in seconds.
+#[inline]
+pub fn timestamp_tz(micros: i64, offset: i32) -> i128 {
+    ((micros as i128) << 64) | (offset as u32 as i128)
+}
+
+/// The microsecs offset from 1970-01-01 00:00:00 UTC.
+#[inline]
+pub fn timestamp_tz_micros(value: i128) -> i64 {
+    (value >> 64) as i64
+}
+
+/// The offset of the time zone in seconds.
+#[inline]
+pub fn timestamp_tz_offset(value: i128) -> i32 {
+    value as u32 as i32
+}
+
+pub fn timestamp_tz_to_datetime(value: i128) -> DateTime<FixedOffset> {
+    let micros = timestamp_tz_micros(value);
+    let offset = FixedOffset::east_opt(timestamp_tz_offset(value))
+        .unwrap_or_else(|| FixedOffset::east_opt(0).unwrap());
+    micros.to_timestamp(Tz::UTC).with_timezone(&offset)
+}
+
+pub fn datetime_to_timestamp_tz<T: TimeZone>(dt: &DateTime<T>) -> i128 {
+    timestamp_tz(dt.timestamp_micros(), dt.offset().fix().local_minus_utc())
+}
+
+/// Parse a timestamp with an explicit offset like `2023-01-01 00:00:00 +08:00`, the
+/// timestamps without offset are in the time zone `tz`.
+pub fn string_to_timestamp_tz(ts_str: impl AsRef<[u8]>, tz: Tz) -> Option<i128> {
+    let s = std::str::from_utf8(ts_str.as_ref()).ok()?.trim();
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        return Some(datetime_to_timestamp_tz(&dt));
+    }
+    for format in [
+        "%Y-%m-%d %H:%M:%S%.f %z",
+        "%Y-%m-%d %H:%M:%S%.f%z",
+        "%Y-%m-%d %H:%M:%S%.f %:z",
+        "%Y-%m-%d %H:%M:%S%.f%:z",
+        "%Y-%m-%dT%H:%M:%S%.f%z",
+    ] {
+        if let Ok(dt) = DateTime::parse_from_str(s, format) {
+            return Some(datetime_to_timestamp_tz(&dt));
+        }
+    }
+    string_to_timestamp(s, tz).map(|dt| datetime_to_timestamp_tz(&dt))
+}
+
+#[inline]
+pub fn timestamp_tz_to_string(value: i128) -> impl Display {
+    timestamp_tz_to_datetime(value).format(TIMESTAMP_TZ_FORMAT)
+}
+
+/// Format the timestamp in the local time of its own time zone, without the offset.
+#[inline]
+pub fn timestamp_tz_to_local_string(value: i128) -> impl Display {
+    timestamp_tz_to_datetime(value).format(TIMESTAMP_FORMAT)
+}
@@ -15,8 +15,11 @@
 use std::ops::Range;
 
 use super::date::date_to_string;
+use super::geometry::geometry_to_geojson;
 use super::number::NumberScalar;
+use super::time::time_to_string;
 use super::timestamp::timestamp_to_string;
+use super::timestamp_tz::timestamp_tz_to_string;
 use crate::date_helper::TzLUT;
 use crate::property::Domain;
 use crate::types::map::KvPair;
@@ -193,6 +196,9 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
         ScalarRef::Boolean(b) => jsonb::Value::Bool(b),
         ScalarRef::String(s) => jsonb::Value::String(String::from_utf8_lossy(s)),
         ScalarRef::Timestamp(ts) => timestamp_to_string(ts, inner_tz).to_string().into(),
+        ScalarRef::TimestampTz(ts) => timestamp_tz_to_string(ts).to_string().into(),
+        ScalarRef::Time(ts) => time_to_string(ts).to_string().into(),
+        ScalarRef::Binary(s) => hex::encode_upper(s).into(),
         ScalarRef::Date(d) => date_to_string(d, inner_tz).to_string().into(),
         ScalarRef::Array(col) => {
             let items = cast_scalars_to_variants(col.iter(), tz);
@@ -210,6 +216,8 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
                         ScalarRef::Decimal(v) => v.to_string(),
                         ScalarRef::Boolean(v) => v.to_string(),
                         ScalarRef::Timestamp(v) => timestamp_to_string(v, inner_tz).to_string(),
+                        ScalarRef::TimestampTz(v) => timestamp_tz_to_string(v).to_string(),
+                        ScalarRef::Time(v) => time_to_string(v).to_string(),
                         ScalarRef::Date(v) => date_to_string(v, inner_tz).to_string(),
                         _ => unreachable!(),
                     };
@@ -242,6 +250,12 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
             buf.extend_from_slice(bytes);
             return;
         }
+        ScalarRef::Geometry(bytes) => {
+            let geojson = geometry_to_geojson(bytes).expect("failed to format geometry");
+            let value = jsonb::parse_value(geojson.as_bytes()).expect("invalid geojson");
+            value.write_to_vec(buf);
+            return;
+        }
     };
     value.write_to_vec(buf);
 }
@@ -57,8 +57,12 @@ macro_rules! for_common_scalar_values {
             { StringType },
             { DateType },
             { TimestampType },
+            { TimestampTzType },
+            { TimeType },
             { VariantType },
-            { BitmapType }
+            { BitmapType },
+            { GeometryType },
+            { BinaryType }
         }
     };
 }
@@ -41,6 +41,8 @@ use crate::types::decimal::DecimalColumn;
 use crate::types::decimal::DecimalDataType;
 use crate::types::decimal::DecimalDomain;
 use crate::types::decimal::DecimalScalar;
+use crate::types::geometry::display_geometry;
+use crate::types::geometry::GeometryDomain;
 use crate::types::map::KvPair;
 use crate::types::nullable::NullableDomain;
 use crate::types::number::NumberColumn;
@@ -50,7 +52,9 @@ use crate::types::number::NumberScalar;
 use crate::types::number::SimpleDomain;
 use crate::types::string::StringColumn;
 use crate::types::string::StringDomain;
+use crate::types::time::time_to_string;
 use crate::types::timestamp::timestamp_to_string;
+use crate::types::timestamp_tz::timestamp_tz_to_string;
 use crate::types::AnyType;
 use crate::types::DataType;
 use crate::types::ValueType;
@@ -125,6 +129,8 @@ impl<'a> Debug for ScalarRef<'a> {
                 }
             },
             ScalarRef::Timestamp(t) => write!(f, "{t:?}"),
+            ScalarRef::TimestampTz(t) => write!(f, "{t:?}"),
+            ScalarRef::Time(t) => write!(f, "{t:?}"),
             ScalarRef::Date(d) => write!(f, "{d:?}"),
             ScalarRef::Array(col) => write!(f, "[{}]", col.iter().join(", ")),
             ScalarRef::Map(col) => {
@@ -158,6 +164,8 @@ impl<'a> Debug for ScalarRef<'a> {
                 write!(f, ")")
             }
             ScalarRef::Variant(s) => write!(f, "0x{}", &hex::encode(s)),
+            ScalarRef::Geometry(s) => write!(f, "0x{}", &hex::encode(s)),
+            ScalarRef::Binary(s) => write!(f, "0x{}", &hex::encode(s)),
         }
     }
 }
@@ -173,6 +181,8 @@ impl Debug for Column {
             Column::Boolean(col) => f.debug_tuple("Boolean").field(col).finish(),
             Column::String(col) => write!(f, "{col:?}"),
             Column::Timestamp(col) => write!(f, "{col:?}"),
+            Column::TimestampTz(col) => write!(f, "{col:?}"),
+            Column::Time(col) => write!(f, "{col:?}"),
             Column::Date(col) => write!(f, "{col:?}"),
             Column::Array(col) => write!(f, "{col:?}"),
             Column::Map(col) => write!(f, "{col:?}"),
@@ -180,6 +190,8 @@ impl Debug for Column {
             Column::Nullable(col) => write!(f, "{col:?}"),
             Column::Tuple(fields) => f.debug_tuple("Tuple").field(fields).finish(),
             Column::Variant(col) => write!(f, "{col:?}"),
+            Column::Geometry(col) => write!(f, "{col:?}"),
+            Column::Binary(col) => write!(f, "{col:?}"),
         }
     }
 }
@@ -204,6 +216,8 @@ impl<'a> Display for ScalarRef<'a> {
                 }
             },
             ScalarRef::Timestamp(t) => write!(f, "'{}'", timestamp_to_string(*t, Tz::UTC)),
+            ScalarRef::TimestampTz(t) => write!(f, "'{}'", timestamp_tz_to_string(*t)),
+            ScalarRef::Time(t) => write!(f, "'{}'", time_to_string(*t)),
             ScalarRef::Date(d) => write!(f, "'{}'", date_to_string(*d as i64, Tz::UTC)),
             ScalarRef::Array(col) => write!(f, "[{}]", col.iter().join(", ")),
             ScalarRef::Map(col) => {
@@ -240,6 +254,8 @@ impl<'a> Display for ScalarRef<'a> {
                 let value = jsonb::to_string(s);
                 write!(f, "{value}")
             }
+            ScalarRef::Geometry(s) => write!(f, "'{}'", display_geometry(s)),
+            ScalarRef::Binary(s) => write!(f, "{}", hex::encode_upper(s)),
         }
     }
 }
@@ -446,6 +462,8 @@ impl Display for DataType {
             DataType::Number(num) => write!(f, "{num}"),
             DataType::Decimal(decimal) => write!(f, "{decimal}"),
             DataType::Timestamp => write!(f, "Timestamp"),
+            DataType::TimestampTz => write!(f, "TimestampTz"),
+            DataType::Time => write!(f, "Time"),
             DataType::Date => write!(f, "Date"),
             DataType::Null => write!(f, "NULL"),
             DataType::Nullable(inner) => write!(f, "{inner} NULL"),
@@ -473,6 +491,8 @@ impl Display for DataType {
                 write!(f, ")")
             }
             DataType::Variant => write!(f, "Variant"),
+            DataType::Geometry => write!(f, "Geometry"),
+            DataType::Binary => write!(f, "Binary"),
             DataType::Generic(index) => write!(f, "T{index}"),
         }
     }
@@ -486,6 +506,8 @@ impl Display for TableDataType {
             TableDataType::Number(num) => write!(f, "{num}"),
             TableDataType::Decimal(decimal) => write!(f, "{decimal}"),
             TableDataType::Timestamp => write!(f, "Timestamp"),
+            TableDataType::TimestampTz => write!(f, "TimestampTz"),
+            TableDataType::Time => write!(f, "Time"),
             TableDataType::Date => write!(f, "Date"),
             TableDataType::Null => write!(f, "NULL"),
             TableDataType::Nullable(inner) => write!(f, "{inner} NULL"),
@@ -519,6 +541,8 @@ impl Display for TableDataType {
                 write!(f, ")")
             }
             TableDataType::Variant => write!(f, "Variant"),
+            TableDataType::Geometry => write!(f, "Geometry"),
+            TableDataType::Binary => write!(f, "Binary"),
         }
     }
 }
@@ -842,6 +866,16 @@ impl Display for BooleanDomain {
     }
 }
 
+impl Display for GeometryDomain {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{{({:?}, {:?})..=({:?}, {:?})}}",
+            self.min_x, self.min_y, self.max_x, self.max_y
+        )
+    }
+}
+
 impl Display for StringDomain {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         if let Some(max) = &self.max {
@@ -906,6 +940,8 @@ impl Display for Domain {
             Domain::Boolean(domain) => write!(f, "{domain}"),
             Domain::String(domain) => write!(f, "{domain}"),
             Domain::Timestamp(domain) => write!(f, "{domain}"),
+            Domain::TimestampTz(domain) => write!(f, "{domain}"),
+            Domain::Time(domain) => write!(f, "{domain}"),
             Domain::Date(domain) => write!(f, "{domain}"),
             Domain::Nullable(domain) => write!(f, "{domain}"),
             Domain::Array(None) => write!(f, "[]"),
@@ -924,6 +960,7 @@ impl Display for Domain {
             Domain::Map(Some((key_domain, val_domain))) => {
                 write!(f, "{{[{key_domain}], [{val_domain}]}}")
             }
+            Domain::Geometry(domain) => write!(f, "{domain}"),
             Domain::Undefined => write!(f, "Undefined"),
         }
     }
@@ -45,6 +45,7 @@ use serde::Serializer;
 use crate::property::Domain;
 use crate::types::array::ArrayColumn;
 use crate::types::array::ArrayColumnBuilder;
+use crate::types::binary::BinaryType;
 use crate::types::bitmap::BitmapType;
 use crate::types::boolean::BooleanDomain;
 use crate::types::date::DATE_MAX;
@@ -55,6 +56,10 @@ use crate::types::decimal::DecimalColumnBuilder;
 use crate::types::decimal::DecimalDataType;
 use crate::types::decimal::DecimalScalar;
 use crate::types::decimal::DecimalSize;
+use crate::types::geometry::column_bbox;
+use crate::types::geometry::geometry_bbox;
+use crate::types::geometry::geometry_to_ewkb;
+use crate::types::geometry::GEOMETRY_EMPTY;
 use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableColumnBuilder;
 use crate::types::nullable::NullableDomain;
@@ -67,9 +72,13 @@ use crate::types::number::F64;
 use crate::types::string::StringColumn;
 use crate::types::string::StringColumnBuilder;
 use crate::types::string::StringDomain;
+use crate::types::time::check_time;
+use crate::types::time::TIME_MAX;
+use crate::types::time::TIME_MIN;
 use crate::types::timestamp::check_timestamp;
 use crate::types::timestamp::TIMESTAMP_MAX;
 use crate::types::timestamp::TIMESTAMP_MIN;
+use crate::types::timestamp_tz::timestamp_tz;
 use crate::types::variant::JSONB_NULL;
 use crate::types::*;
 use crate::utils::arrow::append_bitmap;
@@ -112,6 +121,10 @@ pub enum Scalar {
     Bitmap(Vec<u8>),
     Tuple(Vec<Scalar>),
     Variant(Vec<u8>),
+    Geometry(Vec<u8>),
+    TimestampTz(i128),
+    Time(i64),
+    Binary(Vec<u8>),
 }
 
 #[derive(Clone, Default, Eq, EnumAsInner)]
@@ -125,12 +138,16 @@ pub enum ScalarRef<'a> {
     Boolean(bool),
     String(&'a [u8]),
     Timestamp(i64),
+    TimestampTz(i128),
+    Time(i64),
     Date(i32),
     Array(Column),
     Map(Column),
     Bitmap(&'a [u8]),
     Tuple(Vec<ScalarRef<'a>>),
     Variant(&'a [u8]),
+    Geometry(&'a [u8]),
+    Binary(&'a [u8]),
 }
 
 #[derive(Clone, EnumAsInner)]
@@ -143,6 +160,8 @@ pub enum Column {
     Boolean(Bitmap),
     String(StringColumn),
     Timestamp(Buffer<i64>),
+    TimestampTz(Buffer<i128>),
+    Time(Buffer<i64>),
     Date(Buffer<i32>),
     Array(Box<ArrayColumn<AnyType>>),
     Map(Box<ArrayColumn<AnyType>>),
@@ -150,6 +169,8 @@ pub enum Column {
     Nullable(Box<NullableColumn<AnyType>>),
     Tuple(Vec<Column>),
     Variant(StringColumn),
+    Geometry(StringColumn),
+    Binary(StringColumn),
 }
 
 #[derive(Debug, Clone, EnumAsInner)]
@@ -162,6 +183,8 @@ pub enum ColumnBuilder {
     Boolean(MutableBitmap),
     String(StringColumnBuilder),
     Timestamp(Vec<i64>),
+    TimestampTz(Vec<i128>),
+    Time(Vec<i64>),
     Date(Vec<i32>),
     Array(Box<ArrayColumnBuilder<AnyType>>),
     Map(Box<ArrayColumnBuilder<AnyType>>),
@@ -169,6 +192,8 @@ pub enum ColumnBuilder {
     Nullable(Box<NullableColumnBuilder<AnyType>>),
     Tuple(Vec<ColumnBuilder>),
     Variant(StringColumnBuilder),
+    Geometry(StringColumnBuilder),
+    Binary(StringColumnBuilder),
 }
 
 impl<'a, T: ValueType> ValueRef<'a, T> {
@@ -307,12 +332,16 @@ impl Scalar {
             Scalar::Boolean(b) => ScalarRef::Boolean(*b),
             Scalar::String(s) => ScalarRef::String(s.as_slice()),
             Scalar::Timestamp(t) => ScalarRef::Timestamp(*t),
+            Scalar::TimestampTz(t) => ScalarRef::TimestampTz(*t),
+            Scalar::Time(t) => ScalarRef::Time(*t),
             Scalar::Date(d) => ScalarRef::Date(*d),
             Scalar::Array(col) => ScalarRef::Array(col.clone()),
             Scalar::Map(col) => ScalarRef::Map(col.clone()),
             Scalar::Bitmap(b) => ScalarRef::Bitmap(b.as_slice()),
             Scalar::Tuple(fields) => ScalarRef::Tuple(fields.iter().map(Scalar::as_ref).collect()),
             Scalar::Variant(s) => ScalarRef::Variant(s.as_slice()),
+            Scalar::Geometry(s) => ScalarRef::Geometry(s.as_slice()),
+            Scalar::Binary(s) => ScalarRef::Binary(s.as_slice()),
         }
     }
 
@@ -337,6 +366,8 @@ impl Scalar {
             }),
             DataType::Decimal(ty) => Scalar::Decimal(ty.default_scalar()),
             DataType::Timestamp => Scalar::Timestamp(0),
+            DataType::TimestampTz => Scalar::TimestampTz(0),
+            DataType::Time => Scalar::Time(0),
             DataType::Date => Scalar::Date(0),
             DataType::Nullable(_) => Scalar::Null,
             DataType::Array(ty) => {
@@ -352,6 +383,8 @@ impl Scalar {
             DataType::Bitmap => Scalar::Bitmap(vec![]),
             DataType::Tuple(tys) => Scalar::Tuple(tys.iter().map(Scalar::default_value).collect()),
             DataType::Variant => Scalar::Variant(vec![]),
+            DataType::Geometry => Scalar::Geometry(GEOMETRY_EMPTY.to_vec()),
+            DataType::Binary => Scalar::Binary(vec![]),
 
             _ => unimplemented!(),
         }
@@ -379,6 +412,8 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::Boolean(b) => Scalar::Boolean(*b),
             ScalarRef::String(s) => Scalar::String(s.to_vec()),
             ScalarRef::Timestamp(t) => Scalar::Timestamp(*t),
+            ScalarRef::TimestampTz(t) => Scalar::TimestampTz(*t),
+            ScalarRef::Time(t) => Scalar::Time(*t),
             ScalarRef::Date(d) => Scalar::Date(*d),
             ScalarRef::Array(col) => Scalar::Array(col.clone()),
             ScalarRef::Map(col) => Scalar::Map(col.clone()),
@@ -387,6 +422,8 @@ impl<'a> ScalarRef<'a> {
                 Scalar::Tuple(fields.iter().map(ScalarRef::to_owned).collect())
             }
             ScalarRef::Variant(s) => Scalar::Variant(s.to_vec()),
+            ScalarRef::Geometry(s) => Scalar::Geometry(s.to_vec()),
+            ScalarRef::Binary(s) => Scalar::Binary(s.to_vec()),
         }
     }
 
@@ -423,6 +460,8 @@ impl<'a> ScalarRef<'a> {
                 max: Some(s.to_vec()),
             }),
             ScalarRef::Timestamp(t) => Domain::Timestamp(SimpleDomain { min: *t, max: *t }),
+            ScalarRef::TimestampTz(t) => Domain::TimestampTz(SimpleDomain { min: *t, max: *t }),
+            ScalarRef::Time(t) => Domain::Time(SimpleDomain { min: *t, max: *t }),
             ScalarRef::Date(d) => Domain::Date(SimpleDomain { min: *d, max: *d }),
             ScalarRef::Array(array) => {
                 if array.len() == 0 {
@@ -455,7 +494,12 @@ impl<'a> ScalarRef<'a> {
                         .collect(),
                 )
             }
-            ScalarRef::Bitmap(_) | ScalarRef::Variant(_) => Domain::Undefined,
+            ScalarRef::Bitmap(_) | ScalarRef::Variant(_) | ScalarRef::Binary(_) => {
+                Domain::Undefined
+            }
+            ScalarRef::Geometry(g) => {
+                Domain::Geometry(geometry_bbox(g).unwrap_or_else(GeometryType::full_domain))
+            }
         }
     }
 
@@ -477,12 +521,16 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::Boolean(_) => 1,
             ScalarRef::String(s) => s.len(),
             ScalarRef::Timestamp(_) => 8,
+            ScalarRef::TimestampTz(_) => 16,
+            ScalarRef::Time(_) => 8,
             ScalarRef::Date(_) => 4,
             ScalarRef::Array(col) => col.memory_size(),
             ScalarRef::Map(col) => col.memory_size(),
             ScalarRef::Bitmap(b) => b.len(),
             ScalarRef::Tuple(scalars) => scalars.iter().map(|s| s.memory_size()).sum(),
             ScalarRef::Variant(buf) => buf.len(),
+            ScalarRef::Geometry(buf) => buf.len(),
+            ScalarRef::Binary(buf) => buf.len(),
         }
     }
 
@@ -504,6 +552,8 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::Boolean(_) => DataType::Boolean,
             ScalarRef::String(_) => DataType::String,
             ScalarRef::Timestamp(_) => DataType::Timestamp,
+            ScalarRef::TimestampTz(_) => DataType::TimestampTz,
+            ScalarRef::Time(_) => DataType::Time,
             ScalarRef::Date(_) => DataType::Date,
             ScalarRef::Array(array) => DataType::Array(Box::new(array.data_type())),
             ScalarRef::Map(col) => DataType::Map(Box::new(col.data_type())),
@@ -516,6 +566,8 @@ impl<'a> ScalarRef<'a> {
                 DataType::Tuple(inner)
             }
             ScalarRef::Variant(_) => DataType::Variant,
+            ScalarRef::Geometry(_) => DataType::Geometry,
+            ScalarRef::Binary(_) => DataType::Binary,
         }
     }
 }
@@ -531,6 +583,8 @@ impl PartialOrd for Scalar {
             (Scalar::Boolean(b1), Scalar::Boolean(b2)) => b1.partial_cmp(b2),
             (Scalar::String(s1), Scalar::String(s2)) => s1.partial_cmp(s2),
             (Scalar::Timestamp(t1), Scalar::Timestamp(t2)) => t1.partial_cmp(t2),
+            (Scalar::TimestampTz(t1), Scalar::TimestampTz(t2)) => t1.partial_cmp(t2),
+            (Scalar::Time(t1), Scalar::Time(t2)) => t1.partial_cmp(t2),
             (Scalar::Date(d1), Scalar::Date(d2)) => d1.partial_cmp(d2),
             (Scalar::Array(a1), Scalar::Array(a2)) => a1.partial_cmp(a2),
             (Scalar::Map(m1), Scalar::Map(m2)) => m1.partial_cmp(m2),
@@ -538,6 +592,8 @@ impl PartialOrd for Scalar {
             (Scalar::Variant(v1), Scalar::Variant(v2)) => {
                 jsonb::compare(v1.as_slice(), v2.as_slice()).ok()
             }
+            (Scalar::Geometry(g1), Scalar::Geometry(g2)) => g1.partial_cmp(g2),
+            (Scalar::Binary(b1), Scalar::Binary(b2)) => b1.partial_cmp(b2),
             _ => None,
         }
     }
@@ -566,11 +622,15 @@ impl PartialOrd for ScalarRef<'_> {
             (ScalarRef::Boolean(b1), ScalarRef::Boolean(b2)) => b1.partial_cmp(b2),
             (ScalarRef::String(s1), ScalarRef::String(s2)) => s1.partial_cmp(s2),
             (ScalarRef::Timestamp(t1), ScalarRef::Timestamp(t2)) => t1.partial_cmp(t2),
+            (ScalarRef::TimestampTz(t1), ScalarRef::TimestampTz(t2)) => t1.partial_cmp(t2),
+            (ScalarRef::Time(t1), ScalarRef::Time(t2)) => t1.partial_cmp(t2),
             (ScalarRef::Date(d1), ScalarRef::Date(d2)) => d1.partial_cmp(d2),
             (ScalarRef::Array(a1), ScalarRef::Array(a2)) => a1.partial_cmp(a2),
             (ScalarRef::Map(m1), ScalarRef::Map(m2)) => m1.partial_cmp(m2),
             (ScalarRef::Tuple(t1), ScalarRef::Tuple(t2)) => t1.partial_cmp(t2),
             (ScalarRef::Variant(v1), ScalarRef::Variant(v2)) => jsonb::compare(v1, v2).ok(),
+            (ScalarRef::Geometry(g1), ScalarRef::Geometry(g2)) => g1.partial_cmp(g2),
+            (ScalarRef::Binary(b1), ScalarRef::Binary(b2)) => b1.partial_cmp(b2),
             _ => None,
         }
     }
@@ -605,6 +665,8 @@ impl Hash for ScalarRef<'_> {
             ScalarRef::Boolean(v) => v.hash(state),
             ScalarRef::String(v) => v.hash(state),
             ScalarRef::Timestamp(v) => v.hash(state),
+            ScalarRef::TimestampTz(v) => v.hash(state),
+            ScalarRef::Time(v) => v.hash(state),
             ScalarRef::Date(v) => v.hash(state),
             ScalarRef::Array(v) => {
                 let str = serialize_column(v);
@@ -619,6 +681,8 @@ impl Hash for ScalarRef<'_> {
                 v.hash(state);
             }
             ScalarRef::Variant(v) => v.hash(state),
+            ScalarRef::Geometry(v) => v.hash(state),
+            ScalarRef::Binary(v) => v.hash(state),
         }
     }
 }
@@ -646,6 +710,10 @@ impl PartialOrd for Column {
             (Column::Timestamp(col1), Column::Timestamp(col2)) => {
                 col1.iter().partial_cmp(col2.iter())
             }
+            (Column::TimestampTz(col1), Column::TimestampTz(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
+            (Column::Time(col1), Column::Time(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Date(col1), Column::Date(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Array(col1), Column::Array(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Map(col1), Column::Map(col2)) => col1.iter().partial_cmp(col2.iter()),
@@ -656,6 +724,10 @@ impl PartialOrd for Column {
             (Column::Variant(col1), Column::Variant(col2)) => col1
                 .iter()
                 .partial_cmp_by(col2.iter(), |v1, v2| jsonb::compare(v1, v2).ok()),
+            (Column::Geometry(col1), Column::Geometry(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
+            (Column::Binary(col1), Column::Binary(col2)) => col1.iter().partial_cmp(col2.iter()),
             _ => None,
         }
     }
@@ -672,6 +744,9 @@ pub const ARROW_EXT_TYPE_EMPTY_ARRAY: &str = "EmptyArray";
 pub const ARROW_EXT_TYPE_EMPTY_MAP: &str = "EmptyMap";
 pub const ARROW_EXT_TYPE_VARIANT: &str = "Variant";
 pub const ARROW_EXT_TYPE_BITMAP: &str = "Bitmap";
+pub const ARROW_EXT_TYPE_GEOMETRY: &str = "Geometry";
+pub const ARROW_EXT_TYPE_TIMESTAMP_TZ: &str = "TimestampTz";
+pub const ARROW_EXT_TYPE_BINARY: &str = "Binary";
 
 impl Column {
     pub fn len(&self) -> usize {
@@ -684,6 +759,8 @@ impl Column {
             Column::Boolean(col) => col.len(),
             Column::String(col) => col.len(),
             Column::Timestamp(col) => col.len(),
+            Column::TimestampTz(col) => col.len(),
+            Column::Time(col) => col.len(),
             Column::Date(col) => col.len(),
             Column::Array(col) => col.len(),
             Column::Map(col) => col.len(),
@@ -691,6 +768,8 @@ impl Column {
             Column::Nullable(col) => col.len(),
             Column::Tuple(fields) => fields[0].len(),
             Column::Variant(col) => col.len(),
+            Column::Geometry(col) => col.len(),
+            Column::Binary(col) => col.len(),
         }
     }
 
@@ -704,6 +783,8 @@ impl Column {
             Column::Boolean(col) => Some(ScalarRef::Boolean(col.get(index)?)),
             Column::String(col) => Some(ScalarRef::String(col.index(index)?)),
             Column::Timestamp(col) => Some(ScalarRef::Timestamp(col.get(index).cloned()?)),
+            Column::TimestampTz(col) => Some(ScalarRef::TimestampTz(col.get(index).cloned()?)),
+            Column::Time(col) => Some(ScalarRef::Time(col.get(index).cloned()?)),
             Column::Date(col) => Some(ScalarRef::Date(col.get(index).cloned()?)),
             Column::Array(col) => Some(ScalarRef::Array(col.index(index)?)),
             Column::Map(col) => Some(ScalarRef::Map(col.index(index)?)),
@@ -716,6 +797,8 @@ impl Column {
                     .collect::<Option<Vec<_>>>()?,
             )),
             Column::Variant(col) => Some(ScalarRef::Variant(col.index(index)?)),
+            Column::Geometry(col) => Some(ScalarRef::Geometry(col.index(index)?)),
+            Column::Binary(col) => Some(ScalarRef::Binary(col.index(index)?)),
         }
     }
 
@@ -732,6 +815,8 @@ impl Column {
             Column::Boolean(col) => ScalarRef::Boolean(col.get_bit_unchecked(index)),
             Column::String(col) => ScalarRef::String(col.index_unchecked(index)),
             Column::Timestamp(col) => ScalarRef::Timestamp(*col.get_unchecked(index)),
+            Column::TimestampTz(col) => ScalarRef::TimestampTz(*col.get_unchecked(index)),
+            Column::Time(col) => ScalarRef::Time(*col.get_unchecked(index)),
             Column::Date(col) => ScalarRef::Date(*col.get_unchecked(index)),
             Column::Array(col) => ScalarRef::Array(col.index_unchecked(index)),
             Column::Map(col) => ScalarRef::Map(col.index_unchecked(index)),
@@ -744,6 +829,8 @@ impl Column {
                     .collect::<Vec<_>>(),
             ),
             Column::Variant(col) => ScalarRef::Variant(col.index_unchecked(index)),
+            Column::Geometry(col) => ScalarRef::Geometry(col.index_unchecked(index)),
+            Column::Binary(col) => ScalarRef::Binary(col.index_unchecked(index)),
         }
     }
 
@@ -779,6 +866,12 @@ impl Column {
             Column::Timestamp(col) => {
                 Column::Timestamp(col.clone().sliced(range.start, range.end - range.start))
             }
+            Column::TimestampTz(col) => {
+                Column::TimestampTz(col.clone().sliced(range.start, range.end - range.start))
+            }
+            Column::Time(col) => {
+                Column::Time(col.clone().sliced(range.start, range.end - range.start))
+            }
             Column::Date(col) => {
                 Column::Date(col.clone().sliced(range.start, range.end - range.start))
             }
@@ -793,6 +886,8 @@ impl Column {
                     .collect(),
             ),
             Column::Variant(col) => Column::Variant(col.slice(range)),
+            Column::Geometry(col) => Column::Geometry(col.slice(range)),
+            Column::Binary(col) => Column::Binary(col.slice(range)),
         }
     }
 
@@ -833,6 +928,20 @@ impl Column {
                     max: *max,
                 })
             }
+            Column::TimestampTz(col) => {
+                let (min, max) = col.iter().minmax().into_option().unwrap();
+                Domain::TimestampTz(SimpleDomain {
+                    min: *min,
+                    max: *max,
+                })
+            }
+            Column::Time(col) => {
+                let (min, max) = col.iter().minmax().into_option().unwrap();
+                Domain::Time(SimpleDomain {
+                    min: *min,
+                    max: *max,
+                })
+            }
             Column::Date(col) => {
                 let (min, max) = col.iter().minmax().into_option().unwrap();
                 Domain::Date(SimpleDomain {
@@ -873,7 +982,10 @@ impl Column {
                 let domains = fields.iter().map(|col| col.domain()).collect::<Vec<_>>();
                 Domain::Tuple(domains)
             }
-            Column::Bitmap(_) | Column::Variant(_) => Domain::Undefined,
+            Column::Bitmap(_) | Column::Variant(_) | Column::Binary(_) => Domain::Undefined,
+            Column::Geometry(col) => {
+                Domain::Geometry(column_bbox(col).unwrap_or_else(GeometryType::full_domain))
+            }
         }
     }
 
@@ -892,6 +1004,8 @@ impl Column {
             Column::Boolean(_) => DataType::Boolean,
             Column::String(_) => DataType::String,
             Column::Timestamp(_) => DataType::Timestamp,
+            Column::TimestampTz(_) => DataType::TimestampTz,
+            Column::Time(_) => DataType::Time,
             Column::Date(_) => DataType::Date,
             Column::Array(array) => {
                 let inner = array.values.data_type();
@@ -911,6 +1025,8 @@ impl Column {
                 DataType::Tuple(inner)
             }
             Column::Variant(_) => DataType::Variant,
+            Column::Geometry(_) => DataType::Geometry,
+            Column::Binary(_) => DataType::Binary,
         }
     }
 
@@ -1063,6 +1179,22 @@ impl Column {
                 )
                 .unwrap(),
             ),
+            Column::TimestampTz(col) => Box::new(
+                common_arrow::arrow::array::PrimitiveArray::<i128>::try_new(
+                    arrow_type,
+                    col.clone(),
+                    None,
+                )
+                .unwrap(),
+            ),
+            Column::Time(col) => Box::new(
+                common_arrow::arrow::array::PrimitiveArray::<i64>::try_new(
+                    arrow_type,
+                    col.clone(),
+                    None,
+                )
+                .unwrap(),
+            ),
             Column::Date(col) => Box::new(
                 common_arrow::arrow::array::PrimitiveArray::<i32>::try_new(
                     arrow_type,
@@ -1136,7 +1268,7 @@ impl Column {
                 )
                 .unwrap(),
             ),
-            Column::Variant(col) => {
+            Column::Variant(col) | Column::Geometry(col) | Column::Binary(col) => {
                 let offsets: Buffer<i64> =
                     col.offsets().iter().map(|offset| *offset as i64).collect();
                 Box::new(
@@ -1313,7 +1445,12 @@ impl Column {
                 let offsets = arrow_col.offsets().clone().into_inner();
 
                 let offsets = unsafe { std::mem::transmute::<Buffer<i64>, Buffer<u64>>(offsets) };
-                Column::String(StringColumn::new(arrow_col.values().clone(), offsets))
+                let col = StringColumn::new(arrow_col.values().clone(), offsets);
+                if data_type == DataType::Binary {
+                    Column::Binary(col)
+                } else {
+                    Column::String(col)
+                }
             }
             // TODO: deprecate it and use LargeBinary instead
             ArrowDataType::Binary => {
@@ -1328,10 +1465,12 @@ impl Column {
                     .map(|x| *x as u64)
                     .collect::<Vec<_>>();
 
-                Column::String(StringColumn::new(
-                    arrow_col.values().clone(),
-                    offsets.into(),
-                ))
+                let col = StringColumn::new(arrow_col.values().clone(), offsets.into());
+                if data_type == DataType::Binary {
+                    Column::Binary(col)
+                } else {
+                    Column::String(col)
+                }
             }
             // TODO: deprecate it and use LargeBinary instead
             ArrowDataType::Utf8 => {
@@ -1393,6 +1532,34 @@ impl Column {
                 };
                 Column::Timestamp(values)
             }
+            ArrowDataType::Time64(uint) => {
+                let values = arrow_col
+                    .as_any()
+                    .downcast_ref::<common_arrow::arrow::array::Int64Array>()
+                    .expect("fail to read from arrow: array should be `Int64Array`")
+                    .values();
+                let values = match uint {
+                    TimeUnit::Nanosecond => {
+                        values.iter().map(|x| x / 1_000).collect::<Vec<_>>().into()
+                    }
+                    _ => values.clone(),
+                };
+                Column::Time(values)
+            }
+            ArrowDataType::Time32(uint) => {
+                let values = arrow_col
+                    .as_any()
+                    .downcast_ref::<common_arrow::arrow::array::Int32Array>()
+                    .expect("fail to read from arrow: array should be `Int32Array`")
+                    .values();
+                let scale = match uint {
+                    TimeUnit::Second => 1_000_000,
+                    TimeUnit::Millisecond => 1_000,
+                    _ => 1,
+                };
+                let values = values.iter().map(|x| *x as i64 * scale).collect::<Vec<_>>();
+                Column::Time(values.into())
+            }
             ArrowDataType::Date32 => Column::Date(
                 arrow_col
                     .as_any()
@@ -1576,6 +1743,84 @@ impl Column {
                     ),
                 }
             }
+            ArrowDataType::Extension(name, _, None) if name == ARROW_EXT_TYPE_TIMESTAMP_TZ => {
+                Column::TimestampTz(
+                    arrow_col
+                        .as_any()
+                        .downcast_ref::<common_arrow::arrow::array::PrimitiveArray<i128>>()
+                        .expect("fail to read from arrow: array should be `PrimitiveArray<i128>`")
+                        .values()
+                        .clone(),
+                )
+            }
+            ArrowDataType::Extension(name, box ty, None) if name == ARROW_EXT_TYPE_GEOMETRY => {
+                match ty {
+                    ArrowDataType::LargeBinary => {
+                        let arrow_col = arrow_col
+                            .as_any()
+                            .downcast_ref::<common_arrow::arrow::array::BinaryArray<i64>>()
+                            .expect("fail to read from arrow: array should be `BinaryArray<i64>`");
+                        let offsets = arrow_col.offsets().clone().into_inner();
+
+                        let offsets =
+                            unsafe { std::mem::transmute::<Buffer<i64>, Buffer<u64>>(offsets) };
+                        Column::Geometry(StringColumn::new(arrow_col.values().clone(), offsets))
+                    }
+                    ArrowDataType::Binary => {
+                        let arrow_col = arrow_col
+                            .as_any()
+                            .downcast_ref::<common_arrow::arrow::array::BinaryArray<i32>>()
+                            .expect("fail to read from arrow: array should be `BinaryArray<i32>`");
+                        let offsets = arrow_col
+                            .offsets()
+                            .buffer()
+                            .iter()
+                            .map(|x| *x as u64)
+                            .collect::<Vec<_>>();
+                        Column::Geometry(StringColumn::new(
+                            arrow_col.values().clone(),
+                            offsets.into(),
+                        ))
+                    }
+                    _ => unreachable!(
+                        "fail to read from arrow: array should be `BinaryArray<i32>` or `BinaryArray<i64>`"
+                    ),
+                }
+            }
+            ArrowDataType::Extension(name, box ty, None) if name == ARROW_EXT_TYPE_BINARY => {
+                match ty {
+                    ArrowDataType::LargeBinary => {
+                        let arrow_col = arrow_col
+                            .as_any()
+                            .downcast_ref::<common_arrow::arrow::array::BinaryArray<i64>>()
+                            .expect("fail to read from arrow: array should be `BinaryArray<i64>`");
+                        let offsets = arrow_col.offsets().clone().into_inner();
+
+                        let offsets =
+                            unsafe { std::mem::transmute::<Buffer<i64>, Buffer<u64>>(offsets) };
+                        Column::Binary(StringColumn::new(arrow_col.values().clone(), offsets))
+                    }
+                    ArrowDataType::Binary => {
+                        let arrow_col = arrow_col
+                            .as_any()
+                            .downcast_ref::<common_arrow::arrow::array::BinaryArray<i32>>()
+                            .expect("fail to read from arrow: array should be `BinaryArray<i32>`");
+                        let offsets = arrow_col
+                            .offsets()
+                            .buffer()
+                            .iter()
+                            .map(|x| *x as u64)
+                            .collect::<Vec<_>>();
+                        Column::Binary(StringColumn::new(
+                            arrow_col.values().clone(),
+                            offsets.into(),
+                        ))
+                    }
+                    _ => unreachable!(
+                        "fail to read from arrow: array should be `BinaryArray<i32>` or `BinaryArray<i64>`"
+                    ),
+                }
+            }
             ty => unimplemented!("unsupported arrow type {ty:?}"),
         };
 
@@ -1593,6 +1838,7 @@ impl Column {
 
     pub fn random(ty: &DataType, len: usize) -> Self {
         use rand::distributions::Alphanumeric;
+        use rand::distributions::Standard;
         use rand::rngs::SmallRng;
         use rand::Rng;
         use rand::SeedableRng;
@@ -1635,6 +1881,21 @@ impl Column {
                     .map(|_| SmallRng::from_entropy().gen_range(TIMESTAMP_MIN..=TIMESTAMP_MAX))
                     .collect::<Vec<i64>>(),
             ),
+            DataType::TimestampTz => TimestampTzType::from_data(
+                (0..len)
+                    .map(|_| {
+                        let micros =
+                            SmallRng::from_entropy().gen_range(TIMESTAMP_MIN..=TIMESTAMP_MAX);
+                        let offset = SmallRng::from_entropy().gen_range(-12..=14) * 3600;
+                        timestamp_tz(micros, offset)
+                    })
+                    .collect::<Vec<i128>>(),
+            ),
+            DataType::Time => TimeType::from_data(
+                (0..len)
+                    .map(|_| SmallRng::from_entropy().gen_range(TIME_MIN..=TIME_MAX))
+                    .collect::<Vec<i64>>(),
+            ),
             DataType::Date => DateType::from_data(
                 (0..len)
                     .map(|_| SmallRng::from_entropy().gen_range(DATE_MIN..=DATE_MAX))
@@ -1694,6 +1955,15 @@ impl Column {
                 }
                 VariantType::from_data(data)
             }
+            DataType::Geometry => GeometryType::from_data((0..len).map(|_| {
+                let x: f64 = SmallRng::from_entropy().gen_range(-180.0..180.0);
+                let y: f64 = SmallRng::from_entropy().gen_range(-90.0..90.0);
+                geometry_to_ewkb(&geo::Geometry::Point(geo::point!(x: x, y: y)), None).unwrap()
+            })),
+            DataType::Binary => BinaryType::from_data((0..len).map(|_| {
+                let rng = SmallRng::from_entropy();
+                rng.sample_iter(&Standard).take(5).collect::<Vec<u8>>()
+            })),
             DataType::Generic(_) => unreachable!(),
         }
     }
@@ -1752,6 +2022,8 @@ impl Column {
             Column::Boolean(c) => c.as_slice().0.len(),
             Column::String(col) => col.memory_size(),
             Column::Timestamp(col) => col.len() * 8,
+            Column::TimestampTz(col) => col.len() * 16,
+            Column::Time(col) => col.len() * 8,
             Column::Date(col) => col.len() * 4,
             Column::Array(col) => col.values.memory_size() + col.offsets.len() * 8,
             Column::Map(col) => col.values.memory_size() + col.offsets.len() * 8,
@@ -1759,6 +2031,8 @@ impl Column {
             Column::Nullable(c) => c.column.memory_size() + c.validity.as_slice().0.len(),
             Column::Tuple(fields) => fields.iter().map(|f| f.memory_size()).sum(),
             Column::Variant(col) => col.memory_size(),
+            Column::Geometry(col) => col.memory_size(),
+            Column::Binary(col) => col.memory_size(),
         }
     }
 
@@ -1827,6 +2101,8 @@ impl ColumnBuilder {
             Column::Boolean(col) => ColumnBuilder::Boolean(bitmap_into_mut(col)),
             Column::String(col) => ColumnBuilder::String(StringColumnBuilder::from_column(col)),
             Column::Timestamp(col) => ColumnBuilder::Timestamp(buffer_into_mut(col)),
+            Column::TimestampTz(col) => ColumnBuilder::TimestampTz(buffer_into_mut(col)),
+            Column::Time(col) => ColumnBuilder::Time(buffer_into_mut(col)),
             Column::Date(col) => ColumnBuilder::Date(buffer_into_mut(col)),
             Column::Array(box col) => {
                 ColumnBuilder::Array(Box::new(ArrayColumnBuilder::from_column(col)))
@@ -1845,6 +2121,8 @@ impl ColumnBuilder {
                     .collect(),
             ),
             Column::Variant(col) => ColumnBuilder::Variant(StringColumnBuilder::from_column(col)),
+            Column::Geometry(col) => ColumnBuilder::Geometry(StringColumnBuilder::from_column(col)),
+            Column::Binary(col) => ColumnBuilder::Binary(StringColumnBuilder::from_column(col)),
         }
     }
 
@@ -1886,6 +2164,8 @@ impl ColumnBuilder {
             ScalarRef::Boolean(b) => ColumnBuilder::Boolean(constant_bitmap(*b, n)),
             ScalarRef::String(s) => ColumnBuilder::String(StringColumnBuilder::repeat(s, n)),
             ScalarRef::Timestamp(d) => ColumnBuilder::Timestamp(vec![*d; n]),
+            ScalarRef::TimestampTz(d) => ColumnBuilder::TimestampTz(vec![*d; n]),
+            ScalarRef::Time(d) => ColumnBuilder::Time(vec![*d; n]),
             ScalarRef::Date(d) => ColumnBuilder::Date(vec![*d; n]),
             ScalarRef::Array(col) => {
                 ColumnBuilder::Array(Box::new(ArrayColumnBuilder::repeat(col, n)))
@@ -1910,6 +2190,8 @@ impl ColumnBuilder {
                 )
             }
             ScalarRef::Variant(s) => ColumnBuilder::Variant(StringColumnBuilder::repeat(s, n)),
+            ScalarRef::Geometry(s) => ColumnBuilder::Geometry(StringColumnBuilder::repeat(s, n)),
+            ScalarRef::Binary(s) => ColumnBuilder::Binary(StringColumnBuilder::repeat(s, n)),
         }
     }
 
@@ -1923,6 +2205,8 @@ impl ColumnBuilder {
             ColumnBuilder::Boolean(builder) => builder.len(),
             ColumnBuilder::String(builder) => builder.len(),
             ColumnBuilder::Timestamp(builder) => builder.len(),
+            ColumnBuilder::TimestampTz(builder) => builder.len(),
+            ColumnBuilder::Time(builder) => builder.len(),
             ColumnBuilder::Date(builder) => builder.len(),
             ColumnBuilder::Array(builder) => builder.len(),
             ColumnBuilder::Map(builder) => builder.len(),
@@ -1930,6 +2214,8 @@ impl ColumnBuilder {
             ColumnBuilder::Nullable(builder) => builder.len(),
             ColumnBuilder::Tuple(fields) => fields[0].len(),
             ColumnBuilder::Variant(builder) => builder.len(),
+            ColumnBuilder::Geometry(builder) => builder.len(),
+            ColumnBuilder::Binary(builder) => builder.len(),
         }
     }
 
@@ -1957,6 +2243,8 @@ impl ColumnBuilder {
             ColumnBuilder::Boolean(c) => c.as_slice().len(),
             ColumnBuilder::String(col) => col.data.len() + col.offsets.len() * 8,
             ColumnBuilder::Timestamp(col) => col.len() * 8,
+            ColumnBuilder::TimestampTz(col) => col.len() * 16,
+            ColumnBuilder::Time(col) => col.len() * 8,
             ColumnBuilder::Date(col) => col.len() * 4,
             ColumnBuilder::Array(col) => col.builder.memory_size() + col.offsets.len() * 8,
             ColumnBuilder::Map(col) => col.builder.memory_size() + col.offsets.len() * 8,
@@ -1964,6 +2252,8 @@ impl ColumnBuilder {
             ColumnBuilder::Nullable(c) => c.builder.memory_size() + c.validity.as_slice().len(),
             ColumnBuilder::Tuple(fields) => fields.iter().map(|f| f.memory_size()).sum(),
             ColumnBuilder::Variant(col) => col.data.len() + col.offsets.len() * 8,
+            ColumnBuilder::Geometry(col) => col.data.len() + col.offsets.len() * 8,
+            ColumnBuilder::Binary(col) => col.data.len() + col.offsets.len() * 8,
         }
     }
 
@@ -1982,6 +2272,8 @@ impl ColumnBuilder {
             ColumnBuilder::Boolean(_) => DataType::Boolean,
             ColumnBuilder::String(_) => DataType::String,
             ColumnBuilder::Timestamp(_) => DataType::Timestamp,
+            ColumnBuilder::TimestampTz(_) => DataType::TimestampTz,
+            ColumnBuilder::Time(_) => DataType::Time,
             ColumnBuilder::Date(_) => DataType::Date,
             ColumnBuilder::Array(col) => {
                 let inner = col.builder.data_type();
@@ -1997,6 +2289,8 @@ impl ColumnBuilder {
                 DataType::Tuple(fields.iter().map(|f| f.data_type()).collect::<Vec<_>>())
             }
             ColumnBuilder::Variant(_) => DataType::Variant,
+            ColumnBuilder::Geometry(_) => DataType::Geometry,
+            ColumnBuilder::Binary(_) => DataType::Binary,
         }
     }
 
@@ -2027,6 +2321,8 @@ impl ColumnBuilder {
                 ColumnBuilder::String(StringColumnBuilder::with_capacity(capacity, data_capacity))
             }
             DataType::Timestamp => ColumnBuilder::Timestamp(Vec::with_capacity(capacity)),
+            DataType::TimestampTz => ColumnBuilder::TimestampTz(Vec::with_capacity(capacity)),
+            DataType::Time => ColumnBuilder::Time(Vec::with_capacity(capacity)),
             DataType::Date => ColumnBuilder::Date(Vec::with_capacity(capacity)),
             DataType::Nullable(ty) => ColumnBuilder::Nullable(Box::new(NullableColumnBuilder {
                 builder: Self::with_capacity_hint(ty, capacity, enable_datasize_hint),
@@ -2067,6 +2363,14 @@ impl ColumnBuilder {
                 let data_capacity = if enable_datasize_hint { 0 } else { capacity };
                 ColumnBuilder::Variant(StringColumnBuilder::with_capacity(capacity, data_capacity))
             }
+            DataType::Geometry => {
+                let data_capacity = if enable_datasize_hint { 0 } else { capacity };
+                ColumnBuilder::Geometry(StringColumnBuilder::with_capacity(capacity, data_capacity))
+            }
+            DataType::Binary => {
+                let data_capacity = if enable_datasize_hint { 0 } else { capacity };
+                ColumnBuilder::Binary(StringColumnBuilder::with_capacity(capacity, data_capacity))
+            }
             DataType::Generic(_) => {
                 unreachable!("unable to initialize column builder for generic type")
             }
@@ -2088,6 +2392,12 @@ impl ColumnBuilder {
             (ColumnBuilder::Timestamp(builder), ScalarRef::Timestamp(value)) => {
                 builder.push(value);
             }
+            (ColumnBuilder::TimestampTz(builder), ScalarRef::TimestampTz(value)) => {
+                builder.push(value);
+            }
+            (ColumnBuilder::Time(builder), ScalarRef::Time(value)) => {
+                builder.push(value);
+            }
             (ColumnBuilder::Date(builder), ScalarRef::Date(value)) => builder.push(value),
             (ColumnBuilder::Array(builder), ScalarRef::Array(value)) => {
                 builder.push(value);
@@ -2111,7 +2421,9 @@ impl ColumnBuilder {
                     field.push(scalar.clone());
                 }
             }
-            (ColumnBuilder::Variant(builder), ScalarRef::Variant(value)) => {
+            (ColumnBuilder::Variant(builder), ScalarRef::Variant(value))
+            | (ColumnBuilder::Geometry(builder), ScalarRef::Geometry(value))
+            | (ColumnBuilder::Binary(builder), ScalarRef::Binary(value)) => {
                 builder.put_slice(value);
                 builder.commit_row();
             }
@@ -2127,8 +2439,10 @@ impl ColumnBuilder {
             ColumnBuilder::Number(builder) => builder.push_default(),
             ColumnBuilder::Decimal(builder) => builder.push_default(),
             ColumnBuilder::Boolean(builder) => builder.push(false),
-            ColumnBuilder::String(builder) => builder.commit_row(),
+            ColumnBuilder::String(builder) | ColumnBuilder::Binary(builder) => builder.commit_row(),
             ColumnBuilder::Timestamp(builder) => builder.push(0),
+            ColumnBuilder::TimestampTz(builder) => builder.push(0),
+            ColumnBuilder::Time(builder) => builder.push(0),
             ColumnBuilder::Date(builder) => builder.push(0),
             ColumnBuilder::Array(builder) => builder.push_default(),
             ColumnBuilder::Map(builder) => builder.push_default(),
@@ -2143,6 +2457,10 @@ impl ColumnBuilder {
                 builder.put_slice(JSONB_NULL);
                 builder.commit_row();
             }
+            ColumnBuilder::Geometry(builder) => {
+                builder.put_slice(GEOMETRY_EMPTY);
+                builder.commit_row();
+            }
         }
     }
 
@@ -2169,7 +2487,9 @@ impl ColumnBuilder {
             }
             ColumnBuilder::String(builder)
             | ColumnBuilder::Variant(builder)
-            | ColumnBuilder::Bitmap(builder) => {
+            | ColumnBuilder::Bitmap(builder)
+            | ColumnBuilder::Geometry(builder)
+            | ColumnBuilder::Binary(builder) => {
                 let offset: u64 = reader.read_uvarint()?;
                 builder.data.resize(offset as usize + builder.data.len(), 0);
                 let last = *builder.offsets.last().unwrap() as usize;
@@ -2181,6 +2501,12 @@ impl ColumnBuilder {
                 check_timestamp(value)?;
                 builder.push(value);
             }
+            ColumnBuilder::TimestampTz(builder) => builder.push(i128::de_binary(reader)),
+            ColumnBuilder::Time(builder) => {
+                let value: i64 = reader.read_scalar()?;
+                check_time(value)?;
+                builder.push(value);
+            }
             ColumnBuilder::Date(builder) => {
                 let value: i32 = reader.read_scalar()?;
                 builder.push(value);
@@ -2255,7 +2581,9 @@ impl ColumnBuilder {
             }
             ColumnBuilder::String(builder)
             | ColumnBuilder::Variant(builder)
-            | ColumnBuilder::Bitmap(builder) => {
+            | ColumnBuilder::Bitmap(builder)
+            | ColumnBuilder::Geometry(builder)
+            | ColumnBuilder::Binary(builder) => {
                 for row in 0..rows {
                     let reader = &reader[step * row..];
                     builder.put_slice(reader);
@@ -2270,6 +2598,20 @@ impl ColumnBuilder {
                     builder.push(value);
                 }
             }
+            ColumnBuilder::TimestampTz(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    builder.push(i128::de_binary(&mut reader));
+                }
+            }
+            ColumnBuilder::Time(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    let value: i64 = reader.read_scalar()?;
+                    check_time(value)?;
+                    builder.push(value);
+                }
+            }
             ColumnBuilder::Date(builder) => {
                 for row in 0..rows {
                     let mut reader = &reader[step * row..];
@@ -2348,6 +2690,8 @@ impl ColumnBuilder {
             ColumnBuilder::Boolean(builder) => builder.pop().map(Scalar::Boolean),
             ColumnBuilder::String(builder) => builder.pop().map(Scalar::String),
             ColumnBuilder::Timestamp(builder) => builder.pop().map(Scalar::Timestamp),
+            ColumnBuilder::TimestampTz(builder) => builder.pop().map(Scalar::TimestampTz),
+            ColumnBuilder::Time(builder) => builder.pop().map(Scalar::Time),
             ColumnBuilder::Date(builder) => builder.pop().map(Scalar::Date),
             ColumnBuilder::Array(builder) => builder.pop().map(Scalar::Array),
             ColumnBuilder::Map(builder) => builder.pop().map(Scalar::Map),
@@ -2366,6 +2710,8 @@ impl ColumnBuilder {
                 }
             }
             ColumnBuilder::Variant(builder) => builder.pop().map(Scalar::Variant),
+            ColumnBuilder::Geometry(builder) => builder.pop().map(Scalar::Geometry),
+            ColumnBuilder::Binary(builder) => builder.pop().map(Scalar::Binary),
         }
     }
 
@@ -2392,12 +2738,20 @@ impl ColumnBuilder {
             (ColumnBuilder::String(builder), Column::String(other)) => {
                 builder.append_column(other);
             }
-            (ColumnBuilder::Variant(builder), Column::Variant(other)) => {
+            (ColumnBuilder::Variant(builder), Column::Variant(other))
+            | (ColumnBuilder::Geometry(builder), Column::Geometry(other))
+            | (ColumnBuilder::Binary(builder), Column::Binary(other)) => {
                 builder.append_column(other);
             }
             (ColumnBuilder::Timestamp(builder), Column::Timestamp(other)) => {
                 builder.extend_from_slice(other);
             }
+            (ColumnBuilder::TimestampTz(builder), Column::TimestampTz(other)) => {
+                builder.extend_from_slice(other);
+            }
+            (ColumnBuilder::Time(builder), Column::Time(other)) => {
+                builder.extend_from_slice(other);
+            }
             (ColumnBuilder::Date(builder), Column::Date(other)) => {
                 builder.extend_from_slice(other);
             }
@@ -2437,6 +2791,8 @@ impl ColumnBuilder {
             ColumnBuilder::Boolean(builder) => Column::Boolean(builder.into()),
             ColumnBuilder::String(builder) => Column::String(builder.build()),
             ColumnBuilder::Timestamp(builder) => Column::Timestamp(builder.into()),
+            ColumnBuilder::TimestampTz(builder) => Column::TimestampTz(builder.into()),
+            ColumnBuilder::Time(builder) => Column::Time(builder.into()),
             ColumnBuilder::Date(builder) => Column::Date(builder.into()),
             ColumnBuilder::Array(builder) => Column::Array(Box::new(builder.build())),
             ColumnBuilder::Map(builder) => Column::Map(Box::new(builder.build())),
@@ -2447,6 +2803,8 @@ impl ColumnBuilder {
                 Column::Tuple(fields.into_iter().map(|field| field.build()).collect())
             }
             ColumnBuilder::Variant(builder) => Column::Variant(builder.build()),
+            ColumnBuilder::Geometry(builder) => Column::Geometry(builder.build()),
+            ColumnBuilder::Binary(builder) => Column::Binary(builder.build()),
         }
     }
 
@@ -2461,6 +2819,8 @@ impl ColumnBuilder {
             ColumnBuilder::Boolean(builder) => Scalar::Boolean(builder.get(0)),
             ColumnBuilder::String(builder) => Scalar::String(builder.build_scalar()),
             ColumnBuilder::Timestamp(builder) => Scalar::Timestamp(builder[0]),
+            ColumnBuilder::TimestampTz(builder) => Scalar::TimestampTz(builder[0]),
+            ColumnBuilder::Time(builder) => Scalar::Time(builder[0]),
             ColumnBuilder::Date(builder) => Scalar::Date(builder[0]),
             ColumnBuilder::Array(builder) => Scalar::Array(builder.build_scalar()),
             ColumnBuilder::Map(builder) => Scalar::Map(builder.build_scalar()),
@@ -2473,6 +2833,8 @@ impl ColumnBuilder {
                     .collect(),
             ),
             ColumnBuilder::Variant(builder) => Scalar::Variant(builder.build_scalar()),
+            ColumnBuilder::Geometry(builder) => Scalar::Geometry(builder.build_scalar()),
+            ColumnBuilder::Binary(builder) => Scalar::Binary(builder.build_scalar()),
         }
     }
 }
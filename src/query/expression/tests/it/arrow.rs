@@ -0,0 +1,94 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::Int32Array;
+use common_arrow::arrow::array::Int64Array;
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::Field;
+use common_arrow::arrow::datatypes::Schema;
+use common_arrow::arrow::datatypes::TimeUnit;
+use common_arrow::arrow::io::parquet::read::infer_schema;
+use common_arrow::arrow::io::parquet::read::read_metadata;
+use common_arrow::arrow::io::parquet::read::FileReader;
+use common_arrow::arrow::io::parquet::write::RowGroupIterator;
+use common_arrow::arrow::io::parquet::write::WriteOptions;
+use common_arrow::parquet::compression::CompressionOptions;
+use common_arrow::parquet::encoding::Encoding;
+use common_arrow::parquet::write::Version;
+use common_arrow::parquet::write::WriteOptions as FileWriteOptions;
+use common_arrow::write_parquet_file;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::Column;
+
+#[test]
+fn test_time_from_arrow() {
+    let array = Int32Array::from_slice([0, 1, 86399]).to(ArrowDataType::Time32(TimeUnit::Second));
+    assert_eq!(
+        Column::from_arrow(&array, &DataType::Time),
+        Column::Time(vec![0, 1_000_000, 86_399_000_000].into())
+    );
+}
+
+#[test]
+fn test_time_from_parquet() -> Result<()> {
+    // TIME_MILLIS and TIME_MICROS columns.
+    let millis = Int32Array::from_slice([0, 1_500, 86_399_999])
+        .to(ArrowDataType::Time32(TimeUnit::Millisecond));
+    let micros = Int64Array::from_slice([0, 1_500_001, 86_399_999_999])
+        .to(ArrowDataType::Time64(TimeUnit::Microsecond));
+    let schema = Schema::from(vec![
+        Field::new("millis", millis.data_type().clone(), false),
+        Field::new("micros", micros.data_type().clone(), false),
+    ]);
+    let chunk = Chunk::new(vec![millis.boxed(), micros.boxed()]);
+
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+        data_pagesize_limit: None,
+    };
+    let row_groups =
+        RowGroupIterator::try_new(vec![Ok(chunk)].into_iter(), &schema, options, vec![
+            vec![Encoding::Plain],
+            vec![Encoding::Plain],
+        ])?;
+    let mut data = vec![];
+    let file_options = FileWriteOptions {
+        write_statistics: false,
+        version: Version::V2,
+    };
+    write_parquet_file(&mut data, row_groups, schema, file_options, None)?;
+
+    let mut reader = Cursor::new(data);
+    let meta = read_metadata(&mut reader)?;
+    let schema = infer_schema(&meta)?;
+    let mut chunks = FileReader::new(reader, meta.row_groups, schema, None, None, None);
+    let chunk = chunks.next().unwrap()?;
+    let columns = chunk
+        .arrays()
+        .iter()
+        .map(|array| Column::from_arrow(array.as_ref(), &DataType::Time))
+        .collect::<Vec<_>>();
+    assert_eq!(columns, vec![
+        Column::Time(vec![0, 1_500_000, 86_399_999_000].into()),
+        Column::Time(vec![0, 1_500_001, 86_399_999_999].into()),
+    ]);
+    Ok(())
+}
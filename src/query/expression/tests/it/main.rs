@@ -17,6 +17,7 @@
 
 extern crate core;
 
+mod arrow;
 mod block;
 mod common;
 mod decimal;
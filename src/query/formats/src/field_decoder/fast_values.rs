@@ -29,10 +29,13 @@ use common_expression::types::date::check_date;
 use common_expression::types::decimal::Decimal;
 use common_expression::types::decimal::DecimalColumnBuilder;
 use common_expression::types::decimal::DecimalSize;
+use common_expression::types::geometry::parse_geometry;
 use common_expression::types::nullable::NullableColumnBuilder;
 use common_expression::types::number::Number;
 use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::time::string_to_time;
 use common_expression::types::timestamp::check_timestamp;
+use common_expression::types::timestamp_tz::string_to_timestamp_tz;
 use common_expression::types::AnyType;
 use common_expression::types::NumberColumnBuilder;
 use common_expression::with_decimal_type;
@@ -78,6 +81,7 @@ impl FastFieldDecoderValues {
                 nan_bytes: NAN_BYTES_LOWER.as_bytes().to_vec(),
                 inf_bytes: INF_BYTES_LOWER.as_bytes().to_vec(),
                 timezone: format.timezone,
+                binary_format: format.binary_format,
                 disable_variant_check: false,
             },
         }
@@ -132,12 +136,16 @@ impl FastFieldDecoderValues {
             }),
             ColumnBuilder::Date(c) => self.read_date(c, reader, positions),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, reader, positions),
+            ColumnBuilder::TimestampTz(c) => self.read_timestamp_tz(c, reader, positions),
+            ColumnBuilder::Time(c) => self.read_time(c, reader, positions),
             ColumnBuilder::String(c) => self.read_string(c, reader, positions),
             ColumnBuilder::Array(c) => self.read_array(c, reader, positions),
             ColumnBuilder::Map(c) => self.read_map(c, reader, positions),
             ColumnBuilder::Bitmap(_) => Err(ErrorCode::Unimplemented("not implement")),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, reader, positions),
             ColumnBuilder::Variant(c) => self.read_variant(c, reader, positions),
+            ColumnBuilder::Geometry(c) => self.read_geometry(c, reader, positions),
+            ColumnBuilder::Binary(c) => self.read_binary(c, reader, positions),
             _ => unimplemented!(),
         }
     }
@@ -287,6 +295,42 @@ impl FastFieldDecoderValues {
         Ok(())
     }
 
+    fn read_timestamp_tz<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<i128>,
+        reader: &mut Cursor<R>,
+        positions: &mut VecDeque<usize>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, positions)?;
+        let ts =
+            string_to_timestamp_tz(&buf, self.common_settings().timezone).ok_or_else(|| {
+                let data = buf.to_str().unwrap_or("not utf8");
+                ErrorCode::BadBytes(format!(
+                    "fail to deserialize timestamp with time zone: {}",
+                    data
+                ))
+            })?;
+        column.push(ts);
+        Ok(())
+    }
+
+    fn read_time<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<i64>,
+        reader: &mut Cursor<R>,
+        positions: &mut VecDeque<usize>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, positions)?;
+        let time = string_to_time(&buf).ok_or_else(|| {
+            let data = buf.to_str().unwrap_or("not utf8");
+            ErrorCode::BadBytes(format!("fail to deserialize time: {}", data))
+        })?;
+        column.push(time);
+        Ok(())
+    }
+
     fn read_array<R: AsRef<[u8]>>(
         &self,
         column: &mut ArrayColumnBuilder<AnyType>,
@@ -433,4 +477,31 @@ impl FastFieldDecoderValues {
         }
         Ok(())
     }
+
+    /// The string literal is taken as the raw bytes of the binary, the same as
+    /// casting a string to binary.
+    fn read_binary<R: AsRef<[u8]>>(
+        &self,
+        column: &mut StringColumnBuilder,
+        reader: &mut Cursor<R>,
+        positions: &mut VecDeque<usize>,
+    ) -> Result<()> {
+        self.read_string_inner(reader, &mut column.data, positions)?;
+        column.commit_row();
+        Ok(())
+    }
+
+    fn read_geometry<R: AsRef<[u8]>>(
+        &self,
+        column: &mut StringColumnBuilder,
+        reader: &mut Cursor<R>,
+        positions: &mut VecDeque<usize>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, positions)?;
+        let geometry = parse_geometry(&buf).map_err(ErrorCode::BadBytes)?;
+        column.put_slice(&geometry);
+        column.commit_row();
+        Ok(())
+    }
 }
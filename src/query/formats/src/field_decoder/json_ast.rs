@@ -22,14 +22,18 @@ use common_exception::Result;
 use common_expression::serialize::read_decimal_from_json;
 use common_expression::serialize::uniform_date;
 use common_expression::types::array::ArrayColumnBuilder;
+use common_expression::types::binary::decode_binary;
 use common_expression::types::date::check_date;
 use common_expression::types::decimal::Decimal;
 use common_expression::types::decimal::DecimalColumnBuilder;
 use common_expression::types::decimal::DecimalSize;
+use common_expression::types::geometry::parse_geometry;
 use common_expression::types::nullable::NullableColumnBuilder;
 use common_expression::types::number::Number;
 use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::time::string_to_time;
 use common_expression::types::timestamp::check_timestamp;
+use common_expression::types::timestamp_tz::string_to_timestamp_tz;
 use common_expression::types::AnyType;
 use common_expression::types::NumberColumnBuilder;
 use common_expression::with_decimal_type;
@@ -38,6 +42,7 @@ use common_expression::ColumnBuilder;
 use common_io::cursor_ext::BufferReadDateTimeExt;
 use common_io::cursor_ext::DateTimeResType;
 use common_io::cursor_ext::ReadNumberExt;
+use common_io::prelude::BinaryFormat;
 use lexical_core::FromLexical;
 use num::cast::AsPrimitive;
 use serde_json::Value;
@@ -47,6 +52,7 @@ use crate::FileFormatOptionsExt;
 
 pub struct FieldJsonAstDecoder {
     pub timezone: Tz,
+    pub binary_format: BinaryFormat,
     pub ident_case_sensitive: bool,
     pub is_select: bool,
 }
@@ -61,6 +67,7 @@ impl FieldJsonAstDecoder {
     pub fn create(options: &FileFormatOptionsExt) -> Self {
         FieldJsonAstDecoder {
             timezone: options.timezone,
+            binary_format: options.binary_format,
             ident_case_sensitive: options.ident_case_sensitive,
             is_select: options.is_select,
         }
@@ -85,11 +92,15 @@ impl FieldJsonAstDecoder {
             }),
             ColumnBuilder::Date(c) => self.read_date(c, value),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, value),
+            ColumnBuilder::TimestampTz(c) => self.read_timestamp_tz(c, value),
+            ColumnBuilder::Time(c) => self.read_time(c, value),
             ColumnBuilder::String(c) => self.read_string(c, value),
             ColumnBuilder::Array(c) => self.read_array(c, value),
             ColumnBuilder::Map(c) => self.read_map(c, value),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, value),
             ColumnBuilder::Variant(c) => self.read_variant(c, value),
+            ColumnBuilder::Geometry(c) => self.read_geometry(c, value),
+            ColumnBuilder::Binary(c) => self.read_binary(c, value),
             _ => unimplemented!(),
         }
     }
@@ -249,6 +260,66 @@ impl FieldJsonAstDecoder {
         Ok(())
     }
 
+    fn read_timestamp_tz(&self, column: &mut Vec<i128>, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                let ts = string_to_timestamp_tz(v, self.timezone).ok_or_else(|| {
+                    ErrorCode::BadBytes(format!(
+                        "fail to deserialize timestamp with time zone: {}",
+                        v
+                    ))
+                })?;
+                column.push(ts);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes(
+                "Incorrect timestamp with time zone value, must be string",
+            )),
+        }
+    }
+
+    fn read_time(&self, column: &mut Vec<i64>, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                let time = string_to_time(v).ok_or_else(|| {
+                    ErrorCode::BadBytes(format!("fail to deserialize time: {}", v))
+                })?;
+                column.push(time);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect time value, must be string")),
+        }
+    }
+
+    fn read_binary(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
+        match value {
+            Value::String(s) => {
+                let binary = decode_binary(s.as_bytes(), self.binary_format).map_err(|e| {
+                    ErrorCode::BadBytes(format!("fail to deserialize binary: {}", e))
+                })?;
+                column.put_slice(&binary);
+                column.commit_row();
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes(
+                "Incorrect binary value, must be string",
+            )),
+        }
+    }
+
+    fn read_geometry(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
+        // A geometry is either a string of WKT, EWKT or hex encoded WKB, or a GeoJSON object.
+        let geometry = match value {
+            Value::String(s) => parse_geometry(s.as_bytes()),
+            Value::Object(_) => parse_geometry(value.to_string().as_bytes()),
+            _ => return Err(ErrorCode::BadBytes("Incorrect geometry value")),
+        }
+        .map_err(ErrorCode::BadBytes)?;
+        column.put_slice(&geometry);
+        column.commit_row();
+        Ok(())
+    }
+
     fn read_array(&self, column: &mut ArrayColumnBuilder<AnyType>, value: &Value) -> Result<()> {
         match value {
             Value::Array(vals) => {
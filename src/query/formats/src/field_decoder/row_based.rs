@@ -22,14 +22,18 @@ use common_exception::Result;
 use common_expression::serialize::read_decimal_with_size;
 use common_expression::serialize::uniform_date;
 use common_expression::types::array::ArrayColumnBuilder;
+use common_expression::types::binary::decode_binary;
 use common_expression::types::date::check_date;
 use common_expression::types::decimal::Decimal;
 use common_expression::types::decimal::DecimalColumnBuilder;
 use common_expression::types::decimal::DecimalSize;
+use common_expression::types::geometry::parse_geometry;
 use common_expression::types::nullable::NullableColumnBuilder;
 use common_expression::types::number::Number;
 use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::time::string_to_time;
 use common_expression::types::timestamp::check_timestamp;
+use common_expression::types::timestamp_tz::string_to_timestamp_tz;
 use common_expression::types::AnyType;
 use common_expression::types::NumberColumnBuilder;
 use common_expression::with_decimal_type;
@@ -86,12 +90,16 @@ pub trait FieldDecoderRowBased: FieldDecoder {
             }),
             ColumnBuilder::Date(c) => self.read_date(c, reader, raw),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, reader, raw),
+            ColumnBuilder::TimestampTz(c) => self.read_timestamp_tz(c, reader, raw),
+            ColumnBuilder::Time(c) => self.read_time(c, reader, raw),
             ColumnBuilder::String(c) => self.read_string(c, reader, raw),
             ColumnBuilder::Array(c) => self.read_array(c, reader, raw),
             ColumnBuilder::Map(c) => self.read_map(c, reader, raw),
             ColumnBuilder::Bitmap(c) => self.read_string(c, reader, raw),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, reader, raw),
             ColumnBuilder::Variant(c) => self.read_variant(c, reader, raw),
+            ColumnBuilder::Geometry(c) => self.read_geometry(c, reader, raw),
+            ColumnBuilder::Binary(c) => self.read_binary(c, reader, raw),
             _ => unimplemented!(),
         }
     }
@@ -284,6 +292,71 @@ pub trait FieldDecoderRowBased: FieldDecoder {
         Ok(())
     }
 
+    fn read_timestamp_tz<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<i128>,
+        reader: &mut Cursor<R>,
+        raw: bool,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, raw)?;
+        let ts =
+            string_to_timestamp_tz(&buf, self.common_settings().timezone).ok_or_else(|| {
+                let data = buf.to_str().unwrap_or("not utf8");
+                ErrorCode::BadBytes(format!(
+                    "fail to deserialize timestamp with time zone: {}",
+                    data
+                ))
+            })?;
+        column.push(ts);
+        Ok(())
+    }
+
+    fn read_time<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<i64>,
+        reader: &mut Cursor<R>,
+        raw: bool,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, raw)?;
+        let time = string_to_time(&buf).ok_or_else(|| {
+            let data = buf.to_str().unwrap_or("not utf8");
+            ErrorCode::BadBytes(format!("fail to deserialize time: {}", data))
+        })?;
+        column.push(time);
+        Ok(())
+    }
+
+    fn read_binary<R: AsRef<[u8]>>(
+        &self,
+        column: &mut StringColumnBuilder,
+        reader: &mut Cursor<R>,
+        raw: bool,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, raw)?;
+        let binary = decode_binary(&buf, self.common_settings().binary_format)
+            .map_err(|e| ErrorCode::BadBytes(format!("fail to deserialize binary: {}", e)))?;
+        column.put_slice(&binary);
+        column.commit_row();
+        Ok(())
+    }
+
+    fn read_geometry<R: AsRef<[u8]>>(
+        &self,
+        column: &mut StringColumnBuilder,
+        reader: &mut Cursor<R>,
+        raw: bool,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, raw)?;
+        let geometry = parse_geometry(&buf).map_err(ErrorCode::BadBytes)?;
+        column.put_slice(&geometry);
+        column.commit_row();
+        Ok(())
+    }
+
     fn read_array<R: AsRef<[u8]>>(
         &self,
         column: &mut ArrayColumnBuilder<AnyType>,
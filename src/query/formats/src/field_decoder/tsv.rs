@@ -52,6 +52,7 @@ impl FieldDecoderTSV {
                 nan_bytes: NAN_BYTES_LOWER.as_bytes().to_vec(),
                 inf_bytes: INF_BYTES_LOWER.as_bytes().to_vec(),
                 timezone: options_ext.timezone,
+                binary_format: options_ext.binary_format,
                 disable_variant_check: options_ext.disable_variant_check,
             },
             quote_char: params.quote.as_bytes()[0],
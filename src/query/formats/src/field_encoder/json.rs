@@ -42,6 +42,7 @@ impl FieldEncoderJSON {
                 inf_bytes: NULL_BYTES_LOWER.as_bytes().to_vec(),
                 null_bytes: NULL_BYTES_LOWER.as_bytes().to_vec(),
                 timezone: options.timezone,
+                binary_format: options.binary_format,
                 disable_variant_check: options.disable_variant_check,
             },
             quote_denormals: false,
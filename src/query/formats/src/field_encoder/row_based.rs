@@ -15,12 +15,16 @@
 use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::buffer::Buffer;
 use common_expression::types::array::ArrayColumn;
+use common_expression::types::binary::encode_binary;
 use common_expression::types::date::date_to_string;
 use common_expression::types::decimal::DecimalColumn;
+use common_expression::types::geometry::display_geometry;
 use common_expression::types::nullable::NullableColumn;
 use common_expression::types::number::NumberColumn;
 use common_expression::types::string::StringColumn;
+use common_expression::types::time::time_to_string;
 use common_expression::types::timestamp::timestamp_to_string;
+use common_expression::types::timestamp_tz::timestamp_tz_to_string;
 use common_expression::types::ValueType;
 use common_expression::Column;
 use lexical_core::ToLexical;
@@ -59,6 +63,8 @@ pub trait FieldEncoderRowBased {
             Column::Decimal(c) => self.write_decimal(c, row_index, out_buf),
             Column::Date(c) => self.write_date(c, row_index, out_buf, raw),
             Column::Timestamp(c) => self.write_timestamp(c, row_index, out_buf, raw),
+            Column::TimestampTz(c) => self.write_timestamp_tz(c, row_index, out_buf, raw),
+            Column::Time(c) => self.write_time(c, row_index, out_buf, raw),
             Column::String(c) => self.write_string(c, row_index, out_buf, raw),
             Column::Nullable(box c) => self.write_nullable(c, row_index, out_buf, raw),
             Column::Array(box c) => self.write_array(c, row_index, out_buf, raw),
@@ -66,6 +72,8 @@ pub trait FieldEncoderRowBased {
             Column::Bitmap(b) => self.write_string(b, row_index, out_buf, raw),
             Column::Tuple(fields) => self.write_tuple(fields, row_index, out_buf, raw),
             Column::Variant(c) => self.write_variant(c, row_index, out_buf, raw),
+            Column::Geometry(c) => self.write_geometry(c, row_index, out_buf, raw),
+            Column::Binary(c) => self.write_binary(c, row_index, out_buf, raw),
         }
     }
 
@@ -165,6 +173,24 @@ pub trait FieldEncoderRowBased {
         self.write_string_inner(s.as_bytes(), out_buf, raw);
     }
 
+    fn write_timestamp_tz(
+        &self,
+        column: &Buffer<i128>,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        raw: bool,
+    ) {
+        let v = unsafe { column.get_unchecked(row_index) };
+        let s = timestamp_tz_to_string(*v).to_string();
+        self.write_string_inner(s.as_bytes(), out_buf, raw);
+    }
+
+    fn write_time(&self, column: &Buffer<i64>, row_index: usize, out_buf: &mut Vec<u8>, raw: bool) {
+        let v = unsafe { column.get_unchecked(row_index) };
+        let s = time_to_string(*v).to_string();
+        self.write_string_inner(s.as_bytes(), out_buf, raw);
+    }
+
     fn write_variant(
         &self,
         column: &StringColumn,
@@ -177,6 +203,30 @@ pub trait FieldEncoderRowBased {
         self.write_string_inner(s.as_bytes(), out_buf, raw);
     }
 
+    fn write_binary(
+        &self,
+        column: &StringColumn,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        raw: bool,
+    ) {
+        let v = unsafe { column.index_unchecked(row_index) };
+        let s = encode_binary(v, self.common_settings().binary_format);
+        self.write_string_inner(s.as_bytes(), out_buf, raw);
+    }
+
+    fn write_geometry(
+        &self,
+        column: &StringColumn,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        raw: bool,
+    ) {
+        let v = unsafe { column.index_unchecked(row_index) };
+        let s = display_geometry(v);
+        self.write_string_inner(s.as_bytes(), out_buf, raw);
+    }
+
     fn write_array<T: ValueType>(
         &self,
         column: &ArrayColumn<T>,
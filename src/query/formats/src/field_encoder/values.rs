@@ -23,6 +23,7 @@ use common_io::constants::NAN_BYTES_LOWER;
 use common_io::constants::NAN_BYTES_SNAKE;
 use common_io::constants::NULL_BYTES_UPPER;
 use common_io::constants::TRUE_BYTES_NUM;
+use common_io::prelude::BinaryFormat;
 
 use super::helpers::write_escaped_string;
 use crate::field_encoder::FieldEncoderRowBased;
@@ -44,13 +45,14 @@ impl FieldEncoderValues {
                 nan_bytes: NAN_BYTES_LOWER.as_bytes().to_vec(),
                 inf_bytes: INF_BYTES_LOWER.as_bytes().to_vec(),
                 timezone: options.timezone,
+                binary_format: options.binary_format,
                 disable_variant_check: false,
             },
             quote_char: b'\'',
         }
     }
 
-    pub fn create_for_http_handler(timezone: Tz) -> Self {
+    pub fn create_for_http_handler(timezone: Tz, binary_format: BinaryFormat) -> Self {
         FieldEncoderValues {
             common_settings: CommonSettings {
                 true_bytes: TRUE_BYTES_NUM.as_bytes().to_vec(),
@@ -59,6 +61,7 @@ impl FieldEncoderValues {
                 nan_bytes: NAN_BYTES_LOWER.as_bytes().to_vec(),
                 inf_bytes: INF_BYTES_LOWER.as_bytes().to_vec(),
                 timezone,
+                binary_format,
                 disable_variant_check: false,
             },
             quote_char: b'\'',
@@ -69,7 +72,7 @@ impl FieldEncoderValues {
     // mysql python client will decode to python float, which is printed as 'nan' and 'inf'
     // so we still use 'nan' and 'inf' in logic test.
     // https://github.com/datafuselabs/databend/discussions/8941
-    pub fn create_for_mysql_handler(timezone: Tz) -> Self {
+    pub fn create_for_mysql_handler(timezone: Tz, binary_format: BinaryFormat) -> Self {
         FieldEncoderValues {
             common_settings: CommonSettings {
                 true_bytes: TRUE_BYTES_NUM.as_bytes().to_vec(),
@@ -78,6 +81,7 @@ impl FieldEncoderValues {
                 nan_bytes: NAN_BYTES_SNAKE.as_bytes().to_vec(),
                 inf_bytes: INF_BYTES_LONG.as_bytes().to_vec(),
                 timezone,
+                binary_format,
                 disable_variant_check: false,
             },
             quote_char: b'\'',
@@ -16,6 +16,7 @@ use chrono_tz::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::TableSchemaRef;
+use common_io::prelude::BinaryFormat;
 use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::StageFileFormatType;
 use common_settings::Settings;
@@ -44,6 +45,7 @@ pub struct FileFormatOptionsExt {
     pub json_strings: bool,
     pub disable_variant_check: bool,
     pub timezone: Tz,
+    pub binary_format: BinaryFormat,
     pub is_select: bool,
 }
 
@@ -53,6 +55,7 @@ impl FileFormatOptionsExt {
         is_select: bool,
     ) -> Result<FileFormatOptionsExt> {
         let timezone = parse_timezone(settings)?;
+        let binary_format = parse_binary_format(settings)?;
         let options = FileFormatOptionsExt {
             ident_case_sensitive: false,
             headers: 0,
@@ -60,6 +63,7 @@ impl FileFormatOptionsExt {
             json_strings: false,
             disable_variant_check: false,
             timezone,
+            binary_format,
             is_select,
         };
         Ok(options)
@@ -70,6 +74,7 @@ impl FileFormatOptionsExt {
         settings: &Settings,
     ) -> Result<FileFormatOptionsExt> {
         let timezone = parse_timezone(settings)?;
+        let binary_format = parse_binary_format(settings)?;
         let mut options = FileFormatOptionsExt {
             ident_case_sensitive: settings.get_unquoted_ident_case_sensitive()?,
             headers: 0,
@@ -77,6 +82,7 @@ impl FileFormatOptionsExt {
             json_strings: false,
             disable_variant_check: false,
             timezone,
+            binary_format,
             is_select: false,
         };
         let suf = &clickhouse_type.suffixes;
@@ -187,3 +193,7 @@ pub fn parse_timezone(settings: &Settings) -> Result<Tz> {
     tz.parse::<Tz>()
         .map_err(|_| ErrorCode::InvalidTimezone("Timezone has been checked and should be valid"))
 }
+
+pub fn parse_binary_format(settings: &Settings) -> Result<BinaryFormat> {
+    settings.get_binary_output_format()?.parse::<BinaryFormat>()
+}
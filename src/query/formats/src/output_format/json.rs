@@ -13,7 +13,11 @@
 // limitations under the License.
 
 use common_expression::date_helper::DateConverter;
+use common_expression::types::binary::encode_binary;
+use common_expression::types::geometry::display_geometry;
 use common_expression::types::number::NumberScalar;
+use common_expression::types::time::time_to_string;
+use common_expression::types::timestamp_tz::timestamp_tz_to_string;
 use common_expression::DataBlock;
 use common_expression::ScalarRef;
 use common_expression::TableSchemaRef;
@@ -43,6 +47,7 @@ impl JSONOutputFormat {
             rows: 0,
             format_settings: FormatSettings {
                 timezone: options.timezone,
+                binary_format: options.binary_format,
             },
         }
     }
@@ -97,6 +102,10 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             let dt = DateConverter::to_timestamp(&v, format.timezone);
             serde_json::to_value(dt.format("%Y-%m-%d %H:%M:%S").to_string()).unwrap()
         }
+        ScalarRef::TimestampTz(v) => {
+            serde_json::to_value(timestamp_tz_to_string(v).to_string()).unwrap()
+        }
+        ScalarRef::Time(v) => serde_json::to_value(time_to_string(v).to_string()).unwrap(),
         ScalarRef::EmptyArray => JsonValue::Array(vec![]),
         ScalarRef::EmptyMap => JsonValue::Object(JsonMap::new()),
         ScalarRef::String(x) => JsonValue::String(String::from_utf8_lossy(x).to_string()),
@@ -141,6 +150,8 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             let b = jsonb::from_slice(x).unwrap();
             b.into()
         }
+        ScalarRef::Geometry(x) => JsonValue::String(display_geometry(x)),
+        ScalarRef::Binary(x) => JsonValue::String(encode_binary(x, format.binary_format)),
     }
 }
 
@@ -0,0 +1,309 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::Number;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberType;
+use common_expression::types::ValueType;
+use common_expression::with_number_mapped_type;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_io::prelude::*;
+use num_traits::AsPrimitive;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregator_common::get_u64_param;
+use crate::aggregates::assert_unary_arguments;
+use crate::aggregates::assert_unary_params;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+const MAX_HISTOGRAM_BINS: u64 = 250;
+
+/// Streaming histogram from "A Streaming Parallel Decision Tree Algorithm"
+/// (Y. Ben-Haim and E. Tom-Tov, 2010). Values are kept as weighted centroids,
+/// and the two closest centroids are merged until at most `max_bins` remain.
+#[derive(Serialize, Deserialize)]
+pub struct HistogramState {
+    // (mean, weight) of every centroid.
+    pub centroids: Vec<(f64, f64)>,
+    pub lower: f64,
+    pub upper: f64,
+}
+
+impl HistogramState {
+    fn new() -> Self {
+        Self {
+            centroids: vec![],
+            lower: f64::INFINITY,
+            upper: f64::NEG_INFINITY,
+        }
+    }
+
+    #[inline(always)]
+    fn add(&mut self, value: f64, max_bins: usize) {
+        if !value.is_finite() {
+            return;
+        }
+        self.lower = self.lower.min(value);
+        self.upper = self.upper.max(value);
+        self.centroids.push((value, 1.0));
+        // Compress lazily, so that the quadratic merge runs once every `max_bins` values.
+        if self.centroids.len() >= max_bins * 2 {
+            self.compress(max_bins);
+        }
+    }
+
+    fn merge(&mut self, rhs: &Self, max_bins: usize) {
+        if rhs.centroids.is_empty() {
+            return;
+        }
+        self.lower = self.lower.min(rhs.lower);
+        self.upper = self.upper.max(rhs.upper);
+        self.centroids.extend_from_slice(&rhs.centroids);
+        self.compress(max_bins);
+    }
+
+    fn compress(&mut self, max_bins: usize) {
+        self.centroids.sort_by(|a, b| a.0.total_cmp(&b.0));
+        self.centroids.dedup_by(|next, prev| {
+            if next.0 == prev.0 {
+                prev.1 += next.1;
+                true
+            } else {
+                false
+            }
+        });
+
+        while self.centroids.len() > max_bins {
+            let idx = self
+                .centroids
+                .windows(2)
+                .enumerate()
+                .min_by(|(_, a), (_, b)| (a[1].0 - a[0].0).total_cmp(&(b[1].0 - b[0].0)))
+                .map(|(idx, _)| idx)
+                .unwrap();
+            let (left_mean, left_weight) = self.centroids[idx];
+            let (right_mean, right_weight) = self.centroids.remove(idx + 1);
+            let weight = left_weight + right_weight;
+            let mean = (left_mean * left_weight + right_mean * right_weight) / weight;
+            self.centroids[idx] = (mean, weight);
+        }
+    }
+
+    /// Bins as `(lower, upper, height)`, the bounds lie halfway between neighboring centroids.
+    fn bins(&self) -> Vec<(f64, f64, f64)> {
+        let len = self.centroids.len();
+        self.centroids
+            .iter()
+            .enumerate()
+            .map(|(idx, (mean, weight))| {
+                let lower = if idx == 0 {
+                    self.lower
+                } else {
+                    (self.centroids[idx - 1].0 + mean) / 2.0
+                };
+                let upper = if idx + 1 == len {
+                    self.upper
+                } else {
+                    (mean + self.centroids[idx + 1].0) / 2.0
+                };
+                (lower, upper, *weight)
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateHistogramFunction<T> {
+    display_name: String,
+    max_bins: usize,
+    _t: PhantomData<T>,
+}
+
+impl<T> AggregateFunction for AggregateHistogramFunction<T>
+where T: Number + AsPrimitive<f64>
+{
+    fn name(&self) -> &str {
+        "AggregateHistogramFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        let float64 = DataType::Number(NumberDataType::Float64);
+        Ok(DataType::Array(Box::new(DataType::Tuple(vec![
+            float64.clone(),
+            float64.clone(),
+            float64,
+        ]))))
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(HistogramState::new);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<HistogramState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column = NumberType::<T>::try_downcast_column(&columns[0]).unwrap();
+        let state = place.get::<HistogramState>();
+        match validity {
+            Some(bitmap) => {
+                for (value, valid) in column.iter().zip(bitmap.iter()) {
+                    if valid {
+                        state.add(value.as_(), self.max_bins);
+                    }
+                }
+            }
+            None => {
+                for value in column.iter() {
+                    state.add(value.as_(), self.max_bins);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column = NumberType::<T>::try_downcast_column(&columns[0]).unwrap();
+        column.iter().zip(places.iter()).for_each(|(value, place)| {
+            let place = place.next(offset);
+            let state = place.get::<HistogramState>();
+            state.add(value.as_(), self.max_bins);
+        });
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let column = NumberType::<T>::try_downcast_column(&columns[0]).unwrap();
+        let state = place.get::<HistogramState>();
+        state.add(column[row].as_(), self.max_bins);
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<HistogramState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<HistogramState>();
+        *state = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<HistogramState>();
+        let rhs = rhs.get::<HistogramState>();
+        state.merge(rhs, self.max_bins);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<HistogramState>();
+        state.compress(self.max_bins);
+        let bins = state.bins();
+
+        let data_type = self.return_type()?;
+        let inner_type = data_type.as_array().unwrap();
+        let mut inner_builder = ColumnBuilder::with_capacity(inner_type, bins.len());
+        for (lower, upper, height) in bins {
+            inner_builder.push(ScalarRef::Tuple(vec![
+                ScalarRef::Number(NumberScalar::Float64(lower.into())),
+                ScalarRef::Number(NumberScalar::Float64(upper.into())),
+                ScalarRef::Number(NumberScalar::Float64(height.into())),
+            ]));
+        }
+        builder.push(ScalarRef::Array(inner_builder.build()));
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<HistogramState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl<T> fmt::Display for AggregateHistogramFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub fn try_create_aggregate_histogram_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_params(display_name, params.len())?;
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    let max_bins = get_u64_param(&params[0])?;
+    if max_bins == 0 || max_bins > MAX_HISTOGRAM_BINS {
+        return Err(ErrorCode::BadArguments(format!(
+            "The number of bins of {} must be between 1 and {}, but got {}",
+            display_name, MAX_HISTOGRAM_BINS, max_bins
+        )));
+    }
+
+    with_number_mapped_type!(|NUM_TYPE| match &arguments[0] {
+        DataType::Number(NumberDataType::NUM_TYPE) => {
+            Ok(Arc::new(AggregateHistogramFunction::<NUM_TYPE> {
+                display_name: display_name.to_string(),
+                max_bins: max_bins as usize,
+                _t: PhantomData,
+            }))
+        }
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "{} does not support type '{:?}'",
+            display_name, arguments[0]
+        ))),
+    })
+}
+
+pub fn aggregate_histogram_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_histogram_function))
+}
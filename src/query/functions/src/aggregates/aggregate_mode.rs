@@ -0,0 +1,260 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::collections::HashMap;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::Result;
+use common_expression::types::number::F64;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberType;
+use common_expression::types::ValueType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_io::prelude::*;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+/// Number of occurrences of every distinct value, shared by `mode` and `entropy`.
+#[derive(Serialize, Deserialize, Default)]
+pub struct FrequencyState {
+    pub counts: HashMap<Scalar, u64>,
+}
+
+impl FrequencyState {
+    #[inline(always)]
+    fn add(&mut self, value: Scalar, count: u64) {
+        *self.counts.entry(value).or_insert(0) += count;
+    }
+
+    fn merge(&mut self, rhs: &Self) {
+        for (value, count) in rhs.counts.iter() {
+            self.add(value.clone(), *count);
+        }
+    }
+}
+
+pub trait FrequencyAggregate: Send + Sync + 'static {
+    fn name() -> &'static str;
+
+    fn return_type(argument: &DataType) -> DataType;
+
+    fn merge_result(state: &FrequencyState, builder: &mut ColumnBuilder) -> Result<()>;
+}
+
+#[derive(Clone)]
+pub struct AggregateFrequencyFunction<R> {
+    display_name: String,
+    return_type: DataType,
+    _r: PhantomData<R>,
+}
+
+impl<R> AggregateFunction for AggregateFrequencyFunction<R>
+where R: FrequencyAggregate
+{
+    fn name(&self) -> &str {
+        R::name()
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(self.return_type.clone())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(FrequencyState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<FrequencyState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<FrequencyState>();
+        match validity {
+            Some(bitmap) => {
+                for (value, valid) in columns[0].iter().zip(bitmap.iter()) {
+                    if valid {
+                        state.add(value.to_owned(), 1);
+                    }
+                }
+            }
+            None => {
+                for value in columns[0].iter() {
+                    state.add(value.to_owned(), 1);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (value, place) in columns[0].iter().zip(places.iter()) {
+            let place = place.next(offset);
+            let state = place.get::<FrequencyState>();
+            state.add(value.to_owned(), 1);
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let state = place.get::<FrequencyState>();
+        if let Some(value) = columns[0].index(row) {
+            state.add(value.to_owned(), 1);
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<FrequencyState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<FrequencyState>();
+        *state = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<FrequencyState>();
+        let rhs = rhs.get::<FrequencyState>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<FrequencyState>();
+        R::merge_result(state, builder)
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<FrequencyState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl<R> fmt::Display for AggregateFrequencyFunction<R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub fn try_create_aggregate_frequency<R: FrequencyAggregate>(
+    display_name: &str,
+    _params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    Ok(Arc::new(AggregateFrequencyFunction::<R> {
+        display_name: display_name.to_string(),
+        return_type: R::return_type(&arguments[0]),
+        _r: PhantomData,
+    }))
+}
+
+// `mode` returns the most frequent value, the smallest one wins on ties.
+struct AggregateModeImpl;
+
+impl FrequencyAggregate for AggregateModeImpl {
+    fn name() -> &'static str {
+        "AggregateModeFunction"
+    }
+
+    fn return_type(argument: &DataType) -> DataType {
+        argument.clone()
+    }
+
+    fn merge_result(state: &FrequencyState, builder: &mut ColumnBuilder) -> Result<()> {
+        let mode = state
+            .counts
+            .iter()
+            .max_by(|(a_value, a_count), (b_value, b_count)| {
+                a_count.cmp(b_count).then_with(|| b_value.cmp(a_value))
+            });
+        match mode {
+            Some((value, _)) => builder.push(value.as_ref()),
+            None => builder.push_default(),
+        }
+        Ok(())
+    }
+}
+
+pub fn aggregate_mode_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_frequency::<AggregateModeImpl>,
+    ))
+}
+
+// `entropy` returns the Shannon entropy, in bits, of the value distribution.
+struct AggregateEntropyImpl;
+
+impl FrequencyAggregate for AggregateEntropyImpl {
+    fn name() -> &'static str {
+        "AggregateEntropyFunction"
+    }
+
+    fn return_type(_argument: &DataType) -> DataType {
+        DataType::Number(NumberDataType::Float64)
+    }
+
+    fn merge_result(state: &FrequencyState, builder: &mut ColumnBuilder) -> Result<()> {
+        let builder = NumberType::<F64>::try_downcast_builder(builder).unwrap();
+        let total = state.counts.values().sum::<u64>() as f64;
+        let entropy = state
+            .counts
+            .values()
+            .map(|count| {
+                let p = *count as f64 / total;
+                p * (1.0 / p).log2()
+            })
+            .sum::<f64>();
+        builder.push(entropy.into());
+        Ok(())
+    }
+}
+
+pub fn aggregate_entropy_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_frequency::<AggregateEntropyImpl>,
+    ))
+}
@@ -0,0 +1,438 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::Number;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::number::F64;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberType;
+use common_expression::types::ValueType;
+use common_expression::with_number_mapped_type;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_io::prelude::*;
+use num_traits::AsPrimitive;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionFeatures;
+use crate::aggregates::aggregator_common::assert_binary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+/// State shared by `corr` and the `regr_*` functions. Following the SQL standard,
+/// the first argument is the dependent variable `y` and the second one is the
+/// independent variable `x`.
+#[derive(Serialize, Deserialize, Default)]
+pub struct AggregateRegressionState {
+    pub count: u64,
+    pub x_mean: f64,
+    pub y_mean: f64,
+    // Sum of squared deviations of x and y from their means.
+    pub x_m2: f64,
+    pub y_m2: f64,
+    // Sum of the products of the deviations of x and y.
+    pub co_moments: f64,
+}
+
+// Welford's online update, extended to co-moments, and the pairwise merge
+// from "Numerically Stable, Single-Pass, Parallel Statistics Algorithms"
+// (J. Bennett et al., 2009), the same source as the covariance aggregates.
+impl AggregateRegressionState {
+    #[inline(always)]
+    fn add(&mut self, y: f64, x: f64) {
+        self.count += 1;
+        let n = self.count as f64;
+
+        let x_delta = x - self.x_mean;
+        let y_delta = y - self.y_mean;
+        self.x_mean += x_delta / n;
+        self.y_mean += y_delta / n;
+
+        self.x_m2 += x_delta * (x - self.x_mean);
+        self.y_m2 += y_delta * (y - self.y_mean);
+        self.co_moments += x_delta * (y - self.y_mean);
+    }
+
+    #[inline(always)]
+    fn merge(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+
+        let total = self.count + other.count;
+        let factor = self.count as f64 * other.count as f64 / total as f64;
+        let x_delta = other.x_mean - self.x_mean;
+        let y_delta = other.y_mean - self.y_mean;
+
+        self.x_m2 += other.x_m2 + x_delta * x_delta * factor;
+        self.y_m2 += other.y_m2 + y_delta * y_delta * factor;
+        self.co_moments += other.co_moments + x_delta * y_delta * factor;
+
+        self.x_mean += x_delta * other.count as f64 / total as f64;
+        self.y_mean += y_delta * other.count as f64 / total as f64;
+        self.count = total;
+    }
+
+    fn slope(&self) -> Option<f64> {
+        if self.count == 0 || self.x_m2 == 0.0 {
+            None
+        } else {
+            Some(self.co_moments / self.x_m2)
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateRegressionFunction<T0, T1, R> {
+    display_name: String,
+    _t0: PhantomData<T0>,
+    _t1: PhantomData<T1>,
+    _r: PhantomData<R>,
+}
+
+impl<T0, T1, R> AggregateFunction for AggregateRegressionFunction<T0, T1, R>
+where
+    T0: Number + AsPrimitive<f64>,
+    T1: Number + AsPrimitive<f64>,
+    R: AggregateRegression,
+{
+    fn name(&self) -> &str {
+        R::name()
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(R::return_type())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateRegressionState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateRegressionState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateRegressionState>();
+        let left = NumberType::<T0>::try_downcast_column(&columns[0]).unwrap();
+        let right = NumberType::<T1>::try_downcast_column(&columns[1]).unwrap();
+
+        match validity {
+            Some(bitmap) => {
+                left.iter().zip(right.iter()).zip(bitmap.iter()).for_each(
+                    |((left_val, right_val), valid)| {
+                        if valid {
+                            state.add(left_val.as_(), right_val.as_());
+                        }
+                    },
+                );
+            }
+            None => {
+                left.iter()
+                    .zip(right.iter())
+                    .for_each(|(left_val, right_val)| {
+                        state.add(left_val.as_(), right_val.as_());
+                    });
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let left = NumberType::<T0>::try_downcast_column(&columns[0]).unwrap();
+        let right = NumberType::<T1>::try_downcast_column(&columns[1]).unwrap();
+
+        left.iter().zip(right.iter()).zip(places.iter()).for_each(
+            |((left_val, right_val), place)| {
+                let place = place.next(offset);
+                let state = place.get::<AggregateRegressionState>();
+                state.add(left_val.as_(), right_val.as_());
+            },
+        );
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let left = NumberType::<T0>::try_downcast_column(&columns[0]).unwrap();
+        let right = NumberType::<T1>::try_downcast_column(&columns[1]).unwrap();
+
+        let left_val = unsafe { left.get_unchecked(row) };
+        let right_val = unsafe { right.get_unchecked(row) };
+
+        let state = place.get::<AggregateRegressionState>();
+        state.add(left_val.as_(), right_val.as_());
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<AggregateRegressionState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateRegressionState>();
+        *state = deserialize_from_slice(reader)?;
+
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateRegressionState>();
+        let rhs = rhs.get::<AggregateRegressionState>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<AggregateRegressionState>();
+        R::merge_result(state, builder)
+    }
+}
+
+impl<T0, T1, R> fmt::Display for AggregateRegressionFunction<T0, T1, R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl<T0, T1, R> AggregateRegressionFunction<T0, T1, R>
+where
+    T0: Number + AsPrimitive<f64>,
+    T1: Number + AsPrimitive<f64>,
+    R: AggregateRegression,
+{
+    pub fn try_create(
+        display_name: &str,
+        _arguments: Vec<DataType>,
+    ) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            _t0: PhantomData,
+            _t1: PhantomData,
+            _r: PhantomData,
+        }))
+    }
+}
+
+pub fn try_create_aggregate_regression<R: AggregateRegression>(
+    display_name: &str,
+    _params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_binary_arguments(display_name, arguments.len())?;
+
+    with_number_mapped_type!(|NUM_TYPE0| match &arguments[0] {
+        DataType::Number(NumberDataType::NUM_TYPE0) =>
+            with_number_mapped_type!(|NUM_TYPE1| match &arguments[1] {
+                DataType::Number(NumberDataType::NUM_TYPE1) => {
+                    return AggregateRegressionFunction::<NUM_TYPE0, NUM_TYPE1, R>::try_create(
+                        display_name,
+                        arguments,
+                    );
+                }
+                _ => (),
+            }),
+        _ => (),
+    });
+
+    Err(ErrorCode::BadDataValueType(format!(
+        "Expected number data type, but got {:?}",
+        arguments
+    )))
+}
+
+pub trait AggregateRegression: Send + Sync + 'static {
+    fn name() -> &'static str;
+
+    /// Returns `None` when the result is undefined, e.g. when `x` has no variance.
+    fn apply(state: &AggregateRegressionState) -> Option<f64>;
+
+    fn return_type() -> DataType {
+        DataType::Nullable(Box::new(DataType::Number(NumberDataType::Float64)))
+    }
+
+    fn merge_result(state: &AggregateRegressionState, builder: &mut ColumnBuilder) -> Result<()> {
+        let builder = match builder {
+            ColumnBuilder::Nullable(box b) => b,
+            _ => unreachable!(),
+        };
+        match Self::apply(state) {
+            Some(value) => builder.push(NumberType::<F64>::upcast_scalar(value.into()).as_ref()),
+            None => builder.push_null(),
+        }
+        Ok(())
+    }
+}
+
+macro_rules! impl_regression {
+    ($impl_name: ident, $name: expr, $desc: ident, |$state: ident| $apply: expr) => {
+        struct $impl_name;
+
+        impl AggregateRegression for $impl_name {
+            fn name() -> &'static str {
+                $name
+            }
+
+            fn apply($state: &AggregateRegressionState) -> Option<f64> {
+                $apply
+            }
+        }
+
+        pub fn $desc() -> AggregateFunctionDescription {
+            AggregateFunctionDescription::creator(Box::new(
+                try_create_aggregate_regression::<$impl_name>,
+            ))
+        }
+    };
+}
+
+impl_regression!(
+    AggregateCorrImpl,
+    "AggregateCorrFunction",
+    aggregate_corr_function_desc,
+    |state| {
+        if state.count == 0 || state.x_m2 == 0.0 || state.y_m2 == 0.0 {
+            None
+        } else {
+            Some(state.co_moments / (state.x_m2 * state.y_m2).sqrt())
+        }
+    }
+);
+
+impl_regression!(
+    AggregateRegrSlopeImpl,
+    "AggregateRegrSlopeFunction",
+    aggregate_regr_slope_function_desc,
+    |state| state.slope()
+);
+
+impl_regression!(
+    AggregateRegrInterceptImpl,
+    "AggregateRegrInterceptFunction",
+    aggregate_regr_intercept_function_desc,
+    |state| state
+        .slope()
+        .map(|slope| state.y_mean - slope * state.x_mean)
+);
+
+// A constant `y` is perfectly explained by any line, so `regr_r2` is 1 in that case.
+impl_regression!(
+    AggregateRegrR2Impl,
+    "AggregateRegrR2Function",
+    aggregate_regr_r2_function_desc,
+    |state| {
+        if state.count == 0 || state.x_m2 == 0.0 {
+            None
+        } else if state.y_m2 == 0.0 {
+            Some(1.0)
+        } else {
+            Some(state.co_moments * state.co_moments / (state.x_m2 * state.y_m2))
+        }
+    }
+);
+
+impl_regression!(
+    AggregateRegrAvgxImpl,
+    "AggregateRegrAvgxFunction",
+    aggregate_regr_avgx_function_desc,
+    |state| (state.count > 0).then_some(state.x_mean)
+);
+
+impl_regression!(
+    AggregateRegrAvgyImpl,
+    "AggregateRegrAvgyFunction",
+    aggregate_regr_avgy_function_desc,
+    |state| (state.count > 0).then_some(state.y_mean)
+);
+
+impl_regression!(
+    AggregateRegrSxxImpl,
+    "AggregateRegrSxxFunction",
+    aggregate_regr_sxx_function_desc,
+    |state| (state.count > 0).then_some(state.x_m2)
+);
+
+impl_regression!(
+    AggregateRegrSyyImpl,
+    "AggregateRegrSyyFunction",
+    aggregate_regr_syy_function_desc,
+    |state| (state.count > 0).then_some(state.y_m2)
+);
+
+impl_regression!(
+    AggregateRegrSxyImpl,
+    "AggregateRegrSxyFunction",
+    aggregate_regr_sxy_function_desc,
+    |state| (state.count > 0).then_some(state.co_moments)
+);
+
+// `regr_count` behaves like `count`: it is never NULL, only pairs where both
+// arguments are not NULL are counted.
+struct AggregateRegrCountImpl;
+
+impl AggregateRegression for AggregateRegrCountImpl {
+    fn name() -> &'static str {
+        "AggregateRegrCountFunction"
+    }
+
+    fn apply(state: &AggregateRegressionState) -> Option<f64> {
+        Some(state.count as f64)
+    }
+
+    fn return_type() -> DataType {
+        DataType::Number(NumberDataType::UInt64)
+    }
+
+    fn merge_result(state: &AggregateRegressionState, builder: &mut ColumnBuilder) -> Result<()> {
+        let builder = UInt64Type::try_downcast_builder(builder).unwrap();
+        builder.push(state.count);
+        Ok(())
+    }
+}
+
+pub fn aggregate_regr_count_function_desc() -> AggregateFunctionDescription {
+    let features = AggregateFunctionFeatures {
+        returns_default_when_only_null: true,
+        ..Default::default()
+    };
+    AggregateFunctionDescription::creator_with_features(
+        Box::new(try_create_aggregate_regression::<AggregateRegrCountImpl>),
+        features,
+    )
+}
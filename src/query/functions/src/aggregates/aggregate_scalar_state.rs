@@ -37,6 +37,8 @@ macro_rules! with_simple_no_number_mapped_type {
                 String => StringType,
                 Boolean => BooleanType,
                 Timestamp => TimestampType,
+                TimestampTz => TimestampTzType,
+                Time => TimeType,
                 Null => NullType,
                 EmptyArray => EmptyArrayType,
                 EmptyMap => EmptyMapType,
@@ -250,7 +252,7 @@ where
 
 pub fn need_manual_drop_state(data_type: &DataType) -> bool {
     match data_type {
-        DataType::String | DataType::Variant => true,
+        DataType::String | DataType::Variant | DataType::Geometry | DataType::Binary => true,
         DataType::Nullable(t) | DataType::Array(t) | DataType::Map(t) => need_manual_drop_state(t),
         DataType::Tuple(ts) => ts.iter().any(need_manual_drop_state),
         _ => false,
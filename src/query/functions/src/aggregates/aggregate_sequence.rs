@@ -0,0 +1,613 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::collections::HashSet;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Sub;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::Number;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::number::UInt8Type;
+use common_expression::types::timestamp::MICROS_IN_A_SEC;
+use common_expression::types::ArgType;
+use common_expression::types::BooleanType;
+use common_expression::types::DataType;
+use common_expression::types::DateType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberType;
+use common_expression::types::TimestampType;
+use common_expression::types::ValueType;
+use common_expression::with_integer_mapped_type;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_io::prelude::*;
+use num_traits::AsPrimitive;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::AggregateFunctionRef;
+use super::AggregateNullVariadicAdaptor;
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::assert_unary_params;
+use crate::aggregates::assert_variadic_arguments;
+use crate::aggregates::AggregateFunction;
+
+const SECONDS_IN_A_DAY: u64 = 24 * 3600;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TimeOp {
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+    Eq,
+}
+
+impl TimeOp {
+    fn check(&self, lhs: u64, rhs: u64) -> bool {
+        match self {
+            TimeOp::Lt => lhs < rhs,
+            TimeOp::LtEq => lhs <= rhs,
+            TimeOp::Gt => lhs > rhs,
+            TimeOp::GtEq => lhs >= rhs,
+            TimeOp::Eq => lhs == rhs,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PatternAction {
+    /// `.*`, any number of events.
+    AnyEvents,
+    /// `(?N)` matches an event that satisfies the N-th condition, `.` matches any event.
+    /// A preceding `(?t op secs)` constrains the time elapsed since the previous matched event,
+    /// the duration is in the unit of the timestamp once the function is created.
+    Event {
+        condition: Option<usize>,
+        time: Option<(TimeOp, u64)>,
+    },
+}
+
+/// Parses a pattern like `(?1).*(?2)(?t<3600)(?3)`:
+///
+/// - `(?N)` matches an event where the N-th condition is true,
+/// - `.` matches any event, `.*` matches any number of events,
+/// - `(?t op N)` between two events allows any events in between, and requires
+///   the time between them to satisfy `op`, one of `<`, `<=`, `>`, `>=` and `==`.
+///   `N` is in seconds for `DATE` and `TIMESTAMP`, and in the unit of the integer otherwise.
+///
+/// Only rows where at least one condition is true are events.
+fn parse_pattern(pattern: &str, event_size: usize) -> Result<Vec<PatternAction>> {
+    let bytes = pattern.as_bytes();
+    let mut pos = 0;
+    let mut actions = vec![];
+    let mut pending_time = None;
+
+    let error = |pos: usize, msg: &str| {
+        ErrorCode::BadArguments(format!(
+            "Invalid sequence pattern '{}' at position {}: {}",
+            pattern, pos, msg
+        ))
+    };
+    let parse_number = |pos: &mut usize| {
+        let start = *pos;
+        while *pos < bytes.len() && bytes[*pos].is_ascii_digit() {
+            *pos += 1;
+        }
+        pattern[start..*pos]
+            .parse::<u64>()
+            .map_err(|_| error(start, "expected a number"))
+    };
+
+    while pos < bytes.len() {
+        if bytes[pos].is_ascii_whitespace() {
+            pos += 1;
+        } else if pattern[pos..].starts_with(".*") {
+            if pending_time.is_some() {
+                return Err(error(pos, "a time condition must be followed by an event"));
+            }
+            if actions.last() != Some(&PatternAction::AnyEvents) {
+                actions.push(PatternAction::AnyEvents);
+            }
+            pos += 2;
+        } else if bytes[pos] == b'.' {
+            actions.push(PatternAction::Event {
+                condition: None,
+                time: pending_time.take(),
+            });
+            pos += 1;
+        } else if pattern[pos..].starts_with("(?t") {
+            if !matches!(actions.last(), Some(PatternAction::Event { .. })) {
+                return Err(error(pos, "a time condition must follow an event"));
+            }
+            if pending_time.is_some() {
+                return Err(error(pos, "a time condition must be followed by an event"));
+            }
+            pos += 3;
+            let ops = [
+                ("<=", TimeOp::LtEq),
+                (">=", TimeOp::GtEq),
+                ("==", TimeOp::Eq),
+                ("<", TimeOp::Lt),
+                (">", TimeOp::Gt),
+            ];
+            let (op_str, op) = ops
+                .iter()
+                .find(|(op_str, _)| pattern[pos..].starts_with(op_str))
+                .ok_or_else(|| error(pos, "expected one of <, <=, >, >= and =="))?;
+            pos += op_str.len();
+            let duration = parse_number(&mut pos)?;
+            if bytes.get(pos) != Some(&b')') {
+                return Err(error(pos, "expected ')'"));
+            }
+            pos += 1;
+            pending_time = Some((*op, duration));
+        } else if pattern[pos..].starts_with("(?") {
+            pos += 2;
+            let condition = parse_number(&mut pos)? as usize;
+            if condition == 0 || condition > event_size {
+                return Err(error(
+                    pos,
+                    &format!("the condition must be between 1 and {}", event_size),
+                ));
+            }
+            if bytes.get(pos) != Some(&b')') {
+                return Err(error(pos, "expected ')'"));
+            }
+            pos += 1;
+            actions.push(PatternAction::Event {
+                condition: Some(condition - 1),
+                time: pending_time.take(),
+            });
+        } else {
+            return Err(error(pos, "unexpected character"));
+        }
+    }
+
+    if pending_time.is_some() {
+        return Err(error(pos, "a time condition must be followed by an event"));
+    }
+    if !actions
+        .iter()
+        .any(|action| matches!(action, PatternAction::Event { .. }))
+    {
+        return Err(error(pos, "the pattern must contain at least one event"));
+    }
+    Ok(actions)
+}
+
+#[derive(Serialize, Deserialize)]
+struct AggregateSequenceState<T> {
+    // (timestamp, bitmap of the satisfied conditions) of every event.
+    #[serde(bound(deserialize = "T: DeserializeOwned"))]
+    pub events_list: Vec<(T, u32)>,
+    pub sorted: bool,
+}
+
+impl<T> AggregateSequenceState<T>
+where T: Ord + Sub<Output = T> + AsPrimitive<u64> + Serialize + DeserializeOwned + Copy
+{
+    pub fn new() -> Self {
+        Self {
+            events_list: Vec::new(),
+            sorted: true,
+        }
+    }
+
+    #[inline(always)]
+    fn add(&mut self, timestamp: T, events: u32) {
+        if self.sorted {
+            if let Some(last) = self.events_list.last() {
+                self.sorted = *last <= (timestamp, events);
+            }
+        }
+        self.events_list.push((timestamp, events));
+    }
+
+    fn merge(&mut self, other: &Self) {
+        if other.events_list.is_empty() {
+            return;
+        }
+        self.events_list.extend_from_slice(&other.events_list);
+        self.sorted = false;
+    }
+
+    fn sort(&mut self) {
+        if !self.sorted {
+            self.events_list.sort();
+            self.sorted = true;
+        }
+    }
+
+    /// Returns the end of the first match that starts at or after `start`.
+    ///
+    /// This is a depth-first search over `(action, event, previous matched event)`.
+    /// The outcome of a search state doesn't depend on where the search started,
+    /// so states visited by a failed search are skipped by the following ones.
+    fn find_match(
+        &self,
+        actions: &[PatternAction],
+        keep_prev: &[bool],
+        elapsed_scale: u64,
+        start: usize,
+        visited: &mut HashSet<(usize, usize, Option<usize>)>,
+    ) -> Option<usize> {
+        let events = &self.events_list;
+        for begin in start..events.len() {
+            let mut stack = vec![(0, begin, None)];
+            while let Some((action_idx, event_idx, prev_event)) = stack.pop() {
+                if action_idx == actions.len() {
+                    return Some(event_idx);
+                }
+                if !visited.insert((action_idx, event_idx, prev_event)) {
+                    continue;
+                }
+
+                match &actions[action_idx] {
+                    PatternAction::AnyEvents => {
+                        if event_idx < events.len() {
+                            stack.push((action_idx, event_idx + 1, prev_event));
+                        }
+                        stack.push((action_idx + 1, event_idx, prev_event));
+                    }
+                    PatternAction::Event { condition, time } => {
+                        // A time condition allows any events before the matched one.
+                        if time.is_some() && event_idx < events.len() {
+                            stack.push((action_idx, event_idx + 1, prev_event));
+                        }
+                        let Some((timestamp, conditions)) = events.get(event_idx) else {
+                            continue;
+                        };
+                        if let Some(condition) = condition {
+                            if conditions & (1 << condition) == 0 {
+                                continue;
+                            }
+                        }
+                        if let (Some((op, duration)), Some(prev_event)) = (time, prev_event) {
+                            let elapsed: u64 = (*timestamp - events[prev_event].0).as_();
+                            let elapsed = elapsed.saturating_mul(elapsed_scale);
+                            if !op.check(elapsed, *duration) {
+                                continue;
+                            }
+                        }
+                        let prev_event = keep_prev[action_idx + 1].then_some(event_idx);
+                        stack.push((action_idx + 1, event_idx + 1, prev_event));
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// `elapsed_scale` converts the time between events to the unit of the pattern durations.
+    fn count_matches(
+        &mut self,
+        actions: &[PatternAction],
+        elapsed_scale: u64,
+        limit: usize,
+    ) -> usize {
+        self.sort();
+        // The previous matched event only matters if the next event has a time condition,
+        // dropping it otherwise keeps the number of search states linear in the events.
+        let mut keep_prev = vec![false; actions.len() + 1];
+        for (idx, action) in actions.iter().enumerate().rev() {
+            keep_prev[idx] = match action {
+                PatternAction::AnyEvents => keep_prev[idx + 1],
+                PatternAction::Event { time, .. } => time.is_some(),
+            };
+        }
+
+        let mut visited = HashSet::new();
+        let mut count = 0;
+        let mut start = 0;
+        while count < limit {
+            match self.find_match(actions, &keep_prev, elapsed_scale, start, &mut visited) {
+                Some(end) => {
+                    count += 1;
+                    start = end;
+                }
+                None => break,
+            }
+        }
+        count
+    }
+}
+
+/// `sequence_match` if `COUNT` is false, else `sequence_count`.
+#[derive(Clone)]
+pub struct AggregateSequenceFunction<T, const COUNT: bool> {
+    display_name: String,
+    event_size: usize,
+    actions: Vec<PatternAction>,
+    elapsed_scale: u64,
+    t: PhantomData<T>,
+}
+
+impl<T, const COUNT: bool> AggregateFunction for AggregateSequenceFunction<T, COUNT>
+where
+    T: ArgType + Send + Sync,
+    T::Scalar: Number
+        + Ord
+        + Sub<Output = T::Scalar>
+        + AsPrimitive<u64>
+        + Copy
+        + Serialize
+        + DeserializeOwned
+        + 'static,
+{
+    fn name(&self) -> &str {
+        if COUNT {
+            "AggregateSequenceCountFunction"
+        } else {
+            "AggregateSequenceMatchFunction"
+        }
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        if COUNT {
+            Ok(DataType::Number(NumberDataType::UInt64))
+        } else {
+            Ok(DataType::Number(NumberDataType::UInt8))
+        }
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateSequenceState::<T::Scalar>::new);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateSequenceState<T::Scalar>>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let dcolumns = self.downcast_conditions(columns);
+        let tcolumn = T::try_downcast_column(&columns[0]).unwrap();
+        let state = place.get::<AggregateSequenceState<T::Scalar>>();
+
+        for (row, timestamp) in T::iter_column(&tcolumn).enumerate() {
+            if validity.map(|bitmap| bitmap.get_bit(row)).unwrap_or(true) {
+                let events = Self::row_events(&dcolumns, row);
+                if events != 0 {
+                    state.add(T::to_owned_scalar(timestamp), events);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let dcolumns = self.downcast_conditions(columns);
+        let tcolumn = T::try_downcast_column(&columns[0]).unwrap();
+
+        for ((row, timestamp), place) in T::iter_column(&tcolumn).enumerate().zip(places.iter()) {
+            let events = Self::row_events(&dcolumns, row);
+            if events != 0 {
+                let state = place
+                    .next(offset)
+                    .get::<AggregateSequenceState<T::Scalar>>();
+                state.add(T::to_owned_scalar(timestamp), events);
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let dcolumns = self.downcast_conditions(columns);
+        let events = Self::row_events(&dcolumns, row);
+        if events != 0 {
+            let tcolumn = T::try_downcast_column(&columns[0]).unwrap();
+            let timestamp = unsafe { T::index_column_unchecked(&tcolumn, row) };
+            let state = place.get::<AggregateSequenceState<T::Scalar>>();
+            state.add(T::to_owned_scalar(timestamp), events);
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<AggregateSequenceState<T::Scalar>>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateSequenceState<T::Scalar>>();
+        *state = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let rhs = rhs.get::<AggregateSequenceState<T::Scalar>>();
+        let state = place.get::<AggregateSequenceState<T::Scalar>>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<AggregateSequenceState<T::Scalar>>();
+        if COUNT {
+            let builder = UInt64Type::try_downcast_builder(builder).unwrap();
+            builder.push(state.count_matches(&self.actions, self.elapsed_scale, usize::MAX) as u64);
+        } else {
+            let builder = UInt8Type::try_downcast_builder(builder).unwrap();
+            builder.push(state.count_matches(&self.actions, self.elapsed_scale, 1) as u8);
+        }
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<AggregateSequenceState<T::Scalar>>();
+        std::ptr::drop_in_place(state);
+    }
+
+    fn get_own_null_adaptor(
+        &self,
+        _nested_function: AggregateFunctionRef,
+        _params: Vec<Scalar>,
+        _arguments: Vec<DataType>,
+    ) -> Result<Option<AggregateFunctionRef>> {
+        Ok(Some(AggregateNullVariadicAdaptor::<false>::create(
+            Arc::new(self.clone()),
+        )))
+    }
+}
+
+impl<T, const COUNT: bool> fmt::Display for AggregateSequenceFunction<T, COUNT> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl<T, const COUNT: bool> AggregateSequenceFunction<T, COUNT>
+where
+    T: ArgType + Send + Sync,
+    T::Scalar: Number
+        + Ord
+        + Sub<Output = T::Scalar>
+        + AsPrimitive<u64>
+        + Copy
+        + Serialize
+        + DeserializeOwned
+        + 'static,
+{
+    /// The durations of the pattern are scaled by `duration_scale` and the time between
+    /// events by `elapsed_scale`, so that both are compared in the same unit.
+    pub fn try_create(
+        display_name: &str,
+        params: Vec<Scalar>,
+        arguments: Vec<DataType>,
+        (elapsed_scale, duration_scale): (u64, u64),
+    ) -> Result<AggregateFunctionRef> {
+        let event_size = arguments.len() - 1;
+        let pattern = match &params[0] {
+            Scalar::String(pattern) => String::from_utf8_lossy(pattern).to_string(),
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The pattern of {} must be a constant string, but got {}",
+                    display_name, params[0]
+                )));
+            }
+        };
+        let mut actions = parse_pattern(&pattern, event_size)?;
+        for action in actions.iter_mut() {
+            if let PatternAction::Event {
+                time: Some((_, duration)),
+                ..
+            } = action
+            {
+                *duration = duration.saturating_mul(duration_scale);
+            }
+        }
+
+        Ok(Arc::new(Self {
+            display_name: display_name.to_owned(),
+            event_size,
+            actions,
+            elapsed_scale,
+            t: PhantomData,
+        }))
+    }
+
+    fn downcast_conditions(&self, columns: &[Column]) -> Vec<Bitmap> {
+        (0..self.event_size)
+            .map(|i| BooleanType::try_downcast_column(&columns[i + 1]).unwrap())
+            .collect()
+    }
+
+    #[inline(always)]
+    fn row_events(dcolumns: &[Bitmap], row: usize) -> u32 {
+        dcolumns
+            .iter()
+            .enumerate()
+            .filter(|(_, filter)| filter.get_bit(row))
+            .fold(0, |events, (i, _)| events | (1 << i))
+    }
+}
+
+fn try_create_aggregate_sequence_function<const COUNT: bool>(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_params(display_name, params.len())?;
+    assert_variadic_arguments(display_name, arguments.len(), (2, 33))?;
+
+    for (idx, arg) in arguments[1..].iter().enumerate() {
+        if !arg.is_boolean() {
+            return Err(ErrorCode::BadDataValueType(format!(
+                "Illegal type of the argument {:?} in {}, must be boolean, got: {:?}",
+                idx + 1,
+                display_name,
+                arg
+            )));
+        }
+    }
+
+    with_integer_mapped_type!(|NUM_TYPE| match &arguments[0] {
+        DataType::Number(NumberDataType::NUM_TYPE) => AggregateSequenceFunction::<
+            NumberType<NUM_TYPE>,
+            COUNT,
+        >::try_create(
+            display_name, params, arguments, (1, 1)
+        ),
+        // The durations are in seconds, days and microseconds are converted to them.
+        DataType::Date => AggregateSequenceFunction::<DateType, COUNT>::try_create(
+            display_name,
+            params,
+            arguments,
+            (SECONDS_IN_A_DAY, 1)
+        ),
+        DataType::Timestamp => AggregateSequenceFunction::<TimestampType, COUNT>::try_create(
+            display_name,
+            params,
+            arguments,
+            (1, MICROS_IN_A_SEC as u64)
+        ),
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "{} does not support type '{:?}'",
+            display_name, arguments[0]
+        ))),
+    })
+}
+
+pub fn aggregate_sequence_match_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_sequence_function::<false>))
+}
+
+pub fn aggregate_sequence_count_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_sequence_function::<true>))
+}
@@ -0,0 +1,319 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_io::prelude::*;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregator_common::get_u64_param;
+use crate::aggregates::assert_unary_arguments;
+use crate::aggregates::assert_variadic_params;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+const DEFAULT_LOAD_FACTOR: u64 = 3;
+const MAX_TOP_K: u64 = 1 << 20;
+
+/// Space-Saving sketch from "Efficient Computation of Frequent and Top-k Elements
+/// in Data Streams" (A. Metwally et al., 2005). At most `capacity` counters are kept,
+/// a new value replaces the smallest counter and inherits its count as the error bound.
+#[derive(Serialize, Deserialize, Default)]
+pub struct SpaceSavingState {
+    // value -> (count, error)
+    pub counters: HashMap<Scalar, (u64, u64)>,
+    // A lazy min-heap of the counters, one entry per value. Increments do not touch the
+    // heap, so the count of an entry may be stale (smaller), it is refreshed when the
+    // entry reaches the top. Rebuilt after the state is merged, and when it is out of sync
+    // after the state is deserialized.
+    #[serde(skip)]
+    heap: BinaryHeap<Reverse<(u64, Scalar)>>,
+}
+
+impl SpaceSavingState {
+    fn add(&mut self, value: Scalar, capacity: usize) {
+        if let Some((count, _)) = self.counters.get_mut(&value) {
+            *count += 1;
+            return;
+        }
+
+        if self.counters.len() < capacity {
+            self.counters.insert(value.clone(), (1, 0));
+            self.heap.push(Reverse((1, value)));
+            return;
+        }
+
+        if self.heap.len() != self.counters.len() {
+            self.rebuild_heap();
+        }
+        let min_count = loop {
+            let Reverse((count, min_value)) = self.heap.pop().unwrap();
+            let (current, _) = self.counters[&min_value];
+            if current == count {
+                self.counters.remove(&min_value);
+                break count;
+            }
+            self.heap.push(Reverse((current, min_value)));
+        };
+        self.counters
+            .insert(value.clone(), (min_count + 1, min_count));
+        self.heap.push(Reverse((min_count + 1, value)));
+    }
+
+    fn rebuild_heap(&mut self) {
+        self.heap = self
+            .counters
+            .iter()
+            .map(|(value, (count, _))| Reverse((*count, value.clone())))
+            .collect();
+    }
+
+    // Mergeable summaries (P. Agarwal et al., 2012): a value missing from a full
+    // sketch may have occurred up to the minimum count of that sketch.
+    fn merge(&mut self, rhs: &Self, capacity: usize) {
+        if rhs.counters.is_empty() {
+            return;
+        }
+
+        let lhs_min = self.min_count(capacity);
+        let rhs_min = rhs.min_count(capacity);
+
+        for (value, (count, error)) in self.counters.iter_mut() {
+            match rhs.counters.get(value) {
+                Some((rhs_count, rhs_error)) => {
+                    *count += rhs_count;
+                    *error += rhs_error;
+                }
+                None => {
+                    *count += rhs_min;
+                    *error += rhs_min;
+                }
+            }
+        }
+        for (value, (count, error)) in rhs.counters.iter() {
+            if !self.counters.contains_key(value) {
+                self.counters
+                    .insert(value.clone(), (count + lhs_min, error + lhs_min));
+            }
+        }
+
+        if self.counters.len() > capacity {
+            let mut counters = self.counters.drain().collect::<Vec<_>>();
+            counters.sort_by(|(_, (a, _)), (_, (b, _))| b.cmp(a));
+            counters.truncate(capacity);
+            self.counters = counters.into_iter().collect();
+        }
+        self.rebuild_heap();
+    }
+
+    fn min_count(&self, capacity: usize) -> u64 {
+        if self.counters.len() < capacity {
+            0
+        } else {
+            self.counters
+                .values()
+                .map(|(count, _)| *count)
+                .min()
+                .unwrap_or(0)
+        }
+    }
+
+    /// Values sorted by their estimated frequency, the smaller value wins on ties.
+    fn top_k(&self, k: usize) -> Vec<&Scalar> {
+        let mut counters = self.counters.iter().collect::<Vec<_>>();
+        counters.sort_by(|(a_value, (a_count, _)), (b_value, (b_count, _))| {
+            b_count.cmp(a_count).then_with(|| a_value.cmp(b_value))
+        });
+        counters
+            .into_iter()
+            .take(k)
+            .map(|(value, _)| value)
+            .collect()
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateTopKFunction {
+    display_name: String,
+    return_type: DataType,
+    k: usize,
+    capacity: usize,
+}
+
+impl AggregateFunction for AggregateTopKFunction {
+    fn name(&self) -> &str {
+        "AggregateTopKFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(self.return_type.clone())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(SpaceSavingState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<SpaceSavingState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<SpaceSavingState>();
+        match validity {
+            Some(bitmap) => {
+                for (value, valid) in columns[0].iter().zip(bitmap.iter()) {
+                    if valid {
+                        state.add(value.to_owned(), self.capacity);
+                    }
+                }
+            }
+            None => {
+                for value in columns[0].iter() {
+                    state.add(value.to_owned(), self.capacity);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (value, place) in columns[0].iter().zip(places.iter()) {
+            let place = place.next(offset);
+            let state = place.get::<SpaceSavingState>();
+            state.add(value.to_owned(), self.capacity);
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let state = place.get::<SpaceSavingState>();
+        if let Some(value) = columns[0].index(row) {
+            state.add(value.to_owned(), self.capacity);
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<SpaceSavingState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<SpaceSavingState>();
+        *state = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<SpaceSavingState>();
+        let rhs = rhs.get::<SpaceSavingState>();
+        state.merge(rhs, self.capacity);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<SpaceSavingState>();
+        let values = state.top_k(self.k);
+
+        let inner_type = self.return_type.as_array().unwrap();
+        let mut inner_builder = ColumnBuilder::with_capacity(inner_type, values.len());
+        for value in values {
+            inner_builder.push(value.as_ref());
+        }
+        builder.push(ScalarRef::Array(inner_builder.build()));
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<SpaceSavingState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl fmt::Display for AggregateTopKFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub fn try_create_aggregate_top_k_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_variadic_params(display_name, params.len(), (1, 2))?;
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    let k = get_u64_param(&params[0])?;
+    if k == 0 || k > MAX_TOP_K {
+        return Err(ErrorCode::BadArguments(format!(
+            "The k of {} must be between 1 and {}, but got {}",
+            display_name, MAX_TOP_K, k
+        )));
+    }
+    let load_factor = match params.get(1) {
+        Some(param) => get_u64_param(param)?,
+        None => DEFAULT_LOAD_FACTOR,
+    };
+    let capacity = k.saturating_mul(load_factor);
+    if load_factor == 0 || capacity > MAX_TOP_K {
+        return Err(ErrorCode::BadArguments(format!(
+            "The load factor of {} must be positive and at most {} counters can be reserved, but got {}",
+            display_name, MAX_TOP_K, load_factor
+        )));
+    }
+
+    Ok(Arc::new(AggregateTopKFunction {
+        display_name: display_name.to_string(),
+        return_type: DataType::Array(Box::new(arguments[0].clone())),
+        k: k as usize,
+        capacity: capacity as usize,
+    }))
+}
+
+pub fn aggregate_top_k_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_top_k_function))
+}
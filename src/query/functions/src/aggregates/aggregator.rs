@@ -40,16 +40,32 @@ use super::AggregateIfCombinator;
 use crate::aggregates::aggregate_array_agg::aggregate_array_agg_function_desc;
 use crate::aggregates::aggregate_array_moving::aggregate_array_moving_avg_function_desc;
 use crate::aggregates::aggregate_array_moving::aggregate_array_moving_sum_function_desc;
+use crate::aggregates::aggregate_histogram::aggregate_histogram_function_desc;
 use crate::aggregates::aggregate_kurtosis::aggregate_kurtosis_function_desc;
+use crate::aggregates::aggregate_mode::aggregate_entropy_function_desc;
+use crate::aggregates::aggregate_mode::aggregate_mode_function_desc;
 use crate::aggregates::aggregate_quantile_cont::aggregate_median_function_desc;
 use crate::aggregates::aggregate_quantile_cont::aggregate_quantile_cont_function_desc;
 use crate::aggregates::aggregate_quantile_disc::aggregate_quantile_disc_function_desc;
 use crate::aggregates::aggregate_quantile_tdigest::aggregate_median_tdigest_function_desc;
 use crate::aggregates::aggregate_quantile_tdigest::aggregate_quantile_tdigest_function_desc;
+use crate::aggregates::aggregate_regression::aggregate_corr_function_desc;
+use crate::aggregates::aggregate_regression::aggregate_regr_avgx_function_desc;
+use crate::aggregates::aggregate_regression::aggregate_regr_avgy_function_desc;
+use crate::aggregates::aggregate_regression::aggregate_regr_count_function_desc;
+use crate::aggregates::aggregate_regression::aggregate_regr_intercept_function_desc;
+use crate::aggregates::aggregate_regression::aggregate_regr_r2_function_desc;
+use crate::aggregates::aggregate_regression::aggregate_regr_slope_function_desc;
+use crate::aggregates::aggregate_regression::aggregate_regr_sxx_function_desc;
+use crate::aggregates::aggregate_regression::aggregate_regr_sxy_function_desc;
+use crate::aggregates::aggregate_regression::aggregate_regr_syy_function_desc;
 use crate::aggregates::aggregate_retention::aggregate_retention_function_desc;
+use crate::aggregates::aggregate_sequence::aggregate_sequence_count_function_desc;
+use crate::aggregates::aggregate_sequence::aggregate_sequence_match_function_desc;
 use crate::aggregates::aggregate_skewness::aggregate_skewness_function_desc;
 use crate::aggregates::aggregate_string_agg::aggregate_string_agg_function_desc;
 use crate::aggregates::aggregate_sum::aggregate_sum_function_desc;
+use crate::aggregates::aggregate_top_k::aggregate_top_k_function_desc;
 
 pub struct Aggregators;
 
@@ -69,6 +85,16 @@ impl Aggregators {
 
         factory.register("covar_samp", aggregate_covariance_sample_desc());
         factory.register("covar_pop", aggregate_covariance_population_desc());
+        factory.register("corr", aggregate_corr_function_desc());
+        factory.register("regr_slope", aggregate_regr_slope_function_desc());
+        factory.register("regr_intercept", aggregate_regr_intercept_function_desc());
+        factory.register("regr_r2", aggregate_regr_r2_function_desc());
+        factory.register("regr_count", aggregate_regr_count_function_desc());
+        factory.register("regr_avgx", aggregate_regr_avgx_function_desc());
+        factory.register("regr_avgy", aggregate_regr_avgy_function_desc());
+        factory.register("regr_sxx", aggregate_regr_sxx_function_desc());
+        factory.register("regr_syy", aggregate_regr_syy_function_desc());
+        factory.register("regr_sxy", aggregate_regr_sxy_function_desc());
         factory.register("stddev_samp", aggregate_stddev_samp_function_desc());
         factory.register("stddev_pop", aggregate_stddev_pop_function_desc());
         factory.register("stddev", aggregate_stddev_pop_function_desc());
@@ -88,6 +114,8 @@ impl Aggregators {
             aggregate_approx_count_distinct_function_desc(),
         );
         factory.register("retention", aggregate_retention_function_desc());
+        factory.register("sequence_match", aggregate_sequence_match_function_desc());
+        factory.register("sequence_count", aggregate_sequence_count_function_desc());
         factory.register("array_agg", aggregate_array_agg_function_desc());
         factory.register("list", aggregate_array_agg_function_desc());
         factory.register(
@@ -100,6 +128,11 @@ impl Aggregators {
         );
         factory.register("kurtosis", aggregate_kurtosis_function_desc());
         factory.register("skewness", aggregate_skewness_function_desc());
+        factory.register("mode", aggregate_mode_function_desc());
+        factory.register("entropy", aggregate_entropy_function_desc());
+        factory.register("top_k", aggregate_top_k_function_desc());
+        factory.register("approx_top_k", aggregate_top_k_function_desc());
+        factory.register("histogram", aggregate_histogram_function_desc());
         factory.register("string_agg", aggregate_string_agg_function_desc());
 
         factory.register(
@@ -17,14 +17,19 @@ use std::fmt::Display;
 use bumpalo::Bump;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::type_check::check_number;
 use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
 use common_expression::Column;
 use common_expression::ColumnBuilder;
+use common_expression::Expr;
+use common_expression::FunctionContext;
 use common_expression::Scalar;
 
 use super::AggregateFunctionFactory;
 use super::AggregateFunctionRef;
 use super::StateAddr;
+use crate::BUILTIN_FUNCTIONS;
 
 pub fn assert_unary_params<D: Display>(name: D, actual: usize) -> Result<()> {
     if actual != 1 {
@@ -104,6 +109,25 @@ pub fn assert_variadic_arguments<D: Display>(
     Ok(())
 }
 
+/// Casts a constant aggregate parameter, such as the `k` of `top_k(k)(x)`, to `u64`.
+pub(crate) fn get_u64_param(param: &Scalar) -> Result<u64> {
+    check_number(
+        None,
+        &FunctionContext::default(),
+        &Expr::<usize>::Cast {
+            span: None,
+            is_try: false,
+            expr: Box::new(Expr::Constant {
+                span: None,
+                scalar: param.clone(),
+                data_type: param.as_ref().infer_data_type(),
+            }),
+            dest_type: DataType::Number(NumberDataType::UInt64),
+        },
+        &BUILTIN_FUNCTIONS,
+    )
+}
+
 struct EvalAggr {
     addr: StateAddr,
     _arena: Bump,
@@ -30,18 +30,23 @@ mod aggregate_combinator_if;
 mod aggregate_combinator_state;
 mod aggregate_covariance;
 mod aggregate_distinct_state;
+mod aggregate_histogram;
 mod aggregate_kurtosis;
 mod aggregate_min_max_any;
+mod aggregate_mode;
 mod aggregate_null_result;
 mod aggregate_quantile_cont;
 mod aggregate_quantile_disc;
 mod aggregate_quantile_tdigest;
+mod aggregate_regression;
 mod aggregate_retention;
 mod aggregate_scalar_state;
+mod aggregate_sequence;
 mod aggregate_skewness;
 mod aggregate_stddev;
 mod aggregate_string_agg;
 mod aggregate_sum;
+mod aggregate_top_k;
 mod aggregate_window_funnel;
 mod aggregator;
 mod aggregator_common;
@@ -62,14 +67,19 @@ pub use aggregate_function_factory::AggregateFunctionFactory;
 pub use aggregate_function_state::get_layout_offsets;
 pub use aggregate_function_state::StateAddr;
 pub use aggregate_function_state::StateAddrs;
+pub use aggregate_histogram::AggregateHistogramFunction;
 pub use aggregate_kurtosis::AggregateKurtosisFunction;
 pub use aggregate_min_max_any::AggregateMinMaxAnyFunction;
+pub use aggregate_mode::AggregateFrequencyFunction;
 pub use aggregate_null_result::AggregateNullResultFunction;
 pub use aggregate_quantile_cont::AggregateQuantileContFunction;
 pub use aggregate_quantile_disc::AggregateQuantileDiscFunction;
+pub use aggregate_regression::AggregateRegressionFunction;
 pub use aggregate_retention::AggregateRetentionFunction;
+pub use aggregate_sequence::AggregateSequenceFunction;
 pub use aggregate_skewness::AggregateSkewnessFunction;
 pub use aggregate_string_agg::AggregateStringAggFunction;
 pub use aggregate_sum::AggregateSumFunction;
+pub use aggregate_top_k::AggregateTopKFunction;
 pub use aggregator::Aggregators;
 pub use aggregator_common::*;
@@ -52,7 +52,14 @@ pub const GENERAL_WINDOW_FUNCTIONS: [&str; 13] = [
     "cume_dist",
 ];
 
-pub const GENERAL_LAMBDA_FUNCTIONS: [&str; 3] = ["array_transform", "array_apply", "array_filter"];
+pub const GENERAL_LAMBDA_FUNCTIONS: [&str; 6] = [
+    "array_transform",
+    "array_apply",
+    "array_filter",
+    "map_filter",
+    "map_transform_keys",
+    "map_transform_values",
+];
 
 fn builtin_functions() -> FunctionRegistry {
     let mut registry = FunctionRegistry::empty();
@@ -0,0 +1,158 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use base64::engine::general_purpose;
+use base64::prelude::*;
+use common_expression::error_to_null;
+use common_expression::types::number::NumberType;
+use common_expression::types::BinaryType;
+use common_expression::types::StringType;
+use common_expression::vectorize_1_arg;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::vectorize_with_builder_3_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+use crate::scalars::string::substr;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // cast(xx AS binary)
+    // to_binary(xx)
+    register_to_binary(registry);
+
+    // cast(binary AS string), try_to_string(binary)
+    register_binary_to_string(registry);
+
+    // to_hex(binary), from_hex(string), to_base64(binary)
+    register_binary_encoding(registry);
+
+    // length(binary), substr(binary, pos[, len])
+    register_binary_bytes(registry);
+}
+
+fn register_to_binary(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "to_binary",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<StringType, BinaryType>(|val, output, _| {
+            output.put_slice(val);
+            output.commit_row();
+        }),
+    );
+    registry.register_passthrough_nullable_1_arg::<BinaryType, BinaryType, _, _>(
+        "to_binary",
+        |_, _| FunctionDomain::Full,
+        |val, _| val.to_owned(),
+    );
+}
+
+fn register_binary_to_string(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::MayThrow,
+        eval_binary_to_string,
+    );
+    registry.register_combine_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "try_to_string",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_binary_to_string),
+    );
+
+    /// Only valid UTF-8 bytes can be converted into a string.
+    fn eval_binary_to_string(
+        val: ValueRef<BinaryType>,
+        ctx: &mut EvalContext,
+    ) -> Value<StringType> {
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(|val, output, ctx| {
+            match std::str::from_utf8(val) {
+                Ok(s) => output.put_str(s),
+                Err(e) => ctx.set_error(output.len(), format!("invalid utf8 binary: {e}")),
+            }
+            output.commit_row();
+        })(val, ctx)
+    }
+}
+
+fn register_binary_encoding(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "to_hex",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(|val, output, _| {
+            output.put_str(&hex::encode_upper(val));
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "from_hex",
+        |_, _| FunctionDomain::MayThrow,
+        eval_from_hex,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "try_from_hex",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_from_hex),
+    );
+
+    fn eval_from_hex(val: ValueRef<StringType>, ctx: &mut EvalContext) -> Value<BinaryType> {
+        vectorize_with_builder_1_arg::<StringType, BinaryType>(|val, output, ctx| {
+            match hex::decode(val) {
+                Ok(bytes) => output.put_slice(&bytes),
+                Err(e) => ctx.set_error(output.len(), format!("invalid hex string: {e}")),
+            }
+            output.commit_row();
+        })(val, ctx)
+    }
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "to_base64",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(|val, output, _| {
+            output.put_str(&general_purpose::STANDARD.encode(val));
+            output.commit_row();
+        }),
+    );
+}
+
+fn register_binary_bytes(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<BinaryType, NumberType<u64>, _, _>(
+        "length",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<BinaryType, NumberType<u64>>(|val, _| val.len() as u64),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<BinaryType, NumberType<i64>, BinaryType, _, _>(
+        "substr",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<BinaryType, NumberType<i64>, BinaryType>(
+            |s, pos, output, _| {
+                output.put_slice(substr(s, pos, s.len() as u64));
+                output.commit_row();
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_3_arg::<BinaryType, NumberType<i64>, NumberType<u64>, BinaryType, _, _>(
+        "substr",
+        |_, _, _, _| FunctionDomain::Full,
+        vectorize_with_builder_3_arg::<BinaryType, NumberType<i64>, NumberType<u64>, BinaryType>(|s, pos, len, output, _| {
+            output.put_slice(substr(s, pos, len));
+            output.commit_row();
+        }),
+    );
+}
@@ -18,10 +18,13 @@ use std::sync::Arc;
 
 use common_arrow::arrow::bitmap::MutableBitmap;
 use common_expression::types::boolean::BooleanDomain;
+use common_expression::types::number::SimpleDomain;
 use common_expression::types::string::StringDomain;
+use common_expression::types::timestamp_tz::timestamp_tz_micros;
 use common_expression::types::AnyType;
 use common_expression::types::ArgType;
 use common_expression::types::ArrayType;
+use common_expression::types::BinaryType;
 use common_expression::types::BooleanType;
 use common_expression::types::DataType;
 use common_expression::types::DateType;
@@ -30,7 +33,9 @@ use common_expression::types::GenericType;
 use common_expression::types::NumberClass;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
+use common_expression::types::TimeType;
 use common_expression::types::TimestampType;
+use common_expression::types::TimestampTzType;
 use common_expression::types::ValueType;
 use common_expression::types::VariantType;
 use common_expression::types::ALL_NUMBER_CLASSES;
@@ -58,6 +63,9 @@ pub fn register(registry: &mut FunctionRegistry) {
     register_string_cmp(registry);
     register_date_cmp(registry);
     register_timestamp_cmp(registry);
+    register_timestamp_tz_cmp(registry);
+    register_time_cmp(registry);
+    register_binary_cmp(registry);
     register_number_cmp(registry);
     register_boolean_cmp(registry);
     register_array_cmp(registry);
@@ -169,6 +177,85 @@ fn register_timestamp_cmp(registry: &mut FunctionRegistry) {
     register_simple_domain_type_cmp!(registry, TimestampType);
 }
 
+/// `TIMESTAMP_TZ` values are compared by the instants, the time zones are ignored.
+fn register_timestamp_tz_cmp(registry: &mut FunctionRegistry) {
+    fn instant_domain(domain: &SimpleDomain<i128>) -> SimpleDomain<i64> {
+        SimpleDomain {
+            min: timestamp_tz_micros(domain.min),
+            max: timestamp_tz_micros(domain.max),
+        }
+    }
+
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "eq",
+        |_, d1, d2| instant_domain(d1).domain_eq(&instant_domain(d2)),
+        |lhs, rhs, _| timestamp_tz_micros(lhs) == timestamp_tz_micros(rhs),
+    );
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "noteq",
+        |_, d1, d2| instant_domain(d1).domain_noteq(&instant_domain(d2)),
+        |lhs, rhs, _| timestamp_tz_micros(lhs) != timestamp_tz_micros(rhs),
+    );
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "gt",
+        |_, d1, d2| instant_domain(d1).domain_gt(&instant_domain(d2)),
+        |lhs, rhs, _| timestamp_tz_micros(lhs) > timestamp_tz_micros(rhs),
+    );
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "gte",
+        |_, d1, d2| instant_domain(d1).domain_gte(&instant_domain(d2)),
+        |lhs, rhs, _| timestamp_tz_micros(lhs) >= timestamp_tz_micros(rhs),
+    );
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "lt",
+        |_, d1, d2| instant_domain(d1).domain_lt(&instant_domain(d2)),
+        |lhs, rhs, _| timestamp_tz_micros(lhs) < timestamp_tz_micros(rhs),
+    );
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "lte",
+        |_, d1, d2| instant_domain(d1).domain_lte(&instant_domain(d2)),
+        |lhs, rhs, _| timestamp_tz_micros(lhs) <= timestamp_tz_micros(rhs),
+    );
+}
+
+fn register_time_cmp(registry: &mut FunctionRegistry) {
+    register_simple_domain_type_cmp!(registry, TimeType);
+}
+
+/// `BINARY` values are compared byte by byte.
+fn register_binary_cmp(registry: &mut FunctionRegistry) {
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "eq",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs == rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "noteq",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs != rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "gt",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs > rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "gte",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs >= rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "lt",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs < rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "lte",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs <= rhs,
+    );
+}
+
 fn register_boolean_cmp(registry: &mut FunctionRegistry) {
     registry.register_2_arg::<BooleanType, BooleanType, BooleanType, _, _>(
         "eq",
@@ -0,0 +1,663 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::f64::consts::PI;
+
+use common_expression::types::boolean::BooleanDomain;
+use common_expression::types::geometry::ewkb_to_geometry;
+use common_expression::types::geometry::geometry_to_ewkb;
+use common_expression::types::geometry::geometry_to_ewkt;
+use common_expression::types::geometry::geometry_to_geojson;
+use common_expression::types::geometry::geometry_to_wkb;
+use common_expression::types::geometry::geometry_to_wkt;
+use common_expression::types::geometry::parse_geometry;
+use common_expression::types::geometry::read_srid;
+use common_expression::types::geometry::GeometryDomain;
+use common_expression::types::number::Float64Type;
+use common_expression::types::number::Int32Type;
+use common_expression::types::number::SimpleDomain;
+use common_expression::types::number::F64;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::BooleanType;
+use common_expression::types::GeometryType;
+use common_expression::types::NullableType;
+use common_expression::types::StringType;
+use common_expression::types::VariantType;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::vectorize_with_builder_3_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use geo::coord;
+use geo::Area;
+use geo::BooleanOps;
+use geo::Contains;
+use geo::Coord;
+use geo::CoordsIter;
+use geo::EuclideanDistance;
+use geo::Geometry;
+use geo::Intersects;
+use geo::Line;
+use geo::LineString;
+use geo::MapCoords;
+use geo::MultiPolygon;
+use geo::Point;
+use geo::Polygon;
+
+/// The number of segments used to approximate a circle in `st_buffer`.
+const BUFFER_CIRCLE_SEGMENTS: usize = 32;
+
+/// Half of the circumference of the earth in meters, used by Web Mercator (EPSG:3857).
+const WEB_MERCATOR_HALF_SIZE: f64 = 20037508.342789244;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_aliases("to_geometry", &[
+        "st_geometryfromtext",
+        "st_geomfromtext",
+        "st_geomfromwkt",
+        "st_geomfromewkt",
+        "st_geomfromwkb",
+        "st_geomfromewkb",
+        "st_geomfromgeojson",
+        "st_geographyfromtext",
+        "to_geography",
+    ]);
+    registry.register_aliases("st_aswkt", &["st_astext"]);
+    registry.register_aliases("st_aswkb", &["st_asbinary"]);
+    registry.register_aliases("st_makepoint", &["st_point"]);
+    registry.register_aliases("st_makepolygon", &["st_polygon"]);
+
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "to_geometry",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, GeometryType>(|s, builder, ctx| {
+            match parse_geometry(s) {
+                Ok(ewkb) => builder.put_slice(&ewkb),
+                Err(e) => ctx.set_error(builder.len(), e),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<StringType, Int32Type, GeometryType, _, _>(
+        "to_geometry",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<StringType, Int32Type, GeometryType>(
+            |s, srid, builder, ctx| {
+                let geo = parse_geometry(s).and_then(|ewkb| ewkb_to_geometry(&ewkb));
+                match geo {
+                    Ok((geo, _)) => write_geometry(&geo, Some(srid), builder, ctx),
+                    Err(e) => {
+                        ctx.set_error(builder.len(), e);
+                        builder.commit_row();
+                    }
+                }
+            },
+        ),
+    );
+
+    registry.register_combine_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "try_to_geometry",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<StringType, NullableType<GeometryType>>(|s, output, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(output.len()) {
+                    output.push_null();
+                    return;
+                }
+            }
+            match parse_geometry(s) {
+                Ok(ewkb) => output.push(&ewkb),
+                Err(_) => output.push_null(),
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, StringType>(|geo, builder, ctx| {
+            match geometry_to_ewkt(geo) {
+                Ok(ewkt) => builder.put_str(&ewkt),
+                Err(e) => ctx.set_error(builder.len(), e),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "st_asewkt",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, StringType>(|geo, builder, ctx| {
+            match geometry_to_ewkt(geo) {
+                Ok(ewkt) => builder.put_str(&ewkt),
+                Err(e) => ctx.set_error(builder.len(), e),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "st_aswkt",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, StringType>(|geo, builder, ctx| {
+            match geometry_to_wkt(geo) {
+                Ok(wkt) => builder.put_str(&wkt),
+                Err(e) => ctx.set_error(builder.len(), e),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    // WKB and EWKB are returned as upper case hex strings.
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "st_aswkb",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, StringType>(|geo, builder, ctx| {
+            match geometry_to_wkb(geo) {
+                Ok(wkb) => builder.put_str(&hex::encode_upper(wkb)),
+                Err(e) => ctx.set_error(builder.len(), e),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "st_asewkb",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<GeometryType, StringType>(|geo, builder, _| {
+            builder.put_str(&hex::encode_upper(geo));
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, VariantType, _, _>(
+        "st_asgeojson",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, VariantType>(|geo, builder, ctx| {
+            match geometry_to_geojson(geo).and_then(|json| {
+                jsonb::parse_value(json.as_bytes())
+                    .map(|value| value.write_to_vec(&mut builder.data))
+                    .map_err(|e| format!("invalid GeoJSON: {e}"))
+            }) {
+                Ok(()) => {}
+                Err(e) => ctx.set_error(builder.len(), e),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<Float64Type, Float64Type, GeometryType, _, _>(
+        "st_makepoint",
+        |_, x, y| {
+            FunctionDomain::Domain(GeometryDomain {
+                min_x: *x.min,
+                min_y: *y.min,
+                max_x: *x.max,
+                max_y: *y.max,
+            })
+        },
+        vectorize_with_builder_2_arg::<Float64Type, Float64Type, GeometryType>(
+            |x, y, builder, ctx| {
+                let point = Geometry::Point(Point::new(*x, *y));
+                write_geometry(&point, None, builder, ctx);
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, GeometryType, _, _>(
+        "st_makeline",
+        |_, a, b| FunctionDomain::Domain(a.merge(b)),
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, GeometryType>(
+            |a, b, builder, ctx| {
+                let line = decode_pair(a, b).and_then(|(a, b, srid)| {
+                    let coords = a.coords_iter().chain(b.coords_iter()).collect::<Vec<_>>();
+                    if coords.len() < 2 {
+                        return Err("st_makeline requires at least two points".to_string());
+                    }
+                    Ok((Geometry::LineString(LineString::new(coords)), srid))
+                });
+                match line {
+                    Ok((line, srid)) => write_geometry(&line, srid, builder, ctx),
+                    Err(e) => {
+                        ctx.set_error(builder.len(), e);
+                        builder.commit_row();
+                    }
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, GeometryType, _, _>(
+        "st_makepolygon",
+        |_, domain| FunctionDomain::Domain(*domain),
+        vectorize_with_builder_1_arg::<GeometryType, GeometryType>(|geo, builder, ctx| {
+            let polygon = ewkb_to_geometry(geo).and_then(|(geo, srid)| match geo {
+                Geometry::LineString(line) if line.is_closed() && line.0.len() >= 4 => {
+                    Ok((Geometry::Polygon(Polygon::new(line, vec![])), srid))
+                }
+                _ => Err(
+                    "st_makepolygon requires a closed linestring with at least four points"
+                        .to_string(),
+                ),
+            });
+            match polygon {
+                Ok((polygon, srid)) => write_geometry(&polygon, srid, builder, ctx),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e);
+                    builder.commit_row();
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "st_contains",
+        |_, a, b| {
+            if a.contains(b) {
+                FunctionDomain::MayThrow
+            } else {
+                FunctionDomain::Domain(all_false_domain())
+            }
+        },
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+            |a, b, builder, ctx| match decode_pair(a, b) {
+                Ok((a, b, _)) => builder.push(a.contains(&b)),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e);
+                    builder.push(false);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "st_within",
+        |_, a, b| {
+            if b.contains(a) {
+                FunctionDomain::MayThrow
+            } else {
+                FunctionDomain::Domain(all_false_domain())
+            }
+        },
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+            |a, b, builder, ctx| match decode_pair(a, b) {
+                Ok((a, b, _)) => builder.push(b.contains(&a)),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e);
+                    builder.push(false);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "st_intersects",
+        |_, a, b| {
+            if a.intersects(b) {
+                FunctionDomain::MayThrow
+            } else {
+                FunctionDomain::Domain(all_false_domain())
+            }
+        },
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+            |a, b, builder, ctx| match decode_pair(a, b) {
+                Ok((a, b, _)) => builder.push(a.intersects(&b)),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e);
+                    builder.push(false);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, Float64Type, _, _>(
+        "st_distance",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, Float64Type>(
+            |a, b, builder, ctx| match decode_pair(a, b).and_then(|(a, b, _)| distance(&a, &b)) {
+                Ok(distance) => builder.push(F64::from(distance)),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e);
+                    builder.push(F64::from(0.0));
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, Float64Type, _, _>(
+        "st_area",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, Float64Type>(|geo, builder, ctx| {
+            match ewkb_to_geometry(geo) {
+                Ok((geo, _)) => builder.push(F64::from(geo.unsigned_area())),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e);
+                    builder.push(F64::from(0.0));
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, Float64Type, GeometryType, _, _>(
+        "st_buffer",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, Float64Type, GeometryType>(
+            |geo, distance, builder, ctx| match ewkb_to_geometry(geo)
+                .and_then(|(geo, srid)| Ok((buffer(&geo, *distance)?, srid)))
+            {
+                Ok((geo, srid)) => write_geometry(&geo, srid, builder, ctx),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e);
+                    builder.commit_row();
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, Int32Type, GeometryType, _, _>(
+        "st_transform",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, Int32Type, GeometryType>(
+            |geo, to_srid, builder, ctx| {
+                let transformed = ewkb_to_geometry(geo).and_then(|(geo, srid)| {
+                    let from_srid = srid.ok_or_else(|| {
+                        "st_transform requires the geometry to have a SRID".to_string()
+                    })?;
+                    transform(&geo, from_srid, to_srid)
+                });
+                match transformed {
+                    Ok(geo) => write_geometry(&geo, Some(to_srid), builder, ctx),
+                    Err(e) => {
+                        ctx.set_error(builder.len(), e);
+                        builder.commit_row();
+                    }
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_3_arg::<GeometryType, Int32Type, Int32Type, GeometryType, _, _>(
+        "st_transform",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<GeometryType, Int32Type, Int32Type, GeometryType>(
+            |geo, from_srid, to_srid, builder, ctx| {
+                match ewkb_to_geometry(geo).and_then(|(geo, _)| transform(&geo, from_srid, to_srid)) {
+                    Ok(geo) => write_geometry(&geo, Some(to_srid), builder, ctx),
+                    Err(e) => {
+                        ctx.set_error(builder.len(), e);
+                        builder.commit_row();
+                    }
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, Float64Type, _, _>(
+        "st_x",
+        |_, domain| FunctionDomain::Domain(float_domain(domain.min_x, domain.max_x)),
+        vectorize_with_builder_1_arg::<GeometryType, Float64Type>(|geo, builder, ctx| {
+            match ewkb_to_geometry(geo).and_then(|(geo, _)| point_of(geo, "st_x")) {
+                Ok(point) => builder.push(F64::from(point.x())),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e);
+                    builder.push(F64::from(0.0));
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, Float64Type, _, _>(
+        "st_y",
+        |_, domain| FunctionDomain::Domain(float_domain(domain.min_y, domain.max_y)),
+        vectorize_with_builder_1_arg::<GeometryType, Float64Type>(|geo, builder, ctx| {
+            match ewkb_to_geometry(geo).and_then(|(geo, _)| point_of(geo, "st_y")) {
+                Ok(point) => builder.push(F64::from(point.y())),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e);
+                    builder.push(F64::from(0.0));
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, Int32Type, _, _>(
+        "st_srid",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<GeometryType, Int32Type>(|geo, builder, _| {
+            builder.push(read_srid(geo).unwrap_or(0));
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, Int32Type, GeometryType, _, _>(
+        "st_setsrid",
+        |_, domain, _| FunctionDomain::Domain(*domain),
+        vectorize_with_builder_2_arg::<GeometryType, Int32Type, GeometryType>(
+            |geo, srid, builder, ctx| match ewkb_to_geometry(geo) {
+                Ok((geo, _)) => write_geometry(&geo, Some(srid), builder, ctx),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e);
+                    builder.commit_row();
+                }
+            },
+        ),
+    );
+}
+
+fn write_geometry(
+    geo: &Geometry<f64>,
+    srid: Option<i32>,
+    builder: &mut StringColumnBuilder,
+    ctx: &mut EvalContext,
+) {
+    match geometry_to_ewkb(geo, srid) {
+        Ok(ewkb) => builder.put_slice(&ewkb),
+        Err(e) => ctx.set_error(builder.len(), e),
+    }
+    builder.commit_row();
+}
+
+/// Decode two geometries, they must have the same SRID.
+fn decode_pair(a: &[u8], b: &[u8]) -> Result<(Geometry<f64>, Geometry<f64>, Option<i32>), String> {
+    let (a, a_srid) = ewkb_to_geometry(a)?;
+    let (b, b_srid) = ewkb_to_geometry(b)?;
+    if a_srid != b_srid {
+        return Err(format!(
+            "geometries have different SRIDs: {} and {}",
+            a_srid.unwrap_or(0),
+            b_srid.unwrap_or(0)
+        ));
+    }
+    Ok((a, b, a_srid))
+}
+
+fn all_false_domain() -> BooleanDomain {
+    BooleanDomain {
+        has_false: true,
+        has_true: false,
+    }
+}
+
+fn float_domain(min: f64, max: f64) -> SimpleDomain<F64> {
+    SimpleDomain {
+        min: F64::from(min),
+        max: F64::from(max),
+    }
+}
+
+fn point_of(geo: Geometry<f64>, func: &str) -> Result<Point<f64>, String> {
+    match geo {
+        Geometry::Point(point) => Ok(point),
+        _ => Err(format!("{func} requires a point geometry")),
+    }
+}
+
+/// Collect the segments of the geometry.
+fn collect_lines(geo: &Geometry<f64>, lines: &mut Vec<Line<f64>>) {
+    match geo {
+        Geometry::Point(_) | Geometry::MultiPoint(_) => {}
+        Geometry::Line(line) => lines.push(*line),
+        Geometry::LineString(ls) => lines.extend(ls.lines()),
+        Geometry::Polygon(polygon) => {
+            lines.extend(polygon.exterior().lines());
+            for interior in polygon.interiors() {
+                lines.extend(interior.lines());
+            }
+        }
+        Geometry::MultiLineString(mls) => {
+            for ls in mls {
+                lines.extend(ls.lines());
+            }
+        }
+        Geometry::MultiPolygon(mp) => {
+            for polygon in mp {
+                collect_lines(&Geometry::Polygon(polygon.clone()), lines);
+            }
+        }
+        Geometry::GeometryCollection(gc) => {
+            for geo in gc {
+                collect_lines(geo, lines);
+            }
+        }
+        Geometry::Rect(rect) => collect_lines(&Geometry::Polygon(rect.to_polygon()), lines),
+        Geometry::Triangle(triangle) => {
+            collect_lines(&Geometry::Polygon(triangle.to_polygon()), lines)
+        }
+    }
+}
+
+/// Collect the polygons of the geometry.
+fn collect_polygons(geo: &Geometry<f64>, polygons: &mut Vec<Polygon<f64>>) {
+    match geo {
+        Geometry::Polygon(polygon) => polygons.push(polygon.clone()),
+        Geometry::MultiPolygon(mp) => polygons.extend(mp.iter().cloned()),
+        Geometry::Rect(rect) => polygons.push(rect.to_polygon()),
+        Geometry::Triangle(triangle) => polygons.push(triangle.to_polygon()),
+        Geometry::GeometryCollection(gc) => {
+            for geo in gc {
+                collect_polygons(geo, polygons);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The minimum euclidean distance between two geometries.
+fn distance(a: &Geometry<f64>, b: &Geometry<f64>) -> Result<f64, String> {
+    let a_points = a.coords_iter().map(Point::from).collect::<Vec<_>>();
+    let b_points = b.coords_iter().map(Point::from).collect::<Vec<_>>();
+    if a_points.is_empty() || b_points.is_empty() {
+        return Err("st_distance doesn't support empty geometries".to_string());
+    }
+    if a.intersects(b) {
+        return Ok(0.0);
+    }
+    let mut a_lines = vec![];
+    collect_lines(a, &mut a_lines);
+    let mut b_lines = vec![];
+    collect_lines(b, &mut b_lines);
+
+    // The geometries don't intersect, so the closest pair is formed by a vertex
+    // of one geometry and a vertex or segment of the other one.
+    let mut min = f64::INFINITY;
+    for p in &a_points {
+        for q in &b_points {
+            min = min.min(p.euclidean_distance(q));
+        }
+        for line in &b_lines {
+            min = min.min(p.euclidean_distance(line));
+        }
+    }
+    for q in &b_points {
+        for line in &a_lines {
+            min = min.min(q.euclidean_distance(line));
+        }
+    }
+    Ok(min)
+}
+
+/// Approximate the area within `distance` of the geometry with polygons: a circle
+/// around each vertex, a rectangle around each segment and the polygons themselves.
+fn buffer(geo: &Geometry<f64>, distance: f64) -> Result<Geometry<f64>, String> {
+    if !distance.is_finite() || distance <= 0.0 {
+        return Err(format!(
+            "st_buffer requires a positive distance, but got {distance}"
+        ));
+    }
+    let mut parts = vec![];
+    collect_polygons(geo, &mut parts);
+    for c in geo.coords_iter() {
+        let ring = (0..=BUFFER_CIRCLE_SEGMENTS)
+            .map(|i| {
+                let angle =
+                    2.0 * PI * (i % BUFFER_CIRCLE_SEGMENTS) as f64 / BUFFER_CIRCLE_SEGMENTS as f64;
+                coord! { x: c.x + distance * angle.cos(), y: c.y + distance * angle.sin() }
+            })
+            .collect::<Vec<_>>();
+        parts.push(Polygon::new(LineString::new(ring), vec![]));
+    }
+    let mut lines = vec![];
+    collect_lines(geo, &mut lines);
+    for line in lines {
+        let (dx, dy) = (line.dx(), line.dy());
+        let len = dx.hypot(dy);
+        if len == 0.0 {
+            continue;
+        }
+        let offset: Coord<f64> = coord! { x: -dy / len * distance, y: dx / len * distance };
+        let ring = vec![
+            line.start + offset,
+            line.end + offset,
+            line.end - offset,
+            line.start - offset,
+            line.start + offset,
+        ];
+        parts.push(Polygon::new(LineString::new(ring), vec![]));
+    }
+    if parts.is_empty() {
+        return Err("st_buffer doesn't support empty geometries".to_string());
+    }
+
+    let mut result = MultiPolygon::new(vec![]);
+    for part in parts {
+        result = result.union(&MultiPolygon::new(vec![part]));
+    }
+    if result.0.len() == 1 {
+        Ok(Geometry::Polygon(result.0.pop().unwrap()))
+    } else {
+        Ok(Geometry::MultiPolygon(result))
+    }
+}
+
+/// Transform the geometry between WGS 84 (EPSG:4326) and Web Mercator (EPSG:3857).
+fn transform(geo: &Geometry<f64>, from_srid: i32, to_srid: i32) -> Result<Geometry<f64>, String> {
+    match (from_srid, to_srid) {
+        (from, to) if from == to => Ok(geo.clone()),
+        (4326, 3857) => Ok(geo.map_coords(|c| {
+            let x = c.x * WEB_MERCATOR_HALF_SIZE / 180.0;
+            let y = ((90.0 + c.y) * PI / 360.0).tan().ln() / PI * WEB_MERCATOR_HALF_SIZE;
+            coord! { x: x, y: y }
+        })),
+        (3857, 4326) => Ok(geo.map_coords(|c| {
+            let x = c.x / WEB_MERCATOR_HALF_SIZE * 180.0;
+            let y =
+                (2.0 * (c.y / WEB_MERCATOR_HALF_SIZE * PI).exp().atan() - PI / 2.0) * 180.0 / PI;
+            coord! { x: x, y: y }
+        })),
+        (from, to) => Err(format!(
+            "st_transform only supports the SRIDs 4326 and 3857, but got {from} and {to}"
+        )),
+    }
+}
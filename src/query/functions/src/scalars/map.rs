@@ -16,12 +16,16 @@ use std::hash::Hash;
 
 use common_expression::types::nullable::NullableDomain;
 use common_expression::types::ArrayType;
+use common_expression::types::BooleanType;
 use common_expression::types::EmptyArrayType;
 use common_expression::types::EmptyMapType;
 use common_expression::types::GenericType;
 use common_expression::types::MapType;
 use common_expression::types::NullType;
 use common_expression::types::NullableType;
+use common_expression::types::UInt64Type;
+use common_expression::vectorize_1_arg;
+use common_expression::vectorize_2_arg;
 use common_expression::vectorize_with_builder_2_arg;
 use common_expression::FunctionDomain;
 use common_expression::FunctionRegistry;
@@ -31,6 +35,8 @@ use siphasher::sip128::Hasher128;
 use siphasher::sip128::SipHasher24;
 
 pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_aliases("map", &["map_from_arrays"]);
+
     registry
         .register_passthrough_nullable_2_arg::<EmptyArrayType, EmptyArrayType, EmptyMapType, _, _>(
             "map",
@@ -108,4 +114,106 @@ pub fn register(registry: &mut FunctionRegistry) {
             }
         ),
     );
+
+    registry.register_passthrough_nullable_1_arg::<EmptyMapType, EmptyArrayType, _, _>(
+        "map_keys",
+        |_, _| FunctionDomain::Full,
+        |_, _| Value::Scalar(()),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<MapType<GenericType<0>, GenericType<1>>, ArrayType<GenericType<0>>, _, _>(
+        "map_keys",
+        |_, domain| FunctionDomain::Domain(domain.clone().map(|(key_domain, _)| key_domain)),
+        vectorize_1_arg::<MapType<GenericType<0>, GenericType<1>>, ArrayType<GenericType<0>>>(
+            |map, _| map.keys
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<EmptyMapType, EmptyArrayType, _, _>(
+        "map_values",
+        |_, _| FunctionDomain::Full,
+        |_, _| Value::Scalar(()),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<MapType<GenericType<0>, GenericType<1>>, ArrayType<GenericType<1>>, _, _>(
+        "map_values",
+        |_, domain| FunctionDomain::Domain(domain.clone().map(|(_, val_domain)| val_domain)),
+        vectorize_1_arg::<MapType<GenericType<0>, GenericType<1>>, ArrayType<GenericType<1>>>(
+            |map, _| map.values
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<EmptyMapType, UInt64Type, _, _>(
+        "map_size",
+        |_, _| FunctionDomain::Full,
+        |_, _| Value::Scalar(0),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<MapType<GenericType<0>, GenericType<1>>, UInt64Type, _, _>(
+        "map_size",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<MapType<GenericType<0>, GenericType<1>>, UInt64Type>(
+            |map, _| map.len() as u64
+        ),
+    );
+
+    registry
+        .register_passthrough_nullable_2_arg::<EmptyMapType, GenericType<0>, BooleanType, _, _>(
+            "map_contains_key",
+            |_, _, _| FunctionDomain::Full,
+            |_, _, _| Value::Scalar(false),
+        );
+
+    registry.register_passthrough_nullable_2_arg::<MapType<GenericType<0>, GenericType<1>>, GenericType<0>, BooleanType, _, _>(
+        "map_contains_key",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_2_arg::<MapType<GenericType<0>, GenericType<1>>, GenericType<0>, BooleanType>(
+            |map, key, _| map.iter().any(|(k, _)| k == key)
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<EmptyMapType, EmptyMapType, EmptyMapType, _, _>(
+        "map_cat",
+        |_, _, _| FunctionDomain::Full,
+        |_, _, _| Value::Scalar(()),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<MapType<GenericType<0>, GenericType<1>>, MapType<GenericType<0>, GenericType<1>>, MapType<GenericType<0>, GenericType<1>>, _, _>(
+        "map_cat",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<MapType<GenericType<0>, GenericType<1>>, MapType<GenericType<0>, GenericType<1>>, MapType<GenericType<0>, GenericType<1>>>(
+            |lhs, rhs, output, _| {
+                // The values of the right map take precedence for the duplicate keys.
+                for (k, v) in lhs.iter() {
+                    if !rhs.iter().any(|(rk, _)| rk == k) {
+                        output.put_item((k, v));
+                    }
+                }
+                output.builder.append_column(&rhs);
+                output.commit_row();
+            }
+        ),
+    );
+
+    registry
+        .register_passthrough_nullable_2_arg::<EmptyMapType, GenericType<0>, EmptyMapType, _, _>(
+            "map_delete",
+            |_, _, _| FunctionDomain::Full,
+            |_, _, _| Value::Scalar(()),
+        );
+
+    registry.register_passthrough_nullable_2_arg::<MapType<GenericType<0>, GenericType<1>>, GenericType<0>, MapType<GenericType<0>, GenericType<1>>, _, _>(
+        "map_delete",
+        |_, domain, _| FunctionDomain::Domain(domain.clone()),
+        vectorize_with_builder_2_arg::<MapType<GenericType<0>, GenericType<1>>, GenericType<0>, MapType<GenericType<0>, GenericType<1>>>(
+            |map, key, output, _| {
+                for (k, v) in map.iter() {
+                    if k != key {
+                        output.put_item((k, v));
+                    }
+                }
+                output.commit_row();
+            }
+        ),
+    );
 }
@@ -17,6 +17,7 @@ use common_expression::FunctionRegistry;
 mod arithmetic;
 mod arithmetic_modulo;
 mod array;
+mod binary;
 mod bitmap;
 mod boolean;
 mod comparison;
@@ -25,12 +26,16 @@ mod datetime;
 mod decimal;
 mod geo;
 mod geo_h3;
+mod geometry;
 mod hash;
 mod map;
 mod math;
 mod other;
+mod search;
 mod string;
 mod string_multi_args;
+mod time;
+mod timestamp_tz;
 mod tuple;
 mod variant;
 mod vector;
@@ -39,6 +44,7 @@ pub use comparison::check_pattern_type;
 pub use comparison::is_like_pattern_escape;
 pub use comparison::PatternType;
 pub use comparison::ALL_COMP_FUNC_NAMES;
+pub use search::Tokenizer;
 
 pub fn register(registry: &mut FunctionRegistry) {
     variant::register(registry);
@@ -48,16 +54,21 @@ pub fn register(registry: &mut FunctionRegistry) {
     control::register(registry);
     comparison::register(registry);
     datetime::register(registry);
+    timestamp_tz::register(registry);
+    time::register(registry);
     math::register(registry);
     map::register(registry);
     string::register(registry);
     string_multi_args::register(registry);
+    binary::register(registry);
     tuple::register(registry);
     geo::register(registry);
     geo_h3::register(registry);
+    geometry::register(registry);
     hash::register(registry);
     other::register(registry);
     decimal::register(registry);
     vector::register(registry);
     bitmap::register(registry);
+    search::register(registry);
 }
@@ -0,0 +1,140 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::BooleanType;
+use common_expression::types::StringType;
+use common_expression::vectorize_with_builder_3_arg;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+
+/// Words dropped by the `english` tokenizer, same as the default stop set of Lucene.
+const ENGLISH_STOP_WORDS: [&str; 33] = [
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "if", "in", "into", "is", "it",
+    "no", "not", "of", "on", "or", "such", "that", "the", "their", "then", "there", "these",
+    "they", "this", "to", "was", "will", "with",
+];
+
+/// Splits text into the terms of an inverted index.
+///
+/// The same tokenizer must be used to build the index and to evaluate
+/// `match`, otherwise the index may prune blocks that have matched rows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tokenizer {
+    /// Lowercased alphanumeric words, without the english stop words.
+    English,
+    /// Lowercased alphanumeric words, and overlapping bigrams of CJK characters.
+    Chinese,
+}
+
+impl Tokenizer {
+    pub fn try_create(name: &str) -> Result<Self> {
+        match name.to_lowercase().as_str() {
+            "english" => Ok(Tokenizer::English),
+            "chinese" => Ok(Tokenizer::Chinese),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Unknown tokenizer '{}', expected 'english' or 'chinese'",
+                name
+            ))),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Tokenizer::English => "english",
+            Tokenizer::Chinese => "chinese",
+        }
+    }
+
+    pub fn tokenize(&self, text: &[u8]) -> Vec<String> {
+        let text = String::from_utf8_lossy(text);
+        let mut tokens = Vec::new();
+        let mut word = String::new();
+        let mut cjk_chars = Vec::new();
+        for c in text.chars() {
+            let is_cjk = *self == Tokenizer::Chinese && is_cjk_char(c);
+            if !c.is_alphanumeric() || is_cjk {
+                self.push_word(&mut word, &mut tokens);
+            }
+            if !is_cjk {
+                push_cjk_bigrams(&mut cjk_chars, &mut tokens);
+            }
+            if is_cjk {
+                cjk_chars.push(c);
+            } else if c.is_alphanumeric() {
+                word.extend(c.to_lowercase());
+            }
+        }
+        self.push_word(&mut word, &mut tokens);
+        push_cjk_bigrams(&mut cjk_chars, &mut tokens);
+        tokens
+    }
+
+    fn push_word(&self, word: &mut String, tokens: &mut Vec<String>) {
+        if word.is_empty() {
+            return;
+        }
+        if *self == Tokenizer::English && ENGLISH_STOP_WORDS.contains(&word.as_str()) {
+            word.clear();
+            return;
+        }
+        tokens.push(std::mem::take(word));
+    }
+}
+
+fn is_cjk_char(c: char) -> bool {
+    matches!(c as u32,
+        0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF | 0x20000..=0x2FA1F)
+}
+
+// A single CJK character is a token, longer runs are split into overlapping bigrams.
+fn push_cjk_bigrams(chars: &mut Vec<char>, tokens: &mut Vec<String>) {
+    match chars.len() {
+        0 => {}
+        1 => tokens.push(chars[0].to_string()),
+        _ => tokens.extend(chars.windows(2).map(|w| w.iter().collect::<String>())),
+    }
+    chars.clear();
+}
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // `match(col, 'terms')` is bound to `match(col, 'terms', tokenizer)`,
+    // the tokenizer is the one of the inverted index on `col`.
+    registry.register_passthrough_nullable_3_arg::<StringType, StringType, StringType, BooleanType, _, _>(
+        "match",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<StringType, StringType, StringType, BooleanType>(
+            |text, terms, tokenizer, output, ctx| {
+                let tokenizer = match std::str::from_utf8(tokenizer)
+                    .map_err(|e| e.to_string())
+                    .and_then(|name| Tokenizer::try_create(name).map_err(|e| e.message()))
+                {
+                    Ok(tokenizer) => tokenizer,
+                    Err(e) => {
+                        ctx.set_error(output.len(), e);
+                        output.push(false);
+                        return;
+                    }
+                };
+                let terms = tokenizer.tokenize(terms);
+                let tokens = tokenizer.tokenize(text);
+                let tokens = tokens.iter().collect::<HashSet<_>>();
+                output.push(terms.iter().any(|term| tokens.contains(term)));
+            },
+        ),
+    );
+}
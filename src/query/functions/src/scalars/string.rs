@@ -774,7 +774,7 @@ pub(crate) mod soundex {
 }
 
 #[inline]
-fn substr(str: &[u8], pos: i64, len: u64) -> &[u8] {
+pub(crate) fn substr(str: &[u8], pos: i64, len: u64) -> &[u8] {
     if pos > 0 && pos <= str.len() as i64 {
         let l = str.len();
         let s = (pos - 1) as usize;
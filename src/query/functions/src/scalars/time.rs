@@ -0,0 +1,210 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use chrono::TimeZone;
+use common_expression::error_to_null;
+use common_expression::types::number::Int64Type;
+use common_expression::types::number::UInt8Type;
+use common_expression::types::time::micros_to_naive_time;
+use common_expression::types::time::naive_time_to_micros;
+use common_expression::types::time::string_to_time;
+use common_expression::types::time::time_to_string;
+use common_expression::types::time::MICROS_IN_A_DAY;
+use common_expression::types::timestamp::check_timestamp;
+use common_expression::types::timestamp::MICROS_IN_A_SEC;
+use common_expression::types::DateType;
+use common_expression::types::StringType;
+use common_expression::types::TimeType;
+use common_expression::types::TimestampType;
+use common_expression::utils::date_helper::DateConverter;
+use common_expression::vectorize_1_arg;
+use common_expression::vectorize_2_arg;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // cast(xx AS time)
+    // to_time(xx)
+    register_to_time(registry);
+
+    // cast(time AS string)
+    register_time_to_other(registry);
+
+    // to_hour(time), to_minute(time), to_second(time), extract(hour from time)
+    register_to_number_functions(registry);
+
+    // time_add(time, secs), time_diff(time, time), add_hours(time, n), ...
+    register_time_arith(registry);
+
+    // date + time -> timestamp
+    register_date_plus_time(registry);
+}
+
+/// Normalize the microsecs into a time of day, wraps around midnight.
+#[inline]
+fn wrap_time(micros: i64) -> i64 {
+    micros.rem_euclid(MICROS_IN_A_DAY)
+}
+
+fn register_to_time(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, TimeType, _, _>(
+        "to_time",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_time,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, TimeType, _, _>(
+        "try_to_time",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_time),
+    );
+
+    fn eval_string_to_time(val: ValueRef<StringType>, ctx: &mut EvalContext) -> Value<TimeType> {
+        vectorize_with_builder_1_arg::<StringType, TimeType>(
+            |val, output, ctx| match string_to_time(val) {
+                Some(time) => output.push(time),
+                None => {
+                    ctx.set_error(output.len(), "cannot parse to type `TIME`");
+                    output.push(0);
+                }
+            },
+        )(val, ctx)
+    }
+
+    // The time of day of the timestamp in the session time zone.
+    registry.register_passthrough_nullable_1_arg::<TimestampType, TimeType, _, _>(
+        "to_time",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampType, TimeType>(|val, ctx| {
+            naive_time_to_micros(&val.to_timestamp(ctx.func_ctx.tz.tz).time())
+        }),
+    );
+    registry.register_passthrough_nullable_1_arg::<TimeType, TimeType, _, _>(
+        "to_time",
+        |_, domain| FunctionDomain::Domain(*domain),
+        vectorize_1_arg::<TimeType, TimeType>(|val, _| val),
+    );
+}
+
+fn register_time_to_other(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<TimeType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<TimeType, StringType>(|val, output, _| {
+            write!(output.data, "{}", time_to_string(val)).unwrap();
+            output.commit_row();
+        }),
+    );
+}
+
+fn register_to_number_functions(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<TimeType, UInt8Type, _, _>(
+        "to_hour",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<TimeType, UInt8Type>(|val, _| (val / (3600 * MICROS_IN_A_SEC)) as u8),
+    );
+    registry.register_passthrough_nullable_1_arg::<TimeType, UInt8Type, _, _>(
+        "to_minute",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<TimeType, UInt8Type>(|val, _| (val / (60 * MICROS_IN_A_SEC) % 60) as u8),
+    );
+    registry.register_passthrough_nullable_1_arg::<TimeType, UInt8Type, _, _>(
+        "to_second",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<TimeType, UInt8Type>(|val, _| (val / MICROS_IN_A_SEC % 60) as u8),
+    );
+}
+
+fn register_time_arith(registry: &mut FunctionRegistry) {
+    // time_add(t, secs): `secs` seconds later than `t`, wraps around midnight.
+    registry.register_2_arg::<TimeType, Int64Type, TimeType, _, _>(
+        "time_add",
+        |_, _, _| FunctionDomain::Full,
+        |time, secs, _| wrap_time(time + secs % (24 * 3600) * MICROS_IN_A_SEC),
+    );
+
+    // time_diff(t1, t2): the seconds from `t2` to `t1`, negative if `t1` is earlier.
+    registry.register_2_arg::<TimeType, TimeType, Int64Type, _, _>(
+        "time_diff",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| (lhs - rhs) / MICROS_IN_A_SEC,
+    );
+
+    // The functions behind `t + INTERVAL n HOUR` and `date_add(hour, n, t)`.
+    for (name, factor, sign) in [
+        ("add_hours", 3600, 1),
+        ("add_minutes", 60, 1),
+        ("add_seconds", 1, 1),
+        ("subtract_hours", 3600, -1),
+        ("subtract_minutes", 60, -1),
+        ("subtract_seconds", 1, -1),
+    ] {
+        registry.register_passthrough_nullable_2_arg::<TimeType, Int64Type, TimeType, _, _>(
+            name,
+            |_, _, _| FunctionDomain::Full,
+            vectorize_2_arg::<TimeType, Int64Type, TimeType>(move |time, delta, _| {
+                let secs = (delta % (24 * 3600)) * factor * sign;
+                wrap_time(time + secs * MICROS_IN_A_SEC)
+            }),
+        );
+    }
+}
+
+fn register_date_plus_time(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<DateType, TimeType, TimestampType, _, _>(
+        "plus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<DateType, TimeType, TimestampType>(
+            |date, time, output, ctx| match date_and_time_to_timestamp(date, time, ctx) {
+                Ok(ts) => output.push(ts),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(0);
+                }
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_2_arg::<TimeType, DateType, TimestampType, _, _>(
+        "plus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<TimeType, DateType, TimestampType>(
+            |time, date, output, ctx| match date_and_time_to_timestamp(date, time, ctx) {
+                Ok(ts) => output.push(ts),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(0);
+                }
+            },
+        ),
+    );
+
+    /// The wall clock `date time` in the session time zone.
+    fn date_and_time_to_timestamp(date: i32, time: i64, ctx: &EvalContext) -> Result<i64, String> {
+        let tz = ctx.func_ctx.tz.tz;
+        let date = date.to_date(tz);
+        let local = date.and_time(micros_to_naive_time(time));
+        let micros = tz
+            .from_local_datetime(&local)
+            .earliest()
+            .map(|dt| dt.timestamp_micros())
+            .ok_or_else(|| format!("invalid local time `{local}`"))?;
+        check_timestamp(micros)
+    }
+}
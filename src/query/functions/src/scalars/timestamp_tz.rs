@@ -0,0 +1,399 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use chrono::Datelike;
+use chrono::FixedOffset;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::Offset;
+use chrono::TimeZone;
+use chrono_tz::Tz;
+use common_arrow::arrow::temporal_conversions::EPOCH_DAYS_FROM_CE;
+use common_expression::error_to_null;
+use common_expression::types::date::check_date;
+use common_expression::types::number::SimpleDomain;
+use common_expression::types::timestamp::check_timestamp;
+use common_expression::types::timestamp::MICROS_IN_A_SEC;
+use common_expression::types::timestamp_tz::datetime_to_timestamp_tz;
+use common_expression::types::timestamp_tz::string_to_timestamp_tz;
+use common_expression::types::timestamp_tz::timestamp_tz;
+use common_expression::types::timestamp_tz::timestamp_tz_micros;
+use common_expression::types::timestamp_tz::timestamp_tz_offset;
+use common_expression::types::timestamp_tz::timestamp_tz_to_datetime;
+use common_expression::types::timestamp_tz::timestamp_tz_to_string;
+use common_expression::types::DateType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::types::TimestampTzType;
+use common_expression::utils::date_helper::DateConverter;
+use common_expression::utils::date_helper::Round;
+use common_expression::vectorize_1_arg;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::vectorize_with_builder_3_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // cast(xx AS timestamp_tz)
+    // to_timestamp_tz(xx)
+    register_to_timestamp_tz(registry);
+
+    // cast(timestamp_tz AS [timestamp | date | string])
+    register_timestamp_tz_to_other(registry);
+
+    // convert_timezone([src_tz,] dst_tz, ts)
+    // ts AT TIME ZONE dst_tz
+    register_convert_timezone(registry);
+
+    // to_start_of_*(timestamp_tz), rounded in the time zone of the value itself.
+    register_rounder_functions(registry);
+}
+
+/// The time zone argument of the functions, either a IANA time zone name like
+/// `Asia/Shanghai` or a fixed offset like `+08:00`.
+enum TimeZoneArg {
+    Named(Tz),
+    Fixed(FixedOffset),
+}
+
+impl TimeZoneArg {
+    fn parse(s: &[u8]) -> Result<Self, String> {
+        let s = std::str::from_utf8(s).map_err(|e| e.to_string())?.trim();
+        if let Ok(tz) = s.parse::<Tz>() {
+            return Ok(TimeZoneArg::Named(tz));
+        }
+        parse_fixed_offset(s)
+            .map(TimeZoneArg::Fixed)
+            .ok_or_else(|| format!("invalid time zone `{s}`"))
+    }
+
+    /// The offset in seconds of the time zone at the instant `micros`.
+    fn offset_at(&self, micros: i64) -> i32 {
+        match self {
+            TimeZoneArg::Named(tz) => micros.to_timestamp(*tz).offset().fix().local_minus_utc(),
+            TimeZoneArg::Fixed(offset) => offset.local_minus_utc(),
+        }
+    }
+
+    /// Interpret the wall clock `local` in the time zone, returns the microsecs since epoch.
+    fn from_local(&self, local: &NaiveDateTime) -> Option<i64> {
+        match self {
+            TimeZoneArg::Named(tz) => tz
+                .from_local_datetime(local)
+                .earliest()
+                .map(|dt| dt.timestamp_micros()),
+            TimeZoneArg::Fixed(offset) => offset
+                .from_local_datetime(local)
+                .single()
+                .map(|dt| dt.timestamp_micros()),
+        }
+    }
+}
+
+/// Parse offsets like `+08:00`, `-0530` or `+8`.
+fn parse_fixed_offset(s: &str) -> Option<FixedOffset> {
+    let (sign, rest) = match s.as_bytes().first()? {
+        b'+' => (1, &s[1..]),
+        b'-' => (-1, &s[1..]),
+        _ => return None,
+    };
+    let (hours, minutes) = match rest.split_once(':') {
+        Some((h, m)) => (h, m),
+        None if rest.len() == 4 => rest.split_at(2),
+        None => (rest, "0"),
+    };
+    let hours: i32 = hours.parse().ok()?;
+    let minutes: i32 = minutes.parse().ok()?;
+    if hours > 14 || minutes >= 60 {
+        return None;
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+fn timestamp_to_timestamp_tz(micros: i64, tz: Tz) -> i128 {
+    timestamp_tz(
+        micros,
+        micros.to_timestamp(tz).offset().fix().local_minus_utc(),
+    )
+}
+
+fn naive_date_to_days(date: NaiveDate) -> i32 {
+    date.num_days_from_ce() - EPOCH_DAYS_FROM_CE
+}
+
+fn register_to_timestamp_tz(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, TimestampTzType, _, _>(
+        "to_timestamp_tz",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_timestamp_tz,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, TimestampTzType, _, _>(
+        "try_to_timestamp_tz",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_timestamp_tz),
+    );
+
+    fn eval_string_to_timestamp_tz(
+        val: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<TimestampTzType> {
+        vectorize_with_builder_1_arg::<StringType, TimestampTzType>(|val, output, ctx| {
+            match string_to_timestamp_tz(val, ctx.func_ctx.tz.tz) {
+                Some(ts) => match check_timestamp(timestamp_tz_micros(ts)) {
+                    Ok(_) => output.push(ts),
+                    Err(e) => {
+                        ctx.set_error(output.len(), e);
+                        output.push(0);
+                    }
+                },
+                None => {
+                    ctx.set_error(output.len(), "cannot parse to type `TIMESTAMP_TZ`");
+                    output.push(0);
+                }
+            }
+        })(val, ctx)
+    }
+
+    // The timestamp is the instant, the offset is taken from the session time zone.
+    registry.register_passthrough_nullable_1_arg::<TimestampType, TimestampTzType, _, _>(
+        "to_timestamp_tz",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampType, TimestampTzType>(|val, ctx| {
+            timestamp_to_timestamp_tz(val, ctx.func_ctx.tz.tz)
+        }),
+    );
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, TimestampTzType, _, _>(
+        "to_timestamp_tz",
+        |_, domain| FunctionDomain::Domain(*domain),
+        vectorize_1_arg::<TimestampTzType, TimestampTzType>(|val, _| val),
+    );
+    registry.register_passthrough_nullable_1_arg::<DateType, TimestampTzType, _, _>(
+        "to_timestamp_tz",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<DateType, TimestampTzType>(|val, ctx| {
+            let tz = ctx.func_ctx.tz.tz;
+            let dt = val
+                .to_date(tz)
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+                .and_local_timezone(tz)
+                .earliest()
+                .unwrap();
+            datetime_to_timestamp_tz(&dt)
+        }),
+    );
+}
+
+fn register_timestamp_tz_to_other(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, TimestampType, _, _>(
+        "to_timestamp",
+        |_, domain| {
+            FunctionDomain::Domain(SimpleDomain {
+                min: timestamp_tz_micros(domain.min),
+                max: timestamp_tz_micros(domain.max),
+            })
+        },
+        vectorize_1_arg::<TimestampTzType, TimestampType>(|val, _| timestamp_tz_micros(val)),
+    );
+
+    // The date in the time zone of the value.
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, DateType, _, _>(
+        "to_date",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampTzType, DateType>(|val, _| {
+            naive_date_to_days(timestamp_tz_to_datetime(val).date_naive())
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<TimestampTzType, StringType>(|val, output, _| {
+            write!(output.data, "{}", timestamp_tz_to_string(val)).unwrap();
+            output.commit_row();
+        }),
+    );
+}
+
+fn register_convert_timezone(registry: &mut FunctionRegistry) {
+    // convert_timezone(dst_tz, ts): the same instant, seen from the time zone `dst_tz`.
+    registry
+        .register_passthrough_nullable_2_arg::<StringType, TimestampTzType, TimestampTzType, _, _>(
+            "convert_timezone",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<StringType, TimestampTzType, TimestampTzType>(
+                |dst_tz, val, output, ctx| {
+                    let micros = timestamp_tz_micros(val);
+                    match TimeZoneArg::parse(dst_tz) {
+                        Ok(tz) => output.push(timestamp_tz(micros, tz.offset_at(micros))),
+                        Err(e) => {
+                            ctx.set_error(output.len(), e);
+                            output.push(0);
+                        }
+                    }
+                },
+            ),
+        );
+    registry
+        .register_passthrough_nullable_2_arg::<StringType, TimestampType, TimestampTzType, _, _>(
+            "convert_timezone",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<StringType, TimestampType, TimestampTzType>(
+                |dst_tz, micros, output, ctx| match TimeZoneArg::parse(dst_tz) {
+                    Ok(tz) => output.push(timestamp_tz(micros, tz.offset_at(micros))),
+                    Err(e) => {
+                        ctx.set_error(output.len(), e);
+                        output.push(0);
+                    }
+                },
+            ),
+        );
+
+    // convert_timezone(src_tz, dst_tz, ts): the wall clock `ts` in `src_tz` converted to the
+    // wall clock in `dst_tz`. The wall clock of a timestamp is its value in the session time zone.
+    registry.register_passthrough_nullable_3_arg::<StringType, StringType, TimestampType, TimestampType, _, _>(
+        "convert_timezone",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<StringType, StringType, TimestampType, TimestampType>(
+            |src_tz, dst_tz, micros, output, ctx| {
+                let session_tz = ctx.func_ctx.tz.tz;
+                let res = TimeZoneArg::parse(src_tz).and_then(|src_tz| {
+                    let dst_tz = TimeZoneArg::parse(dst_tz)?;
+                    let wall = micros.to_timestamp(session_tz).naive_local();
+                    let instant = src_tz
+                        .from_local(&wall)
+                        .ok_or_else(|| format!("invalid local time `{wall}`"))?;
+                    let offset = dst_tz.offset_at(instant);
+                    let wall = instant
+                        .to_timestamp(Tz::UTC)
+                        .naive_utc()
+                        .checked_add_signed(chrono::Duration::seconds(offset as i64))
+                        .ok_or_else(|| "timestamp is out of range".to_string())?;
+                    let micros = session_tz
+                        .from_local_datetime(&wall)
+                        .earliest()
+                        .map(|dt| dt.timestamp_micros())
+                        .ok_or_else(|| format!("invalid local time `{wall}`"))?;
+                    check_timestamp(micros)
+                });
+                match res {
+                    Ok(micros) => output.push(micros),
+                    Err(e) => {
+                        ctx.set_error(output.len(), e);
+                        output.push(0);
+                    }
+                }
+            },
+        ),
+    );
+}
+
+/// Round the timestamp down in its own time zone, the offset is kept.
+///
+/// The offset of a `TIMESTAMP_TZ` value is fixed, so the rounding is exact arithmetic
+/// on the local microsecs without any DST ambiguity.
+fn round_timestamp_tz(val: i128, round: Round) -> i128 {
+    let offset = timestamp_tz_offset(val);
+    let offset_micros = offset as i64 * MICROS_IN_A_SEC;
+    let local = timestamp_tz_micros(val) + offset_micros;
+    let unit = MICROS_IN_A_SEC
+        * match round {
+            Round::Second => 1,
+            Round::Minute => 60,
+            Round::FiveMinutes => 5 * 60,
+            Round::TenMinutes => 10 * 60,
+            Round::FifteenMinutes => 15 * 60,
+            Round::TimeSlot => 30 * 60,
+            Round::Hour => 3600,
+            Round::Day => 24 * 3600,
+        };
+    timestamp_tz(local.div_euclid(unit) * unit - offset_micros, offset)
+}
+
+fn register_rounder_functions(registry: &mut FunctionRegistry) {
+    for (name, round) in [
+        ("to_start_of_second", Round::Second),
+        ("to_start_of_minute", Round::Minute),
+        ("to_start_of_five_minutes", Round::FiveMinutes),
+        ("to_start_of_ten_minutes", Round::TenMinutes),
+        ("to_start_of_fifteen_minutes", Round::FifteenMinutes),
+        ("to_start_of_hour", Round::Hour),
+        ("to_start_of_day", Round::Day),
+        ("time_slot", Round::TimeSlot),
+    ] {
+        registry.register_passthrough_nullable_1_arg::<TimestampTzType, TimestampTzType, _, _>(
+            name,
+            |_, _| FunctionDomain::Full,
+            vectorize_1_arg::<TimestampTzType, TimestampTzType>(move |val, _| {
+                round_timestamp_tz(val, round)
+            }),
+        );
+    }
+
+    // timestamp_tz -> date, in the time zone of the value.
+    for (name, rounder) in [
+        ("to_monday", to_last_monday as fn(NaiveDate) -> NaiveDate),
+        ("to_start_of_week", to_last_sunday),
+        ("to_start_of_month", to_start_of_month),
+        ("to_start_of_quarter", to_start_of_quarter),
+        ("to_start_of_year", to_start_of_year),
+        ("to_start_of_iso_year", to_start_of_iso_year),
+    ] {
+        registry.register_passthrough_nullable_1_arg::<TimestampTzType, DateType, _, _>(
+            name,
+            |_, _| FunctionDomain::Full,
+            vectorize_with_builder_1_arg::<TimestampTzType, DateType>(move |val, output, ctx| {
+                let date = rounder(timestamp_tz_to_datetime(val).date_naive());
+                match check_date(naive_date_to_days(date) as i64) {
+                    Ok(days) => output.push(days),
+                    Err(e) => {
+                        ctx.set_error(output.len(), e);
+                        output.push(0);
+                    }
+                }
+            }),
+        );
+    }
+
+    fn to_last_monday(date: NaiveDate) -> NaiveDate {
+        date - chrono::Duration::days(date.weekday().num_days_from_monday() as i64)
+    }
+
+    fn to_last_sunday(date: NaiveDate) -> NaiveDate {
+        date - chrono::Duration::days(date.weekday().num_days_from_sunday() as i64)
+    }
+
+    fn to_start_of_month(date: NaiveDate) -> NaiveDate {
+        date.with_day(1).unwrap()
+    }
+
+    fn to_start_of_quarter(date: NaiveDate) -> NaiveDate {
+        let month = date.month0() / 3 * 3 + 1;
+        NaiveDate::from_ymd_opt(date.year(), month, 1).unwrap()
+    }
+
+    fn to_start_of_year(date: NaiveDate) -> NaiveDate {
+        NaiveDate::from_ymd_opt(date.year(), 1, 1).unwrap()
+    }
+
+    fn to_start_of_iso_year(date: NaiveDate) -> NaiveDate {
+        NaiveDate::from_isoywd_opt(date.iso_week().year(), 1, chrono::Weekday::Mon).unwrap()
+    }
+}
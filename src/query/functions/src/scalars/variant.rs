@@ -12,11 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Ordering;
 use std::collections::HashSet;
 use std::sync::Arc;
 
 use bstr::ByteSlice;
 use chrono::Datelike;
+use common_arrow::arrow::bitmap::MutableBitmap;
 use common_arrow::arrow::temporal_conversions::EPOCH_DAYS_FROM_CE;
 use common_expression::types::date::string_to_date;
 use common_expression::types::nullable::NullableColumn;
@@ -39,7 +41,9 @@ use common_expression::types::TimestampType;
 use common_expression::types::VariantType;
 use common_expression::types::ALL_NUMERICS_TYPES;
 use common_expression::utils::arrow::constant_bitmap;
+use common_expression::utils::date_helper::TzLUT;
 use common_expression::vectorize_1_arg;
+use common_expression::vectorize_2_arg;
 use common_expression::vectorize_with_builder_1_arg;
 use common_expression::vectorize_with_builder_2_arg;
 use common_expression::with_number_mapped_type;
@@ -56,11 +60,15 @@ use common_expression::ScalarRef;
 use common_expression::Value;
 use common_expression::ValueRef;
 use jsonb::array_length;
+use jsonb::array_values;
 use jsonb::as_bool;
 use jsonb::as_f64;
 use jsonb::as_i64;
 use jsonb::as_str;
+use jsonb::build_array;
 use jsonb::build_object;
+use jsonb::compare;
+use jsonb::from_slice;
 use jsonb::get_by_index;
 use jsonb::get_by_name;
 use jsonb::get_by_path;
@@ -77,6 +85,7 @@ use jsonb::to_i64;
 use jsonb::to_str;
 use jsonb::to_string;
 use jsonb::to_u64;
+use jsonb::Value as JsonbValue;
 
 pub fn register(registry: &mut FunctionRegistry) {
     registry.register_aliases("json_object_keys", &["object_keys"]);
@@ -928,6 +937,211 @@ pub fn register(registry: &mut FunctionRegistry) {
             },
         }))
     });
+
+    registry.register_aliases("json_object", &["object_construct"]);
+    registry.register_aliases("json_object_keep_null", &["object_construct_keep_null"]);
+
+    registry.register_function_factory("json_array", |_, args_type| {
+        Some(json_modify_function(
+            "json_array",
+            args_type,
+            |_| None,
+            json_array_fn,
+        ))
+    });
+
+    registry.register_function_factory("object_insert", |_, args_type| {
+        if args_type.len() != 3 && args_type.len() != 4 {
+            return None;
+        }
+        Some(json_modify_function(
+            "object_insert",
+            args_type,
+            |i| match i {
+                0 => Some(DataType::Variant),
+                1 => Some(DataType::String),
+                2 => None,
+                _ => Some(DataType::Boolean),
+            },
+            object_insert_fn,
+        ))
+    });
+
+    registry.register_function_factory("object_delete", |_, args_type| {
+        if args_type.len() < 2 {
+            return None;
+        }
+        Some(json_modify_function(
+            "object_delete",
+            args_type,
+            |i| match i {
+                0 => Some(DataType::Variant),
+                _ => Some(DataType::String),
+            },
+            |args, _, buf| object_filter_keys(args, false, buf),
+        ))
+    });
+
+    registry.register_function_factory("object_pick", |_, args_type| {
+        if args_type.len() < 2 {
+            return None;
+        }
+        Some(json_modify_function(
+            "object_pick",
+            args_type,
+            |i| match i {
+                0 => Some(DataType::Variant),
+                _ => Some(DataType::String),
+            },
+            |args, _, buf| object_filter_keys(args, true, buf),
+        ))
+    });
+
+    registry.register_function_factory("array_insert", |_, args_type| {
+        if args_type.len() != 3 {
+            return None;
+        }
+        Some(json_modify_function(
+            "array_insert",
+            args_type,
+            |i| match i {
+                0 => Some(DataType::Variant),
+                1 => Some(DataType::Number(NumberDataType::Int64)),
+                _ => None,
+            },
+            array_insert_fn,
+        ))
+    });
+
+    // Only takes over `array_append` for variant arrays, the typed array version is registered
+    // in `array.rs`.
+    registry.register_function_factory("array_append", |_, args_type| {
+        if args_type.len() != 2 || args_type[0].remove_nullable() != DataType::Variant {
+            return None;
+        }
+        Some(json_modify_function(
+            "array_append",
+            args_type,
+            |i| match i {
+                0 => Some(DataType::Variant),
+                _ => None,
+            },
+            array_append_fn,
+        ))
+    });
+
+    registry.register_function_factory("json_set", |_, args_type| {
+        if args_type.len() != 3 {
+            return None;
+        }
+        Some(json_modify_function(
+            "json_set",
+            args_type,
+            |i| match i {
+                0 => Some(DataType::Variant),
+                1 => Some(DataType::String),
+                _ => None,
+            },
+            json_set_fn,
+        ))
+    });
+
+    registry.register_passthrough_nullable_2_arg::<VariantType, StringType, VariantType, _, _>(
+        "json_remove",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<VariantType, StringType, VariantType>(
+            |val, path, output, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(output.len()) {
+                        output.commit_row();
+                        return;
+                    }
+                }
+                let result = match parse_json_modify_path(path) {
+                    Some(path) if path.is_empty() => {
+                        Err("Unable to remove the root of a JSON value".to_string())
+                    }
+                    Some(path) => remove_path(val, &path, &mut output.data),
+                    None => Err(format!(
+                        "Invalid JSON Path '{}'",
+                        &String::from_utf8_lossy(path)
+                    )),
+                };
+                if let Err(err) = result {
+                    ctx.set_error(output.len(), err);
+                }
+                output.commit_row();
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<VariantType, VariantType, _, _>(
+        "json_strip_nulls",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<VariantType, VariantType>(|val, output, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(output.len()) {
+                    output.commit_row();
+                    return;
+                }
+            }
+            if let Err(err) = strip_nulls(val, &mut output.data) {
+                ctx.set_error(output.len(), err);
+            }
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<VariantType, StringType, _, _>(
+        "json_typeof",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<VariantType, StringType>(|val, output, _| {
+            output.put_str(json_type_name(val));
+            output.commit_row();
+        }),
+    );
+
+    registry.register_1_arg_core::<NullableType<VariantType>, NullableType<UInt32Type>, _, _>(
+        "json_array_length",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<NullableType<VariantType>, NullableType<UInt32Type>>(|val, _| {
+            val.and_then(|v| array_length(v).map(|v| v as u32))
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<VariantType, VariantType, BooleanType, _, _>(
+        "json_contains",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_2_arg::<VariantType, VariantType, BooleanType>(|left, right, _| {
+            if !is_array(right) && !is_object(right) && is_array(left) {
+                // A top-level array contains a scalar equal to any of its scalar elements.
+                return array_values(left).into_iter().flatten().any(|item| {
+                    !is_array(&item) && !is_object(&item) && json_contains(&item, right)
+                });
+            }
+            json_contains(left, right)
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<VariantType, StringType, BooleanType, _, _>(
+        "json_exists_key",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_2_arg::<VariantType, StringType, BooleanType>(|val, key, _| {
+            let Ok(key) = std::str::from_utf8(key) else {
+                return false;
+            };
+            if is_object(val) {
+                get_by_name(val, key, false).is_some()
+            } else if is_array(val) {
+                array_values(val)
+                    .into_iter()
+                    .flatten()
+                    .any(|item| as_str(&item).map_or(false, |s| s == key))
+            } else {
+                as_str(val).map_or(false, |s| s == key)
+            }
+        }),
+    );
 }
 
 fn json_object_fn(
@@ -1001,3 +1215,444 @@ fn json_object_fn(
         None => Value::Scalar(Scalar::Variant(builder.build_scalar())),
     }
 }
+
+type JsonModifyFn = fn(&[ScalarRef], TzLUT, &mut Vec<u8>) -> Result<(), String>;
+
+/// Builds a function returning a variant. Arguments with an expected type are casted to it and
+/// make the function return NULL if any of them is NULL, the others accept values of any type,
+/// which are passed to `eval` as is.
+fn json_modify_function(
+    name: &str,
+    args_type: &[DataType],
+    expected_type: impl Fn(usize) -> Option<DataType>,
+    eval: JsonModifyFn,
+) -> Arc<Function> {
+    let mut num_generics = 0;
+    let mut nullable = false;
+    let mut typed = Vec::with_capacity(args_type.len());
+    let signature_args_type = args_type
+        .iter()
+        .enumerate()
+        .map(|(i, arg_type)| match expected_type(i) {
+            Some(ty) => {
+                typed.push(true);
+                if arg_type.is_nullable_or_null() {
+                    nullable = true;
+                    ty.wrap_nullable()
+                } else {
+                    ty
+                }
+            }
+            None => {
+                typed.push(false);
+                num_generics += 1;
+                DataType::Generic(num_generics - 1)
+            }
+        })
+        .collect();
+    let return_type = if nullable {
+        DataType::Nullable(Box::new(DataType::Variant))
+    } else {
+        DataType::Variant
+    };
+
+    Arc::new(Function {
+        signature: FunctionSignature {
+            name: name.to_string(),
+            args_type: signature_args_type,
+            return_type,
+        },
+        eval: FunctionEval::Scalar {
+            calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+            eval: Box::new(move |args, ctx| json_modify_fn(args, ctx, &typed, nullable, eval)),
+        },
+    })
+}
+
+fn json_modify_fn(
+    args: &[ValueRef<AnyType>],
+    ctx: &mut EvalContext,
+    typed: &[bool],
+    nullable: bool,
+    eval: JsonModifyFn,
+) -> Value<AnyType> {
+    let len = args.iter().find_map(|arg| match arg {
+        ValueRef::Column(col) => Some(col.len()),
+        _ => None,
+    });
+
+    let num_rows = len.unwrap_or(1);
+    let mut builder = StringColumnBuilder::with_capacity(num_rows, num_rows * 50);
+    let mut validity = MutableBitmap::with_capacity(num_rows);
+    let mut row = Vec::with_capacity(args.len());
+    for idx in 0..num_rows {
+        row.clear();
+        row.extend(args.iter().map(|arg| arg.index(idx).unwrap()));
+        if row
+            .iter()
+            .zip(typed)
+            .any(|(val, typed)| *typed && *val == ScalarRef::Null)
+        {
+            validity.push(false);
+        } else {
+            if let Err(err) = eval(&row, ctx.func_ctx.tz, &mut builder.data) {
+                ctx.set_error(builder.len(), err);
+            }
+            validity.push(true);
+        }
+        builder.commit_row();
+    }
+
+    match len {
+        Some(_) => {
+            let column = Column::Variant(builder.build());
+            if nullable {
+                Value::Column(Column::Nullable(Box::new(NullableColumn {
+                    column,
+                    validity: validity.into(),
+                })))
+            } else {
+                Value::Column(column)
+            }
+        }
+        None if validity.get(0) => Value::Scalar(Scalar::Variant(builder.build_scalar())),
+        None => Value::Scalar(Scalar::Null),
+    }
+}
+
+fn json_array_fn(args: &[ScalarRef], tz: TzLUT, buf: &mut Vec<u8>) -> Result<(), String> {
+    let items = cast_scalars_to_variants(args.iter().cloned(), tz);
+    build_array(items.iter(), buf).map_err(|err| err.to_string())
+}
+
+fn object_insert_fn(args: &[ScalarRef], tz: TzLUT, buf: &mut Vec<u8>) -> Result<(), String> {
+    let (ScalarRef::Variant(val), ScalarRef::String(key)) = (&args[0], &args[1]) else {
+        unreachable!()
+    };
+    if !is_object(val) {
+        return Err("object_insert expects an object as the first argument".to_string());
+    }
+    let key = String::from_utf8_lossy(key);
+    let update = matches!(args.get(3), Some(ScalarRef::Boolean(true)));
+
+    let mut entries = object_entries(val);
+    let pos = entries.iter().position(|(k, _)| *k == key);
+    if pos.is_some() && !update {
+        return Err(format!(
+            "Key '{key}' already exists in the object, set update_flag to true to update it"
+        ));
+    }
+    match (pos, &args[2]) {
+        // Inserting a SQL NULL value omits the key.
+        (Some(pos), ScalarRef::Null) => {
+            entries.remove(pos);
+        }
+        (None, ScalarRef::Null) => {}
+        (Some(pos), value) => {
+            entries[pos].1.clear();
+            cast_scalar_to_variant(value.clone(), tz, &mut entries[pos].1);
+        }
+        (None, value) => {
+            let mut item = vec![];
+            cast_scalar_to_variant(value.clone(), tz, &mut item);
+            entries.push((key.to_string(), item));
+        }
+    }
+    build_object(entries.iter().map(|(k, v)| (k, &v[..])), buf).map_err(|err| err.to_string())
+}
+
+fn object_filter_keys(args: &[ScalarRef], pick: bool, buf: &mut Vec<u8>) -> Result<(), String> {
+    let ScalarRef::Variant(val) = args[0] else {
+        unreachable!()
+    };
+    if !is_object(val) {
+        let name = if pick { "object_pick" } else { "object_delete" };
+        return Err(format!("{name} expects an object as the first argument"));
+    }
+    let keys = args[1..]
+        .iter()
+        .filter_map(|key| match key {
+            ScalarRef::String(key) => Some(*key),
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+
+    let entries = object_entries(val);
+    build_object(
+        entries
+            .iter()
+            .filter(|(k, _)| keys.contains(k.as_bytes()) == pick)
+            .map(|(k, v)| (k, &v[..])),
+        buf,
+    )
+    .map_err(|err| err.to_string())
+}
+
+fn array_insert_fn(args: &[ScalarRef], tz: TzLUT, buf: &mut Vec<u8>) -> Result<(), String> {
+    let (ScalarRef::Variant(val), ScalarRef::Number(NumberScalar::Int64(pos))) =
+        (&args[0], &args[1])
+    else {
+        unreachable!()
+    };
+    if !is_array(val) {
+        return Err("array_insert expects an array as the first argument".to_string());
+    }
+    let mut items = array_values(val).unwrap_or_default();
+    // Negative positions count from the end of the array.
+    let pos = if *pos < 0 {
+        (items.len() as i64 + *pos).max(0) as usize
+    } else {
+        *pos as usize
+    };
+    if pos > items.len() {
+        let mut null = vec![];
+        cast_scalar_to_variant(ScalarRef::Null, tz, &mut null);
+        items.resize(pos, null);
+    }
+    let mut item = vec![];
+    cast_scalar_to_variant(args[2].clone(), tz, &mut item);
+    items.insert(pos, item);
+    build_array(items.iter().map(|v| &v[..]), buf).map_err(|err| err.to_string())
+}
+
+fn array_append_fn(args: &[ScalarRef], tz: TzLUT, buf: &mut Vec<u8>) -> Result<(), String> {
+    let ScalarRef::Variant(val) = args[0] else {
+        unreachable!()
+    };
+    if !is_array(val) {
+        return Err("array_append expects an array as the first argument".to_string());
+    }
+    let mut items = array_values(val).unwrap_or_default();
+    let mut item = vec![];
+    cast_scalar_to_variant(args[1].clone(), tz, &mut item);
+    items.push(item);
+    build_array(items.iter().map(|v| &v[..]), buf).map_err(|err| err.to_string())
+}
+
+fn json_set_fn(args: &[ScalarRef], tz: TzLUT, buf: &mut Vec<u8>) -> Result<(), String> {
+    let (ScalarRef::Variant(val), ScalarRef::String(path)) = (&args[0], &args[1]) else {
+        unreachable!()
+    };
+    let Some(path) = parse_json_modify_path(path) else {
+        return Err(format!(
+            "Invalid JSON Path '{}'",
+            &String::from_utf8_lossy(path)
+        ));
+    };
+    let mut new_value = vec![];
+    cast_scalar_to_variant(args[2].clone(), tz, &mut new_value);
+    set_path(val, &path, &new_value, buf)
+}
+
+enum JsonPathElement {
+    Key(String),
+    Index(usize),
+}
+
+/// Parses the paths accepted by `json_set` and `json_remove`, such as `$.a.b`, `a[0]` and `$["a"]`.
+fn parse_json_modify_path(path: &[u8]) -> Option<Vec<JsonPathElement>> {
+    let path = std::str::from_utf8(path).ok()?.trim();
+    let mut rest = path.strip_prefix('$').unwrap_or(path);
+    let mut elements = Vec::new();
+    while !rest.is_empty() {
+        if let Some(stripped) = rest.strip_prefix('[') {
+            match stripped.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let inner = &stripped[1..];
+                    let end = inner.find(quote)?;
+                    elements.push(JsonPathElement::Key(inner[..end].to_string()));
+                    rest = inner[end + 1..].strip_prefix(']')?;
+                }
+                _ => {
+                    let end = stripped.find(']')?;
+                    let index = stripped[..end].trim().parse().ok()?;
+                    elements.push(JsonPathElement::Index(index));
+                    rest = &stripped[end + 1..];
+                }
+            }
+        } else {
+            let stripped = match rest.strip_prefix('.') {
+                Some(stripped) => stripped,
+                // The leading key can omit the dot if the path does not start with `$`.
+                None if elements.is_empty() && !path.starts_with('$') => rest,
+                None => return None,
+            };
+            let end = stripped.find(['.', '[']).unwrap_or(stripped.len());
+            if end == 0 {
+                return None;
+            }
+            elements.push(JsonPathElement::Key(stripped[..end].to_string()));
+            rest = &stripped[end..];
+        }
+    }
+    Some(elements)
+}
+
+/// Sets the value at `path` to `new_value`, the last path element is inserted if missing.
+/// The value is kept unchanged if any other path element does not exist.
+fn set_path(
+    value: &[u8],
+    path: &[JsonPathElement],
+    new_value: &[u8],
+    buf: &mut Vec<u8>,
+) -> Result<(), String> {
+    let Some((element, rest)) = path.split_first() else {
+        buf.extend_from_slice(new_value);
+        return Ok(());
+    };
+    match element {
+        JsonPathElement::Key(key) if is_object(value) => {
+            let mut entries = object_entries(value);
+            match entries.iter_mut().find(|(k, _)| k == key) {
+                Some((_, child)) => {
+                    let mut new_child = vec![];
+                    set_path(child, rest, new_value, &mut new_child)?;
+                    *child = new_child;
+                }
+                None if rest.is_empty() => entries.push((key.clone(), new_value.to_vec())),
+                None => {
+                    buf.extend_from_slice(value);
+                    return Ok(());
+                }
+            }
+            build_object(entries.iter().map(|(k, v)| (k, &v[..])), buf)
+                .map_err(|err| err.to_string())
+        }
+        JsonPathElement::Index(index) if is_array(value) => {
+            let mut items = array_values(value).unwrap_or_default();
+            match items.get_mut(*index) {
+                Some(child) => {
+                    let mut new_child = vec![];
+                    set_path(child, rest, new_value, &mut new_child)?;
+                    *child = new_child;
+                }
+                None if rest.is_empty() => items.push(new_value.to_vec()),
+                None => {
+                    buf.extend_from_slice(value);
+                    return Ok(());
+                }
+            }
+            build_array(items.iter().map(|v| &v[..]), buf).map_err(|err| err.to_string())
+        }
+        _ => {
+            buf.extend_from_slice(value);
+            Ok(())
+        }
+    }
+}
+
+/// Removes the value at `path`, the value is kept unchanged if the path does not exist.
+fn remove_path(value: &[u8], path: &[JsonPathElement], buf: &mut Vec<u8>) -> Result<(), String> {
+    match path.split_first() {
+        Some((JsonPathElement::Key(key), rest)) if is_object(value) => {
+            let mut entries = object_entries(value);
+            if let Some(pos) = entries.iter().position(|(k, _)| k == key) {
+                if rest.is_empty() {
+                    entries.remove(pos);
+                } else {
+                    let mut new_child = vec![];
+                    remove_path(&entries[pos].1, rest, &mut new_child)?;
+                    entries[pos].1 = new_child;
+                }
+                return build_object(entries.iter().map(|(k, v)| (k, &v[..])), buf)
+                    .map_err(|err| err.to_string());
+            }
+        }
+        Some((JsonPathElement::Index(index), rest)) if is_array(value) => {
+            let mut items = array_values(value).unwrap_or_default();
+            if *index < items.len() {
+                if rest.is_empty() {
+                    items.remove(*index);
+                } else {
+                    let mut new_child = vec![];
+                    remove_path(&items[*index], rest, &mut new_child)?;
+                    items[*index] = new_child;
+                }
+                return build_array(items.iter().map(|v| &v[..]), buf)
+                    .map_err(|err| err.to_string());
+            }
+        }
+        _ => {}
+    }
+    buf.extend_from_slice(value);
+    Ok(())
+}
+
+/// Removes the object fields with null values recursively, nulls in arrays are kept.
+fn strip_nulls(value: &[u8], buf: &mut Vec<u8>) -> Result<(), String> {
+    if is_object(value) {
+        let mut entries = Vec::new();
+        for (key, child) in object_entries(value) {
+            if json_type_name(&child) == "null" {
+                continue;
+            }
+            let mut new_child = vec![];
+            strip_nulls(&child, &mut new_child)?;
+            entries.push((key, new_child));
+        }
+        build_object(entries.iter().map(|(k, v)| (k, &v[..])), buf).map_err(|err| err.to_string())
+    } else if is_array(value) {
+        let mut items = Vec::new();
+        for child in array_values(value).unwrap_or_default() {
+            let mut new_child = vec![];
+            strip_nulls(&child, &mut new_child)?;
+            items.push(new_child);
+        }
+        build_array(items.iter().map(|v| &v[..]), buf).map_err(|err| err.to_string())
+    } else {
+        buf.extend_from_slice(value);
+        Ok(())
+    }
+}
+
+fn json_type_name(value: &[u8]) -> &'static str {
+    if is_object(value) {
+        "object"
+    } else if is_array(value) {
+        "array"
+    } else {
+        match from_slice(value) {
+            Ok(JsonbValue::Bool(_)) => "boolean",
+            Ok(JsonbValue::String(_)) => "string",
+            Ok(JsonbValue::Number(_)) => "number",
+            _ => "null",
+        }
+    }
+}
+
+/// Checks whether `left` contains `right`: objects contain the objects with a subset of their
+/// fields, arrays contain the arrays whose elements are all contained by one of their elements,
+/// and scalars only contain the equal scalars.
+fn json_contains(left: &[u8], right: &[u8]) -> bool {
+    if is_object(right) {
+        is_object(left)
+            && object_entries(right).iter().all(|(key, r)| {
+                get_by_name(left, key, false).map_or(false, |l| json_contains(&l, r))
+            })
+    } else if is_array(right) {
+        if !is_array(left) {
+            return false;
+        }
+        let items = array_values(left).unwrap_or_default();
+        array_values(right)
+            .into_iter()
+            .flatten()
+            .all(|r| items.iter().any(|l| json_contains(l, &r)))
+    } else {
+        !is_object(left)
+            && !is_array(left)
+            && compare(left, right).map_or(false, |ord| ord == Ordering::Equal)
+    }
+}
+
+fn object_entries(value: &[u8]) -> Vec<(String, Vec<u8>)> {
+    let keys = object_keys(value).and_then(|keys| array_values(&keys));
+    keys.into_iter()
+        .flatten()
+        .filter_map(|key| {
+            let key = as_str(&key)?.to_string();
+            let val = get_by_name(value, &key, false)?;
+            Some((key, val))
+        })
+        .collect()
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod variant;
+
 use std::sync::Arc;
 
 use common_expression::types::nullable::NullableColumn;
@@ -31,6 +33,8 @@ use jsonb::array_values;
 use jsonb::jsonpath::parse_json_path;
 use jsonb::jsonpath::Mode as SelectorMode;
 use jsonb::jsonpath::Selector;
+pub use variant::srf_arg_names;
+pub use variant::srf_column_names;
 
 pub fn register(registry: &mut FunctionRegistry) {
     registry.properties.insert(
@@ -165,6 +169,8 @@ pub fn register(registry: &mut FunctionRegistry) {
             },
         }))
     });
+
+    variant::register(registry);
 }
 
 fn build_unnest(
@@ -0,0 +1,433 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_expression::types::nullable::NullableColumnBuilder;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::AnyType;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::VariantType;
+use common_expression::Column;
+use common_expression::EvalContext;
+use common_expression::Function;
+use common_expression::FunctionEval;
+use common_expression::FunctionKind;
+use common_expression::FunctionProperty;
+use common_expression::FunctionRegistry;
+use common_expression::FunctionSignature;
+use common_expression::ScalarRef;
+use common_expression::Value;
+use jsonb::array_values;
+use jsonb::as_str;
+use jsonb::get_by_name;
+use jsonb::get_by_path;
+use jsonb::is_array;
+use jsonb::is_object;
+use jsonb::jsonpath::parse_json_path;
+use jsonb::object_keys;
+
+/// The arguments of `flatten`, which can also be passed by name in the `FROM` clause,
+/// e.g. `FROM flatten(input => v, recursive => true)`.
+pub const FLATTEN_ARGS: [&str; 5] = ["input", "path", "outer", "recursive", "mode"];
+
+/// The output columns of `flatten`.
+pub const FLATTEN_COLUMNS: [&str; 6] = ["seq", "key", "path", "index", "value", "this"];
+
+pub const JSON_EACH_COLUMNS: [&str; 2] = ["key", "value"];
+
+pub const JSON_ARRAY_ELEMENTS_COLUMNS: [&str; 1] = ["value"];
+
+pub fn register(registry: &mut FunctionRegistry) {
+    for name in ["flatten", "json_each", "json_array_elements"] {
+        registry.properties.insert(
+            name.to_string(),
+            FunctionProperty::default().kind(FunctionKind::SRF),
+        );
+    }
+
+    // flatten(input[, path[, outer[, recursive[, mode]]]])
+    registry.register_function_factory("flatten", |_, args_type: &[DataType]| {
+        if args_type.is_empty() || args_type.len() > FLATTEN_ARGS.len() {
+            return None;
+        }
+        let expected = [
+            DataType::Variant,
+            DataType::String,
+            DataType::Boolean,
+            DataType::Boolean,
+            DataType::String,
+        ];
+        for (arg_type, expected) in args_type.iter().zip(expected.iter()) {
+            if arg_type.remove_nullable() != *expected && *arg_type != DataType::Null {
+                return None;
+            }
+        }
+
+        Some(build_flatten_function(
+            "flatten",
+            args_type,
+            &[0, 1, 2, 3, 4, 5],
+            |args, ctx, row| {
+                let path = match unsafe { args.get(1).map(|arg| arg.index_unchecked(row)) } {
+                    Some(ScalarRef::String(path)) => path,
+                    _ => &[],
+                };
+                let outer = matches!(
+                    unsafe { args.get(2).map(|arg| arg.index_unchecked(row)) },
+                    Some(ScalarRef::Boolean(true))
+                );
+                let recursive = matches!(
+                    unsafe { args.get(3).map(|arg| arg.index_unchecked(row)) },
+                    Some(ScalarRef::Boolean(true))
+                );
+                let mode = match unsafe { args.get(4).map(|arg| arg.index_unchecked(row)) } {
+                    Some(ScalarRef::String(mode)) => {
+                        match String::from_utf8_lossy(mode).to_ascii_lowercase().as_str() {
+                            "object" => FlattenMode::Object,
+                            "array" => FlattenMode::Array,
+                            "both" => FlattenMode::Both,
+                            _ => {
+                                ctx.set_error(
+                                    row,
+                                    format!(
+                                        "Invalid flatten mode '{}', must be one of OBJECT, ARRAY and BOTH",
+                                        String::from_utf8_lossy(mode)
+                                    ),
+                                );
+                                FlattenMode::Both
+                            }
+                        }
+                    }
+                    _ => FlattenMode::Both,
+                };
+                Flatten {
+                    path,
+                    outer,
+                    recursive,
+                    mode,
+                }
+            },
+        ))
+    });
+
+    // json_each(object) -> (key, value)
+    registry.register_function_factory("json_each", |_, args_type: &[DataType]| {
+        if args_type.len() != 1
+            || (args_type[0].remove_nullable() != DataType::Variant
+                && args_type[0] != DataType::Null)
+        {
+            return None;
+        }
+        Some(build_flatten_function(
+            "json_each",
+            args_type,
+            &[1, 4],
+            |_, _, _| Flatten {
+                path: &[],
+                outer: false,
+                recursive: false,
+                mode: FlattenMode::Object,
+            },
+        ))
+    });
+
+    // json_array_elements(array) -> (value)
+    registry.register_function_factory("json_array_elements", |_, args_type: &[DataType]| {
+        if args_type.len() != 1
+            || (args_type[0].remove_nullable() != DataType::Variant
+                && args_type[0] != DataType::Null)
+        {
+            return None;
+        }
+        Some(build_flatten_function(
+            "json_array_elements",
+            args_type,
+            &[4],
+            |_, _, _| Flatten {
+                path: &[],
+                outer: false,
+                recursive: false,
+                mode: FlattenMode::Array,
+            },
+        ))
+    });
+}
+
+/// Returns the names of the arguments that can be passed by name to the set-returning function.
+pub fn srf_arg_names(name: &str) -> Option<&'static [&'static str]> {
+    match name {
+        "flatten" => Some(&FLATTEN_ARGS),
+        _ => None,
+    }
+}
+
+/// Returns the names of the output columns of the set-returning function.
+pub fn srf_column_names(name: &str) -> Option<&'static [&'static str]> {
+    match name {
+        "flatten" => Some(&FLATTEN_COLUMNS),
+        "json_each" => Some(&JSON_EACH_COLUMNS),
+        "json_array_elements" => Some(&JSON_ARRAY_ELEMENTS_COLUMNS),
+        _ => None,
+    }
+}
+
+fn flatten_column_types() -> [DataType; 6] {
+    [
+        DataType::Number(NumberDataType::UInt64),
+        DataType::String,
+        DataType::String,
+        DataType::Number(NumberDataType::UInt64),
+        DataType::Variant,
+        DataType::Variant,
+    ]
+}
+
+/// Build a set-returning function that explodes the first argument, `columns` are the
+/// indexes of the output columns of `flatten` that the function returns.
+fn build_flatten_function(
+    name: &str,
+    args_type: &[DataType],
+    columns: &'static [usize],
+    options: for<'a> fn(&'a [Value<AnyType>], &mut EvalContext, usize) -> Flatten<'a>,
+) -> Arc<Function> {
+    let column_types = flatten_column_types();
+    let return_type = DataType::Tuple(
+        columns
+            .iter()
+            .map(|i| DataType::Nullable(Box::new(column_types[*i].clone())))
+            .collect(),
+    );
+
+    Arc::new(Function {
+        signature: FunctionSignature {
+            name: name.to_string(),
+            args_type: args_type.to_vec(),
+            return_type,
+        },
+        eval: FunctionEval::SRF {
+            eval: Box::new(move |args, ctx, max_nums_per_row| {
+                let args = args
+                    .iter()
+                    .map(|arg| arg.clone().to_owned())
+                    .collect::<Vec<_>>();
+                (0..ctx.num_rows)
+                    .map(|row| {
+                        let flatten = options(&args, ctx, row);
+                        let mut builder = FlattenBuilder::default();
+                        let input = unsafe { args[0].index_unchecked(row) };
+                        if let ScalarRef::Variant(input) = input {
+                            // `seq` is the position of the row in the current block.
+                            flatten.flatten(ctx, row, row as u64 + 1, input, &mut builder);
+                        }
+                        let len = builder.len;
+                        max_nums_per_row[row] = std::cmp::max(max_nums_per_row[row], len);
+                        let mut fields = builder.build();
+                        let fields = columns
+                            .iter()
+                            .map(|i| std::mem::replace(&mut fields[*i], Column::Null { len: 0 }))
+                            .collect();
+                        (Value::Column(Column::Tuple(fields)), len)
+                    })
+                    .collect()
+            }),
+        },
+    })
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FlattenMode {
+    Object,
+    Array,
+    Both,
+}
+
+struct Flatten<'a> {
+    path: &'a [u8],
+    outer: bool,
+    recursive: bool,
+    mode: FlattenMode,
+}
+
+impl Flatten<'_> {
+    fn flatten(
+        &self,
+        ctx: &mut EvalContext,
+        row: usize,
+        seq: u64,
+        input: &[u8],
+        builder: &mut FlattenBuilder,
+    ) {
+        let target = if self.path.is_empty() {
+            Some(input.to_vec())
+        } else {
+            match parse_json_path(self.path) {
+                Ok(json_path) => {
+                    let mut values = StringColumnBuilder::with_capacity(0, 0);
+                    get_by_path(input, json_path, &mut values.data, &mut values.offsets);
+                    values.build().index(0).map(|value| value.to_vec())
+                }
+                Err(_) => {
+                    ctx.set_error(
+                        row,
+                        format!(
+                            "Invalid JSON Path '{}'",
+                            &String::from_utf8_lossy(self.path),
+                        ),
+                    );
+                    return;
+                }
+            }
+        };
+
+        let path = String::from_utf8_lossy(self.path);
+        if let Some(target) = &target {
+            self.flatten_value(seq, target, &path, builder);
+        }
+        if builder.len == 0 && self.outer {
+            // Generate exactly one row for the zero-row expansion.
+            let path = (!path.is_empty()).then_some(path.as_ref());
+            builder.push(seq, None, path, None, None, target.as_deref());
+        }
+    }
+
+    fn flatten_value(&self, seq: u64, value: &[u8], path: &str, builder: &mut FlattenBuilder) {
+        if self.mode != FlattenMode::Array && is_object(value) {
+            let keys = object_keys(value).and_then(|keys| array_values(&keys));
+            for key in keys.into_iter().flatten() {
+                let Some(key) = as_str(&key) else {
+                    continue;
+                };
+                let Some(child) = get_by_name(value, &key, false) else {
+                    continue;
+                };
+                let child_path = object_path(path, &key);
+                builder.push(
+                    seq,
+                    Some(&key),
+                    Some(&child_path),
+                    None,
+                    Some(&child),
+                    Some(value),
+                );
+                if self.recursive {
+                    self.flatten_value(seq, &child, &child_path, builder);
+                }
+            }
+        } else if self.mode != FlattenMode::Object && is_array(value) {
+            for (index, child) in array_values(value).into_iter().flatten().enumerate() {
+                let child_path = format!("{path}[{index}]");
+                builder.push(
+                    seq,
+                    None,
+                    Some(&child_path),
+                    Some(index as u64),
+                    Some(&child),
+                    Some(value),
+                );
+                if self.recursive {
+                    self.flatten_value(seq, &child, &child_path, builder);
+                }
+            }
+        }
+    }
+}
+
+/// The path of the value of `key` in the object at `path`, keys that are not simple
+/// identifiers are quoted, e.g. `a.b` and `a['b c']`.
+fn object_path(path: &str, key: &str) -> String {
+    let is_ident = key
+        .chars()
+        .next()
+        .map_or(false, |c| c.is_ascii_alphabetic() || c == '_')
+        && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    match (path.is_empty(), is_ident) {
+        (true, true) => key.to_string(),
+        (false, true) => format!("{path}.{key}"),
+        (_, false) => format!("{path}['{}']", key.replace('\'', "\\'")),
+    }
+}
+
+struct FlattenBuilder {
+    len: usize,
+    seq: NullableColumnBuilder<UInt64Type>,
+    key: NullableColumnBuilder<StringType>,
+    path: NullableColumnBuilder<StringType>,
+    index: NullableColumnBuilder<UInt64Type>,
+    value: NullableColumnBuilder<VariantType>,
+    this: NullableColumnBuilder<VariantType>,
+}
+
+impl Default for FlattenBuilder {
+    fn default() -> Self {
+        FlattenBuilder {
+            len: 0,
+            seq: NullableColumnBuilder::with_capacity(0, &[]),
+            key: NullableColumnBuilder::with_capacity(0, &[]),
+            path: NullableColumnBuilder::with_capacity(0, &[]),
+            index: NullableColumnBuilder::with_capacity(0, &[]),
+            value: NullableColumnBuilder::with_capacity(0, &[]),
+            this: NullableColumnBuilder::with_capacity(0, &[]),
+        }
+    }
+}
+
+impl FlattenBuilder {
+    fn push(
+        &mut self,
+        seq: u64,
+        key: Option<&str>,
+        path: Option<&str>,
+        index: Option<u64>,
+        value: Option<&[u8]>,
+        this: Option<&[u8]>,
+    ) {
+        self.len += 1;
+        self.seq.push(seq);
+        match key {
+            Some(key) => self.key.push(key.as_bytes()),
+            None => self.key.push_null(),
+        }
+        match path {
+            Some(path) => self.path.push(path.as_bytes()),
+            None => self.path.push_null(),
+        }
+        match index {
+            Some(index) => self.index.push(index),
+            None => self.index.push_null(),
+        }
+        match value {
+            Some(value) => self.value.push(value),
+            None => self.value.push_null(),
+        }
+        match this {
+            Some(this) => self.this.push(this),
+            None => self.this.push_null(),
+        }
+    }
+
+    fn build(self) -> Vec<Column> {
+        vec![
+            Column::Nullable(Box::new(self.seq.build().upcast())),
+            Column::Nullable(Box::new(self.key.build().upcast())),
+            Column::Nullable(Box::new(self.path.build().upcast())),
+            Column::Nullable(Box::new(self.index.build().upcast())),
+            Column::Nullable(Box::new(self.value.build().upcast())),
+            Column::Nullable(Box::new(self.this.build().upcast())),
+        ]
+    }
+}
@@ -45,6 +45,7 @@ mod misc;
 mod other;
 pub(crate) mod parser;
 mod regexp;
+mod search;
 mod string;
 mod tuple;
 mod variant;
@@ -0,0 +1,43 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use goldenfile::Mint;
+
+use super::run_ast;
+
+#[test]
+fn test_search() {
+    let mut mint = Mint::new("tests/it/scalars/testdata");
+    let file = &mut mint.new_goldenfile("search.txt").unwrap();
+
+    test_match(file);
+}
+
+fn test_match(file: &mut impl Write) {
+    run_ast(file, "match('The quick brown fox', 'FOX', 'english')", &[]);
+    run_ast(file, "match('The quick brown fox', 'the', 'english')", &[]);
+    run_ast(
+        file,
+        "match('The quick brown fox', 'lazy dog', 'english')",
+        &[],
+    );
+    run_ast(
+        file,
+        "match('The quick brown fox', 'lazy fox', 'english')",
+        &[],
+    );
+    run_ast(file, "match('error-code: 1045', 'CODE', 'chinese')", &[]);
+}
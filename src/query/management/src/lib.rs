@@ -22,6 +22,7 @@ mod role;
 mod serde;
 mod setting;
 mod stage;
+mod tag;
 mod udf;
 mod user;
 
@@ -41,6 +42,8 @@ pub use setting::SettingApi;
 pub use setting::SettingMgr;
 pub use stage::StageApi;
 pub use stage::StageMgr;
+pub use tag::TagApi;
+pub use tag::TagMgr;
 pub use udf::UdfApi;
 pub use udf::UdfMgr;
 pub use user::UserApi;
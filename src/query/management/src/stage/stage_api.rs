@@ -25,6 +25,8 @@ pub trait StageApi: Sync + Send {
 
     async fn get_stage(&self, name: &str, seq: MatchSeq) -> Result<SeqV<StageInfo>>;
 
+    async fn update_stage(&self, info: StageInfo, seq: MatchSeq) -> Result<u64>;
+
     // Get all the stages for a tenant.
     async fn get_stages(&self) -> Result<Vec<StageInfo>>;
 
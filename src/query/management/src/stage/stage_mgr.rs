@@ -109,6 +109,32 @@ impl StageApi for StageMgr {
         }
     }
 
+    #[async_backtrace::framed]
+    async fn update_stage(&self, info: StageInfo, seq: MatchSeq) -> Result<u64> {
+        let val = Operation::Update(serialize_struct(
+            &info,
+            ErrorCode::IllegalUserStageFormat,
+            || "",
+        )?);
+        let key = format!(
+            "{}/{}",
+            self.stage_prefix,
+            escape_for_key(&info.stage_name)?
+        );
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None))
+            .await?;
+
+        match res.result {
+            Some(SeqV { seq: s, .. }) => Ok(s),
+            None => Err(ErrorCode::UnknownStage(format!(
+                "Unknown stage, or seq not match {}",
+                info.stage_name
+            ))),
+        }
+    }
+
     #[async_backtrace::framed]
     async fn get_stages(&self) -> Result<Vec<StageInfo>> {
         let values = self.kv_api.prefix_list_kv(&self.stage_prefix).await?;
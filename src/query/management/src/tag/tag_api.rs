@@ -0,0 +1,60 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_app::principal::Tag;
+use common_meta_app::principal::TagReference;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+#[async_trait::async_trait]
+pub trait TagApi: Sync + Send {
+    async fn add_tag(&self, tag: Tag) -> Result<u64>;
+
+    async fn get_tag(&self, name: &str, seq: MatchSeq) -> Result<SeqV<Tag>>;
+
+    async fn get_tags(&self) -> Result<Vec<Tag>>;
+
+    // Drop the tag and all the values of it set on objects.
+    async fn drop_tag(&self, name: &str, seq: MatchSeq) -> Result<()>;
+
+    // Set the value of a tag on an object, the previous value is overridden.
+    async fn set_tag_reference(&self, reference: TagReference) -> Result<u64>;
+
+    async fn unset_tag_reference(
+        &self,
+        tag_name: &str,
+        object_domain: &str,
+        object_name: &str,
+    ) -> Result<()>;
+
+    async fn get_tag_references(&self) -> Result<Vec<TagReference>>;
+
+    // Drop the values of all tags set on an object and the objects nested in it,
+    // `object_domains` lists the domains of the object and of its nested objects.
+    async fn drop_object_tag_references(
+        &self,
+        object_domains: &[&str],
+        object_name: &str,
+    ) -> Result<()>;
+
+    // Move the values of all tags set on an object and the objects nested in it
+    // to the new name of the object.
+    async fn rename_object_tag_references(
+        &self,
+        object_domains: &[&str],
+        object_name: &str,
+        new_object_name: &str,
+    ) -> Result<()>;
+}
@@ -0,0 +1,357 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::reply::txn_reply_to_api_result;
+use common_meta_api::txn_cond_seq;
+use common_meta_api::txn_op_del;
+use common_meta_api::txn_op_put;
+use common_meta_app::app_error::TxnRetryMaxTimes;
+use common_meta_app::principal::Tag;
+use common_meta_app::principal::TagReference;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::ConditionResult::Eq;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+use common_meta_types::TxnOp;
+use common_meta_types::TxnRequest;
+
+use crate::serde::deserialize_struct;
+use crate::serde::serialize_struct;
+use crate::tag::tag_api::TagApi;
+
+static TAG_API_KEY_PREFIX: &str = "__fd_tags";
+static TAG_REFERENCE_API_KEY_PREFIX: &str = "__fd_tag_references";
+const TXN_MAX_RETRY_TIMES: u32 = 10;
+
+pub struct TagMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    tag_prefix: String,
+    tag_reference_prefix: String,
+}
+
+impl TagMgr {
+    pub fn create(
+        kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+        tenant: &str,
+    ) -> Result<Self, ErrorCode> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty (while create tag)",
+            ));
+        }
+
+        Ok(TagMgr {
+            kv_api,
+            tag_prefix: format!("{}/{}", TAG_API_KEY_PREFIX, tenant),
+            tag_reference_prefix: format!("{}/{}", TAG_REFERENCE_API_KEY_PREFIX, tenant),
+        })
+    }
+
+    fn make_tag_key(&self, name: &str) -> Result<String> {
+        Ok(format!("{}/{}", self.tag_prefix, escape_for_key(name)?))
+    }
+
+    // References of a tag are listed by the prefix `<tag_reference_prefix>/<tag_name>/`.
+    fn make_tag_reference_prefix(&self, tag_name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}/",
+            self.tag_reference_prefix,
+            escape_for_key(tag_name)?
+        ))
+    }
+
+    fn make_tag_reference_key(
+        &self,
+        tag_name: &str,
+        object_domain: &str,
+        object_name: &str,
+    ) -> Result<String> {
+        Ok(format!(
+            "{}{}/{}",
+            self.make_tag_reference_prefix(tag_name)?,
+            escape_for_key(object_domain)?,
+            escape_for_key(object_name)?
+        ))
+    }
+
+    // List the references on the object `object_name` and on the objects nested in it,
+    // e.g. the columns of a table or the tables of a database.
+    async fn get_object_tag_references(
+        &self,
+        object_domains: &[&str],
+        object_name: &str,
+    ) -> Result<Vec<(String, TagReference)>> {
+        let values = self
+            .kv_api
+            .prefix_list_kv(&self.tag_reference_prefix)
+            .await?;
+
+        let nested_prefix = format!("{}.", object_name);
+        let mut references = vec![];
+        for (key, value) in values {
+            let reference: TagReference =
+                deserialize_struct(&value.data, ErrorCode::IllegalTag, || "")?;
+            if object_domains.contains(&reference.object_domain.as_str())
+                && (reference.object_name == object_name
+                    || reference.object_name.starts_with(&nested_prefix))
+            {
+                references.push((key, reference));
+            }
+        }
+        Ok(references)
+    }
+}
+
+#[async_trait::async_trait]
+impl TagApi for TagMgr {
+    #[async_backtrace::framed]
+    async fn add_tag(&self, tag: Tag) -> Result<u64> {
+        let match_seq = MatchSeq::Exact(0);
+        let key = self.make_tag_key(tag.name.as_str())?;
+        let value = Operation::Update(serialize_struct(&tag, ErrorCode::IllegalTag, || "")?);
+
+        let kv_api = self.kv_api.clone();
+        let upsert_kv = kv_api.upsert_kv(UpsertKVReq::new(&key, match_seq, value, None));
+
+        let res = upsert_kv.await?.added_or_else(|v| {
+            ErrorCode::TagAlreadyExists(format!("Tag already exists, seq [{}]", v.seq))
+        })?;
+
+        Ok(res.seq)
+    }
+
+    #[async_backtrace::framed]
+    async fn get_tag(&self, name: &str, seq: MatchSeq) -> Result<SeqV<Tag>> {
+        let key = self.make_tag_key(name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value =
+            res.ok_or_else(|| ErrorCode::UnknownTag(format!("Unknown Tag {}", name)))?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(SeqV::new(
+                seq_value.seq,
+                deserialize_struct(&seq_value.data, ErrorCode::IllegalTag, || "")?,
+            )),
+            Err(_) => Err(ErrorCode::UnknownTag(format!("Unknown Tag {}", name))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn get_tags(&self) -> Result<Vec<Tag>> {
+        let values = self.kv_api.prefix_list_kv(&self.tag_prefix).await?;
+
+        let mut tags = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let tag = deserialize_struct(&value.data, ErrorCode::IllegalTag, || "")?;
+            tags.push(tag);
+        }
+        Ok(tags)
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_tag(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let tag_key = self.make_tag_key(name)?;
+        let reference_prefix = self.make_tag_reference_prefix(name)?;
+
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            let tag_seq = match self.kv_api.get_kv(&tag_key).await? {
+                Some(seq_v) if seq.match_seq(&seq_v).is_ok() => seq_v.seq,
+                _ => return Err(ErrorCode::UnknownTag(format!("Unknown Tag {}", name))),
+            };
+
+            // list all the values of the tag, and delete them
+            let reference_keys = self.kv_api.prefix_list_kv(&reference_prefix).await?;
+            let mut dels: Vec<TxnOp> = reference_keys
+                .iter()
+                .map(|(key, _)| txn_op_del(key))
+                .collect();
+            dels.push(txn_op_del(&tag_key));
+
+            let txn_req = TxnRequest {
+                condition: vec![
+                    // tag is not changed, prevent setting it on objects
+                    txn_cond_seq(&tag_key, Eq, tag_seq),
+                ],
+                if_then: dels,
+                else_then: vec![],
+            };
+            let tx_reply = self.kv_api.transaction(txn_req).await?;
+            let (succ, _) = txn_reply_to_api_result(tx_reply)?;
+
+            if succ {
+                return Ok(());
+            }
+        }
+
+        Err(ErrorCode::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("drop_tag", TXN_MAX_RETRY_TIMES).to_string(),
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn set_tag_reference(&self, reference: TagReference) -> Result<u64> {
+        let tag_key = self.make_tag_key(&reference.tag_name)?;
+        let reference_key = self.make_tag_reference_key(
+            &reference.tag_name,
+            &reference.object_domain,
+            &reference.object_name,
+        )?;
+        let value = serialize_struct(&reference, ErrorCode::IllegalTag, || "")?;
+
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            let tag_seq = match self.kv_api.get_kv(&tag_key).await? {
+                Some(seq_v) => seq_v.seq,
+                None => {
+                    return Err(ErrorCode::UnknownTag(format!(
+                        "Unknown Tag {}",
+                        reference.tag_name
+                    )));
+                }
+            };
+
+            let txn_req = TxnRequest {
+                condition: vec![
+                    // tag is not dropped
+                    txn_cond_seq(&tag_key, Eq, tag_seq),
+                ],
+                if_then: vec![txn_op_put(&reference_key, value.clone())],
+                else_then: vec![],
+            };
+            let tx_reply = self.kv_api.transaction(txn_req).await?;
+            let (succ, _) = txn_reply_to_api_result(tx_reply)?;
+
+            if succ {
+                return Ok(tag_seq);
+            }
+        }
+
+        Err(ErrorCode::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("set_tag_reference", TXN_MAX_RETRY_TIMES).to_string(),
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn unset_tag_reference(
+        &self,
+        tag_name: &str,
+        object_domain: &str,
+        object_name: &str,
+    ) -> Result<()> {
+        let key = self.make_tag_reference_key(tag_name, object_domain, object_name)?;
+        self.kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::GE(0),
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn get_tag_references(&self) -> Result<Vec<TagReference>> {
+        let values = self
+            .kv_api
+            .prefix_list_kv(&self.tag_reference_prefix)
+            .await?;
+
+        let mut references = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let reference = deserialize_struct(&value.data, ErrorCode::IllegalTag, || "")?;
+            references.push(reference);
+        }
+        Ok(references)
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_object_tag_references(
+        &self,
+        object_domains: &[&str],
+        object_name: &str,
+    ) -> Result<()> {
+        let references = self
+            .get_object_tag_references(object_domains, object_name)
+            .await?;
+        if references.is_empty() {
+            return Ok(());
+        }
+
+        let txn_req = TxnRequest {
+            condition: vec![],
+            if_then: references.iter().map(|(key, _)| txn_op_del(key)).collect(),
+            else_then: vec![],
+        };
+        let tx_reply = self.kv_api.transaction(txn_req).await?;
+        txn_reply_to_api_result(tx_reply)?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn rename_object_tag_references(
+        &self,
+        object_domains: &[&str],
+        object_name: &str,
+        new_object_name: &str,
+    ) -> Result<()> {
+        let references = self
+            .get_object_tag_references(object_domains, object_name)
+            .await?;
+        if references.is_empty() {
+            return Ok(());
+        }
+
+        let mut ops = Vec::with_capacity(references.len() * 2);
+        for (key, mut reference) in references {
+            ops.push(txn_op_del(&key));
+
+            reference.object_name = format!(
+                "{}{}",
+                new_object_name,
+                &reference.object_name[object_name.len()..]
+            );
+            let new_key = self.make_tag_reference_key(
+                &reference.tag_name,
+                &reference.object_domain,
+                &reference.object_name,
+            )?;
+            let value = serialize_struct(&reference, ErrorCode::IllegalTag, || "")?;
+            ops.push(txn_op_put(&new_key, value));
+        }
+
+        let txn_req = TxnRequest {
+            condition: vec![],
+            if_then: ops,
+            else_then: vec![],
+        };
+        let tx_reply = self.kv_api.transaction(txn_req).await?;
+        txn_reply_to_api_result(tx_reply)?;
+        Ok(())
+    }
+}
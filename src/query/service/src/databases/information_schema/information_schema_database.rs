@@ -24,6 +24,7 @@ use common_storages_information_schema::KeywordsTable;
 use common_storages_information_schema::SchemataTable;
 use common_storages_information_schema::StatisticsTable;
 use common_storages_information_schema::TablesTable;
+use common_storages_information_schema::TagReferencesTable;
 use common_storages_information_schema::ViewsTable;
 
 use crate::catalogs::InMemoryMetas;
@@ -45,6 +46,7 @@ impl InformationSchemaDatabase {
             SchemataTable::create(sys_db_meta.next_table_id()),
             StatisticsTable::create(sys_db_meta.next_table_id()),
             KeyColumnUsageTable::create(sys_db_meta.next_table_id()),
+            TagReferencesTable::create(sys_db_meta.next_table_id()),
         ];
 
         let db = "information_schema";
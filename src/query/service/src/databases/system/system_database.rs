@@ -20,6 +20,7 @@ use common_meta_app::schema::DatabaseIdent;
 use common_meta_app::schema::DatabaseInfo;
 use common_meta_app::schema::DatabaseMeta;
 use common_meta_app::schema::DatabaseNameIdent;
+use common_storages_system::AuditLogTable;
 use common_storages_system::BackgroundJobTable;
 use common_storages_system::BackgroundTaskTable;
 use common_storages_system::BacktraceTable;
@@ -50,6 +51,8 @@ use common_storages_system::StagesTable;
 use common_storages_system::TableFunctionsTable;
 use common_storages_system::TablesTableWithHistory;
 use common_storages_system::TablesTableWithoutHistory;
+use common_storages_system::TagReferencesTable;
+use common_storages_system::TagsTable;
 use common_storages_system::TracingTable;
 use common_storages_system::UsersTable;
 
@@ -100,6 +103,10 @@ impl SystemDatabase {
                 sys_db_meta.next_table_id(),
                 config.query.max_query_log_size,
             )),
+            Arc::new(AuditLogTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_query_log_size,
+            )),
             EnginesTable::create(sys_db_meta.next_table_id()),
             RolesTable::create(sys_db_meta.next_table_id()),
             StagesTable::create(sys_db_meta.next_table_id()),
@@ -113,6 +120,8 @@ impl SystemDatabase {
             BackgroundTaskTable::create(sys_db_meta.next_table_id()),
             BackgroundJobTable::create(sys_db_meta.next_table_id()),
             BacktraceTable::create(sys_db_meta.next_table_id()),
+            TagsTable::create(sys_db_meta.next_table_id()),
+            TagReferencesTable::create(sys_db_meta.next_table_id()),
         ];
 
         let disable_tables = Self::disable_system_tables();
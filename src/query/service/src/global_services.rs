@@ -23,9 +23,11 @@ use common_config::GlobalConfig;
 use common_config::InnerConfig;
 use common_exception::Result;
 use common_meta_app::schema::CatalogType;
+use common_meta_store::MetaStoreProvider;
 use common_profile::QueryProfileManager;
 use common_sharing::ShareEndpointManager;
 use common_storage::DataOperator;
+use common_storage::EncryptionKeyProvider;
 use common_storage::ShareTableConfig;
 use common_storages_iceberg::IcebergCreator;
 use common_tracing::GlobalLogger;
@@ -37,6 +39,7 @@ use crate::api::DataExchangeManager;
 use crate::auth::AuthMgr;
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::ClusterDiscovery;
+use crate::interpreters::AuditLogger;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::SessionManager;
 
@@ -58,12 +61,14 @@ impl GlobalServices {
 
         GlobalLogger::init(&app_name_shuffle, &config.log);
         GlobalIORuntime::init(config.storage.num_cpus as usize)?;
+        AuditLogger::init(&config.log.audit)?;
         GlobalQueryRuntime::init(config.storage.num_cpus as usize)?;
 
         // Cluster discovery.
         ClusterDiscovery::init(config.clone()).await?;
 
         DataOperator::init(&config.storage).await?;
+        EncryptionKeyProvider::init(&config.storage)?;
 
         ShareTableConfig::init(
             &config.query.share_endpoint_address,
@@ -84,9 +89,15 @@ impl GlobalServices {
 
             let default_catalog = DatabaseCatalog::try_create_with_config(config.clone()).await?;
 
+            // The iceberg catalog coordinates the commits of its tables in the meta service.
+            let meta_store = MetaStoreProvider::new(config.meta.to_meta_grpc_client_conf())
+                .create_meta_store()
+                .await?;
+            let iceberg_creator = IcebergCreator::create(Arc::new(meta_store));
+
             #[allow(unused_mut)]
             let mut catalog_creator: Vec<(CatalogType, Arc<dyn CatalogCreator>)> =
-                vec![(CatalogType::Iceberg, Arc::new(IcebergCreator))];
+                vec![(CatalogType::Iceberg, Arc::new(iceberg_creator))];
             // Register hive catalog.
             #[cfg(feature = "hive")]
             {
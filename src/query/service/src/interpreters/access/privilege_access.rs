@@ -21,6 +21,7 @@ use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::UserGrantSet;
 use common_meta_app::principal::UserPrivilegeType;
 use common_sql::plans::CopyPlan;
+use common_sql::plans::ObjectIdent;
 use common_sql::plans::RewriteKind;
 use common_users::RoleCacheManager;
 
@@ -264,6 +265,18 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::RotateTableKey(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
             Plan::SetOptions(plan) => {
                 session
                     .validate_privilege(
@@ -276,6 +289,30 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::CreateTableIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::DropTableIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
             Plan::AddTableColumn(plan) => {
                 session
                     .validate_privilege(
@@ -312,6 +349,30 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::AddTableRowAccessPolicy(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::DropTableRowAccessPolicy(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
             Plan::DropTableColumn(plan) => {
                 session
                     .validate_privilege(
@@ -348,6 +409,18 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::DropTablePartition(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Delete],
+                    )
+                    .await?;
+            }
             Plan::ReclusterTable(plan) => {
                 session
                     .validate_privilege(
@@ -538,6 +611,22 @@ impl AccessChecker for PrivilegeAccess {
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Alter])
                     .await?;
             }
+            Plan::SetDatabaseOptions(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::AlterObjectTag(plan) => {
+                let (object, privilege) = object_ident_privilege(&plan.object);
+                session.validate_privilege(&object, vec![privilege]).await?;
+            }
+            Plan::CommentOn(plan) => {
+                let (object, privilege) = object_ident_privilege(&plan.object);
+                session.validate_privilege(&object, vec![privilege]).await?;
+            }
             Plan::Copy(plan) => match plan.as_ref() {
                 CopyPlan::IntoTable(plan) => {
                     session
@@ -579,7 +668,11 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::AlterNetworkPolicy(_)
             | Plan::DropNetworkPolicy(_)
             | Plan::DescNetworkPolicy(_)
-            | Plan::ShowNetworkPolicies(_) => {
+            | Plan::ShowNetworkPolicies(_)
+            | Plan::CreateRowAccessPolicy(_)
+            | Plan::DropRowAccessPolicy(_)
+            | Plan::CreateTag(_)
+            | Plan::DropTag(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Super])
                     .await?;
@@ -600,12 +693,47 @@ impl AccessChecker for PrivilegeAccess {
             // just used in clickhouse-sqlalchemy, no need to check
             Plan::ExistsTable(_) => {}
             Plan::DescDatamaskPolicy(_) => {}
+            Plan::DescRowAccessPolicy(_) => {}
         }
 
         Ok(())
     }
 }
 
+/// Comments and tags on tables and databases require the `Alter` privilege of
+/// the object, stages and users are only managed by `Super`.
+fn object_ident_privilege(object: &ObjectIdent) -> (GrantObject, UserPrivilegeType) {
+    match object {
+        ObjectIdent::Database { catalog, database } => (
+            GrantObject::Database(catalog.clone(), database.clone()),
+            UserPrivilegeType::Alter,
+        ),
+        ObjectIdent::Table {
+            catalog,
+            database,
+            table,
+        }
+        | ObjectIdent::Column {
+            catalog,
+            database,
+            table,
+            ..
+        } => (
+            GrantObject::Table(catalog.clone(), database.clone(), table.clone()),
+            UserPrivilegeType::Alter,
+        ),
+        ObjectIdent::View {
+            catalog, database, ..
+        } => (
+            GrantObject::Database(catalog.clone(), database.clone()),
+            UserPrivilegeType::Alter,
+        ),
+        ObjectIdent::Stage { .. } | ObjectIdent::User { .. } => {
+            (GrantObject::Global, UserPrivilegeType::Super)
+        }
+    }
+}
+
 async fn has_priv(
     tenant: &str,
     database: &String,
@@ -15,10 +15,14 @@
 mod grant;
 mod metrics;
 mod table;
+mod tag;
 mod util;
 
 pub use grant::validate_grant_object_exists;
 pub use table::check_referenced_computed_columns;
+pub use tag::drop_object_tag_references;
+pub use tag::rename_object_tag_references;
+pub use tag::validate_tag_object_exists;
 pub use util::check_deduplicate_label;
 
 pub use self::metrics::*;
@@ -0,0 +1,140 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_sql::plans::ObjectIdent;
+use common_storages_view::view_table::VIEW_ENGINE;
+use common_users::UserApiProvider;
+
+use crate::sessions::QueryContext;
+
+#[async_backtrace::framed]
+pub async fn validate_tag_object_exists(
+    ctx: &Arc<QueryContext>,
+    object: &ObjectIdent,
+) -> Result<()> {
+    let tenant = ctx.get_tenant();
+
+    match object {
+        ObjectIdent::Database { catalog, database } => {
+            let catalog = ctx.get_catalog(catalog).await?;
+            if !catalog.exists_database(tenant.as_str(), database).await? {
+                return Err(ErrorCode::UnknownDatabase(format!(
+                    "database {} not exists",
+                    database,
+                )));
+            }
+        }
+        ObjectIdent::Table {
+            catalog,
+            database,
+            table,
+        } => {
+            let catalog = ctx.get_catalog(catalog).await?;
+            let table = catalog.get_table(tenant.as_str(), database, table).await?;
+            if table.engine() == VIEW_ENGINE {
+                return Err(ErrorCode::UnknownTable(format!(
+                    "{}.{} is a view, not a table",
+                    database,
+                    table.name(),
+                )));
+            }
+        }
+        ObjectIdent::View {
+            catalog,
+            database,
+            view,
+        } => {
+            let catalog = ctx.get_catalog(catalog).await?;
+            let table = catalog.get_table(tenant.as_str(), database, view).await?;
+            if table.engine() != VIEW_ENGINE {
+                return Err(ErrorCode::UnknownView(format!(
+                    "{}.{} is not a view",
+                    database, view,
+                )));
+            }
+        }
+        ObjectIdent::Column {
+            catalog,
+            database,
+            table,
+            column,
+        } => {
+            let catalog = ctx.get_catalog(catalog).await?;
+            let table = catalog.get_table(tenant.as_str(), database, table).await?;
+            if table.schema().field_with_name(column).is_err() {
+                return Err(ErrorCode::UnknownColumn(format!(
+                    "column {} not exists in table {}.{}",
+                    column,
+                    database,
+                    table.name(),
+                )));
+            }
+        }
+        ObjectIdent::Stage { stage } => {
+            UserApiProvider::instance()
+                .get_stage(tenant.as_str(), stage)
+                .await?;
+        }
+        ObjectIdent::User { user } => {
+            UserApiProvider::instance()
+                .get_user(tenant.as_str(), user.clone())
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+// The domains of an object and of the objects nested in it.
+fn nested_tag_domains(object: &ObjectIdent) -> &'static [&'static str] {
+    match object {
+        ObjectIdent::Database { .. } => &["DATABASE", "TABLE", "VIEW", "COLUMN"],
+        ObjectIdent::Table { .. } => &["TABLE", "COLUMN"],
+        ObjectIdent::View { .. } => &["VIEW", "COLUMN"],
+        ObjectIdent::Column { .. } => &["COLUMN"],
+        ObjectIdent::Stage { .. } => &["STAGE"],
+        ObjectIdent::User { .. } => &["USER"],
+    }
+}
+
+/// Drop the tags set on a dropped object and on the objects nested in it,
+/// so an object created later with the same name does not inherit them.
+#[async_backtrace::framed]
+pub async fn drop_object_tag_references(tenant: &str, object: &ObjectIdent) -> Result<()> {
+    UserApiProvider::instance()
+        .drop_object_tag_references(tenant, nested_tag_domains(object), &object.name())
+        .await
+}
+
+/// Move the tags set on a renamed object and on the objects nested in it to the new name.
+#[async_backtrace::framed]
+pub async fn rename_object_tag_references(
+    tenant: &str,
+    object: &ObjectIdent,
+    new_object: &ObjectIdent,
+) -> Result<()> {
+    UserApiProvider::instance()
+        .rename_object_tag_references(
+            tenant,
+            nested_tag_domains(object),
+            &object.name(),
+            &new_object.name(),
+        )
+        .await
+}
@@ -23,6 +23,7 @@ use common_expression::DataSchemaRefExt;
 use common_expression::SendableDataBlockStream;
 use log::error;
 
+use crate::interpreters::InterpreterAuditLog;
 use crate::interpreters::InterpreterMetrics;
 use crate::interpreters::InterpreterQueryLog;
 use crate::pipelines::executor::ExecutorSettings;
@@ -148,6 +149,8 @@ fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>) {
         SessionManager::instance().status.write().query_finish(now)
     }
 
+    InterpreterAuditLog::log_finish(ctx, now, error.as_ref());
+
     if let Err(error) = InterpreterQueryLog::log_finish(ctx, now, error) {
         error!("interpreter.finish.error: {:?}", error)
     }
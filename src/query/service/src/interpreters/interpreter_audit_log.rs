@@ -0,0 +1,695 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_base::base::tokio;
+use common_base::base::GlobalInstance;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::table::AppendMode;
+use common_catalog::table::Table;
+use common_config::GlobalConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_meta_app::principal::UserInfo;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_sql::plans::CopyPlan;
+use common_sql::plans::InsertInputSource;
+use common_sql::MetadataRef;
+use common_storages_system::AuditCategory;
+use common_storages_system::AuditLogElement;
+use common_storages_system::AuditLogQueue;
+use common_storages_system::SystemLogElement;
+use common_tracing::AuditLogConfig;
+use common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
+use log::error;
+use log::info;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+
+use crate::pipelines::builders::build_append2table_with_commit_pipeline;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryAudit;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+use crate::sql::plans::Plan;
+
+/// A destination of audit events.
+pub trait AuditSink: Send + Sync {
+    fn write(&self, event: &AuditLogElement) -> Result<()>;
+}
+
+/// Writes audit events as JSON lines to the files in `log.audit.dir`.
+pub struct FileAuditSink;
+
+impl AuditSink for FileAuditSink {
+    fn write(&self, event: &AuditLogElement) -> Result<()> {
+        let event_str = serde_json::to_string(event)?;
+        info!(target: "audit", "{}", event_str);
+        Ok(())
+    }
+}
+
+/// The interval at which [`TableAuditSink`] flushes buffered events.
+const AUDIT_TABLE_FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The max number of events [`TableAuditSink`] keeps while the table can not be written.
+const AUDIT_TABLE_MAX_BUFFERED: usize = 100_000;
+
+/// Writes audit events into the fuse table `log.audit.table`, which is created if it does not exist.
+///
+/// Events are buffered and appended to the table by a background task, so that an audited
+/// statement does not wait for the write. The recent events of this node are also kept in
+/// `system.audit_log`.
+pub struct TableAuditSink {
+    database: String,
+    table: String,
+    buffer: Mutex<VecDeque<AuditLogElement>>,
+}
+
+impl AuditSink for TableAuditSink {
+    fn write(&self, event: &AuditLogElement) -> Result<()> {
+        {
+            let mut buffer = self.buffer.lock();
+            if buffer.len() >= AUDIT_TABLE_MAX_BUFFERED {
+                buffer.pop_front();
+            }
+            buffer.push_back(event.clone());
+        }
+        AuditLogQueue::instance()?.append_data(event.clone())
+    }
+}
+
+impl TableAuditSink {
+    pub fn create(table: &str) -> Result<Arc<TableAuditSink>> {
+        let (database, table) = table.split_once('.').ok_or_else(|| {
+            ErrorCode::InvalidConfig(format!(
+                "invalid audit table `{}`, expect `database.table`",
+                table
+            ))
+        })?;
+
+        Ok(Arc::new(TableAuditSink {
+            database: database.to_string(),
+            table: table.to_string(),
+            buffer: Mutex::new(VecDeque::new()),
+        }))
+    }
+
+    /// Spawn the background task that flushes buffered events to the table periodically.
+    pub fn start(self: &Arc<Self>) {
+        let sink = self.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            loop {
+                tokio::time::sleep(AUDIT_TABLE_FLUSH_INTERVAL).await;
+                if let Err(e) = sink.flush().await {
+                    error!(
+                        "fail to write audit log to table {}.{}: {:?}",
+                        sink.database, sink.table, e
+                    );
+                }
+            }
+        });
+    }
+
+    /// Append the buffered events to the table.
+    ///
+    /// If the append fails, the events are put back to the buffer, to be retried in the next flush.
+    pub async fn flush(&self) -> Result<()> {
+        let mut events = std::mem::take(&mut *self.buffer.lock());
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        if let Err(e) = self.append(events.make_contiguous()).await {
+            let mut buffer = self.buffer.lock();
+            let mut retry = events;
+            retry.append(&mut buffer);
+            if retry.len() > AUDIT_TABLE_MAX_BUFFERED {
+                retry.drain(..retry.len() - AUDIT_TABLE_MAX_BUFFERED);
+            }
+            *buffer = retry;
+            return Err(e);
+        }
+
+        Ok(())
+    }
+
+    async fn append(&self, events: &[AuditLogElement]) -> Result<()> {
+        // Written with a pipeline rather than an `INSERT` statement, which would be audited itself.
+        let session = SessionManager::instance()
+            .create_session(SessionType::Dummy)
+            .await?;
+        let user = UserInfo::new_no_auth("audit-log", "%");
+        session
+            .set_authed_user(user, Some(BUILTIN_ROLE_ACCOUNT_ADMIN.to_string()))
+            .await?;
+        let ctx = session.create_query_context().await?;
+
+        let table = self.get_or_create_table(&ctx).await?;
+        let schema = AuditLogElement::schema();
+        let source_schema: DataSchemaRef = Arc::new(DataSchema::from(&schema));
+
+        let mut columns = schema
+            .fields()
+            .iter()
+            .map(|f| ColumnBuilder::with_capacity(&DataType::from(f.data_type()), events.len()))
+            .collect::<Vec<_>>();
+        for event in events {
+            event.fill_to_data_block(&mut columns)?;
+        }
+        let block = DataBlock::new_from_columns(columns.into_iter().map(|c| c.build()).collect());
+
+        let mut build_res = PipelineBuildResult::from_blocks(vec![block])?;
+        build_append2table_with_commit_pipeline(
+            ctx.clone(),
+            &mut build_res.main_pipeline,
+            table,
+            source_schema,
+            None,
+            false,
+            AppendMode::Normal,
+        )?;
+
+        let settings = ExecutorSettings::try_create(&ctx.get_settings(), ctx.get_id())?;
+        let executor = PipelineCompleteExecutor::try_create(build_res.main_pipeline, settings)?;
+        ctx.set_executor(executor.get_inner())?;
+        executor.execute()
+    }
+
+    async fn get_or_create_table(&self, ctx: &QueryContext) -> Result<Arc<dyn Table>> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT).await?;
+        match catalog
+            .get_table(&tenant, &self.database, &self.table)
+            .await
+        {
+            Ok(table) => return Ok(table),
+            Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE => {}
+            Err(e) => return Err(e),
+        }
+
+        info!("create audit log table {}.{}", self.database, self.table);
+        let db = catalog.get_database(&tenant, &self.database).await?;
+        let options = BTreeMap::from([(
+            OPT_KEY_DATABASE_ID.to_string(),
+            db.get_db_info().ident.db_id.to_string(),
+        )]);
+        catalog
+            .create_table(CreateTableReq {
+                if_not_exists: true,
+                name_ident: TableNameIdent {
+                    tenant: tenant.clone(),
+                    db_name: self.database.clone(),
+                    table_name: self.table.clone(),
+                },
+                table_meta: TableMeta {
+                    schema: AuditLogElement::schema(),
+                    engine: "FUSE".to_string(),
+                    options,
+                    ..Default::default()
+                },
+            })
+            .await?;
+
+        catalog
+            .get_table(&tenant, &self.database, &self.table)
+            .await
+    }
+}
+
+/// Sends audit events through the normal logging and tracing pipeline.
+pub struct TracingAuditSink;
+
+impl AuditSink for TracingAuditSink {
+    fn write(&self, event: &AuditLogElement) -> Result<()> {
+        let event_str = serde_json::to_string(event)?;
+        info!("audit: {}", event_str);
+        Ok(())
+    }
+}
+
+/// Dispatches audit events to the configured sinks.
+pub struct AuditLogger {
+    sinks: RwLock<Vec<Arc<dyn AuditSink>>>,
+}
+
+impl AuditLogger {
+    pub fn init(config: &AuditLogConfig) -> Result<()> {
+        let mut sinks: Vec<Arc<dyn AuditSink>> = vec![];
+        if config.on {
+            for sink in &config.sinks {
+                match sink.as_str() {
+                    "file" => sinks.push(Arc::new(FileAuditSink)),
+                    "table" => {
+                        let sink = TableAuditSink::create(&config.table)?;
+                        sink.start();
+                        sinks.push(sink);
+                    }
+                    "tracing" => sinks.push(Arc::new(TracingAuditSink)),
+                    other => {
+                        return Err(ErrorCode::InvalidConfig(format!(
+                            "unknown audit sink `{}`",
+                            other
+                        )));
+                    }
+                }
+            }
+        }
+
+        GlobalInstance::set(Arc::new(AuditLogger {
+            sinks: RwLock::new(sinks),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<AuditLogger> {
+        GlobalInstance::get()
+    }
+
+    /// Register an additional sink, such as one forwarding events to an external system.
+    pub fn add_sink(&self, sink: Arc<dyn AuditSink>) {
+        self.sinks.write().push(sink);
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !self.sinks.read().is_empty()
+    }
+
+    fn write(&self, event: AuditLogElement) {
+        for sink in self.sinks.read().iter() {
+            if let Err(e) = sink.write(&event) {
+                error!("fail to write audit log: {:?}", e);
+            }
+        }
+    }
+}
+
+pub struct InterpreterAuditLog;
+
+impl InterpreterAuditLog {
+    /// Attach the audit event of `plan` to the query, to be written when the query finishes.
+    pub fn attach(ctx: &QueryContext, plan: &Plan) {
+        if !AuditLogger::instance().is_enabled() {
+            return;
+        }
+
+        if let Some(audit) = audit_of(plan) {
+            ctx.set_audit(audit);
+        }
+    }
+
+    /// Write the audit event of `plan` that is rejected by the access check.
+    pub fn log_denied(ctx: &QueryContext, plan: &Plan, err: &ErrorCode) {
+        let logger = AuditLogger::instance();
+        if !logger.is_enabled() {
+            return;
+        }
+
+        if let Some(audit) = audit_of(plan) {
+            match build_event(ctx, SystemTime::now(), audit, Some(err)) {
+                Ok(event) => logger.write(event),
+                Err(e) => error!("fail to build audit log: {:?}", e),
+            }
+        }
+    }
+
+    /// Write the audit event attached to the query, if there is one.
+    pub fn log_finish(ctx: &QueryContext, now: SystemTime, err: Option<&ErrorCode>) {
+        let audit = match ctx.take_audit() {
+            Some(audit) => audit,
+            None => return,
+        };
+
+        match build_event(ctx, now, audit, err) {
+            Ok(event) => AuditLogger::instance().write(event),
+            Err(e) => error!("fail to build audit log: {:?}", e),
+        }
+    }
+}
+
+fn build_event(
+    ctx: &QueryContext,
+    now: SystemTime,
+    audit: QueryAudit,
+    err: Option<&ErrorCode>,
+) -> Result<AuditLogElement> {
+    let user = ctx.get_current_user()?;
+    let role = ctx
+        .get_current_role()
+        .map(|role| role.name)
+        .unwrap_or_default();
+    let client_address = ctx
+        .get_client_address()
+        .map(|addr| addr.ip().to_string())
+        .unwrap_or_default();
+    let (error_code, error_message) = match err {
+        None => (0, "".to_string()),
+        Some(e) => (e.code().into(), e.message()),
+    };
+
+    Ok(AuditLogElement {
+        event_time: now
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or(Duration::new(0, 0))
+            .as_micros() as i64,
+        tenant_id: ctx.get_tenant(),
+        cluster_id: GlobalConfig::instance().query.cluster_id.clone(),
+        node_id: ctx.get_cluster().local_id.clone(),
+        user: user.identity().to_string(),
+        role,
+        client_address,
+        query_id: ctx.get_id(),
+        category: audit.category,
+        statement_kind: audit.statement_kind,
+        objects: audit.objects,
+        success: err.is_none(),
+        error_code,
+        error_message,
+    })
+}
+
+/// Returns the audit event of `plan`, or `None` if the statement is not audited.
+///
+/// DDL, DCL and statements reading or writing table data are audited,
+/// `SHOW`, `DESC`, `SET` and the like are not.
+pub fn audit_of(plan: &Plan) -> Option<QueryAudit> {
+    let (category, objects) = match plan {
+        // Data access.
+        Plan::Query { metadata, .. } => (AuditCategory::DataAccess, read_tables(metadata)),
+        Plan::ExplainAnalyze { plan } => return audit_of(plan),
+        Plan::Insert(plan) => {
+            let mut objects = vec![table_name(&plan.catalog, &plan.database, &plan.table)];
+            if let InsertInputSource::SelectPlan(select) = &plan.source {
+                objects.extend(audit_of(select).map(|a| a.objects).unwrap_or_default());
+            }
+            (AuditCategory::DataAccess, objects)
+        }
+        Plan::Replace(plan) => (AuditCategory::DataAccess, vec![table_name(
+            &plan.catalog,
+            &plan.database,
+            &plan.table,
+        )]),
+        Plan::Delete(plan) => (AuditCategory::DataAccess, vec![table_name(
+            &plan.catalog_name,
+            &plan.database_name,
+            &plan.table_name,
+        )]),
+        Plan::Update(plan) => (AuditCategory::DataAccess, vec![table_name(
+            &plan.catalog,
+            &plan.database,
+            &plan.table,
+        )]),
+        Plan::Copy(copy) => match copy.as_ref() {
+            CopyPlan::IntoTable(plan) => (AuditCategory::DataAccess, vec![
+                table_name(
+                    plan.catalog_info.catalog_name(),
+                    &plan.database_name,
+                    &plan.table_name,
+                ),
+                format!("@{}", plan.stage_table_info.stage_info.stage_name),
+            ]),
+            CopyPlan::IntoStage { stage, from, .. } => {
+                let mut objects = vec![format!("@{}", stage.stage_name)];
+                objects.extend(audit_of(from).map(|a| a.objects).unwrap_or_default());
+                (AuditCategory::DataAccess, objects)
+            }
+            CopyPlan::NoFileToCopy => return None,
+        },
+
+        // DDL.
+        Plan::CreateCatalog(plan) => (AuditCategory::Ddl, vec![plan.catalog.clone()]),
+        Plan::DropCatalog(plan) => (AuditCategory::Ddl, vec![plan.catalog.clone()]),
+        Plan::CreateDatabase(plan) => (AuditCategory::Ddl, vec![database_name(
+            &plan.catalog,
+            &plan.database,
+        )]),
+        Plan::DropDatabase(plan) => (AuditCategory::Ddl, vec![database_name(
+            &plan.catalog,
+            &plan.database,
+        )]),
+        Plan::UndropDatabase(plan) => (AuditCategory::Ddl, vec![database_name(
+            &plan.catalog,
+            &plan.database,
+        )]),
+        Plan::SetDatabaseOptions(plan) => (AuditCategory::Ddl, vec![database_name(
+            &plan.catalog,
+            &plan.database,
+        )]),
+        Plan::RenameDatabase(plan) => (
+            AuditCategory::Ddl,
+            plan.entities
+                .iter()
+                .flat_map(|e| {
+                    [
+                        database_name(&e.catalog, &e.database),
+                        database_name(&e.catalog, &e.new_database),
+                    ]
+                })
+                .collect(),
+        ),
+        Plan::CreateTable(plan) => (AuditCategory::Ddl, vec![table_name(
+            &plan.catalog,
+            &plan.database,
+            &plan.table,
+        )]),
+        Plan::DropTable(plan) => (AuditCategory::Ddl, vec![table_name(
+            &plan.catalog,
+            &plan.database,
+            &plan.table,
+        )]),
+        Plan::UndropTable(plan) => (AuditCategory::Ddl, vec![table_name(
+            &plan.catalog,
+            &plan.database,
+            &plan.table,
+        )]),
+        Plan::RenameTable(plan) => (AuditCategory::Ddl, vec![
+            table_name(&plan.catalog, &plan.database, &plan.table),
+            table_name(&plan.catalog, &plan.new_database, &plan.new_table),
+        ]),
+        Plan::RenameTableColumn(plan) => (AuditCategory::Ddl, vec![table_name(
+            &plan.catalog,
+            &plan.database,
+            &plan.table,
+        )]),
+        Plan::AddTableColumn(plan) => (AuditCategory::Ddl, vec![table_name(
+            &plan.catalog,
+            &plan.database,
+            &plan.table,
+        )]),
+        Plan::DropTableColumn(plan) => (AuditCategory::Ddl, vec![table_name(
+            &plan.catalog,
+            &plan.database,
+            &plan.table,
+        )]),
+        Plan::ModifyTableColumn(plan) => (AuditCategory::Ddl, vec![table_name(
+            &plan.catalog,
+            &plan.database,
+            &plan.table,
+        )]),
+        Plan::AddTableRowAccessPolicy(plan) => (AuditCategory::Ddl, vec![table_name(
+            &plan.catalog,
+            &plan.database,
+            &plan.table,
+        )]),
+        Plan::DropTableRowAccessPolicy(plan) => (AuditCategory::Ddl, vec![table_name(
+            &plan.catalog,
+            &plan.database,
+            &plan.table,
+        )]),
+        Plan::AlterTableClusterKey(plan) => (AuditCategory::Ddl, vec![table_name(
+            &plan.catalog,
+            &plan.database,
+            &plan.table,
+        )]),
+        Plan::DropTableClusterKey(plan) => (AuditCategory::Ddl, vec![table_name(
+            &plan.catalog,
+            &plan.database,
+            &plan.table,
+        )]),
+        Plan::DropTablePartition(plan) => (AuditCategory::Ddl, vec![table_name(
+            &plan.catalog,
+            &plan.database,
+            &plan.table,
+        )]),
+        Plan::RotateTableKey(plan) => (AuditCategory::Ddl, vec![table_name(
+            &plan.catalog,
+            &plan.database,
+            &plan.table,
+        )]),
+        Plan::RevertTable(plan) => (AuditCategory::Ddl, vec![table_name(
+            &plan.catalog,
+            &plan.database,
+            &plan.table,
+        )]),
+        Plan::TruncateTable(plan) => (AuditCategory::Ddl, vec![table_name(
+            &plan.catalog,
+            &plan.database,
+            &plan.table,
+        )]),
+        Plan::SetOptions(plan) => (AuditCategory::Ddl, vec![table_name(
+            &plan.catalog,
+            &plan.database,
+            &plan.table,
+        )]),
+        Plan::CreateView(plan) => (AuditCategory::Ddl, vec![table_name(
+            &plan.catalog,
+            &plan.database,
+            &plan.view_name,
+        )]),
+        Plan::AlterView(plan) => (AuditCategory::Ddl, vec![table_name(
+            &plan.catalog,
+            &plan.database,
+            &plan.view_name,
+        )]),
+        Plan::DropView(plan) => (AuditCategory::Ddl, vec![table_name(
+            &plan.catalog,
+            &plan.database,
+            &plan.view_name,
+        )]),
+        Plan::CreateIndex(plan) => (AuditCategory::Ddl, vec![plan.index_name.clone()]),
+        Plan::DropIndex(plan) => (AuditCategory::Ddl, vec![plan.index.clone()]),
+        Plan::CreateTableIndex(plan) => (AuditCategory::Ddl, vec![
+            plan.index_name.clone(),
+            table_name(&plan.catalog, &plan.database, &plan.table),
+        ]),
+        Plan::DropTableIndex(plan) => (AuditCategory::Ddl, vec![
+            plan.index_name.clone(),
+            table_name(&plan.catalog, &plan.database, &plan.table),
+        ]),
+        Plan::CreateVirtualColumns(plan) => (AuditCategory::Ddl, vec![table_name(
+            &plan.catalog,
+            &plan.database,
+            &plan.table,
+        )]),
+        Plan::AlterVirtualColumns(plan) => (AuditCategory::Ddl, vec![table_name(
+            &plan.catalog,
+            &plan.database,
+            &plan.table,
+        )]),
+        Plan::DropVirtualColumns(plan) => (AuditCategory::Ddl, vec![table_name(
+            &plan.catalog,
+            &plan.database,
+            &plan.table,
+        )]),
+        Plan::CreateUDF(plan) => (AuditCategory::Ddl, vec![plan.udf.name.clone()]),
+        Plan::AlterUDF(plan) => (AuditCategory::Ddl, vec![plan.udf.name.clone()]),
+        Plan::DropUDF(plan) => (AuditCategory::Ddl, vec![plan.name.clone()]),
+        Plan::CreateFileFormat(plan) => (AuditCategory::Ddl, vec![plan.name.clone()]),
+        Plan::DropFileFormat(plan) => (AuditCategory::Ddl, vec![plan.name.clone()]),
+        Plan::CreateStage(plan) => (AuditCategory::Ddl, vec![format!(
+            "@{}",
+            plan.stage_info.stage_name
+        )]),
+        Plan::DropStage(plan) => (AuditCategory::Ddl, vec![format!("@{}", plan.name)]),
+        Plan::CreateShareEndpoint(plan) => (AuditCategory::Ddl, vec![plan.endpoint.to_string()]),
+        Plan::DropShareEndpoint(plan) => (AuditCategory::Ddl, vec![plan.endpoint.clone()]),
+        Plan::CreateShare(plan) => (AuditCategory::Ddl, vec![plan.share.clone()]),
+        Plan::DropShare(plan) => (AuditCategory::Ddl, vec![plan.share.clone()]),
+        Plan::CreateDatamaskPolicy(plan) => (AuditCategory::Ddl, vec![plan.name.clone()]),
+        Plan::DropDatamaskPolicy(plan) => (AuditCategory::Ddl, vec![plan.name.clone()]),
+        Plan::CreateRowAccessPolicy(plan) => (AuditCategory::Ddl, vec![plan.name.clone()]),
+        Plan::DropRowAccessPolicy(plan) => (AuditCategory::Ddl, vec![plan.name.clone()]),
+        Plan::CreateTag(plan) => (AuditCategory::Ddl, vec![plan.name.clone()]),
+        Plan::DropTag(plan) => (AuditCategory::Ddl, vec![plan.name.clone()]),
+        Plan::AlterObjectTag(plan) => (AuditCategory::Ddl, vec![plan.object.name()]),
+        Plan::CommentOn(plan) => (AuditCategory::Ddl, vec![plan.object.name()]),
+        Plan::CreateNetworkPolicy(plan) => (AuditCategory::Ddl, vec![plan.name.clone()]),
+        Plan::AlterNetworkPolicy(plan) => (AuditCategory::Ddl, vec![plan.name.clone()]),
+        Plan::DropNetworkPolicy(plan) => (AuditCategory::Ddl, vec![plan.name.clone()]),
+
+        // DCL.
+        Plan::CreateUser(plan) => (AuditCategory::Dcl, vec![plan.user.to_string()]),
+        Plan::AlterUser(plan) => (AuditCategory::Dcl, vec![plan.user.to_string()]),
+        Plan::DropUser(plan) => (AuditCategory::Dcl, vec![plan.user.to_string()]),
+        Plan::CreateRole(plan) => (AuditCategory::Dcl, vec![plan.role_name.clone()]),
+        Plan::DropRole(plan) => (AuditCategory::Dcl, vec![plan.role_name.clone()]),
+        Plan::GrantRole(plan) => (AuditCategory::Dcl, vec![
+            plan.principal.to_string(),
+            plan.role.clone(),
+        ]),
+        Plan::RevokeRole(plan) => (AuditCategory::Dcl, vec![
+            plan.principal.to_string(),
+            plan.role.clone(),
+        ]),
+        Plan::GrantPriv(plan) => (AuditCategory::Dcl, vec![
+            plan.principal.to_string(),
+            plan.on.to_string(),
+        ]),
+        Plan::RevokePriv(plan) => (AuditCategory::Dcl, vec![
+            plan.principal.to_string(),
+            plan.on.to_string(),
+        ]),
+        Plan::GrantShareObject(plan) => (AuditCategory::Dcl, vec![
+            plan.share.clone(),
+            plan.object.to_string(),
+        ]),
+        Plan::RevokeShareObject(plan) => (AuditCategory::Dcl, vec![
+            plan.share.clone(),
+            plan.object.to_string(),
+        ]),
+        Plan::AlterShareTenants(plan) => (AuditCategory::Dcl, vec![plan.share.clone()]),
+
+        _ => return None,
+    };
+
+    Some(QueryAudit {
+        category,
+        statement_kind: plan.to_string(),
+        objects,
+    })
+}
+
+fn read_tables(metadata: &MetadataRef) -> Vec<String> {
+    let metadata = metadata.read();
+    let mut tables = vec![];
+    for table in metadata.tables() {
+        // The privilege is checked on the view rather than on the tables it reads from.
+        if table.is_source_of_view() {
+            continue;
+        }
+        let name = table_name(table.catalog(), table.database(), table.name());
+        if !tables.contains(&name) {
+            tables.push(name);
+        }
+    }
+    tables
+}
+
+fn database_name(catalog: &str, database: &str) -> String {
+    format!("{}.{}", catalog, database)
+}
+
+fn table_name(catalog: &str, database: &str, table: &str) -> String {
+    format!("{}.{}.{}", catalog, database, table)
+}
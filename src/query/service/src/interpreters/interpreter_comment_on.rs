@@ -0,0 +1,173 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::DatabaseNameIdent;
+use common_meta_app::schema::DatabaseType;
+use common_meta_app::schema::UpdateDatabaseCommentReq;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::CommentOnPlan;
+use common_sql::plans::ObjectIdent;
+use common_storages_share::save_share_table_info;
+use common_storages_view::view_table::VIEW_ENGINE;
+use common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::common::validate_tag_object_exists;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct CommentOnInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CommentOnPlan,
+}
+
+impl CommentOnInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CommentOnPlan) -> Result<Self> {
+        Ok(CommentOnInterpreter { ctx, plan })
+    }
+
+    /// Update the comment of a table or a view, or the comment of one column
+    /// of a table if `column` is given.
+    #[async_backtrace::framed]
+    async fn comment_on_table(
+        &self,
+        catalog_name: &str,
+        database: &str,
+        table: &str,
+        column: Option<&str>,
+    ) -> Result<()> {
+        let catalog = self.ctx.get_catalog(catalog_name).await?;
+        let table = catalog
+            .get_table(self.plan.tenant.as_str(), database, table)
+            .await?;
+        let table_info = table.get_table_info();
+        if table_info.db_type != DatabaseType::NormalDB {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} doesn't support comment",
+                database,
+                table.name()
+            )));
+        }
+
+        let mut new_table_meta = table_info.meta.clone();
+        match column {
+            Some(column) => {
+                if table_info.engine() == VIEW_ENGINE {
+                    return Err(ErrorCode::TableEngineNotSupported(format!(
+                        "{}.{} engine is VIEW that doesn't support column comment",
+                        database,
+                        table.name()
+                    )));
+                }
+                let index = new_table_meta.schema.index_of(column)?;
+                // Tables created before `field_comments` was introduced have no comments.
+                let num_fields = new_table_meta.schema.num_fields();
+                new_table_meta
+                    .field_comments
+                    .resize(num_fields, "".to_string());
+                new_table_meta.field_comments[index] = self.plan.comment.clone();
+            }
+            None => new_table_meta.comment = self.plan.comment.clone(),
+        }
+
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+            deduplicated_label: None,
+        };
+        let res = catalog.update_table_meta(table_info, req).await?;
+        if let Some(share_table_info) = res.share_table_info {
+            save_share_table_info(
+                &self.ctx.get_tenant(),
+                self.ctx.get_data_operator()?.operator(),
+                share_table_info,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CommentOnInterpreter {
+    fn name(&self) -> &str {
+        "CommentOnInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "comment_on_execute");
+
+        validate_tag_object_exists(&self.ctx, &self.plan.object).await?;
+
+        match &self.plan.object {
+            ObjectIdent::Database { catalog, database } => {
+                let catalog = self.ctx.get_catalog(catalog).await?;
+                let req = UpdateDatabaseCommentReq {
+                    name_ident: DatabaseNameIdent {
+                        tenant: self.plan.tenant.clone(),
+                        db_name: database.clone(),
+                    },
+                    comment: self.plan.comment.clone(),
+                };
+                catalog.update_database_comment(req).await?;
+            }
+            ObjectIdent::Table {
+                catalog,
+                database,
+                table,
+            } => {
+                self.comment_on_table(catalog, database, table, None)
+                    .await?;
+            }
+            ObjectIdent::View {
+                catalog,
+                database,
+                view,
+            } => {
+                self.comment_on_table(catalog, database, view, None).await?;
+            }
+            ObjectIdent::Column {
+                catalog,
+                database,
+                table,
+                column,
+            } => {
+                self.comment_on_table(catalog, database, table, Some(column))
+                    .await?;
+            }
+            ObjectIdent::Stage { stage } => {
+                UserApiProvider::instance()
+                    .update_stage_comment(&self.plan.tenant, stage, self.plan.comment.clone())
+                    .await?;
+            }
+            ObjectIdent::User { .. } => {
+                return Err(ErrorCode::Unimplemented("Cannot comment on user"));
+            }
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use common_exception::ErrorCode;
@@ -23,7 +25,10 @@ use common_sharing::ShareEndpointManager;
 use common_sql::plans::CreateDatabasePlan;
 use common_users::UserApiProvider;
 use log::debug;
+use once_cell::sync::Lazy;
+use storages_common_table_meta::table::OPT_KEY_DATA_RETENTION_TIME_IN_DAYS;
 
+use super::interpreter_table_create::is_valid_data_retention_time_in_days;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -110,6 +115,7 @@ impl Interpreter for CreateDatabaseInterpreter {
                 quota.max_databases
             )));
         };
+        is_valid_database_options(&self.plan.meta.options)?;
         // if create from other tenant, check from share endpoint
         if let Some(ref share_name) = self.plan.meta.from_share {
             self.check_create_database_from_share(&tenant, share_name)
@@ -120,3 +126,21 @@ impl Interpreter for CreateDatabaseInterpreter {
         Ok(PipelineBuildResult::create())
     }
 }
+
+/// Option keys that can occur in 'create database statement'.
+pub static CREATE_DATABASE_OPTIONS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
+    let mut r = HashSet::new();
+    r.insert(OPT_KEY_DATA_RETENTION_TIME_IN_DAYS);
+    r
+});
+
+pub fn is_valid_database_options(options: &BTreeMap<String, String>) -> Result<()> {
+    for key in options.keys() {
+        if !CREATE_DATABASE_OPTIONS.contains(key.to_lowercase().as_str()) {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "database option {key} is invalid",
+            )));
+        }
+    }
+    is_valid_data_retention_time_in_days(options)
+}
@@ -16,8 +16,10 @@ use std::sync::Arc;
 
 use common_exception::Result;
 use common_sql::plans::DropDatabasePlan;
+use common_sql::plans::ObjectIdent;
 use common_storages_share::save_share_spec;
 
+use crate::interpreters::common::drop_object_tag_references;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -44,6 +46,13 @@ impl Interpreter for DropDatabaseInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
         let resp = catalog.drop_database(self.plan.clone().into()).await?;
+
+        drop_object_tag_references(&self.plan.tenant, &ObjectIdent::Database {
+            catalog: self.plan.catalog.clone(),
+            database: self.plan.database.clone(),
+        })
+        .await?;
+
         if let Some(spec_vec) = resp.spec_vec {
             let mut share_table_into = Vec::with_capacity(spec_vec.len());
             for share_spec in &spec_vec {
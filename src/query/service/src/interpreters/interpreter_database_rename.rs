@@ -17,8 +17,10 @@ use std::sync::Arc;
 use common_exception::Result;
 use common_meta_app::schema::DatabaseNameIdent;
 use common_meta_app::schema::RenameDatabaseReq;
+use common_sql::plans::ObjectIdent;
 use common_sql::plans::RenameDatabasePlan;
 
+use crate::interpreters::common::rename_object_tag_references;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -50,12 +52,25 @@ impl Interpreter for RenameDatabaseInterpreter {
                 .rename_database(RenameDatabaseReq {
                     if_exists: entity.if_exists,
                     name_ident: DatabaseNameIdent {
-                        tenant,
+                        tenant: tenant.clone(),
                         db_name: entity.database.clone(),
                     },
                     new_db_name: entity.new_database.clone(),
                 })
                 .await?;
+
+            rename_object_tag_references(
+                &tenant,
+                &ObjectIdent::Database {
+                    catalog: entity.catalog.clone(),
+                    database: entity.database.clone(),
+                },
+                &ObjectIdent::Database {
+                    catalog: entity.catalog.clone(),
+                    database: entity.new_database.clone(),
+                },
+            )
+            .await?;
         }
 
         Ok(PipelineBuildResult::create())
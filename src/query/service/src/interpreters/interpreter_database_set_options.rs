@@ -0,0 +1,75 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::DatabaseNameIdent;
+use common_meta_app::schema::UpsertDatabaseOptionReq;
+use common_sql::plans::SetDatabaseOptionsPlan;
+use log::debug;
+
+use super::interpreter_database_create::is_valid_database_options;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct SetDatabaseOptionsInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: SetDatabaseOptionsPlan,
+}
+
+impl SetDatabaseOptionsInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: SetDatabaseOptionsPlan) -> Result<Self> {
+        Ok(SetDatabaseOptionsInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for SetDatabaseOptionsInterpreter {
+    fn name(&self) -> &str {
+        "SetDatabaseOptionsInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "set_database_options_execute");
+
+        is_valid_database_options(&self.plan.set_options)?;
+
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let req = UpsertDatabaseOptionReq {
+            name_ident: DatabaseNameIdent {
+                tenant: self.plan.tenant.clone(),
+                db_name: self.plan.database.clone(),
+            },
+            options: self
+                .plan
+                .set_options
+                .iter()
+                .map(|(k, v)| (k.to_lowercase(), Some(v.clone())))
+                .collect(),
+        };
+        match catalog.upsert_database_option(req).await {
+            Ok(_) => Ok(PipelineBuildResult::create()),
+            Err(e) if self.plan.if_exists && e.code() == ErrorCode::UNKNOWN_DATABASE => {
+                Ok(PipelineBuildResult::create())
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
@@ -74,6 +74,9 @@ impl Interpreter for ShowCreateDatabaseInterpreter {
                 info.push_str(&engine);
             }
         }
+        for (k, v) in db.options().iter() {
+            write!(info, " {}='{}'", k.to_uppercase(), v).expect("write to string must succeed");
+        }
 
         PipelineBuildResult::from_blocks(vec![DataBlock::new(
             vec![
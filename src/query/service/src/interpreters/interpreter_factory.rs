@@ -23,6 +23,8 @@ use super::interpreter_catalog_create::CreateCatalogInterpreter;
 use super::interpreter_index_create::CreateIndexInterpreter;
 use super::interpreter_index_drop::DropIndexInterpreter;
 use super::interpreter_share_desc::DescShareInterpreter;
+use super::interpreter_table_index_create::CreateTableIndexInterpreter;
+use super::interpreter_table_index_drop::DropTableIndexInterpreter;
 use super::interpreter_table_set_options::SetOptionsInterpreter;
 use super::interpreter_user_stage_drop::DropUserStageInterpreter;
 use super::*;
@@ -41,6 +43,7 @@ use crate::interpreters::CreateShareEndpointInterpreter;
 use crate::interpreters::CreateShareInterpreter;
 use crate::interpreters::DropShareInterpreter;
 use crate::interpreters::DropUserInterpreter;
+use crate::interpreters::InterpreterAuditLog;
 use crate::interpreters::SetRoleInterpreter;
 use crate::interpreters::UpdateInterpreter;
 use crate::sessions::QueryContext;
@@ -58,8 +61,10 @@ impl InterpreterFactory {
         let access_checker = Accessor::create(ctx.clone());
         access_checker.check(plan).await.map_err(|e| {
             error!("Access.denied(v2): {:?}", e);
+            InterpreterAuditLog::log_denied(&ctx, plan, &e);
             e
         })?;
+        InterpreterAuditLog::attach(&ctx, plan);
         Self::get_inner(ctx, plan)
     }
 
@@ -150,6 +155,10 @@ impl InterpreterFactory {
                 RenameDatabaseInterpreter::try_create(ctx, *rename_database.clone())?,
             )),
 
+            Plan::SetDatabaseOptions(set_database_options) => Ok(Arc::new(
+                SetDatabaseOptionsInterpreter::try_create(ctx, *set_database_options.clone())?,
+            )),
+
             // Tables
             Plan::ShowCreateTable(show_create_table) => Ok(Arc::new(
                 ShowCreateTableInterpreter::try_create(ctx, *show_create_table.clone())?,
@@ -189,12 +198,24 @@ impl InterpreterFactory {
             Plan::DropTableColumn(drop_table_column) => Ok(Arc::new(
                 DropTableColumnInterpreter::try_create(ctx, *drop_table_column.clone())?,
             )),
+            Plan::AddTableRowAccessPolicy(p) => Ok(Arc::new(
+                AddTableRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropTableRowAccessPolicy(p) => Ok(Arc::new(
+                DropTableRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
             Plan::AlterTableClusterKey(alter_table_cluster_key) => Ok(Arc::new(
                 AlterTableClusterKeyInterpreter::try_create(ctx, *alter_table_cluster_key.clone())?,
             )),
             Plan::DropTableClusterKey(drop_table_cluster_key) => Ok(Arc::new(
                 DropTableClusterKeyInterpreter::try_create(ctx, *drop_table_cluster_key.clone())?,
             )),
+            Plan::DropTablePartition(drop_table_partition) => Ok(Arc::new(
+                DropTablePartitionInterpreter::try_create(ctx, *drop_table_partition.clone())?,
+            )),
+            Plan::RotateTableKey(rotate_table_key) => Ok(Arc::new(
+                RotateTableKeyInterpreter::try_create(ctx, *rotate_table_key.clone())?,
+            )),
             Plan::ReclusterTable(recluster_table) => Ok(Arc::new(
                 ReclusterTableInterpreter::try_create(ctx, *recluster_table.clone())?,
             )),
@@ -248,6 +269,14 @@ impl InterpreterFactory {
                 ctx,
                 *index.clone(),
             )?)),
+            Plan::CreateTableIndex(index) => Ok(Arc::new(CreateTableIndexInterpreter::try_create(
+                ctx,
+                *index.clone(),
+            )?)),
+            Plan::DropTableIndex(index) => Ok(Arc::new(DropTableIndexInterpreter::try_create(
+                ctx,
+                *index.clone(),
+            )?)),
             // Virtual columns
             Plan::CreateVirtualColumns(create_virtual_columns) => Ok(Arc::new(
                 CreateVirtualColumnsInterpreter::try_create(ctx, *create_virtual_columns.clone())?,
@@ -444,6 +473,24 @@ impl InterpreterFactory {
                 *p.clone(),
             )?)),
 
+            Plan::CreateRowAccessPolicy(p) => Ok(Arc::new(
+                CreateRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropRowAccessPolicy(p) => Ok(Arc::new(
+                DropRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DescRowAccessPolicy(p) => Ok(Arc::new(
+                DescRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+
+            Plan::CreateTag(p) => Ok(Arc::new(CreateTagInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::DropTag(p) => Ok(Arc::new(DropTagInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::AlterObjectTag(p) => Ok(Arc::new(AlterObjectTagInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::CommentOn(p) => Ok(Arc::new(CommentOnInterpreter::try_create(ctx, *p.clone())?)),
+
             Plan::CreateNetworkPolicy(p) => Ok(Arc::new(
                 CreateNetworkPolicyInterpreter::try_create(ctx, *p.clone())?,
             )),
@@ -225,13 +225,14 @@ impl Interpreter for RefreshIndexInterpreter {
             }
         };
 
-        let data_accessor = self.ctx.get_data_operator()?;
         let fuse_table = FuseTable::do_create(self.plan.table_info.clone())?;
         let fuse_table: Arc<FuseTable> = fuse_table.into();
+        // the operator of the table, which encrypts the index files of encrypted tables.
+        let operator = fuse_table.get_operator();
 
         // generate new `DataSourcePlan` that skip refreshed parts.
         let new_read_source = self
-            .get_read_source(&query_plan, fuse_table.clone(), data_accessor.operator())
+            .get_read_source(&query_plan, fuse_table.clone(), operator.clone())
             .await?;
 
         if new_read_source.is_none() {
@@ -298,7 +299,7 @@ impl Interpreter for RefreshIndexInterpreter {
         build_res.main_pipeline.add_sink(|input| {
             AggIndexSink::try_create(
                 input,
-                data_accessor.operator(),
+                operator.clone(),
                 self.plan.index_id,
                 write_settings.clone(),
                 sink_schema.clone(),
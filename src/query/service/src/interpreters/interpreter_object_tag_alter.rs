@@ -0,0 +1,84 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_exception::Result;
+use common_meta_app::principal::TagReference;
+use common_sql::plans::AlterObjectTagPlan;
+use common_sql::plans::ObjectTagAction;
+use common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::common::validate_tag_object_exists;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct AlterObjectTagInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterObjectTagPlan,
+}
+
+impl AlterObjectTagInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterObjectTagPlan) -> Result<Self> {
+        Ok(AlterObjectTagInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterObjectTagInterpreter {
+    fn name(&self) -> &str {
+        "AlterObjectTagInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "alter_object_tag_execute");
+
+        validate_tag_object_exists(&self.ctx, &self.plan.object).await?;
+
+        let tenant = self.plan.tenant.as_str();
+        let object_domain = self.plan.object.domain();
+        let object_name = self.plan.object.name();
+        let user_mgr = UserApiProvider::instance();
+        match &self.plan.action {
+            ObjectTagAction::Set(tags) => {
+                for (tag_name, tag_value) in tags {
+                    let reference = TagReference {
+                        tag_name: tag_name.clone(),
+                        tag_value: tag_value.clone(),
+                        object_domain: object_domain.to_string(),
+                        object_name: object_name.clone(),
+                        create_on: Utc::now(),
+                    };
+                    user_mgr.set_tag_reference(tenant, reference).await?;
+                }
+            }
+            ObjectTagAction::Unset(tags) => {
+                for tag_name in tags {
+                    user_mgr
+                        .unset_tag_reference(tenant, tag_name, object_domain, &object_name)
+                        .await?;
+                }
+            }
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -37,10 +37,17 @@ use common_sql::field_default_value;
 use common_sql::plans::CreateTablePlan;
 use common_sql::plans::PREDICATE_COLUMN_NAME;
 use common_sql::BloomIndexColumns;
+use common_storage::encryption::generate_table_keyring;
+use common_storage::encryption::ENCRYPTION_ALGORITHM_AES_256_GCM;
 use common_storage::DataOperator;
+use common_storage::EncryptionKeyProvider;
 use common_storages_fuse::io::MetaReaders;
+use common_storages_fuse::FuseDeleteMode;
+use common_storages_fuse::FuseStorageFormat;
 use common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use common_storages_fuse::FUSE_OPT_KEY_DELETE_MODE;
+use common_storages_fuse::FUSE_OPT_KEY_ICEBERG_METADATA;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
@@ -50,12 +57,17 @@ use log::error;
 use once_cell::sync::Lazy;
 use storages_common_cache::LoadParams;
 use storages_common_index::BloomIndex;
+use storages_common_index::NgramIndex;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::Versioned;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_COMMENT;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_DATA_RETENTION_TIME_IN_DAYS;
+use storages_common_table_meta::table::OPT_KEY_ENCRYPTION;
+use storages_common_table_meta::table::OPT_KEY_ENCRYPTION_KEYS;
 use storages_common_table_meta::table::OPT_KEY_ENGINE;
+use storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
@@ -247,6 +259,7 @@ impl CreateTableInterpreter {
             part_prefix: self.plan.part_prefix.clone(),
             options: self.plan.options.clone(),
             default_cluster_key: None,
+            partition_key: self.plan.partition_key.clone(),
             field_comments: self.plan.field_comments.clone(),
             drop_on: None,
             statistics: if let Some(stat) = statistics {
@@ -259,6 +272,10 @@ impl CreateTableInterpreter {
 
         is_valid_block_per_segment(&table_meta.options)?;
         is_valid_row_per_block(&table_meta.options)?;
+        is_valid_data_retention_time_in_days(&table_meta.options)?;
+        is_valid_delete_mode(&table_meta.options)?;
+        is_valid_iceberg_metadata(&table_meta.options)?;
+        is_valid_encryption(&table_meta.options)?;
         // check bloom_index_columns.
         is_valid_bloom_index_columns(&table_meta.options, schema)?;
 
@@ -272,6 +289,15 @@ impl CreateTableInterpreter {
             }
         }
 
+        // the data key of the table, wrapped by the master key.
+        if table_meta.options.contains_key(OPT_KEY_ENCRYPTION) {
+            let provider = EncryptionKeyProvider::instance().provider()?;
+            table_meta.options.insert(
+                OPT_KEY_ENCRYPTION_KEYS.to_string(),
+                generate_table_keyring(provider.as_ref())?,
+            );
+        }
+
         if let Some(cluster_key) = &self.plan.cluster_key {
             table_meta = table_meta.push_cluster_key(cluster_key.clone());
         }
@@ -354,12 +380,17 @@ pub static CREATE_TABLE_OPTIONS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     r.insert(FUSE_OPT_KEY_ROW_PER_BLOCK);
     r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
+    r.insert(FUSE_OPT_KEY_DELETE_MODE);
+    r.insert(FUSE_OPT_KEY_ICEBERG_METADATA);
 
     r.insert(OPT_KEY_BLOOM_INDEX_COLUMNS);
+    r.insert(OPT_KEY_NGRAM_INDEX_COLUMNS);
     r.insert(OPT_KEY_TABLE_COMPRESSION);
     r.insert(OPT_KEY_STORAGE_FORMAT);
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_COMMENT);
+    r.insert(OPT_KEY_DATA_RETENTION_TIME_IN_DAYS);
+    r.insert(OPT_KEY_ENCRYPTION);
 
     r.insert(OPT_KEY_ENGINE);
 
@@ -424,12 +455,93 @@ pub fn is_valid_row_per_block(options: &BTreeMap<String, String>) -> Result<()>
     Ok(())
 }
 
+pub fn is_valid_data_retention_time_in_days(options: &BTreeMap<String, String>) -> Result<()> {
+    // check data_retention_time_in_days is a positive integer.
+    if let Some(value) = options.get(OPT_KEY_DATA_RETENTION_TIME_IN_DAYS) {
+        if !matches!(value.parse::<u64>(), Ok(days) if days > 0) {
+            let error_str = format!(
+                "invalid {} option {}, must be a positive integer",
+                OPT_KEY_DATA_RETENTION_TIME_IN_DAYS, value
+            );
+            error!("{}", error_str);
+            return Err(ErrorCode::TableOptionInvalid(error_str));
+        }
+    }
+    Ok(())
+}
+
+pub fn is_valid_delete_mode(options: &BTreeMap<String, String>) -> Result<()> {
+    if let Some(value) = options.get(FUSE_OPT_KEY_DELETE_MODE) {
+        value.parse::<FuseDeleteMode>()?;
+    }
+    Ok(())
+}
+
+pub fn is_valid_iceberg_metadata(options: &BTreeMap<String, String>) -> Result<()> {
+    let Some(value) = options.get(FUSE_OPT_KEY_ICEBERG_METADATA) else {
+        return Ok(());
+    };
+    let enabled = value.to_lowercase().parse::<bool>().map_err(|_| {
+        ErrorCode::TableOptionInvalid(format!(
+            "invalid {} option {}, must be true or false",
+            FUSE_OPT_KEY_ICEBERG_METADATA, value
+        ))
+    })?;
+    if !enabled {
+        return Ok(());
+    }
+
+    // the blocks are referenced as iceberg data files in place, they must be parquet
+    // files, and rows must not be deleted by deletion vectors.
+    if let Some(format) = options.get(OPT_KEY_STORAGE_FORMAT) {
+        if !matches!(
+            format.parse::<FuseStorageFormat>()?,
+            FuseStorageFormat::Parquet
+        ) {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "{} requires the parquet storage format",
+                FUSE_OPT_KEY_ICEBERG_METADATA
+            )));
+        }
+    }
+    if options.contains_key(OPT_KEY_ENCRYPTION) {
+        return Err(ErrorCode::TableOptionInvalid(format!(
+            "{} can not be enabled for encrypted tables",
+            FUSE_OPT_KEY_ICEBERG_METADATA
+        )));
+    }
+    if let Some(mode) = options.get(FUSE_OPT_KEY_DELETE_MODE) {
+        if mode.parse::<FuseDeleteMode>()? == FuseDeleteMode::MergeOnRead {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "{} can not be enabled with the merge_on_read delete mode",
+                FUSE_OPT_KEY_ICEBERG_METADATA
+            )));
+        }
+    }
+    Ok(())
+}
+
+pub fn is_valid_encryption(options: &BTreeMap<String, String>) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_ENCRYPTION) {
+        if !value.eq_ignore_ascii_case(ENCRYPTION_ALGORITHM_AES_256_GCM) {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "invalid {} option {}, must be '{}'",
+                OPT_KEY_ENCRYPTION, value, ENCRYPTION_ALGORITHM_AES_256_GCM
+            )));
+        }
+    }
+    Ok(())
+}
+
 pub fn is_valid_bloom_index_columns(
     options: &BTreeMap<String, String>,
     schema: TableSchemaRef,
 ) -> Result<()> {
     if let Some(value) = options.get(OPT_KEY_BLOOM_INDEX_COLUMNS) {
-        BloomIndexColumns::verify_definition(value, schema, BloomIndex::supported_type)?;
+        BloomIndexColumns::verify_definition(value, schema.clone(), BloomIndex::supported_type)?;
+    }
+    if let Some(value) = options.get(OPT_KEY_NGRAM_INDEX_COLUMNS) {
+        BloomIndexColumns::verify_definition(value, schema, NgramIndex::supported_type)?;
     }
     Ok(())
 }
@@ -19,9 +19,11 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::schema::DropTableByIdReq;
 use common_sql::plans::DropTablePlan;
+use common_sql::plans::ObjectIdent;
 use common_storages_share::save_share_spec;
 use common_storages_view::view_table::VIEW_ENGINE;
 
+use crate::interpreters::common::drop_object_tag_references;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -77,6 +79,13 @@ impl Interpreter for DropTableInterpreter {
                 })
                 .await?;
 
+            drop_object_tag_references(&self.plan.tenant, &ObjectIdent::Table {
+                catalog: self.plan.catalog.clone(),
+                database: self.plan.database.clone(),
+                table: self.plan.table.clone(),
+            })
+            .await?;
+
             // if `plan.all`, truncate, then purge the historical data
             if self.plan.all {
                 let purge = true;
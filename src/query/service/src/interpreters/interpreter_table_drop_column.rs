@@ -21,12 +21,15 @@ use common_meta_app::schema::DatabaseType;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_types::MatchSeq;
 use common_sql::plans::DropTableColumnPlan;
+use common_sql::plans::ObjectIdent;
 use common_sql::BloomIndexColumns;
 use common_storages_share::save_share_table_info;
 use common_storages_view::view_table::VIEW_ENGINE;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::common::drop_object_tag_references;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -87,19 +90,38 @@ impl Interpreter for DropTableColumnInterpreter {
             )?;
         }
 
+        // The column can't be dropped while an inverted index is built on it.
+        let column_id = table_info
+            .schema()
+            .field_with_name(self.plan.column.as_str())?
+            .column_id();
+        if let Some(index) = table_info
+            .meta
+            .indexes
+            .values()
+            .find(|index| index.column_ids.contains(&column_id))
+        {
+            return Err(ErrorCode::ColumnReferencedByInvertedIndex(format!(
+                "column `{}` is used by inverted index `{}`, drop the index first",
+                self.plan.column, index.name
+            )));
+        }
+
         let catalog = self.ctx.get_catalog(catalog_name).await?;
         let mut new_table_meta = table.get_table_info().meta.clone();
         new_table_meta.drop_column(&self.plan.column)?;
 
         // update table options
         let opts = &mut new_table_meta.options;
-        if let Some(value) = opts.get_mut(OPT_KEY_BLOOM_INDEX_COLUMNS) {
-            let bloom_index_cols = value.parse::<BloomIndexColumns>()?;
-            if let BloomIndexColumns::Specify(mut cols) = bloom_index_cols {
-                if let Some(pos) = cols.iter().position(|x| *x == self.plan.column) {
-                    // remove from the bloom index columns.
-                    cols.remove(pos);
-                    *value = cols.join(",");
+        for key in [OPT_KEY_BLOOM_INDEX_COLUMNS, OPT_KEY_NGRAM_INDEX_COLUMNS] {
+            if let Some(value) = opts.get_mut(key) {
+                let index_cols = value.parse::<BloomIndexColumns>()?;
+                if let BloomIndexColumns::Specify(mut cols) = index_cols {
+                    if let Some(pos) = cols.iter().position(|x| *x == self.plan.column) {
+                        // remove from the index columns.
+                        cols.remove(pos);
+                        *value = cols.join(",");
+                    }
                 }
             }
         }
@@ -116,6 +138,15 @@ impl Interpreter for DropTableColumnInterpreter {
         };
 
         let res = catalog.update_table_meta(table_info, req).await?;
+
+        drop_object_tag_references(&self.ctx.get_tenant(), &ObjectIdent::Column {
+            catalog: self.plan.catalog.clone(),
+            database: self.plan.database.clone(),
+            table: self.plan.table.clone(),
+            column: self.plan.column.clone(),
+        })
+        .await?;
+
         if let Some(share_table_info) = res.share_table_info {
             save_share_table_info(
                 &self.ctx.get_tenant(),
@@ -0,0 +1,163 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::scalars::Tokenizer;
+use common_meta_app::schema::DatabaseType;
+use common_meta_app::schema::TableIndex;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::CreateTableIndexPlan;
+use common_storages_fuse::FuseTable;
+use common_storages_share::save_share_table_info;
+use storages_common_index::InvertedIndex;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEX_TOKENIZER;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateTableIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateTableIndexPlan,
+}
+
+impl CreateTableIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateTableIndexPlan) -> Result<Self> {
+        Ok(CreateTableIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateTableIndexInterpreter {
+    fn name(&self) -> &str {
+        "CreateTableIndexInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.as_str();
+        let db_name = self.plan.database.as_str();
+        let tbl_name = self.plan.table.as_str();
+        let table = self
+            .ctx
+            .get_catalog(catalog_name)
+            .await?
+            .get_table(self.ctx.get_tenant().as_str(), db_name, tbl_name)
+            .await?;
+
+        // only fuse tables can build inverted indexes.
+        FuseTable::try_from_table(table.as_ref())?;
+
+        let table_info = table.get_table_info();
+        if table_info.db_type != DatabaseType::NormalDB {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} doesn't support alter",
+                &self.plan.database, &self.plan.table
+            )));
+        }
+
+        let index_name = self.plan.index_name.clone();
+        if table_info.meta.indexes.contains_key(&index_name) {
+            return if self.plan.if_not_exists {
+                Ok(PipelineBuildResult::create())
+            } else {
+                Err(ErrorCode::IndexAlreadyExists(format!(
+                    "inverted index `{}` already exists on table `{}`",
+                    index_name, tbl_name
+                )))
+            };
+        }
+
+        let mut options = self.plan.options.clone();
+        for key in options.keys() {
+            if key != OPT_KEY_INVERTED_INDEX_TOKENIZER {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "invalid inverted index option `{}`, only `{}` is supported",
+                    key, OPT_KEY_INVERTED_INDEX_TOKENIZER
+                )));
+            }
+        }
+        let tokenizer = match options.get(OPT_KEY_INVERTED_INDEX_TOKENIZER) {
+            Some(name) => Tokenizer::try_create(name)?,
+            None => Tokenizer::English,
+        };
+        options.insert(
+            OPT_KEY_INVERTED_INDEX_TOKENIZER.to_string(),
+            tokenizer.name().to_string(),
+        );
+
+        let schema = table_info.schema();
+        let mut column_ids = Vec::with_capacity(self.plan.columns.len());
+        for column in self.plan.columns.iter() {
+            let field = schema.field_with_name(column)?;
+            if !InvertedIndex::supported_type(field.data_type()) {
+                return Err(ErrorCode::UnsupportedIndex(format!(
+                    "inverted index only supports String columns, but column `{}` is {}",
+                    column,
+                    field.data_type()
+                )));
+            }
+            let column_id = field.column_id();
+            if let Some(index) = table_info
+                .meta
+                .indexes
+                .values()
+                .find(|index| index.column_ids.contains(&column_id))
+            {
+                return Err(ErrorCode::UnsupportedIndex(format!(
+                    "column `{}` already belongs to inverted index `{}`",
+                    column, index.name
+                )));
+            }
+            if !column_ids.contains(&column_id) {
+                column_ids.push(column_id);
+            }
+        }
+
+        let catalog = self.ctx.get_catalog(catalog_name).await?;
+        let mut new_table_meta = table_info.meta.clone();
+        new_table_meta
+            .indexes
+            .insert(index_name.clone(), TableIndex {
+                name: index_name,
+                column_ids,
+                options,
+            });
+
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+            deduplicated_label: None,
+        };
+
+        let res = catalog.update_table_meta(table_info, req).await?;
+        if let Some(share_table_info) = res.share_table_info {
+            save_share_table_info(
+                &self.ctx.get_tenant(),
+                self.ctx.get_data_operator()?.operator(),
+                share_table_info,
+            )
+            .await?;
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
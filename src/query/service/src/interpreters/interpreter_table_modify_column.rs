@@ -48,7 +48,9 @@ use common_storages_view::view_table::VIEW_ENGINE;
 use common_users::UserApiProvider;
 use data_mask_feature::get_datamask_handler;
 use storages_common_index::BloomIndex;
+use storages_common_index::NgramIndex;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
 use table_lock::TableLockHandlerWrapper;
 
 use super::common::check_referenced_computed_columns;
@@ -217,6 +219,12 @@ impl ModifyTableColumnInterpreter {
                 bloom_index_cols = cols;
             }
         }
+        let mut ngram_index_cols = vec![];
+        if let Some(v) = table_info.options().get(OPT_KEY_NGRAM_INDEX_COLUMNS) {
+            if let BloomIndexColumns::Specify(cols) = v.parse::<BloomIndexColumns>()? {
+                ngram_index_cols = cols;
+            }
+        }
 
         for (column, type_name) in column_name_types {
             let column = column.to_string();
@@ -243,6 +251,14 @@ impl ModifyTableColumnInterpreter {
                             new_type
                         )));
                     }
+                    if ngram_index_cols.iter().any(|v| v.as_str() == column)
+                        && !NgramIndex::supported_type(&new_type)
+                    {
+                        return Err(ErrorCode::TableOptionInvalid(format!(
+                            "Unsupported data type '{}' for ngram index",
+                            new_type
+                        )));
+                    }
                     new_schema.fields[i].data_type = new_type;
                 }
             } else {
@@ -17,8 +17,10 @@ use std::sync::Arc;
 use common_exception::Result;
 use common_meta_app::schema::RenameTableReq;
 use common_meta_app::schema::TableNameIdent;
+use common_sql::plans::ObjectIdent;
 use common_sql::plans::RenameTablePlan;
 
+use crate::interpreters::common::rename_object_tag_references;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -60,6 +62,21 @@ impl Interpreter for RenameTableInterpreter {
             })
             .await?;
 
+        rename_object_tag_references(
+            &self.plan.tenant,
+            &ObjectIdent::Table {
+                catalog: self.plan.catalog.clone(),
+                database: self.plan.database.clone(),
+                table: self.plan.table.clone(),
+            },
+            &ObjectIdent::Table {
+                catalog: self.plan.catalog.clone(),
+                database: self.plan.new_database.clone(),
+                table: self.plan.new_table.clone(),
+            },
+        )
+        .await?;
+
         Ok(PipelineBuildResult::create())
     }
 }
@@ -20,13 +20,16 @@ use common_expression::DataSchema;
 use common_meta_app::schema::DatabaseType;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_types::MatchSeq;
+use common_sql::plans::ObjectIdent;
 use common_sql::plans::RenameTableColumnPlan;
 use common_sql::BloomIndexColumns;
 use common_storages_share::save_share_table_info;
 use common_storages_view::view_table::VIEW_ENGINE;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::common::rename_object_tag_references;
 use crate::interpreters::interpreter_table_create::is_valid_column;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -101,13 +104,15 @@ impl Interpreter for RenameTableColumnInterpreter {
 
             // update table options
             let opts = &mut new_table_meta.options;
-            if let Some(value) = opts.get_mut(OPT_KEY_BLOOM_INDEX_COLUMNS) {
-                let bloom_index_cols = value.parse::<BloomIndexColumns>()?;
-                if let BloomIndexColumns::Specify(mut cols) = bloom_index_cols {
-                    if let Some(pos) = cols.iter().position(|x| *x == self.plan.old_column) {
-                        // replace the bloom index columns with new column name.
-                        cols[pos] = self.plan.new_column.clone();
-                        *value = cols.join(",");
+            for key in [OPT_KEY_BLOOM_INDEX_COLUMNS, OPT_KEY_NGRAM_INDEX_COLUMNS] {
+                if let Some(value) = opts.get_mut(key) {
+                    let index_cols = value.parse::<BloomIndexColumns>()?;
+                    if let BloomIndexColumns::Specify(mut cols) = index_cols {
+                        if let Some(pos) = cols.iter().position(|x| *x == self.plan.old_column) {
+                            // replace the index columns with new column name.
+                            cols[pos] = self.plan.new_column.clone();
+                            *value = cols.join(",");
+                        }
                     }
                 }
             }
@@ -125,6 +130,19 @@ impl Interpreter for RenameTableColumnInterpreter {
 
             let res = catalog.update_table_meta(table_info, req).await?;
 
+            let column = |column: &str| ObjectIdent::Column {
+                catalog: self.plan.catalog.clone(),
+                database: self.plan.database.clone(),
+                table: self.plan.table.clone(),
+                column: column.to_string(),
+            };
+            rename_object_tag_references(
+                &self.plan.tenant,
+                &column(&self.plan.old_column),
+                &column(&self.plan.new_column),
+            )
+            .await?;
+
             if let Some(share_table_info) = res.share_table_info {
                 save_share_table_info(
                     &self.ctx.get_tenant(),
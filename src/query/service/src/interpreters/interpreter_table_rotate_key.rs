@@ -0,0 +1,82 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::RotateTableKeyPlan;
+use common_storage::encryption::rotate_table_keyring;
+use common_storage::EncryptionKeyProvider;
+use storages_common_table_meta::table::OPT_KEY_ENCRYPTION_KEYS;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct RotateTableKeyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RotateTableKeyPlan,
+}
+
+impl RotateTableKeyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RotateTableKeyPlan) -> Result<Self> {
+        Ok(RotateTableKeyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RotateTableKeyInterpreter {
+    fn name(&self) -> &str {
+        "RotateTableKeyInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog).await?;
+        let table = catalog
+            .get_table(plan.tenant.as_str(), &plan.database, &plan.table)
+            .await?;
+
+        let Some(keyring) = table.options().get(OPT_KEY_ENCRYPTION_KEYS) else {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "table {}.{} is not encrypted",
+                plan.database, plan.table
+            )));
+        };
+
+        // objects written from now on are encrypted with the new data key,
+        // former data keys are kept to read the objects written with them.
+        let provider = EncryptionKeyProvider::instance().provider()?;
+        let keyring = rotate_table_keyring(provider.as_ref(), keyring)?;
+
+        let mut options = HashMap::new();
+        options.insert(OPT_KEY_ENCRYPTION_KEYS.to_string(), Some(keyring));
+        let req = UpsertTableOptionReq {
+            table_id: table.get_id(),
+            seq: MatchSeq::Exact(table.get_table_info().ident.seq),
+            options,
+        };
+        catalog
+            .upsert_table_option(plan.tenant.as_str(), &plan.database, req)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -20,14 +20,19 @@ use common_exception::Result;
 use common_meta_app::schema::UpsertTableOptionReq;
 use common_meta_types::MatchSeq;
 use common_sql::plans::SetOptionsPlan;
+use common_storages_fuse::FuseTable;
 use common_storages_fuse::TableContext;
 use log::error;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_ENCRYPTION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 
 use super::interpreter_table_create::is_valid_block_per_segment;
 use super::interpreter_table_create::is_valid_bloom_index_columns;
 use super::interpreter_table_create::is_valid_create_opt;
+use super::interpreter_table_create::is_valid_data_retention_time_in_days;
+use super::interpreter_table_create::is_valid_delete_mode;
+use super::interpreter_table_create::is_valid_iceberg_metadata;
 use super::interpreter_table_create::is_valid_row_per_block;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -58,6 +63,10 @@ impl Interpreter for SetOptionsInterpreter {
         is_valid_block_per_segment(&self.plan.set_options)?;
         // check row_per_block
         is_valid_row_per_block(&self.plan.set_options)?;
+        // check data_retention_time_in_days
+        is_valid_data_retention_time_in_days(&self.plan.set_options)?;
+        // check delete_mode
+        is_valid_delete_mode(&self.plan.set_options)?;
         // check storage_format
         let error_str = "invalid opt for fuse table in alter table statement";
         if self.plan.set_options.get(OPT_KEY_STORAGE_FORMAT).is_some() {
@@ -67,6 +76,13 @@ impl Interpreter for SetOptionsInterpreter {
                 OPT_KEY_STORAGE_FORMAT
             )));
         }
+        if self.plan.set_options.get(OPT_KEY_ENCRYPTION).is_some() {
+            error!("{}", &error_str);
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "can't change {} for alter table statement",
+                OPT_KEY_ENCRYPTION
+            )));
+        }
         if self.plan.set_options.get(OPT_KEY_DATABASE_ID).is_some() {
             error!("{}", &error_str);
             return Err(ErrorCode::TableOptionInvalid(format!(
@@ -98,8 +114,12 @@ impl Interpreter for SetOptionsInterpreter {
             return Err(ErrorCode::UnknownTable(self.plan.table.as_str()));
         };
 
-        // check bloom_index_columns.
+        // check bloom_index_columns and ngram_index_columns.
         is_valid_bloom_index_columns(&self.plan.set_options, table.schema())?;
+        // check iceberg_metadata against the options the table will end up with.
+        let mut new_options = table.options().clone();
+        new_options.extend(self.plan.set_options.clone());
+        is_valid_iceberg_metadata(&new_options)?;
 
         let req = UpsertTableOptionReq {
             table_id: table.get_id(),
@@ -110,6 +130,16 @@ impl Interpreter for SetOptionsInterpreter {
         catalog
             .upsert_table_option(self.ctx.get_tenant().as_str(), database, req)
             .await?;
+
+        // export the current snapshot right away, later commits keep it in sync.
+        if FuseTable::is_iceberg_metadata_enabled(&new_options) {
+            let table = catalog
+                .get_table(self.ctx.get_tenant().as_str(), database, table.name())
+                .await?;
+            FuseTable::try_from_table(table.as_ref())?
+                .refresh_iceberg_metadata()
+                .await?;
+        }
         Ok(PipelineBuildResult::create())
     }
 }
@@ -161,6 +161,10 @@ impl Interpreter for ShowCreateTableInterpreter {
             table_create_sql.push_str(format!(" CLUSTER BY {}", cluster_keys_str).as_str());
         }
 
+        if let Some(partition_key_str) = &table_info.meta.partition_key {
+            table_create_sql.push_str(format!(" PARTITION BY {}", partition_key_str).as_str());
+        }
+
         let settings = self.ctx.get_settings();
         let hide_options_in_show_create_table = settings
             .get_hide_options_in_show_create_table()
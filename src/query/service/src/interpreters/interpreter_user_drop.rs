@@ -16,9 +16,11 @@ use std::sync::Arc;
 
 use common_exception::Result;
 use common_sql::plans::DropUserPlan;
+use common_sql::plans::ObjectIdent;
 use common_users::UserApiProvider;
 use log::debug;
 
+use crate::interpreters::common::drop_object_tag_references;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -50,8 +52,9 @@ impl Interpreter for DropUserInterpreter {
         let plan = self.plan.clone();
         let tenant = self.ctx.get_tenant();
         UserApiProvider::instance()
-            .drop_user(&tenant, plan.user, plan.if_exists)
+            .drop_user(&tenant, plan.user.clone(), plan.if_exists)
             .await?;
+        drop_object_tag_references(&tenant, &ObjectIdent::User { user: plan.user }).await?;
 
         Ok(PipelineBuildResult::create())
     }
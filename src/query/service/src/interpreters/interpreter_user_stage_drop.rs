@@ -18,11 +18,13 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::StageType;
 use common_sql::plans::DropStagePlan;
+use common_sql::plans::ObjectIdent;
 use common_storages_stage::StageTable;
 use common_users::UserApiProvider;
 use log::debug;
 use log::info;
 
+use crate::interpreters::common::drop_object_tag_references;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -66,6 +68,10 @@ impl Interpreter for DropUserStageInterpreter {
         user_mgr
             .drop_stage(&tenant, &plan.name, plan.if_exists)
             .await?;
+        drop_object_tag_references(&tenant, &ObjectIdent::Stage {
+            stage: plan.name.clone(),
+        })
+        .await?;
 
         if let Ok(stage) = stage {
             if !matches!(&stage.stage_type, StageType::External) {
@@ -18,8 +18,10 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::schema::DropTableByIdReq;
 use common_sql::plans::DropViewPlan;
+use common_sql::plans::ObjectIdent;
 use common_storages_view::view_table::VIEW_ENGINE;
 
+use crate::interpreters::common::drop_object_tag_references;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -78,6 +80,13 @@ impl Interpreter for DropViewInterpreter {
                     tb_id: table.get_id(),
                 })
                 .await?;
+
+            drop_object_tag_references(&self.plan.tenant, &ObjectIdent::View {
+                catalog: self.plan.catalog.clone(),
+                database: self.plan.database.clone(),
+                view: self.plan.view_name.clone(),
+            })
+            .await?;
         };
 
         Ok(PipelineBuildResult::create())
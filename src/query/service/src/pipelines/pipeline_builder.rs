@@ -1423,6 +1423,12 @@ impl PipelineBuilder {
                         .field(order_by[0].offset)
                         .data_type()
                         .remove_nullable();
+                    // DATE and TIMESTAMP are compared by their physical values.
+                    let data_type = match data_type {
+                        DataType::Date => DataType::Number(NumberDataType::Int32),
+                        DataType::Timestamp => DataType::Number(NumberDataType::Int64),
+                        data_type => data_type,
+                    };
                     with_number_mapped_type!(|NUM_TYPE| match data_type {
                         DataType::Number(NumberDataType::NUM_TYPE) => {
                             let start_bound =
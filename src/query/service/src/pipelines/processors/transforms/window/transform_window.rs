@@ -21,9 +21,11 @@ use std::cmp::Ordering;
 use std::collections::VecDeque;
 use std::sync::Arc;
 
+use common_arrow::arrow::buffer::Buffer;
 use common_exception::Result;
 use common_expression::arithmetics_type::ResultTypeOfUnary;
 use common_expression::types::Number;
+use common_expression::types::NumberColumn;
 use common_expression::types::NumberScalar;
 use common_expression::BlockEntry;
 use common_expression::Column;
@@ -164,6 +166,49 @@ impl<T: Number> TransformWindow<T> {
             .unwrap()
     }
 
+    #[inline(always)]
+    fn value_at(&self, index: &RowPtr, column_index: usize) -> Scalar {
+        let value = unsafe {
+            self.column_at(index, column_index)
+                .index_unchecked(index.row)
+        };
+        value.to_owned()
+    }
+
+    /// Find the `n`-th (counting from 1) row of the current frame whose value of column `arg` is not NULL.
+    ///
+    /// Rows are counted from the frame start, or from the frame end if `reverse` is true.
+    fn nth_non_null_row_in_frame(&self, arg: usize, mut n: u64, reverse: bool) -> Option<RowPtr> {
+        if self.frame_start == self.frame_end {
+            return None;
+        }
+
+        let mut cur = if reverse {
+            self.goback_row(self.frame_end)
+        } else {
+            self.frame_start
+        };
+        loop {
+            if !unsafe { self.column_at(&cur, arg).index_unchecked(cur.row) }.is_null() {
+                n -= 1;
+                if n == 0 {
+                    return Some(cur);
+                }
+            }
+            if reverse {
+                if cur == self.frame_start {
+                    return None;
+                }
+                cur = self.goback_row(cur);
+            } else {
+                cur = self.advance_row(cur);
+                if cur == self.frame_end {
+                    return None;
+                }
+            }
+        }
+    }
+
     fn add_rows_within_partition(&self, mut cur: RowPtr, mut n: usize) -> RowPtr {
         debug_assert!(cur.ge(&self.partition_start) && cur.le(&self.partition_end));
 
@@ -527,6 +572,17 @@ impl<T: Number> TransformWindow<T> {
                 };
                 builder.push(ScalarRef::Number(NumberScalar::Float64(percent.into())));
             }
+            WindowFunctionImpl::LagLead(ll) if ll.ignore_nulls && ll.offset > 0 => {
+                let value = match self.nth_non_null_row_in_frame(ll.arg, ll.offset, ll.is_lag) {
+                    Some(row) => self.value_at(&row, ll.arg),
+                    None => match ll.default.clone() {
+                        LagLeadDefault::Null => Scalar::Null,
+                        LagLeadDefault::Index(col) => self.value_at(&self.current_row, col),
+                    },
+                };
+                let builder = &mut self.blocks[self.current_row.block - self.first_block].builder;
+                builder.push(value.as_ref());
+            }
             WindowFunctionImpl::LagLead(ll) => {
                 let value = if self.frame_start == self.frame_end {
                     let default_value = match ll.default.clone() {
@@ -552,6 +608,19 @@ impl<T: Number> TransformWindow<T> {
                 let builder = &mut self.blocks[self.current_row.block - self.first_block].builder;
                 builder.push(value.as_ref());
             }
+            WindowFunctionImpl::NthValue(func) if func.ignore_nulls => {
+                let row = match func.n {
+                    Some(n) => self.nth_non_null_row_in_frame(func.arg, n, false),
+                    // last_value
+                    None => self.nth_non_null_row_in_frame(func.arg, 1, true),
+                };
+                let value = match row {
+                    Some(row) => self.value_at(&row, func.arg),
+                    None => Scalar::Null,
+                };
+                let builder = &mut self.blocks[self.current_row.block - self.first_block].builder;
+                builder.push(value.as_ref());
+            }
             WindowFunctionImpl::NthValue(func) => {
                 let value = if self.frame_start == self.frame_end {
                     Scalar::Null
@@ -792,6 +861,19 @@ where T: Number + ResultTypeOfUnary
         }
     }
 
+    /// Downcast the ORDER BY column of a `RANGE` frame with offsets.
+    ///
+    /// DATE and TIMESTAMP columns are compared by their physical values (days and microseconds).
+    #[inline]
+    fn downcast_order_column(col: &Column) -> Buffer<T> {
+        match col {
+            Column::Date(col) => T::try_downcast_column(&NumberColumn::Int32(col.clone())),
+            Column::Timestamp(col) => T::try_downcast_column(&NumberColumn::Int64(col.clone())),
+            col => T::try_downcast_column(col.as_number().unwrap()),
+        }
+        .unwrap()
+    }
+
     fn advance_frame_start(&mut self) {
         if self.frame_started {
             return;
@@ -1065,18 +1147,10 @@ macro_rules! impl_advance_frame_bound_method {
                         ..
                     } = self.order_by[0];
                     let preceding = asc == is_preceding;
-                    let ref_col = self
-                        .column_at(&self.current_row, offset)
-                        .as_number()
-                        .unwrap();
-                    let ref_col = T::try_downcast_column(ref_col).unwrap();
+                    let ref_col = Self::downcast_order_column(self.column_at(&self.current_row, offset));
                     let ref_v = unsafe { ref_col.get_unchecked(self.current_row.row) };
                     while self.[<frame_ $bound>] < self.partition_end {
-                        let cmp_col = self
-                            .column_at(&self.[<frame_ $bound>], offset)
-                            .as_number()
-                            .unwrap();
-                        let cmp_col = T::try_downcast_column(cmp_col).unwrap();
+                        let cmp_col = Self::downcast_order_column(self.column_at(&self.[<frame_ $bound>], offset));
                         let cmp_v = unsafe { cmp_col.get_unchecked(self.[<frame_ $bound>].row) };
                         let mut ordering = Self::compare_value_with_offset(*cmp_v, *ref_v, n, preceding);
                         if !asc {
@@ -1108,7 +1182,7 @@ macro_rules! impl_advance_frame_bound_method {
                         .as_nullable()
                         .unwrap()
                         .column;
-                    let ref_col = T::try_downcast_column(ref_col.as_number().unwrap()).unwrap();
+                    let ref_col = Self::downcast_order_column(ref_col);
                     let ref_v = unsafe { ref_col.get_unchecked(self.current_row.row) };
                     while self.[<frame_ $bound>] < self.partition_end {
                         let col = self
@@ -1128,7 +1202,7 @@ macro_rules! impl_advance_frame_bound_method {
                                 return;
                             }
                         }
-                        let cmp_col = T::try_downcast_column(col.column.as_number().unwrap()).unwrap();
+                        let cmp_col = Self::downcast_order_column(&col.column);
                         let cmp_v = unsafe { cmp_col.get_unchecked(self.[<frame_ $bound>].row) };
                         let mut ordering = Self::compare_value_with_offset(*cmp_v, *ref_v, n, preceding);
                         if !asc {
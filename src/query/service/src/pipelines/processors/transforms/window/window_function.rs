@@ -95,9 +95,14 @@ impl Drop for WindowFuncAggImpl {
 
 #[derive(Clone)]
 pub struct WindowFuncLagLeadImpl {
+    pub is_lag: bool,
+    pub offset: u64,
     pub arg: usize,
     pub default: LagLeadDefault,
     pub return_type: DataType,
+    /// If true, the frame covers all the rows before (`lag`) or after (`lead`) the current row,
+    /// and the result is the `offset`-th non-NULL value counting from the current row.
+    pub ignore_nulls: bool,
 }
 
 #[derive(Clone)]
@@ -105,6 +110,8 @@ pub struct WindowFuncNthValueImpl {
     pub n: Option<u64>,
     pub arg: usize,
     pub return_type: DataType,
+    /// If true, the NULL values of the frame are skipped.
+    pub ignore_nulls: bool,
 }
 
 #[derive(Clone)]
@@ -211,9 +218,12 @@ impl WindowFunctionInfo {
                     }
                 };
                 Self::LagLead(WindowFuncLagLeadImpl {
+                    is_lag: ll.is_lag,
+                    offset: ll.offset,
                     arg: new_arg,
                     default: new_default,
                     return_type: ll.return_type.clone(),
+                    ignore_nulls: ll.ignore_nulls,
                 })
             }
             WindowFunction::NthValue(func) => {
@@ -222,6 +232,7 @@ impl WindowFunctionInfo {
                     n: func.n,
                     arg: new_arg,
                     return_type: func.return_type.clone(),
+                    ignore_nulls: func.ignore_nulls,
                 })
             }
             WindowFunction::Ntile(func) => Self::Ntile(WindowFuncNtileImpl {
@@ -0,0 +1,85 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_storages_fuse::operations::CheckReport;
+
+use crate::procedures::OneBlockProcedure;
+use crate::procedures::Procedure;
+use crate::procedures::ProcedureFeatures;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::storages::fuse::FuseTable;
+
+/// `CALL system$check_table('[db.]table' [, 'repair'])`
+pub struct CheckTableProcedure {}
+
+impl CheckTableProcedure {
+    pub fn try_create() -> Result<Box<dyn Procedure>> {
+        Ok(CheckTableProcedure {}.into_procedure())
+    }
+}
+
+#[async_trait::async_trait]
+impl OneBlockProcedure for CheckTableProcedure {
+    fn name(&self) -> &str {
+        "CHECK_TABLE"
+    }
+
+    fn features(&self) -> ProcedureFeatures {
+        ProcedureFeatures::default().variadic_arguments(1, 2)
+    }
+
+    #[async_backtrace::framed]
+    async fn all_data(&self, ctx: Arc<QueryContext>, args: Vec<String>) -> Result<DataBlock> {
+        let (database_name, table_name) = match args[0].split_once('.') {
+            Some((database, table)) => (database.to_string(), table.to_string()),
+            None => (ctx.get_current_database(), args[0].clone()),
+        };
+        let repair = match args.get(1).map(|v| v.to_lowercase()) {
+            None => false,
+            Some(v) if v == "repair" => true,
+            Some(v) => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Invalid argument '{}' of CHECK_TABLE, expecting 'repair'",
+                    v
+                )));
+            }
+        };
+
+        let tenant_id = ctx.get_tenant();
+        let tbl = ctx
+            .get_catalog(&ctx.get_current_catalog())
+            .await?
+            .get_table(
+                tenant_id.as_str(),
+                database_name.as_str(),
+                table_name.as_str(),
+            )
+            .await?;
+
+        let tbl = FuseTable::try_from_table(tbl.as_ref())?;
+        let report = tbl.check(ctx.clone(), repair).await?;
+        Ok(report.to_data_block())
+    }
+
+    fn schema(&self) -> Arc<DataSchema> {
+        Arc::new(CheckReport::schema().into())
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod check_table;
 mod clustering_information;
 mod execute_job;
 mod fuse_block;
@@ -21,6 +22,7 @@ mod fuse_snapshot;
 mod search_tables;
 mod system;
 
+pub use check_table::CheckTableProcedure;
 pub use clustering_information::ClusteringInformationProcedure;
 pub use fuse_block::FuseBlockProcedure;
 pub use fuse_column::FuseColumnProcedure;
@@ -14,6 +14,7 @@
 
 use super::FuseColumnProcedure;
 use crate::procedures::systems::execute_job::ExecuteJobProcedure;
+use crate::procedures::systems::CheckTableProcedure;
 use crate::procedures::systems::ClusteringInformationProcedure;
 use crate::procedures::systems::FuseBlockProcedure;
 use crate::procedures::systems::FuseSegmentProcedure;
@@ -49,6 +50,10 @@ impl SystemProcedure {
             "system$search_tables",
             Box::new(SearchTablesProcedure::try_create),
         );
+        factory.register(
+            "system$check_table",
+            Box::new(CheckTableProcedure::try_create),
+        );
         factory.register(
             "system$execute_background_job",
             Box::new(ExecuteJobProcedure::try_create),
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod query_affect;
+mod query_audit;
 pub mod query_ctx;
 mod query_ctx_shared;
 mod session;
@@ -25,6 +26,7 @@ mod session_type;
 
 pub use common_catalog::table_context::TableContext;
 pub use query_affect::QueryAffect;
+pub use query_audit::QueryAudit;
 pub use query_ctx::QueryContext;
 pub use query_ctx_shared::short_sql;
 pub use query_ctx_shared::QueryContextShared;
@@ -0,0 +1,25 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_storages_system::AuditCategory;
+
+/// What a query does from the audit point of view.
+///
+/// It is attached to the query context once the plan passes the access check,
+/// and written to the audit log when the query finishes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueryAudit {
+    pub category: AuditCategory,
+    pub statement_kind: String,
+    pub objects: Vec<String>,
+}
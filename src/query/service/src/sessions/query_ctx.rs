@@ -44,6 +44,7 @@ use common_exception::Result;
 use common_expression::date_helper::TzFactory;
 use common_expression::DataBlock;
 use common_expression::FunctionContext;
+use common_io::prelude::BinaryFormat;
 use common_io::prelude::FormatSettings;
 use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::OnErrorMode;
@@ -78,6 +79,7 @@ use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::ProcessInfo;
+use crate::sessions::QueryAudit;
 use crate::sessions::QueryContextShared;
 use crate::sessions::Session;
 use crate::sessions::SessionManager;
@@ -230,6 +232,14 @@ impl QueryContext {
         self.shared.set_affect(affect)
     }
 
+    pub fn set_audit(&self, audit: QueryAudit) {
+        self.shared.set_audit(audit)
+    }
+
+    pub fn take_audit(&self) -> Option<QueryAudit> {
+        self.shared.take_audit()
+    }
+
     pub fn set_id(&self, id: String) {
         *self.shared.init_query_id.write() = id;
     }
@@ -454,7 +464,14 @@ impl TableContext for QueryContext {
         let timezone = tz.parse::<Tz>().map_err(|_| {
             ErrorCode::InvalidTimezone("Timezone has been checked and should be valid")
         })?;
-        let format = FormatSettings { timezone };
+        let binary_format = self
+            .query_settings
+            .get_binary_output_format()?
+            .parse::<BinaryFormat>()?;
+        let format = FormatSettings {
+            timezone,
+            binary_format,
+        };
         Ok(format)
     }
 
@@ -43,6 +43,7 @@ use uuid::Uuid;
 use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
 use crate::sessions::query_affect::QueryAffect;
+use crate::sessions::QueryAudit;
 use crate::sessions::Session;
 use crate::storages::Table;
 
@@ -68,6 +69,8 @@ pub struct QueryContextShared {
     pub(in crate::sessions) aborting: Arc<AtomicBool>,
     pub(in crate::sessions) tables_refs: Arc<Mutex<HashMap<DatabaseAndTable, Arc<dyn Table>>>>,
     pub(in crate::sessions) affect: Arc<Mutex<Option<QueryAffect>>>,
+    /// The pending audit event, written to the audit log when the query finishes.
+    pub(in crate::sessions) audit: Arc<Mutex<Option<QueryAudit>>>,
     pub(in crate::sessions) catalog_manager: Arc<CatalogManager>,
     pub(in crate::sessions) data_operator: DataOperator,
     pub(in crate::sessions) executor: Arc<RwLock<Weak<PipelineExecutor>>>,
@@ -111,6 +114,7 @@ impl QueryContextShared {
             aborting: Arc::new(AtomicBool::new(false)),
             tables_refs: Arc::new(Mutex::new(HashMap::new())),
             affect: Arc::new(Mutex::new(None)),
+            audit: Arc::new(Mutex::new(None)),
             executor: Arc::new(RwLock::new(Weak::new())),
             stage_attachment: Arc::new(RwLock::new(None)),
             created_time: SystemTime::now(),
@@ -347,6 +351,16 @@ impl QueryContextShared {
         *guard = Some(affect);
     }
 
+    pub fn set_audit(&self, audit: QueryAudit) {
+        let mut guard = self.audit.lock();
+        *guard = Some(audit);
+    }
+
+    pub fn take_audit(&self) -> Option<QueryAudit> {
+        let mut guard = self.audit.lock();
+        guard.take()
+    }
+
     pub fn set_executor(&self, executor: Arc<PipelineExecutor>) -> Result<()> {
         let mut guard = self.executor.write();
         match self.check_aborting() {
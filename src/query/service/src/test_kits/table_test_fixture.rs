@@ -189,6 +189,7 @@ impl TestFixture {
             field_comments: vec![],
             as_select: None,
             cluster_key: Some("(id)".to_string()),
+            partition_key: None,
         }
     }
 
@@ -212,6 +213,7 @@ impl TestFixture {
             field_comments: vec![],
             as_select: None,
             cluster_key: None,
+            partition_key: None,
         }
     }
 
@@ -246,6 +248,7 @@ impl TestFixture {
             field_comments: vec![],
             as_select: None,
             cluster_key: None,
+            partition_key: None,
         }
     }
 
@@ -289,6 +292,7 @@ impl TestFixture {
             field_comments: vec![],
             as_select: None,
             cluster_key: None,
+            partition_key: None,
         }
     }
 
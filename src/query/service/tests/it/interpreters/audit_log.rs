@@ -0,0 +1,147 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_storages_system::AuditCategory;
+use common_storages_system::AuditLogElement;
+use databend_query::interpreters::AuditLogger;
+use databend_query::interpreters::AuditSink;
+use databend_query::interpreters::TableAuditSink;
+use databend_query::test_kits::table_test_fixture::execute_command;
+use databend_query::test_kits::table_test_fixture::execute_query;
+use databend_query::test_kits::utils::query_count;
+use databend_query::test_kits::TestFixture;
+use parking_lot::Mutex;
+
+#[derive(Default)]
+struct CollectSink {
+    events: Mutex<Vec<AuditLogElement>>,
+}
+
+impl AuditSink for CollectSink {
+    fn write(&self, event: &AuditLogElement) -> Result<()> {
+        self.events.lock().push(event.clone());
+        Ok(())
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_audit_log() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let sink = Arc::new(CollectSink::default());
+    AuditLogger::instance().add_sink(sink.clone());
+
+    let table = format!("{}.t_audit", fixture.default_db_name());
+    execute_command(ctx.clone(), &format!("create table {table}(a int)")).await?;
+    execute_command(ctx.clone(), &format!("insert into {table} values(1)")).await?;
+    execute_command(ctx.clone(), &format!("select * from {table}")).await?;
+    // Not audited.
+    execute_command(ctx.clone(), "show databases").await?;
+    // Failed statements are audited too.
+    let res = execute_command(ctx.clone(), &format!("create table {table}(a int)")).await;
+    assert!(res.is_err());
+
+    let events = sink.events.lock().clone();
+    let summary = events
+        .iter()
+        .map(|e| {
+            (
+                e.category,
+                e.statement_kind.as_str(),
+                e.objects.clone(),
+                e.success,
+            )
+        })
+        .collect::<Vec<_>>();
+    let object = format!("default.{table}");
+    assert_eq!(summary, vec![
+        (
+            AuditCategory::Ddl,
+            "CreateTable",
+            vec![object.clone()],
+            true
+        ),
+        (
+            AuditCategory::DataAccess,
+            "Insert",
+            vec![object.clone()],
+            true
+        ),
+        (
+            AuditCategory::DataAccess,
+            "Query",
+            vec![object.clone()],
+            true
+        ),
+        (AuditCategory::Ddl, "CreateTable", vec![object], false),
+    ]);
+
+    for event in &events {
+        assert_eq!(event.user, "'root'@'%'");
+    }
+    assert_ne!(events[3].error_code, 0);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_audit_log_table_sink() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let table = format!("{}.t_audit_sink", fixture.default_db_name());
+    let sink = TableAuditSink::create(&table)?;
+
+    let event = AuditLogElement {
+        event_time: 0,
+        tenant_id: "test".to_string(),
+        cluster_id: "test".to_string(),
+        node_id: "node".to_string(),
+        user: "'root'@'%'".to_string(),
+        role: "account_admin".to_string(),
+        client_address: "127.0.0.1".to_string(),
+        query_id: "query".to_string(),
+        category: AuditCategory::Ddl,
+        statement_kind: "CreateTable".to_string(),
+        objects: vec!["default.db.t".to_string()],
+        success: true,
+        error_code: 0,
+        error_message: "".to_string(),
+    };
+
+    // The table is created by the first flush.
+    sink.write(&event)?;
+    sink.write(&event)?;
+    sink.flush().await?;
+    sink.write(&event)?;
+    sink.flush().await?;
+    // Nothing to flush.
+    sink.flush().await?;
+
+    let stream = execute_query(ctx.clone(), &format!("select count(*) from {table}")).await?;
+    assert_eq!(3, query_count(stream).await?);
+
+    let stream = execute_query(
+        ctx.clone(),
+        &format!("select count(*) from {table} where statement_kind = 'CreateTable'"),
+    )
+    .await?;
+    assert_eq!(3, query_count(stream).await?);
+
+    Ok(())
+}
@@ -90,6 +90,7 @@ fn test_resolvable_delete_conflict() {
         index_size: 6,
         col_stats: HashMap::new(),
         cluster_stats: None,
+        partition: None,
     };
 
     let mut latest_snapshot = TableSnapshot::new_empty_snapshot(TableSchema::default());
@@ -108,6 +109,7 @@ fn test_resolvable_delete_conflict() {
         index_size: 9,
         col_stats: HashMap::new(),
         cluster_stats: None,
+        partition: None,
     };
 
     let removed_statistics = Statistics {
@@ -119,6 +121,7 @@ fn test_resolvable_delete_conflict() {
         index_size: 5,
         col_stats: HashMap::new(),
         cluster_stats: None,
+        partition: None,
     };
 
     let added_statistics = Statistics {
@@ -130,6 +133,7 @@ fn test_resolvable_delete_conflict() {
         index_size: 8,
         col_stats: HashMap::new(),
         cluster_stats: None,
+        partition: None,
     };
 
     let ctx = ConflictResolveContext::ModifiedSegmentExistsInLatest(SnapshotChanges {
@@ -163,6 +167,7 @@ fn test_resolvable_delete_conflict() {
         index_size: 12,
         col_stats: HashMap::new(),
         cluster_stats: None,
+        partition: None,
     };
     assert_eq!(actual, expected);
 }
@@ -0,0 +1,94 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_storages_fuse::io::SegmentsIO;
+use common_storages_fuse::operations::CheckIssueKind;
+use common_storages_fuse::FuseTable;
+use databend_query::test_kits::table_test_fixture::append_sample_data;
+use databend_query::test_kits::table_test_fixture::TestFixture;
+use storages_common_table_meta::meta::SegmentInfo;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_check_table() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx: Arc<dyn TableContext> = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    // two segments, one block for each
+    append_sample_data(1, &fixture).await?;
+    append_sample_data(1, &fixture).await?;
+
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let report = fuse_table.check(ctx.clone(), false).await?;
+    assert!(report.issues.is_empty(), "{:?}", report.issues);
+    assert_eq!(report.segment_count, 2);
+    assert_eq!(report.block_count, 2);
+
+    // lose the block of one segment, and leave an orphan object
+    let snapshot = fuse_table.read_table_snapshot().await?.unwrap();
+    let operator = fuse_table.get_operator();
+    let segments = SegmentsIO::create(ctx.clone(), operator.clone(), fuse_table.schema())
+        .read_segments::<Arc<SegmentInfo>>(&snapshot.segments[..1], false)
+        .await?;
+    let lost_block = segments[0].as_ref().unwrap().blocks[0].location.0.clone();
+    operator.delete(&lost_block).await?;
+    let orphan = format!(
+        "{}/_b/orphan.parquet",
+        fuse_table.meta_location_generator().prefix()
+    );
+    operator.write(&orphan, vec![1, 2, 3]).await?;
+
+    let report = fuse_table.check(ctx.clone(), false).await?;
+    assert!(!report.is_healthy());
+    assert!(
+        report
+            .issues
+            .iter()
+            .any(|issue| { issue.kind == CheckIssueKind::Missing && issue.location == lost_block })
+    );
+    assert!(
+        report
+            .issues
+            .iter()
+            .any(|issue| { issue.kind == CheckIssueKind::Orphaned && issue.location == orphan })
+    );
+    assert_eq!(report.excluded_block_count, None);
+
+    // repair excludes the lost block
+    let report = fuse_table.check(ctx.clone(), true).await?;
+    assert_eq!(report.excluded_block_count, Some(1));
+    assert!(report.repaired_snapshot_location.is_some());
+
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let repaired = fuse_table.read_table_snapshot().await?.unwrap();
+    assert_eq!(repaired.summary.block_count, 1);
+    assert_eq!(repaired.segments.len(), 1);
+
+    // only the orphan object is left
+    let report = fuse_table.check(ctx.clone(), false).await?;
+    assert!(report.is_healthy(), "{:?}", report.issues);
+    assert_eq!(report.block_count, 1);
+    assert_eq!(report.issues.len(), 1);
+    assert_eq!(report.issues[0].location, orphan);
+
+    Ok(())
+}
@@ -53,6 +53,7 @@ async fn test_fuse_alter_table_cluster_key() -> common_exception::Result<()> {
         field_comments: vec![],
         as_select: None,
         cluster_key: None,
+        partition_key: None,
     };
 
     // create test table
@@ -138,6 +138,7 @@ async fn test_recluster_mutator_block_select() -> Result<()> {
         schema,
         &None,
         BloomIndexColumns::All,
+        BloomIndexColumns::None,
     )?
     .read_pruning(segment_locations)
     .await?;
@@ -16,6 +16,8 @@ use std::ops::Sub;
 use std::time::Duration;
 
 use common_base::base::tokio;
+use common_catalog::table::NavigationDescriptor;
+use common_catalog::table::NavigationPoint;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
@@ -123,6 +125,62 @@ async fn test_fuse_navigate() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_navigate_within_retention() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    let qry = format!("insert into {}.{} values (1, (2, 3))", db, tbl);
+    execute_query(ctx.clone(), qry.as_str())
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let qry = format!(
+        "alter table {}.{} set options(data_retention_time_in_days = 1)",
+        db, tbl
+    );
+    execute_query(ctx.clone(), qry.as_str())
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    assert_eq!(
+        Some(1),
+        fuse_table
+            .get_data_retention_time_in_days(ctx.as_ref(), &db)
+            .await?
+    );
+
+    // the current snapshot is always visible
+    let snapshot = fuse_table.read_table_snapshot().await?.unwrap();
+    let navigation = NavigationDescriptor {
+        database_name: db.clone(),
+        point: NavigationPoint::SnapshotID(snapshot.snapshot_id.simple().to_string()),
+    };
+    fuse_table
+        .navigate_within_retention(ctx.as_ref(), &navigation)
+        .await?;
+
+    // points beyond the retention period are rejected
+    let navigation = NavigationDescriptor {
+        database_name: db.clone(),
+        point: NavigationPoint::TimePoint(chrono::Utc::now().sub(chrono::Duration::days(2))),
+    };
+    let res = fuse_table
+        .navigate_within_retention(ctx.as_ref(), &navigation)
+        .await;
+    match res {
+        Ok(_) => panic!("historical data beyond retention period should not be visible"),
+        Err(e) => assert_eq!(e.code(), ErrorCode::TABLE_HISTORICAL_DATA_NOT_FOUND),
+    };
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_navigate_for_purge() -> Result<()> {
     // 1. Setup
@@ -165,6 +165,81 @@ async fn test_table_update_analyze_statistics() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_analyze_histogram() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    execute_command(ctx.clone(), "create table t(c int, s string)").await?;
+    // skewed data: value 1 takes 10 of 14 rows
+    execute_command(ctx.clone(), "insert into t select 1, 'a' from numbers(10)").await?;
+    execute_command(
+        ctx.clone(),
+        "insert into t values(2, 'b'), (3, 'c'), (4, 'd'), (5, null)",
+    )
+    .await?;
+    execute_command(ctx.clone(), "analyze table default.t").await?;
+
+    let table = ctx
+        .get_catalog("default")
+        .await?
+        .get_table(ctx.get_tenant().as_str(), "default", "t")
+        .await?;
+    let provider = table.column_statistics_provider().await?;
+
+    let int = |v: i32| Scalar::Number(NumberScalar::Int32(v));
+    let histogram = provider.histogram(0).unwrap();
+    assert_eq!(histogram.num_rows, 14);
+    assert_eq!(histogram.most_common_values, vec![(int(1), 10)]);
+    assert_eq!(histogram.buckets.first().unwrap().lower_bound, int(1));
+    assert_eq!(histogram.buckets.last().unwrap().upper_bound, int(5));
+    let num_values: u64 = histogram.buckets.iter().map(|b| b.num_values).sum();
+    assert_eq!(num_values, 14);
+
+    // null values are excluded
+    let histogram = provider.histogram(1).unwrap();
+    assert_eq!(histogram.num_rows, 13);
+    assert_eq!(histogram.most_common_values, vec![(
+        Scalar::String(b"a".to_vec()),
+        10
+    )]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_analyze_histogram_sampled() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    // more rows than the values kept in the sample of a column
+    execute_command(ctx.clone(), "create table t(c int)").await?;
+    execute_command(
+        ctx.clone(),
+        "insert into t select number from numbers(100000)",
+    )
+    .await?;
+    execute_command(ctx.clone(), "analyze table default.t").await?;
+
+    let table = ctx
+        .get_catalog("default")
+        .await?
+        .get_table(ctx.get_tenant().as_str(), "default", "t")
+        .await?;
+    let provider = table.column_statistics_provider().await?;
+
+    // the counts are scaled to the whole table
+    let histogram = provider.histogram(0).unwrap();
+    assert_eq!(histogram.num_rows, 100000);
+    assert!(histogram.most_common_values.is_empty());
+    let num_values: u64 = histogram.buckets.iter().map(|b| b.num_values).sum();
+    assert!(num_values.abs_diff(100000) <= histogram.buckets.len() as u64);
+    let num_distinct: u64 = histogram.buckets.iter().map(|b| b.num_distinct).sum();
+    assert!(num_distinct.abs_diff(100000) <= histogram.buckets.len() as u64);
+
+    Ok(())
+}
+
 async fn check_column_ndv_statistics(
     table: Arc<dyn Table>,
     expected: HashMap<u32, u64>,
@@ -69,6 +69,12 @@ impl DefaultSettings {
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
+                ("data_retention_time_in_days", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the default data retention period in days for tables that do not specify one, 0 means unlimited.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
                 ("max_storage_io_requests", DefaultSettingValue {
                     value: UserSettingValue::UInt64(default_max_storage_io_requests),
                     desc: "Sets the maximum number of concurrent I/O requests.",
@@ -112,6 +118,12 @@ impl DefaultSettings {
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
+                ("binary_output_format", DefaultSettingValue {
+                    value: UserSettingValue::String("hex".to_owned()),
+                    desc: "Sets the text format of BINARY values. Available values include \"hex\" and \"base64\".",
+                    possible_values: Some(vec!["hex", "base64"]),
+                    display_in_show_settings: true,
+                }),
                 ("group_by_two_level_threshold", DefaultSettingValue {
                     value: UserSettingValue::UInt64(20000),
                     desc: "Sets the number of keys in a GROUP BY operation that will trigger a two-level aggregation.",
@@ -119,6 +119,10 @@ impl Settings {
         self.try_get_u64("retention_period")
     }
 
+    pub fn get_data_retention_time_in_days(&self) -> Result<u64> {
+        self.try_get_u64("data_retention_time_in_days")
+    }
+
     pub fn get_max_storage_io_requests(&self) -> Result<u64> {
         self.try_get_u64("max_storage_io_requests")
     }
@@ -181,6 +185,10 @@ impl Settings {
         self.try_get_string("timezone")
     }
 
+    pub fn get_binary_output_format(&self) -> Result<String> {
+        self.try_get_string("binary_output_format")
+    }
+
     // Get group by two level threshold
     pub fn get_group_by_two_level_threshold(&self) -> Result<u64> {
         self.try_get_u64("group_by_two_level_threshold")
@@ -16,6 +16,7 @@ use std::collections::HashSet;
 use std::sync::Arc;
 
 use common_catalog::plan::AggIndexMeta;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::array::ArrayColumn;
 use common_expression::types::nullable::NullableColumn;
@@ -330,6 +331,12 @@ impl BlockOperator {
                     let expr = func.lambda_expr.as_expr(&BUILTIN_FUNCTIONS);
                     // TODO: Support multi args
                     let input_column = input.get_by_offset(func.arg_indices[0]);
+                    if func.func_name.starts_with("map_") {
+                        let col =
+                            eval_map_lambda(func, &expr, input_column, func_ctx, input.num_rows())?;
+                        input.add_column(col);
+                        continue;
+                    }
                     match &input_column.value {
                         Value::Scalar(s) => match s {
                             Scalar::Null => {
@@ -455,6 +462,95 @@ impl BlockOperator {
     }
 }
 
+/// Evaluates the lambda function of `map_filter`, `map_transform_keys` or `map_transform_values`
+/// on the key-value pairs of a map column.
+fn eval_map_lambda(
+    func: &LambdaFunctionDesc,
+    expr: &Expr,
+    input_column: &BlockEntry,
+    func_ctx: &FunctionContext,
+    num_rows: usize,
+) -> Result<BlockEntry> {
+    let column = input_column
+        .value
+        .convert_to_full_column(&input_column.data_type, num_rows);
+    let (map_col, validity) = match column {
+        Column::Map(box map_col) => (map_col, None),
+        Column::Nullable(box nullable_col) => match nullable_col.column {
+            Column::Map(box map_col) => (map_col, Some(nullable_col.validity)),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+    let Column::Tuple(fields) = &map_col.values else {
+        unreachable!()
+    };
+    let (keys, values) = (fields[0].clone(), fields[1].clone());
+
+    let num_pairs = keys.len();
+    let block = DataBlock::new(
+        vec![
+            BlockEntry::new(keys.data_type(), Value::Column(keys.clone())),
+            BlockEntry::new(values.data_type(), Value::Column(values.clone())),
+        ],
+        num_pairs,
+    );
+    let evaluator = Evaluator::new(&block, func_ctx, &BUILTIN_FUNCTIONS);
+    let result = evaluator.run(expr)?;
+    let result_col = result.convert_to_full_column(expr.data_type(), num_pairs);
+
+    let (pairs, offsets) = match func.func_name.as_str() {
+        "map_filter" => {
+            let result_col = result_col.remove_nullable();
+            let bitmap = result_col.as_boolean().unwrap();
+            // generate new offsets after filter.
+            let mut new_offset = 0;
+            let mut filtered_offsets = Vec::with_capacity(map_col.offsets.len());
+            filtered_offsets.push(0);
+            for offset in map_col.offsets.windows(2) {
+                let off = offset[0] as usize;
+                let len = (offset[1] - offset[0]) as usize;
+                let unset_count = bitmap.null_count_range(off, len);
+                new_offset += (len - unset_count) as u64;
+                filtered_offsets.push(new_offset);
+            }
+            (map_col.values.filter(bitmap), filtered_offsets.into())
+        }
+        "map_transform_keys" => {
+            for offset in map_col.offsets.windows(2) {
+                let mut set = HashSet::new();
+                for idx in offset[0] as usize..offset[1] as usize {
+                    let key = unsafe { result_col.index_unchecked(idx) };
+                    if !set.insert(key) {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "map keys have to be unique while evaluating function `{}`",
+                            func.func_name
+                        )));
+                    }
+                }
+            }
+            (Column::Tuple(vec![result_col, values]), map_col.offsets)
+        }
+        _ => (Column::Tuple(vec![keys, result_col]), map_col.offsets),
+    };
+
+    let map_col = Column::Map(Box::new(ArrayColumn {
+        values: pairs,
+        offsets,
+    }));
+    let col = match validity {
+        Some(validity) => Column::Nullable(Box::new(NullableColumn {
+            column: map_col,
+            validity,
+        })),
+        None => map_col,
+    };
+    Ok(BlockEntry::new(
+        func.data_type.as_ref().clone(),
+        Value::Column(col),
+    ))
+}
+
 /// `CompoundBlockOperator` is a pipeline of `BlockOperator`s
 pub struct CompoundBlockOperator {
     pub operators: Vec<BlockOperator>,
@@ -1230,6 +1230,7 @@ pub struct LagLeadFunctionDesc {
     pub arg: usize,
     pub return_type: DataType,
     pub default: LagLeadDefault,
+    pub ignore_nulls: bool,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -1237,6 +1238,7 @@ pub struct NthValueFunctionDesc {
     pub n: Option<u64>,
     pub arg: usize,
     pub return_type: DataType,
+    pub ignore_nulls: bool,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -31,7 +31,10 @@ use common_exception::Result;
 use common_expression::type_check;
 use common_expression::type_check::check_function;
 use common_expression::type_check::common_super_type;
+use common_expression::types::decimal::DecimalScalar;
 use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
 use common_expression::ConstantFolder;
 use common_expression::DataBlock;
 use common_expression::DataField;
@@ -40,6 +43,7 @@ use common_expression::DataSchemaRefExt;
 use common_expression::FunctionContext;
 use common_expression::RawExpr;
 use common_expression::RemoteExpr;
+use common_expression::Scalar;
 use common_expression::TableSchema;
 use common_expression::ROW_ID_COL_NAME;
 use common_functions::BUILTIN_FUNCTIONS;
@@ -87,6 +91,7 @@ use crate::optimizer::SExpr;
 use crate::planner;
 use crate::plans::AggregateMode;
 use crate::plans::BoundColumnRef;
+use crate::plans::ConstantExpr;
 use crate::plans::Exchange;
 use crate::plans::FunctionCall;
 use crate::plans::RelOperator;
@@ -109,6 +114,9 @@ use crate::Visibility;
 use crate::DUMMY_COLUMN_INDEX;
 use crate::DUMMY_TABLE_INDEX;
 
+/// Decimal keys of RANGE offset frames are compared as scaled Int64 values.
+const MAX_RANGE_OFFSET_DECIMAL_PRECISION: u8 = 18;
+
 pub struct PhysicalPlanBuilder {
     metadata: MetadataRef,
     ctx: Arc<dyn TableContext>,
@@ -1258,52 +1266,117 @@ impl PhysicalPlanBuilder {
                 _ => None,
             };
 
-            let mut common_ty = order_by
+            let order_by_ty = order_by
                 .resolve_and_check(&*input_schema)?
                 .data_type()
                 .clone();
-            for scalar in start.iter_mut().chain(end.iter_mut()) {
-                let ty = scalar.as_ref().infer_data_type();
-                common_ty = common_super_type(
-                    common_ty.clone(),
-                    ty.clone(),
-                    &BUILTIN_FUNCTIONS.default_cast_rules,
-                )
-                .ok_or_else(|| {
-                    ErrorCode::IllegalDataType(format!(
-                        "Cannot find common type for {:?} and {:?}",
-                        &common_ty, &ty
-                    ))
-                })?;
-            }
 
-            *order_by = wrap_cast(order_by, &common_ty);
-            for scalar in start.iter_mut().chain(end.iter_mut()) {
-                let raw_expr = RawExpr::<usize>::Cast {
-                    span: w.span,
-                    is_try: false,
-                    expr: Box::new(RawExpr::Constant {
-                        span: w.span,
-                        scalar: scalar.clone(),
-                    }),
-                    dest_type: common_ty.clone(),
+            if matches!(
+                order_by_ty.remove_nullable(),
+                DataType::Date | DataType::Timestamp
+            ) {
+                // The offsets over DATE and TIMESTAMP columns have been resolved
+                // into days and microseconds by the binder.
+                if start.iter().chain(end.iter()).any(|s| !s.is_positive()) {
+                    return Err(ErrorCode::SemanticError(
+                        "Only positive numbers are allowed in RANGE offset".to_string(),
+                    )
+                    .set_span(w.span));
+                }
+            } else {
+                let mut common_ty = order_by_ty;
+                for scalar in start.iter_mut().chain(end.iter_mut()) {
+                    let ty = scalar.as_ref().infer_data_type();
+                    common_ty = common_super_type(
+                        common_ty.clone(),
+                        ty.clone(),
+                        &BUILTIN_FUNCTIONS.default_cast_rules,
+                    )
+                    .ok_or_else(|| {
+                        ErrorCode::IllegalDataType(format!(
+                            "Cannot find common type for {:?} and {:?}",
+                            &common_ty, &ty
+                        ))
+                    })?;
+                }
+                // Decimal order keys are compared as their scaled integers when computing
+                // the offset frame, which is exact as long as they fit in Int64.
+                let decimal_scale = match common_ty.remove_nullable() {
+                    DataType::Decimal(decimal) if start.is_some() || end.is_some() => {
+                        let size = decimal.size();
+                        if size.precision > MAX_RANGE_OFFSET_DECIMAL_PRECISION {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "RANGE offset over DECIMAL is only supported up to precision {}, but got {:?}",
+                                MAX_RANGE_OFFSET_DECIMAL_PRECISION, common_ty
+                            ))
+                            .set_span(w.span));
+                        }
+                        Some(size.scale)
+                    }
+                    _ => None,
                 };
-                let expr = type_check::check(&raw_expr, &BUILTIN_FUNCTIONS)?;
-                let (expr, _) =
-                    ConstantFolder::fold(&expr, &FunctionContext::default(), &BUILTIN_FUNCTIONS);
-                if let common_expression::Expr::Constant {
-                    scalar: new_scalar, ..
-                } = expr
-                {
-                    if new_scalar.is_positive() {
-                        **scalar = new_scalar;
-                        continue;
+
+                *order_by = wrap_cast(order_by, &common_ty);
+                if let Some(scale) = decimal_scale {
+                    let scaled = ScalarExpr::FunctionCall(FunctionCall {
+                        span: w.span,
+                        func_name: "multiply".to_string(),
+                        params: vec![],
+                        arguments: vec![
+                            order_by.clone(),
+                            ScalarExpr::ConstantExpr(ConstantExpr {
+                                span: w.span,
+                                value: Scalar::Number(NumberScalar::Int64(
+                                    10_i64.pow(scale as u32),
+                                )),
+                            }),
+                        ],
+                    });
+                    let int_ty = DataType::Number(NumberDataType::Int64);
+                    let int_ty = if common_ty.is_nullable() {
+                        int_ty.wrap_nullable()
+                    } else {
+                        int_ty
+                    };
+                    *order_by = wrap_cast(&scaled, &int_ty);
+                }
+                for scalar in start.iter_mut().chain(end.iter_mut()) {
+                    let raw_expr = RawExpr::<usize>::Cast {
+                        span: w.span,
+                        is_try: false,
+                        expr: Box::new(RawExpr::Constant {
+                            span: w.span,
+                            scalar: scalar.clone(),
+                        }),
+                        dest_type: common_ty.clone(),
+                    };
+                    let expr = type_check::check(&raw_expr, &BUILTIN_FUNCTIONS)?;
+                    let (expr, _) = ConstantFolder::fold(
+                        &expr,
+                        &FunctionContext::default(),
+                        &BUILTIN_FUNCTIONS,
+                    );
+                    if let common_expression::Expr::Constant {
+                        scalar: new_scalar, ..
+                    } = expr
+                    {
+                        if new_scalar.is_positive() {
+                            **scalar = match new_scalar {
+                                Scalar::Decimal(DecimalScalar::Decimal128(v, _))
+                                    if decimal_scale.is_some() =>
+                                {
+                                    Scalar::Number(NumberScalar::Int64(v as i64))
+                                }
+                                new_scalar => new_scalar,
+                            };
+                            continue;
+                        }
                     }
+                    return Err(ErrorCode::SemanticError(
+                        "Only positive numbers are allowed in RANGE offset".to_string(),
+                    )
+                    .set_span(w.span));
                 }
-                return Err(ErrorCode::SemanticError(
-                    "Only positive numbers are allowed in RANGE offset".to_string(),
-                )
-                .set_span(w.span));
             }
         }
 
@@ -1403,12 +1476,14 @@ impl PhysicalPlanBuilder {
                         ))
                     }?,
                     default: new_default,
+                    ignore_nulls: lag_lead.ignore_nulls,
                 })
             }
 
             WindowFuncType::NthValue(func) => WindowFunction::NthValue(NthValueFunctionDesc {
                 n: func.n,
                 return_type: *func.return_type.clone(),
+                ignore_nulls: func.ignore_nulls,
                 arg: if let ScalarExpr::BoundColumnRef(col) = &*func.arg {
                     Ok(col.column.index)
                 } else {
@@ -186,6 +186,7 @@ impl<'a> AggregateRewriter<'a> {
                             offset: ll.offset,
                             default: new_default,
                             return_type: ll.return_type.clone(),
+                            ignore_nulls: ll.ignore_nulls,
                         })
                     }
                     WindowFuncType::NthValue(func) => {
@@ -194,6 +195,7 @@ impl<'a> AggregateRewriter<'a> {
                             n: func.n,
                             arg: Box::new(new_arg),
                             return_type: func.return_type.clone(),
+                            ignore_nulls: func.ignore_nulls,
                         })
                     }
                     func => func.clone(),
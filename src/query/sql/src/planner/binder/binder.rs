@@ -300,6 +300,9 @@ impl<'a> Binder {
             Statement::CreateIndex(stmt) => self.bind_create_index(bind_context, stmt).await?,
             Statement::DropIndex(stmt) => self.bind_drop_index(stmt).await?,
             Statement::RefreshIndex(stmt) => self.bind_refresh_index(bind_context, stmt).await?,
+            Statement::CreateNgramIndex(stmt) => self.bind_create_ngram_index(stmt).await?,
+            Statement::CreateInvertedIndex(stmt) => self.bind_create_inverted_index(stmt).await?,
+            Statement::DropInvertedIndex(stmt) => self.bind_drop_inverted_index(stmt).await?,
 
             // Virtual Columns
             Statement::CreateVirtualColumns(stmt) => self.bind_create_virtual_columns(stmt).await?,
@@ -551,6 +554,19 @@ impl<'a> Binder {
             Statement::DescDatamaskPolicy(stmt) => {
                 self.bind_desc_data_mask_policy(stmt).await?
             }
+            Statement::CreateRowAccessPolicy(stmt) => {
+                self.bind_create_row_access_policy(stmt).await?
+            }
+            Statement::DropRowAccessPolicy(stmt) => {
+                self.bind_drop_row_access_policy(stmt).await?
+            }
+            Statement::DescRowAccessPolicy(stmt) => {
+                self.bind_desc_row_access_policy(stmt).await?
+            }
+            Statement::CreateTag(stmt) => self.bind_create_tag(stmt).await?,
+            Statement::DropTag(stmt) => self.bind_drop_tag(stmt).await?,
+            Statement::AlterObjectTag(stmt) => self.bind_alter_object_tag(stmt).await?,
+            Statement::CommentOn(stmt) => self.bind_comment_on(stmt).await?,
             Statement::CreateNetworkPolicy(stmt) => {
                 self.bind_create_network_policy(stmt).await?
             }
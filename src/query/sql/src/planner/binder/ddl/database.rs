@@ -40,6 +40,7 @@ use crate::plans::Plan;
 use crate::plans::RenameDatabaseEntity;
 use crate::plans::RenameDatabasePlan;
 use crate::plans::RewriteKind;
+use crate::plans::SetDatabaseOptionsPlan;
 use crate::plans::ShowCreateDatabasePlan;
 use crate::plans::UndropDatabasePlan;
 use crate::BindContext;
@@ -149,6 +150,15 @@ impl Binder {
                     entities: vec![entry],
                 })))
             }
+            AlterDatabaseAction::SetOptions { set_options } => {
+                Ok(Plan::SetDatabaseOptions(Box::new(SetDatabaseOptionsPlan {
+                    if_exists: *if_exists,
+                    tenant,
+                    catalog,
+                    database,
+                    set_options: set_options.clone(),
+                })))
+            }
         }
     }
 
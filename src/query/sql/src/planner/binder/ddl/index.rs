@@ -12,10 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use common_ast::ast::CreateIndexStmt;
+use common_ast::ast::CreateInvertedIndexStmt;
+use common_ast::ast::CreateNgramIndexStmt;
 use common_ast::ast::DropIndexStmt;
+use common_ast::ast::DropInvertedIndexStmt;
 use common_ast::ast::GroupBy;
 use common_ast::ast::Identifier;
 use common_ast::ast::Query;
@@ -31,17 +35,22 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::schema::GetIndexReq;
 use common_meta_app::schema::IndexNameIdent;
+use storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
 
 use crate::binder::Binder;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerConfig;
 use crate::optimizer::OptimizerContext;
 use crate::plans::CreateIndexPlan;
+use crate::plans::CreateTableIndexPlan;
 use crate::plans::DropIndexPlan;
+use crate::plans::DropTableIndexPlan;
 use crate::plans::Plan;
 use crate::plans::RefreshIndexPlan;
+use crate::plans::SetOptionsPlan;
 use crate::AggregatingIndexRewriter;
 use crate::BindContext;
+use crate::BloomIndexColumns;
 use crate::SUPPORTED_AGGREGATING_INDEX_FUNCTIONS;
 
 impl Binder {
@@ -257,3 +266,123 @@ impl Binder {
         }
     }
 }
+
+impl Binder {
+    /// The ngram index is kept in the table option `ngram_index_columns`,
+    /// `CREATE NGRAM INDEX` adds the columns to it.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_ngram_index(
+        &mut self,
+        stmt: &CreateNgramIndexStmt,
+    ) -> Result<Plan> {
+        let CreateNgramIndexStmt {
+            catalog,
+            database,
+            table,
+            columns,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+
+        let table_info = self.ctx.get_table(&catalog, &database, &table).await?;
+        if table_info.engine() != "FUSE" {
+            return Err(ErrorCode::SemanticError(
+                "Ngram index only support FUSE engine",
+            ));
+        }
+
+        let mut index_columns = match table_info
+            .options()
+            .get(OPT_KEY_NGRAM_INDEX_COLUMNS)
+            .map(|v| v.parse::<BloomIndexColumns>())
+            .transpose()?
+        {
+            Some(BloomIndexColumns::Specify(cols)) => cols,
+            _ => vec![],
+        };
+        for column in columns {
+            let column = self.normalize_object_identifier(column);
+            if !index_columns.contains(&column) {
+                index_columns.push(column);
+            }
+        }
+
+        let mut set_options = BTreeMap::new();
+        set_options.insert(
+            OPT_KEY_NGRAM_INDEX_COLUMNS.to_string(),
+            index_columns.join(","),
+        );
+        Ok(Plan::SetOptions(Box::new(SetOptionsPlan {
+            set_options,
+            catalog,
+            database,
+            table,
+        })))
+    }
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_inverted_index(
+        &mut self,
+        stmt: &CreateInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let CreateInvertedIndexStmt {
+            if_not_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+            columns,
+            options,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+
+        let table_info = self.ctx.get_table(&catalog, &database, &table).await?;
+        if table_info.engine() != "FUSE" {
+            return Err(ErrorCode::SemanticError(
+                "Inverted index only support FUSE engine",
+            ));
+        }
+
+        let columns = columns
+            .iter()
+            .map(|column| self.normalize_object_identifier(column))
+            .collect();
+
+        Ok(Plan::CreateTableIndex(Box::new(CreateTableIndexPlan {
+            if_not_exists: *if_not_exists,
+            index_name: self.normalize_object_identifier(index_name),
+            catalog,
+            database,
+            table,
+            columns,
+            options: options.clone(),
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_inverted_index(
+        &mut self,
+        stmt: &DropInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let DropInvertedIndexStmt {
+            if_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+
+        Ok(Plan::DropTableIndex(Box::new(DropTableIndexPlan {
+            if_exists: *if_exists,
+            index_name: self.normalize_object_identifier(index_name),
+            catalog,
+            database,
+            table,
+        })))
+    }
+}
@@ -20,8 +20,10 @@ mod database;
 mod index;
 mod network_policy;
 mod role;
+mod row_access_policy;
 mod share;
 mod stage;
 mod table;
+mod tag;
 mod view;
 mod virtual_column;
@@ -0,0 +1,111 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+
+use crate::binder::Binder;
+use crate::binder::ColumnBindingBuilder;
+use crate::binder::Visibility;
+use crate::planner::semantic::resolve_type_name;
+use crate::plans::BoundColumnRef;
+use crate::plans::CreateRowAccessPolicyPlan;
+use crate::plans::DescRowAccessPolicyPlan;
+use crate::plans::DropRowAccessPolicyPlan;
+use crate::plans::Plan;
+use crate::plans::ScalarExpr;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_row_access_policy(
+        &mut self,
+        stmt: &CreateRowAccessPolicyStmt,
+    ) -> Result<Plan> {
+        let CreateRowAccessPolicyStmt {
+            if_not_exists,
+            name,
+            policy,
+        } = stmt;
+
+        let return_type = match &policy.return_type {
+            TypeName::Nullable(inner) => inner.as_ref(),
+            return_type => return_type,
+        };
+        if !matches!(return_type, TypeName::Boolean) {
+            return Err(ErrorCode::SemanticError(format!(
+                "row access policy must return BOOLEAN, but got {}",
+                policy.return_type
+            )));
+        }
+
+        // Check the body of policy could be bound with the arguments.
+        let mut aliases = Vec::with_capacity(policy.args.len());
+        for (i, arg) in policy.args.iter().enumerate() {
+            let data_type = DataType::from(&resolve_type_name(&arg.arg_type)?);
+            let column = ColumnBindingBuilder::new(
+                arg.arg_name.to_string(),
+                i,
+                Box::new(data_type),
+                Visibility::Visible,
+            )
+            .build();
+            aliases.push((
+                arg.arg_name.to_string(),
+                ScalarExpr::BoundColumnRef(BoundColumnRef { span: None, column }),
+            ));
+        }
+        self.bind_row_access_policy_body(&policy.body, &aliases)
+            .await?;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = CreateRowAccessPolicyPlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            name: name.to_string(),
+            policy: policy.clone(),
+        };
+        Ok(Plan::CreateRowAccessPolicy(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_row_access_policy(
+        &mut self,
+        stmt: &DropRowAccessPolicyStmt,
+    ) -> Result<Plan> {
+        let DropRowAccessPolicyStmt { if_exists, name } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = DropRowAccessPolicyPlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+        };
+        Ok(Plan::DropRowAccessPolicy(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_desc_row_access_policy(
+        &mut self,
+        stmt: &DescRowAccessPolicyStmt,
+    ) -> Result<Plan> {
+        let DescRowAccessPolicyStmt { name } = stmt;
+
+        let plan = DescRowAccessPolicyPlan {
+            name: name.to_string(),
+        };
+        Ok(Plan::DescRowAccessPolicy(Box::new(plan)))
+    }
+}
@@ -58,8 +58,10 @@ use common_expression::infer_schema_type;
 use common_expression::infer_table_schema;
 use common_expression::types::DataType;
 use common_expression::ComputedExpr;
+use common_expression::ConstantFolder;
 use common_expression::DataField;
 use common_expression::DataSchemaRefExt;
+use common_expression::Scalar;
 use common_expression::TableField;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
@@ -67,6 +69,7 @@ use common_expression::TableSchemaRefExt;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_app::storage::StorageParams;
 use common_storage::DataOperator;
+use common_storages_deltalake::DeltaTable;
 use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
 use log::debug;
@@ -91,13 +94,16 @@ use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::resolve_type_name;
 use crate::planner::semantic::IdentifierNormalizer;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CreateTablePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
+use crate::plans::DropTablePartitionPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRowAccessPolicyPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::ModifyTableColumnPlan;
 use crate::plans::OptimizeTableAction;
@@ -108,6 +114,7 @@ use crate::plans::RenameTableColumnPlan;
 use crate::plans::RenameTablePlan;
 use crate::plans::RevertTablePlan;
 use crate::plans::RewriteKind;
+use crate::plans::RotateTableKeyPlan;
 use crate::plans::SetOptionsPlan;
 use crate::plans::ShowCreateTablePlan;
 use crate::plans::TruncateTablePlan;
@@ -380,6 +387,7 @@ impl Binder {
             source,
             table_options,
             cluster_by,
+            partition_by,
             as_query,
             transient,
             engine,
@@ -426,6 +434,19 @@ impl Binder {
             None => (None, "".to_string()),
         };
 
+        if engine == Engine::Delta {
+            if storage_params.is_none() {
+                return Err(ErrorCode::BadArguments(
+                    "A LOCATION is required to create a table of the DELTA engine",
+                ));
+            }
+            if as_query.is_some() {
+                return Err(ErrorCode::BadArguments(
+                    "Tables of the DELTA engine are read only, CREATE TABLE AS SELECT is not supported",
+                ));
+            }
+        }
+
         // If table is TRANSIENT, set a flag in table option
         if *transient {
             options.insert("TRANSIENT".to_owned(), "T".to_owned());
@@ -477,6 +498,12 @@ impl Binder {
                 Self::validate_create_table_schema(&source_schema)?;
                 (source_schema, source_comments)
             }
+            (None, None) if engine == Engine::Delta => {
+                // `CREATE TABLE ... ENGINE = DELTA` without column definitions, the
+                // columns are loaded from the metadata of the delta table.
+                let schema = DeltaTable::load_schema(storage_params.as_ref().unwrap()).await?;
+                (Arc::new(schema), vec![])
+            }
             _ => Err(ErrorCode::BadArguments(
                 "Incorrect CREATE query: required list of column descriptions or AS section or SELECT..",
             ))?,
@@ -561,6 +588,21 @@ impl Binder {
             }
         };
 
+        let partition_key = if partition_by.is_empty() {
+            None
+        } else {
+            if engine != Engine::Fuse {
+                return Err(ErrorCode::InvalidPartitionKeys(format!(
+                    "PARTITION BY is not supported by the {} engine",
+                    engine
+                )));
+            }
+            let keys = self
+                .analyze_partition_keys(partition_by, schema.clone())
+                .await?;
+            Some(format!("({})", keys.join(", ")))
+        };
+
         let plan = CreateTablePlan {
             if_not_exists: *if_not_exists,
             tenant: self.ctx.get_tenant(),
@@ -574,6 +616,7 @@ impl Binder {
             options,
             field_comments,
             cluster_key,
+            partition_key,
             as_select: if let Some(query) = as_query {
                 let mut bind_context = BindContext::new();
                 let stmt = Statement::Query(Box::new(*query.clone()));
@@ -642,6 +685,7 @@ impl Binder {
             options,
             engine: Engine::Fuse,
             cluster_key: None,
+            partition_key: None,
             as_select: None,
             schema: Arc::new(TableSchema::default()),
             field_comments: vec![],
@@ -794,6 +838,28 @@ impl Binder {
                     column: column.to_string(),
                 })))
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => Ok(
+                Plan::AddTableRowAccessPolicy(Box::new(AddTableRowAccessPolicyPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    policy: policy.to_string(),
+                    columns: columns
+                        .iter()
+                        .map(|column| self.normalize_object_identifier(column))
+                        .collect(),
+                })),
+            ),
+            AlterTableAction::DropRowAccessPolicy { policy } => Ok(Plan::DropTableRowAccessPolicy(
+                Box::new(DropTableRowAccessPolicyPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    policy: policy.to_string(),
+                }),
+            )),
             AlterTableAction::AlterTableClusterKey { cluster_by } => {
                 let schema = self
                     .ctx
@@ -820,6 +886,24 @@ impl Binder {
                     table,
                 },
             ))),
+            AlterTableAction::DropPartition { values }
+            | AlterTableAction::TruncatePartition { values } => {
+                let partition = self.analyze_partition_values(values).await?;
+                Ok(Plan::DropTablePartition(Box::new(DropTablePartitionPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    partition,
+                    is_truncate: matches!(action, AlterTableAction::TruncatePartition { .. }),
+                })))
+            }
+            AlterTableAction::RotateKey => Ok(Plan::RotateTableKey(Box::new(RotateTableKeyPlan {
+                tenant,
+                catalog,
+                database,
+                table,
+            }))),
             AlterTableAction::ReclusterTable {
                 is_final,
                 selection,
@@ -1401,6 +1485,108 @@ impl Binder {
         Ok(cluster_keys)
     }
 
+    #[async_backtrace::framed]
+    async fn analyze_partition_keys(
+        &mut self,
+        partition_by: &[Expr],
+        schema: TableSchemaRef,
+    ) -> Result<Vec<String>> {
+        // Partition values are computed before the virtual computed columns exist,
+        // so only the stored columns can be referenced.
+        let schema = schema.remove_virtual_computed_fields();
+        let mut bind_context = BindContext::new();
+        for (index, field) in schema.fields().iter().enumerate() {
+            let column = ColumnBindingBuilder::new(
+                field.name().clone(),
+                index,
+                Box::new(DataType::from(field.data_type())),
+                Visibility::Visible,
+            )
+            .build();
+
+            bind_context.columns.push(column);
+        }
+        let mut scalar_binder = ScalarBinder::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+        );
+        // partition keys cannot be a udf expression.
+        scalar_binder.forbid_udf();
+
+        let mut partition_keys = Vec::with_capacity(partition_by.len());
+        for partition_by in partition_by.iter() {
+            let (partition_key, _) = scalar_binder.bind(partition_by).await?;
+            if partition_key.used_columns().is_empty() {
+                return Err(ErrorCode::InvalidPartitionKeys(format!(
+                    "Partition by expression `{:#}` must reference a column",
+                    partition_by
+                )));
+            }
+
+            let expr = partition_key.as_expr()?;
+            if !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+                return Err(ErrorCode::InvalidPartitionKeys(format!(
+                    "Partition by expression `{:#}` is not deterministic",
+                    partition_by
+                )));
+            }
+
+            let data_type = expr.data_type();
+            if !Self::valid_cluster_key_type(data_type) {
+                return Err(ErrorCode::InvalidPartitionKeys(format!(
+                    "Unsupported data type '{}' for partition by expression `{:#}`",
+                    data_type, partition_by
+                )));
+            }
+
+            let mut partition_by = partition_by.clone();
+            walk_expr_mut(
+                &mut IdentifierNormalizer {
+                    ctx: &self.name_resolution_ctx,
+                },
+                &mut partition_by,
+            );
+            partition_keys.push(format!("{:#}", &partition_by));
+        }
+
+        Ok(partition_keys)
+    }
+
+    #[async_backtrace::framed]
+    async fn analyze_partition_values(&mut self, values: &[Expr]) -> Result<Vec<Scalar>> {
+        let mut bind_context = BindContext::new();
+        let mut scalar_binder = ScalarBinder::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+        );
+
+        let mut partition = Vec::with_capacity(values.len());
+        for value in values.iter() {
+            let (scalar, _) = scalar_binder.bind(value).await?;
+            let expr = scalar.as_expr()?;
+            let (expr, _) =
+                ConstantFolder::fold(&expr, &self.ctx.get_function_context()?, &BUILTIN_FUNCTIONS);
+            match expr {
+                common_expression::Expr::Constant { scalar, .. } => partition.push(scalar),
+                _ => {
+                    return Err(ErrorCode::InvalidPartitionKeys(format!(
+                        "Partition value `{:#}` must be a constant",
+                        value
+                    )));
+                }
+            }
+        }
+        Ok(partition)
+    }
+
     fn valid_cluster_key_type(data_type: &DataType) -> bool {
         let inner_type = data_type.remove_nullable();
         matches!(
@@ -0,0 +1,166 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::*;
+use common_exception::Result;
+
+use crate::binder::Binder;
+use crate::plans::AlterObjectTagPlan;
+use crate::plans::CommentOnPlan;
+use crate::plans::CreateTagPlan;
+use crate::plans::DropTagPlan;
+use crate::plans::ObjectIdent;
+use crate::plans::ObjectTagAction;
+use crate::plans::Plan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_tag(
+        &mut self,
+        stmt: &CreateTagStmt,
+    ) -> Result<Plan> {
+        let CreateTagStmt {
+            if_not_exists,
+            name,
+            comment,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = CreateTagPlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            name: self.normalize_object_identifier(name),
+            comment: comment.clone().unwrap_or_default(),
+        };
+        Ok(Plan::CreateTag(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_tag(
+        &mut self,
+        stmt: &DropTagStmt,
+    ) -> Result<Plan> {
+        let DropTagStmt { if_exists, name } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = DropTagPlan {
+            if_exists: *if_exists,
+            tenant,
+            name: self.normalize_object_identifier(name),
+        };
+        Ok(Plan::DropTag(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_object_tag(
+        &mut self,
+        stmt: &AlterObjectTagStmt,
+    ) -> Result<Plan> {
+        let AlterObjectTagStmt { object, action } = stmt;
+
+        let action = match action {
+            AlterObjectTagAction::Set { tags } => ObjectTagAction::Set(
+                tags.iter()
+                    .map(|(name, value)| (self.normalize_object_identifier(name), value.clone()))
+                    .collect(),
+            ),
+            AlterObjectTagAction::Unset { tags } => ObjectTagAction::Unset(
+                tags.iter()
+                    .map(|name| self.normalize_object_identifier(name))
+                    .collect(),
+            ),
+        };
+
+        let tenant = self.ctx.get_tenant();
+        let plan = AlterObjectTagPlan {
+            tenant,
+            object: self.resolve_object_ref(object),
+            action,
+        };
+        Ok(Plan::AlterObjectTag(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_comment_on(
+        &mut self,
+        stmt: &CommentOnStmt,
+    ) -> Result<Plan> {
+        let CommentOnStmt { object, comment } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = CommentOnPlan {
+            tenant,
+            object: self.resolve_object_ref(object),
+            comment: comment.clone(),
+        };
+        Ok(Plan::CommentOn(Box::new(plan)))
+    }
+
+    fn resolve_object_ref(&self, object: &ObjectRef) -> ObjectIdent {
+        match object {
+            ObjectRef::Database { catalog, database } => ObjectIdent::Database {
+                catalog: catalog
+                    .as_ref()
+                    .map(|ident| self.normalize_object_identifier(ident))
+                    .unwrap_or_else(|| self.ctx.get_current_catalog()),
+                database: self.normalize_object_identifier(database),
+            },
+            ObjectRef::Table {
+                catalog,
+                database,
+                table,
+            } => {
+                let (catalog, database, table) =
+                    self.normalize_object_identifier_triple(catalog, database, table);
+                ObjectIdent::Table {
+                    catalog,
+                    database,
+                    table,
+                }
+            }
+            ObjectRef::View {
+                catalog,
+                database,
+                view,
+            } => {
+                let (catalog, database, view) =
+                    self.normalize_object_identifier_triple(catalog, database, view);
+                ObjectIdent::View {
+                    catalog,
+                    database,
+                    view,
+                }
+            }
+            ObjectRef::Column {
+                catalog,
+                database,
+                table,
+                column,
+            } => {
+                let (catalog, database, table) =
+                    self.normalize_object_identifier_triple(catalog, database, table);
+                ObjectIdent::Column {
+                    catalog,
+                    database,
+                    table,
+                    column: self.normalize_object_identifier(column),
+                }
+            }
+            ObjectRef::Stage { stage } => ObjectIdent::Stage {
+                stage: stage.clone(),
+            },
+            ObjectRef::User { user } => ObjectIdent::User { user: user.clone() },
+        }
+    }
+}
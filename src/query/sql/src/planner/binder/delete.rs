@@ -44,15 +44,18 @@ impl<'a> Binder {
         table_expr: SExpr,
         scalar_binder: &mut ScalarBinder<'_>,
     ) -> Result<(Option<ScalarExpr>, Vec<SubqueryDesc>)> {
-        Ok(if let Some(expr) = filter {
-            let (scalar, _) = scalar_binder.bind(expr).await?;
-            let mut subquery_desc = vec![];
-            self.subquery_desc(&scalar, table_expr, &mut subquery_desc)
+        let scalar = match filter {
+            Some(expr) => Some(scalar_binder.bind(expr).await?.0),
+            None => None,
+        };
+        // The filter of row access policy on the table is merged into the selection.
+        let (table_expr, selection) = Self::merge_row_access_policy(table_expr, scalar.clone());
+        let mut subquery_desc = vec![];
+        if let Some(scalar) = &scalar {
+            self.subquery_desc(scalar, table_expr, &mut subquery_desc)
                 .await?;
-            (Some(scalar), subquery_desc)
-        } else {
-            (None, vec![])
-        })
+        }
+        Ok((selection, subquery_desc))
     }
 
     #[async_backtrace::framed]
@@ -18,6 +18,7 @@ use common_catalog::plan::InternalColumn;
 use common_catalog::plan::InternalColumnType;
 use common_expression::BLOCK_NAME_COL_NAME;
 use common_expression::ROW_ID_COL_NAME;
+use common_expression::SEARCH_SCORE_COL_NAME;
 use common_expression::SEGMENT_NAME_COL_NAME;
 use common_expression::SNAPSHOT_NAME_COL_NAME;
 use ctor::ctor;
@@ -53,6 +54,11 @@ impl InternalColumnFactory {
             InternalColumn::new(SNAPSHOT_NAME_COL_NAME, InternalColumnType::SnapshotName),
         );
 
+        internal_columns.insert(
+            SEARCH_SCORE_COL_NAME.to_string(),
+            InternalColumn::new(SEARCH_SCORE_COL_NAME, InternalColumnType::SearchScore),
+        );
+
         InternalColumnFactory { internal_columns }
     }
 
@@ -35,6 +35,7 @@ mod presign;
 mod project;
 mod project_set;
 mod replace;
+mod row_access_policy;
 mod scalar;
 mod scalar_common;
 mod scalar_visitor;
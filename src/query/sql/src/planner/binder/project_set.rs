@@ -18,9 +18,8 @@ use common_ast::ast::Expr;
 use common_ast::ast::Identifier;
 use common_ast::ast::Lambda;
 use common_ast::ast::Literal;
-use common_ast::ast::Window;
+use common_ast::ast::WindowDesc;
 use common_ast::Visitor;
-use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::Span;
 use common_expression::FunctionKind;
@@ -52,7 +51,7 @@ impl<'a> Visitor<'a> for SrfCollector {
         name: &'a Identifier,
         args: &'a [Expr],
         params: &'a [Literal],
-        over: &'a Option<Window>,
+        over: &'a Option<WindowDesc>,
         lambda: &'a Option<Lambda>,
     ) {
         if BUILTIN_FUNCTIONS
@@ -146,12 +145,7 @@ impl Binder {
 
             let srf_expr = srf_scalar.as_expr()?;
             let return_types = srf_expr.data_type().as_tuple().unwrap();
-
-            if return_types.len() > 1 {
-                return Err(ErrorCode::Unimplemented(
-                    "set-returning functions with more than one return type are not supported yet",
-                ));
-            }
+            let multiple_columns = return_types.len() > 1;
 
             // Add result column to metadata
             let column_index = self
@@ -172,17 +166,22 @@ impl Binder {
             };
             items.push(item);
 
-            // Flatten the tuple fields of the srfs to the top level columns
-            // TODO(andylokandy/leisky): support multiple return types
-            let flatten_result = ScalarExpr::FunctionCall(FunctionCall {
+            // Flatten the tuple fields of the srfs to the top level columns,
+            // the srfs with multiple columns are kept as tuples, e.g. `flatten`.
+            let result_column = ScalarExpr::BoundColumnRef(BoundColumnRef {
                 span: srf.span(),
-                func_name: "get".to_string(),
-                params: vec![1],
-                arguments: vec![ScalarExpr::BoundColumnRef(BoundColumnRef {
-                    span: srf.span(),
-                    column,
-                })],
+                column,
             });
+            let flatten_result = if multiple_columns {
+                result_column
+            } else {
+                ScalarExpr::FunctionCall(FunctionCall {
+                    span: srf.span(),
+                    func_name: "get".to_string(),
+                    params: vec![1],
+                    arguments: vec![result_column],
+                })
+            };
 
             // Add the srf to bind context, so we can replace the srfs later.
             bind_context.srfs.insert(srf.to_string(), flatten_result);
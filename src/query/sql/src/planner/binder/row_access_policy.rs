@@ -0,0 +1,164 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_ast::ast::Expr;
+use common_ast::parser::parse_expr;
+use common_ast::parser::tokenize_sql;
+use common_ast::Dialect;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_meta_api::RowAccessPolicyApi;
+use common_meta_app::row_access_policy::GetRowAccessPolicyReq;
+use common_meta_app::row_access_policy::RowAccessPolicyNameIdent;
+use common_meta_app::schema::TableRowAccessPolicy;
+use common_users::UserApiProvider;
+
+use crate::binder::scalar_common::split_conjunctions;
+use crate::binder::Binder;
+use crate::binder::ScalarBinder;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::Filter;
+use crate::plans::FunctionCall;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::BindContext;
+
+impl Binder {
+    /// Bind the body of a row access policy, the arguments of the policy are
+    /// resolved through `aliases` only.
+    pub(in crate::planner::binder) async fn bind_row_access_policy_body(
+        &self,
+        body: &Expr,
+        aliases: &[(String, ScalarExpr)],
+    ) -> Result<ScalarExpr> {
+        let mut bind_context = BindContext::new();
+        let mut scalar_binder = ScalarBinder::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            aliases,
+            self.m_cte_bound_ctx.clone(),
+        );
+        let (scalar, data_type) = scalar_binder.bind(body).await?;
+        if data_type.remove_nullable() != DataType::Boolean {
+            return Err(ErrorCode::SemanticError(format!(
+                "row access policy body must return boolean, but got {}",
+                data_type
+            ))
+            .set_span(body.span()));
+        }
+        Ok(scalar)
+    }
+
+    /// Put the predicate of the row access policy attached to a table above
+    /// the scan of the table, `bind_context` is the context of the scan.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_row_access_policy(
+        &self,
+        bind_context: &BindContext,
+        s_expr: SExpr,
+        row_access_policy: &Option<TableRowAccessPolicy>,
+    ) -> Result<SExpr> {
+        let Some(row_access_policy) = row_access_policy else {
+            return Ok(s_expr);
+        };
+        if bind_context.planning_agg_index {
+            return Ok(s_expr);
+        }
+
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let policy = meta_api
+            .get_row_access_policy(GetRowAccessPolicyReq {
+                name: RowAccessPolicyNameIdent {
+                    tenant: self.ctx.get_tenant(),
+                    name: row_access_policy.policy.clone(),
+                },
+            })
+            .await?
+            .policy;
+
+        if policy.args.len() != row_access_policy.columns.len() {
+            return Err(ErrorCode::SemanticError(format!(
+                "row access policy {} expects {} arguments, but {} columns are attached",
+                row_access_policy.policy,
+                policy.args.len(),
+                row_access_policy.columns.len()
+            )));
+        }
+
+        let mut aliases = Vec::with_capacity(policy.args.len());
+        for ((arg_name, _), column_name) in policy.args.iter().zip(&row_access_policy.columns) {
+            let column = bind_context
+                .columns
+                .iter()
+                .find(|column| &column.column_name == column_name)
+                .ok_or_else(|| {
+                    ErrorCode::UnknownColumn(format!(
+                        "column {} of row access policy {} does not exist",
+                        column_name, row_access_policy.policy
+                    ))
+                })?;
+            let scalar = ScalarExpr::BoundColumnRef(BoundColumnRef {
+                span: None,
+                column: column.clone(),
+            });
+            aliases.push((arg_name.clone(), scalar));
+        }
+
+        let tokens = tokenize_sql(&policy.body)?;
+        let body = parse_expr(&tokens, Dialect::PostgreSQL)?;
+        let scalar = self.bind_row_access_policy_body(&body, &aliases).await?;
+
+        let filter = Filter {
+            predicates: split_conjunctions(&scalar),
+            is_having: false,
+        };
+        Ok(SExpr::create_unary(
+            Arc::new(filter.into()),
+            Arc::new(s_expr),
+        ))
+    }
+
+    /// DML statements work on the scan of the table directly, so the filter of
+    /// the row access policy is taken off the scan and merged into `selection`.
+    pub(in crate::planner::binder) fn merge_row_access_policy(
+        table_expr: SExpr,
+        selection: Option<ScalarExpr>,
+    ) -> (SExpr, Option<ScalarExpr>) {
+        let predicates = match &*table_expr.plan {
+            RelOperator::Filter(filter)
+                if matches!(&*table_expr.child(0).unwrap().plan, RelOperator::Scan(_)) =>
+            {
+                filter.predicates.clone()
+            }
+            _ => return (table_expr, selection),
+        };
+        let table_expr = table_expr.child(0).unwrap().clone();
+
+        let selection = predicates.into_iter().chain(selection).reduce(|lhs, rhs| {
+            ScalarExpr::FunctionCall(FunctionCall {
+                span: None,
+                func_name: "and".to_string(),
+                params: vec![],
+                arguments: vec![lhs, rhs],
+            })
+        });
+        (table_expr, selection)
+    }
+}
@@ -227,9 +227,13 @@ pub fn wrap_cast_scalar(
             | DataType::Number(_)
             | DataType::Decimal(_)
             | DataType::Timestamp
+            | DataType::TimestampTz
+            | DataType::Time
             | DataType::Date
             | DataType::Bitmap
-            | DataType::Variant => wrap_cast(scalar, target_type),
+            | DataType::Variant
+            | DataType::Geometry
+            | DataType::Binary => wrap_cast(scalar, target_type),
             DataType::String => {
                 // parse string to JSON value
                 let func = ScalarExpr::FunctionCall(FunctionCall {
@@ -22,8 +22,11 @@ use std::sync::Arc;
 use async_recursion::async_recursion;
 use chrono::TimeZone;
 use chrono::Utc;
+use common_ast::ast::Expr;
 use common_ast::ast::Indirection;
 use common_ast::ast::Join;
+use common_ast::ast::JoinOperator;
+use common_ast::ast::Literal;
 use common_ast::ast::SelectStmt;
 use common_ast::ast::SelectTarget;
 use common_ast::ast::Statement;
@@ -37,6 +40,7 @@ use common_catalog::catalog_kind::CATALOG_DEFAULT;
 use common_catalog::plan::ParquetReadOptions;
 use common_catalog::plan::StageTableInfo;
 use common_catalog::table::ColumnStatistics;
+use common_catalog::table::NavigationDescriptor;
 use common_catalog::table::NavigationPoint;
 use common_catalog::table::Table;
 use common_catalog::table_args::TableArgs;
@@ -54,6 +58,8 @@ use common_expression::Scalar;
 use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
+use common_functions::srfs::srf_arg_names;
+use common_functions::srfs::srf_column_names;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_license::license::Feature::AggregateIndex;
 use common_license::license_manager::get_license_manager;
@@ -90,6 +96,9 @@ use crate::optimizer::SExpr;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::TypeChecker;
 use crate::plans::CteScan;
+use crate::plans::EvalScalar;
+use crate::plans::FunctionCall;
+use crate::plans::ScalarItem;
 use crate::plans::Scan;
 use crate::plans::Statistics;
 use crate::BaseTableColumn;
@@ -97,6 +106,7 @@ use crate::BindContext;
 use crate::ColumnEntry;
 use crate::DerivedColumn;
 use crate::IndexType;
+use crate::ScalarExpr;
 use crate::TableInternalColumn;
 use crate::VirtualColumn;
 
@@ -383,6 +393,8 @@ impl Binder {
                     _ => {
                         let table_name = table_meta.name();
                         let full_table_name = format!("{catalog}.{database}.{table_name}");
+                        let row_access_policy =
+                            table_meta.get_table_info().meta.row_access_policy.clone();
                         let table_index = self.metadata.write().add_table(
                             catalog,
                             database.clone(),
@@ -402,6 +414,9 @@ impl Binder {
                         let (s_expr, mut bind_context) = self
                             .bind_base_table(bind_context, database.as_str(), table_index)
                             .await?;
+                        let s_expr = self
+                            .bind_row_access_policy(&bind_context, s_expr, &row_access_policy)
+                            .await?;
                         if let Some(alias) = alias {
                             bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
                         }
@@ -411,11 +426,35 @@ impl Binder {
             }
             TableReference::TableFunction {
                 span,
+                lateral,
                 name,
                 params,
                 named_params,
                 alias,
             } => {
+                let func_name = normalize_identifier(name, &self.name_resolution_ctx);
+                let is_srf = BUILTIN_FUNCTIONS
+                    .get_property(&func_name.name)
+                    .map(|p| p.kind == FunctionKind::SRF)
+                    .unwrap_or(false);
+
+                if *lateral && !is_srf {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "LATERAL is only supported for set-returning functions, but got `{}`",
+                        func_name.name
+                    ))
+                    .set_span(*span));
+                }
+
+                if is_srf && srf_column_names(&func_name.name).is_some() {
+                    // The set-returning functions with named output columns are bound over
+                    // `system.one`, the same as being called laterally.
+                    let (s_expr, bind_context) = self.bind_one_table(bind_context, &vec![]).await?;
+                    return self
+                        .bind_lateral_table_function(bind_context, s_expr, table_ref, true)
+                        .await;
+                }
+
                 let mut scalar_binder = ScalarBinder::new(
                     bind_context,
                     self.ctx.clone(),
@@ -426,8 +465,6 @@ impl Binder {
                 );
                 let table_args = bind_table_args(&mut scalar_binder, params, named_params).await?;
 
-                let func_name = normalize_identifier(name, &self.name_resolution_ctx);
-
                 if func_name.name.eq_ignore_ascii_case("result_scan") {
                     let query_id = parse_result_scan_args(&table_args)?;
                     if query_id.is_empty() {
@@ -489,11 +526,7 @@ impl Binder {
                     return Ok((s_expr, bind_context));
                 }
 
-                if BUILTIN_FUNCTIONS
-                    .get_property(&func_name.name)
-                    .map(|p| p.kind == FunctionKind::SRF)
-                    .unwrap_or(false)
-                {
+                if is_srf {
                     // If it is a set-returning function, we bind it as a subquery.
                     let mut bind_context = BindContext::new();
                     let stmt = SelectStmt {
@@ -510,7 +543,12 @@ impl Binder {
                                     quote: None,
                                 },
                                 params: vec![],
-                                args: params.clone(),
+                                args: self.srf_table_function_args(
+                                    *span,
+                                    &func_name.name,
+                                    params,
+                                    named_params,
+                                )?,
                                 window: None,
                                 lambda: None,
                             }),
@@ -749,6 +787,15 @@ impl Binder {
                     result_expr = join_expr;
                     result_ctx = ctx;
                 }
+                TableReference::TableFunction { .. }
+                    if self.is_lateral_table_function(&join.right, &join.op)? =>
+                {
+                    let (lateral_expr, ctx) = self
+                        .bind_lateral_table_function(result_ctx, result_expr, &join.right, false)
+                        .await?;
+                    result_expr = lateral_expr;
+                    result_ctx = ctx;
+                }
                 _ => {
                     let (right_expr, right_ctx) =
                         self.bind_single_table(current_ctx, &join.right).await?;
@@ -771,6 +818,183 @@ impl Binder {
         Ok((result_expr, result_ctx))
     }
 
+    /// Check whether the table function on the right side of the join is called laterally,
+    /// the set-returning functions in a cross join are evaluated for each row of the left side.
+    fn is_lateral_table_function(
+        &self,
+        table_ref: &TableReference,
+        join_op: &JoinOperator,
+    ) -> Result<bool> {
+        let TableReference::TableFunction {
+            span,
+            lateral,
+            name,
+            ..
+        } = table_ref
+        else {
+            return Ok(false);
+        };
+        let func_name = normalize_identifier(name, &self.name_resolution_ctx).name;
+        let is_srf = BUILTIN_FUNCTIONS
+            .get_property(&func_name)
+            .map(|p| p.kind == FunctionKind::SRF)
+            .unwrap_or(false);
+        match join_op {
+            JoinOperator::CrossJoin => Ok(is_srf),
+            _ if *lateral => Err(ErrorCode::SemanticError(
+                "LATERAL table functions can only be cross joined, use `outer => true` of `flatten` to keep the rows without output".to_string(),
+            )
+            .set_span(*span)),
+            _ => Ok(false),
+        }
+    }
+
+    /// Bind a set-returning function in the `FROM` clause over the rows of `input`. The function
+    /// is evaluated for each input row, so the arguments can refer to the input columns,
+    /// e.g. `SELECT t.id, f.value FROM t, LATERAL flatten(input => t.v) f`.
+    /// The output columns are appended to the input columns, or replace them if `replace_input`.
+    #[async_backtrace::framed]
+    async fn bind_lateral_table_function(
+        &mut self,
+        mut bind_context: BindContext,
+        input: SExpr,
+        table_ref: &TableReference,
+        replace_input: bool,
+    ) -> Result<(SExpr, BindContext)> {
+        let TableReference::TableFunction {
+            span,
+            name,
+            params,
+            named_params,
+            alias,
+            ..
+        } = table_ref
+        else {
+            unreachable!()
+        };
+        let func_name = normalize_identifier(name, &self.name_resolution_ctx).name;
+        let srf = Expr::FunctionCall {
+            span: *span,
+            distinct: false,
+            name: name.clone(),
+            args: self.srf_table_function_args(*span, &func_name, params, named_params)?,
+            params: vec![],
+            window: None,
+            lambda: None,
+        };
+        let s_expr = self
+            .bind_project_set(&mut bind_context, &[srf.clone()], input)
+            .await?;
+        let srf_result = bind_context.srfs.remove(&srf.to_string()).ok_or_else(|| {
+            ErrorCode::Internal(format!("set-returning function `{srf}` is not bound"))
+        })?;
+
+        // Expand the output tuple to the columns.
+        let column_names = srf_column_names(&func_name).unwrap_or(&[]);
+        let data_type = srf_result.data_type()?;
+        let fields = match &data_type {
+            DataType::Tuple(fields_type) if column_names.len() > 1 => fields_type
+                .iter()
+                .cloned()
+                .enumerate()
+                .map(|(i, field_type)| {
+                    let field = ScalarExpr::FunctionCall(FunctionCall {
+                        span: *span,
+                        func_name: "get".to_string(),
+                        params: vec![i + 1],
+                        arguments: vec![srf_result.clone()],
+                    });
+                    (field, field_type)
+                })
+                .collect::<Vec<_>>(),
+            _ => vec![(srf_result, data_type.clone())],
+        };
+
+        let table_name = match alias {
+            Some(alias) => normalize_identifier(&alias.name, &self.name_resolution_ctx).name,
+            None => func_name.clone(),
+        };
+        if replace_input {
+            bind_context.columns.clear();
+        }
+        let mut items = Vec::with_capacity(fields.len());
+        for (i, (field, field_type)) in fields.into_iter().enumerate() {
+            let column_name = match alias.as_ref().and_then(|alias| alias.columns.get(i)) {
+                Some(column) => normalize_identifier(column, &self.name_resolution_ctx).name,
+                None => column_names
+                    .get(i)
+                    .map(|name| name.to_string())
+                    .unwrap_or_else(|| func_name.clone()),
+            };
+            let mut column = self.create_derived_column_binding(column_name, field_type);
+            column.table_name = Some(table_name.clone());
+            items.push(ScalarItem {
+                scalar: field,
+                index: column.index,
+            });
+            bind_context.add_column_binding(column);
+        }
+
+        let eval_scalar = EvalScalar { items };
+        Ok((
+            SExpr::create_unary(Arc::new(eval_scalar.into()), Arc::new(s_expr)),
+            bind_context,
+        ))
+    }
+
+    /// The arguments of a set-returning function in the `FROM` clause, the named arguments are
+    /// placed by their names, and the skipped arguments are `NULL`.
+    fn srf_table_function_args(
+        &self,
+        span: Span,
+        func_name: &str,
+        params: &[Expr],
+        named_params: &[(String, Expr)],
+    ) -> Result<Vec<Expr>> {
+        if named_params.is_empty() {
+            return Ok(params.to_vec());
+        }
+        let arg_names = srf_arg_names(func_name).ok_or_else(|| {
+            ErrorCode::SemanticError(format!(
+                "set-returning function `{func_name}` does not accept named arguments"
+            ))
+            .set_span(span)
+        })?;
+        let mut args: Vec<Option<Expr>> = params.iter().cloned().map(Some).collect();
+        for (name, arg) in named_params {
+            let pos = arg_names
+                .iter()
+                .position(|arg_name| arg_name.eq_ignore_ascii_case(name))
+                .ok_or_else(|| {
+                    ErrorCode::SemanticError(format!(
+                        "unknown argument `{name}` of `{func_name}`, expected one of {}",
+                        arg_names.join(", ")
+                    ))
+                    .set_span(span)
+                })?;
+            if args.len() <= pos {
+                args.resize(pos + 1, None);
+            }
+            if args[pos].is_some() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "argument `{}` of `{func_name}` is specified more than once",
+                    arg_names[pos]
+                ))
+                .set_span(span));
+            }
+            args[pos] = Some(arg.clone());
+        }
+        Ok(args
+            .into_iter()
+            .map(|arg| {
+                arg.unwrap_or(Expr::Literal {
+                    span,
+                    lit: Literal::Null,
+                })
+            })
+            .collect())
+    }
+
     fn bind_cte_scan(&mut self, cte_info: &CteInfo) -> Result<SExpr> {
         let blocks = Arc::new(RwLock::new(vec![]));
         self.ctx
@@ -872,6 +1096,7 @@ impl Binder {
         let statistics_provider = table.table().column_statistics_provider().await?;
 
         let mut col_stats: HashMap<IndexType, Option<ColumnStatistics>> = HashMap::new();
+        let mut histograms = HashMap::new();
         for column in columns.iter() {
             match column {
                 ColumnEntry::BaseTableColumn(BaseTableColumn {
@@ -907,6 +1132,11 @@ impl Binder {
                             let col_stat =
                                 statistics_provider.column_statistics(col_id as ColumnId);
                             col_stats.insert(*column_index, col_stat);
+                            if let Some(histogram) =
+                                statistics_provider.histogram(col_id as ColumnId)
+                            {
+                                histograms.insert(*column_index, histogram);
+                            }
                         }
                     }
                 }
@@ -943,6 +1173,7 @@ impl Binder {
                     statistics: Statistics {
                         statistics: stat,
                         col_stats,
+                        histograms,
                     },
                     ..Default::default()
                 }
@@ -966,7 +1197,13 @@ impl Binder {
         let mut table_meta = catalog.get_table(tenant, database_name, table_name).await?;
 
         if let Some(tp) = travel_point {
-            table_meta = table_meta.navigate_to(tp).await?;
+            let navigation = NavigationDescriptor {
+                database_name: database_name.to_string(),
+                point: tp.clone(),
+            };
+            table_meta = table_meta
+                .navigate_to(self.ctx.clone(), &navigation)
+                .await?;
         }
         Ok(table_meta)
     }
@@ -368,6 +368,7 @@ impl<'a> WindowRewriter<'a> {
                     offset: ll.offset,
                     default: new_default,
                     return_type: ll.return_type.clone(),
+                    ignore_nulls: ll.ignore_nulls,
                 })
             }
             WindowFuncType::NthValue(func) => {
@@ -382,6 +383,7 @@ impl<'a> WindowRewriter<'a> {
                     n: func.n,
                     arg: Box::new(replaced_arg.into()),
                     return_type: func.return_type.clone(),
+                    ignore_nulls: func.ignore_nulls,
                 })
             }
             func => func.clone(),
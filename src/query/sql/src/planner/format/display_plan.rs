@@ -65,6 +65,9 @@ impl Plan {
             Plan::DropDatabase(drop_database) => Ok(format!("{:?}", drop_database)),
             Plan::UndropDatabase(undrop_database) => Ok(format!("{:?}", undrop_database)),
             Plan::RenameDatabase(rename_database) => Ok(format!("{:?}", rename_database)),
+            Plan::SetDatabaseOptions(set_database_options) => {
+                Ok(format!("{:?}", set_database_options))
+            }
 
             // Tables
             Plan::ShowCreateTable(show_create_table) => Ok(format!("{:?}", show_create_table)),
@@ -82,12 +85,18 @@ impl Plan {
                 Ok(format!("{:?}", modify_table_column))
             }
             Plan::DropTableColumn(drop_table_column) => Ok(format!("{:?}", drop_table_column)),
+            Plan::AddTableRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DropTableRowAccessPolicy(p) => Ok(format!("{:?}", p)),
             Plan::AlterTableClusterKey(alter_table_cluster_key) => {
                 Ok(format!("{:?}", alter_table_cluster_key))
             }
             Plan::DropTableClusterKey(drop_table_cluster_key) => {
                 Ok(format!("{:?}", drop_table_cluster_key))
             }
+            Plan::DropTablePartition(drop_table_partition) => {
+                Ok(format!("{:?}", drop_table_partition))
+            }
+            Plan::RotateTableKey(rotate_table_key) => Ok(format!("{:?}", rotate_table_key)),
             Plan::ReclusterTable(recluster_table) => Ok(format!("{:?}", recluster_table)),
             Plan::TruncateTable(truncate_table) => Ok(format!("{:?}", truncate_table)),
             Plan::OptimizeTable(optimize_table) => Ok(format!("{:?}", optimize_table)),
@@ -178,6 +187,17 @@ impl Plan {
             Plan::DropDatamaskPolicy(p) => Ok(format!("{:?}", p)),
             Plan::DescDatamaskPolicy(p) => Ok(format!("{:?}", p)),
 
+            // row access policy
+            Plan::CreateRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DropRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DescRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+
+            // tag and comment
+            Plan::CreateTag(p) => Ok(format!("{:?}", p)),
+            Plan::DropTag(p) => Ok(format!("{:?}", p)),
+            Plan::AlterObjectTag(p) => Ok(format!("{:?}", p)),
+            Plan::CommentOn(p) => Ok(format!("{:?}", p)),
+
             // network policy
             Plan::CreateNetworkPolicy(p) => Ok(format!("{:?}", p)),
             Plan::AlterNetworkPolicy(p) => Ok(format!("{:?}", p)),
@@ -35,7 +35,9 @@ pub struct ColumnStat {
     /// Count of null values
     pub null_count: u64,
 
-    /// Histogram of column
+    /// Histogram of column, built from the blocks sampled by `ANALYZE TABLE`
+    /// if available, otherwise derived from NDV. The most common values
+    /// are only kept for the sampled one.
     pub histogram: Option<Histogram>,
 }
 
@@ -18,6 +18,7 @@ use std::fmt::Debug;
 
 use common_exception::Result;
 use common_expression::arithmetics_type::ResultTypeOfUnary;
+use storages_common_table_meta::meta::ColumnHistogram;
 
 use crate::optimizer::property::datum::Datum;
 
@@ -34,18 +35,24 @@ pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
 /// it is difficult to give the exact frequency of the skew data
 /// when the skew data and other data fall into the same bucket
 ///
-/// We choose this approach because so far the histogram is originally
-/// constructed from NDV(number of distinct values) and the total number
-/// of rows instead of maintaining a real histogram for each column,
-/// which brings the assumption that the data is uniformly distributed.
+/// The histogram is built from the blocks sampled by `ANALYZE TABLE` if
+/// available, see `histogram_from_sample`. Otherwise it's constructed from
+/// NDV(number of distinct values) and the total number of rows, which brings
+/// the assumption that the data is uniformly distributed.
 #[derive(Debug, Clone)]
 pub struct Histogram {
     pub buckets: Vec<HistogramBucket>,
+    /// The most common values and their estimated number of values,
+    /// only available for the sampled histogram.
+    pub most_common_values: Vec<(Datum, f64)>,
 }
 
 impl Histogram {
     pub fn new(buckets: Vec<HistogramBucket>) -> Self {
-        Self { buckets }
+        Self {
+            buckets,
+            most_common_values: vec![],
+        }
     }
 
     /// Get number of buckets
@@ -68,6 +75,28 @@ impl Histogram {
             .fold(0.0, |acc, bucket| acc + bucket.num_distinct())
     }
 
+    /// Estimate the selectivity of `column = datum` with the most common values.
+    /// Returns `None` if the histogram has no most common values.
+    pub fn equal_selectivity(&self, datum: &Datum, ndv: f64) -> Option<f64> {
+        let num_values = self.num_values();
+        if self.most_common_values.is_empty() || num_values == 0.0 {
+            return None;
+        }
+        if let Some((_, count)) = self.most_common_values.iter().find(|(v, _)| v == datum) {
+            return Some((count / num_values).min(1.0));
+        }
+        // The rest values are assumed to be uniformly distributed.
+        let common_values = self
+            .most_common_values
+            .iter()
+            .fold(0.0, |acc, (_, count)| acc + count);
+        let rest_ndv = ndv - self.most_common_values.len() as f64;
+        if rest_ndv < 1.0 {
+            return Some(0.0);
+        }
+        Some(((num_values - common_values).max(0.0) / num_values) / rest_ndv)
+    }
+
     /// Get iterator of buckets
     pub fn buckets_iter(
         &self,
@@ -96,7 +125,7 @@ pub fn histogram_from_ndv(
                 ndv, num_rows
             ))
         } else {
-            Ok(Histogram { buckets: vec![] })
+            Ok(Histogram::new(vec![]))
         };
     }
 
@@ -151,7 +180,44 @@ pub fn histogram_from_ndv(
         buckets.push(bucket);
     }
 
-    Ok(Histogram { buckets })
+    Ok(Histogram::new(buckets))
+}
+
+/// Construct a histogram from the one collected by `ANALYZE TABLE`.
+///
+/// The number of values is scaled to `num_rows`, as the table may have changed since
+/// it was analyzed. Returns `None` if the values can't be represented by `Datum`.
+pub fn histogram_from_sample(histogram: &ColumnHistogram, num_rows: u64) -> Option<Histogram> {
+    if histogram.num_rows == 0 || histogram.buckets.is_empty() {
+        return None;
+    }
+    let scale = num_rows as f64 / histogram.num_rows as f64;
+
+    let first = histogram.buckets.first()?;
+    // The first bucket is a dummy bucket which records the min value of the column,
+    // in line with `histogram_from_ndv`.
+    let mut buckets = vec![HistogramBucket::new(
+        Datum::from_scalar(&first.lower_bound)?,
+        0.0,
+        0.0,
+    )];
+    for bucket in histogram.buckets.iter() {
+        buckets.push(HistogramBucket::new(
+            Datum::from_scalar(&bucket.upper_bound)?,
+            bucket.num_values as f64 * scale,
+            bucket.num_distinct as f64,
+        ));
+    }
+    let most_common_values = histogram
+        .most_common_values
+        .iter()
+        .map(|(value, count)| Some((Datum::from_scalar(value)?, *count as f64 * scale)))
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(Histogram {
+        buckets,
+        most_common_values,
+    })
 }
 
 #[derive(Debug, Clone)]
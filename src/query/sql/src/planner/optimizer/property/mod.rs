@@ -34,6 +34,7 @@ pub use constraint::ConstraintSet;
 pub use datum::Datum;
 pub use enforcer::require_property;
 pub use histogram::histogram_from_ndv;
+pub use histogram::histogram_from_sample;
 pub use histogram::Histogram;
 pub use histogram::HistogramBucket;
 pub use histogram::InterleavedBucket;
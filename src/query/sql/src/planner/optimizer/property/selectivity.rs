@@ -132,8 +132,9 @@ impl<'a> SelectivityEstimator<'a> {
 
             return match op {
                 ComparisonOp::Equal => {
-                    // For equal predicate, we just use cardinality of a single
-                    // value to estimate the selectivity. This assumes that
+                    // For equal predicate, we use the most common values collected
+                    // by `ANALYZE TABLE` if there are, otherwise we just use cardinality
+                    // of a single value to estimate the selectivity. This assumes that
                     // the column is in a uniform distribution.
                     let selectivity = evaluate_equal(column_stat, constant);
                     if update {
@@ -357,6 +358,12 @@ fn compare_equal(datum: &Option<Datum>, column_stat: &ColumnStat) -> f64 {
         }
     }
 
+    if let (Some(constant_datum), Some(histogram)) = (datum, column_stat.histogram.as_ref()) {
+        if let Some(selectivity) = histogram.equal_selectivity(constant_datum, column_stat.ndv) {
+            return selectivity;
+        }
+    }
+
     if column_stat.ndv == 0.0 {
         0.0
     } else {
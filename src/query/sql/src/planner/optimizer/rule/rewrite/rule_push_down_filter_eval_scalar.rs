@@ -129,6 +129,7 @@ impl RulePushDownFilterEvalScalar {
                             offset: ll.offset,
                             default: new_default,
                             return_type: ll.return_type.clone(),
+                            ignore_nulls: ll.ignore_nulls,
                         })
                     }
                     WindowFuncType::NthValue(func) => {
@@ -137,6 +138,7 @@ impl RulePushDownFilterEvalScalar {
                             n: func.n,
                             arg: Box::new(new_arg),
                             return_type: func.return_type.clone(),
+                            ignore_nulls: func.ignore_nulls,
                         })
                     }
                     func => func.clone(),
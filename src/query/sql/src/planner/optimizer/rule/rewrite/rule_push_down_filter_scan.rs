@@ -152,6 +152,7 @@ impl RulePushDownFilterScan {
                             offset: ll.offset,
                             default: new_default,
                             return_type: ll.return_type.clone(),
+                            ignore_nulls: ll.ignore_nulls,
                         })
                     }
                     WindowFuncType::NthValue(func) => {
@@ -161,6 +162,7 @@ impl RulePushDownFilterScan {
                             n: func.n,
                             arg: Box::new(new_arg),
                             return_type: func.return_type.clone(),
+                            ignore_nulls: func.ignore_nulls,
                         })
                     }
                     func => func.clone(),
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use common_expression::DataSchemaRef;
 use common_meta_app::schema::CreateDatabaseReq;
 use common_meta_app::schema::DatabaseMeta;
@@ -122,6 +124,16 @@ impl From<UndropDatabasePlan> for UndropDatabaseReq {
     }
 }
 
+/// Set options.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetDatabaseOptionsPlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub set_options: BTreeMap<String, String>,
+}
+
 /// Use.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct UseDatabasePlan {
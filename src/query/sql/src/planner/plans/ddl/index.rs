@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use common_ast::ast::TableIndexType;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::TableInfo;
@@ -47,3 +49,23 @@ pub struct RefreshIndexPlan {
     pub metadata: MetadataRef,
     pub user_defined_block_name: bool,
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateTableIndexPlan {
+    pub if_not_exists: bool,
+    pub index_name: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub columns: Vec<String>,
+    pub options: BTreeMap<String, String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropTableIndexPlan {
+    pub if_exists: bool,
+    pub index_name: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
@@ -24,6 +24,7 @@ use common_expression::DataField;
 use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
+use common_expression::Scalar;
 use common_expression::TableField;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
@@ -50,6 +51,7 @@ pub struct CreateTablePlan {
     pub options: TableOptions,
     pub field_comments: Vec<String>,
     pub cluster_key: Option<String>,
+    pub partition_key: Option<String>,
     pub as_select: Option<Box<Plan>>,
 }
 
@@ -387,3 +389,36 @@ impl DropTableClusterKeyPlan {
         Arc::new(DataSchema::empty())
     }
 }
+
+/// Drop (or truncate) a partition of a partitioned table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropTablePartitionPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub partition: Vec<Scalar>,
+    /// `TRUNCATE PARTITION` succeeds even if the partition holds no data.
+    pub is_truncate: bool,
+}
+
+impl DropTablePartitionPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+/// Add a new data key to the keyring of an encrypted table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RotateTableKeyPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
+impl RotateTableKeyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
@@ -37,12 +37,14 @@ mod project_set;
 mod recluster_table;
 mod replace;
 mod revert_table;
+pub mod row_access_policy;
 mod runtime_filter_source;
 mod scalar_expr;
 mod scan;
 mod setting;
 pub mod share;
 mod sort;
+pub mod tag;
 mod union_all;
 mod update;
 mod window;
@@ -74,6 +76,7 @@ pub use project_set::*;
 pub use recluster_table::ReclusterTablePlan;
 pub use replace::Replace;
 pub use revert_table::RevertTablePlan;
+pub use row_access_policy::*;
 pub use runtime_filter_source::RuntimeFilterId;
 pub use runtime_filter_source::RuntimeFilterSource;
 pub use scalar_expr::*;
@@ -81,6 +84,7 @@ pub use scan::*;
 pub use setting::*;
 pub use share::*;
 pub use sort::*;
+pub use tag::*;
 pub use union_all::UnionAll;
 pub use update::*;
 pub use window::*;
@@ -24,13 +24,24 @@ use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
 
 use super::data_mask::CreateDatamaskPolicyPlan;
+use super::AddTableRowAccessPolicyPlan;
+use super::AlterObjectTagPlan;
+use super::CommentOnPlan;
 use super::CopyIntoTableMode;
 use super::CreateIndexPlan;
+use super::CreateRowAccessPolicyPlan;
 use super::CreateShareEndpointPlan;
+use super::CreateTableIndexPlan;
+use super::CreateTagPlan;
 use super::DescDatamaskPolicyPlan;
+use super::DescRowAccessPolicyPlan;
 use super::DropDatamaskPolicyPlan;
 use super::DropIndexPlan;
+use super::DropRowAccessPolicyPlan;
 use super::DropShareEndpointPlan;
+use super::DropTableIndexPlan;
+use super::DropTableRowAccessPolicyPlan;
+use super::DropTagPlan;
 use super::ModifyTableColumnPlan;
 use super::RenameTableColumnPlan;
 use super::SetOptionsPlan;
@@ -81,6 +92,7 @@ use crate::plans::DropRolePlan;
 use crate::plans::DropStagePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
+use crate::plans::DropTablePartitionPlan;
 use crate::plans::DropTablePlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
@@ -100,6 +112,8 @@ use crate::plans::Replace;
 use crate::plans::RevertTablePlan;
 use crate::plans::RevokePrivilegePlan;
 use crate::plans::RevokeRolePlan;
+use crate::plans::RotateTableKeyPlan;
+use crate::plans::SetDatabaseOptionsPlan;
 use crate::plans::SetRolePlan;
 use crate::plans::SettingPlan;
 use crate::plans::ShowCreateCatalogPlan;
@@ -163,6 +177,7 @@ pub enum Plan {
     DropDatabase(Box<DropDatabasePlan>),
     UndropDatabase(Box<UndropDatabasePlan>),
     RenameDatabase(Box<RenameDatabasePlan>),
+    SetDatabaseOptions(Box<SetDatabaseOptionsPlan>),
     UseDatabase(Box<UseDatabasePlan>),
 
     // Tables
@@ -176,8 +191,12 @@ pub enum Plan {
     AddTableColumn(Box<AddTableColumnPlan>),
     DropTableColumn(Box<DropTableColumnPlan>),
     ModifyTableColumn(Box<ModifyTableColumnPlan>),
+    AddTableRowAccessPolicy(Box<AddTableRowAccessPolicyPlan>),
+    DropTableRowAccessPolicy(Box<DropTableRowAccessPolicyPlan>),
     AlterTableClusterKey(Box<AlterTableClusterKeyPlan>),
     DropTableClusterKey(Box<DropTableClusterKeyPlan>),
+    DropTablePartition(Box<DropTablePartitionPlan>),
+    RotateTableKey(Box<RotateTableKeyPlan>),
     ReclusterTable(Box<ReclusterTablePlan>),
     RevertTable(Box<RevertTablePlan>),
     TruncateTable(Box<TruncateTablePlan>),
@@ -203,6 +222,8 @@ pub enum Plan {
     CreateIndex(Box<CreateIndexPlan>),
     DropIndex(Box<DropIndexPlan>),
     RefreshIndex(Box<RefreshIndexPlan>),
+    CreateTableIndex(Box<CreateTableIndexPlan>),
+    DropTableIndex(Box<DropTableIndexPlan>),
 
     // Virtual Columns
     CreateVirtualColumns(Box<CreateVirtualColumnsPlan>),
@@ -268,6 +289,17 @@ pub enum Plan {
     DropDatamaskPolicy(Box<DropDatamaskPolicyPlan>),
     DescDatamaskPolicy(Box<DescDatamaskPolicyPlan>),
 
+    // Row access policy
+    CreateRowAccessPolicy(Box<CreateRowAccessPolicyPlan>),
+    DropRowAccessPolicy(Box<DropRowAccessPolicyPlan>),
+    DescRowAccessPolicy(Box<DescRowAccessPolicyPlan>),
+
+    // Tag and comment
+    CreateTag(Box<CreateTagPlan>),
+    DropTag(Box<DropTagPlan>),
+    AlterObjectTag(Box<AlterObjectTagPlan>),
+    CommentOn(Box<CommentOnPlan>),
+
     // Network policy
     CreateNetworkPolicy(Box<CreateNetworkPolicyPlan>),
     AlterNetworkPolicy(Box<AlterNetworkPolicyPlan>),
@@ -322,6 +354,7 @@ impl Display for Plan {
             Plan::UndropDatabase(_) => write!(f, "UndropDatabase"),
             Plan::UseDatabase(_) => write!(f, "UseDatabase"),
             Plan::RenameDatabase(_) => write!(f, "RenameDatabase"),
+            Plan::SetDatabaseOptions(_) => write!(f, "SetDatabaseOptions"),
             Plan::ShowCreateTable(_) => write!(f, "ShowCreateTable"),
             Plan::DescribeTable(_) => write!(f, "DescribeTable"),
             Plan::CreateTable(_) => write!(f, "CreateTable"),
@@ -331,9 +364,13 @@ impl Display for Plan {
             Plan::RenameTableColumn(_) => write!(f, "RenameTableColumn"),
             Plan::AddTableColumn(_) => write!(f, "AddTableColumn"),
             Plan::ModifyTableColumn(_) => write!(f, "ModifyTableColumn"),
+            Plan::AddTableRowAccessPolicy(_) => write!(f, "AddTableRowAccessPolicy"),
+            Plan::DropTableRowAccessPolicy(_) => write!(f, "DropTableRowAccessPolicy"),
             Plan::DropTableColumn(_) => write!(f, "DropTableColumn"),
             Plan::AlterTableClusterKey(_) => write!(f, "AlterTableClusterKey"),
             Plan::DropTableClusterKey(_) => write!(f, "DropTableClusterKey"),
+            Plan::DropTablePartition(_) => write!(f, "DropTablePartition"),
+            Plan::RotateTableKey(_) => write!(f, "RotateTableKey"),
             Plan::ReclusterTable(_) => write!(f, "ReclusterTable"),
             Plan::TruncateTable(_) => write!(f, "TruncateTable"),
             Plan::OptimizeTable(_) => write!(f, "OptimizeTable"),
@@ -347,6 +384,8 @@ impl Display for Plan {
             Plan::CreateIndex(_) => write!(f, "CreateIndex"),
             Plan::DropIndex(_) => write!(f, "DropIndex"),
             Plan::RefreshIndex(_) => write!(f, "RefreshIndex"),
+            Plan::CreateTableIndex(_) => write!(f, "CreateTableIndex"),
+            Plan::DropTableIndex(_) => write!(f, "DropTableIndex"),
             Plan::CreateVirtualColumns(_) => write!(f, "CreateVirtualColumns"),
             Plan::AlterVirtualColumns(_) => write!(f, "AlterVirtualColumns"),
             Plan::DropVirtualColumns(_) => write!(f, "DropVirtualColumns"),
@@ -405,6 +444,19 @@ impl Display for Plan {
             Plan::DescDatamaskPolicy(..) => {
                 write!(f, "Desc Data Mask Policy")
             }
+            Plan::CreateRowAccessPolicy(..) => {
+                write!(f, "Create Row Access Policy")
+            }
+            Plan::DropRowAccessPolicy(..) => {
+                write!(f, "Drop Row Access Policy")
+            }
+            Plan::DescRowAccessPolicy(..) => {
+                write!(f, "Desc Row Access Policy")
+            }
+            Plan::CreateTag(..) => write!(f, "CreateTag"),
+            Plan::DropTag(..) => write!(f, "DropTag"),
+            Plan::AlterObjectTag(..) => write!(f, "AlterObjectTag"),
+            Plan::CommentOn(..) => write!(f, "CommentOn"),
             Plan::SetOptions(..) => {
                 write!(f, "SetOptions")
             }
@@ -457,6 +509,13 @@ impl Plan {
             Plan::CreateDatamaskPolicy(plan) => plan.schema(),
             Plan::DropDatamaskPolicy(plan) => plan.schema(),
             Plan::DescDatamaskPolicy(plan) => plan.schema(),
+            Plan::CreateRowAccessPolicy(plan) => plan.schema(),
+            Plan::DropRowAccessPolicy(plan) => plan.schema(),
+            Plan::DescRowAccessPolicy(plan) => plan.schema(),
+            Plan::CreateTag(plan) => plan.schema(),
+            Plan::DropTag(plan) => plan.schema(),
+            Plan::AlterObjectTag(plan) => plan.schema(),
+            Plan::CommentOn(plan) => plan.schema(),
             Plan::CreateNetworkPolicy(plan) => plan.schema(),
             Plan::AlterNetworkPolicy(plan) => plan.schema(),
             Plan::DropNetworkPolicy(plan) => plan.schema(),
@@ -493,6 +552,7 @@ impl Plan {
                 | Plan::VacuumTable(_)
                 | Plan::VacuumDropTable(_)
                 | Plan::DescDatamaskPolicy(_)
+                | Plan::DescRowAccessPolicy(_)
                 | Plan::DescNetworkPolicy(_)
                 | Plan::ShowNetworkPolicies(_)
         )
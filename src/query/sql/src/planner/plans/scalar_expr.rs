@@ -408,6 +408,8 @@ pub struct LagLeadFunction {
     pub offset: u64,
     pub default: Option<Box<ScalarExpr>>,
     pub return_type: Box<DataType>,
+    /// Skip NULL values when looking for the offset row (`IGNORE NULLS`).
+    pub ignore_nulls: bool,
 }
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
@@ -420,6 +422,8 @@ pub struct NthValueFunction {
     pub n: Option<u64>,
     pub arg: Box<ScalarExpr>,
     pub return_type: Box<DataType>,
+    /// Skip NULL values when counting rows of the frame (`IGNORE NULLS`).
+    pub ignore_nulls: bool,
 }
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
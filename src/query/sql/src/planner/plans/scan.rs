@@ -22,9 +22,11 @@ use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::TableSchemaRef;
 use itertools::Itertools;
+use storages_common_table_meta::meta::ColumnHistogram;
 
 use super::ScalarItem;
 use crate::optimizer::histogram_from_ndv;
+use crate::optimizer::histogram_from_sample;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::ColumnStat;
 use crate::optimizer::ColumnStatSet;
@@ -83,6 +85,8 @@ pub struct Statistics {
     pub statistics: Option<TableStatistics>,
     // statistics will be ignored in comparison and hashing
     pub col_stats: HashMap<IndexType, Option<ColumnStatistics>>,
+    // histograms collected by `ANALYZE TABLE`, will be ignored in comparison and hashing
+    pub histograms: HashMap<IndexType, ColumnHistogram>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -107,6 +111,13 @@ impl Scan {
             .filter(|(col, _)| columns.contains(*col))
             .map(|(col, stat)| (*col, stat.clone()))
             .collect();
+        let histograms = self
+            .statistics
+            .histograms
+            .iter()
+            .filter(|(col, _)| columns.contains(*col))
+            .map(|(col, histogram)| (*col, histogram.clone()))
+            .collect();
 
         Scan {
             table_index: self.table_index,
@@ -117,6 +128,7 @@ impl Scan {
             statistics: Statistics {
                 statistics: self.statistics.statistics,
                 col_stats,
+                histograms,
             },
             prewhere,
             agg_index: self.agg_index.clone(),
@@ -200,13 +212,20 @@ impl Operator for Scan {
                 let min_datum = Datum::from_scalar(&min);
                 let max_datum = Datum::from_scalar(&max);
                 if let (Some(min), Some(max)) = (min_datum, max_datum) {
-                    let histogram = histogram_from_ndv(
-                        col_stat.number_of_distinct_values,
-                        num_rows,
-                        Some((min.clone(), max.clone())),
-                        DEFAULT_HISTOGRAM_BUCKETS,
-                    )
-                    .ok();
+                    let histogram = self
+                        .statistics
+                        .histograms
+                        .get(k)
+                        .and_then(|histogram| histogram_from_sample(histogram, num_rows))
+                        .or_else(|| {
+                            histogram_from_ndv(
+                                col_stat.number_of_distinct_values,
+                                num_rows,
+                                Some((min.clone(), max.clone())),
+                                DEFAULT_HISTOGRAM_BUCKETS,
+                            )
+                            .ok()
+                        });
                     let column_stat = ColumnStat {
                         min,
                         max,
@@ -0,0 +1,159 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::catalog::CATALOG_DEFAULT;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_meta_app::principal::UserIdentity;
+
+/// A resolved catalog object that comments and tags are attached to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ObjectIdent {
+    Database {
+        catalog: String,
+        database: String,
+    },
+    Table {
+        catalog: String,
+        database: String,
+        table: String,
+    },
+    View {
+        catalog: String,
+        database: String,
+        view: String,
+    },
+    Column {
+        catalog: String,
+        database: String,
+        table: String,
+        column: String,
+    },
+    Stage {
+        stage: String,
+    },
+    User {
+        user: UserIdentity,
+    },
+}
+
+impl ObjectIdent {
+    /// The domain recorded in `system.tag_references`.
+    pub fn domain(&self) -> &'static str {
+        match self {
+            ObjectIdent::Database { .. } => "DATABASE",
+            ObjectIdent::Table { .. } => "TABLE",
+            ObjectIdent::View { .. } => "VIEW",
+            ObjectIdent::Column { .. } => "COLUMN",
+            ObjectIdent::Stage { .. } => "STAGE",
+            ObjectIdent::User { .. } => "USER",
+        }
+    }
+
+    /// The fully qualified name recorded in `system.tag_references`,
+    /// the catalog is omitted for the default catalog.
+    pub fn name(&self) -> String {
+        let qualified = |catalog: &str, names: &[&str]| {
+            let mut parts = Vec::with_capacity(names.len() + 1);
+            if catalog != CATALOG_DEFAULT {
+                parts.push(catalog);
+            }
+            parts.extend_from_slice(names);
+            parts.join(".")
+        };
+
+        match self {
+            ObjectIdent::Database { catalog, database } => qualified(catalog, &[database]),
+            ObjectIdent::Table {
+                catalog,
+                database,
+                table,
+            } => qualified(catalog, &[database, table]),
+            ObjectIdent::View {
+                catalog,
+                database,
+                view,
+            } => qualified(catalog, &[database, view]),
+            ObjectIdent::Column {
+                catalog,
+                database,
+                table,
+                column,
+            } => qualified(catalog, &[database, table, column]),
+            ObjectIdent::Stage { stage } => stage.clone(),
+            ObjectIdent::User { user } => user.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateTagPlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub name: String,
+    pub comment: String,
+}
+
+impl CreateTagPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropTagPlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+}
+
+impl DropTagPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ObjectTagAction {
+    Set(Vec<(String, String)>),
+    Unset(Vec<String>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterObjectTagPlan {
+    pub tenant: String,
+    pub object: ObjectIdent,
+    pub action: ObjectTagAction,
+}
+
+impl AlterObjectTagPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommentOnPlan {
+    pub tenant: String,
+    pub object: ObjectIdent,
+    pub comment: String,
+}
+
+impl CommentOnPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
@@ -0,0 +1,194 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// The search expression of the `query` function, a subset of the Lucene query syntax,
+/// e.g. `title:rust AND (body:"inverted index" OR NOT draft)`.
+///
+/// Clauses without an operator between them are combined with `OR`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchQuery {
+    /// Terms searched in `column`, or in all the columns with inverted index if `column` is None.
+    /// All the terms of a phrase must be matched.
+    Terms {
+        column: Option<String>,
+        text: String,
+        phrase: bool,
+    },
+    And(Box<SearchQuery>, Box<SearchQuery>),
+    Or(Box<SearchQuery>, Box<SearchQuery>),
+    Not(Box<SearchQuery>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    LParen,
+    RParen,
+    Colon,
+    And,
+    Or,
+    Not,
+    Word(String),
+    Phrase(String),
+}
+
+pub fn parse_search_query(query: &str) -> Result<SearchQuery> {
+    let tokens = tokenize(query)?;
+    let mut parser = Parser {
+        query,
+        tokens,
+        pos: 0,
+    };
+    let search_query = parser.parse_or(None)?;
+    if parser.pos < parser.tokens.len() {
+        return Err(parser.error("unexpected ')'"));
+    }
+    Ok(search_query)
+}
+
+fn tokenize(query: &str) -> Result<Vec<Token>> {
+    let mut tokens = vec![];
+    let mut chars = query.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {}
+            '(' => tokens.push(Token::LParen),
+            ')' => tokens.push(Token::RParen),
+            ':' => tokens.push(Token::Colon),
+            '"' => {
+                let mut phrase = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some(c) => phrase.push(c),
+                        None => {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "invalid search query '{query}': unterminated phrase"
+                            )));
+                        }
+                    }
+                }
+                tokens.push(Token::Phrase(phrase));
+            }
+            _ => {
+                let mut word = c.to_string();
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || matches!(c, '(' | ')' | ':' | '"') {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+                let token = match word.as_str() {
+                    "AND" => Token::And,
+                    "OR" => Token::Or,
+                    "NOT" => Token::Not,
+                    _ => Token::Word(word),
+                };
+                tokens.push(token);
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    query: &'a str,
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn error(&self, msg: &str) -> ErrorCode {
+        ErrorCode::SemanticError(format!("invalid search query '{}': {}", self.query, msg))
+    }
+
+    fn parse_or(&mut self, column: Option<&str>) -> Result<SearchQuery> {
+        let mut left = self.parse_and(column)?;
+        loop {
+            match self.peek() {
+                Some(Token::Or) => {
+                    self.pos += 1;
+                }
+                Some(Token::LParen | Token::Not | Token::Word(_) | Token::Phrase(_)) => {}
+                _ => return Ok(left),
+            }
+            let right = self.parse_and(column)?;
+            left = SearchQuery::Or(Box::new(left), Box::new(right));
+        }
+    }
+
+    fn parse_and(&mut self, column: Option<&str>) -> Result<SearchQuery> {
+        let mut left = self.parse_not(column)?;
+        while let Some(Token::And) = self.peek() {
+            self.pos += 1;
+            let right = self.parse_not(column)?;
+            left = SearchQuery::And(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_not(&mut self, column: Option<&str>) -> Result<SearchQuery> {
+        if let Some(Token::Not) = self.peek() {
+            self.pos += 1;
+            let query = self.parse_not(column)?;
+            return Ok(SearchQuery::Not(Box::new(query)));
+        }
+        self.parse_primary(column)
+    }
+
+    fn parse_primary(&mut self, column: Option<&str>) -> Result<SearchQuery> {
+        match self.next() {
+            Some(Token::LParen) => {
+                let query = self.parse_or(column)?;
+                match self.next() {
+                    Some(Token::RParen) => Ok(query),
+                    _ => Err(self.error("expected ')'")),
+                }
+            }
+            Some(Token::Word(word)) => {
+                if let Some(Token::Colon) = self.peek() {
+                    if column.is_some() {
+                        return Err(self.error("nested column is not allowed"));
+                    }
+                    self.pos += 1;
+                    return self.parse_primary(Some(&word));
+                }
+                Ok(SearchQuery::Terms {
+                    column: column.map(|c| c.to_string()),
+                    text: word,
+                    phrase: false,
+                })
+            }
+            Some(Token::Phrase(text)) => Ok(SearchQuery::Terms {
+                column: column.map(|c| c.to_string()),
+                text,
+                phrase: true,
+            }),
+            _ => Err(self.error("expected a term")),
+        }
+    }
+}
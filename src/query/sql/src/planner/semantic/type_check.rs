@@ -48,6 +48,8 @@ use common_expression::type_check::check_number;
 use common_expression::types::decimal::DecimalDataType;
 use common_expression::types::decimal::DecimalScalar;
 use common_expression::types::decimal::DecimalSize;
+use common_expression::types::time::MICROS_IN_A_DAY;
+use common_expression::types::timestamp::MICROS_IN_A_SEC;
 use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
 use common_expression::types::NumberScalar;
@@ -58,17 +60,22 @@ use common_expression::FunctionKind;
 use common_expression::RawExpr;
 use common_expression::Scalar;
 use common_expression::TableDataType;
+use common_expression::SEARCH_SCORE_COL_NAME;
 use common_functions::aggregates::AggregateCountFunction;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::is_builtin_function;
+use common_functions::scalars::Tokenizer;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_functions::GENERAL_LAMBDA_FUNCTIONS;
 use common_functions::GENERAL_WINDOW_FUNCTIONS;
 use common_users::UserApiProvider;
 use simsearch::SimSearch;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEX_TOKENIZER;
 
 use super::name_resolution::NameResolutionContext;
 use super::normalize_identifier;
+use super::parse_search_query;
+use super::SearchQuery;
 use crate::binder::wrap_cast;
 use crate::binder::Binder;
 use crate::binder::ColumnBindingBuilder;
@@ -730,10 +737,13 @@ impl<'a> TypeChecker<'a> {
                             "window function {name} can only be used in window clause"
                         )));
                     }
-                    let func = self.resolve_general_window_function(&name, &args).await?;
                     let window = window.as_ref().unwrap();
+                    let func = self
+                        .resolve_general_window_function(&name, &args, window.ignore_nulls)
+                        .await
+                        .map_err(|e| e.set_span(*span))?;
                     let display_name = format!("{:#}", expr);
-                    self.resolve_window(*span, display_name, window, func)
+                    self.resolve_window(*span, display_name, &window.window, func)
                         .await?
                 } else if AggregateFunctionFactory::instance().contains(&name) {
                     if matches!(
@@ -754,9 +764,15 @@ impl<'a> TypeChecker<'a> {
                     self.in_window_function = in_window;
                     if let Some(window) = window {
                         // aggregate window function
+                        if window.ignore_nulls.is_some() {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "IGNORE NULLS and RESPECT NULLS are not supported by aggregate window function {name}"
+                            ))
+                            .set_span(*span));
+                        }
                         let display_name = format!("{:#}", expr);
                         let func = WindowFuncType::Aggregate(new_agg_func);
-                        self.resolve_window(*span, display_name, window, func)
+                        self.resolve_window(*span, display_name, &window.window, func)
                             .await?
                     } else {
                         // aggregate function
@@ -785,10 +801,13 @@ impl<'a> TypeChecker<'a> {
                         .map(|param| param.name.clone())
                         .collect::<Vec<_>>();
 
-                    // TODO: support multiple params
-                    if params.len() != 1 {
+                    // Map lambda functions take the key and the value as parameters.
+                    let is_map_func = name.starts_with("map_");
+                    let num_params = if is_map_func { 2 } else { 1 };
+                    if params.len() != num_params {
                         return Err(ErrorCode::SemanticError(format!(
-                            "incorrect number of parameters in lambda function, {name} expects 1 parameter",
+                            "incorrect number of parameters in lambda function, {name} expects {num_params} parameter{}",
+                            if num_params == 1 { "" } else { "s" }
                         )));
                     }
 
@@ -800,7 +819,7 @@ impl<'a> TypeChecker<'a> {
                     let box (arg, arg_type) = self.resolve(args[0]).await?;
                     match arg_type.remove_nullable() {
                         // Empty array will always return an Empty array
-                        DataType::EmptyArray => Box::new((
+                        DataType::EmptyArray if !is_map_func => Box::new((
                             ConstantExpr {
                                 span: *span,
                                 value: Scalar::EmptyArray,
@@ -808,11 +827,10 @@ impl<'a> TypeChecker<'a> {
                             .into(),
                             DataType::EmptyArray,
                         )),
-                        DataType::Array(box inner_ty) => {
+                        DataType::Array(box inner_ty) if !is_map_func => {
                             let box (lambda_expr, lambda_type) = parse_lambda_expr(
                                 self.ctx.clone(),
-                                &params[0],
-                                &inner_ty,
+                                &[(params[0].clone(), inner_ty.clone())],
                                 &lambda.expr,
                             )?;
 
@@ -843,10 +861,73 @@ impl<'a> TypeChecker<'a> {
                                 return_type,
                             ))
                         }
+                        // Empty map will always return an Empty map
+                        DataType::EmptyMap if is_map_func => Box::new((
+                            ConstantExpr {
+                                span: *span,
+                                value: Scalar::EmptyMap,
+                            }
+                            .into(),
+                            DataType::EmptyMap,
+                        )),
+                        DataType::Map(box DataType::Tuple(fields)) if is_map_func => {
+                            let (key_ty, value_ty) = (fields[0].clone(), fields[1].clone());
+                            let lambda_params = vec![
+                                (params[0].clone(), key_ty.clone()),
+                                (params[1].clone(), value_ty.clone()),
+                            ];
+                            let box (lambda_expr, lambda_type) =
+                                parse_lambda_expr(self.ctx.clone(), &lambda_params, &lambda.expr)?;
+
+                            let map_type = match name.as_str() {
+                                "map_filter" => {
+                                    if lambda_type.remove_nullable() != DataType::Boolean {
+                                        return Err(ErrorCode::SemanticError(
+                                            "invalid lambda function for `map_filter`, the result data type of lambda function must be boolean".to_string()
+                                        ));
+                                    }
+                                    DataType::Map(Box::new(DataType::Tuple(vec![key_ty, value_ty])))
+                                }
+                                "map_transform_keys" => {
+                                    if !is_valid_map_key_type(&lambda_type) {
+                                        return Err(ErrorCode::SemanticError(format!(
+                                            "invalid lambda function for `map_transform_keys`, map keys can not be {lambda_type}"
+                                        )));
+                                    }
+                                    DataType::Map(Box::new(DataType::Tuple(vec![
+                                        lambda_type,
+                                        value_ty,
+                                    ])))
+                                }
+                                _ => DataType::Map(Box::new(DataType::Tuple(vec![
+                                    key_ty,
+                                    lambda_type,
+                                ]))),
+                            };
+                            let return_type = if arg_type.is_nullable() {
+                                DataType::Nullable(Box::new(map_type))
+                            } else {
+                                map_type
+                            };
+                            Box::new((
+                                LambdaFunc {
+                                    span: *span,
+                                    func_name: name.clone(),
+                                    display_name: format!("{:#}", expr),
+                                    args: vec![arg],
+                                    params: lambda_params,
+                                    lambda_expr: Box::new(lambda_expr),
+                                    return_type: Box::new(return_type.clone()),
+                                }
+                                .into(),
+                                return_type,
+                            ))
+                        }
                         _ => {
-                            return Err(ErrorCode::SemanticError(
-                                "invalid arguments for lambda function, argument data type must be array".to_string()
-                            ));
+                            let expected = if is_map_func { "map" } else { "array" };
+                            return Err(ErrorCode::SemanticError(format!(
+                                "invalid arguments for lambda function, argument data type must be {expected}"
+                            )));
                         }
                     }
                 } else {
@@ -971,6 +1052,19 @@ impl<'a> TypeChecker<'a> {
                 self.resolve_map_access(expr, paths).await?
             }
 
+            Expr::AtTimeZone {
+                span,
+                expr,
+                time_zone,
+            } => {
+                // `<expr> AT TIME ZONE <tz>` is a shorthand of `convert_timezone(<tz>, <expr>)`.
+                self.resolve_function(*span, "convert_timezone", vec![], &[
+                    time_zone.as_ref(),
+                    expr.as_ref(),
+                ])
+                .await?
+            }
+
             Expr::Extract {
                 span, kind, expr, ..
             } => self.resolve_extract_expr(*span, kind, expr).await?,
@@ -1196,10 +1290,21 @@ impl<'a> TypeChecker<'a> {
     }
 
     #[async_backtrace::framed]
-    async fn resolve_range_offset(&mut self, bound: &WindowFrameBound) -> Result<Option<Scalar>> {
+    async fn resolve_range_offset(
+        &mut self,
+        bound: &WindowFrameBound,
+        order_type: &DataType,
+    ) -> Result<Option<Scalar>> {
         match bound {
             WindowFrameBound::Following(Some(box expr))
             | WindowFrameBound::Preceding(Some(box expr)) => {
+                let order_type = order_type.remove_nullable();
+                if matches!(order_type, DataType::Date | DataType::Timestamp) {
+                    return self
+                        .resolve_temporal_range_offset(expr, &order_type)
+                        .await
+                        .map(Some);
+                }
                 let box (expr, _) = self.resolve(expr).await?;
                 let (expr, _) =
                     ConstantFolder::fold(&expr.type_check()?, &self.func_ctx, &BUILTIN_FUNCTIONS);
@@ -1216,10 +1321,84 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Resolve the offset of a RANGE frame over a DATE or TIMESTAMP column
+    /// into the physical unit of the column: days for DATE, microseconds for TIMESTAMP.
+    ///
+    /// The offset can be an `INTERVAL` of DAY, HOUR, MINUTE or SECOND,
+    /// or a plain number of days for DATE columns.
+    #[async_backtrace::framed]
+    async fn resolve_temporal_range_offset(
+        &mut self,
+        expr: &Expr,
+        order_type: &DataType,
+    ) -> Result<Scalar> {
+        let (value, unit) = match expr {
+            Expr::Interval { expr, unit, .. } => (expr.as_ref(), Some(*unit)),
+            _ => (expr, None),
+        };
+        let micros_per_unit = match unit {
+            Some(ASTIntervalKind::Day) => MICROS_IN_A_DAY,
+            Some(ASTIntervalKind::Hour) => 3600 * MICROS_IN_A_SEC,
+            Some(ASTIntervalKind::Minute) => 60 * MICROS_IN_A_SEC,
+            Some(ASTIntervalKind::Second) => MICROS_IN_A_SEC,
+            None if *order_type == DataType::Date => MICROS_IN_A_DAY,
+            Some(unit) => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Interval unit {unit} is not allowed in RANGE offset, expected DAY, HOUR, MINUTE or SECOND"
+                ))
+                .set_span(expr.span()));
+            }
+            None => {
+                return Err(ErrorCode::SemanticError(
+                    "RANGE offset over TIMESTAMP column must be an INTERVAL".to_string(),
+                )
+                .set_span(expr.span()));
+            }
+        };
+
+        let box (value, _) = self.resolve(value).await?;
+        let value = ScalarExpr::CastExpr(CastExpr {
+            span: value.span(),
+            is_try: false,
+            argument: Box::new(value),
+            target_type: Box::new(DataType::Number(NumberDataType::Int64)),
+        })
+        .as_expr()?;
+        let value =
+            check_number::<_, i64>(value.span(), &self.func_ctx, &value, &BUILTIN_FUNCTIONS)?;
+        let micros = value.checked_mul(micros_per_unit).ok_or_else(|| {
+            ErrorCode::SemanticError("RANGE offset is out of range".to_string())
+                .set_span(expr.span())
+        })?;
+
+        if *order_type == DataType::Timestamp {
+            return Ok(Scalar::Number(NumberScalar::Int64(micros)));
+        }
+        if micros % MICROS_IN_A_DAY != 0 {
+            return Err(ErrorCode::SemanticError(
+                "RANGE offset over DATE column must be a whole number of days".to_string(),
+            )
+            .set_span(expr.span()));
+        }
+        let days = i32::try_from(micros / MICROS_IN_A_DAY).map_err(|_| {
+            ErrorCode::SemanticError("RANGE offset is out of range".to_string())
+                .set_span(expr.span())
+        })?;
+        Ok(Scalar::Number(NumberScalar::Int32(days)))
+    }
+
     #[async_backtrace::framed]
-    async fn resolve_window_range_frame(&mut self, frame: WindowFrame) -> Result<WindowFuncFrame> {
-        let start_offset = self.resolve_range_offset(&frame.start_bound).await?;
-        let end_offset = self.resolve_range_offset(&frame.end_bound).await?;
+    async fn resolve_window_range_frame(
+        &mut self,
+        frame: WindowFrame,
+        order_type: &DataType,
+    ) -> Result<WindowFuncFrame> {
+        let start_offset = self
+            .resolve_range_offset(&frame.start_bound, order_type)
+            .await?;
+        let end_offset = self
+            .resolve_range_offset(&frame.end_bound, order_type)
+            .await?;
 
         let units = match frame.units {
             WindowFrameUnits::Rows => WindowFuncFrameUnits::Rows,
@@ -1260,6 +1439,24 @@ impl<'a> TypeChecker<'a> {
                     end_bound: WindowFuncFrameBound::Following(None),
                 });
             }
+            // With `IGNORE NULLS`, the offset is counted over the non-NULL rows,
+            // so the frame covers all the rows before (or after) the current row.
+            WindowFuncType::LagLead(lag_lead) if lag_lead.ignore_nulls && lag_lead.offset > 0 => {
+                let one = Some(Scalar::Number(NumberScalar::UInt64(1)));
+                return Ok(if lag_lead.is_lag {
+                    WindowFuncFrame {
+                        units: WindowFuncFrameUnits::Rows,
+                        start_bound: WindowFuncFrameBound::Preceding(None),
+                        end_bound: WindowFuncFrameBound::Preceding(one),
+                    }
+                } else {
+                    WindowFuncFrame {
+                        units: WindowFuncFrameUnits::Rows,
+                        start_bound: WindowFuncFrameBound::Following(one),
+                        end_bound: WindowFuncFrameBound::Following(None),
+                    }
+                });
+            }
             WindowFuncType::LagLead(lag_lead) if lag_lead.is_lag => {
                 return Ok(WindowFuncFrame {
                     units: WindowFuncFrameUnits::Rows,
@@ -1307,7 +1504,8 @@ impl<'a> TypeChecker<'a> {
                         order_by.len()
                     )).set_span(span));
                 }
-                self.resolve_window_range_frame(frame).await
+                let order_type = order_by[0].expr.data_type()?;
+                self.resolve_window_range_frame(frame, &order_type).await
             } else {
                 self.resolve_window_rows_frame(frame)
             }
@@ -1332,7 +1530,20 @@ impl<'a> TypeChecker<'a> {
         &mut self,
         func_name: &str,
         args: &[&Expr],
+        ignore_nulls: Option<bool>,
     ) -> Result<WindowFuncType> {
+        if ignore_nulls.is_some()
+            && !matches!(
+                func_name,
+                "lag" | "lead" | "first_value" | "first" | "last_value" | "last" | "nth_value"
+            )
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "IGNORE NULLS and RESPECT NULLS are not supported by window function {func_name}"
+            )));
+        }
+        let ignore_nulls = ignore_nulls.unwrap_or(false);
+
         // try to resolve window function without arguments first
         if let Ok(window_func) = WindowFuncType::from_name(func_name) {
             return Ok(window_func);
@@ -1350,12 +1561,22 @@ impl<'a> TypeChecker<'a> {
 
         match func_name {
             "lag" | "lead" => {
-                self.resolve_lag_lead_window_function(func_name, &arguments, &arg_types)
-                    .await
+                self.resolve_lag_lead_window_function(
+                    func_name,
+                    &arguments,
+                    &arg_types,
+                    ignore_nulls,
+                )
+                .await
             }
             "first_value" | "first" | "last_value" | "last" | "nth_value" => {
-                self.resolve_nth_value_window_function(func_name, &arguments, &arg_types)
-                    .await
+                self.resolve_nth_value_window_function(
+                    func_name,
+                    &arguments,
+                    &arg_types,
+                    ignore_nulls,
+                )
+                .await
             }
             "ntile" => self.resolve_ntile_window_function(&arguments).await,
             _ => Err(ErrorCode::UnknownFunction(format!(
@@ -1370,6 +1591,7 @@ impl<'a> TypeChecker<'a> {
         func_name: &str,
         args: &[ScalarExpr],
         arg_types: &[DataType],
+        ignore_nulls: bool,
     ) -> Result<WindowFuncType> {
         if args.is_empty() || args.len() > 3 {
             return Err(ErrorCode::InvalidArgument(
@@ -1428,6 +1650,7 @@ impl<'a> TypeChecker<'a> {
             offset: offset.unsigned_abs(),
             default: cast_default,
             return_type: Box::new(return_type),
+            ignore_nulls,
         }))
     }
 
@@ -1437,6 +1660,7 @@ impl<'a> TypeChecker<'a> {
         func_name: &str,
         args: &[ScalarExpr],
         arg_types: &[DataType],
+        ignore_nulls: bool,
     ) -> Result<WindowFuncType> {
         Ok(match func_name {
             "first_value" | "first" => {
@@ -1450,6 +1674,7 @@ impl<'a> TypeChecker<'a> {
                     n: Some(1),
                     arg: Box::new(args[0].clone()),
                     return_type: Box::new(return_type),
+                    ignore_nulls,
                 })
             }
             "last_value" | "last" => {
@@ -1463,6 +1688,7 @@ impl<'a> TypeChecker<'a> {
                     n: None,
                     arg: Box::new(args[0].clone()),
                     return_type: Box::new(return_type),
+                    ignore_nulls,
                 })
             }
             _ => {
@@ -1496,6 +1722,7 @@ impl<'a> TypeChecker<'a> {
                     n: Some(n),
                     arg: Box::new(args[0].clone()),
                     return_type: Box::new(return_type),
+                    ignore_nulls,
                 })
             }
         })
@@ -2048,6 +2275,9 @@ impl<'a> TypeChecker<'a> {
             "array_sort",
             "array_aggregate",
             "array_reduce",
+            "match",
+            "query",
+            "score",
         ]
     }
 
@@ -2376,10 +2606,213 @@ impl<'a> TypeChecker<'a> {
                     "Array aggregate function name be must a constant string",
                 )))
             }
+            ("match", &[column, terms]) => {
+                let box (column, _) = match self.resolve(column).await {
+                    Ok(res) => res,
+                    Err(e) => return Some(Err(e)),
+                };
+                let box (terms, _) = match self.resolve(terms).await {
+                    Ok(res) => res,
+                    Err(e) => return Some(Err(e)),
+                };
+                let tokenizer = match &column {
+                    ScalarExpr::BoundColumnRef(BoundColumnRef { column, .. }) => {
+                        self.inverted_index_tokenizer(column)
+                    }
+                    _ => None,
+                };
+                let Some(tokenizer) = tokenizer else {
+                    return Some(Err(ErrorCode::SemanticError(
+                        "match function requires a column with inverted index",
+                    )
+                    .set_span(span)));
+                };
+                let tokenizer = ConstantExpr {
+                    span,
+                    value: Scalar::String(tokenizer.name().as_bytes().to_vec()),
+                };
+                Some(
+                    self.resolve_scalar_function_call(span, "match", vec![], vec![
+                        column,
+                        terms,
+                        tokenizer.into(),
+                    ])
+                    .await,
+                )
+            }
+            ("query", &[search_query]) => {
+                let res: Result<FunctionCall> = try {
+                    let box (search_query, _) = self.resolve(search_query).await?;
+                    let Ok(ConstantExpr {
+                        value: Scalar::String(search_query),
+                        ..
+                    }) = ConstantExpr::try_from(search_query)
+                    else {
+                        return Some(Err(ErrorCode::SemanticError(
+                            "query function requires a constant string argument",
+                        )
+                        .set_span(span)));
+                    };
+                    let search_query = String::from_utf8(search_query)?;
+                    let search_query = parse_search_query(&search_query)?;
+                    self.resolve_search_query(span, &search_query)?
+                };
+                Some(match res {
+                    Ok(func) => {
+                        self.resolve_scalar_function_call(
+                            span,
+                            &func.func_name,
+                            func.params,
+                            func.arguments,
+                        )
+                        .await
+                    }
+                    Err(e) => Err(e.set_span(span)),
+                })
+            }
+            ("score", &[]) => Some(
+                self.resolve(&Expr::ColumnRef {
+                    span,
+                    database: None,
+                    table: None,
+                    column: ColumnID::Name(Identifier::from_name(SEARCH_SCORE_COL_NAME)),
+                })
+                .await,
+            ),
             _ => None,
         }
     }
 
+    /// Returns the tokenizer of the inverted index built on the column, if any.
+    fn inverted_index_tokenizer(&self, column: &ColumnBinding) -> Option<Tokenizer> {
+        let metadata = self.metadata.read();
+        let ColumnEntry::BaseTableColumn(BaseTableColumn {
+            table_index,
+            column_name,
+            path_indices: None,
+            ..
+        }) = metadata.column(column.index)
+        else {
+            return None;
+        };
+        let table = metadata.table(*table_index).table();
+        let table_info = table.get_table_info();
+        let column_id = table_info.schema().column_id_of(column_name).ok()?;
+        let index = table_info
+            .meta
+            .indexes
+            .values()
+            .find(|index| index.column_ids.contains(&column_id))?;
+        match index.options.get(OPT_KEY_INVERTED_INDEX_TOKENIZER) {
+            Some(tokenizer) => Tokenizer::try_create(tokenizer).ok(),
+            None => Some(Tokenizer::English),
+        }
+    }
+
+    /// Rewrites the search expression of `query` into `match` functions combined with
+    /// `and`, `or` and `not`.
+    fn resolve_search_query(&self, span: Span, search_query: &SearchQuery) -> Result<FunctionCall> {
+        let func = |func_name: &str, arguments: Vec<ScalarExpr>| FunctionCall {
+            span,
+            params: vec![],
+            arguments,
+            func_name: func_name.to_string(),
+        };
+        let fold = |func_name: &str, funcs: Vec<FunctionCall>| {
+            funcs
+                .into_iter()
+                .reduce(|left, right| func(func_name, vec![left.into(), right.into()]))
+        };
+
+        match search_query {
+            SearchQuery::Terms {
+                column,
+                text,
+                phrase,
+            } => {
+                let columns = match column {
+                    Some(name) => {
+                        let column = self
+                            .bind_context
+                            .columns
+                            .iter()
+                            .find(|c| &c.column_name == name)
+                            .ok_or_else(|| {
+                                ErrorCode::SemanticError(format!(
+                                    "column `{name}` in search query doesn't exist"
+                                ))
+                            })?;
+                        let tokenizer = self.inverted_index_tokenizer(column).ok_or_else(|| {
+                            ErrorCode::SemanticError(format!(
+                                "column `{name}` in search query doesn't have inverted index"
+                            ))
+                        })?;
+                        vec![(column.clone(), tokenizer)]
+                    }
+                    None => self
+                        .bind_context
+                        .columns
+                        .iter()
+                        .filter(|c| c.visibility == Visibility::Visible)
+                        .filter_map(|c| Some((c.clone(), self.inverted_index_tokenizer(c)?)))
+                        .collect(),
+                };
+
+                let mut exprs = Vec::with_capacity(columns.len());
+                for (column, tokenizer) in columns {
+                    // A phrase matches when all of its terms are matched, the other terms
+                    // match when any of them is matched.
+                    let terms = if *phrase {
+                        tokenizer.tokenize(text.as_bytes())
+                    } else {
+                        vec![text.clone()]
+                    };
+                    let matches = terms
+                        .into_iter()
+                        .map(|terms| {
+                            func("match", vec![
+                                BoundColumnRef {
+                                    span,
+                                    column: column.clone(),
+                                }
+                                .into(),
+                                ConstantExpr {
+                                    span,
+                                    value: Scalar::String(terms.into_bytes()),
+                                }
+                                .into(),
+                                ConstantExpr {
+                                    span,
+                                    value: Scalar::String(tokenizer.name().as_bytes().to_vec()),
+                                }
+                                .into(),
+                            ])
+                        })
+                        .collect();
+                    if let Some(expr) = fold("and", matches) {
+                        exprs.push(expr);
+                    }
+                }
+                fold("or", exprs).ok_or_else(|| {
+                    ErrorCode::SemanticError(format!(
+                        "no column with inverted index can be searched for '{text}'"
+                    ))
+                })
+            }
+            SearchQuery::And(left, right) => Ok(func("and", vec![
+                self.resolve_search_query(span, left)?.into(),
+                self.resolve_search_query(span, right)?.into(),
+            ])),
+            SearchQuery::Or(left, right) => Ok(func("or", vec![
+                self.resolve_search_query(span, left)?.into(),
+                self.resolve_search_query(span, right)?.into(),
+            ])),
+            SearchQuery::Not(query) => Ok(func("not", vec![
+                self.resolve_search_query(span, query)?.into(),
+            ])),
+        }
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     async fn resolve_trim_function(
@@ -3002,6 +3435,19 @@ impl<'a> TypeChecker<'a> {
                     ),
                     target_type: target_type.clone(),
                 }),
+                Expr::AtTimeZone {
+                    span,
+                    expr,
+                    time_zone,
+                } => Ok(Expr::AtTimeZone {
+                    span: *span,
+                    expr: Box::new(
+                        self.clone_expr_with_replacement(expr.as_ref(), replacement_fn)?,
+                    ),
+                    time_zone: Box::new(
+                        self.clone_expr_with_replacement(time_zone.as_ref(), replacement_fn)?,
+                    ),
+                }),
                 Expr::Extract { span, kind, expr } => Ok(Expr::Extract {
                     span: *span,
                     kind: *kind,
@@ -3243,6 +3689,8 @@ pub fn resolve_type_name(type_name: &TypeName) -> Result<TableDataType> {
         }
         TypeName::String => TableDataType::String,
         TypeName::Timestamp => TableDataType::Timestamp,
+        TypeName::TimestampTz => TableDataType::TimestampTz,
+        TypeName::Time => TableDataType::Time,
         TypeName::Date => TableDataType::Date,
         TypeName::Array(item_type) => TableDataType::Array(Box::new(resolve_type_name(item_type)?)),
         TypeName::Map { key_type, val_type } => {
@@ -3292,6 +3740,8 @@ pub fn resolve_type_name(type_name: &TypeName) -> Result<TableDataType> {
             TableDataType::Nullable(Box::new(resolve_type_name(inner_type)?))
         }
         TypeName::Variant => TableDataType::Variant,
+        TypeName::Geometry => TableDataType::Geometry,
+        TypeName::Binary => TableDataType::Binary,
     };
 
     Ok(data_type)
@@ -3373,3 +3823,12 @@ fn check_prefix(like_str: &str) -> bool {
     }
     true
 }
+
+// Keep in sync with the key types accepted by the `map` function.
+fn is_valid_map_key_type(ty: &DataType) -> bool {
+    ty.is_boolean()
+        || ty.is_string()
+        || ty.is_numeric()
+        || ty.is_decimal()
+        || ty.is_date_or_date_time()
+}
@@ -12,9 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_expression::types::number::NumberScalar;
+use common_expression::Scalar;
+use common_sql::optimizer::histogram_from_sample;
 use common_sql::optimizer::Datum;
 use common_sql::optimizer::Histogram;
 use common_sql::optimizer::HistogramBucket;
+use storages_common_table_meta::meta::ColumnHistogram;
+use storages_common_table_meta::meta::ColumnHistogramBucket;
 
 #[test]
 fn test_histogram() {
@@ -28,3 +33,45 @@ fn test_histogram() {
     assert_eq!(histogram.num_values(), 4.0);
     assert_eq!(histogram.num_distinct_values(), 2.0);
 }
+
+#[test]
+fn test_histogram_from_sample() {
+    let int = |v: i32| Scalar::Number(NumberScalar::Int32(v));
+    let sample = ColumnHistogram {
+        num_rows: 10,
+        buckets: vec![
+            ColumnHistogramBucket {
+                lower_bound: int(1),
+                upper_bound: int(1),
+                num_values: 7,
+                num_distinct: 1,
+            },
+            ColumnHistogramBucket {
+                lower_bound: int(2),
+                upper_bound: int(4),
+                num_values: 3,
+                num_distinct: 3,
+            },
+        ],
+        most_common_values: vec![(int(1), 7)],
+    };
+
+    let histogram = histogram_from_sample(&sample, 100).unwrap();
+    // The first bucket is the dummy one records the min value.
+    assert_eq!(histogram.num_buckets(), 3);
+    assert_eq!(histogram.buckets[0].upper_bound(), &Datum::Int(1));
+    assert_eq!(histogram.num_values(), 100.0);
+    assert_eq!(histogram.most_common_values, vec![(Datum::Int(1), 70.0)]);
+
+    // The most common value.
+    assert_eq!(histogram.equal_selectivity(&Datum::Int(1), 4.0), Some(0.7));
+    // The rest values share the rest rows evenly.
+    let selectivity = histogram.equal_selectivity(&Datum::Int(3), 4.0).unwrap();
+    assert!((selectivity - 0.1).abs() < 1e-9);
+
+    // Histogram derived from NDV has no most common values.
+    assert_eq!(
+        Histogram::new(vec![]).equal_selectivity(&Datum::Int(1), 4.0),
+        None
+    );
+}
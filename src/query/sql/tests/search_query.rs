@@ -0,0 +1,77 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_sql::parse_search_query;
+use common_sql::SearchQuery;
+
+fn terms(column: Option<&str>, text: &str, phrase: bool) -> Box<SearchQuery> {
+    Box::new(SearchQuery::Terms {
+        column: column.map(|c| c.to_string()),
+        text: text.to_string(),
+        phrase,
+    })
+}
+
+#[test]
+fn test_parse_search_query() -> Result<()> {
+    assert_eq!(*terms(None, "rust", false), parse_search_query("rust")?);
+
+    assert_eq!(
+        SearchQuery::And(
+            terms(Some("title"), "foo", false),
+            terms(None, "bar", false)
+        ),
+        parse_search_query("title:foo AND bar")?
+    );
+
+    // AND binds tighter than OR, adjacent clauses are OR-ed.
+    assert_eq!(
+        SearchQuery::Or(
+            Box::new(SearchQuery::Or(
+                terms(None, "a", false),
+                Box::new(SearchQuery::And(
+                    terms(None, "b", false),
+                    terms(None, "c", false)
+                ))
+            )),
+            terms(None, "d", false)
+        ),
+        parse_search_query("a OR b AND c d")?
+    );
+
+    assert_eq!(
+        SearchQuery::And(
+            Box::new(SearchQuery::Or(
+                terms(Some("title"), "rust", false),
+                terms(Some("title"), "go", false)
+            )),
+            Box::new(SearchQuery::Not(terms(Some("body"), "web framework", true)))
+        ),
+        parse_search_query(r#"title:(rust OR go) AND NOT body:"web framework""#)?
+    );
+
+    for query in [
+        "",
+        "title:(rust",
+        "rust)",
+        "title:body:rust",
+        r#"body:"unterminated"#,
+        "rust AND",
+    ] {
+        assert!(parse_search_query(query).is_err(), "{query}");
+    }
+
+    Ok(())
+}
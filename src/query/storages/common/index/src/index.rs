@@ -23,6 +23,8 @@ pub trait Index {
             DataType::Number(_)
                 | DataType::Date
                 | DataType::Timestamp
+                | DataType::TimestampTz
+                | DataType::Time
                 | DataType::String
                 | DataType::Decimal(_)
         )
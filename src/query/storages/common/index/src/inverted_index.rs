@@ -0,0 +1,558 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+use common_expression::types::DataType;
+use common_expression::types::F32;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::ConstantFolder;
+use common_expression::DataBlock;
+use common_expression::Expr;
+use common_expression::FieldIndex;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_expression::Value;
+use common_functions::scalars::Tokenizer;
+use common_functions::BUILTIN_FUNCTIONS;
+use storages_common_table_meta::meta::InvertedIndexStatistics;
+
+use crate::FilterEvalResult;
+
+/// InvertedIndex keeps, for each indexed string column of a data block, the postings
+/// of all the terms produced by the tokenizer of the index, and the statistics used
+/// to compute the BM25 score of the rows.
+///
+/// It is used to prune blocks for `match(column, 'terms')`: if none of the terms is in
+/// the postings, no row of the block matches. The postings of the terms are also used
+/// to score the matched rows without reading the data, with the statistics of the
+/// whole table collected by `ANALYZE TABLE` if it is analyzed, so that the scores of the
+/// rows in different blocks are comparable.
+///
+/// The index is stored in the same layout as [`crate::BloomIndex`], one column named
+/// `Inverted(column_id:tokenizer)` per indexed column, holding the encoded [`InvertedIndexColumn`].
+pub struct InvertedIndex {
+    pub func_ctx: FunctionContext,
+
+    /// The schema of the index block.
+    pub index_schema: TableSchemaRef,
+
+    /// The index of each column in `index_schema`.
+    pub columns: Vec<Arc<InvertedIndexColumn>>,
+}
+
+impl InvertedIndex {
+    pub fn from_index_block(
+        func_ctx: FunctionContext,
+        index_schema: TableSchemaRef,
+        index_block: &DataBlock,
+    ) -> Result<Self> {
+        let columns = index_block
+            .columns()
+            .iter()
+            .map(|entry| match &entry.value {
+                Value::Scalar(Scalar::String(bytes)) => {
+                    Ok(Arc::new(InvertedIndexColumn::from_bytes(bytes)?))
+                }
+                Value::Column(Column::String(column)) if column.len() == 1 => {
+                    Ok(Arc::new(InvertedIndexColumn::from_bytes(unsafe {
+                        column.index_unchecked(0)
+                    })?))
+                }
+                _ => Err(ErrorCode::StorageOther(
+                    "unexpected inverted index data, expect one string value per column",
+                )),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            func_ctx,
+            index_schema,
+            columns,
+        })
+    }
+
+    /// Create an inverted index of the columns in `index_columns`, which maps the offset
+    /// of a column in the data blocks to the column and the tokenizer of its index.
+    #[minitrace::trace(name = "block_inverted_index_create")]
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        data_blocks_tobe_indexed: &[&DataBlock],
+        index_columns: BTreeMap<FieldIndex, (TableField, Tokenizer)>,
+    ) -> Result<Option<Self>> {
+        if data_blocks_tobe_indexed.is_empty() {
+            return Err(ErrorCode::BadArguments("data blocks is empty"));
+        }
+
+        let mut index_fields = Vec::with_capacity(index_columns.len());
+        let mut columns = Vec::with_capacity(index_columns.len());
+        for (index, (field, tokenizer)) in index_columns.into_iter() {
+            let field_type = &DataType::from(field.data_type());
+            if !Self::supported_data_type(field_type) {
+                continue;
+            }
+
+            let mut index_column = InvertedIndexColumn::default();
+            for block in data_blocks_tobe_indexed {
+                let column = block
+                    .get_by_offset(index)
+                    .value
+                    .convert_to_full_column(field_type, block.num_rows());
+                let (column, validity) = match column {
+                    Column::Nullable(box inner) => (inner.column, Some(inner.validity)),
+                    column => (column, None),
+                };
+                let Column::String(column) = column else {
+                    continue;
+                };
+                for (row, value) in column.iter().enumerate() {
+                    let is_null = validity
+                        .as_ref()
+                        .map(|validity| !validity.get_bit(row))
+                        .unwrap_or(false);
+                    if is_null {
+                        index_column.add_row(&[]);
+                    } else {
+                        index_column.add_row(&tokenizer.tokenize(value));
+                    }
+                }
+            }
+
+            let index_name = Self::build_index_column_name(&field, tokenizer);
+            index_fields.push(TableField::new(&index_name, TableDataType::String));
+            columns.push(Arc::new(index_column));
+        }
+
+        if index_fields.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self {
+            func_ctx,
+            index_schema: Arc::new(TableSchema::new(index_fields)),
+            columns,
+        }))
+    }
+
+    pub fn serialize_to_data_block(&self) -> Result<DataBlock> {
+        let index_columns = self
+            .columns
+            .iter()
+            .map(|column| {
+                let value = Value::Scalar(Scalar::String(column.to_bytes()));
+                BlockEntry::new(DataType::String, value)
+            })
+            .collect();
+        Ok(DataBlock::new(index_columns, 1))
+    }
+
+    /// Apply the predicate expression, return the result.
+    ///
+    /// The `match` predicates whose terms are all absent from the postings are rewritten
+    /// to `false`, then the expression is folded. If it folds to `false`, the block can be skipped.
+    #[minitrace::trace(name = "block_inverted_index_eval")]
+    pub fn apply(
+        &self,
+        mut expr: Expr<String>,
+        data_schema: TableSchemaRef,
+    ) -> Result<FilterEvalResult> {
+        visit_expr_match(
+            &mut expr,
+            &mut |span, col_name, tokenizer, terms, return_type| {
+                let index_column =
+                    self.find_column(data_schema.field_with_name(col_name)?, tokenizer);
+                match index_column {
+                    Some(index_column) if !index_column.contains_any(terms) => {
+                        Ok(Some(Expr::Constant {
+                            span,
+                            scalar: Scalar::Boolean(false),
+                            data_type: return_type.clone(),
+                        }))
+                    }
+                    _ => Ok(None),
+                }
+            },
+        )?;
+
+        let (new_expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+
+        match new_expr {
+            Expr::Constant {
+                scalar: Scalar::Boolean(false),
+                ..
+            } => Ok(FilterEvalResult::MustFalse),
+            _ => Ok(FilterEvalResult::Uncertain),
+        }
+    }
+
+    /// Compute the BM25 scores of the rows that match any `match` predicate of the expression.
+    ///
+    /// The score of a row is the sum of its scores for each predicate, the rows are sorted
+    /// by their offsets in the block. Returns `None` if a searched column is not indexed.
+    ///
+    /// `table_statistics` are the statistics of the index columns over the table, keyed by
+    /// the index column name. The statistics of the block are used for a column without them.
+    pub fn search_scores(
+        &self,
+        expr: &Expr<String>,
+        data_schema: TableSchemaRef,
+        table_statistics: Option<&HashMap<String, InvertedIndexStatistics>>,
+    ) -> Result<Option<Vec<(usize, F32)>>> {
+        let mut scores: BTreeMap<usize, f32> = BTreeMap::new();
+        let mut all_indexed = true;
+        visit_expr_match(
+            &mut expr.clone(),
+            &mut |_, col_name, tokenizer, terms, _| {
+                let field = data_schema.field_with_name(col_name)?;
+                match self.find_column(field, tokenizer) {
+                    Some(index_column) => {
+                        let index_name = Self::build_index_column_name(field, tokenizer);
+                        let stats = table_statistics.and_then(|stats| stats.get(&index_name));
+                        for (row, score) in index_column.bm25(terms, stats) {
+                            *scores.entry(row).or_default() += score;
+                        }
+                    }
+                    None => all_indexed = false,
+                }
+                Ok(None)
+            },
+        )?;
+
+        if !all_indexed {
+            return Ok(None);
+        }
+        Ok(Some(
+            scores
+                .into_iter()
+                .map(|(row, score)| (row, F32::from(score)))
+                .collect(),
+        ))
+    }
+
+    /// The statistics of each index column of the block, keyed by the index column name.
+    pub fn statistics(&self) -> HashMap<String, InvertedIndexStatistics> {
+        self.index_schema
+            .fields()
+            .iter()
+            .zip(self.columns.iter())
+            .map(|(field, column)| (field.name().clone(), column.statistics()))
+            .collect()
+    }
+
+    /// Find all columns that are searched by `match` in the expression, with the tokenizer used.
+    pub fn find_match_columns(
+        expr: &Expr<String>,
+        fields: Vec<TableField>,
+    ) -> Result<Vec<(TableField, Tokenizer)>> {
+        let mut cols: Vec<(TableField, Tokenizer)> = Vec::new();
+        visit_expr_match(&mut expr.clone(), &mut |_, col_name, tokenizer, _, _| {
+            if let Some(v) = fields.iter().find(|f| f.name() == col_name) {
+                if !cols
+                    .iter()
+                    .any(|(c, t)| c.column_id() == v.column_id() && *t == tokenizer)
+                {
+                    cols.push((v.clone(), tokenizer));
+                }
+            }
+            Ok(None)
+        })?;
+        Ok(cols)
+    }
+
+    /// The index of a column will be stored with field name 'Inverted(column_id:tokenizer)',
+    /// so that an index recreated with another tokenizer never uses the stale postings.
+    pub fn build_index_column_name(field: &TableField, tokenizer: Tokenizer) -> String {
+        format!("Inverted({}:{})", field.column_id(), tokenizer.name())
+    }
+
+    fn find_column(
+        &self,
+        field: &TableField,
+        tokenizer: Tokenizer,
+    ) -> Option<&InvertedIndexColumn> {
+        let name = Self::build_index_column_name(field, tokenizer);
+        let idx = self.index_schema.index_of(&name).ok()?;
+        self.columns.get(idx).map(|column| column.as_ref())
+    }
+
+    pub fn supported_type(data_type: &TableDataType) -> bool {
+        matches!(data_type.remove_nullable(), TableDataType::String)
+    }
+
+    pub fn supported_data_type(data_type: &DataType) -> bool {
+        matches!(data_type.remove_nullable(), DataType::String)
+    }
+}
+
+/// The postings and the BM25 statistics of one indexed column of a block.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InvertedIndexColumn {
+    /// The number of terms of each row.
+    pub doc_lengths: Vec<u32>,
+    /// The offsets of the rows which contain the term, and the term frequency in each row.
+    pub postings: BTreeMap<String, Vec<(u32, u32)>>,
+}
+
+impl InvertedIndexColumn {
+    // The parameters of BM25, same as the defaults of Lucene.
+    const BM25_K1: f32 = 1.2;
+    const BM25_B: f32 = 0.75;
+
+    pub fn add_row(&mut self, tokens: &[String]) {
+        let row = self.doc_lengths.len() as u32;
+        self.doc_lengths.push(tokens.len() as u32);
+
+        let mut freqs: HashMap<&String, u32> = HashMap::new();
+        for token in tokens {
+            *freqs.entry(token).or_default() += 1;
+        }
+        for (token, freq) in freqs {
+            self.postings
+                .entry(token.clone())
+                .or_default()
+                .push((row, freq));
+        }
+    }
+
+    pub fn contains_any(&self, terms: &[String]) -> bool {
+        terms.iter().any(|term| self.postings.contains_key(term))
+    }
+
+    /// The number of rows, the total length of the rows and the document frequency of
+    /// each term of the block.
+    pub fn statistics(&self) -> InvertedIndexStatistics {
+        InvertedIndexStatistics {
+            num_docs: self.doc_lengths.len() as u64,
+            total_length: self.doc_lengths.iter().map(|l| *l as u64).sum(),
+            doc_freqs: self
+                .postings
+                .iter()
+                .map(|(term, postings)| (term.clone(), postings.len() as u64))
+                .collect(),
+        }
+    }
+
+    /// The BM25 scores of the rows that contain any of the terms.
+    ///
+    /// The statistics (number of rows, average row length and document frequency)
+    /// are the ones of the table if `table_stats` is given, otherwise the ones of the block.
+    /// The table statistics may be collected before the block is written, so the ones of
+    /// the block are taken as a lower bound.
+    pub fn bm25(
+        &self,
+        terms: &[String],
+        table_stats: Option<&InvertedIndexStatistics>,
+    ) -> BTreeMap<usize, f32> {
+        let block_docs = self.doc_lengths.len() as u64;
+        let (num_docs, total_length, doc_freqs) = match table_stats {
+            Some(stats) if stats.num_docs >= block_docs => {
+                (stats.num_docs, stats.total_length, Some(&stats.doc_freqs))
+            }
+            _ => {
+                let total_length = self.doc_lengths.iter().map(|l| *l as u64).sum();
+                (block_docs, total_length, None)
+            }
+        };
+        let avg_length = if total_length == 0 {
+            1.0
+        } else {
+            total_length as f32 / num_docs as f32
+        };
+
+        let mut scores = BTreeMap::new();
+        for term in terms {
+            let Some(postings) = self.postings.get(term) else {
+                continue;
+            };
+            let doc_freq = doc_freqs
+                .and_then(|freqs| freqs.get(term).copied())
+                .unwrap_or_default()
+                .max(postings.len() as u64)
+                .min(num_docs) as f32;
+            let num_docs = num_docs as f32;
+            let idf = (1.0 + (num_docs - doc_freq + 0.5) / (doc_freq + 0.5)).ln();
+            for (row, freq) in postings {
+                let freq = *freq as f32;
+                let length = self.doc_lengths[*row as usize] as f32;
+                let norm =
+                    Self::BM25_K1 * (1.0 - Self::BM25_B + Self::BM25_B * length / avg_length);
+                *scores.entry(*row as usize).or_default() +=
+                    idf * freq * (Self::BM25_K1 + 1.0) / (freq + norm);
+            }
+        }
+        scores
+    }
+
+    /// Encode as little-endian u32s:
+    /// `num_rows, doc_lengths.., num_terms, (term_len, term_bytes.., num_postings, (row, freq)..)..`
+    pub fn to_bytes(&self) -> Vec<u8> {
+        fn put_u32(buf: &mut Vec<u8>, v: u32) {
+            buf.extend_from_slice(&v.to_le_bytes());
+        }
+
+        let mut buf = Vec::new();
+        put_u32(&mut buf, self.doc_lengths.len() as u32);
+        for length in &self.doc_lengths {
+            put_u32(&mut buf, *length);
+        }
+        put_u32(&mut buf, self.postings.len() as u32);
+        for (term, postings) in &self.postings {
+            put_u32(&mut buf, term.len() as u32);
+            buf.extend_from_slice(term.as_bytes());
+            put_u32(&mut buf, postings.len() as u32);
+            for (row, freq) in postings {
+                put_u32(&mut buf, *row);
+                put_u32(&mut buf, *freq);
+            }
+        }
+        buf
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = BytesReader { bytes, pos: 0 };
+        let num_rows = reader.get_u32()? as usize;
+        let mut doc_lengths = Vec::with_capacity(num_rows);
+        for _ in 0..num_rows {
+            doc_lengths.push(reader.get_u32()?);
+        }
+        let num_terms = reader.get_u32()?;
+        let mut postings = BTreeMap::new();
+        for _ in 0..num_terms {
+            let len = reader.get_u32()? as usize;
+            let term = String::from_utf8(reader.get_bytes(len)?.to_vec()).map_err(|e| {
+                ErrorCode::StorageOther(format!("invalid inverted index term, {e}"))
+            })?;
+            let num_postings = reader.get_u32()? as usize;
+            let mut term_postings = Vec::with_capacity(num_postings);
+            for _ in 0..num_postings {
+                let row = reader.get_u32()?;
+                if row as usize >= num_rows {
+                    return Err(ErrorCode::StorageOther(
+                        "invalid inverted index, row offset out of range",
+                    ));
+                }
+                term_postings.push((row, reader.get_u32()?));
+            }
+            postings.insert(term, term_postings);
+        }
+        Ok(Self {
+            doc_lengths,
+            postings,
+        })
+    }
+}
+
+struct BytesReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> BytesReader<'a> {
+    fn get_bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.pos + len > self.bytes.len() {
+            return Err(ErrorCode::StorageOther(
+                "invalid inverted index, unexpected end of data",
+            ));
+        }
+        let bytes = &self.bytes[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn get_u32(&mut self) -> Result<u32> {
+        let bytes = self.get_bytes(4)?;
+        Ok(u32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+}
+
+/// Find `match(Column, <constant>, <constant>)`, the arguments are the searched text,
+/// and the tokenizer of the inverted index on the column, which are added by the binder.
+///
+/// The visitor is called with the terms of the searched text.
+fn visit_expr_match(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(
+        Span,
+        &str,
+        Tokenizer,
+        &[String],
+        &DataType,
+    ) -> Result<Option<Expr<String>>>,
+) -> Result<()> {
+    if let Expr::FunctionCall {
+        span,
+        function,
+        args,
+        return_type,
+        ..
+    } = expr
+    {
+        if function.signature.name == "match" {
+            if let [
+                Expr::ColumnRef { id, data_type, .. },
+                Expr::Constant {
+                    scalar: Scalar::String(text),
+                    ..
+                },
+                Expr::Constant {
+                    scalar: Scalar::String(tokenizer),
+                    ..
+                },
+            ] = args.as_slice()
+            {
+                let tokenizer = std::str::from_utf8(tokenizer)
+                    .ok()
+                    .and_then(|name| Tokenizer::try_create(name).ok());
+                if let Some(tokenizer) = tokenizer {
+                    if InvertedIndex::supported_data_type(data_type) {
+                        let col_name = id.to_string();
+                        let terms = tokenizer.tokenize(text);
+                        // If the visitor returns a new expression, then replace with the current expression.
+                        if let Some(new_expr) =
+                            visitor(*span, &col_name, tokenizer, &terms, return_type)?
+                        {
+                            *expr = new_expr;
+                        }
+                        return Ok(());
+                    }
+                }
+            }
+        }
+    }
+
+    // Otherwise, rewrite sub expressions.
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_match(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_match(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
@@ -18,6 +18,8 @@
 mod bloom_index;
 pub mod filters;
 mod index;
+mod inverted_index;
+mod ngram_index;
 mod page_index;
 mod range_index;
 
@@ -25,5 +27,8 @@ pub use bloom_index::BloomIndex;
 pub use bloom_index::BloomIndexMeta;
 pub use bloom_index::FilterEvalResult;
 pub use index::Index;
+pub use inverted_index::InvertedIndex;
+pub use inverted_index::InvertedIndexColumn;
+pub use ngram_index::NgramIndex;
 pub use page_index::PageIndex;
 pub use range_index::RangeIndex;
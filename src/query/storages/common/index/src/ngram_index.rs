@@ -0,0 +1,411 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+use common_expression::eval_function;
+use common_expression::types::DataType;
+use common_expression::types::NumberScalar;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::ConstantFolder;
+use common_expression::DataBlock;
+use common_expression::Expr;
+use common_expression::FieldIndex;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+
+use crate::filters::Filter;
+use crate::filters::FilterBuilder;
+use crate::filters::Xor8Builder;
+use crate::filters::Xor8Filter;
+use crate::FilterEvalResult;
+
+/// NgramIndex keeps, for each indexed string column of a data block, a xor filter
+/// of all the byte n-grams of the lower-cased values.
+///
+/// It is used to prune blocks for substring predicates which the bloom index can not
+/// handle, like `s LIKE '%error-code%'`, `lower(s) LIKE '%error%'` or `position('error' IN s) > 0`:
+/// if any n-gram of the searched literal is absent from the filter, the block does not
+/// contain the literal.
+///
+/// The filters are stored in the same layout as [`crate::BloomIndex`], one column
+/// named `Ngram(column_id)` per indexed column.
+pub struct NgramIndex {
+    pub func_ctx: FunctionContext,
+
+    /// The schema of the filter block.
+    pub filter_schema: TableSchemaRef,
+
+    /// filters.
+    pub filters: Vec<Arc<Xor8Filter>>,
+}
+
+impl NgramIndex {
+    /// Length in bytes of the n-grams.
+    pub const GRAM_SIZE: usize = 3;
+
+    pub fn from_filter_block(
+        func_ctx: FunctionContext,
+        filter_schema: TableSchemaRef,
+        filters: Vec<Arc<Xor8Filter>>,
+    ) -> Self {
+        Self {
+            func_ctx,
+            filter_schema,
+            filters,
+        }
+    }
+
+    /// Create a filter block from source data.
+    ///
+    /// Columns without any n-gram (e.g. all values are shorter than [`Self::GRAM_SIZE`]) are skipped.
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        data_blocks_tobe_indexed: &[&DataBlock],
+        ngram_columns_map: BTreeMap<FieldIndex, TableField>,
+    ) -> Result<Option<Self>> {
+        if data_blocks_tobe_indexed.is_empty() {
+            return Err(ErrorCode::BadArguments("block is empty"));
+        }
+
+        let mut filter_fields = vec![];
+        let mut filters = vec![];
+        for (index, field) in ngram_columns_map.into_iter() {
+            let field_type = &data_blocks_tobe_indexed[0].get_by_offset(index).data_type;
+            if !Self::supported_data_type(field_type) {
+                continue;
+            }
+            let source_columns = data_blocks_tobe_indexed
+                .iter()
+                .map(|block| {
+                    let value = &block.get_by_offset(index).value;
+                    value.convert_to_full_column(field_type, block.num_rows())
+                })
+                .collect::<Vec<_>>();
+            let column = Column::concat(&source_columns);
+
+            // use the same case folding as the `lower` function, so that both
+            // `s LIKE ...` and `lower(s) LIKE ...` can be answered by the filter.
+            let (value, _) = eval_function(
+                None,
+                "lower",
+                [(Value::Column(column.clone()), field_type.clone())],
+                &func_ctx,
+                column.len(),
+                &BUILTIN_FUNCTIONS,
+            )?;
+            let column = value
+                .convert_to_full_column(field_type, column.len())
+                .remove_nullable();
+            let Column::String(column) = column else {
+                continue;
+            };
+
+            let mut grams = HashSet::new();
+            for value in column.iter() {
+                grams.extend(value.windows(Self::GRAM_SIZE));
+            }
+            if grams.is_empty() {
+                continue;
+            }
+
+            let mut filter_builder = Xor8Builder::create();
+            filter_builder.add_keys(&grams.into_iter().collect::<Vec<_>>());
+            let filter = filter_builder.build()?;
+
+            let filter_name = Self::build_filter_column_name(&field);
+            filter_fields.push(TableField::new(&filter_name, TableDataType::String));
+            filters.push(Arc::new(filter));
+        }
+
+        if filter_fields.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self {
+            func_ctx,
+            filter_schema: Arc::new(TableSchema::new(filter_fields)),
+            filters,
+        }))
+    }
+
+    pub fn serialize_to_data_block(&self) -> Result<DataBlock> {
+        let mut filter_columns = Vec::with_capacity(self.filters.len());
+        for filter in &self.filters {
+            let serialized_bytes = filter.to_bytes()?;
+            let filter_value = Value::Scalar(Scalar::String(serialized_bytes));
+            filter_columns.push(BlockEntry::new(DataType::String, filter_value));
+        }
+        Ok(DataBlock::new(filter_columns, 1))
+    }
+
+    /// Apply the predicate expression, return the result.
+    ///
+    /// Substring predicates whose literal can not be found in the filter are rewritten
+    /// to their "not found" result, `false` for `LIKE` and `0` for `locate`/`position`/`instr`,
+    /// then the expression is folded. If it folds to `false`, the block can be skipped.
+    #[minitrace::trace(name = "block_ngram_index_eval")]
+    pub fn apply(
+        &self,
+        mut expr: Expr<String>,
+        data_schema: TableSchemaRef,
+    ) -> Result<FilterEvalResult> {
+        visit_expr_substring_predicate(&mut expr, &mut |span, col_name, needles, return_type| {
+            let filter_column =
+                &Self::build_filter_column_name(data_schema.field_with_name(col_name)?);
+
+            if self.find(filter_column, needles)? == FilterEvalResult::MustFalse {
+                let scalar = match return_type.remove_nullable() {
+                    DataType::Boolean => Scalar::Boolean(false),
+                    _ => Scalar::Number(NumberScalar::UInt64(0)),
+                };
+                Ok(Some(Expr::Constant {
+                    span,
+                    scalar,
+                    data_type: return_type.clone(),
+                }))
+            } else {
+                Ok(None)
+            }
+        })?;
+
+        let (new_expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+
+        match new_expr {
+            Expr::Constant {
+                scalar: Scalar::Boolean(false),
+                ..
+            } => Ok(FilterEvalResult::MustFalse),
+            _ => Ok(FilterEvalResult::Uncertain),
+        }
+    }
+
+    /// Find all columns that are searched by a substring predicate in the expression.
+    pub fn find_substring_columns(
+        expr: &Expr<String>,
+        fields: Vec<TableField>,
+    ) -> Result<Vec<TableField>> {
+        let mut cols: Vec<TableField> = Vec::new();
+        visit_expr_substring_predicate(&mut expr.clone(), &mut |_, col_name, _, _| {
+            if let Some(v) = fields.iter().find(|f| f.name() == col_name) {
+                if !cols.iter().any(|c| c.column_id() == v.column_id()) {
+                    cols.push(v.clone());
+                }
+            }
+            Ok(None)
+        })?;
+        Ok(cols)
+    }
+
+    /// The filter of a column will be stored with field name 'Ngram(column_id)'
+    pub fn build_filter_column_name(field: &TableField) -> String {
+        format!("Ngram({})", field.column_id())
+    }
+
+    fn find(&self, filter_column: &str, needles: &[Vec<u8>]) -> Result<FilterEvalResult> {
+        if !self.filter_schema.has_field(filter_column) {
+            // The column doesn't have a filter.
+            return Ok(FilterEvalResult::Uncertain);
+        }
+
+        let idx = self.filter_schema.index_of(filter_column)?;
+        let filter = &self.filters[idx];
+
+        let contains = needles.iter().all(|needle| {
+            needle
+                .windows(Self::GRAM_SIZE)
+                .all(|gram| filter.contains(gram))
+        });
+
+        if contains {
+            Ok(FilterEvalResult::Uncertain)
+        } else {
+            Ok(FilterEvalResult::MustFalse)
+        }
+    }
+
+    pub fn supported_type(data_type: &TableDataType) -> bool {
+        matches!(data_type.remove_nullable(), TableDataType::String)
+    }
+
+    pub fn supported_data_type(data_type: &DataType) -> bool {
+        matches!(data_type.remove_nullable(), DataType::String)
+    }
+}
+
+/// Find patterns like `Column LIKE <constant>`, `lower(Column) LIKE <constant>`,
+/// `locate(<constant>, Column)`, `position(<constant>, Column)` or `instr(Column, <constant>)`.
+///
+/// The visitor is called with the literals that must all be contained in `lower(Column)`
+/// for the predicate to be true. Only literals that are at least [`NgramIndex::GRAM_SIZE`]
+/// bytes long are passed.
+fn visit_expr_substring_predicate(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(Span, &str, &[Vec<u8>], &DataType) -> Result<Option<Expr<String>>>,
+) -> Result<()> {
+    if let Expr::FunctionCall {
+        span,
+        function,
+        args,
+        return_type,
+        ..
+    } = expr
+    {
+        let matched = match (function.signature.name.as_str(), args.as_slice()) {
+            (
+                "like",
+                [
+                    haystack,
+                    Expr::Constant {
+                        scalar: Scalar::String(pattern),
+                        ..
+                    },
+                ],
+            ) => substring_column(haystack)
+                .map(|(col_name, lowered)| (col_name, lowered, like_pattern_literals(pattern))),
+            (
+                "locate" | "position",
+                [
+                    Expr::Constant {
+                        scalar: Scalar::String(needle),
+                        ..
+                    },
+                    haystack,
+                ],
+            )
+            | (
+                "instr",
+                [
+                    haystack,
+                    Expr::Constant {
+                        scalar: Scalar::String(needle),
+                        ..
+                    },
+                ],
+            ) => substring_column(haystack)
+                .map(|(col_name, lowered)| (col_name, lowered, vec![needle.clone()])),
+            _ => None,
+        };
+
+        if let Some((col_name, lowered, literals)) = matched {
+            let needles = literals
+                .into_iter()
+                .filter_map(|literal| {
+                    if lowered {
+                        Some(literal)
+                    } else {
+                        // `lower` maps each character independently, so the lower-cased
+                        // literal is contained in the lower-cased value.
+                        let literal = std::str::from_utf8(&literal).ok()?;
+                        Some(
+                            literal
+                                .chars()
+                                .flat_map(char::to_lowercase)
+                                .collect::<String>()
+                                .into_bytes(),
+                        )
+                    }
+                })
+                .filter(|needle| needle.len() >= NgramIndex::GRAM_SIZE)
+                .collect::<Vec<_>>();
+            if !needles.is_empty() {
+                let col_name = col_name.to_string();
+                // If the visitor returns a new expression, then replace with the current expression.
+                if let Some(new_expr) = visitor(*span, &col_name, &needles, return_type)? {
+                    *expr = new_expr;
+                }
+                return Ok(());
+            }
+        }
+    }
+
+    // Otherwise, rewrite sub expressions.
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_substring_predicate(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_substring_predicate(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
+/// Returns the column name of `Column` or `lower(Column)`, and whether it is lower-cased.
+fn substring_column(expr: &Expr<String>) -> Option<(&str, bool)> {
+    match expr {
+        Expr::ColumnRef { id, data_type, .. } if NgramIndex::supported_data_type(data_type) => {
+            Some((id.as_str(), false))
+        }
+        Expr::FunctionCall { function, args, .. } if function.signature.name == "lower" => {
+            match args.as_slice() {
+                [Expr::ColumnRef { id, data_type, .. }]
+                    if NgramIndex::supported_data_type(data_type) =>
+                {
+                    Some((id.as_str(), true))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Split a LIKE pattern into the literals between the wildcards `%` and `_`.
+fn like_pattern_literals(pattern: &[u8]) -> Vec<Vec<u8>> {
+    let mut literals = vec![];
+    let mut literal = vec![];
+    let mut iter = pattern.iter();
+    while let Some(c) = iter.next() {
+        match c {
+            b'\\' => match iter.next() {
+                Some(escaped @ (b'%' | b'_' | b'\\')) => literal.push(*escaped),
+                // the fast paths of `like` match a backslash before other characters
+                // literally while the general path drops it, be conservative here.
+                Some(_) => {
+                    if !literal.is_empty() {
+                        literals.push(std::mem::take(&mut literal));
+                    }
+                }
+                None => literal.push(*c),
+            },
+            b'%' | b'_' => {
+                if !literal.is_empty() {
+                    literals.push(std::mem::take(&mut literal));
+                }
+            }
+            _ => literal.push(*c),
+        }
+    }
+    if !literal.is_empty() {
+        literals.push(literal);
+    }
+    literals
+}
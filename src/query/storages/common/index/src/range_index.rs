@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_exception::Result;
+use common_expression::types::geometry::GeometryDomain;
 use common_expression::types::nullable::NullableDomain;
 use common_expression::types::number::SimpleDomain;
 use common_expression::types::string::StringDomain;
@@ -21,7 +22,9 @@ use common_expression::types::DateType;
 use common_expression::types::NumberDataType;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
+use common_expression::types::TimeType;
 use common_expression::types::TimestampType;
+use common_expression::types::TimestampTzType;
 use common_expression::types::ValueType;
 use common_expression::with_number_mapped_type;
 use common_expression::ColumnId;
@@ -192,10 +195,26 @@ pub fn statistics_to_domain(mut stats: Vec<&ColumnStatistics>, data_type: &DataT
                     min: TimestampType::try_downcast_scalar(&stat.min().as_ref()).unwrap(),
                     max: TimestampType::try_downcast_scalar(&stat.max().as_ref()).unwrap(),
                 }),
+                DataType::TimestampTz => TimestampTzType::upcast_domain(SimpleDomain {
+                    min: TimestampTzType::try_downcast_scalar(&stat.min().as_ref()).unwrap(),
+                    max: TimestampTzType::try_downcast_scalar(&stat.max().as_ref()).unwrap(),
+                }),
+                DataType::Time => TimeType::upcast_domain(SimpleDomain {
+                    min: TimeType::try_downcast_scalar(&stat.min().as_ref()).unwrap(),
+                    max: TimeType::try_downcast_scalar(&stat.max().as_ref()).unwrap(),
+                }),
                 DataType::Date => DateType::upcast_domain(SimpleDomain {
                     min: DateType::try_downcast_scalar(&stat.min().as_ref()).unwrap(),
                     max: DateType::try_downcast_scalar(&stat.max().as_ref()).unwrap(),
                 }),
+                DataType::Geometry => match (stat.min(), stat.max()) {
+                    (Scalar::Geometry(min), Scalar::Geometry(max)) => {
+                        GeometryDomain::from_statistics(min, max)
+                            .map(Domain::Geometry)
+                            .unwrap_or_else(|| Domain::full(data_type))
+                    }
+                    _ => Domain::full(data_type),
+                },
                 // Unsupported data type
                 _ => Domain::full(data_type),
             })
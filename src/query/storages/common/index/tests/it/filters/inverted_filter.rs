@@ -0,0 +1,237 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::type_check::check_function;
+use common_expression::types::number::UInt8Type;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::Expr;
+use common_expression::FromData;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_functions::scalars::Tokenizer;
+use common_functions::BUILTIN_FUNCTIONS;
+use storages_common_index::FilterEvalResult;
+use storages_common_index::InvertedIndex;
+use storages_common_index::InvertedIndexColumn;
+use storages_common_table_meta::meta::InvertedIndexStatistics;
+
+#[test]
+fn test_tokenizer() -> Result<()> {
+    let english = Tokenizer::try_create("English")?;
+    assert_eq!(english.tokenize(b"The Quick, brown-fox!  is 42"), vec![
+        "quick", "brown", "fox", "42"
+    ]);
+
+    let chinese = Tokenizer::try_create("chinese")?;
+    assert_eq!(
+        chinese.tokenize("数据库 Databend，云数仓。".as_bytes()),
+        vec!["数据", "据库", "databend", "云数", "数仓"]
+    );
+    assert_eq!(chinese.tokenize("a 云".as_bytes()), vec!["a", "云"]);
+
+    assert!(Tokenizer::try_create("french").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_inverted_filter() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("0", TableDataType::Number(NumberDataType::UInt8)),
+        TableField::new("1", TableDataType::String),
+    ]));
+
+    let blocks = vec![DataBlock::new_from_columns(vec![
+        UInt8Type::from_data(vec![1, 2, 3]),
+        StringType::from_data(vec![
+            "connection reset by peer",
+            "the peer closed the connection, reset the connection",
+            "disk full",
+        ]),
+    ])];
+    let blocks_ref = blocks.iter().collect::<Vec<_>>();
+
+    let mut index_columns = BTreeMap::new();
+    index_columns.insert(1, (schema.field(1).clone(), Tokenizer::English));
+    let index =
+        InvertedIndex::try_create(FunctionContext::default(), &blocks_ref, index_columns)?.unwrap();
+
+    // the index survives serialization
+    let index_block = index.serialize_to_data_block()?;
+    let index = InvertedIndex::from_index_block(
+        FunctionContext::default(),
+        index.index_schema.clone(),
+        &index_block,
+    )?;
+
+    let cases = vec![
+        (search("1", "peer", "english"), FilterEvalResult::Uncertain),
+        (
+            search("1", "PEER timeout", "english"),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            search("1", "timeout", "english"),
+            FilterEvalResult::MustFalse,
+        ),
+        // stop words are not indexed, nothing to search
+        (search("1", "the", "english"), FilterEvalResult::MustFalse),
+        // the index is built with another tokenizer
+        (
+            search("1", "timeout", "chinese"),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            func("not", vec![search("1", "timeout", "english")]),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            func("and", vec![
+                search("1", "timeout", "english"),
+                search("1", "disk", "english"),
+            ]),
+            FilterEvalResult::MustFalse,
+        ),
+        (
+            func("or", vec![
+                search("1", "timeout", "english"),
+                search("1", "disk", "english"),
+            ]),
+            FilterEvalResult::Uncertain,
+        ),
+    ];
+
+    for (expr, expected) in cases {
+        assert_eq!(
+            index.apply(expr.clone(), schema.clone())?,
+            expected,
+            "{}",
+            expr.sql_display()
+        );
+    }
+
+    let expr = search("1", "reset connection", "english");
+    let columns = InvertedIndex::find_match_columns(&expr, schema.fields.clone())?;
+    assert_eq!(columns, vec![(schema.field(1).clone(), Tokenizer::English)]);
+
+    let expr = search("1", "connection", "english");
+    let scores = index.search_scores(&expr, schema.clone(), None)?.unwrap();
+    assert_eq!(
+        scores.iter().map(|(row, _)| *row).collect::<Vec<_>>(),
+        vec![0, 1]
+    );
+    // the second row contains "connection" twice.
+    assert!(scores[1].1 > scores[0].1);
+
+    let expr = search("1", "reset", "chinese");
+    assert!(index.search_scores(&expr, schema.clone(), None)?.is_none());
+
+    let index =
+        InvertedIndex::try_create(FunctionContext::default(), &blocks_ref, BTreeMap::new())?;
+    assert!(index.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_inverted_index_column_bytes() -> Result<()> {
+    let mut column = InvertedIndexColumn::default();
+    column.add_row(&["a".to_string(), "b".to_string(), "a".to_string()]);
+    column.add_row(&[]);
+    column.add_row(&["b".to_string()]);
+
+    let decoded = InvertedIndexColumn::from_bytes(&column.to_bytes())?;
+    assert_eq!(decoded, column);
+    assert_eq!(decoded.doc_lengths, vec![3, 0, 1]);
+    assert_eq!(decoded.postings["a"], vec![(0, 2)]);
+    assert_eq!(decoded.postings["b"], vec![(0, 1), (2, 1)]);
+
+    assert!(InvertedIndexColumn::from_bytes(&column.to_bytes()[..10]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_inverted_index_table_statistics() -> Result<()> {
+    let tokens = |s: &str| s.split(' ').map(|t| t.to_string()).collect::<Vec<_>>();
+
+    // "rust" is in every row of the first block, and in one row of the second.
+    let mut first = InvertedIndexColumn::default();
+    first.add_row(&tokens("rust book"));
+    first.add_row(&tokens("rust guide"));
+    let mut second = InvertedIndexColumn::default();
+    second.add_row(&tokens("rust book"));
+    second.add_row(&tokens("sql guide"));
+    second.add_row(&tokens("data book"));
+
+    let mut stats = first.statistics();
+    assert_eq!(stats.num_docs, 2);
+    assert_eq!(stats.total_length, 4);
+    assert_eq!(stats.doc_freqs["rust"], 2);
+    stats.merge(&second.statistics());
+    assert_eq!(stats, InvertedIndexStatistics {
+        num_docs: 5,
+        total_length: 10,
+        doc_freqs: BTreeMap::from([
+            ("book".to_string(), 3),
+            ("data".to_string(), 1),
+            ("guide".to_string(), 2),
+            ("rust".to_string(), 3),
+            ("sql".to_string(), 1),
+        ]),
+    });
+
+    let terms = tokens("rust");
+    // with the statistics of the blocks, the same row "rust book" is scored differently.
+    assert_ne!(first.bm25(&terms, None)[&0], second.bm25(&terms, None)[&0]);
+    // with the statistics of the table, the scores are comparable.
+    assert_eq!(
+        first.bm25(&terms, Some(&stats))[&0],
+        second.bm25(&terms, Some(&stats))[&0]
+    );
+    Ok(())
+}
+
+fn search(column: &str, text: &str, tokenizer: &str) -> Expr<String> {
+    func("match", vec![
+        Expr::ColumnRef {
+            span: None,
+            id: column.to_string(),
+            data_type: DataType::String,
+            display_name: column.to_string(),
+        },
+        constant(text),
+        constant(tokenizer),
+    ])
+}
+
+fn constant(s: &str) -> Expr<String> {
+    Expr::Constant {
+        span: None,
+        scalar: Scalar::String(s.as_bytes().to_vec()),
+        data_type: DataType::String,
+    }
+}
+
+fn func(name: &str, args: Vec<Expr<String>>) -> Expr<String> {
+    check_function(None, name, &[], &args, &BUILTIN_FUNCTIONS).unwrap()
+}
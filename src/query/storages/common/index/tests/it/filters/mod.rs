@@ -13,4 +13,6 @@
 // limitations under the License.
 
 mod bloom_filter;
+mod inverted_filter;
+mod ngram_filter;
 mod xor8;
@@ -0,0 +1,176 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::type_check::check_function;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::number::UInt8Type;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::Expr;
+use common_expression::FromData;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_functions::BUILTIN_FUNCTIONS;
+use storages_common_index::FilterEvalResult;
+use storages_common_index::NgramIndex;
+
+#[test]
+fn test_ngram_filter() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("0", TableDataType::Number(NumberDataType::UInt8)),
+        TableField::new("1", TableDataType::String),
+    ]));
+
+    let blocks = vec![
+        DataBlock::new_from_columns(vec![
+            UInt8Type::from_data(vec![1, 2]),
+            StringType::from_data(vec!["connection reset by peer", "ERROR-CODE 1045"]),
+        ]),
+        DataBlock::new_from_columns(vec![
+            UInt8Type::from_data(vec![3]),
+            StringType::from_data(vec!["disk full"]),
+        ]),
+    ];
+    let blocks_ref = blocks.iter().collect::<Vec<_>>();
+
+    let mut ngram_columns = BTreeMap::new();
+    ngram_columns.insert(1, schema.field(1).clone());
+    let index =
+        NgramIndex::try_create(FunctionContext::default(), &blocks_ref, ngram_columns)?.unwrap();
+
+    let cases = vec![
+        (like(column("1"), "%reset%"), FilterEvalResult::Uncertain),
+        (like(column("1"), "%disk%full"), FilterEvalResult::Uncertain),
+        // the index is case insensitive
+        (
+            like(column("1"), "%error-code%"),
+            FilterEvalResult::Uncertain,
+        ),
+        (like(column("1"), "%timeout%"), FilterEvalResult::MustFalse),
+        (
+            like(column("1"), "%reset%timeout%"),
+            FilterEvalResult::MustFalse,
+        ),
+        (like(column("1"), "%res_t%"), FilterEvalResult::Uncertain),
+        // literals shorter than the gram size can not be checked
+        (like(column("1"), "%xy%"), FilterEvalResult::Uncertain),
+        (
+            like(lower(column("1")), "%error%"),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            like(lower(column("1")), "%ERROR%"),
+            FilterEvalResult::MustFalse,
+        ),
+        (
+            found(locate("peer", column("1"))),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            found(locate("quota", column("1"))),
+            FilterEvalResult::MustFalse,
+        ),
+        (
+            found(locate("quota", lower(column("1")))),
+            FilterEvalResult::MustFalse,
+        ),
+        (
+            func("not", vec![like(column("1"), "%timeout%")]),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            func("or", vec![
+                like(column("1"), "%timeout%"),
+                like(column("1"), "%peer%"),
+            ]),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            func("and", vec![
+                like(column("1"), "%timeout%"),
+                like(column("1"), "%peer%"),
+            ]),
+            FilterEvalResult::MustFalse,
+        ),
+    ];
+
+    for (expr, expected) in cases {
+        let fields = NgramIndex::find_substring_columns(&expr, schema.fields.clone())?;
+        assert_eq!(fields.len(), 1, "{}", expr.sql_display());
+        assert_eq!(
+            index.apply(expr.clone(), schema.clone())?,
+            expected,
+            "{}",
+            expr.sql_display()
+        );
+    }
+
+    // column without ngram index
+    let expr = like(column("1"), "%timeout%");
+    let index = NgramIndex::try_create(FunctionContext::default(), &blocks_ref, BTreeMap::new())?;
+    assert!(index.is_none());
+    assert!(NgramIndex::find_substring_columns(&expr, vec![schema.field(0).clone()])?.is_empty());
+
+    Ok(())
+}
+
+fn column(name: &str) -> Expr<String> {
+    Expr::ColumnRef {
+        span: None,
+        id: name.to_string(),
+        data_type: DataType::String,
+        display_name: name.to_string(),
+    }
+}
+
+fn constant(s: &str) -> Expr<String> {
+    Expr::Constant {
+        span: None,
+        scalar: Scalar::String(s.as_bytes().to_vec()),
+        data_type: DataType::String,
+    }
+}
+
+fn func(name: &str, args: Vec<Expr<String>>) -> Expr<String> {
+    check_function(None, name, &[], &args, &BUILTIN_FUNCTIONS).unwrap()
+}
+
+fn like(expr: Expr<String>, pattern: &str) -> Expr<String> {
+    func("like", vec![expr, constant(pattern)])
+}
+
+fn lower(expr: Expr<String>) -> Expr<String> {
+    func("lower", vec![expr])
+}
+
+fn locate(needle: &str, expr: Expr<String>) -> Expr<String> {
+    func("locate", vec![constant(needle), expr])
+}
+
+fn found(expr: Expr<String>) -> Expr<String> {
+    func("gt", vec![expr, Expr::Constant {
+        span: None,
+        scalar: Scalar::Number(NumberScalar::UInt64(0)),
+        data_type: DataType::Number(NumberDataType::UInt64),
+    }])
+}
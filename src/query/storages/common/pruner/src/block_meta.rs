@@ -17,6 +17,7 @@ use std::ops::Range;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::F32;
 use common_expression::BlockMetaInfo;
 use common_expression::BlockMetaInfoDowncast;
 use common_expression::BlockMetaInfoPtr;
@@ -37,6 +38,8 @@ pub struct BlockMetaIndex {
     pub block_location: String,
     pub segment_location: String,
     pub snapshot_location: Option<String>,
+    /// Offsets and BM25 scores of the rows matched by the inverted index.
+    pub search_scores: Option<Vec<(usize, F32)>>,
 }
 
 #[typetag::serde(name = "block_meta_index")]
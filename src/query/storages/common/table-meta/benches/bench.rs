@@ -191,6 +191,14 @@ fn build_test_segment_info(num_blocks_per_seg: usize) -> common_exception::Resul
         location: block_location,
         bloom_filter_index_location: Some(location_gen.block_bloom_index_location(&block_uuid)),
         bloom_filter_index_size: 0,
+        ngram_index_location: None,
+        ngram_index_size: 0,
+        inverted_index_location: None,
+        inverted_index_size: 0,
+        deletion_vector_location: None,
+        deletion_vector_size: 0,
+        deleted_row_count: 0,
+        partition: None,
         compression: Compression::Lz4,
         create_on: Some(Utc::now()),
     };
@@ -208,6 +216,7 @@ fn build_test_segment_info(num_blocks_per_seg: usize) -> common_exception::Resul
         index_size: 0,
         col_stats: col_stats.clone(),
         cluster_stats: None,
+        partition: None,
     };
 
     Ok(SegmentInfo::new(block_metas, statistics))
@@ -15,6 +15,7 @@
 mod segment;
 mod snapshot;
 pub mod statistics;
+mod table_snapshot_statistics;
 
 pub use segment::BlockMeta;
 pub use segment::ColumnMeta;
@@ -23,3 +24,7 @@ pub use snapshot::TableSnapshot;
 pub use statistics::ClusterStatistics;
 pub use statistics::ColumnStatistics;
 pub use statistics::Statistics;
+pub use table_snapshot_statistics::ColumnHistogram;
+pub use table_snapshot_statistics::ColumnHistogramBucket;
+pub use table_snapshot_statistics::InvertedIndexStatistics;
+pub use table_snapshot_statistics::TableSnapshotStatistics;
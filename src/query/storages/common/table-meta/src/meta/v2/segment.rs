@@ -23,6 +23,7 @@ use common_arrow::native::ColumnMeta as NativeColumnMeta;
 use common_expression::BlockMetaInfo;
 use common_expression::BlockMetaInfoDowncast;
 use common_expression::ColumnId;
+use common_expression::Scalar;
 use common_expression::TableField;
 use enum_as_inner::EnumAsInner;
 use serde::Deserialize;
@@ -77,6 +78,29 @@ pub struct BlockMeta {
 
     #[serde(default)]
     pub bloom_filter_index_size: u64,
+    /// location of ngram index
+    #[serde(default)]
+    pub ngram_index_location: Option<Location>,
+    #[serde(default)]
+    pub ngram_index_size: u64,
+    /// location of inverted index
+    #[serde(default)]
+    pub inverted_index_location: Option<Location>,
+    #[serde(default)]
+    pub inverted_index_size: u64,
+    /// location of deletion vector, the positions of the rows deleted from this block.
+    ///
+    /// Only segments of format version 5 and later carry deletion vectors.
+    #[serde(default)]
+    pub deletion_vector_location: Option<Location>,
+    #[serde(default)]
+    pub deletion_vector_size: u64,
+    /// number of rows marked as deleted by the deletion vector
+    #[serde(default)]
+    pub deleted_row_count: u64,
+    /// the partition value of the rows in this block, if the table is partitioned
+    #[serde(default)]
+    pub partition: Option<Vec<Scalar>>,
     pub compression: Compression,
 
     // block create_on
@@ -108,6 +132,14 @@ impl BlockMeta {
             location,
             bloom_filter_index_location,
             bloom_filter_index_size,
+            ngram_index_location: None,
+            ngram_index_size: 0,
+            inverted_index_location: None,
+            inverted_index_size: 0,
+            deletion_vector_location: None,
+            deletion_vector_size: 0,
+            deleted_row_count: 0,
+            partition: None,
             compression,
             create_on,
         }
@@ -117,6 +149,11 @@ impl BlockMeta {
         self.compression
     }
 
+    /// Number of rows that are not marked as deleted by the deletion vector.
+    pub fn live_row_count(&self) -> u64 {
+        self.row_count - self.deleted_row_count
+    }
+
     /// Get the page size of the block.
     /// - If the format is parquet, its page size is its row count.
     /// - If the format is native, its page size is the row count of each page.
@@ -257,6 +294,14 @@ impl BlockMeta {
             location: (s.location.path.clone(), 0),
             bloom_filter_index_location: None,
             bloom_filter_index_size: 0,
+            ngram_index_location: None,
+            ngram_index_size: 0,
+            inverted_index_location: None,
+            inverted_index_size: 0,
+            deletion_vector_location: None,
+            deletion_vector_size: 0,
+            deleted_row_count: 0,
+            partition: None,
             compression: Compression::Lz4,
             create_on: None,
         }
@@ -288,6 +333,14 @@ impl BlockMeta {
             location: s.location.clone(),
             bloom_filter_index_location: s.bloom_filter_index_location.clone(),
             bloom_filter_index_size: s.bloom_filter_index_size,
+            ngram_index_location: None,
+            ngram_index_size: 0,
+            inverted_index_location: None,
+            inverted_index_size: 0,
+            deletion_vector_location: None,
+            deletion_vector_size: 0,
+            deleted_row_count: 0,
+            partition: None,
             compression: s.compression,
             create_on: None,
         }
@@ -53,6 +53,9 @@ pub struct Statistics {
 
     pub col_stats: HashMap<ColumnId, ColumnStatistics>,
     pub cluster_stats: Option<ClusterStatistics>,
+    /// the partition value shared by all the blocks, if the table is partitioned
+    #[serde(default)]
+    pub partition: Option<Vec<Scalar>>,
 }
 
 // conversions from old meta data
@@ -169,6 +172,7 @@ impl Statistics {
             index_size: v0.index_size,
             col_stats,
             cluster_stats: None,
+            partition: None,
         }
     }
 }
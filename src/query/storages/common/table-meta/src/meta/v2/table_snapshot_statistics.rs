@@ -0,0 +1,136 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use common_expression::ColumnId;
+use common_expression::Scalar;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::meta::v1;
+use crate::meta::FormatVersion;
+use crate::meta::SnapshotId;
+use crate::meta::Versioned;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TableSnapshotStatistics {
+    /// format version of snapshot
+    pub format_version: FormatVersion,
+
+    /// id of snapshot
+    pub snapshot_id: SnapshotId,
+
+    pub column_distinct_values: HashMap<ColumnId, u64>,
+
+    /// histograms built from the blocks sampled by `ANALYZE TABLE`
+    pub histograms: HashMap<ColumnId, ColumnHistogram>,
+
+    /// BM25 statistics of the inverted indexes collected by `ANALYZE TABLE`,
+    /// keyed by the name of the index column, `Inverted(column_id:tokenizer)`
+    #[serde(default)]
+    pub inverted_index_statistics: HashMap<String, InvertedIndexStatistics>,
+}
+
+/// An equi-height histogram of a column, together with the most common values.
+///
+/// The histogram is built from a sample of the rows, and all the counts are
+/// scaled to the whole table when it is analyzed, null values excluded.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ColumnHistogram {
+    /// estimated number of non-null rows of the table
+    pub num_rows: u64,
+
+    /// buckets ordered by their bounds, a value never spans two buckets
+    pub buckets: Vec<ColumnHistogramBucket>,
+
+    /// the most common values and their number of occurrences
+    pub most_common_values: Vec<(Scalar, u64)>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ColumnHistogramBucket {
+    pub lower_bound: Scalar,
+    pub upper_bound: Scalar,
+    pub num_values: u64,
+    pub num_distinct: u64,
+}
+
+/// The statistics of an inverted index column over all the indexed blocks of a table,
+/// used to compute BM25 scores that are comparable across blocks.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct InvertedIndexStatistics {
+    /// number of indexed rows
+    pub num_docs: u64,
+
+    /// total number of terms of the indexed rows
+    pub total_length: u64,
+
+    /// number of rows containing each term
+    pub doc_freqs: BTreeMap<String, u64>,
+}
+
+impl InvertedIndexStatistics {
+    pub fn merge(&mut self, other: &InvertedIndexStatistics) {
+        self.num_docs += other.num_docs;
+        self.total_length += other.total_length;
+        for (term, freq) in &other.doc_freqs {
+            *self.doc_freqs.entry(term.clone()).or_default() += freq;
+        }
+    }
+}
+
+impl TableSnapshotStatistics {
+    pub fn new(
+        column_distinct_values: HashMap<ColumnId, u64>,
+        histograms: HashMap<ColumnId, ColumnHistogram>,
+        inverted_index_statistics: HashMap<String, InvertedIndexStatistics>,
+    ) -> Self {
+        Self {
+            format_version: TableSnapshotStatistics::VERSION,
+            snapshot_id: SnapshotId::new_v4(),
+            column_distinct_values,
+            histograms,
+            inverted_index_statistics,
+        }
+    }
+
+    pub fn format_version(&self) -> u64 {
+        self.format_version
+    }
+
+    pub fn get_column_distinct_values(&self) -> &HashMap<ColumnId, u64> {
+        &self.column_distinct_values
+    }
+
+    pub fn get_histograms(&self) -> &HashMap<ColumnId, ColumnHistogram> {
+        &self.histograms
+    }
+
+    pub fn get_inverted_index_statistics(&self) -> &HashMap<String, InvertedIndexStatistics> {
+        &self.inverted_index_statistics
+    }
+}
+
+impl From<v1::TableSnapshotStatistics> for TableSnapshotStatistics {
+    fn from(value: v1::TableSnapshotStatistics) -> Self {
+        Self {
+            format_version: TableSnapshotStatistics::VERSION,
+            snapshot_id: value.snapshot_id,
+            column_distinct_values: value.column_distinct_values,
+            histograms: HashMap::new(),
+            inverted_index_statistics: HashMap::new(),
+        }
+    }
+}
@@ -60,6 +60,14 @@ impl From<BlockMeta> for crate::meta::BlockMeta {
             location: value.location,
             bloom_filter_index_location: value.bloom_filter_index_location,
             bloom_filter_index_size: value.bloom_filter_index_size,
+            ngram_index_location: None,
+            ngram_index_size: 0,
+            inverted_index_location: None,
+            inverted_index_size: 0,
+            deletion_vector_location: None,
+            deletion_vector_size: 0,
+            deleted_row_count: 0,
+            partition: None,
             compression: value.compression.into(),
             create_on: None,
         }
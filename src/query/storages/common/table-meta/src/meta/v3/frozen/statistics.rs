@@ -47,6 +47,7 @@ impl From<Statistics> for crate::meta::Statistics {
                 .map(|(k, v)| (k, v.into()))
                 .collect(),
             cluster_stats: None,
+            partition: None,
         }
     }
 }
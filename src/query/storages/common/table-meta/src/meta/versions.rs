@@ -22,6 +22,7 @@ use crate::meta::v0;
 use crate::meta::v1;
 use crate::meta::v3;
 use crate::meta::v4;
+use crate::meta::v5;
 
 // Here versions of meta are tagged with numeric values
 //
@@ -47,6 +48,7 @@ impl Versioned<1> for v1::SegmentInfo {}
 impl Versioned<2> for v2::SegmentInfo {}
 impl Versioned<3> for v3::SegmentInfo {}
 impl Versioned<4> for v4::SegmentInfo {}
+impl Versioned<5> for v5::SegmentInfo {}
 
 pub enum SegmentInfoVersion {
     V0(PhantomData<v0::SegmentInfo>),
@@ -54,6 +56,7 @@ pub enum SegmentInfoVersion {
     V2(PhantomData<v2::SegmentInfo>),
     V3(PhantomData<v3::SegmentInfo>),
     V4(PhantomData<v4::SegmentInfo>),
+    V5(PhantomData<v5::SegmentInfo>),
 }
 
 impl SegmentInfoVersion {
@@ -64,6 +67,7 @@ impl SegmentInfoVersion {
             SegmentInfoVersion::V2(a) => Self::ver(a),
             SegmentInfoVersion::V3(a) => Self::ver(a),
             SegmentInfoVersion::V4(a) => Self::ver(a),
+            SegmentInfoVersion::V5(a) => Self::ver(a),
         }
     }
 
@@ -103,17 +107,20 @@ impl SnapshotVersion {
 }
 
 impl Versioned<0> for v1::TableSnapshotStatistics {}
+impl Versioned<1> for v2::TableSnapshotStatistics {}
 
 impl Versioned<2> for DataBlock {}
 
 pub enum TableSnapshotStatisticsVersion {
     V0(PhantomData<v1::TableSnapshotStatistics>),
+    V1(PhantomData<v2::TableSnapshotStatistics>),
 }
 
 impl TableSnapshotStatisticsVersion {
     pub fn version(&self) -> u64 {
         match self {
             TableSnapshotStatisticsVersion::V0(a) => Self::ver(a),
+            TableSnapshotStatisticsVersion::V1(a) => Self::ver(a),
         }
     }
 
@@ -146,8 +153,9 @@ mod converters {
                 2 => Ok(SegmentInfoVersion::V2(testify_version::<_, 2>(PhantomData))),
                 3 => Ok(SegmentInfoVersion::V3(testify_version::<_, 3>(PhantomData))),
                 4 => Ok(SegmentInfoVersion::V4(testify_version::<_, 4>(PhantomData))),
+                5 => Ok(SegmentInfoVersion::V5(testify_version::<_, 5>(PhantomData))),
                 _ => Err(ErrorCode::Internal(format!(
-                    "unknown segment version {value}, versions supported: 0, 1, 2, 3, 4"
+                    "unknown segment version {value}, versions supported: 0, 1, 2, 3, 4, 5"
                 ))),
             }
         }
@@ -176,8 +184,11 @@ mod converters {
                 0 => Ok(TableSnapshotStatisticsVersion::V0(testify_version::<_, 0>(
                     PhantomData,
                 ))),
+                1 => Ok(TableSnapshotStatisticsVersion::V1(testify_version::<_, 1>(
+                    PhantomData,
+                ))),
                 _ => Err(ErrorCode::Internal(format!(
-                    "unknown table snapshot statistics version {value}, versions supported: 0"
+                    "unknown table snapshot statistics version {value}, versions supported: 0, 1"
                 ))),
             }
         }
@@ -34,7 +34,8 @@ impl VersionedReader<CompactSegmentInfo> for (SegmentInfoVersion, TableSchemaRef
         let mut buffer: Vec<u8> = vec![];
         reader.read_to_end(&mut buffer).await?;
         let bytes_of_current_format = match &self.0 {
-            SegmentInfoVersion::V4(_) => Ok(buffer),
+            // v4 shares the layout of the current version
+            SegmentInfoVersion::V5(_) | SegmentInfoVersion::V4(_) => Ok(buffer),
             SegmentInfoVersion::V3(_) => {
                 let current: SegmentInfo = SegmentInfoV3::from_slice(&buffer)?.into();
                 current.to_bytes()
@@ -24,6 +24,17 @@ pub const OPT_KEY_COMMENT: &str = "comment";
 pub const OPT_KEY_EXTERNAL_LOCATION: &str = "external_location";
 pub const OPT_KEY_ENGINE: &str = "engine";
 pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
+/// String columns that have an ngram index, which is used to prune blocks for `LIKE '%substr%'`.
+pub const OPT_KEY_NGRAM_INDEX_COLUMNS: &str = "ngram_index_columns";
+/// Option of an inverted index, the tokenizer that splits the text of the columns into terms.
+pub const OPT_KEY_INVERTED_INDEX_TOKENIZER: &str = "tokenizer";
+/// Days of history kept for time travel, also accepted as a database option,
+/// which is then the default of the tables in that database.
+pub const OPT_KEY_DATA_RETENTION_TIME_IN_DAYS: &str = "data_retention_time_in_days";
+/// Client side encryption of the objects of the table, `aes_256_gcm` is the only algorithm.
+pub const OPT_KEY_ENCRYPTION: &str = "encryption";
+/// The data keys of an encrypted table, wrapped by the master key.
+pub const OPT_KEY_ENCRYPTION_KEYS: &str = "encryption_keys";
 
 /// Legacy table snapshot location key
 ///
@@ -42,6 +53,7 @@ pub static RESERVED_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_ENCRYPTION_KEYS);
     r
 });
 
@@ -50,6 +62,7 @@ pub static INTERNAL_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_ENCRYPTION_KEYS);
     r
 });
 
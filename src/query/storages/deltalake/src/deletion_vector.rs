@@ -0,0 +1,204 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use croaring::Bitmap;
+use croaring::Treemap;
+use opendal::Operator;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The magic number of a serialized `RoaringBitmapArray`.
+const DV_MAGIC_NUMBER: u32 = 1681511377;
+
+const Z85_CHARS: &[u8] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+/// The descriptor of a deletion vector in the delta log, see
+/// <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#deletion-vector-descriptor-schema>.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DeletionVectorDescriptor {
+    /// `u` for a relative path, `i` for an inline vector and `p` for an absolute path.
+    pub storage_type: String,
+    pub path_or_inline_dv: String,
+    /// The start of the vector in the file, absent for inline vectors.
+    #[serde(default)]
+    pub offset: Option<i32>,
+    pub size_in_bytes: i32,
+    /// The number of rows deleted by the vector.
+    pub cardinality: i64,
+}
+
+impl DeletionVectorDescriptor {
+    pub fn unique_id(&self) -> String {
+        match self.offset {
+            Some(offset) => format!("{}{}@{}", self.storage_type, self.path_or_inline_dv, offset),
+            None => format!("{}{}", self.storage_type, self.path_or_inline_dv),
+        }
+    }
+
+    /// Read the positions of the rows deleted from the data file.
+    #[async_backtrace::framed]
+    pub async fn read_deleted_rows(&self, op: &Operator) -> Result<Treemap> {
+        let size = self.size_in_bytes as usize;
+        match self.storage_type.as_str() {
+            "i" => {
+                let data = z85_decode(&self.path_or_inline_dv)?;
+                let data = data.get(..size).ok_or_else(|| {
+                    ErrorCode::ReadTableDataError("Invalid inline delta deletion vector")
+                })?;
+                parse_bitmap_array(data)
+            }
+            "u" => {
+                let path = self.relative_path()?;
+                // The size and the checksum are stored around the vector.
+                let offset = self.offset.unwrap_or(1) as u64;
+                let data = op
+                    .range_read(&path, offset..offset + 4 + size as u64)
+                    .await?;
+                let len = u32::from_be_bytes(data[..4].try_into().unwrap()) as usize;
+                if len != size {
+                    return Err(ErrorCode::ReadTableDataError(format!(
+                        "Delta deletion vector {path} is corrupted, expect {size} bytes but got {len}"
+                    )));
+                }
+                parse_bitmap_array(&data[4..])
+            }
+            other => Err(ErrorCode::Unimplemented(format!(
+                "Delta deletion vector of storage type '{other}' is not supported"
+            ))),
+        }
+    }
+
+    /// The path of the deletion vector file, relative to the table root.
+    ///
+    /// It is encoded as an optional random prefix followed by the z85 encoded uuid.
+    fn relative_path(&self) -> Result<String> {
+        let encoded = &self.path_or_inline_dv;
+        if encoded.len() < 20 {
+            return Err(ErrorCode::ReadTableDataError(format!(
+                "Invalid delta deletion vector path '{encoded}'"
+            )));
+        }
+        let (prefix, uuid) = encoded.split_at(encoded.len() - 20);
+        let uuid = uuid::Uuid::from_slice(&z85_decode(uuid)?).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!(
+                "Invalid delta deletion vector path '{encoded}': {e}"
+            ))
+        })?;
+        let file_name = format!("deletion_vector_{uuid}.bin");
+        if prefix.is_empty() {
+            Ok(file_name)
+        } else {
+            Ok(format!("{prefix}/{file_name}"))
+        }
+    }
+}
+
+/// Parse a `RoaringBitmapArray` in the portable format: the magic number, the number of
+/// bitmaps, and for each bitmap the high 32 bits of its values followed by the bitmap.
+fn parse_bitmap_array(data: &[u8]) -> Result<Treemap> {
+    let invalid = || ErrorCode::ReadTableDataError("Invalid delta deletion vector");
+    if data.len() < 12 || u32::from_le_bytes(data[..4].try_into().unwrap()) != DV_MAGIC_NUMBER {
+        return Err(invalid());
+    }
+    let num_bitmaps = u64::from_le_bytes(data[4..12].try_into().unwrap());
+
+    let mut deleted = Treemap::create();
+    let mut pos = 12;
+    for _ in 0..num_bitmaps {
+        let key = data
+            .get(pos..pos + 4)
+            .map(|k| u32::from_le_bytes(k.try_into().unwrap()))
+            .ok_or_else(invalid)?;
+        let bitmap = Bitmap::try_deserialize(&data[pos + 4..]).ok_or_else(invalid)?;
+        pos += 4 + bitmap.get_serialized_size_in_bytes();
+        let high = (key as u64) << 32;
+        for low in bitmap.iter() {
+            deleted.add(high | low as u64);
+        }
+    }
+    Ok(deleted)
+}
+
+fn z85_decode(encoded: &str) -> Result<Vec<u8>> {
+    let encoded = encoded.as_bytes();
+    if encoded.len() % 5 != 0 {
+        return Err(ErrorCode::ReadTableDataError(format!(
+            "Invalid z85 string of length {}",
+            encoded.len()
+        )));
+    }
+    let mut decoded = Vec::with_capacity(encoded.len() / 5 * 4);
+    for chunk in encoded.chunks(5) {
+        let mut value: u32 = 0;
+        for c in chunk {
+            let digit = Z85_CHARS.iter().position(|z| z == c).ok_or_else(|| {
+                ErrorCode::ReadTableDataError(format!("Invalid z85 character '{}'", *c as char))
+            })?;
+            value = value.wrapping_mul(85).wrapping_add(digit as u32);
+        }
+        decoded.extend_from_slice(&value.to_be_bytes());
+    }
+    Ok(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_z85_decode() {
+        // The example of the z85 specification.
+        let decoded = z85_decode("HelloWorld").unwrap();
+        assert_eq!(decoded, vec![
+            0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B
+        ]);
+    }
+
+    #[test]
+    fn test_relative_path() {
+        let dv = DeletionVectorDescriptor {
+            storage_type: "u".to_string(),
+            path_or_inline_dv: "ab^-aqEH.-t@S}K{vb[*k^".to_string(),
+            offset: Some(4),
+            size_in_bytes: 40,
+            cardinality: 6,
+        };
+        assert_eq!(
+            dv.relative_path().unwrap(),
+            "ab/deletion_vector_d2c639aa-8816-431a-aaf6-d3fe2512ff61.bin"
+        );
+        assert_eq!(dv.unique_id(), "uab^-aqEH.-t@S}K{vb[*k^@4");
+    }
+
+    #[test]
+    fn test_parse_bitmap_array() {
+        let mut data = vec![];
+        data.extend_from_slice(&DV_MAGIC_NUMBER.to_le_bytes());
+        data.extend_from_slice(&2u64.to_le_bytes());
+        for (key, values) in [(0u32, vec![1u32, 3]), (1, vec![2])] {
+            data.extend_from_slice(&key.to_le_bytes());
+            data.extend_from_slice(&Bitmap::of(&values).serialize());
+        }
+        let deleted = parse_bitmap_array(&data).unwrap();
+        assert_eq!(deleted.iter().collect::<Vec<_>>(), vec![
+            1,
+            3,
+            (1 << 32) | 2
+        ]);
+    }
+}
@@ -0,0 +1,305 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Replay of the Delta transaction log.
+//!
+//! The state of a Delta table at a version is built by loading the latest
+//! checkpoint (if any) and applying the JSON commits after it, see
+//! <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#delta-log-entries>.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use bytes::Bytes;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use opendal::Operator;
+use parquet::file::reader::FileReader;
+use parquet::file::serialized_reader::SerializedFileReader;
+use serde::Deserialize;
+
+use crate::deletion_vector::DeletionVectorDescriptor;
+
+pub const DELTA_LOG_DIR: &str = "_delta_log";
+
+/// The highest reader protocol version we are able to read.
+const MAX_READER_VERSION: i32 = 3;
+
+/// The reader table features we are able to read.
+const SUPPORTED_READER_FEATURES: &[&str] = &["deletionVectors", "timestampNtz"];
+
+/// A data file added to the table.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AddAction {
+    /// The URI encoded path of the data file, relative to the table root.
+    pub path: String,
+    #[serde(default)]
+    pub partition_values: Option<HashMap<String, Option<String>>>,
+    pub size: i64,
+    /// The statistics of the data file, as a JSON string.
+    #[serde(default)]
+    pub stats: Option<String>,
+    #[serde(default)]
+    pub deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+/// A data file removed from the table.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoveAction {
+    pub path: String,
+    #[serde(default)]
+    pub deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataAction {
+    pub schema_string: String,
+    #[serde(default)]
+    pub partition_columns: Vec<String>,
+    #[serde(default)]
+    pub configuration: Option<HashMap<String, Option<String>>>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ProtocolAction {
+    pub min_reader_version: i32,
+    #[serde(default)]
+    pub reader_features: Option<Vec<String>>,
+}
+
+/// One line of a commit file, or one row of a checkpoint file.
+///
+/// Only one of the actions is set, the actions we don't care about
+/// (`commitInfo`, `txn`, `cdc`, ...) are ignored.
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+struct Action {
+    #[serde(default)]
+    add: Option<AddAction>,
+    #[serde(default)]
+    remove: Option<RemoveAction>,
+    #[serde(default)]
+    meta_data: Option<MetadataAction>,
+    #[serde(default)]
+    protocol: Option<ProtocolAction>,
+}
+
+/// The state of a Delta table at a version.
+#[derive(Debug, Clone)]
+pub struct DeltaSnapshot {
+    pub version: i64,
+    pub metadata: MetadataAction,
+    /// The live data files of the table.
+    pub files: Vec<AddAction>,
+}
+
+/// The log files of a Delta table.
+#[derive(Default)]
+struct LogSegment {
+    /// Commit files by version.
+    commits: BTreeMap<i64, String>,
+    /// Checkpoint parts by version, with the number of parts of the checkpoint.
+    checkpoints: BTreeMap<i64, (usize, Vec<String>)>,
+}
+
+impl DeltaSnapshot {
+    /// Load the latest snapshot of the table under the root of `op`.
+    #[async_backtrace::framed]
+    pub async fn load(op: &Operator) -> Result<DeltaSnapshot> {
+        let segment = list_log_segment(op).await?;
+
+        // The latest checkpoint with all of its parts written.
+        let checkpoint = segment
+            .checkpoints
+            .iter()
+            .rev()
+            .find(|(_, (parts, files))| *parts == files.len());
+
+        let mut replay = LogReplay::default();
+        let mut version = -1;
+        if let Some((checkpoint_version, (_, files))) = checkpoint {
+            for file in files {
+                let data = op.read(file).await?;
+                for action in read_checkpoint_actions(Bytes::from(data), file)? {
+                    // Removes in checkpoints are only tombstones for vacuum.
+                    replay.apply(Action {
+                        remove: None,
+                        ..action
+                    });
+                }
+            }
+            version = *checkpoint_version;
+        }
+
+        for (commit_version, file) in segment.commits.range(version + 1..) {
+            if *commit_version != version + 1 {
+                return Err(ErrorCode::ReadTableDataError(format!(
+                    "Delta log is not contiguous, version {} is missing",
+                    version + 1
+                )));
+            }
+            let data = op.read(file).await?;
+            for line in data.split(|b| *b == b'\n') {
+                if line.iter().all(|b| b.is_ascii_whitespace()) {
+                    continue;
+                }
+                let action: Action = serde_json::from_slice(line).map_err(|e| {
+                    ErrorCode::ReadTableDataError(format!("Invalid delta log file {file}: {e}"))
+                })?;
+                replay.apply(action);
+            }
+            version = *commit_version;
+        }
+
+        if version < 0 {
+            return Err(ErrorCode::ReadTableDataError(format!(
+                "No delta log is found under {}",
+                DELTA_LOG_DIR
+            )));
+        }
+
+        replay.into_snapshot(version)
+    }
+}
+
+#[derive(Default)]
+struct LogReplay {
+    metadata: Option<MetadataAction>,
+    protocol: Option<ProtocolAction>,
+    /// Live files keyed by the path and the unique id of the deletion vector.
+    files: BTreeMap<(String, Option<String>), AddAction>,
+}
+
+impl LogReplay {
+    fn apply(&mut self, action: Action) {
+        if let Some(add) = action.add {
+            let key = (add.path.clone(), dv_unique_id(&add.deletion_vector));
+            self.files.insert(key, add);
+        }
+        if let Some(remove) = action.remove {
+            let key = (remove.path, dv_unique_id(&remove.deletion_vector));
+            self.files.remove(&key);
+        }
+        if action.meta_data.is_some() {
+            self.metadata = action.meta_data;
+        }
+        if action.protocol.is_some() {
+            self.protocol = action.protocol;
+        }
+    }
+
+    fn into_snapshot(self, version: i64) -> Result<DeltaSnapshot> {
+        let protocol = self.protocol.ok_or_else(|| {
+            ErrorCode::ReadTableDataError("Delta log has no protocol action".to_string())
+        })?;
+        check_protocol(&protocol)?;
+
+        let metadata = self.metadata.ok_or_else(|| {
+            ErrorCode::ReadTableDataError("Delta log has no metaData action".to_string())
+        })?;
+        let column_mapping = metadata
+            .configuration
+            .as_ref()
+            .and_then(|c| c.get("delta.columnMapping.mode").cloned().flatten());
+        if column_mapping.is_some_and(|mode| mode != "none") {
+            return Err(ErrorCode::Unimplemented(
+                "Delta table with column mapping is not supported",
+            ));
+        }
+
+        Ok(DeltaSnapshot {
+            version,
+            metadata,
+            files: self.files.into_values().collect(),
+        })
+    }
+}
+
+fn check_protocol(protocol: &ProtocolAction) -> Result<()> {
+    if protocol.min_reader_version > MAX_READER_VERSION {
+        return Err(ErrorCode::Unimplemented(format!(
+            "Delta reader protocol version {} is not supported",
+            protocol.min_reader_version
+        )));
+    }
+    for feature in protocol.reader_features.iter().flatten() {
+        if !SUPPORTED_READER_FEATURES.contains(&feature.as_str()) {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Delta reader feature '{feature}' is not supported"
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn dv_unique_id(dv: &Option<DeletionVectorDescriptor>) -> Option<String> {
+    dv.as_ref().map(|dv| dv.unique_id())
+}
+
+/// Read the actions of a checkpoint file.
+///
+/// Each row of a checkpoint is converted to JSON so that it shares the
+/// deserialization of the commit files.
+fn read_checkpoint_actions(data: Bytes, file: &str) -> Result<Vec<Action>> {
+    let map_err = |e: parquet::errors::ParquetError| {
+        ErrorCode::ReadTableDataError(format!("Invalid delta checkpoint file {file}: {e}"))
+    };
+    let reader = SerializedFileReader::new(data).map_err(map_err)?;
+    let mut actions = Vec::with_capacity(reader.metadata().file_metadata().num_rows() as usize);
+    for row in reader.get_row_iter(None).map_err(map_err)? {
+        let row = row.map_err(map_err)?;
+        let action: Action = serde_json::from_value(row.to_json_value()).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("Invalid delta checkpoint file {file}: {e}"))
+        })?;
+        actions.push(action);
+    }
+    Ok(actions)
+}
+
+async fn list_log_segment(op: &Operator) -> Result<LogSegment> {
+    let mut segment = LogSegment::default();
+    let mut lister = op.list(&format!("{DELTA_LOG_DIR}/")).await?;
+    while let Some(page) = lister.next_page().await? {
+        for entry in page {
+            let name = entry.name();
+            let Some(version) = name.get(..20).and_then(|v| v.parse::<i64>().ok()) else {
+                continue;
+            };
+            let path = format!("{DELTA_LOG_DIR}/{name}");
+            let suffix = &name[20..];
+            if suffix == ".json" {
+                segment.commits.insert(version, path);
+            } else if suffix == ".checkpoint.parquet" {
+                segment.checkpoints.insert(version, (1, vec![path]));
+            } else if let Some(parts) = suffix
+                .strip_prefix(".checkpoint.")
+                .and_then(|s| s.strip_suffix(".parquet"))
+                .and_then(|s| s.split('.').nth(1))
+                .and_then(|s| s.parse::<usize>().ok())
+            {
+                // Multi-part checkpoint: `n.checkpoint.<part>.<parts>.parquet`
+                let checkpoint = segment
+                    .checkpoints
+                    .entry(version)
+                    .or_insert_with(|| (parts, vec![]));
+                checkpoint.1.push(path);
+            }
+        }
+    }
+    Ok(segment)
+}
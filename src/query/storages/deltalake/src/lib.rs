@@ -0,0 +1,37 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The Delta Lake table engine.
+//!
+//! A table of the engine is a read only view of the delta table at its location:
+//! ```sql
+//! CREATE TABLE events ENGINE = DELTA LOCATION = 's3://bkt/path/to/events/' CONNECTION = (...);
+//! ```
+//!
+//! The columns are loaded from the metadata of the delta table if they are not specified.
+//!
+//! Every read replays the `_delta_log` from the latest checkpoint to list the live data
+//! files, which are pruned by their statistics and partition values before being read.
+//! The rows deleted by deletion vectors are skipped when the data files are decoded.
+
+mod deletion_vector;
+mod delta_log;
+mod partition;
+mod schema;
+mod stats;
+mod table;
+mod table_source;
+
+pub use table::DeltaTable;
+pub use table::DELTA_ENGINE;
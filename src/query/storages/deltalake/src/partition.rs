@@ -0,0 +1,66 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use common_catalog::plan::PartInfo;
+use common_catalog::plan::PartInfoPtr;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::Scalar;
+
+use crate::deletion_vector::DeletionVectorDescriptor;
+
+/// A parquet data file of the delta table to read.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug, Clone)]
+pub struct DeltaPartInfo {
+    /// The path of the data file, relative to the table root.
+    pub path: String,
+    pub size: u64,
+    /// The values of the partition columns, which are not stored in the data file.
+    pub partition_values: Vec<(String, Scalar)>,
+    pub deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+impl DeltaPartInfo {
+    pub fn from_part(info: &PartInfoPtr) -> Result<&DeltaPartInfo> {
+        info.as_any()
+            .downcast_ref::<DeltaPartInfo>()
+            .ok_or(ErrorCode::Internal(
+                "Cannot downcast from PartInfo to DeltaPartInfo.",
+            ))
+    }
+}
+
+#[typetag::serde(name = "delta")]
+impl PartInfo for DeltaPartInfo {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        info.as_any()
+            .downcast_ref::<DeltaPartInfo>()
+            .is_some_and(|other| self == other)
+    }
+
+    fn hash(&self) -> u64 {
+        let mut s = DefaultHasher::new();
+        self.path.hash(&mut s);
+        s.finish()
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DecimalDataType;
+use common_expression::types::DecimalSize;
+use common_expression::types::NumberDataType;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use serde_json::Value;
+
+/// Convert the `schemaString` of the delta metadata to [`TableSchema`], see
+/// <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#schema-serialization-format>.
+///
+/// The names of the columns are lowercased, as the unquoted identifiers in SQL are.
+pub fn delta_to_table_schema(schema_string: &str) -> Result<TableSchema> {
+    let schema: Value = serde_json::from_str(schema_string)
+        .map_err(|e| ErrorCode::ReadTableDataError(format!("Invalid delta table schema: {e}")))?;
+    let fields = struct_fields(&schema)?
+        .into_iter()
+        .map(|(name, ty)| TableField::new(&name.to_lowercase(), ty))
+        .collect();
+    Ok(TableSchema::new(fields))
+}
+
+fn struct_fields(ty: &Value) -> Result<Vec<(String, TableDataType)>> {
+    let fields = ty
+        .get("fields")
+        .and_then(|f| f.as_array())
+        .ok_or_else(|| invalid_type(ty))?;
+    fields
+        .iter()
+        .map(|field| {
+            let name = field
+                .get("name")
+                .and_then(|n| n.as_str())
+                .ok_or_else(|| invalid_type(field))?;
+            let nullable = field
+                .get("nullable")
+                .and_then(|n| n.as_bool())
+                .unwrap_or(true);
+            let ty = field.get("type").ok_or_else(|| invalid_type(field))?;
+            Ok((
+                name.to_string(),
+                wrap_nullable(to_table_type(ty)?, nullable),
+            ))
+        })
+        .collect()
+}
+
+fn to_table_type(ty: &Value) -> Result<TableDataType> {
+    if let Some(name) = ty.as_str() {
+        return primitive_type(name);
+    }
+    match ty.get("type").and_then(|t| t.as_str()) {
+        Some("struct") => {
+            let (fields_name, fields_type) = struct_fields(ty)?.into_iter().unzip();
+            Ok(TableDataType::Tuple {
+                fields_name,
+                fields_type,
+            })
+        }
+        Some("array") => {
+            let element = ty.get("elementType").ok_or_else(|| invalid_type(ty))?;
+            let contains_null = ty
+                .get("containsNull")
+                .and_then(|n| n.as_bool())
+                .unwrap_or(true);
+            Ok(TableDataType::Array(Box::new(wrap_nullable(
+                to_table_type(element)?,
+                contains_null,
+            ))))
+        }
+        Some("map") => {
+            let key = ty.get("keyType").ok_or_else(|| invalid_type(ty))?;
+            let value = ty.get("valueType").ok_or_else(|| invalid_type(ty))?;
+            let value_contains_null = ty
+                .get("valueContainsNull")
+                .and_then(|n| n.as_bool())
+                .unwrap_or(true);
+            Ok(TableDataType::Map(Box::new(TableDataType::Tuple {
+                fields_name: vec!["key".to_string(), "value".to_string()],
+                fields_type: vec![
+                    to_table_type(key)?,
+                    wrap_nullable(to_table_type(value)?, value_contains_null),
+                ],
+            })))
+        }
+        _ => Err(invalid_type(ty)),
+    }
+}
+
+fn primitive_type(name: &str) -> Result<TableDataType> {
+    let ty = match name {
+        "boolean" => TableDataType::Boolean,
+        "byte" => TableDataType::Number(NumberDataType::Int8),
+        "short" => TableDataType::Number(NumberDataType::Int16),
+        "integer" => TableDataType::Number(NumberDataType::Int32),
+        "long" => TableDataType::Number(NumberDataType::Int64),
+        "float" => TableDataType::Number(NumberDataType::Float32),
+        "double" => TableDataType::Number(NumberDataType::Float64),
+        "string" | "binary" => TableDataType::String,
+        "date" => TableDataType::Date,
+        "timestamp" | "timestamp_ntz" => TableDataType::Timestamp,
+        _ => {
+            // decimal(<precision>,<scale>)
+            let size = name
+                .strip_prefix("decimal(")
+                .and_then(|s| s.strip_suffix(')'))
+                .and_then(|s| s.split_once(','))
+                .and_then(|(p, s)| Some((p.trim().parse().ok()?, s.trim().parse().ok()?)));
+            let Some((precision, scale)) = size else {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "Delta type '{name}' is not supported"
+                )));
+            };
+            TableDataType::Decimal(DecimalDataType::from_size(DecimalSize {
+                precision,
+                scale,
+            })?)
+        }
+    };
+    Ok(ty)
+}
+
+fn wrap_nullable(ty: TableDataType, nullable: bool) -> TableDataType {
+    if nullable { ty.wrap_nullable() } else { ty }
+}
+
+fn invalid_type(ty: &Value) -> ErrorCode {
+    ErrorCode::ReadTableDataError(format!("Invalid delta table schema: {ty}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delta_to_table_schema() {
+        let schema = r#"{"type":"struct","fields":[
+            {"name":"Id","type":"long","nullable":false,"metadata":{}},
+            {"name":"price","type":"decimal(10,2)","nullable":true,"metadata":{}},
+            {"name":"tags","type":{"type":"array","elementType":"string","containsNull":true},"nullable":true,"metadata":{}}
+        ]}"#;
+        let schema = delta_to_table_schema(schema).unwrap();
+        let names = schema
+            .fields()
+            .iter()
+            .map(|f| f.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["id", "price", "tags"]);
+        assert_eq!(
+            schema.field(0).data_type(),
+            &TableDataType::Number(NumberDataType::Int64)
+        );
+        assert_eq!(
+            schema.field(1).data_type(),
+            &TableDataType::Decimal(DecimalDataType::Decimal128(DecimalSize {
+                precision: 10,
+                scale: 2
+            }))
+            .wrap_nullable()
+        );
+        assert_eq!(
+            schema.field(2).data_type(),
+            &TableDataType::Array(Box::new(TableDataType::String.wrap_nullable())).wrap_nullable()
+        );
+    }
+}
@@ -0,0 +1,226 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use chrono::DateTime;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use common_expression::serialize::read_decimal_with_size;
+use common_expression::serialize::uniform_date;
+use common_expression::types::decimal::DecimalScalar;
+use common_expression::types::DecimalDataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::types::F32;
+use common_expression::types::F64;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableSchema;
+use serde::Deserialize;
+use serde_json::Value;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+
+/// Delta writers truncate the string statistics to a prefix of this length,
+/// so a longer maximum value is not an upper bound.
+const STRING_STATS_PREFIX_LENGTH: usize = 32;
+
+/// The statistics of a data file, see
+/// <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#per-file-statistics>.
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FileStats {
+    pub num_records: Option<u64>,
+    #[serde(default)]
+    pub min_values: HashMap<String, Value>,
+    #[serde(default)]
+    pub max_values: HashMap<String, Value>,
+    #[serde(default)]
+    pub null_count: HashMap<String, Value>,
+}
+
+impl FileStats {
+    pub fn parse(stats: Option<&str>) -> FileStats {
+        // The statistics are optional, a file without valid statistics is never pruned.
+        stats
+            .and_then(|s| serde_json::from_str(s).ok())
+            .unwrap_or_default()
+    }
+
+    /// Build the statistics of the columns for pruning, the partition columns
+    /// have the partition values as both the minimum and the maximum.
+    pub fn to_column_stats(
+        &self,
+        schema: &TableSchema,
+        partition_values: &HashMap<String, Scalar>,
+    ) -> StatisticsOfColumns {
+        let mut stats = StatisticsOfColumns::with_capacity(schema.num_fields());
+        for field in schema.fields() {
+            let name = field.name();
+            let stat = if let Some(value) = partition_values.get(name) {
+                let null_count = if matches!(value, Scalar::Null) {
+                    match self.num_records {
+                        Some(n) => n,
+                        None => continue,
+                    }
+                } else {
+                    0
+                };
+                ColumnStatistics {
+                    min: value.clone(),
+                    max: value.clone(),
+                    null_count,
+                    in_memory_size: 0, // this field is not used.
+                    distinct_of_values: Some(1),
+                }
+            } else {
+                let ty = field.data_type();
+                let (Some(min), Some(max), Some(null_count)) = (
+                    find_value(&self.min_values, name).and_then(|v| json_to_scalar(ty, v, false)),
+                    find_value(&self.max_values, name).and_then(|v| json_to_scalar(ty, v, true)),
+                    find_value(&self.null_count, name).and_then(|v| v.as_u64()),
+                ) else {
+                    continue;
+                };
+                ColumnStatistics {
+                    min,
+                    max,
+                    null_count,
+                    in_memory_size: 0, // this field is not used.
+                    distinct_of_values: None,
+                }
+            };
+            stats.insert(field.column_id(), stat);
+        }
+        stats
+    }
+}
+
+/// The statistics are keyed by the original column names, which might not be lowercase.
+fn find_value<'a>(values: &'a HashMap<String, Value>, name: &str) -> Option<&'a Value> {
+    values.get(name).or_else(|| {
+        values
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v)
+    })
+}
+
+fn json_to_scalar(ty: &TableDataType, value: &Value, is_max: bool) -> Option<Scalar> {
+    match value {
+        Value::Bool(v) => Some(Scalar::Boolean(*v)),
+        Value::Number(v) => parse_scalar(ty, &v.to_string()),
+        Value::String(v) => match ty.remove_nullable() {
+            TableDataType::String if is_max && v.chars().count() >= STRING_STATS_PREFIX_LENGTH => {
+                None
+            }
+            // The timestamps are truncated to milliseconds.
+            TableDataType::Timestamp if is_max => match parse_scalar(ty, v)? {
+                Scalar::Timestamp(ts) => Some(Scalar::Timestamp(ts + 999)),
+                _ => None,
+            },
+            _ => parse_scalar(ty, v),
+        },
+        // Statistics of nested columns are not used.
+        _ => None,
+    }
+}
+
+/// Parse a partition value serialized as a string, `None` means a null value.
+pub fn parse_partition_value(ty: &TableDataType, value: Option<&str>) -> Option<Scalar> {
+    match value {
+        None => Some(Scalar::Null),
+        Some(v) => parse_scalar(ty, v),
+    }
+}
+
+fn parse_scalar(ty: &TableDataType, value: &str) -> Option<Scalar> {
+    let scalar = match ty.remove_nullable() {
+        TableDataType::Boolean => Scalar::Boolean(value.parse().ok()?),
+        TableDataType::String => Scalar::String(value.as_bytes().to_vec()),
+        TableDataType::Number(ty) => Scalar::Number(match ty {
+            NumberDataType::Int8 => NumberScalar::Int8(value.parse().ok()?),
+            NumberDataType::Int16 => NumberScalar::Int16(value.parse().ok()?),
+            NumberDataType::Int32 => NumberScalar::Int32(value.parse().ok()?),
+            NumberDataType::Int64 => NumberScalar::Int64(value.parse().ok()?),
+            NumberDataType::Float32 => NumberScalar::Float32(F32::from(value.parse::<f32>().ok()?)),
+            NumberDataType::Float64 => NumberScalar::Float64(F64::from(value.parse::<f64>().ok()?)),
+            _ => return None,
+        }),
+        TableDataType::Decimal(DecimalDataType::Decimal128(size)) => {
+            let (v, _) = read_decimal_with_size::<i128>(value.as_bytes(), size, true).ok()?;
+            Scalar::Decimal(DecimalScalar::Decimal128(v, size))
+        }
+        TableDataType::Date => {
+            let date = NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()?;
+            Scalar::Date(uniform_date(date))
+        }
+        TableDataType::Timestamp => {
+            // Partition values are like `2023-01-01 00:00:00.000001`,
+            // and statistics are like `2023-01-01T00:00:00.000Z`.
+            let micros = match DateTime::parse_from_rfc3339(value) {
+                Ok(ts) => ts.timestamp_micros(),
+                Err(_) => NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%.f")
+                    .ok()?
+                    .timestamp_micros(),
+            };
+            Scalar::Timestamp(micros)
+        }
+        _ => return None,
+    };
+    Some(scalar)
+}
+
+#[cfg(test)]
+mod tests {
+    use common_expression::TableField;
+
+    use super::*;
+
+    #[test]
+    fn test_to_column_stats() {
+        let schema = TableSchema::new(vec![
+            TableField::new("id", TableDataType::Number(NumberDataType::Int64)),
+            TableField::new("name", TableDataType::String.wrap_nullable()),
+            TableField::new("day", TableDataType::Date),
+        ]);
+        let stats = FileStats::parse(Some(
+            r#"{"numRecords":3,"minValues":{"Id":1,"name":"a"},"maxValues":{"Id":9,"name":"c"},"nullCount":{"Id":0,"name":1}}"#,
+        ));
+        let day = parse_partition_value(&TableDataType::Date, Some("1970-01-02")).unwrap();
+        let partition_values = HashMap::from([("day".to_string(), day)]);
+
+        let stats = stats.to_column_stats(&schema, &partition_values);
+        assert_eq!(stats.len(), 3);
+        assert_eq!(stats[&0].min, Scalar::Number(NumberScalar::Int64(1)));
+        assert_eq!(stats[&0].max, Scalar::Number(NumberScalar::Int64(9)));
+        assert_eq!(stats[&1].null_count, 1);
+        assert_eq!(stats[&2].min, Scalar::Date(1));
+        assert_eq!(stats[&2].max, Scalar::Date(1));
+    }
+
+    #[test]
+    fn test_parse_timestamp() {
+        let ty = TableDataType::Timestamp;
+        assert_eq!(
+            parse_scalar(&ty, "1970-01-01T00:00:01.000Z"),
+            Some(Scalar::Timestamp(1_000_000))
+        );
+        assert_eq!(
+            parse_scalar(&ty, "1970-01-01 00:00:01.000002"),
+            Some(Scalar::Timestamp(1_000_002))
+        );
+    }
+}
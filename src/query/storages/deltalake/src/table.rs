@@ -0,0 +1,252 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use common_catalog::catalog::StorageDescription;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartInfo;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataSchema;
+use common_expression::Scalar;
+use common_expression::TableSchema;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::storage::StorageParams;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::Pipeline;
+use common_pipeline_core::SourcePipeBuilder;
+use common_storage::DataOperator;
+use opendal::Operator;
+use percent_encoding::percent_decode_str;
+use storages_common_pruner::RangePrunerCreator;
+
+use crate::delta_log::AddAction;
+use crate::delta_log::DeltaSnapshot;
+use crate::partition::DeltaPartInfo;
+use crate::schema::delta_to_table_schema;
+use crate::stats::parse_partition_value;
+use crate::stats::FileStats;
+use crate::table_source::DeltaTableSource;
+
+pub const DELTA_ENGINE: &str = "DELTA";
+
+/// A table on the delta lake table at the `LOCATION` it is created with.
+///
+/// The table is read only, the data files are listed by replaying the delta log
+/// every time the table is read.
+pub struct DeltaTable {
+    info: TableInfo,
+    op: Operator,
+}
+
+impl DeltaTable {
+    pub fn try_create(info: TableInfo) -> Result<Box<dyn Table>> {
+        let sp = info.meta.storage_params.as_ref().ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "Table {} of engine {DELTA_ENGINE} must have a location",
+                info.name
+            ))
+        })?;
+        let op = DataOperator::try_new(sp)?.operator();
+        Ok(Box::new(Self { info, op }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: DELTA_ENGINE.to_string(),
+            comment: "DELTA Storage Engine".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Load the schema of the delta table at `sp`, it's used when the table is
+    /// created without columns.
+    #[async_backtrace::framed]
+    pub async fn load_schema(sp: &StorageParams) -> Result<TableSchema> {
+        let op = DataOperator::try_create(sp).await?.operator();
+        let snapshot = DeltaSnapshot::load(&op).await?;
+        delta_to_table_schema(&snapshot.metadata.schema_string)
+    }
+
+    fn do_read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = std::cmp::min(plan.parts.len(), max_threads);
+        let output_schema: Arc<DataSchema> = Arc::new(plan.schema().into());
+
+        let mut source_builder = SourcePipeBuilder::create();
+        for _ in 0..std::cmp::max(1, max_threads) {
+            let output = OutputPort::create();
+            source_builder.add_source(
+                output.clone(),
+                DeltaTableSource::create(
+                    ctx.clone(),
+                    self.op.clone(),
+                    output,
+                    output_schema.clone(),
+                )?,
+            );
+        }
+
+        pipeline.add_pipe(source_builder.finalize());
+        Ok(())
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn do_read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let snapshot = DeltaSnapshot::load(&self.op).await?;
+
+        let filter = push_downs
+            .as_ref()
+            .and_then(|extra| extra.filter.as_ref().map(|f| f.as_expr(&BUILTIN_FUNCTIONS)));
+        let schema = self.schema();
+        let pruner =
+            RangePrunerCreator::try_create(ctx.get_function_context()?, &schema, filter.as_ref())?;
+
+        let partitions_total = snapshot.files.len();
+        let mut read_rows = 0;
+        let mut read_bytes = 0;
+        let mut is_exact = true;
+        let mut partitions = Vec::with_capacity(partitions_total);
+        for add in snapshot.files {
+            let partition_values = self.partition_values(&add)?;
+            let stats = FileStats::parse(add.stats.as_deref());
+            let column_stats = stats.to_column_stats(&schema, &partition_values);
+            if !pruner.should_keep(&column_stats, None) {
+                continue;
+            }
+
+            let deleted_rows = add.deletion_vector.as_ref().map_or(0, |dv| dv.cardinality);
+            match stats.num_records {
+                Some(n) => read_rows += n as usize - deleted_rows as usize,
+                None => is_exact = false,
+            }
+            read_bytes += add.size as usize;
+
+            partitions.push(Arc::new(Box::new(DeltaPartInfo {
+                path: data_file_path(&add.path)?,
+                size: add.size as u64,
+                partition_values: partition_values.into_iter().collect(),
+                deletion_vector: add.deletion_vector,
+            }) as Box<dyn PartInfo>));
+        }
+
+        let partitions_scanned = partitions.len();
+        let statistics = if is_exact {
+            PartStatistics::new_exact(read_rows, read_bytes, partitions_scanned, partitions_total)
+        } else {
+            PartStatistics::new_estimated(
+                None,
+                read_rows,
+                read_bytes,
+                partitions_scanned,
+                partitions_total,
+            )
+        };
+        Ok((
+            statistics,
+            Partitions::create_nolazy(PartitionsShuffleKind::Mod, partitions),
+        ))
+    }
+
+    /// The typed partition values of the data file, keyed by the lowercase column name.
+    fn partition_values(&self, add: &AddAction) -> Result<HashMap<String, Scalar>> {
+        let schema = self.schema();
+        let mut values = HashMap::new();
+        for (name, value) in add.partition_values.iter().flatten() {
+            let name = name.to_lowercase();
+            let field = schema.field_with_name(&name)?;
+            let scalar =
+                parse_partition_value(field.data_type(), value.as_deref()).ok_or_else(|| {
+                    ErrorCode::ReadTableDataError(format!(
+                        "Invalid value {value:?} of partition column {name} in delta data file {}",
+                        add.path
+                    ))
+                })?;
+            values.insert(name, scalar);
+        }
+        Ok(values)
+    }
+}
+
+/// The path of a data file relative to the table root, decoded from the URI in the log.
+fn data_file_path(path: &str) -> Result<String> {
+    if path.contains("://") {
+        return Err(ErrorCode::Unimplemented(format!(
+            "Delta data file with absolute path {path} is not supported"
+        )));
+    }
+    let path = percent_decode_str(path).decode_utf8().map_err(|e| {
+        ErrorCode::ReadTableDataError(format!("Invalid delta data file path {path}: {e}"))
+    })?;
+    Ok(path.into_owned())
+}
+
+#[async_trait]
+impl Table for DeltaTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn is_local(&self) -> bool {
+        false
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.info
+    }
+
+    fn support_column_projection(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        self.do_read_partitions(ctx, push_downs).await
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        self.do_read_data(ctx, plan, pipeline)
+    }
+}
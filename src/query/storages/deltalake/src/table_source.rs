@@ -0,0 +1,233 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bytes::Bytes;
+use common_catalog::plan::PartInfoPtr;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::DataField;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::Scalar;
+use common_expression::Value;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use croaring::Treemap;
+use opendal::Operator;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReader;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::arrow_reader::RowSelection;
+use parquet::arrow::arrow_reader::RowSelector;
+use parquet::arrow::ProjectionMask;
+
+use crate::partition::DeltaPartInfo;
+
+/// Reads the parquet data files of a delta table.
+///
+/// The rows deleted by the deletion vector of a file are skipped when decoding,
+/// and the partition columns are filled with the partition values of the file.
+pub struct DeltaTableSource {
+    ctx: Arc<dyn TableContext>,
+    op: Operator,
+    output_schema: DataSchemaRef,
+    batch_size: usize,
+    /// The reader of the data file being read.
+    current: Option<(ParquetRecordBatchReader, PartInfoPtr)>,
+}
+
+impl DeltaTableSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        op: Operator,
+        output: Arc<OutputPort>,
+        output_schema: DataSchemaRef,
+    ) -> Result<ProcessorPtr> {
+        let batch_size = ctx.get_settings().get_max_block_size()? as usize;
+        AsyncSourcer::create(ctx.clone(), output, DeltaTableSource {
+            ctx,
+            op,
+            output_schema,
+            batch_size,
+            current: None,
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn open(&self, part: &DeltaPartInfo) -> Result<ParquetRecordBatchReader> {
+        let data = self.op.read(&part.path).await?;
+        let map_err = |e: parquet::errors::ParquetError| {
+            ErrorCode::ReadTableDataError(format!("Cannot read delta data file {}: {e}", part.path))
+        };
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new(Bytes::from(data)).map_err(map_err)?;
+
+        // Only the top level columns that are in the output are read.
+        let indices = builder
+            .schema()
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| {
+                self.output_schema
+                    .fields()
+                    .iter()
+                    .any(|o| o.name().eq_ignore_ascii_case(f.name()))
+            })
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        let mask = ProjectionMask::roots(builder.parquet_schema(), indices);
+        let num_rows = builder.metadata().file_metadata().num_rows() as u64;
+
+        let mut builder = builder
+            .with_projection(mask)
+            .with_batch_size(self.batch_size);
+        if let Some(dv) = &part.deletion_vector {
+            let deleted = dv.read_deleted_rows(&self.op).await?;
+            builder = builder.with_row_selection(deleted_rows_to_selection(&deleted, num_rows));
+        }
+        builder.build().map_err(map_err)
+    }
+
+    /// Convert the record batch to a block of the output schema.
+    fn to_block(
+        output_schema: &DataSchema,
+        batch: &arrow_array::RecordBatch,
+        part: &DeltaPartInfo,
+    ) -> Result<DataBlock> {
+        let num_rows = batch.num_rows();
+        let batch_schema = batch.schema();
+        let mut entries = Vec::with_capacity(output_schema.num_fields());
+        for field in output_schema.fields() {
+            let data_type = field.data_type().clone();
+            if let Some((_, value)) = part
+                .partition_values
+                .iter()
+                .find(|(k, _)| k == field.name())
+            {
+                entries.push(BlockEntry::new(data_type, Value::Scalar(value.clone())));
+                continue;
+            }
+
+            let position = batch_schema
+                .fields()
+                .iter()
+                .position(|f| f.name().eq_ignore_ascii_case(field.name()));
+            let column = match position {
+                Some(i) => {
+                    let column =
+                        Column::from_arrow_rs(batch.column(i).clone(), batch_schema.field(i))
+                            .map_err(|e| {
+                                ErrorCode::ReadTableDataError(format!(
+                                    "Cannot convert delta data file {}: {e}",
+                                    part.path
+                                ))
+                            })?;
+                    Value::Column(adjust_nullable(column, field)?)
+                }
+                // The column is added after the data file is written.
+                None if data_type.is_nullable_or_null() => Value::Scalar(Scalar::Null),
+                None => {
+                    return Err(ErrorCode::TableSchemaMismatch(format!(
+                        "Column {} is not found in delta data file {}",
+                        field.name(),
+                        part.path
+                    )));
+                }
+            };
+            entries.push(BlockEntry::new(data_type, column));
+        }
+        Ok(DataBlock::new(entries, num_rows))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for DeltaTableSource {
+    const NAME: &'static str = "DeltaEngineSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            if let Some((reader, part)) = &mut self.current {
+                match reader.next() {
+                    Some(batch) => {
+                        let batch = batch.map_err(|e| {
+                            ErrorCode::ReadTableDataError(format!(
+                                "Cannot read delta data file: {e}"
+                            ))
+                        })?;
+                        let part = DeltaPartInfo::from_part(part)?;
+                        return Ok(Some(Self::to_block(&self.output_schema, &batch, part)?));
+                    }
+                    None => self.current = None,
+                }
+            }
+
+            let Some(part) = self.ctx.get_partition() else {
+                return Ok(None);
+            };
+            let reader = self.open(DeltaPartInfo::from_part(&part)?).await?;
+            self.current = Some((reader, part));
+        }
+    }
+}
+
+/// Parquet writers of delta tables mark all columns as optional, so the nullable
+/// columns without nulls are accepted for the non-nullable fields.
+fn adjust_nullable(column: Column, field: &DataField) -> Result<Column> {
+    let column = match column {
+        Column::Nullable(c) if !field.is_nullable() && c.validity.unset_bits() == 0 => c.column,
+        Column::Nullable(c) if !field.is_nullable() => {
+            return Err(ErrorCode::TableSchemaMismatch(format!(
+                "Column {} is not nullable, but the data contains nulls",
+                field.name()
+            )));
+        }
+        c if field.is_nullable() && !c.data_type().is_nullable_or_null() => c.wrap_nullable(None),
+        c => c,
+    };
+    if column.data_type() != *field.data_type() {
+        return Err(ErrorCode::TableSchemaMismatch(format!(
+            "Data schema mismatched (col name: {}). Data column type is {:?}, but schema field type is {:?}",
+            field.name(),
+            column.data_type(),
+            field.data_type()
+        )));
+    }
+    Ok(column)
+}
+
+fn deleted_rows_to_selection(deleted: &Treemap, num_rows: u64) -> RowSelection {
+    let mut selectors = vec![];
+    let mut pos = 0;
+    for row in deleted.iter().take_while(|row| *row < num_rows) {
+        if row > pos {
+            selectors.push(RowSelector::select((row - pos) as usize));
+        }
+        selectors.push(RowSelector::skip(1));
+        pos = row + 1;
+    }
+    if pos < num_rows {
+        selectors.push(RowSelector::select((num_rows - pos) as usize));
+    }
+    RowSelection::from(selectors)
+}
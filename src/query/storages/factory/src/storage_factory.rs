@@ -19,6 +19,7 @@ use common_config::InnerConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::schema::TableInfo;
+use common_storages_deltalake::DeltaTable;
 use common_storages_memory::MemoryTable;
 use common_storages_null::NullTable;
 use common_storages_random::RandomTable;
@@ -102,6 +103,12 @@ impl StorageFactory {
             descriptor: Arc::new(RandomTable::description),
         });
 
+        // Register DELTA table engine
+        creators.insert("DELTA".to_string(), Storage {
+            creator: Arc::new(DeltaTable::try_create),
+            descriptor: Arc::new(DeltaTable::description),
+        });
+
         StorageFactory { storages: creators }
     }
 
@@ -17,16 +17,25 @@ pub const FUSE_OPT_KEY_BLOCK_PER_SEGMENT: &str = "block_per_segment";
 pub const FUSE_OPT_KEY_ROW_PER_BLOCK: &str = "row_per_block";
 pub const FUSE_OPT_KEY_ROW_PER_PAGE: &str = "row_per_page";
 pub const FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD: &str = "row_avg_depth_threshold";
+/// How `DELETE` removes rows from a block, either `copy_on_write` or `merge_on_read`.
+pub const FUSE_OPT_KEY_DELETE_MODE: &str = "delete_mode";
+/// Whether Iceberg v2 metadata describing the current snapshot is kept under `metadata/`,
+/// so that external engines can read the table as an Iceberg table.
+pub const FUSE_OPT_KEY_ICEBERG_METADATA: &str = "iceberg_metadata";
 
 pub const FUSE_TBL_BLOCK_PREFIX: &str = "_b";
 pub const FUSE_TBL_BLOCK_INDEX_PREFIX: &str = "_i";
 pub const FUSE_TBL_XOR_BLOOM_INDEX_PREFIX: &str = "_i_b_v2";
+pub const FUSE_TBL_NGRAM_INDEX_PREFIX: &str = "_i_ng";
+pub const FUSE_TBL_INVERTED_INDEX_PREFIX: &str = "_i_inv";
+pub const FUSE_TBL_DELETION_VECTOR_PREFIX: &str = "_dv";
 pub const FUSE_TBL_SEGMENT_PREFIX: &str = "_sg";
 pub const FUSE_TBL_SNAPSHOT_PREFIX: &str = "_ss";
 pub const FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX: &str = "_ts";
 pub const FUSE_TBL_LAST_SNAPSHOT_HINT: &str = "last_snapshot_location_hint";
 pub const FUSE_TBL_VIRTUAL_BLOCK_PREFIX: &str = "_vb";
 pub const FUSE_TBL_AGG_INDEX_PREFIX: &str = "_i_a";
+pub const FUSE_TBL_ICEBERG_METADATA_PREFIX: &str = "metadata";
 
 pub const DEFAULT_BLOCK_PER_SEGMENT: usize = 1000;
 pub const DEFAULT_ROW_PER_PAGE: usize = 131072;
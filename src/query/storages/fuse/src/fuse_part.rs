@@ -33,6 +33,8 @@ use storages_common_pruner::BlockMetaIndex;
 use storages_common_table_meta::meta::ColumnMeta;
 use storages_common_table_meta::meta::Compression;
 
+use crate::io::DeletionVector;
+
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug)]
 pub struct FusePartInfo {
     pub location: String,
@@ -45,6 +47,9 @@ pub struct FusePartInfo {
 
     pub sort_min_max: Option<(Scalar, Scalar)>,
     pub block_meta_index: Option<BlockMetaIndex>,
+    /// The rows of the block deleted by merge-on-read `DELETE`s,
+    /// `nums_rows` counts them too.
+    pub deletion_vector: Option<DeletionVector>,
 }
 
 #[typetag::serde(name = "fuse")]
@@ -77,6 +82,7 @@ impl FusePartInfo {
         sort_min_max: Option<(Scalar, Scalar)>,
         block_meta_index: Option<BlockMetaIndex>,
         create_on: Option<DateTime<Utc>>,
+        deletion_vector: Option<DeletionVector>,
     ) -> Arc<Box<dyn PartInfo>> {
         Arc::new(Box::new(FusePartInfo {
             location,
@@ -87,6 +93,7 @@ impl FusePartInfo {
             compression,
             sort_min_max,
             block_meta_index,
+            deletion_vector,
         }))
     }
 
@@ -108,6 +115,15 @@ impl FusePartInfo {
         self.block_meta_index.as_ref()
     }
 
+    /// Number of rows of the block that are not deleted.
+    pub fn live_rows(&self) -> usize {
+        self.nums_rows
+            - self
+                .deletion_vector
+                .as_ref()
+                .map_or(0, |dv| dv.deleted_row_count as usize)
+    }
+
     pub fn page_size(&self) -> usize {
         self.block_meta_index
             .as_ref()
@@ -38,6 +38,8 @@ use common_expression::ColumnId;
 use common_expression::FieldIndex;
 use common_expression::RemoteExpr;
 use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_functions::scalars::Tokenizer;
 use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
 use common_meta_app::schema::DatabaseType;
@@ -48,6 +50,7 @@ use common_sql::parse_exprs;
 use common_sql::BloomIndexColumns;
 use common_storage::init_operator;
 use common_storage::DataOperator;
+use common_storage::EncryptionLayer;
 use common_storage::ShareTableConfig;
 use common_storage::StorageMetrics;
 use common_storage::StorageMetricsLayer;
@@ -55,7 +58,9 @@ use log::error;
 use log::warn;
 use opendal::Operator;
 use storages_common_cache::LoadParams;
+use storages_common_index::InvertedIndex;
 use storages_common_table_meta::meta::ClusterKey;
+use storages_common_table_meta::meta::ColumnHistogram;
 use storages_common_table_meta::meta::ColumnStatistics as FuseColumnStatistics;
 use storages_common_table_meta::meta::SnapshotId;
 use storages_common_table_meta::meta::Statistics as FuseStatistics;
@@ -66,7 +71,10 @@ use storages_common_table_meta::table::table_storage_prefix;
 use storages_common_table_meta::table::TableCompression;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_ENCRYPTION_KEYS;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEX_TOKENIZER;
 use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
@@ -86,6 +94,7 @@ use crate::DEFAULT_ROW_PER_PAGE;
 use crate::DEFAULT_ROW_PER_PAGE_FOR_BLOCKING;
 use crate::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use crate::FUSE_OPT_KEY_DELETE_MODE;
 use crate::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use crate::FUSE_OPT_KEY_ROW_PER_PAGE;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
@@ -98,7 +107,9 @@ pub struct FuseTable {
     pub(crate) cluster_key_meta: Option<ClusterKey>,
     pub(crate) storage_format: FuseStorageFormat,
     pub(crate) table_compression: TableCompression,
+    pub(crate) delete_mode: FuseDeleteMode,
     pub(crate) bloom_index_cols: BloomIndexColumns,
+    pub(crate) ngram_index_cols: BloomIndexColumns,
 
     pub(crate) operator: Operator,
     pub(crate) data_metrics: Arc<StorageMetrics>,
@@ -130,6 +141,10 @@ impl FuseTable {
             }
         }?;
 
+        if let Some(keyring) = table_info.options().get(OPT_KEY_ENCRYPTION_KEYS) {
+            operator = operator.layer(EncryptionLayer::create(keyring));
+        }
+
         let data_metrics = Arc::new(StorageMetrics::default());
         operator = operator.layer(StorageMetricsLayer::new(data_metrics.clone()));
 
@@ -151,6 +166,19 @@ impl FuseTable {
             .and_then(|s| s.parse::<BloomIndexColumns>().ok())
             .unwrap_or(BloomIndexColumns::All);
 
+        // unlike the bloom index, the ngram index is only built for the columns specified.
+        let ngram_index_cols = table_info
+            .options()
+            .get(OPT_KEY_NGRAM_INDEX_COLUMNS)
+            .and_then(|s| s.parse::<BloomIndexColumns>().ok())
+            .unwrap_or(BloomIndexColumns::None);
+
+        let delete_mode = table_info
+            .options()
+            .get(FUSE_OPT_KEY_DELETE_MODE)
+            .and_then(|s| s.parse::<FuseDeleteMode>().ok())
+            .unwrap_or_default();
+
         let part_prefix = table_info.meta.part_prefix.clone();
 
         let meta_location_generator =
@@ -161,10 +189,12 @@ impl FuseTable {
             meta_location_generator,
             cluster_key_meta,
             bloom_index_cols,
+            ngram_index_cols,
             operator,
             data_metrics,
             storage_format: FuseStorageFormat::from_str(storage_format.as_str())?,
             table_compression: table_compression.as_str().try_into()?,
+            delete_mode,
         }))
     }
 
@@ -180,6 +210,10 @@ impl FuseTable {
         matches!(self.storage_format, FuseStorageFormat::Native)
     }
 
+    pub fn delete_mode(&self) -> FuseDeleteMode {
+        self.delete_mode
+    }
+
     pub fn meta_location_generator(&self) -> &TableMetaLocationGenerator {
         &self.meta_location_generator
     }
@@ -352,9 +386,63 @@ impl FuseTable {
         self.cluster_key_meta.clone().map(|v| v.0)
     }
 
+    pub fn partition_key_str(&self) -> Option<&String> {
+        self.table_info.meta.partition_key.as_ref()
+    }
+
+    /// The expressions of the partition key, with the columns referenced by name.
+    pub fn partition_keys(&self, ctx: Arc<dyn TableContext>) -> Result<Vec<RemoteExpr<String>>> {
+        let Some(partition_key) = self.partition_key_str() else {
+            return Ok(vec![]);
+        };
+        let table_meta = Arc::new(self.clone());
+        let partition_keys = parse_exprs(ctx, table_meta.clone(), partition_key)?;
+        let partition_keys = if partition_keys.len() == 1 {
+            unwrap_tuple(&partition_keys[0]).unwrap_or(partition_keys)
+        } else {
+            partition_keys
+        };
+        Ok(partition_keys
+            .iter()
+            .map(|k| {
+                k.project_column_ref(|index| table_meta.schema().field(*index).name().to_string())
+                    .as_remote_expr()
+            })
+            .collect())
+    }
+
     pub fn bloom_index_cols(&self) -> BloomIndexColumns {
         self.bloom_index_cols.clone()
     }
+
+    pub fn ngram_index_cols(&self) -> BloomIndexColumns {
+        self.ngram_index_cols.clone()
+    }
+
+    /// The columns of the inverted indexes of the table, keyed by their offsets in `schema`,
+    /// with the tokenizer of their index.
+    pub fn inverted_index_fields(
+        &self,
+        schema: &TableSchemaRef,
+    ) -> Result<BTreeMap<FieldIndex, (TableField, Tokenizer)>> {
+        let mut fields = BTreeMap::new();
+        for index in self.table_info.meta.indexes.values() {
+            let tokenizer = index
+                .options
+                .get(OPT_KEY_INVERTED_INDEX_TOKENIZER)
+                .map(|name| Tokenizer::try_create(name))
+                .transpose()?
+                .unwrap_or(Tokenizer::English);
+            for (offset, field) in schema.fields().iter().enumerate() {
+                if index.column_ids.contains(&field.column_id())
+                    && InvertedIndex::supported_type(field.data_type())
+                {
+                    fields.insert(offset, (field.clone(), tokenizer));
+                }
+            }
+        }
+        Ok(fields)
+    }
 }
 
 #[async_trait::async_trait]
@@ -622,12 +710,14 @@ impl Table for FuseTable {
                     row_count: snapshot.summary.row_count,
                     // save row count first
                     column_distinct_values: Some(table_statistics.column_distinct_values.clone()),
+                    histograms: Some(table_statistics.histograms.clone()),
                 }
             } else {
                 FuseTableColumnStatisticsProvider {
                     column_stats: stats.clone(),
                     row_count: snapshot.summary.row_count,
                     column_distinct_values: None,
+                    histograms: None,
                 }
             }
         } else {
@@ -638,24 +728,14 @@ impl Table for FuseTable {
 
     #[minitrace::trace(name = "fuse_table_navigate_to")]
     #[async_backtrace::framed]
-    async fn navigate_to(&self, point: &NavigationPoint) -> Result<Arc<dyn Table>> {
-        let snapshot_location = if let Some(loc) = self.snapshot_loc().await? {
-            loc
-        } else {
-            // not an error?
-            return Err(ErrorCode::TableHistoricalDataNotFound(
-                "Empty Table has no historical data",
-            ));
-        };
-
-        match point {
-            NavigationPoint::SnapshotID(snapshot_id) => Ok(self
-                .navigate_to_snapshot(snapshot_location, snapshot_id.as_str())
-                .await?),
-            NavigationPoint::TimePoint(time_point) => Ok(self
-                .navigate_to_time_point(snapshot_location, *time_point)
-                .await?),
-        }
+    async fn navigate_to(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        navigation: &NavigationDescriptor,
+    ) -> Result<Arc<dyn Table>> {
+        Ok(self
+            .navigate_within_retention(ctx.as_ref(), navigation)
+            .await?)
     }
 
     #[async_backtrace::framed]
@@ -765,10 +845,37 @@ impl FromStr for FuseStorageFormat {
     }
 }
 
+/// How `DELETE` removes the rows from the blocks of a fuse table.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FuseDeleteMode {
+    /// Rewrite the blocks without the deleted rows.
+    #[default]
+    CopyOnWrite,
+    /// Keep the blocks, and mark the deleted rows in the deletion vectors of them,
+    /// the deleted rows are skipped by the readers, and removed by compaction.
+    MergeOnRead,
+}
+
+impl FromStr for FuseDeleteMode {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "copy_on_write" => Ok(FuseDeleteMode::CopyOnWrite),
+            "merge_on_read" => Ok(FuseDeleteMode::MergeOnRead),
+            other => Err(ErrorCode::TableOptionInvalid(format!(
+                "invalid {} option {}, must be 'copy_on_write' or 'merge_on_read'",
+                FUSE_OPT_KEY_DELETE_MODE, other
+            ))),
+        }
+    }
+}
+
 #[derive(Default)]
 struct FuseTableColumnStatisticsProvider {
     column_stats: HashMap<ColumnId, FuseColumnStatistics>,
     pub column_distinct_values: Option<HashMap<ColumnId, u64>>,
+    pub histograms: Option<HashMap<ColumnId, ColumnHistogram>>,
     pub row_count: u64,
 }
 
@@ -789,4 +896,10 @@ impl ColumnStatisticsProvider for FuseTableColumnStatisticsProvider {
             }
         })
     }
+
+    fn histogram(&self, column_id: ColumnId) -> Option<ColumnHistogram> {
+        self.histograms
+            .as_ref()
+            .and_then(|histograms| histograms.get(&column_id).cloned())
+    }
 }
@@ -0,0 +1,105 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use croaring::treemap::NativeSerializer;
+use croaring::Treemap;
+use opendal::Operator;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::Versioned;
+
+/// The deletion vector of a block, the positions of the rows deleted from the block by
+/// a merge-on-read `DELETE`, instead of rewriting the block without them.
+///
+/// It is stored as a standalone file, serialized in the same way as the values of the
+/// `BITMAP` type, and referenced by the [BlockMeta] of the block.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DeletionVector {
+    pub location: Location,
+    pub size: u64,
+    pub deleted_row_count: u64,
+}
+
+impl Versioned<0> for DeletionVector {}
+
+impl DeletionVector {
+    pub fn from_block_meta(meta: &BlockMeta) -> Option<Self> {
+        meta.deletion_vector_location
+            .as_ref()
+            .map(|location| DeletionVector {
+                location: location.clone(),
+                size: meta.deletion_vector_size,
+                deleted_row_count: meta.deleted_row_count,
+            })
+    }
+
+    /// Returns a copy of the block meta that references this deletion vector,
+    /// the block file and the indexes of it are kept.
+    pub fn apply_to(&self, meta: &BlockMeta) -> BlockMeta {
+        let mut new_meta = meta.clone();
+        new_meta.deletion_vector_location = Some(self.location.clone());
+        new_meta.deletion_vector_size = self.size;
+        new_meta.deleted_row_count = self.deleted_row_count;
+        new_meta
+    }
+
+    /// Serializes the rows of a block that are unset in `live_rows`.
+    pub fn serialize(live_rows: &Bitmap) -> Result<Vec<u8>> {
+        let deleted = Treemap::from_iter(
+            live_rows
+                .iter()
+                .enumerate()
+                .filter(|(_, live)| !live)
+                .map(|(pos, _)| pos as u64),
+        );
+        deleted
+            .serialize()
+            .map_err(|e| ErrorCode::Internal(format!("serialize deletion vector failed: {e}")))
+    }
+
+    /// Reads the deletion vector, the rows of the block that are not deleted are set in
+    /// the returned bitmap, which has `num_rows` bits.
+    #[async_backtrace::framed]
+    pub async fn read_live_rows(&self, dal: &Operator, num_rows: usize) -> Result<Bitmap> {
+        let (path, _) = &self.location;
+        let data = dal.range_read(path, 0..self.size).await?;
+        Self::live_rows(path, &data, num_rows)
+    }
+
+    pub fn sync_read_live_rows(&self, dal: &Operator, num_rows: usize) -> Result<Bitmap> {
+        let (path, _) = &self.location;
+        let data = dal.blocking().range_read(path, 0..self.size)?;
+        Self::live_rows(path, &data, num_rows)
+    }
+
+    fn live_rows(path: &str, data: &[u8], num_rows: usize) -> Result<Bitmap> {
+        let deleted = Treemap::deserialize(data)
+            .map_err(|e| ErrorCode::StorageOther(format!("invalid deletion vector {path}: {e}")))?;
+
+        let mut live_rows = MutableBitmap::from_len_set(num_rows);
+        for pos in deleted.iter() {
+            if pos >= num_rows as u64 {
+                return Err(ErrorCode::StorageOther(format!(
+                    "invalid deletion vector {path}: row {pos} is out of the block of {num_rows} rows"
+                )));
+            }
+            live_rows.set(pos as usize, false);
+        }
+        Ok(live_rows.into())
+    }
+}
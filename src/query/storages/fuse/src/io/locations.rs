@@ -29,8 +29,13 @@ use crate::constants::FUSE_TBL_SNAPSHOT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
 use crate::constants::FUSE_TBL_VIRTUAL_BLOCK_PREFIX;
 use crate::index::filters::BlockFilter;
+use crate::io::DeletionVector;
 use crate::FUSE_TBL_AGG_INDEX_PREFIX;
+use crate::FUSE_TBL_DELETION_VECTOR_PREFIX;
+use crate::FUSE_TBL_ICEBERG_METADATA_PREFIX;
+use crate::FUSE_TBL_INVERTED_INDEX_PREFIX;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
+use crate::FUSE_TBL_NGRAM_INDEX_PREFIX;
 use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 
 static SNAPSHOT_V0: SnapshotVersion = SnapshotVersion::V0(PhantomData);
@@ -41,6 +46,8 @@ static SNAPSHOT_V4: SnapshotVersion = SnapshotVersion::V4(PhantomData);
 
 static SNAPSHOT_STATISTICS_V0: TableSnapshotStatisticsVersion =
     TableSnapshotStatisticsVersion::V0(PhantomData);
+static SNAPSHOT_STATISTICS_V1: TableSnapshotStatisticsVersion =
+    TableSnapshotStatisticsVersion::V1(PhantomData);
 
 #[derive(Clone)]
 pub struct TableMetaLocationGenerator {
@@ -96,6 +103,51 @@ impl TableMetaLocationGenerator {
         )
     }
 
+    /// The ngram index shares the layout of the bloom index, it is versioned as a [BlockFilter].
+    pub fn block_ngram_index_location(&self, block_id: &Uuid) -> Location {
+        (
+            format!(
+                "{}/{}/{}_v{}.parquet",
+                &self.prefix,
+                FUSE_TBL_NGRAM_INDEX_PREFIX,
+                block_id.as_simple(),
+                BlockFilter::VERSION,
+            ),
+            BlockFilter::VERSION,
+        )
+    }
+
+    /// The inverted index is stored as a parquet file of string columns like the bloom index,
+    /// it is versioned as a [BlockFilter] too.
+    pub fn block_inverted_index_location(&self, block_id: &Uuid) -> Location {
+        (
+            format!(
+                "{}/{}/{}_v{}.parquet",
+                &self.prefix,
+                FUSE_TBL_INVERTED_INDEX_PREFIX,
+                block_id.as_simple(),
+                BlockFilter::VERSION,
+            ),
+            BlockFilter::VERSION,
+        )
+    }
+
+    /// A new deletion vector is written each time rows of the block are deleted,
+    /// so it is not named after the block like the indexes.
+    pub fn gen_deletion_vector_location(&self) -> Location {
+        let uuid = Uuid::new_v4();
+        (
+            format!(
+                "{}/{}/{}_v{}.bin",
+                &self.prefix,
+                FUSE_TBL_DELETION_VECTOR_PREFIX,
+                uuid.as_simple(),
+                DeletionVector::VERSION,
+            ),
+            DeletionVector::VERSION,
+        )
+    }
+
     pub fn gen_segment_info_location(&self) -> String {
         let segment_uuid = Uuid::new_v4().simple().to_string();
         format!(
@@ -135,14 +187,58 @@ impl TableMetaLocationGenerator {
         Ok(statistics_version.create(id, &self.prefix))
     }
 
-    pub fn snapshot_statistics_version(_location: impl AsRef<str>) -> u64 {
-        SNAPSHOT_STATISTICS_V0.version()
+    pub fn snapshot_statistics_version(location: impl AsRef<str>) -> u64 {
+        if location
+            .as_ref()
+            .ends_with(SNAPSHOT_STATISTICS_V1.suffix().as_str())
+        {
+            SNAPSHOT_STATISTICS_V1.version()
+        } else {
+            SNAPSHOT_STATISTICS_V0.version()
+        }
     }
 
     pub fn gen_last_snapshot_hint_location(&self) -> String {
         format!("{}/{}", &self.prefix, FUSE_TBL_LAST_SNAPSHOT_HINT)
     }
 
+    pub fn gen_iceberg_metadata_location(&self, version: u64) -> String {
+        format!(
+            "{}/{}/v{}.metadata.json",
+            &self.prefix, FUSE_TBL_ICEBERG_METADATA_PREFIX, version
+        )
+    }
+
+    pub fn gen_iceberg_version_hint_location(&self) -> String {
+        format!(
+            "{}/{}/version-hint.text",
+            &self.prefix, FUSE_TBL_ICEBERG_METADATA_PREFIX
+        )
+    }
+
+    pub fn gen_iceberg_manifest_list_location(&self, snapshot_id: i64) -> String {
+        format!(
+            "{}/{}/snap-{}.avro",
+            &self.prefix, FUSE_TBL_ICEBERG_METADATA_PREFIX, snapshot_id
+        )
+    }
+
+    // one manifest per segment, named after the segment so that it can be reused
+    // as long as the segment is referenced by the snapshot
+    pub fn gen_iceberg_manifest_location(&self, segment_location: &str) -> String {
+        let segment_name = segment_location
+            .rsplit('/')
+            .next()
+            .unwrap_or(segment_location);
+        let stem = segment_name
+            .split_once('.')
+            .map_or(segment_name, |(stem, _)| stem);
+        format!(
+            "{}/{}/{}-m0.avro",
+            &self.prefix, FUSE_TBL_ICEBERG_METADATA_PREFIX, stem
+        )
+    }
+
     pub fn gen_virtual_block_location(location: &str) -> String {
         location.replace(FUSE_TBL_BLOCK_PREFIX, FUSE_TBL_VIRTUAL_BLOCK_PREFIX)
     }
@@ -197,6 +293,7 @@ impl SnapshotLocationCreator for TableSnapshotStatisticsVersion {
     fn suffix(&self) -> String {
         match self {
             TableSnapshotStatisticsVersion::V0(_) => "_ts_v0.json".to_string(),
+            TableSnapshotStatisticsVersion::V1(_) => "_ts_v1.json".to_string(),
         }
     }
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod deletion_vector;
 mod files;
 mod locations;
 pub mod read;
@@ -19,12 +20,14 @@ mod segments;
 mod snapshots;
 mod write;
 
+pub use deletion_vector::DeletionVector;
 pub use files::Files;
 pub use locations::TableMetaLocationGenerator;
 pub use read::AggIndexReader;
 pub use read::BlockReader;
 pub use read::BloomBlockFilterReader;
 pub use read::CompactSegmentInfoReader;
+pub use read::InvertedIndexReader;
 pub use read::MergeIOReadResult;
 pub use read::MetaReaders;
 pub use read::NativeReaderExt;
@@ -42,6 +45,7 @@ pub use write::write_data;
 pub use write::BlockBuilder;
 pub use write::BlockSerialization;
 pub use write::CachedMetaWriter;
+pub use write::IcebergMetadataWriter;
 pub use write::MetaWriter;
 pub use write::SegmentWriter;
 pub use write::WriteSettings;
@@ -0,0 +1,78 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+use std::sync::Arc;
+
+use common_arrow::arrow::io::parquet::read::infer_schema;
+use common_arrow::arrow::io::parquet::read::{self as pread};
+use common_arrow::parquet::read::read_metadata;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::FunctionContext;
+use common_expression::TableSchema;
+use opendal::Operator;
+use storages_common_index::InvertedIndex;
+use storages_common_table_meta::meta::Location;
+
+use crate::metrics::metrics_inc_block_index_read_bytes;
+
+#[async_trait::async_trait]
+pub trait InvertedIndexReader {
+    async fn read_inverted_index(
+        &self,
+        dal: Operator,
+        func_ctx: FunctionContext,
+        index_length: u64,
+    ) -> Result<InvertedIndex>;
+}
+
+#[async_trait::async_trait]
+impl InvertedIndexReader for Location {
+    /// The inverted index file of a block is small, and all of its columns are
+    /// usually searched, so the whole file is loaded.
+    #[async_backtrace::framed]
+    async fn read_inverted_index(
+        &self,
+        dal: Operator,
+        func_ctx: FunctionContext,
+        index_length: u64,
+    ) -> Result<InvertedIndex> {
+        let (path, _) = &self;
+        let data = dal.range_read(path, 0..index_length).await?;
+        metrics_inc_block_index_read_bytes(data.len() as u64);
+
+        let mut reader = Cursor::new(data);
+        let meta = read_metadata(&mut reader)?;
+        let arrow_schema = infer_schema(&meta)?;
+        let index_schema = TableSchema::from(&arrow_schema);
+        let schema = DataSchema::from(&index_schema);
+
+        let mut chunks =
+            pread::FileReader::new(reader, meta.row_groups, arrow_schema, None, None, None);
+        let index_block = match chunks.next() {
+            Some(chunk) => DataBlock::from_arrow_chunk(&chunk?, &schema)?,
+            None => {
+                return Err(ErrorCode::StorageOther(format!(
+                    "inverted index {} has no data",
+                    path
+                )));
+            }
+        };
+
+        InvertedIndex::from_index_block(func_ctx, Arc::new(index_schema), &index_block)
+    }
+}
@@ -26,11 +26,14 @@ use common_expression::DataBlock;
 use common_expression::FieldIndex;
 use common_expression::TableField;
 use common_expression::TableSchemaRef;
+use common_functions::scalars::Tokenizer;
 use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
 use opendal::Operator;
 use storages_common_blocks::blocks_to_parquet;
 use storages_common_index::BloomIndex;
+use storages_common_index::InvertedIndex;
+use storages_common_index::NgramIndex;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
 use storages_common_table_meta::meta::ColumnMeta;
@@ -43,6 +46,7 @@ use crate::io::TableMetaLocationGenerator;
 use crate::operations::util;
 use crate::statistics::gen_columns_statistics;
 use crate::statistics::ClusterStatsGenerator;
+use crate::statistics::PartitionGenerator;
 
 // TODO rename this, it is serialization, or pass in a writer(if not rename)
 pub fn serialize_block(
@@ -151,11 +155,89 @@ impl BloomIndexState {
     }
 }
 
+pub struct NgramIndexState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) size: u64,
+    pub(crate) location: Location,
+}
+
+impl NgramIndexState {
+    pub fn try_create(
+        ctx: Arc<dyn TableContext>,
+        block: &DataBlock,
+        location: Location,
+        ngram_columns_map: BTreeMap<FieldIndex, TableField>,
+    ) -> Result<Option<Self>> {
+        if ngram_columns_map.is_empty() {
+            return Ok(None);
+        }
+        let maybe_ngram_index =
+            NgramIndex::try_create(ctx.get_function_context()?, &[block], ngram_columns_map)?;
+        if let Some(ngram_index) = maybe_ngram_index {
+            let index_block = ngram_index.serialize_to_data_block()?;
+            let mut data = Vec::with_capacity(DEFAULT_BLOCK_INDEX_BUFFER_SIZE);
+            let (size, _) = blocks_to_parquet(
+                &ngram_index.filter_schema,
+                vec![index_block],
+                &mut data,
+                TableCompression::None,
+            )?;
+            Ok(Some(Self {
+                data,
+                size,
+                location,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+pub struct InvertedIndexState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) size: u64,
+    pub(crate) location: Location,
+}
+
+impl InvertedIndexState {
+    pub fn try_create(
+        ctx: Arc<dyn TableContext>,
+        block: &DataBlock,
+        location: Location,
+        inverted_columns_map: BTreeMap<FieldIndex, (TableField, Tokenizer)>,
+    ) -> Result<Option<Self>> {
+        if inverted_columns_map.is_empty() {
+            return Ok(None);
+        }
+        let maybe_inverted_index =
+            InvertedIndex::try_create(ctx.get_function_context()?, &[block], inverted_columns_map)?;
+        if let Some(inverted_index) = maybe_inverted_index {
+            let index_block = inverted_index.serialize_to_data_block()?;
+            let mut data = Vec::with_capacity(DEFAULT_BLOCK_INDEX_BUFFER_SIZE);
+            let (size, _) = blocks_to_parquet(
+                &inverted_index.index_schema,
+                vec![index_block],
+                &mut data,
+                TableCompression::Zstd,
+            )?;
+            Ok(Some(Self {
+                data,
+                size,
+                location,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
 pub struct BlockSerialization {
     pub block_raw_data: Vec<u8>,
     pub size: u64, // TODO redundancy
     pub block_meta: BlockMeta,
     pub bloom_index_state: Option<BloomIndexState>,
+    pub ngram_index_state: Option<NgramIndexState>,
+    pub inverted_index_state: Option<InvertedIndexState>,
 }
 
 #[derive(Clone)]
@@ -165,7 +247,10 @@ pub struct BlockBuilder {
     pub source_schema: TableSchemaRef,
     pub write_settings: WriteSettings,
     pub cluster_stats_gen: ClusterStatsGenerator,
+    pub partition_gen: PartitionGenerator,
     pub bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub ngram_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub inverted_columns_map: BTreeMap<FieldIndex, (TableField, Tokenizer)>,
 }
 
 impl BlockBuilder {
@@ -173,6 +258,7 @@ impl BlockBuilder {
     where F: Fn(DataBlock, &ClusterStatsGenerator) -> Result<(Option<ClusterStatistics>, DataBlock)>
     {
         let (cluster_stats, data_block) = f(data_block, &self.cluster_stats_gen)?;
+        let partition = self.partition_gen.partition_of(&data_block)?;
         let (block_location, block_id) = self.meta_locations.gen_block_location();
 
         let bloom_index_location = self.meta_locations.block_bloom_index_location(&block_id);
@@ -186,6 +272,22 @@ impl BlockBuilder {
             .as_ref()
             .map(|i| i.column_distinct_count.clone());
 
+        let ngram_index_location = self.meta_locations.block_ngram_index_location(&block_id);
+        let ngram_index_state = NgramIndexState::try_create(
+            self.ctx.clone(),
+            &data_block,
+            ngram_index_location,
+            self.ngram_columns_map.clone(),
+        )?;
+
+        let inverted_index_location = self.meta_locations.block_inverted_index_location(&block_id);
+        let inverted_index_state = InvertedIndexState::try_create(
+            self.ctx.clone(),
+            &data_block,
+            inverted_index_location,
+            self.inverted_columns_map.clone(),
+        )?;
+
         let row_count = data_block.num_rows() as u64;
         let block_size = data_block.memory_size() as u64;
         let col_stats =
@@ -212,6 +314,20 @@ impl BlockBuilder {
                 .as_ref()
                 .map(|v| v.size)
                 .unwrap_or_default(),
+            ngram_index_location: ngram_index_state.as_ref().map(|v| v.location.clone()),
+            ngram_index_size: ngram_index_state
+                .as_ref()
+                .map(|v| v.size)
+                .unwrap_or_default(),
+            inverted_index_location: inverted_index_state.as_ref().map(|v| v.location.clone()),
+            inverted_index_size: inverted_index_state
+                .as_ref()
+                .map(|v| v.size)
+                .unwrap_or_default(),
+            deletion_vector_location: None,
+            deletion_vector_size: 0,
+            deleted_row_count: 0,
+            partition,
             compression: self.write_settings.table_compression.try_into()?,
             create_on: Some(Utc::now()),
         };
@@ -221,6 +337,8 @@ impl BlockBuilder {
             size: file_size,
             block_meta,
             bloom_index_state,
+            ngram_index_state,
+            inverted_index_state,
         };
         Ok(serialized)
     }
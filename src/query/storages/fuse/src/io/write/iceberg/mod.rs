@@ -0,0 +1,371 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Exposes the current snapshot of a fuse table as an Iceberg v2 table.
+//!
+//! Blocks of fuse are parquet files already, the metadata written here references
+//! them in place, so that external engines may read the table without copying data.
+//!
+//! Layout, under `{table_prefix}/metadata/`:
+//! - `v{N}.metadata.json`, the table metadata, and `version-hint.text` pointing at it.
+//! - `snap-{snapshot_id}.avro`, the manifest list of the snapshot.
+//! - `{segment}-m0.avro`, one manifest per segment, reused while the segment is alive.
+
+mod manifest;
+mod schema;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use opendal::ErrorKind;
+use opendal::Operator;
+use opendal::Scheme;
+use serde_json::json;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::ColumnMeta;
+use storages_common_table_meta::meta::TableSnapshot;
+use uuid::Uuid;
+
+use self::manifest::decode_manifest_list;
+use self::manifest::encode_manifest;
+use self::manifest::encode_manifest_list;
+use self::manifest::DataFile;
+use self::manifest::ManifestFile;
+use self::manifest::SnapshotContext;
+use self::schema::iceberg_fields;
+use self::schema::name_mapping_json;
+use self::schema::schema_json;
+use self::schema::IcebergField;
+use crate::io::SegmentsIO;
+use crate::io::TableMetaLocationGenerator;
+
+const SUMMARY_KEY_FUSE_SNAPSHOT_ID: &str = "databend.snapshot-id";
+const SUMMARY_KEY_FUSE_SNAPSHOT_TIMESTAMP: &str = "databend.snapshot-timestamp-ms";
+const MAX_METADATA_LOG_ENTRIES: usize = 10;
+
+pub struct IcebergMetadataWriter<'a> {
+    operator: &'a Operator,
+    location_generator: &'a TableMetaLocationGenerator,
+}
+
+impl<'a> IcebergMetadataWriter<'a> {
+    pub fn create(
+        operator: &'a Operator,
+        location_generator: &'a TableMetaLocationGenerator,
+    ) -> Self {
+        Self {
+            operator,
+            location_generator,
+        }
+    }
+
+    /// Writes the Iceberg metadata of the given snapshot, unless the metadata of
+    /// this snapshot, or of a newer one, has been written already.
+    #[async_backtrace::framed]
+    pub async fn write(&self, snapshot: &TableSnapshot) -> Result<()> {
+        let base_uri = storage_uri(self.operator)?;
+        let previous = self.read_current_metadata().await?;
+
+        let fuse_snapshot_id = snapshot.snapshot_id.simple().to_string();
+        let timestamp_ms = snapshot
+            .timestamp
+            .unwrap_or_else(Utc::now)
+            .timestamp_millis();
+        if let Some((_, metadata)) = &previous {
+            if let Some(summary) = current_snapshot(metadata).map(|s| &s["summary"]) {
+                if summary[SUMMARY_KEY_FUSE_SNAPSHOT_ID].as_str() == Some(&fuse_snapshot_id) {
+                    return Ok(());
+                }
+                let exported_ts = summary[SUMMARY_KEY_FUSE_SNAPSHOT_TIMESTAMP]
+                    .as_str()
+                    .and_then(|v| v.parse::<i64>().ok());
+                if matches!(exported_ts, Some(ts) if ts > timestamp_ms) {
+                    return Ok(());
+                }
+            }
+        }
+
+        let snapshot_id = iceberg_snapshot_id(&snapshot.snapshot_id);
+        let parent_snapshot_id = previous
+            .as_ref()
+            .and_then(|(_, metadata)| metadata["current-snapshot-id"].as_i64())
+            .filter(|id| *id >= 0);
+        let sequence_number = previous
+            .as_ref()
+            .and_then(|(_, metadata)| metadata["last-sequence-number"].as_i64())
+            .unwrap_or(0)
+            + 1;
+
+        let fields = iceberg_fields(&snapshot.schema);
+        let schema = schema_json(&fields);
+        let schema_str = schema.to_string();
+        let ctx = SnapshotContext {
+            snapshot_id,
+            parent_snapshot_id,
+            sequence_number,
+            schema_json: &schema_str,
+        };
+
+        // manifests of segments which are still referenced are reused
+        let mut reusable = HashMap::new();
+        if let Some(manifest_list) = previous
+            .as_ref()
+            .and_then(|(_, metadata)| current_snapshot(metadata))
+            .and_then(|s| s["manifest-list"].as_str())
+        {
+            if let Some(path) = manifest_list.strip_prefix(&format!("{}/", base_uri)) {
+                let data = self.operator.read(path).await?;
+                for manifest in decode_manifest_list(&data)? {
+                    reusable.insert(manifest.manifest_path.clone(), manifest);
+                }
+            }
+        }
+
+        let table_schema = Arc::new(snapshot.schema.clone());
+        let mut manifests = Vec::with_capacity(snapshot.segments.len());
+        for segment_location in &snapshot.segments {
+            let manifest_path = self
+                .location_generator
+                .gen_iceberg_manifest_location(&segment_location.0);
+            let manifest_uri = format!("{}/{}", base_uri, manifest_path);
+            if let Some(manifest) = reusable.remove(&manifest_uri) {
+                manifests.push(manifest);
+                continue;
+            }
+
+            let segment = SegmentsIO::read_segment(
+                self.operator.clone(),
+                segment_location.clone(),
+                table_schema.clone(),
+                false,
+            )
+            .await?;
+            let data_files = segment
+                .blocks
+                .iter()
+                .map(|block| data_file(&base_uri, &fields, block))
+                .collect::<Result<Vec<_>>>()?;
+            let data = encode_manifest(&ctx, &data_files)?;
+            let manifest_length = data.len() as i64;
+            self.operator.write(&manifest_path, data).await?;
+            manifests.push(ManifestFile {
+                manifest_path: manifest_uri,
+                manifest_length,
+                sequence_number,
+                added_snapshot_id: snapshot_id,
+                added_files_count: data_files.len() as i32,
+                added_rows_count: data_files.iter().map(|f| f.record_count).sum(),
+            });
+        }
+
+        let manifest_list_path = self
+            .location_generator
+            .gen_iceberg_manifest_list_location(snapshot_id);
+        let data = encode_manifest_list(&ctx, &manifests)?;
+        self.operator.write(&manifest_list_path, data).await?;
+
+        let table_location = format!("{}/{}", base_uri, self.location_generator.prefix());
+        let (version, table_uuid, mut metadata_log) = match &previous {
+            Some((version, metadata)) => {
+                let mut metadata_log = metadata["metadata-log"]
+                    .as_array()
+                    .cloned()
+                    .unwrap_or_default();
+                metadata_log.push(json!({
+                    "timestamp-ms": metadata["last-updated-ms"],
+                    "metadata-file": format!(
+                        "{}/{}",
+                        base_uri,
+                        self.location_generator.gen_iceberg_metadata_location(*version)
+                    ),
+                }));
+                let table_uuid = metadata["table-uuid"]
+                    .as_str()
+                    .map(|s| s.to_string())
+                    .unwrap_or_else(|| Uuid::new_v4().to_string());
+                (version + 1, table_uuid, metadata_log)
+            }
+            None => (1, Uuid::new_v4().to_string(), vec![]),
+        };
+        if metadata_log.len() > MAX_METADATA_LOG_ENTRIES {
+            metadata_log.drain(..metadata_log.len() - MAX_METADATA_LOG_ENTRIES);
+        }
+
+        let mut iceberg_snapshot = json!({
+            "snapshot-id": snapshot_id,
+            "sequence-number": sequence_number,
+            "timestamp-ms": timestamp_ms,
+            "manifest-list": format!("{}/{}", base_uri, manifest_list_path),
+            "schema-id": 0,
+            "summary": {
+                "operation": "overwrite",
+                "total-records": snapshot.summary.row_count.to_string(),
+                "total-data-files": snapshot.summary.block_count.to_string(),
+                "total-delete-files": "0",
+                SUMMARY_KEY_FUSE_SNAPSHOT_ID: fuse_snapshot_id,
+                SUMMARY_KEY_FUSE_SNAPSHOT_TIMESTAMP: timestamp_ms.to_string(),
+            },
+        });
+        if let Some(parent) = parent_snapshot_id {
+            iceberg_snapshot["parent-snapshot-id"] = json!(parent);
+        }
+
+        let metadata = json!({
+            "format-version": 2,
+            "table-uuid": table_uuid,
+            "location": table_location,
+            "last-sequence-number": sequence_number,
+            "last-updated-ms": Utc::now().timestamp_millis(),
+            "last-column-id": fields.iter().map(|f| f.id).max().unwrap_or(0),
+            "current-schema-id": 0,
+            "schemas": [schema],
+            "default-spec-id": 0,
+            "partition-specs": [{ "spec-id": 0, "fields": [] }],
+            "last-partition-id": 999,
+            "default-sort-order-id": 0,
+            "sort-orders": [{ "order-id": 0, "fields": [] }],
+            "properties": {
+                "write.format.default": "parquet",
+                "schema.name-mapping.default": name_mapping_json(&fields).to_string(),
+            },
+            "current-snapshot-id": snapshot_id,
+            "refs": { "main": { "snapshot-id": snapshot_id, "type": "branch" } },
+            "snapshots": [iceberg_snapshot],
+            "snapshot-log": [{ "timestamp-ms": timestamp_ms, "snapshot-id": snapshot_id }],
+            "metadata-log": metadata_log,
+        });
+
+        let metadata_path = self
+            .location_generator
+            .gen_iceberg_metadata_location(version);
+        self.operator
+            .write(&metadata_path, serde_json::to_vec_pretty(&metadata)?)
+            .await?;
+        self.operator
+            .write(
+                &self.location_generator.gen_iceberg_version_hint_location(),
+                version.to_string(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn read_current_metadata(&self) -> Result<Option<(u64, serde_json::Value)>> {
+        let hint_path = self.location_generator.gen_iceberg_version_hint_location();
+        let hint = match self.operator.read(&hint_path).await {
+            Ok(hint) => hint,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let version = String::from_utf8_lossy(&hint)
+            .trim()
+            .parse::<u64>()
+            .map_err(|e| {
+                ErrorCode::Internal(format!("invalid iceberg version hint {}: {}", hint_path, e))
+            })?;
+        let metadata_path = self
+            .location_generator
+            .gen_iceberg_metadata_location(version);
+        let data = self.operator.read(&metadata_path).await?;
+        Ok(Some((version, serde_json::from_slice(&data)?)))
+    }
+}
+
+fn current_snapshot(metadata: &serde_json::Value) -> Option<&serde_json::Value> {
+    let current = metadata["current-snapshot-id"].as_i64()?;
+    metadata["snapshots"]
+        .as_array()?
+        .iter()
+        .find(|s| s["snapshot-id"].as_i64() == Some(current))
+}
+
+// Iceberg snapshot ids are positive longs, take the high bits of the fuse snapshot id.
+fn iceberg_snapshot_id(snapshot_id: &Uuid) -> i64 {
+    (snapshot_id.as_u128() >> 65) as i64
+}
+
+fn data_file(base_uri: &str, fields: &[IcebergField], block: &BlockMeta) -> Result<DataFile> {
+    if block.deletion_vector_location.is_some() {
+        return Err(ErrorCode::Unimplemented(format!(
+            "block {} has a deletion vector, which can not be exported as iceberg metadata",
+            block.location.0
+        )));
+    }
+
+    let mut data_file = DataFile {
+        file_path: format!("{}/{}", base_uri, block.location.0),
+        record_count: block.row_count as i64,
+        file_size_in_bytes: block.file_size as i64,
+        column_sizes: vec![],
+        value_counts: vec![],
+        null_value_counts: vec![],
+        lower_bounds: vec![],
+        upper_bounds: vec![],
+    };
+    for field in fields {
+        if let Some(ColumnMeta::Parquet(meta)) = block.col_metas.get(&field.column_id) {
+            data_file.column_sizes.push((field.id, meta.len as i64));
+            data_file
+                .value_counts
+                .push((field.id, meta.num_values as i64));
+        }
+        if let Some(stats) = block.col_stats.get(&field.column_id) {
+            data_file
+                .null_value_counts
+                .push((field.id, stats.null_count as i64));
+            let bounds = (
+                field.primitive.serialize_bound(&stats.min),
+                field.primitive.serialize_bound(&stats.max),
+            );
+            if let (Some(lower), Some(upper)) = bounds {
+                data_file.lower_bounds.push((field.id, lower));
+                data_file.upper_bounds.push((field.id, upper));
+            }
+        }
+    }
+    Ok(data_file)
+}
+
+/// The absolute uri of the storage root, data files are referenced by absolute paths.
+fn storage_uri(operator: &Operator) -> Result<String> {
+    let info = operator.info();
+    let scheme = match info.scheme() {
+        Scheme::Fs => "file",
+        Scheme::S3 => "s3",
+        Scheme::Gcs => "gs",
+        Scheme::Oss => "oss",
+        Scheme::Cos => "cosn",
+        Scheme::Obs => "obs",
+        other => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "iceberg metadata is not supported on storage {}",
+                other
+            )));
+        }
+    };
+    let bucket = match info.scheme() {
+        Scheme::Fs => "",
+        _ => info.name(),
+    };
+    let root = info.root().trim_matches('/');
+    let mut uri = format!("{}://{}", scheme, bucket);
+    if !root.is_empty() {
+        uri.push('/');
+        uri.push_str(root);
+    }
+    Ok(uri)
+}
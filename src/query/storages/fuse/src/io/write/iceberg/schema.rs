@@ -0,0 +1,237 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::types::decimal::DecimalScalar;
+use common_expression::types::DecimalDataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::ColumnId;
+use common_expression::ComputedExpr;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableSchema;
+use serde_json::json;
+
+/// A top level column of the fuse table, as it is exposed in the Iceberg schema.
+pub struct IcebergField {
+    /// Iceberg field ids are 1-based, they are derived from the column id of fuse.
+    pub id: i32,
+    pub column_id: ColumnId,
+    pub name: String,
+    pub required: bool,
+    pub primitive: IcebergPrimitive,
+}
+
+#[derive(Clone, Copy)]
+pub enum IcebergPrimitive {
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Decimal { precision: u8, scale: u8 },
+    Date,
+    Timestamp,
+    String,
+    Binary,
+}
+
+impl IcebergPrimitive {
+    fn from_table_data_type(data_type: &TableDataType) -> Option<Self> {
+        let primitive = match data_type {
+            TableDataType::Boolean => IcebergPrimitive::Boolean,
+            TableDataType::Number(num_ty) => match num_ty {
+                NumberDataType::UInt8
+                | NumberDataType::UInt16
+                | NumberDataType::Int8
+                | NumberDataType::Int16
+                | NumberDataType::Int32 => IcebergPrimitive::Int,
+                // Iceberg has no unsigned types, values of UInt64 above i64::MAX
+                // are seen as negative numbers by external engines.
+                NumberDataType::UInt32 | NumberDataType::UInt64 | NumberDataType::Int64 => {
+                    IcebergPrimitive::Long
+                }
+                NumberDataType::Float32 => IcebergPrimitive::Float,
+                NumberDataType::Float64 => IcebergPrimitive::Double,
+            },
+            TableDataType::Decimal(DecimalDataType::Decimal128(size))
+            | TableDataType::Decimal(DecimalDataType::Decimal256(size))
+                if size.precision <= 38 =>
+            {
+                IcebergPrimitive::Decimal {
+                    precision: size.precision,
+                    scale: size.scale,
+                }
+            }
+            TableDataType::Date => IcebergPrimitive::Date,
+            TableDataType::Timestamp => IcebergPrimitive::Timestamp,
+            TableDataType::String => IcebergPrimitive::String,
+            TableDataType::Variant => IcebergPrimitive::Binary,
+            _ => return None,
+        };
+        Some(primitive)
+    }
+
+    fn type_name(&self) -> String {
+        match self {
+            IcebergPrimitive::Boolean => "boolean".to_string(),
+            IcebergPrimitive::Int => "int".to_string(),
+            IcebergPrimitive::Long => "long".to_string(),
+            IcebergPrimitive::Float => "float".to_string(),
+            IcebergPrimitive::Double => "double".to_string(),
+            IcebergPrimitive::Decimal { precision, scale } => {
+                format!("decimal({}, {})", precision, scale)
+            }
+            IcebergPrimitive::Date => "date".to_string(),
+            IcebergPrimitive::Timestamp => "timestamp".to_string(),
+            IcebergPrimitive::String => "string".to_string(),
+            IcebergPrimitive::Binary => "binary".to_string(),
+        }
+    }
+
+    /// Serializes a statistics value with the single-value serialization of the Iceberg spec.
+    ///
+    /// Bounds of strings and binaries are not exported, fuse may truncate them.
+    pub fn serialize_bound(&self, value: &Scalar) -> Option<Vec<u8>> {
+        let bytes = match (self, value) {
+            (IcebergPrimitive::Boolean, Scalar::Boolean(v)) => vec![*v as u8],
+            (IcebergPrimitive::Int, Scalar::Number(num)) => {
+                let v: i32 = match num {
+                    NumberScalar::UInt8(v) => *v as i32,
+                    NumberScalar::UInt16(v) => *v as i32,
+                    NumberScalar::Int8(v) => *v as i32,
+                    NumberScalar::Int16(v) => *v as i32,
+                    NumberScalar::Int32(v) => *v,
+                    _ => return None,
+                };
+                v.to_le_bytes().to_vec()
+            }
+            (IcebergPrimitive::Long, Scalar::Number(num)) => {
+                let v: i64 = match num {
+                    NumberScalar::UInt32(v) => *v as i64,
+                    // the order of values above i64::MAX is not kept
+                    NumberScalar::UInt64(v) => i64::try_from(*v).ok()?,
+                    NumberScalar::Int64(v) => *v,
+                    _ => return None,
+                };
+                v.to_le_bytes().to_vec()
+            }
+            (IcebergPrimitive::Float, Scalar::Number(NumberScalar::Float32(v))) => {
+                v.0.to_le_bytes().to_vec()
+            }
+            (IcebergPrimitive::Double, Scalar::Number(NumberScalar::Float64(v))) => {
+                v.0.to_le_bytes().to_vec()
+            }
+            (IcebergPrimitive::Decimal { .. }, Scalar::Decimal(decimal)) => {
+                let unscaled = match decimal {
+                    DecimalScalar::Decimal128(v, _) => *v,
+                    DecimalScalar::Decimal256(v, _) => i128::try_from(*v).ok()?,
+                };
+                minimal_be_bytes(unscaled)
+            }
+            (IcebergPrimitive::Date, Scalar::Date(v)) => v.to_le_bytes().to_vec(),
+            (IcebergPrimitive::Timestamp, Scalar::Timestamp(v)) => v.to_le_bytes().to_vec(),
+            _ => return None,
+        };
+        Some(bytes)
+    }
+}
+
+// Decimals are stored as the minimum number of bytes of the two's-complement
+// big-endian representation of the unscaled value.
+fn minimal_be_bytes(v: i128) -> Vec<u8> {
+    let bytes = v.to_be_bytes();
+    let mut start = 0;
+    while start < bytes.len() - 1 {
+        let redundant = (bytes[start] == 0x00 && bytes[start + 1] & 0x80 == 0)
+            || (bytes[start] == 0xff && bytes[start + 1] & 0x80 != 0);
+        if !redundant {
+            break;
+        }
+        start += 1;
+    }
+    bytes[start..].to_vec()
+}
+
+/// Collects the columns of the schema that can be exposed to Iceberg.
+///
+/// Only top level columns of primitive types are exported. Nested columns are stored
+/// as several leaf columns by fuse, and virtual computed columns are not stored at all.
+pub fn iceberg_fields(schema: &TableSchema) -> Vec<IcebergField> {
+    schema
+        .fields()
+        .iter()
+        .filter(|f| !matches!(f.computed_expr(), Some(ComputedExpr::Virtual(_))))
+        .filter_map(|f| {
+            let (required, data_type) = match f.data_type() {
+                TableDataType::Nullable(inner) => (false, inner.as_ref()),
+                ty => (true, ty),
+            };
+            let primitive = IcebergPrimitive::from_table_data_type(data_type)?;
+            Some(IcebergField {
+                id: f.column_id() as i32 + 1,
+                column_id: f.column_id(),
+                name: f.name().clone(),
+                required,
+                primitive,
+            })
+        })
+        .collect()
+}
+
+pub fn schema_json(fields: &[IcebergField]) -> serde_json::Value {
+    let fields = fields
+        .iter()
+        .map(|f| {
+            json!({
+                "id": f.id,
+                "name": f.name,
+                "required": f.required,
+                "type": f.primitive.type_name(),
+            })
+        })
+        .collect::<Vec<_>>();
+    json!({
+        "type": "struct",
+        "schema-id": 0,
+        "fields": fields,
+    })
+}
+
+/// The parquet files written by fuse carry no field ids, external engines resolve
+/// the columns by name with this mapping.
+pub fn name_mapping_json(fields: &[IcebergField]) -> serde_json::Value {
+    let mapping = fields
+        .iter()
+        .map(|f| json!({ "field-id": f.id, "names": [f.name] }))
+        .collect::<Vec<_>>();
+    json!(mapping)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minimal_be_bytes() {
+        assert_eq!(minimal_be_bytes(0), vec![0x00]);
+        assert_eq!(minimal_be_bytes(1), vec![0x01]);
+        assert_eq!(minimal_be_bytes(127), vec![0x7f]);
+        assert_eq!(minimal_be_bytes(128), vec![0x00, 0x80]);
+        assert_eq!(minimal_be_bytes(-1), vec![0xff]);
+        assert_eq!(minimal_be_bytes(-128), vec![0x80]);
+        assert_eq!(minimal_be_bytes(-129), vec![0xff, 0x7f]);
+        assert_eq!(minimal_be_bytes(1234), vec![0x04, 0xd2]);
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod block_writer;
+mod iceberg;
 mod meta_writer;
 mod segment_writer;
 mod write_settings;
@@ -22,6 +23,9 @@ pub use block_writer::write_data;
 pub use block_writer::BlockBuilder;
 pub use block_writer::BlockSerialization;
 pub use block_writer::BloomIndexState;
+pub use block_writer::InvertedIndexState;
+pub use block_writer::NgramIndexState;
+pub use iceberg::IcebergMetadataWriter;
 pub use meta_writer::CachedMetaWriter;
 pub use meta_writer::MetaWriter;
 pub use segment_writer::SegmentWriter;
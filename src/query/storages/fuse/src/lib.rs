@@ -40,6 +40,7 @@ pub use common_catalog::table_context::TableContext;
 pub use constants::*;
 pub use fuse_lazy_part::FuseLazyPartInfo;
 pub use fuse_part::FusePartInfo;
+pub use fuse_table::FuseDeleteMode;
 pub use fuse_table::FuseStorageFormat;
 pub use fuse_table::FuseTable;
 pub use io::MergeIOReadResult;
@@ -16,20 +16,46 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
+use common_catalog::plan::Projection;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::ColumnId;
+use common_expression::ComputedExpr;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
 use log::warn;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::ColumnHistogram;
+use storages_common_table_meta::meta::ColumnHistogramBucket;
+use storages_common_table_meta::meta::InvertedIndexStatistics;
+use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
 
+use crate::io::DeletionVector;
+use crate::io::InvertedIndexReader;
+use crate::io::ReadSettings;
 use crate::io::SegmentsIO;
 use crate::statistics::reduce_block_statistics;
 use crate::statistics::reduce_cluster_statistics;
 use crate::FuseTable;
 
+/// Max number of blocks sampled to build the histograms.
+const HISTOGRAM_SAMPLE_BLOCKS: usize = 64;
+/// Max number of values of a column kept in the sample, which bounds the memory used.
+const HISTOGRAM_SAMPLE_VALUES: usize = 30_000;
+/// Number of buckets of an equi-height histogram.
+const HISTOGRAM_BUCKETS: usize = 100;
+/// Max number of the most common values kept for a column.
+const HISTOGRAM_MOST_COMMON_VALUES: usize = 16;
+
 impl FuseTable {
     #[async_backtrace::framed]
     pub async fn do_analyze(&self, ctx: &Arc<dyn TableContext>) -> Result<()> {
@@ -60,6 +86,18 @@ impl FuseTable {
             let mut col_stats = HashMap::new();
             let mut cluster_stats = None;
 
+            // Blocks are sampled evenly across the table to build the histograms.
+            let sample_step = std::cmp::max(
+                1,
+                snapshot.summary.block_count as usize / HISTOGRAM_SAMPLE_BLOCKS,
+            );
+            let mut sampled_blocks = Vec::new();
+            let mut block_index = 0;
+
+            // The inverted indexes of all the blocks are read to build the BM25 statistics.
+            let has_inverted_index = !self.table_info.meta.indexes.is_empty();
+            let mut inverted_indexes = Vec::new();
+
             let start = Instant::now();
             let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
             let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
@@ -80,6 +118,20 @@ impl FuseTable {
                     stats_of_columns.push(segment.summary.col_stats.clone());
                     blocks_cluster_stats.push(segment.summary.cluster_stats.clone());
                     segment.blocks.iter().for_each(|block| {
+                        if block.row_count != 0 {
+                            if block_index % sample_step == 0
+                                && sampled_blocks.len() < HISTOGRAM_SAMPLE_BLOCKS
+                            {
+                                sampled_blocks.push(block.clone());
+                            }
+                            block_index += 1;
+                        }
+                        if let Some(location) = &block.inverted_index_location {
+                            if has_inverted_index {
+                                inverted_indexes
+                                    .push((location.clone(), block.inverted_index_size));
+                            }
+                        }
                         let block = block.as_ref();
                         let row_count = block.row_count;
                         if row_count != 0 {
@@ -127,8 +179,19 @@ impl FuseTable {
                 ndv_map.insert(*i, (density_avg * row_count_sum as f64) as u64);
             }
 
-            // 3. Generate new table statistics
-            let table_statistics = TableSnapshotStatistics::new(ndv_map);
+            // 3. Build histograms from the sampled blocks
+            let histograms = self
+                .build_histograms(ctx, &sampled_blocks, row_count_sum)
+                .await?;
+
+            // 4. Build the BM25 statistics from the inverted indexes
+            let inverted_index_statistics = self
+                .build_inverted_index_statistics(ctx, &inverted_indexes)
+                .await?;
+
+            // 5. Generate new table statistics
+            let table_statistics =
+                TableSnapshotStatistics::new(ndv_map, histograms, inverted_index_statistics);
             let table_statistics_location = self
                 .meta_location_generator
                 .snapshot_statistics_location_from_uuid(
@@ -136,7 +199,7 @@ impl FuseTable {
                     table_statistics.format_version(),
                 )?;
 
-            // 4. Save table statistics
+            // 6. Save table statistics
             let mut new_snapshot = TableSnapshot::from_previous(&snapshot);
             new_snapshot.summary.col_stats = col_stats;
             new_snapshot.summary.cluster_stats = cluster_stats;
@@ -155,4 +218,227 @@ impl FuseTable {
 
         Ok(())
     }
+
+    /// Build the histograms from a reservoir sample of the values of `blocks`,
+    /// with the counts scaled to the `table_rows` rows of the table.
+    #[async_backtrace::framed]
+    async fn build_histograms(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        blocks: &[Arc<BlockMeta>],
+        table_rows: u64,
+    ) -> Result<HashMap<ColumnId, ColumnHistogram>> {
+        let schema = self.schema();
+        let (field_indices, column_ids): (Vec<_>, Vec<_>) = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| {
+                !matches!(f.computed_expr(), Some(ComputedExpr::Virtual(_)))
+                    && is_histogram_supported(&DataType::from(f.data_type()).remove_nullable())
+            })
+            .map(|(i, f)| (i, f.column_id()))
+            .unzip();
+        if field_indices.is_empty() || blocks.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let block_reader =
+            self.create_block_reader(Projection::Columns(field_indices), false, ctx.clone())?;
+        let settings = ReadSettings::from_ctx(ctx)?;
+        let mut rng = StdRng::from_entropy();
+        let mut samples: Vec<Reservoir> = vec![Reservoir::default(); column_ids.len()];
+        let sampled_rows: u64 = blocks.iter().map(|b| b.row_count).sum();
+        for (i, block) in blocks.iter().enumerate() {
+            let mut data_block = block_reader
+                .read_by_meta(&settings, block.as_ref(), &self.storage_format)
+                .await?;
+            if let Some(dv) = DeletionVector::from_block_meta(block) {
+                let live_rows = dv
+                    .read_live_rows(&block_reader.operator, block.row_count as usize)
+                    .await?;
+                data_block = data_block.filter_with_bitmap(&live_rows)?;
+            }
+            for (entry, sample) in data_block.columns().iter().zip(samples.iter_mut()) {
+                for row in 0..data_block.num_rows() {
+                    match entry.value.index(row) {
+                        Some(ScalarRef::Null) | None => {}
+                        Some(v) => sample.add(v, &mut rng),
+                    }
+                }
+            }
+            ctx.set_status_info(&format!(
+                "analyze: sampled blocks:{}/{} to build histograms",
+                i + 1,
+                blocks.len()
+            ));
+        }
+
+        // The sampled blocks stand for the whole table.
+        let table_scale = table_rows as f64 / std::cmp::max(1, sampled_rows) as f64;
+        Ok(column_ids
+            .into_iter()
+            .zip(samples)
+            .filter(|(_, sample)| !sample.values.is_empty())
+            .map(|(id, sample)| {
+                let num_rows = (sample.seen as f64 * table_scale).round() as u64;
+                (id, build_histogram(sample.values, num_rows))
+            })
+            .collect())
+    }
+
+    /// Merge the statistics of the inverted indexes of the blocks, so that the BM25 scores
+    /// of the rows in different blocks are comparable.
+    #[async_backtrace::framed]
+    async fn build_inverted_index_statistics(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        indexes: &[(Location, u64)],
+    ) -> Result<HashMap<String, InvertedIndexStatistics>> {
+        let func_ctx = ctx.get_function_context()?;
+        let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
+        let mut statistics: HashMap<String, InvertedIndexStatistics> = HashMap::new();
+        let mut read_index_count = 0;
+        for chunk in indexes.chunks(chunk_size) {
+            let futures = chunk.iter().map(|(location, size)| {
+                location.read_inverted_index(self.operator.clone(), func_ctx.clone(), *size)
+            });
+            for index in futures::future::try_join_all(futures).await? {
+                for (name, stats) in index.statistics() {
+                    statistics.entry(name).or_default().merge(&stats);
+                }
+            }
+
+            read_index_count += chunk.len();
+            ctx.set_status_info(&format!(
+                "analyze: read inverted index files:{}/{}",
+                read_index_count,
+                indexes.len()
+            ));
+        }
+        Ok(statistics)
+    }
+}
+
+/// A uniform sample of at most [`HISTOGRAM_SAMPLE_VALUES`] values, with reservoir sampling.
+#[derive(Clone, Default)]
+struct Reservoir {
+    values: Vec<Scalar>,
+    /// number of values offered to the sample
+    seen: u64,
+}
+
+impl Reservoir {
+    fn add(&mut self, value: ScalarRef, rng: &mut StdRng) {
+        self.seen += 1;
+        if self.values.len() < HISTOGRAM_SAMPLE_VALUES {
+            self.values.push(value.to_owned());
+        } else {
+            let i = rng.gen_range(0..self.seen) as usize;
+            if i < HISTOGRAM_SAMPLE_VALUES {
+                self.values[i] = value.to_owned();
+            }
+        }
+    }
+}
+
+fn is_histogram_supported(data_type: &DataType) -> bool {
+    data_type.is_numeric() || matches!(data_type, DataType::Date | DataType::String)
+}
+
+/// Build an equi-height histogram with the most common values from the sampled values,
+/// with the counts scaled to the `num_rows` non-null values the sample stands for.
+fn build_histogram(mut values: Vec<Scalar>, num_rows: u64) -> ColumnHistogram {
+    values.sort();
+    let sampled_rows = values.len() as u64;
+    let scale = num_rows as f64 / sampled_rows as f64;
+
+    // Distinct values and their number of occurrences, in order.
+    let mut distinct: Vec<(Scalar, u64)> = Vec::new();
+    for value in values {
+        match distinct.last_mut() {
+            Some((last, count)) if *last == value => *count += 1,
+            _ => distinct.push((value, 1)),
+        }
+    }
+
+    // A value is common if it occurs more often than the average.
+    let avg_count = sampled_rows as f64 / distinct.len() as f64;
+    let mut most_common_values = distinct
+        .iter()
+        .filter(|(_, count)| *count as f64 > avg_count && *count > 1)
+        .cloned()
+        .collect::<Vec<_>>();
+    most_common_values.sort_by(|a, b| b.1.cmp(&a.1));
+    most_common_values.truncate(HISTOGRAM_MOST_COMMON_VALUES);
+    for (_, count) in most_common_values.iter_mut() {
+        *count = (*count as f64 * scale).round() as u64;
+    }
+
+    // Every bucket holds about the same number of values, and a value
+    // never spans two buckets.
+    let bucket_height = std::cmp::max(1, sampled_rows / HISTOGRAM_BUCKETS as u64);
+    let mut buckets: Vec<ColumnHistogramBucket> = Vec::with_capacity(HISTOGRAM_BUCKETS);
+    let mut current: Option<SampleBucket> = None;
+    for (value, count) in distinct {
+        let bucket = current.get_or_insert_with(|| SampleBucket {
+            lower_bound: value.clone(),
+            upper_bound: value.clone(),
+            num_values: 0,
+            num_distinct: 0,
+            num_singletons: 0,
+        });
+        bucket.upper_bound = value;
+        bucket.num_values += count;
+        bucket.num_distinct += 1;
+        if count == 1 {
+            bucket.num_singletons += 1;
+        }
+        if bucket.num_values >= bucket_height {
+            buckets.extend(current.take().map(|b| b.scale(scale)));
+        }
+    }
+    buckets.extend(current.map(|b| b.scale(scale)));
+
+    ColumnHistogram {
+        num_rows,
+        buckets,
+        most_common_values,
+    }
+}
+
+/// A histogram bucket with the counts of the sampled values.
+struct SampleBucket {
+    lower_bound: Scalar,
+    upper_bound: Scalar,
+    num_values: u64,
+    num_distinct: u64,
+    /// number of values occurring exactly once in the sample
+    num_singletons: u64,
+}
+
+impl SampleBucket {
+    /// Scale the counts to the whole table.
+    ///
+    /// The number of distinct values is estimated with the Duj1 estimator of Haas and Stokes:
+    /// `n * d / (n - f1 + f1 * n / N)`, where `n` is the number of sampled values, `N` the
+    /// number of values they stand for, `d` the distinct values and `f1` the singletons of the sample.
+    fn scale(self, scale: f64) -> ColumnHistogramBucket {
+        let n = self.num_values as f64;
+        let d = self.num_distinct as f64;
+        let f1 = self.num_singletons as f64;
+        let total = n * scale;
+        let num_distinct = if scale <= 1.0 {
+            d
+        } else {
+            (n * d / (n - f1 + f1 / scale)).clamp(d, total)
+        };
+
+        ColumnHistogramBucket {
+            lower_bound: self.lower_bound,
+            upper_bound: self.upper_bound,
+            num_values: total.round() as u64,
+            num_distinct: num_distinct.round() as u64,
+        }
+    }
 }
@@ -27,14 +27,17 @@ use common_functions::BUILTIN_FUNCTIONS;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::Pipeline;
 use common_pipeline_transforms::processors::transforms::transform_block_compact_for_copy::BlockCompactorForCopy;
+use common_pipeline_transforms::processors::transforms::AccumulatingTransformer;
 use common_pipeline_transforms::processors::transforms::BlockCompactor;
 use common_pipeline_transforms::processors::transforms::TransformCompact;
 use common_pipeline_transforms::processors::transforms::TransformSortPartial;
 use common_sql::evaluator::BlockOperator;
 use common_sql::evaluator::CompoundBlockOperator;
 
+use crate::operations::common::TransformPartitionSplit;
 use crate::operations::common::TransformSerializeBlock;
 use crate::statistics::ClusterStatsGenerator;
+use crate::statistics::PartitionGenerator;
 use crate::FuseTable;
 
 impl FuseTable {
@@ -91,6 +94,18 @@ impl FuseTable {
         pipeline: &mut Pipeline,
         block_thresholds: BlockThresholds,
     ) -> Result<ClusterStatsGenerator> {
+        // A block must not span partitions, split them before sorting by the cluster key.
+        let partition_gen = self.get_partition_gen(ctx.clone())?;
+        if partition_gen.is_partitioned() {
+            pipeline.add_transform(|input, output| {
+                Ok(ProcessorPtr::create(AccumulatingTransformer::create(
+                    input,
+                    output,
+                    TransformPartitionSplit::new(partition_gen.clone()),
+                )))
+            })?;
+        }
+
         let cluster_stats_gen = self.get_cluster_stats_gen(ctx.clone(), 0, block_thresholds)?;
 
         let operators = cluster_stats_gen.operators.clone();
@@ -190,6 +205,25 @@ impl FuseTable {
         ))
     }
 
+    pub fn get_partition_gen(&self, ctx: Arc<dyn TableContext>) -> Result<PartitionGenerator> {
+        let partition_keys = self.partition_keys(ctx.clone())?;
+        if partition_keys.is_empty() {
+            return Ok(PartitionGenerator::default());
+        }
+
+        // the blocks being written do not carry the virtual computed columns.
+        let input_schema = self.table_info.schema().remove_virtual_computed_fields();
+        let exprs = partition_keys
+            .iter()
+            .map(|remote_expr| {
+                remote_expr
+                    .as_expr(&BUILTIN_FUNCTIONS)
+                    .project_column_ref(|name| input_schema.index_of(name).unwrap())
+            })
+            .collect();
+        Ok(PartitionGenerator::new(exprs, ctx.get_function_context()?))
+    }
+
     pub fn get_option<T: FromStr>(&self, opt_key: &str, default: T) -> T {
         self.table_info
             .options()
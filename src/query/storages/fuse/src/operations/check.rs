@@ -0,0 +1,631 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::geometry::GeometryDomain;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRefExt;
+use common_storage::parquet_rs::read_metadata_async;
+use futures::stream;
+use futures::StreamExt;
+use futures_util::TryStreamExt;
+use opendal::EntryMode;
+use opendal::ErrorKind;
+use opendal::Metakey;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::ColumnMeta;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::meta::Statistics;
+use storages_common_table_meta::meta::TableSnapshot;
+
+use crate::io::MetaReaders;
+use crate::io::SegmentWriter;
+use crate::io::SegmentsIO;
+use crate::io::SnapshotHistoryReader;
+use crate::operations::common::AbortOperation;
+use crate::operations::gc::LocationTuple;
+use crate::statistics::reducers::merge_statistics_mut;
+use crate::statistics::reducers::reduce_block_metas;
+use crate::FuseStorageFormat;
+use crate::FuseTable;
+use crate::FUSE_TBL_BLOCK_INDEX_PREFIX;
+use crate::FUSE_TBL_BLOCK_PREFIX;
+use crate::FUSE_TBL_DELETION_VECTOR_PREFIX;
+use crate::FUSE_TBL_INVERTED_INDEX_PREFIX;
+use crate::FUSE_TBL_NGRAM_INDEX_PREFIX;
+use crate::FUSE_TBL_SEGMENT_PREFIX;
+use crate::FUSE_TBL_SNAPSHOT_PREFIX;
+use crate::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
+use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
+
+/// The kinds of problems reported by [FuseTable::check].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CheckIssueKind {
+    /// An object referenced by the current snapshot does not exist.
+    Missing,
+    /// The size of an object differs from the size recorded in the meta.
+    SizeMismatch,
+    /// The row count of a block differs from the row count recorded in its [BlockMeta].
+    RowCountMismatch,
+    /// The column statistics of a block are not consistent with the block.
+    InvalidStatistics,
+    /// The summary of a segment or a snapshot does not match the blocks it covers.
+    SummaryMismatch,
+    /// An object exists but can not be read or decoded.
+    Corrupted,
+    /// An object under the table prefix which is not referenced by any snapshot.
+    Orphaned,
+}
+
+impl Display for CheckIssueKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CheckIssueKind::Missing => write!(f, "missing"),
+            CheckIssueKind::SizeMismatch => write!(f, "size_mismatch"),
+            CheckIssueKind::RowCountMismatch => write!(f, "row_count_mismatch"),
+            CheckIssueKind::InvalidStatistics => write!(f, "invalid_statistics"),
+            CheckIssueKind::SummaryMismatch => write!(f, "summary_mismatch"),
+            CheckIssueKind::Corrupted => write!(f, "corrupted"),
+            CheckIssueKind::Orphaned => write!(f, "orphaned"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CheckIssue {
+    pub kind: CheckIssueKind,
+    pub location: String,
+    pub detail: String,
+}
+
+impl CheckIssue {
+    fn new(kind: CheckIssueKind, location: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            kind,
+            location: location.into(),
+            detail: detail.into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CheckReport {
+    /// location of the snapshot being checked, None if the table has no snapshot.
+    pub snapshot_location: Option<String>,
+    pub segment_count: u64,
+    pub block_count: u64,
+    pub issues: Vec<CheckIssue>,
+    /// number of blocks excluded by the repair, None if the table is not repaired.
+    pub excluded_block_count: Option<u64>,
+    /// location of the snapshot committed by the repair.
+    pub repaired_snapshot_location: Option<String>,
+}
+
+impl CheckReport {
+    /// Whether the current snapshot is readable, orphaned objects do not count.
+    pub fn is_healthy(&self) -> bool {
+        self.issues
+            .iter()
+            .all(|issue| issue.kind == CheckIssueKind::Orphaned)
+    }
+
+    pub fn to_data_block(&self) -> DataBlock {
+        let mut kinds = Vec::with_capacity(self.issues.len() + 1);
+        let mut locations = Vec::with_capacity(self.issues.len() + 1);
+        let mut details = Vec::with_capacity(self.issues.len() + 1);
+        for issue in &self.issues {
+            kinds.push(issue.kind.to_string().into_bytes());
+            locations.push(issue.location.clone().into_bytes());
+            details.push(issue.detail.clone().into_bytes());
+        }
+        if let Some(excluded) = self.excluded_block_count {
+            kinds.push(b"repaired".to_vec());
+            locations.push(
+                self.repaired_snapshot_location
+                    .clone()
+                    .unwrap_or_default()
+                    .into_bytes(),
+            );
+            details
+                .push(format!("{} blocks excluded from the new snapshot", excluded).into_bytes());
+        }
+        DataBlock::new_from_columns(vec![
+            StringType::from_data(kinds),
+            StringType::from_data(locations),
+            StringType::from_data(details),
+        ])
+    }
+
+    pub fn schema() -> Arc<TableSchema> {
+        TableSchemaRefExt::create(vec![
+            TableField::new("kind", TableDataType::String),
+            TableField::new("location", TableDataType::String),
+            TableField::new("detail", TableDataType::String),
+        ])
+    }
+}
+
+enum SegmentState {
+    /// summary of a segment whose blocks are all readable
+    Healthy(Statistics),
+    /// the readable blocks of a segment, empty if the segment itself is lost
+    Damaged(Vec<Arc<BlockMeta>>),
+}
+
+impl FuseTable {
+    /// Check the consistency of the table.
+    ///
+    /// The current snapshot is walked down to its segments, blocks and index files: existence,
+    /// sizes, row counts and column statistics are verified against the meta. Objects under the
+    /// table prefix that are not referenced by any snapshot in the history are reported as
+    /// orphaned, note that objects written by in-flight transactions are reported as well.
+    ///
+    /// If `repair` is true and the current snapshot references lost or corrupted blocks, a new
+    /// snapshot which excludes them is committed. The data of those blocks is lost.
+    #[async_backtrace::framed]
+    pub async fn check(&self, ctx: Arc<dyn TableContext>, repair: bool) -> Result<CheckReport> {
+        let mut report = CheckReport::default();
+        let mut referenced = HashSet::new();
+
+        let snapshot = self.read_table_snapshot().await?;
+        let mut segment_states = vec![];
+        if let Some(snapshot) = &snapshot {
+            let snapshot_location = self.snapshot_loc().await?.ok_or_else(|| {
+                ErrorCode::Internal("snapshot of the table is read without location")
+            })?;
+            report.snapshot_location = Some(snapshot_location.clone());
+            segment_states = self.check_snapshot(&ctx, snapshot, &mut report).await?;
+            self.collect_referenced_locations(&ctx, snapshot_location, &mut referenced)
+                .await?;
+        }
+        self.check_orphans(&referenced, &mut report).await?;
+
+        if let (true, Some(snapshot)) = (repair, snapshot) {
+            if let Some(excluded) = self
+                .exclude_damaged_blocks(&ctx, snapshot, segment_states)
+                .await?
+            {
+                let table = self.refresh(ctx.as_ref()).await?;
+                let table = FuseTable::try_from_table(table.as_ref())?;
+                report.excluded_block_count = Some(excluded);
+                report.repaired_snapshot_location = table.snapshot_loc().await?;
+            }
+        }
+        Ok(report)
+    }
+
+    async fn check_snapshot(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        snapshot: &TableSnapshot,
+        report: &mut CheckReport,
+    ) -> Result<Vec<SegmentState>> {
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
+        let thresholds = self.get_block_thresholds();
+        let default_cluster_key_id = self.cluster_key_id();
+
+        let mut states = Vec::with_capacity(snapshot.segments.len());
+        let mut summary = Statistics::default();
+        let mut all_segments_read = true;
+        for chunk in snapshot.segments.chunks(max_threads.max(1) * 4) {
+            let segments = segments_io
+                .read_segments::<Arc<SegmentInfo>>(chunk, false)
+                .await?;
+            for ((location, _), segment) in chunk.iter().zip(segments) {
+                let segment = match segment {
+                    Ok(segment) => segment,
+                    Err(e) => {
+                        let kind = if e.code() == ErrorCode::STORAGE_NOT_FOUND {
+                            CheckIssueKind::Missing
+                        } else {
+                            CheckIssueKind::Corrupted
+                        };
+                        report
+                            .issues
+                            .push(CheckIssue::new(kind, location, e.message()));
+                        states.push(SegmentState::Damaged(vec![]));
+                        all_segments_read = false;
+                        continue;
+                    }
+                };
+                report.segment_count += 1;
+                report.block_count += segment.blocks.len() as u64;
+
+                let computed =
+                    reduce_block_metas(&segment.blocks, thresholds, default_cluster_key_id);
+                if let Some(diff) = diff_summary(&segment.summary, &computed) {
+                    report.issues.push(CheckIssue::new(
+                        CheckIssueKind::SummaryMismatch,
+                        location,
+                        diff,
+                    ));
+                }
+                merge_statistics_mut(&mut summary, &segment.summary, default_cluster_key_id);
+
+                let results =
+                    stream::iter(segment.blocks.iter().map(|block| self.check_block(block)))
+                        .buffered(max_threads.max(1))
+                        .collect::<Vec<_>>()
+                        .await;
+                let mut healthy_blocks = Vec::with_capacity(segment.blocks.len());
+                for (block, (issues, damaged)) in segment.blocks.iter().zip(results) {
+                    report.issues.extend(issues);
+                    if !damaged {
+                        healthy_blocks.push(block.clone());
+                    }
+                }
+                if healthy_blocks.len() == segment.blocks.len() {
+                    states.push(SegmentState::Healthy(segment.summary.clone()));
+                } else {
+                    states.push(SegmentState::Damaged(healthy_blocks));
+                }
+            }
+        }
+
+        if all_segments_read {
+            if let Some(diff) = diff_summary(&snapshot.summary, &summary) {
+                report.issues.push(CheckIssue::new(
+                    CheckIssueKind::SummaryMismatch,
+                    report.snapshot_location.clone().unwrap_or_default(),
+                    diff,
+                ));
+            }
+        }
+        Ok(states)
+    }
+
+    /// Returns the issues of the block, and whether the block can not be read anymore.
+    async fn check_block(&self, block: &BlockMeta) -> (Vec<CheckIssue>, bool) {
+        let mut issues = vec![];
+        let location = &block.location.0;
+        let damaged = match self.check_object(location, block.file_size).await {
+            Some(issue) => {
+                issues.push(issue);
+                true
+            }
+            None => match self.read_block_row_count(block).await {
+                Ok(row_count) if row_count != block.row_count => {
+                    issues.push(CheckIssue::new(
+                        CheckIssueKind::RowCountMismatch,
+                        location,
+                        format!(
+                            "recorded {} rows, found {} rows",
+                            block.row_count, row_count
+                        ),
+                    ));
+                    true
+                }
+                Ok(_) => false,
+                Err(e) => {
+                    issues.push(CheckIssue::new(
+                        CheckIssueKind::Corrupted,
+                        location,
+                        e.message(),
+                    ));
+                    true
+                }
+            },
+        };
+        check_column_statistics(block, &mut issues);
+
+        // a block without its index files can still be read, the pruning just ignores them
+        let indexes = [
+            (
+                &block.bloom_filter_index_location,
+                block.bloom_filter_index_size,
+            ),
+            (&block.ngram_index_location, block.ngram_index_size),
+            (&block.inverted_index_location, block.inverted_index_size),
+        ];
+        for (index_location, size) in indexes {
+            if let Some((index_location, _)) = index_location {
+                issues.extend(self.check_object(index_location, size).await);
+            }
+        }
+
+        // while the deleted rows can not be told apart without the deletion vector
+        let mut dv_lost = false;
+        if let Some((dv_location, _)) = &block.deletion_vector_location {
+            if let Some(issue) = self
+                .check_object(dv_location, block.deletion_vector_size)
+                .await
+            {
+                issues.push(issue);
+                dv_lost = true;
+            }
+        }
+        (issues, damaged || dv_lost)
+    }
+
+    /// Check the existence of an object, and its size if the size is recorded.
+    async fn check_object(&self, location: &str, size: u64) -> Option<CheckIssue> {
+        match self.operator.stat(location).await {
+            Ok(meta) if size != 0 && meta.content_length() != size => Some(CheckIssue::new(
+                CheckIssueKind::SizeMismatch,
+                location,
+                format!(
+                    "recorded {} bytes, found {} bytes",
+                    size,
+                    meta.content_length()
+                ),
+            )),
+            Ok(_) => None,
+            Err(e) if e.kind() == ErrorKind::NotFound => Some(CheckIssue::new(
+                CheckIssueKind::Missing,
+                location,
+                "object not found",
+            )),
+            Err(e) => Some(CheckIssue::new(
+                CheckIssueKind::Corrupted,
+                location,
+                e.to_string(),
+            )),
+        }
+    }
+
+    async fn read_block_row_count(&self, block: &BlockMeta) -> Result<u64> {
+        for (column_id, column_meta) in &block.col_metas {
+            let (offset, len) = column_meta.offset_length();
+            if offset + len > block.file_size {
+                return Err(ErrorCode::StorageOther(format!(
+                    "column {} at [{}, {}) is out of the block of {} bytes",
+                    column_id,
+                    offset,
+                    offset + len,
+                    block.file_size
+                )));
+            }
+        }
+
+        match self.storage_format {
+            FuseStorageFormat::Parquet => {
+                let meta =
+                    read_metadata_async(&block.location.0, &self.operator, Some(block.file_size))
+                        .await?;
+                Ok(meta.file_metadata().num_rows() as u64)
+            }
+            // pages of the native format are row based, every column holds all the rows
+            FuseStorageFormat::Native => {
+                for (column_id, column_meta) in &block.col_metas {
+                    if let ColumnMeta::Native(_) = column_meta {
+                        let rows = column_meta.total_rows() as u64;
+                        if rows != block.row_count {
+                            return Err(ErrorCode::StorageOther(format!(
+                                "column {} holds {} rows, but the block holds {} rows",
+                                column_id, rows, block.row_count
+                            )));
+                        }
+                    }
+                }
+                Ok(block.row_count)
+            }
+        }
+    }
+
+    /// Collect the locations of all the objects referenced by the snapshots in the history.
+    async fn collect_referenced_locations(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        snapshot_location: String,
+        referenced: &mut HashSet<String>,
+    ) -> Result<()> {
+        let version = self
+            .snapshot_format_version(Some(snapshot_location.clone()))
+            .await?;
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let mut snapshots = reader.snapshot_history(
+            snapshot_location,
+            version,
+            self.meta_location_generator().clone(),
+        );
+
+        let mut segments = HashSet::new();
+        while let Some((snapshot, version)) = snapshots.try_next().await? {
+            referenced.insert(
+                self.meta_location_generator()
+                    .snapshot_location_from_uuid(&snapshot.snapshot_id, version)?,
+            );
+            if let Some(location) = &snapshot.table_statistics_location {
+                referenced.insert(location.clone());
+            }
+            segments.extend(snapshot.segments.iter().cloned());
+        }
+
+        let segments = segments.into_iter().collect::<Vec<_>>();
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
+        let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
+        for chunk in segments.chunks(chunk_size.max(1)) {
+            let results = segments_io
+                .read_segments::<LocationTuple>(chunk, false)
+                .await?;
+            // the unreadable segments of the current snapshot are reported by `check_snapshot`,
+            // those of the history are not the concern of the current snapshot.
+            for locations in results.into_iter().flatten() {
+                referenced.extend(locations.block_location);
+                referenced.extend(locations.bloom_location);
+            }
+        }
+        referenced.extend(segments.into_iter().map(|(location, _)| location));
+        Ok(())
+    }
+
+    async fn check_orphans(
+        &self,
+        referenced: &HashSet<String>,
+        report: &mut CheckReport,
+    ) -> Result<()> {
+        let prefixes = [
+            FUSE_TBL_SNAPSHOT_PREFIX,
+            FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX,
+            FUSE_TBL_SEGMENT_PREFIX,
+            FUSE_TBL_BLOCK_PREFIX,
+            FUSE_TBL_BLOCK_INDEX_PREFIX,
+            FUSE_TBL_XOR_BLOOM_INDEX_PREFIX,
+            FUSE_TBL_NGRAM_INDEX_PREFIX,
+            FUSE_TBL_INVERTED_INDEX_PREFIX,
+            FUSE_TBL_DELETION_VECTOR_PREFIX,
+        ];
+        for prefix in prefixes {
+            let dir = format!("{}/{}/", self.meta_location_generator().prefix(), prefix);
+            let mut entries = self.operator.list_with(&dir).delimiter("").await?;
+            while let Some(entry) = entries.try_next().await? {
+                let meta = self.operator.metadata(&entry, Metakey::Mode).await?;
+                if meta.mode() == EntryMode::FILE && !referenced.contains(entry.path()) {
+                    report.issues.push(CheckIssue::new(
+                        CheckIssueKind::Orphaned,
+                        entry.path(),
+                        "not referenced by any snapshot",
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Commit a snapshot without the damaged blocks, returns the number of blocks excluded,
+    /// or None if there is nothing to exclude.
+    async fn exclude_damaged_blocks(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        snapshot: Arc<TableSnapshot>,
+        segment_states: Vec<SegmentState>,
+    ) -> Result<Option<u64>> {
+        let thresholds = self.get_block_thresholds();
+        let default_cluster_key_id = self.cluster_key_id();
+        let segment_writer = SegmentWriter::new(&self.operator, &self.meta_location_generator);
+
+        let mut segments: Vec<Location> = Vec::with_capacity(snapshot.segments.len());
+        let mut summary = Statistics::default();
+        let mut abort_operation = AbortOperation::default();
+        let mut damaged = false;
+        for (location, state) in snapshot.segments.iter().zip(segment_states) {
+            match state {
+                SegmentState::Healthy(segment_summary) => {
+                    merge_statistics_mut(&mut summary, &segment_summary, default_cluster_key_id);
+                    segments.push(location.clone());
+                }
+                SegmentState::Damaged(blocks) => {
+                    damaged = true;
+                    if blocks.is_empty() {
+                        continue;
+                    }
+                    let segment_summary =
+                        reduce_block_metas(&blocks, thresholds, default_cluster_key_id);
+                    merge_statistics_mut(&mut summary, &segment_summary, default_cluster_key_id);
+                    let new_location = segment_writer
+                        .write_segment(SegmentInfo::new(blocks, segment_summary))
+                        .await?;
+                    abort_operation.add_segment(new_location.0.clone());
+                    segments.push(new_location);
+                }
+            }
+        }
+        if !damaged {
+            return Ok(None);
+        }
+
+        let excluded = snapshot
+            .summary
+            .block_count
+            .saturating_sub(summary.block_count);
+        self.commit_mutation(ctx, snapshot, &segments, summary, abort_operation, None)
+            .await?;
+        Ok(Some(excluded))
+    }
+}
+
+fn check_column_statistics(block: &BlockMeta, issues: &mut Vec<CheckIssue>) {
+    let location = &block.location.0;
+    for (column_id, stats) in &block.col_stats {
+        if stats.null_count > block.row_count {
+            issues.push(CheckIssue::new(
+                CheckIssueKind::InvalidStatistics,
+                location,
+                format!(
+                    "column {}: null count {} exceeds the row count {}",
+                    column_id, stats.null_count, block.row_count
+                ),
+            ));
+        }
+        // The distinct count is an estimation that may exceed the row count, it's not checked.
+        let min_greater_than_max = match (&stats.min, &stats.max) {
+            (Scalar::Null, _) | (_, Scalar::Null) => false,
+            // The statistics of geometry are the corners of the bounding box.
+            (Scalar::Geometry(min), Scalar::Geometry(max)) => {
+                GeometryDomain::from_statistics(min, max).map_or(true, |bbox| {
+                    bbox.min_x > bbox.max_x || bbox.min_y > bbox.max_y
+                })
+            }
+            (min, max) => min.partial_cmp(max) == Some(Ordering::Greater),
+        };
+        if min_greater_than_max {
+            issues.push(CheckIssue::new(
+                CheckIssueKind::InvalidStatistics,
+                location,
+                format!(
+                    "column {}: min value {} is greater than max value {}",
+                    column_id, stats.min, stats.max
+                ),
+            ));
+        }
+    }
+}
+
+/// Compare the recorded summary with the one computed from the underlying meta.
+fn diff_summary(recorded: &Statistics, computed: &Statistics) -> Option<String> {
+    let fields = [
+        ("row_count", recorded.row_count, computed.row_count),
+        ("block_count", recorded.block_count, computed.block_count),
+        (
+            "uncompressed_byte_size",
+            recorded.uncompressed_byte_size,
+            computed.uncompressed_byte_size,
+        ),
+        (
+            "compressed_byte_size",
+            recorded.compressed_byte_size,
+            computed.compressed_byte_size,
+        ),
+        ("index_size", recorded.index_size, computed.index_size),
+    ];
+    let diffs = fields
+        .iter()
+        .filter(|(_, recorded, computed)| recorded != computed)
+        .map(|(name, recorded, computed)| {
+            format!("{}: recorded {}, computed {}", name, recorded, computed)
+        })
+        .collect::<Vec<_>>();
+    if diffs.is_empty() {
+        None
+    } else {
+        Some(diffs.join(", "))
+    }
+}
@@ -51,6 +51,7 @@ use storages_common_table_meta::meta::Versioned;
 use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 
+use crate::io::IcebergMetadataWriter;
 use crate::io::MetaWriter;
 use crate::io::SegmentsIO;
 use crate::io::TableMetaLocationGenerator;
@@ -66,6 +67,7 @@ use crate::operations::common::TableMutationAggregator;
 use crate::operations::common::TransformSerializeSegment;
 use crate::statistics::merge_statistics;
 use crate::FuseTable;
+use crate::FUSE_OPT_KEY_ICEBERG_METADATA;
 
 const OCC_DEFAULT_BACKOFF_INIT_DELAY_MS: Duration = Duration::from_millis(5);
 const OCC_DEFAULT_BACKOFF_MAX_DELAY_MS: Duration = Duration::from_millis(20 * 1000);
@@ -227,10 +229,14 @@ impl FuseTable {
         let reply = catalog.update_table_meta(table_info, req).await;
         match reply {
             Ok(_) => {
-                TableSnapshot::cache().put(snapshot_location.clone(), Arc::new(snapshot));
+                let snapshot = Arc::new(snapshot);
+                TableSnapshot::cache().put(snapshot_location.clone(), snapshot.clone());
                 // try keep a hit file of last snapshot
                 Self::write_last_snapshot_hint(operator, location_generator, snapshot_location)
                     .await;
+                if Self::is_iceberg_metadata_enabled(&table_info.meta.options) {
+                    Self::write_iceberg_metadata(operator, location_generator, &snapshot).await;
+                }
                 Ok(())
             }
             Err(e) => {
@@ -281,6 +287,41 @@ impl FuseTable {
             });
     }
 
+    pub fn is_iceberg_metadata_enabled(options: &BTreeMap<String, String>) -> bool {
+        options
+            .get(FUSE_OPT_KEY_ICEBERG_METADATA)
+            .map_or(false, |v| v.eq_ignore_ascii_case("true"))
+    }
+
+    /// Writes the iceberg metadata of the current snapshot, if it is not up to date.
+    #[async_backtrace::framed]
+    pub async fn refresh_iceberg_metadata(&self) -> Result<()> {
+        if let Some(snapshot) = self.read_table_snapshot().await? {
+            IcebergMetadataWriter::create(&self.operator, &self.meta_location_generator)
+                .write(&snapshot)
+                .await?;
+        }
+        Ok(())
+    }
+
+    // Keep the iceberg metadata in sync with the newly committed snapshot.
+    //
+    // Like the hint file, this is best effort: the commit has succeeded already, failures
+    // are logged and the metadata is brought up to date by the next commit.
+    #[async_backtrace::framed]
+    pub async fn write_iceberg_metadata(
+        operator: &Operator,
+        location_generator: &TableMetaLocationGenerator,
+        snapshot: &TableSnapshot,
+    ) {
+        IcebergMetadataWriter::create(operator, location_generator)
+            .write(snapshot)
+            .await
+            .unwrap_or_else(|e| {
+                warn!("write iceberg metadata failure. {}", e);
+            });
+    }
+
     // TODO refactor, it is called by segment compaction and re-cluster now
     #[async_backtrace::framed]
     pub async fn commit_mutation(
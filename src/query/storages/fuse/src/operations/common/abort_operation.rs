@@ -28,6 +28,8 @@ pub struct AbortOperation {
     pub segments: Vec<String>,
     pub blocks: Vec<String>,
     pub bloom_filter_indexes: Vec<String>,
+    #[serde(default)]
+    pub deletion_vectors: Vec<String>,
 }
 
 impl AbortOperation {
@@ -36,6 +38,7 @@ impl AbortOperation {
         self.blocks.extend(rhs.blocks.clone());
         self.bloom_filter_indexes
             .extend(rhs.bloom_filter_indexes.clone());
+        self.deletion_vectors.extend(rhs.deletion_vectors.clone());
     }
 
     pub fn add_block(&mut self, block: &BlockMeta) {
@@ -44,6 +47,16 @@ impl AbortOperation {
         if let Some(index) = block.bloom_filter_index_location.clone() {
             self.bloom_filter_indexes.push(index.0);
         }
+        if let Some(index) = block.ngram_index_location.clone() {
+            self.bloom_filter_indexes.push(index.0);
+        }
+        if let Some(index) = block.inverted_index_location.clone() {
+            self.bloom_filter_indexes.push(index.0);
+        }
+    }
+
+    pub fn add_deletion_vector(&mut self, deletion_vector: String) {
+        self.deletion_vectors.push(deletion_vector);
     }
 
     pub fn add_segment(&mut self, segment: String) {
@@ -64,6 +77,7 @@ impl AbortOperation {
             .blocks
             .into_iter()
             .chain(self.bloom_filter_indexes.into_iter())
+            .chain(self.deletion_vectors.into_iter())
             .chain(self.segments.into_iter());
         fuse_file.remove_file_in_batch(locations).await
     }
@@ -36,6 +36,7 @@ use storages_common_table_meta::meta::Versioned;
 use super::ConflictResolveContext;
 use super::SnapshotChanges;
 use super::SnapshotMerged;
+use crate::io::DeletionVector;
 use crate::io::SegmentsIO;
 use crate::io::SerializedSegment;
 use crate::io::TableMetaLocationGenerator;
@@ -54,6 +55,7 @@ use crate::FuseTable;
 struct BlockMutations {
     replaced_blocks: Vec<(BlockIndex, Arc<BlockMeta>)>,
     deleted_blocks: Vec<BlockIndex>,
+    deletion_vectors: Vec<(BlockIndex, DeletionVector)>,
 }
 
 impl BlockMutations {
@@ -61,6 +63,7 @@ impl BlockMutations {
         BlockMutations {
             replaced_blocks: vec![(block_idx, block_meta)],
             deleted_blocks: vec![],
+            deletion_vectors: vec![],
         }
     }
 
@@ -68,6 +71,15 @@ impl BlockMutations {
         BlockMutations {
             replaced_blocks: vec![],
             deleted_blocks: vec![block_idx],
+            deletion_vectors: vec![],
+        }
+    }
+
+    fn new_deletion_vector(block_idx: BlockIndex, deletion_vector: DeletionVector) -> Self {
+        BlockMutations {
+            replaced_blocks: vec![],
+            deleted_blocks: vec![],
+            deletion_vectors: vec![(block_idx, deletion_vector)],
         }
     }
 
@@ -78,6 +90,10 @@ impl BlockMutations {
     fn push_deleted(&mut self, block_idx: BlockIndex) {
         self.deleted_blocks.push(block_idx)
     }
+
+    fn push_deletion_vector(&mut self, block_idx: BlockIndex, deletion_vector: DeletionVector) {
+        self.deletion_vectors.push((block_idx, deletion_vector))
+    }
 }
 
 pub struct MutationAccumulator {
@@ -142,6 +158,22 @@ impl MutationAccumulator {
                     .and_modify(|v| v.push_deleted(index.block_idx))
                     .or_insert(BlockMutations::new_deletion(index.block_idx));
             }
+            MutationLogEntry::DeletionVector {
+                index,
+                deletion_vector,
+            } => {
+                self.abort_operation
+                    .add_deletion_vector(deletion_vector.location.0.clone());
+                self.mutations
+                    .entry(index.segment_idx)
+                    .and_modify(|v| {
+                        v.push_deletion_vector(index.block_idx, deletion_vector.clone())
+                    })
+                    .or_insert(BlockMutations::new_deletion_vector(
+                        index.block_idx,
+                        deletion_vector,
+                    ));
+            }
             MutationLogEntry::DeletedSegment { deleted_segment } => {
                 self.deleted_segments.push(deleted_segment)
             }
@@ -304,6 +336,12 @@ impl MutationAccumulator {
                 for idx in segment_mutation.deleted_blocks {
                     block_editor.remove(&idx);
                 }
+                // the block is kept, only the deletion vector it references is replaced.
+                for (idx, deletion_vector) in segment_mutation.deletion_vectors {
+                    if let Some(old_meta) = block_editor.get_mut(&idx) {
+                        *old_meta = Arc::new(deletion_vector.apply_to(old_meta));
+                    }
+                }
 
                 if !block_editor.is_empty() {
                     // assign back the mutated blocks to segment
@@ -24,6 +24,7 @@ use storages_common_table_meta::meta::FormatVersion;
 use storages_common_table_meta::meta::SegmentInfo;
 
 use super::ConflictResolveContext;
+use crate::io::DeletionVector;
 use crate::operations::common::AbortOperation;
 use crate::operations::mutation::MutationDeletedSegment;
 
@@ -49,6 +50,11 @@ pub enum MutationLogEntry {
         index: BlockMetaIndex,
         block_meta: Arc<BlockMeta>,
     },
+    /// Rows of the block are deleted by the deletion vector, the block itself is kept.
+    DeletionVector {
+        index: BlockMetaIndex,
+        deletion_vector: DeletionVector,
+    },
     DoNothing,
 }
 
@@ -19,6 +19,8 @@ use std::sync::Arc;
 
 use common_catalog::plan::InternalColumn;
 use common_catalog::plan::InternalColumnMeta;
+use common_catalog::plan::InternalColumnType;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::BlockMetaInfoDowncast;
 use common_expression::DataBlock;
@@ -106,6 +108,16 @@ impl Processor for FillInternalColumnProcessor {
             let mut data_block = data_block;
             let num_rows = data_block.num_rows();
             for internal_column in self.internal_columns.values() {
+                // The scores are only known for the blocks searched with their inverted index,
+                // a block written before the index is created would be ranked wrongly.
+                if internal_column.column_type == InternalColumnType::SearchScore
+                    && internal_column_meta.search_scores.is_none()
+                {
+                    return Err(ErrorCode::InvertedIndexNotBuilt(format!(
+                        "score() requires the rows to be searched by match or query with an inverted index, but block {} is not indexed, it may be written before the index is created",
+                        internal_column_meta.block_location
+                    )));
+                }
                 let column =
                     internal_column.generate_column_values(&internal_column_meta, num_rows);
                 data_block.add_column(column);
@@ -15,11 +15,13 @@
 mod fill_internal_columns;
 mod sink_commit;
 mod transform_mutation_aggregator;
+mod transform_partition_split;
 mod transform_serialize_block;
 mod transform_serialize_segment;
 
 pub use fill_internal_columns::FillInternalColumnProcessor;
 pub use sink_commit::CommitSink;
 pub use transform_mutation_aggregator::TableMutationAggregator;
+pub use transform_partition_split::TransformPartitionSplit;
 pub use transform_serialize_block::TransformSerializeBlock;
 pub use transform_serialize_segment::TransformSerializeSegment;
@@ -0,0 +1,38 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_pipeline_transforms::processors::transforms::AccumulatingTransform;
+
+use crate::statistics::PartitionGenerator;
+
+/// Splits the incoming blocks of a partitioned table by partition value.
+pub struct TransformPartitionSplit {
+    partition_gen: PartitionGenerator,
+}
+
+impl TransformPartitionSplit {
+    pub fn new(partition_gen: PartitionGenerator) -> Self {
+        Self { partition_gen }
+    }
+}
+
+impl AccumulatingTransform for TransformPartitionSplit {
+    const NAME: &'static str = "TransformPartitionSplit";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        self.partition_gen.split(data)
+    }
+}
@@ -27,6 +27,7 @@ use common_pipeline_core::processors::port::InputPort;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use opendal::Operator;
 use storages_common_index::BloomIndex;
+use storages_common_index::NgramIndex;
 
 use crate::io::write_data;
 use crate::io::BlockBuilder;
@@ -83,13 +84,21 @@ impl TransformSerializeBlock {
         let bloom_columns_map = table
             .bloom_index_cols
             .bloom_index_fields(source_schema.clone(), BloomIndex::supported_type)?;
+        let ngram_columns_map = table
+            .ngram_index_cols
+            .bloom_index_fields(source_schema.clone(), NgramIndex::supported_type)?;
+        let inverted_columns_map = table.inverted_index_fields(&source_schema)?;
+        let partition_gen = table.get_partition_gen(ctx.clone())?;
         let block_builder = BlockBuilder {
             ctx,
             meta_locations: table.meta_location_generator().clone(),
             source_schema,
             write_settings: table.get_write_settings(),
             cluster_stats_gen,
+            partition_gen,
             bloom_columns_map,
+            ngram_columns_map,
+            inverted_columns_map,
         };
         Ok(TransformSerializeBlock {
             state: State::Consume,
@@ -177,6 +186,14 @@ impl Processor for TransformSerializeBlock {
                     Self::mutation_logs(MutationLogEntry::DeletedSegment { deleted_segment });
                 self.output.push_data(Ok(data_block));
                 Ok(Event::NeedConsume)
+            } else if let Some(deletion_vector) = meta.deletion_vector {
+                // delete rows of a block by deletion vector, merge-on-read
+                let data_block = Self::mutation_logs(MutationLogEntry::DeletionVector {
+                    index: meta.index,
+                    deletion_vector,
+                });
+                self.output.push_data(Ok(data_block));
+                Ok(Event::NeedConsume)
             } else if input_data.is_empty() {
                 // delete a whole block, block level
                 let data_block =
@@ -271,6 +288,36 @@ impl Processor for TransformSerializeBlock {
                     }
                 }
 
+                if let Some(ngram_index_state) = serialized.ngram_index_state {
+                    let index_size = ngram_index_state.data.len();
+                    write_data(
+                        ngram_index_state.data,
+                        &self.dal,
+                        &ngram_index_state.location.0,
+                    )
+                    .await?;
+                    // Perf.
+                    {
+                        metrics_inc_block_index_write_nums(1);
+                        metrics_inc_block_index_write_bytes(index_size as u64);
+                    }
+                }
+
+                if let Some(inverted_index_state) = serialized.inverted_index_state {
+                    let index_size = inverted_index_state.data.len();
+                    write_data(
+                        inverted_index_state.data,
+                        &self.dal,
+                        &inverted_index_state.location.0,
+                    )
+                    .await?;
+                    // Perf.
+                    {
+                        metrics_inc_block_index_write_nums(1);
+                        metrics_inc_block_index_write_bytes(index_size as u64);
+                    }
+                }
+
                 let data_block = if let Some(index) = index {
                     Self::mutation_logs(MutationLogEntry::Replaced {
                         index,
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
@@ -21,6 +22,7 @@ use common_exception::Result;
 use common_expression::BlockMetaInfoDowncast;
 use common_expression::BlockThresholds;
 use common_expression::DataBlock;
+use common_expression::Scalar;
 use common_pipeline_core::pipe::PipeItem;
 use common_pipeline_core::processors::port::OutputPort;
 use log::info;
@@ -45,7 +47,8 @@ use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 
 enum State {
     None,
-    GenerateSegment,
+    // generate the segment of the given partition
+    GenerateSegment(Vec<Scalar>),
     SerializedSegment {
         data: Vec<u8>,
         location: String,
@@ -61,7 +64,9 @@ enum State {
 pub struct TransformSerializeSegment {
     data_accessor: Operator,
     meta_locations: TableMetaLocationGenerator,
-    accumulator: StatisticsAccumulator,
+    // blocks of different partitions never share a segment, the key of
+    // an unpartitioned table is always empty.
+    accumulators: BTreeMap<Vec<Scalar>, StatisticsAccumulator>,
     state: State,
     input: Arc<InputPort>,
     output: Arc<OutputPort>,
@@ -87,7 +92,7 @@ impl TransformSerializeSegment {
             data_accessor: table.get_operator(),
             meta_locations: table.meta_location_generator().clone(),
             state: State::None,
-            accumulator: Default::default(),
+            accumulators: BTreeMap::new(),
             block_per_seg: table
                 .get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT)
                 as u64,
@@ -121,7 +126,7 @@ impl Processor for TransformSerializeSegment {
     fn event(&mut self) -> Result<Event> {
         if matches!(
             &self.state,
-            State::GenerateSegment | State::PreCommitSegment { .. }
+            State::GenerateSegment(_) | State::PreCommitSegment { .. }
         ) {
             return Ok(Event::Sync);
         }
@@ -144,8 +149,8 @@ impl Processor for TransformSerializeSegment {
         }
 
         if self.input.is_finished() {
-            if self.accumulator.summary_row_count != 0 {
-                self.state = State::GenerateSegment;
+            if let Some(partition) = self.accumulators.keys().next() {
+                self.state = State::GenerateSegment(partition.clone());
                 return Ok(Event::Sync);
             }
             self.output.finish();
@@ -165,9 +170,11 @@ impl Processor for TransformSerializeSegment {
                 .ok_or(ErrorCode::Internal("No commit meta. It's a bug"))?
                 .clone();
 
-            self.accumulator.add_with_block_meta(block_meta);
-            if self.accumulator.summary_block_count >= self.block_per_seg {
-                self.state = State::GenerateSegment;
+            let partition = block_meta.partition.clone().unwrap_or_default();
+            let accumulator = self.accumulators.entry(partition.clone()).or_default();
+            accumulator.add_with_block_meta(block_meta);
+            if accumulator.summary_block_count >= self.block_per_seg {
+                self.state = State::GenerateSegment(partition);
                 return Ok(Event::Sync);
             }
         }
@@ -178,8 +185,8 @@ impl Processor for TransformSerializeSegment {
 
     fn process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::None) {
-            State::GenerateSegment => {
-                let acc = std::mem::take(&mut self.accumulator);
+            State::GenerateSegment(partition) => {
+                let acc = self.accumulators.remove(&partition).unwrap_or_default();
                 let summary = acc.summary(self.thresholds, self.default_cluster_key_id);
 
                 let segment_info = SegmentInfo::new(acc.blocks_metas, summary);
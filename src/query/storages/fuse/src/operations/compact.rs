@@ -60,7 +60,12 @@ impl FuseTable {
             return Ok(());
         };
 
-        if base_snapshot.summary.block_count <= 1 {
+        // a single block is only compacted to fold its deletion vector in, such a block
+        // is never counted as perfect.
+        if base_snapshot.summary.block_count <= 1
+            && (matches!(target, CompactTarget::Segments)
+                || base_snapshot.summary.perfect_block_count == base_snapshot.summary.block_count)
+        {
             return Ok(());
         }
 
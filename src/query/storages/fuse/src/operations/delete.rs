@@ -258,6 +258,8 @@ impl FuseTable {
                     ops.clone(),
                     self.storage_format,
                     query_row_id_col,
+                    self.delete_mode,
+                    self.meta_location_generator.clone(),
                 )
             },
             max_threads,
@@ -301,6 +303,7 @@ impl FuseTable {
             self.table_info.schema(),
             &push_down,
             self.bloom_index_cols(),
+            self.ngram_index_cols(),
         )?;
 
         let segment_locations = create_segment_location_vector(segment_locations, None);
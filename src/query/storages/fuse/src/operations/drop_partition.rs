@@ -0,0 +1,147 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::type_check::check_cast;
+use common_expression::ConstantFolder;
+use common_expression::Expr;
+use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
+use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::meta::Statistics;
+
+use crate::io::SegmentsIO;
+use crate::operations::common::AbortOperation;
+use crate::statistics::reducers::merge_statistics_mut;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Drop the segments of a partition, only the metadata of the table is touched.
+    ///
+    /// If `is_truncate` is true, dropping a partition which has no data is not an error.
+    #[async_backtrace::framed]
+    pub async fn drop_partition(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        values: Vec<Scalar>,
+        is_truncate: bool,
+    ) -> Result<()> {
+        let partition = self.cast_partition_values(ctx.clone(), values)?;
+
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return self.partition_not_found(&partition, is_truncate);
+        };
+
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
+        let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
+        let mut remain_segments = Vec::with_capacity(snapshot.segments.len());
+        let mut summary = Statistics::default();
+        let default_cluster_key_id = self.cluster_key_id();
+        for chunk in snapshot.segments.chunks(chunk_size.max(1)) {
+            let segments = segments_io
+                .read_segments::<Arc<SegmentInfo>>(chunk, false)
+                .await?;
+            for (location, segment) in chunk.iter().zip(segments) {
+                let segment = segment?;
+                if segment.summary.partition.as_ref() == Some(&partition) {
+                    continue;
+                }
+                merge_statistics_mut(&mut summary, &segment.summary, default_cluster_key_id);
+                remain_segments.push(location.clone());
+            }
+        }
+
+        if remain_segments.len() == snapshot.segments.len() {
+            return self.partition_not_found(&partition, is_truncate);
+        }
+
+        // no new file is written, nothing to abort.
+        self.commit_mutation(
+            &ctx,
+            snapshot,
+            &remain_segments,
+            summary,
+            AbortOperation::default(),
+            None,
+        )
+        .await
+    }
+
+    // Cast the values to the types of the partition keys, so that they can be compared with
+    // the partition of segments.
+    fn cast_partition_values(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        values: Vec<Scalar>,
+    ) -> Result<Vec<Scalar>> {
+        let partition_keys = self.partition_keys(ctx.clone())?;
+        if partition_keys.is_empty() {
+            return Err(ErrorCode::InvalidPartitionKeys(format!(
+                "Table '{}' is not partitioned",
+                self.table_info.name
+            )));
+        }
+        if partition_keys.len() != values.len() {
+            return Err(ErrorCode::InvalidPartitionKeys(format!(
+                "Table '{}' is partitioned by {} keys, but {} values are given",
+                self.table_info.name,
+                partition_keys.len(),
+                values.len()
+            )));
+        }
+
+        let func_ctx = ctx.get_function_context()?;
+        partition_keys
+            .iter()
+            .zip(values)
+            .map(|(key, value)| {
+                let dest_type = key.as_expr(&BUILTIN_FUNCTIONS).data_type().clone();
+                let expr: Expr<String> = Expr::Constant {
+                    span: None,
+                    data_type: value.as_ref().infer_data_type(),
+                    scalar: value,
+                };
+                let expr = check_cast(None, false, expr, &dest_type, &BUILTIN_FUNCTIONS)?;
+                let (expr, _) = ConstantFolder::fold(&expr, &func_ctx, &BUILTIN_FUNCTIONS);
+                match expr {
+                    Expr::Constant { scalar, .. } => Ok(scalar),
+                    _ => Err(ErrorCode::InvalidPartitionKeys(format!(
+                        "Partition value `{}` can not be cast to {}",
+                        expr, dest_type
+                    ))),
+                }
+            })
+            .collect()
+    }
+
+    fn partition_not_found(&self, partition: &[Scalar], is_truncate: bool) -> Result<()> {
+        if is_truncate {
+            return Ok(());
+        }
+        let partition = partition
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        Err(ErrorCode::UnknownPartition(format!(
+            "Partition ({}) does not exist in table '{}'",
+            partition, self.table_info.name
+        )))
+    }
+}
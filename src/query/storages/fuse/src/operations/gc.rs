@@ -681,6 +681,17 @@ impl From<SegmentInfo> for LocationTuple {
             if let Some(bloom_loc) = &block_meta.bloom_filter_index_location {
                 bloom_location.insert(bloom_loc.0.clone());
             }
+            // ngram and inverted index files, and deletion vectors, are purged together
+            // with the bloom index files
+            if let Some(ngram_loc) = &block_meta.ngram_index_location {
+                bloom_location.insert(ngram_loc.0.clone());
+            }
+            if let Some(inverted_loc) = &block_meta.inverted_index_location {
+                bloom_location.insert(inverted_loc.0.clone());
+            }
+            if let Some(dv_loc) = &block_meta.deletion_vector_location {
+                bloom_location.insert(dv_loc.0.clone());
+            }
         }
         Self {
             block_location,
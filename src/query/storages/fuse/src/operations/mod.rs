@@ -15,10 +15,12 @@
 mod agg_index_sink;
 mod analyze;
 mod append;
+mod check;
 mod commit;
 pub mod common;
 mod compact;
 mod delete;
+mod drop_partition;
 mod gc;
 mod mutation;
 mod navigate;
@@ -34,6 +36,9 @@ mod update;
 pub mod util;
 
 pub use agg_index_sink::AggIndexSink;
+pub use check::CheckIssue;
+pub use check::CheckIssueKind;
+pub use check::CheckReport;
 pub use common::BlockMetaIndex;
 pub use common::FillInternalColumnProcessor;
 pub use common::TransformSerializeBlock;
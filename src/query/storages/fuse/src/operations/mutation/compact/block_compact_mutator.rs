@@ -304,6 +304,11 @@ impl SegmentCompactChecker {
         segments.len() != 1
             || (segments[0].1.summary.block_count > 1
                 && segments[0].1.summary.perfect_block_count != segments[0].1.summary.block_count)
+            || segments[0]
+                .1
+                .blocks
+                .iter()
+                .any(|b| b.deletion_vector_location.is_some())
     }
 
     fn add(
@@ -311,25 +316,34 @@ impl SegmentCompactChecker {
         location: Location,
         segment: Arc<SegmentInfo>,
     ) -> Vec<Vec<(Location, Arc<SegmentInfo>)>> {
+        let mut groups = vec![];
+        // segments of different partitions are never compacted together.
+        if let Some((_, last)) = self.segments.last() {
+            if last.summary.partition != segment.summary.partition {
+                self.total_block_count = 0;
+                groups.push(std::mem::take(&mut self.segments));
+            }
+        }
+
         self.total_block_count += segment.summary.block_count;
         if self.total_block_count < self.threshold {
             self.segments.push((location, segment));
-            return vec![];
+            return groups;
         }
 
         if self.total_block_count > 2 * self.threshold {
             self.total_block_count = 0;
-            let trivial = vec![(location, segment)];
-            if self.segments.is_empty() {
-                return vec![trivial];
-            } else {
-                return vec![std::mem::take(&mut self.segments), trivial];
+            if !self.segments.is_empty() {
+                groups.push(std::mem::take(&mut self.segments));
             }
+            groups.push(vec![(location, segment)]);
+            return groups;
         }
 
         self.total_block_count = 0;
         self.segments.push((location, segment));
-        vec![std::mem::take(&mut self.segments)]
+        groups.push(std::mem::take(&mut self.segments));
+        groups
     }
 }
 
@@ -372,7 +386,7 @@ impl CompactTaskBuilder {
             }
         }
 
-        let total_rows = self.total_rows + block.row_count as usize;
+        let total_rows = self.total_rows + block.live_row_count() as usize;
         let total_size = self.total_size + block.block_size as usize;
         if !thresholds.check_large_enough(total_rows, total_size) {
             // blocks < N
@@ -408,6 +422,11 @@ impl CompactTaskBuilder {
     }
 
     fn check_compact(&self, block: &Arc<BlockMeta>) -> bool {
+        // Fold the deletion vector into the block.
+        if block.deletion_vector_location.is_some() {
+            return true;
+        }
+
         let column_ids: HashSet<ColumnId> = block.col_metas.keys().cloned().collect();
         if self.column_ids == column_ids {
             // Check if the block needs to be resort.
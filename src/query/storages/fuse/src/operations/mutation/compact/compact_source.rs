@@ -25,6 +25,7 @@ use common_expression::DataBlock;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 
 use crate::io::BlockReader;
+use crate::io::DeletionVector;
 use crate::io::ReadSettings;
 use crate::metrics::*;
 use crate::operations::mutation::mutation_meta::ClusterStatsGenType;
@@ -165,9 +166,23 @@ impl Processor for CompactSource {
                             metrics_inc_compact_block_read_bytes(block.block_size);
                         }
 
-                        block_reader
+                        let data_block = block_reader
                             .read_by_meta(&settings, block.as_ref(), &storage_format)
-                            .await
+                            .await?;
+
+                        // fold the deletion vector into the compacted block.
+                        match DeletionVector::from_block_meta(block) {
+                            Some(dv) => {
+                                let live_rows = dv
+                                    .read_live_rows(
+                                        &block_reader.operator,
+                                        block.row_count as usize,
+                                    )
+                                    .await?;
+                                data_block.filter_with_bitmap(&live_rows)
+                            }
+                            None => Ok(data_block),
+                        }
                     });
                 }
 
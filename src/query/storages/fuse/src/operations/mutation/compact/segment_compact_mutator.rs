@@ -280,6 +280,13 @@ impl<'a> SegmentCompactor<'a> {
             return Ok(());
         }
 
+        // segments of different partitions are never merged together.
+        if let Some((last, _)) = self.fragmented_segments.last() {
+            if last.summary.partition != segment_info.summary.partition {
+                self.compact_fragments().await?;
+            }
+        }
+
         let s = self.accumulated_num_blocks + num_blocks_current_segment;
 
         if s < self.threshold {
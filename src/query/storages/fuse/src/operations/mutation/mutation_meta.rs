@@ -19,6 +19,7 @@ use common_expression::BlockMetaInfoDowncast;
 use common_expression::BlockMetaInfoPtr;
 use storages_common_table_meta::meta::ClusterStatistics;
 
+use crate::io::DeletionVector;
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::mutation::MutationDeletedSegment;
 
@@ -33,6 +34,7 @@ pub struct SerializeDataMeta {
     pub index: BlockMetaIndex,
     pub stats_type: ClusterStatsGenType,
     pub deleted_segment: Option<MutationDeletedSegment>,
+    pub deletion_vector: Option<DeletionVector>,
 }
 
 #[typetag::serde(name = "serialize_data_meta")]
@@ -56,6 +58,7 @@ impl SerializeDataMeta {
             index,
             stats_type,
             deleted_segment: None,
+            deletion_vector: None,
         })
     }
 
@@ -66,6 +69,19 @@ impl SerializeDataMeta {
             index: BlockMetaIndex::default(),           // default value
             stats_type: ClusterStatsGenType::Generally, // default value
             deleted_segment: Some(deleted_segment),
+            deletion_vector: None,
+        })
+    }
+
+    pub fn create_with_deletion_vector(
+        index: BlockMetaIndex,
+        deletion_vector: DeletionVector,
+    ) -> BlockMetaInfoPtr {
+        Box::new(SerializeDataMeta {
+            index,
+            stats_type: ClusterStatsGenType::Generally, // default value
+            deleted_segment: None,
+            deletion_vector: Some(deletion_vector),
         })
     }
 }
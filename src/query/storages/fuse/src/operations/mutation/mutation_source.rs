@@ -16,6 +16,8 @@ use std::any::Any;
 use std::ops::Not;
 use std::sync::Arc;
 
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
 use common_base::base::ProgressValues;
 use common_catalog::plan::InternalColumn;
 use common_catalog::plan::InternalColumnMeta;
@@ -36,16 +38,21 @@ use common_functions::BUILTIN_FUNCTIONS;
 use common_sql::evaluator::BlockOperator;
 
 use crate::fuse_part::FusePartInfo;
+use crate::io::write_data;
 use crate::io::BlockReader;
+use crate::io::DeletionVector;
 use crate::io::ReadSettings;
+use crate::io::TableMetaLocationGenerator;
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::mutation::mutation_meta::ClusterStatsGenType;
 use crate::operations::mutation::Mutation;
 use crate::operations::mutation::SerializeDataMeta;
+use crate::operations::read::fuse_source::live_row_offsets;
 use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::processors::processor::Event;
 use crate::pipelines::processors::processor::ProcessorPtr;
 use crate::pipelines::processors::Processor;
+use crate::FuseDeleteMode;
 use crate::FuseStorageFormat;
 use crate::MergeIOReadResult;
 
@@ -56,18 +63,24 @@ pub enum MutationAction {
 
 enum State {
     ReadData(Option<PartInfoPtr>),
-    FilterData(PartInfoPtr, MergeIOReadResult),
+    // The live rows are set if the block has a deletion vector.
+    FilterData(PartInfoPtr, MergeIOReadResult, Option<Bitmap>),
     ReadRemain {
         part: PartInfoPtr,
         data_block: DataBlock,
+        live_rows: Option<Bitmap>,
         filter: Option<Value<BooleanType>>,
     },
     MergeRemain {
         part: PartInfoPtr,
         merged_io_read_result: MergeIOReadResult,
         data_block: DataBlock,
+        live_rows: Option<Bitmap>,
         filter: Option<Value<BooleanType>>,
     },
+    WriteDeletionVector {
+        live_rows: Bitmap,
+    },
     PerformOperator(DataBlock),
     Output(Option<PartInfoPtr>, DataBlock),
     Finish,
@@ -85,6 +98,8 @@ pub struct MutationSource {
     storage_format: FuseStorageFormat,
     action: MutationAction,
     query_row_id_col: bool,
+    delete_mode: FuseDeleteMode,
+    location_gen: TableMetaLocationGenerator,
 
     index: BlockMetaIndex,
     stats_type: ClusterStatsGenType,
@@ -102,6 +117,8 @@ impl MutationSource {
         operators: Vec<BlockOperator>,
         storage_format: FuseStorageFormat,
         query_row_id_col: bool,
+        delete_mode: FuseDeleteMode,
+        location_gen: TableMetaLocationGenerator,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(MutationSource {
             state: State::ReadData(None),
@@ -114,6 +131,8 @@ impl MutationSource {
             storage_format,
             action,
             query_row_id_col,
+            delete_mode,
+            location_gen,
             index: BlockMetaIndex::default(),
             stats_type: ClusterStatsGenType::Generally,
         })))
@@ -162,7 +181,10 @@ impl Processor for MutationSource {
             }
         }
 
-        if matches!(self.state, State::ReadData(_) | State::ReadRemain { .. }) {
+        if matches!(
+            self.state,
+            State::ReadData(_) | State::ReadRemain { .. } | State::WriteDeletionVector { .. }
+        ) {
             Ok(Event::Async)
         } else {
             Ok(Event::Sync)
@@ -171,13 +193,17 @@ impl Processor for MutationSource {
 
     fn process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::Finish) {
-            State::FilterData(part, read_res) => {
+            State::FilterData(part, read_res, live_rows) => {
                 let chunks = read_res.columns_chunks()?;
                 let mut data_block = self.block_reader.deserialize_chunks_with_part_info(
                     part.clone(),
                     chunks,
                     &self.storage_format,
                 )?;
+                // Drop the rows deleted by the deletion vector of the block.
+                if let Some(live_rows) = &live_rows {
+                    data_block = data_block.filter_with_bitmap(live_rows)?;
+                }
                 let num_rows = data_block.num_rows();
 
                 if let Some(filter) = self.filter.as_ref() {
@@ -191,7 +217,8 @@ impl Processor for MutationSource {
                             block_location: block_meta.block_location.clone(),
                             segment_location: block_meta.segment_location.clone(),
                             snapshot_location: "".to_string(),
-                            offsets: None,
+                            offsets: live_rows.as_ref().map(live_row_offsets),
+                            search_scores: None,
                         };
                         let internal_col = InternalColumn {
                             column_name: ROW_ID_COL_NAME.to_string(),
@@ -247,6 +274,28 @@ impl Processor for MutationSource {
                                         self.ctx.get_partition(),
                                         DataBlock::empty_with_meta(meta),
                                     );
+                                } else if self.delete_mode == FuseDeleteMode::MergeOnRead {
+                                    // Mark the deleted rows in the deletion vector of the block,
+                                    // instead of rewriting the block.
+                                    let predicate_col = predicates.into_column().unwrap();
+                                    let fuse_part = FusePartInfo::from_part(&part)?;
+                                    let mut new_live_rows = match &live_rows {
+                                        Some(live_rows) => MutableBitmap::from_iter(live_rows),
+                                        None => MutableBitmap::from_len_set(fuse_part.nums_rows),
+                                    };
+                                    let positions = match &live_rows {
+                                        Some(live_rows) => live_row_offsets(live_rows),
+                                        None => (0..fuse_part.nums_rows).collect(),
+                                    };
+                                    for (pos, deleted) in positions.into_iter().zip(&predicate_col)
+                                    {
+                                        if deleted {
+                                            new_live_rows.set(pos, false);
+                                        }
+                                    }
+                                    self.state = State::WriteDeletionVector {
+                                        live_rows: new_live_rows.into(),
+                                    };
                                 } else {
                                     let predicate_col = predicates.into_column().unwrap();
                                     let filter = predicate_col.not();
@@ -257,6 +306,7 @@ impl Processor for MutationSource {
                                         self.state = State::ReadRemain {
                                             part,
                                             data_block,
+                                            live_rows,
                                             filter: Some(Value::Column(filter)),
                                         }
                                     }
@@ -273,6 +323,7 @@ impl Processor for MutationSource {
                                     self.state = State::ReadRemain {
                                         part,
                                         data_block,
+                                        live_rows,
                                         filter: None,
                                     };
                                 }
@@ -296,6 +347,7 @@ impl Processor for MutationSource {
                 part,
                 merged_io_read_result,
                 mut data_block,
+                live_rows,
                 filter,
             } => {
                 if let Some(remain_reader) = self.remain_reader.as_ref() {
@@ -306,6 +358,12 @@ impl Processor for MutationSource {
                         &self.storage_format,
                     )?;
 
+                    let remain_block = if let Some(live_rows) = &live_rows {
+                        remain_block.filter_with_bitmap(live_rows)?
+                    } else {
+                        remain_block
+                    };
+
                     let remain_block = if let Some(filter) = filter {
                         // for deletion.
                         remain_block.filter_boolean_value(&filter)?
@@ -375,7 +433,7 @@ impl Processor for MutationSource {
                         {
                             // whole block deletion.
                             let progress_values = ProgressValues {
-                                rows: fuse_part.nums_rows,
+                                rows: fuse_part.live_rows(),
                                 bytes: 0,
                             };
                             self.ctx.get_write_progress().incr(&progress_values);
@@ -396,7 +454,17 @@ impl Processor for MutationSource {
                                     &fuse_part.columns_meta,
                                 )
                                 .await?;
-                            self.state = State::FilterData(inner_part, read_res);
+                            let live_rows = match &fuse_part.deletion_vector {
+                                Some(dv) => Some(
+                                    dv.read_live_rows(
+                                        &self.block_reader.operator,
+                                        fuse_part.nums_rows,
+                                    )
+                                    .await?,
+                                ),
+                                None => None,
+                            };
+                            self.state = State::FilterData(inner_part, read_res, live_rows);
                         }
                     }
                 }
@@ -404,6 +472,7 @@ impl Processor for MutationSource {
             State::ReadRemain {
                 part,
                 data_block,
+                live_rows,
                 filter,
             } => {
                 if let Some(remain_reader) = self.remain_reader.as_ref() {
@@ -421,12 +490,34 @@ impl Processor for MutationSource {
                         part,
                         merged_io_read_result: read_res,
                         data_block,
+                        live_rows,
                         filter,
                     };
                 } else {
                     return Err(ErrorCode::Internal("It's a bug. No remain reader"));
                 }
             }
+            State::WriteDeletionVector { live_rows } => {
+                let data = DeletionVector::serialize(&live_rows)?;
+                let deletion_vector = DeletionVector {
+                    location: self.location_gen.gen_deletion_vector_location(),
+                    size: data.len() as u64,
+                    deleted_row_count: live_rows.unset_bits() as u64,
+                };
+                write_data(
+                    data,
+                    &self.block_reader.operator,
+                    &deletion_vector.location.0,
+                )
+                .await?;
+
+                let meta = SerializeDataMeta::create_with_deletion_vector(
+                    self.index.clone(),
+                    deletion_vector,
+                );
+                self.state =
+                    State::Output(self.ctx.get_partition(), DataBlock::empty_with_meta(meta));
+            }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
         Ok(())
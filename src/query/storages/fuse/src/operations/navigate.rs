@@ -12,11 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use chrono::DateTime;
 use chrono::Duration;
 use chrono::Utc;
+use common_catalog::table::NavigationDescriptor;
 use common_catalog::table::NavigationPoint;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
@@ -28,6 +30,7 @@ use opendal::EntryMode;
 use opendal::Metakey;
 use storages_common_cache::LoadParams;
 use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::OPT_KEY_DATA_RETENTION_TIME_IN_DAYS;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 
 use crate::io::MetaReaders;
@@ -37,6 +40,115 @@ use crate::FuseTable;
 use crate::FUSE_TBL_SNAPSHOT_PREFIX;
 
 impl FuseTable {
+    /// The data retention period of the table in days, which is taken from the
+    /// table option, or the option of its database, or the tenant setting,
+    /// in that order. `None` if the time travel is not limited.
+    #[async_backtrace::framed]
+    pub async fn get_data_retention_time_in_days(
+        &self,
+        ctx: &dyn TableContext,
+        database_name: &str,
+    ) -> Result<Option<u64>> {
+        // An invalid option is an error rather than falling back to the next level,
+        // which may keep the historical data for a shorter period than configured.
+        let parse = |options: &BTreeMap<String, String>| -> Result<Option<u64>> {
+            options
+                .get(OPT_KEY_DATA_RETENTION_TIME_IN_DAYS)
+                .map(|v| {
+                    v.parse::<u64>().map_err(|_| {
+                        ErrorCode::TableOptionInvalid(format!(
+                            "invalid {} option {}, must be a positive integer",
+                            OPT_KEY_DATA_RETENTION_TIME_IN_DAYS, v
+                        ))
+                    })
+                })
+                .transpose()
+        };
+
+        if let Some(days) = parse(&self.table_info.meta.options)? {
+            return Ok(Some(days));
+        }
+
+        let catalog = ctx.get_catalog(&self.table_info.meta.catalog).await?;
+        let database = catalog
+            .get_database(ctx.get_tenant().as_str(), database_name)
+            .await?;
+        if let Some(days) = parse(database.options())? {
+            return Ok(Some(days));
+        }
+
+        let days = ctx.get_settings().get_data_retention_time_in_days()?;
+        Ok((days > 0).then_some(days))
+    }
+
+    /// Navigate to the given point, rejecting the points that are beyond
+    /// the data retention period of the table.
+    #[async_backtrace::framed]
+    pub async fn navigate_within_retention(
+        &self,
+        ctx: &dyn TableContext,
+        navigation: &NavigationDescriptor,
+    ) -> Result<Arc<FuseTable>> {
+        let snapshot_location = if let Some(loc) = self.snapshot_loc().await? {
+            loc
+        } else {
+            // not an error?
+            return Err(ErrorCode::TableHistoricalDataNotFound(
+                "Empty Table has no historical data",
+            ));
+        };
+
+        let retention = self
+            .get_data_retention_time_in_days(ctx, &navigation.database_name)
+            .await?
+            .map(|days| (days, Utc::now() - Duration::days(days as i64)));
+        let beyond_retention = |days: u64| {
+            ErrorCode::TableHistoricalDataNotFound(format!(
+                "Historical data beyond the data retention period of {} days is not available",
+                days
+            ))
+        };
+
+        match &navigation.point {
+            NavigationPoint::SnapshotID(snapshot_id) => {
+                // A snapshot is visible until it is replaced by a newer one,
+                // snapshots are visited from the newest to the oldest.
+                let mut replaced_on = None;
+                let table = self
+                    .find(snapshot_location, |snapshot| {
+                        let found = snapshot
+                            .snapshot_id
+                            .simple()
+                            .to_string()
+                            .as_str()
+                            .starts_with(snapshot_id.as_str());
+                        if !found {
+                            replaced_on = snapshot.timestamp;
+                        }
+                        found
+                    })
+                    .await?;
+                match (retention, replaced_on) {
+                    (Some((days, retention_point)), Some(replaced_on))
+                        if replaced_on < retention_point =>
+                    {
+                        Err(beyond_retention(days))
+                    }
+                    _ => Ok(table),
+                }
+            }
+            NavigationPoint::TimePoint(time_point) => match retention {
+                Some((days, retention_point)) if *time_point < retention_point => {
+                    Err(beyond_retention(days))
+                }
+                _ => {
+                    self.navigate_to_time_point(snapshot_location, *time_point)
+                        .await
+                }
+            },
+        }
+    }
+
     #[async_backtrace::framed]
     pub async fn navigate_to_time_point(
         &self,
@@ -15,6 +15,7 @@
 use std::collections::VecDeque;
 use std::sync::Arc;
 
+use common_arrow::arrow::bitmap::Bitmap;
 use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::InternalColumnMeta;
 use common_catalog::plan::PartInfoPtr;
@@ -275,6 +276,16 @@ pub fn adjust_threads_and_request(
     (max_threads, max_io_requests)
 }
 
+/// Returns the positions of the live rows in a block with a deletion vector.
+pub(crate) fn live_row_offsets(live_rows: &Bitmap) -> Vec<usize> {
+    live_rows
+        .iter()
+        .enumerate()
+        .filter(|(_, live)| *live)
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
 pub(crate) fn fill_internal_column_meta(
     data_block: DataBlock,
     fuse_part: &FusePartInfo,
@@ -289,6 +300,7 @@ pub(crate) fn fill_internal_column_meta(
         segment_location: block_meta.segment_location.clone(),
         snapshot_location: block_meta.snapshot_location.as_ref().unwrap().clone(),
         offsets,
+        search_scores: block_meta.search_scores.clone(),
     };
 
     let meta: Option<BlockMetaInfoPtr> = Some(Box::new(internal_column_meta));
@@ -16,6 +16,7 @@ use std::any::Any;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 
+use common_arrow::arrow::bitmap::Bitmap;
 use common_catalog::plan::PartInfoPtr;
 use common_expression::BlockMetaInfo;
 use common_expression::BlockMetaInfoPtr;
@@ -26,7 +27,8 @@ use crate::io::NativeSourceData;
 
 pub enum DataSource {
     AggIndex(NativeSourceData),
-    Normal(NativeSourceData),
+    /// The data of the block, and the live rows of it if the block has a deletion vector.
+    Normal((NativeSourceData, Option<Bitmap>)),
 }
 
 pub struct NativeDataSourceMeta {
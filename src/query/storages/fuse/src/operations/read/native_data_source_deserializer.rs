@@ -19,6 +19,8 @@ use std::collections::VecDeque;
 use std::sync::Arc;
 
 use common_arrow::arrow::array::Array;
+use common_arrow::arrow::bitmap::and;
+use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::bitmap::MutableBitmap;
 use common_arrow::native::read::ArrayIter;
 use common_arrow::parquet::metadata::ColumnDescriptor;
@@ -57,6 +59,7 @@ use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::processors::Processor;
 
 use super::fuse_source::fill_internal_column_meta;
+use super::fuse_source::live_row_offsets;
 use super::native_data_source::DataSource;
 use crate::fuse_part::FusePartInfo;
 use crate::io::AggIndexReader;
@@ -382,7 +385,7 @@ impl NativeDeserializeDataTransform {
                 if let Some((_, sorter, index)) = self.top_k.as_mut() {
                     if !self.array_iters.contains_key(index) {
                         let part = FusePartInfo::from_part(&self.parts[0])?;
-                        let num_rows = part.nums_rows;
+                        let num_rows = part.live_rows();
 
                         let data_type = self.src_schema.field(*index).data_type().clone();
                         let default_val = self.block_reader.default_vals[*index].clone();
@@ -410,12 +413,12 @@ impl NativeDeserializeDataTransform {
     }
 
     /// All columns are default values, not need to read.
-    fn finish_process_with_default_values(&mut self) -> Result<()> {
+    fn finish_process_with_default_values(&mut self, live_rows: Option<Bitmap>) -> Result<()> {
         let _ = self.chunks.pop_front();
         let part = self.parts.pop_front().unwrap();
         let fuse_part = FusePartInfo::from_part(&part)?;
 
-        let num_rows = fuse_part.nums_rows;
+        let num_rows = fuse_part.live_rows();
         let mut data_block = self.block_reader.build_default_values_block(num_rows)?;
         if let Some(ref virtual_columns) = &self.virtual_columns {
             for virtual_column in virtual_columns {
@@ -430,7 +433,8 @@ impl NativeDeserializeDataTransform {
         let data_block = if !self.block_reader.query_internal_columns() {
             data_block
         } else {
-            fill_internal_column_meta(data_block, fuse_part, None)?
+            let offsets = live_rows.as_ref().map(live_row_offsets);
+            fill_internal_column_meta(data_block, fuse_part, offsets)?
         };
         let data_block = data_block.resort(&self.src_schema, &self.output_schema)?;
         self.add_block(data_block)?;
@@ -443,17 +447,18 @@ impl NativeDeserializeDataTransform {
     }
 
     /// Empty projection use empty block.
-    fn finish_process_with_empty_block(&mut self) -> Result<()> {
+    fn finish_process_with_empty_block(&mut self, live_rows: Option<Bitmap>) -> Result<()> {
         let _ = self.chunks.pop_front();
         let part = self.parts.pop_front().unwrap();
         let fuse_part = FusePartInfo::from_part(&part)?;
 
-        let num_rows = fuse_part.nums_rows;
+        let num_rows = fuse_part.live_rows();
         let data_block = DataBlock::new(vec![], num_rows);
         let data_block = if !self.block_reader.query_internal_columns() {
             data_block
         } else {
-            fill_internal_column_meta(data_block, fuse_part, None)?
+            let offsets = live_rows.as_ref().map(live_row_offsets);
+            fill_internal_column_meta(data_block, fuse_part, offsets)?
         };
 
         self.add_block(data_block)?;
@@ -530,19 +535,19 @@ impl Processor for NativeDeserializeDataTransform {
 
     fn process(&mut self) -> Result<()> {
         if let Some(chunks) = self.chunks.front_mut() {
-            let chunks = match chunks {
+            let (chunks, live_rows) = match chunks {
                 DataSource::AggIndex(data) => {
                     let agg_index_reader = self.index_reader.as_ref().as_ref().unwrap();
                     let block = agg_index_reader.deserialize_native_data(data)?;
                     self.output_data = Some(block);
                     return self.finish_process();
                 }
-                DataSource::Normal(data) => data,
+                DataSource::Normal((data, live_rows)) => (data, live_rows.clone()),
             };
 
             // this means it's empty projection
             if chunks.is_empty() && !self.inited {
-                return self.finish_process_with_empty_block();
+                return self.finish_process_with_empty_block(live_rows);
             }
 
             // Init array_iters and array_skip_pages to read pages in subsequent processes.
@@ -603,7 +608,7 @@ impl Processor for NativeDeserializeDataTransform {
                 }
                 // No columns need to read, return default value directly.
                 if self.array_iters.is_empty() {
-                    return self.finish_process_with_default_values();
+                    return self.finish_process_with_default_values(live_rows);
                 }
             }
 
@@ -692,6 +697,18 @@ impl Processor for NativeDeserializeDataTransform {
                             .try_downcast::<BooleanType>()
                             .unwrap();
 
+                        // The rows deleted by the deletion vector of the block never match.
+                        let filter = match &live_rows {
+                            Some(live_rows) => {
+                                let num_rows = prewhere_block.num_rows();
+                                let bitmap = FilterHelpers::filter_to_bitmap(filter, num_rows);
+                                let live_rows =
+                                    live_rows.clone().sliced(self.offset_in_part, num_rows);
+                                Value::Column(and(&bitmap.into(), &live_rows))
+                            }
+                            None => filter,
+                        };
+
                         // Step 3: Apply the filter, if it's all filtered, we can skip the remain columns.
                         if FilterHelpers::is_all_unset(&filter) {
                             self.offset_in_part += prewhere_block.num_rows();
@@ -752,6 +769,15 @@ impl Processor for NativeDeserializeDataTransform {
 
             let block = self.block_reader.build_block(arrays.clone(), None)?;
             let origin_num_rows = block.num_rows();
+            // Without prewhere filter, the rows deleted by the deletion vector are filtered here.
+            let filter = match (filter, &live_rows) {
+                (None, Some(live_rows)) => Some(Value::Column(
+                    live_rows
+                        .clone()
+                        .sliced(self.offset_in_part, origin_num_rows),
+                )),
+                (filter, _) => filter,
+            };
             let block = if let Some(filter) = &filter {
                 block.filter_boolean_value(filter)?
             } else {
@@ -104,8 +104,11 @@ impl SyncSource for ReadNativeDataSource<true> {
         match self.partitions.steal_one(self.id) {
             None => Ok(None),
             Some(part) => {
-                if let Some(index_reader) = self.index_reader.as_ref() {
-                    let fuse_part = FusePartInfo::from_part(&part)?;
+                let fuse_part = FusePartInfo::from_part(&part)?;
+                // The aggregating index is built on the whole block, deleted rows included.
+                if let (Some(index_reader), None) =
+                    (self.index_reader.as_ref(), &fuse_part.deletion_vector)
+                {
                     let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &fuse_part.location,
@@ -121,10 +124,18 @@ impl SyncSource for ReadNativeDataSource<true> {
                     }
                 }
 
+                let live_rows = match &fuse_part.deletion_vector {
+                    Some(dv) => Some(
+                        dv.sync_read_live_rows(&self.block_reader.operator, fuse_part.nums_rows)?,
+                    ),
+                    None => None,
+                };
+
                 Ok(Some(DataBlock::empty_with_meta(
-                    NativeDataSourceMeta::create(vec![part.clone()], vec![DataSource::Normal(
+                    NativeDataSourceMeta::create(vec![part.clone()], vec![DataSource::Normal((
                         self.block_reader.sync_read_native_columns_data(part)?,
-                    )]),
+                        live_rows,
+                    ))]),
                 )))
             }
         }
@@ -178,7 +189,10 @@ impl Processor for ReadNativeDataSource<false> {
                 chunks.push(async move {
                     let handler = tokio::spawn(async_backtrace::location!().frame(async move {
                         let fuse_part = FusePartInfo::from_part(&part)?;
-                        if let Some(index_reader) = index_reader.as_ref() {
+                        // The aggregating index is built on the whole block, deleted rows included.
+                        if let (Some(index_reader), None) =
+                            (index_reader.as_ref(), &fuse_part.deletion_vector)
+                        {
                             let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &fuse_part.location,
@@ -190,9 +204,18 @@ impl Processor for ReadNativeDataSource<false> {
                             }
                         }
 
-                        Ok(DataSource::Normal(
+                        let live_rows = match &fuse_part.deletion_vector {
+                            Some(dv) => Some(
+                                dv.read_live_rows(&block_reader.operator, fuse_part.nums_rows)
+                                    .await?,
+                            ),
+                            None => None,
+                        };
+
+                        Ok(DataSource::Normal((
                             block_reader.async_read_native_columns_data(part).await?,
-                        ))
+                            live_rows,
+                        )))
                     }));
                     handler.await.unwrap()
                 });
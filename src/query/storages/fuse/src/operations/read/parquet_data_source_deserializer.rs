@@ -30,6 +30,7 @@ use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::processors::Processor;
 
 use super::fuse_source::fill_internal_column_meta;
+use super::fuse_source::live_row_offsets;
 use super::parquet_data_source::DataSource;
 use crate::fuse_part::FusePartInfo;
 use crate::io::AggIndexReader;
@@ -157,7 +158,7 @@ impl Processor for DeserializeDataTransform {
 
                     self.output_data = Some(block);
                 }
-                DataSource::Normal(data) => {
+                DataSource::Normal((data, live_rows)) => {
                     let start = Instant::now();
                     let columns_chunks = data.columns_chunks()?;
                     let part = FusePartInfo::from_part(&part)?;
@@ -178,6 +179,15 @@ impl Processor for DeserializeDataTransform {
                         );
                     }
 
+                    // Drop the rows deleted by the deletion vector of the block.
+                    let (data_block, offsets) = match live_rows {
+                        Some(live_rows) => (
+                            data_block.filter_with_bitmap(&live_rows)?,
+                            Some(live_row_offsets(&live_rows)),
+                        ),
+                        None => (data_block, None),
+                    };
+
                     let progress_values = ProgressValues {
                         rows: data_block.num_rows(),
                         bytes: data_block.memory_size(),
@@ -187,7 +197,7 @@ impl Processor for DeserializeDataTransform {
                     // Fill `BlockMetaIndex` as `DataBlock.meta` if query internal columns,
                     // `FillInternalColumnProcessor` will generate internal columns using `BlockMetaIndex` in next pipeline.
                     if self.block_reader.query_internal_columns() {
-                        let data_block = fill_internal_column_meta(data_block, part, None)?;
+                        let data_block = fill_internal_column_meta(data_block, part, offsets)?;
                         self.output_data = Some(data_block);
                     } else {
                         self.output_data = Some(data_block);
@@ -96,8 +96,11 @@ impl SyncSource for ReadParquetDataSource<true> {
         match self.partitions.steal_one(self.id) {
             None => Ok(None),
             Some(part) => {
-                if let Some(index_reader) = self.index_reader.as_ref() {
-                    let fuse_part = FusePartInfo::from_part(&part)?;
+                let fuse_part = FusePartInfo::from_part(&part)?;
+                // The aggregating index is built on the whole block, deleted rows included.
+                if let (Some(index_reader), None) =
+                    (self.index_reader.as_ref(), &fuse_part.deletion_vector)
+                {
                     let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &fuse_part.location,
@@ -115,14 +118,22 @@ impl SyncSource for ReadParquetDataSource<true> {
                     }
                 }
 
+                let live_rows = match &fuse_part.deletion_vector {
+                    Some(dv) => Some(
+                        dv.sync_read_live_rows(&self.block_reader.operator, fuse_part.nums_rows)?,
+                    ),
+                    None => None,
+                };
+
                 Ok(Some(DataBlock::empty_with_meta(DataSourceMeta::create(
                     vec![part.clone()],
-                    vec![DataSource::Normal(
+                    vec![DataSource::Normal((
                         self.block_reader.sync_read_columns_data_by_merge_io(
                             &ReadSettings::from_ctx(&self.partitions.ctx)?,
                             part,
                         )?,
-                    )],
+                        live_rows,
+                    ))],
                 ))))
             }
         }
@@ -179,7 +190,10 @@ impl Processor for ReadParquetDataSource<false> {
                     tokio::spawn(async_backtrace::location!().frame(async move {
                         let part = FusePartInfo::from_part(&part)?;
 
-                        if let Some(index_reader) = index_reader.as_ref() {
+                        // The aggregating index is built on the whole block, deleted rows included.
+                        if let (Some(index_reader), None) =
+                            (index_reader.as_ref(), &part.deletion_vector)
+                        {
                             let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &part.location,
@@ -194,7 +208,15 @@ impl Processor for ReadParquetDataSource<false> {
                             }
                         }
 
-                        Ok(DataSource::Normal(
+                        let live_rows = match &part.deletion_vector {
+                            Some(dv) => Some(
+                                dv.read_live_rows(&block_reader.operator, part.nums_rows)
+                                    .await?,
+                            ),
+                            None => None,
+                        };
+
+                        Ok(DataSource::Normal((
                             block_reader
                                 .read_columns_data_by_merge_io(
                                     &settings,
@@ -202,7 +224,8 @@ impl Processor for ReadParquetDataSource<false> {
                                     &part.columns_meta,
                                 )
                                 .await?,
-                        ))
+                            live_rows,
+                        )))
                     }))
                     .await
                     .unwrap()
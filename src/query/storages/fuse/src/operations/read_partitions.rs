@@ -47,10 +47,12 @@ use storages_common_index::RangeIndex;
 use storages_common_pruner::BlockMetaIndex;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ColumnMeta;
+use storages_common_table_meta::meta::TableSnapshotStatistics;
 
 use crate::fuse_lazy_part::FuseLazyPartInfo;
 use crate::fuse_part::FusePartInfo;
 use crate::fuse_part::VirtualColumnMeta;
+use crate::io::DeletionVector;
 use crate::io::TableMetaLocationGenerator;
 use crate::pruning::FusePruner;
 use crate::pruning::SegmentLocation;
@@ -165,27 +167,30 @@ impl FuseTable {
             }
         }
 
-        let mut pruner = if !self.is_native() || self.cluster_key_meta.is_none() {
-            FusePruner::create(
-                &ctx,
-                dal.clone(),
-                table_info.schema(),
-                &push_downs,
-                self.bloom_index_cols(),
-            )?
-        } else {
-            let cluster_keys = self.cluster_keys(ctx.clone());
-
-            FusePruner::create_with_pages(
-                &ctx,
-                dal.clone(),
-                table_info.schema(),
-                &push_downs,
-                self.cluster_key_meta.clone(),
-                cluster_keys,
-                self.bloom_index_cols(),
-            )?
-        };
+        let (cluster_key_meta, cluster_keys) =
+            if !self.is_native() || self.cluster_key_meta.is_none() {
+                (None, vec![])
+            } else {
+                (
+                    self.cluster_key_meta.clone(),
+                    self.cluster_keys(ctx.clone()),
+                )
+            };
+        let partition_keys = self.partition_keys(ctx.clone())?;
+        let table_statistics = self.read_search_statistics(&push_downs).await?;
+
+        let mut pruner = FusePruner::create_with_pages(
+            &ctx,
+            dal.clone(),
+            table_info.schema(),
+            &push_downs,
+            cluster_key_meta,
+            cluster_keys,
+            partition_keys,
+            self.bloom_index_cols(),
+            self.ngram_index_cols(),
+            table_statistics,
+        )?;
 
         let block_metas = pruner.read_pruning(segments_location).await?;
         let pruning_stats = pruner.pruning_stats();
@@ -233,6 +238,21 @@ impl FuseTable {
         Ok(result)
     }
 
+    /// The table statistics with the BM25 statistics of the inverted indexes, which are
+    /// only needed to score the rows searched by the filter.
+    #[async_backtrace::framed]
+    async fn read_search_statistics(
+        &self,
+        push_downs: &Option<PushDownInfo>,
+    ) -> Result<Option<Arc<TableSnapshotStatistics>>> {
+        let has_filter = push_downs.as_ref().is_some_and(|p| p.filter.is_some());
+        if !has_filter || self.table_info.meta.indexes.is_empty() {
+            return Ok(None);
+        }
+        let snapshot = self.read_table_snapshot().await?;
+        self.read_table_snapshot_statistics(snapshot.as_ref()).await
+    }
+
     #[async_backtrace::framed]
     async fn get_virtual_columns_meta(
         &self,
@@ -447,7 +467,7 @@ impl FuseTable {
                 } else {
                     None
                 };
-            let rows = block_meta.row_count as usize;
+            let rows = block_meta.live_row_count() as usize;
             partitions.partitions.push(Self::all_columns_part(
                 schema,
                 block_meta_index,
@@ -506,7 +526,7 @@ impl FuseTable {
                 projection,
             ));
 
-            let rows = block_meta.row_count as usize;
+            let rows = block_meta.live_row_count() as usize;
 
             statistics.read_rows += rows;
             for column in &columns {
@@ -573,6 +593,7 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            DeletionVector::from_block_meta(meta),
         )
     }
 
@@ -617,6 +638,7 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            DeletionVector::from_block_meta(meta),
         )
     }
 }
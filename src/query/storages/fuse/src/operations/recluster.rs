@@ -24,6 +24,7 @@ use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::DataField;
 use common_expression::DataSchemaRefExt;
+use common_expression::Scalar;
 use common_expression::SortColumnDescription;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_transforms::processors::transforms::build_merge_sort_pipeline;
@@ -108,6 +109,7 @@ impl FuseTable {
             schema,
             &push_downs,
             self.bloom_index_cols(),
+            self.ngram_index_cols(),
         )?;
 
         let max_threads = ctx.get_settings().get_max_threads()? as usize;
@@ -116,23 +118,39 @@ impl FuseTable {
             let mut block_metas = pruner.read_pruning(chunk.to_vec()).await?;
             block_metas.truncate(MAX_BLOCK_COUNT);
 
-            let mut blocks_map: BTreeMap<i32, Vec<(BlockMetaIndex, Arc<BlockMeta>)>> =
-                BTreeMap::new();
+            // blocks of different partitions are never reclustered together,
+            // each round works on the blocks of a single partition.
+            let mut partitions: BTreeMap<
+                Vec<Scalar>,
+                BTreeMap<i32, Vec<(BlockMetaIndex, Arc<BlockMeta>)>>,
+            > = BTreeMap::new();
             block_metas.into_iter().for_each(|(idx, b)| {
                 if let Some(stats) = &b.cluster_stats {
                     if stats.cluster_key_id == default_cluster_key_id && stats.level >= 0 {
-                        blocks_map.entry(stats.level).or_default().push((
-                            BlockMetaIndex {
-                                segment_idx: idx.segment_idx,
-                                block_idx: idx.block_idx,
-                            },
-                            b,
-                        ));
+                        partitions
+                            .entry(b.partition.clone().unwrap_or_default())
+                            .or_default()
+                            .entry(stats.level)
+                            .or_default()
+                            .push((
+                                BlockMetaIndex {
+                                    segment_idx: idx.segment_idx,
+                                    block_idx: idx.block_idx,
+                                },
+                                b,
+                            ));
                     }
                 }
             });
 
-            if mutator.target_select(blocks_map).await? {
+            let mut selected = false;
+            for blocks_map in partitions.into_values() {
+                if mutator.target_select(blocks_map).await? {
+                    selected = true;
+                    break;
+                }
+            }
+            if selected {
                 break;
             }
         }
@@ -53,6 +53,7 @@ use crate::io::write_data;
 use crate::io::BlockBuilder;
 use crate::io::BlockReader;
 use crate::io::CompactSegmentInfoReader;
+use crate::io::DeletionVector;
 use crate::io::MetaReaders;
 use crate::io::ReadSettings;
 use crate::io::WriteSettings;
@@ -391,7 +392,7 @@ impl MergeIntoOperationAggregator {
                 let permit = aggregation_ctx.acquire_task_permit().await?;
                 let block_meta = segment_info.blocks[block_index].clone();
                 let aggregation_ctx = aggregation_ctx.clone();
-                num_rows_mutated += block_meta.row_count;
+                num_rows_mutated += block_meta.live_row_count();
                 let handle = io_runtime.spawn(async_backtrace::location!().frame({
                     async move {
                         let mutation_log_entry = aggregation_ctx
@@ -509,11 +510,11 @@ impl AggregationContext {
             .incr(&progress_values);
 
         // shortcut: whole block deletion
-        if delete_nums == block_meta.row_count as usize {
+        if delete_nums == block_meta.live_row_count() as usize {
             info!("whole block deletion");
             metrics_inc_replace_whole_block_deletion(1);
             // whole block deletion
-            // NOTE that if deletion marker is enabled, check the real meaning of `row_count`
+            // NOTE that rows deleted by the deletion vector are not counted here
             let mutation = MutationLogEntry::DeletedBlock {
                 index: BlockMetaIndex {
                     segment_idx: segment_index,
@@ -587,6 +588,12 @@ impl AggregationContext {
         if let Some(index_state) = serialized.bloom_index_state {
             write_data(index_state.data, &data_accessor, &index_state.location.0).await?;
         }
+        if let Some(index_state) = serialized.ngram_index_state {
+            write_data(index_state.data, &data_accessor, &index_state.location.0).await?;
+        }
+        if let Some(index_state) = serialized.inverted_index_state {
+            write_data(index_state.data, &data_accessor, &index_state.location.0).await?;
+        }
 
         // generate log
         let mutation = MutationLogEntry::Replaced {
@@ -669,7 +676,7 @@ impl AggregationContext {
         let storage_format = self.write_settings.storage_format;
         let block_meta_ptr = block_meta.clone();
         let reader = reader.clone();
-        GlobalIORuntime::instance()
+        let data_block = GlobalIORuntime::instance()
             .spawn_blocking(move || {
                 let column_chunks = merged_io_read_result.columns_chunks()?;
                 reader.deserialize_chunks(
@@ -681,7 +688,19 @@ impl AggregationContext {
                     &storage_format,
                 )
             })
-            .await
+            .await?;
+
+        // the rows deleted by the deletion vector of the block are dropped, the block is
+        // rewritten without them.
+        match DeletionVector::from_block_meta(block_meta) {
+            Some(dv) => {
+                let live_rows = dv
+                    .read_live_rows(&self.data_accessor, block_meta.row_count as usize)
+                    .await?;
+                data_block.filter_with_bitmap(&live_rows)
+            }
+            None => Ok(data_block),
+        }
     }
 }
 
@@ -30,8 +30,9 @@ impl FuseTable {
         navigation_descriptor: NavigationDescriptor,
     ) -> Result<()> {
         // 1. try navigate to the point
-        let table = self.navigate_to(&navigation_descriptor.point).await?;
-        let table_reverting_to = FuseTable::try_from_table(table.as_ref())?;
+        let table_reverting_to = self
+            .navigate_within_retention(ctx, &navigation_descriptor)
+            .await?;
         let table_info = table_reverting_to.get_table_info();
 
         // shortcut. if reverting to the same point, just return ok
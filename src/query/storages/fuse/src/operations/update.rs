@@ -18,6 +18,7 @@ use std::sync::Arc;
 use common_catalog::plan::Projection;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::NumberDataType;
 use common_expression::FieldIndex;
@@ -37,6 +38,7 @@ use crate::operations::common::TransformSerializeBlock;
 use crate::operations::mutation::MutationAction;
 use crate::operations::mutation::MutationSource;
 use crate::pipelines::Pipeline;
+use crate::FuseDeleteMode;
 use crate::FuseTable;
 
 impl FuseTable {
@@ -53,6 +55,23 @@ impl FuseTable {
         query_row_id_col: bool,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
+        // The rows of a block must stay in the same partition.
+        let schema = self.schema();
+        for key in self.partition_keys(ctx.clone())? {
+            let key = key.as_expr(&BUILTIN_FUNCTIONS);
+            for (name, _) in key.column_refs() {
+                if update_list
+                    .iter()
+                    .any(|(index, _)| schema.field(*index).name() == &name)
+                {
+                    return Err(ErrorCode::InvalidPartitionKeys(format!(
+                        "Column '{}' is referenced by the partition key and can not be updated",
+                        name
+                    )));
+                }
+            }
+        }
+
         let snapshot_opt = self.read_table_snapshot().await?;
 
         // check if table is empty
@@ -280,6 +299,9 @@ impl FuseTable {
                         ops.clone(),
                         self.storage_format,
                         true,
+                        // update always rewrites the mutated blocks.
+                        FuseDeleteMode::CopyOnWrite,
+                        self.meta_location_generator.clone(),
                     )
                 },
                 max_threads,
@@ -22,6 +22,7 @@ use common_base::base::tokio::sync::OwnedSemaphorePermit;
 use common_catalog::plan::block_id_in_segment;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::F32;
 use common_expression::BLOCK_NAME_COL_NAME;
 use futures_util::future;
 use storages_common_pruner::BlockMetaIndex;
@@ -30,7 +31,6 @@ use storages_common_table_meta::meta::CompactSegmentInfo;
 
 use super::SegmentLocation;
 use crate::metrics::*;
-use crate::pruning::BloomPruner;
 use crate::pruning::PruningContext;
 
 pub struct BlockPruner {
@@ -48,9 +48,10 @@ impl BlockPruner {
         segment_location: SegmentLocation,
         segment_info: &CompactSegmentInfo,
     ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
-        if let Some(bloom_pruner) = &self.pruning_ctx.bloom_pruner {
-            self.block_pruning(bloom_pruner, segment_location, segment_info)
-                .await
+        if self.pruning_ctx.bloom_pruner.is_some()
+            || self.pruning_ctx.inverted_index_pruner.is_some()
+        {
+            self.block_pruning(segment_location, segment_info).await
         } else {
             // if no available filter pruners, just prune the blocks by
             // using zone map index, and do not spawn async tasks
@@ -58,11 +59,10 @@ impl BlockPruner {
         }
     }
 
-    // async pruning with bloom index.
+    // async pruning with bloom index and inverted index.
     #[async_backtrace::framed]
     async fn block_pruning(
         &self,
-        bloom_pruner: &Arc<dyn BloomPruner + Send + Sync>,
         segment_location: SegmentLocation,
         segment_info: &CompactSegmentInfo,
     ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
@@ -72,6 +72,8 @@ impl BlockPruner {
         let limit_pruner = self.pruning_ctx.limit_pruner.clone();
         let range_pruner = self.pruning_ctx.range_pruner.clone();
         let page_pruner = self.pruning_ctx.page_pruner.clone();
+        let bloom_pruner = self.pruning_ctx.bloom_pruner.clone();
+        let inverted_index_pruner = self.pruning_ctx.inverted_index_pruner.clone();
 
         let segment_block_metas = segment_info.block_metas()?;
 
@@ -95,8 +97,19 @@ impl BlockPruner {
                 return None;
             }
 
-            type BlockPruningFutureReturn =
-                Pin<Box<dyn Future<Output = (usize, bool, Option<Range<usize>>, String)> + Send>>;
+            type BlockPruningFutureReturn = Pin<
+                Box<
+                    dyn Future<
+                            Output = (
+                                usize,
+                                bool,
+                                Option<Range<usize>>,
+                                String,
+                                Option<Vec<(usize, F32)>>,
+                            ),
+                        > + Send,
+                >,
+            >;
             type BlockPruningFuture =
                 Box<dyn FnOnce(OwnedSemaphorePermit) -> BlockPruningFutureReturn + Send + 'static>;
 
@@ -111,7 +124,7 @@ impl BlockPruner {
                 }
 
                 let block_meta = block_meta.clone();
-                let row_count = block_meta.row_count;
+                let row_count = block_meta.live_row_count();
                 if range_pruner.should_keep(&block_meta.col_stats, Some(&block_meta.col_metas)) {
                     // Perf.
                     {
@@ -123,10 +136,15 @@ impl BlockPruner {
 
                     // not pruned by block zone map index,
                     let bloom_pruner = bloom_pruner.clone();
+                    let inverted_index_pruner = inverted_index_pruner.clone();
                     let limit_pruner = limit_pruner.clone();
                     let page_pruner = page_pruner.clone();
                     let index_location = block_meta.bloom_filter_index_location.clone();
                     let index_size = block_meta.bloom_filter_index_size;
+                    let ngram_index_location = block_meta.ngram_index_location.clone();
+                    let ngram_index_size = block_meta.ngram_index_size;
+                    let inverted_index_location = block_meta.inverted_index_location.clone();
+                    let inverted_index_size = block_meta.inverted_index_size;
                     let column_ids = block_meta.col_metas.keys().cloned().collect::<Vec<_>>();
 
                     let v: BlockPruningFuture = Box::new(move |permit: OwnedSemaphorePermit| {
@@ -140,10 +158,29 @@ impl BlockPruner {
                             }
 
                             let _permit = permit;
-                            let keep = bloom_pruner
-                                .should_keep(&index_location, index_size, column_ids)
-                                .await
-                                && limit_pruner.within_limit(row_count);
+                            let mut keep = match &bloom_pruner {
+                                Some(bloom_pruner) => {
+                                    bloom_pruner
+                                        .should_keep(
+                                            &index_location,
+                                            index_size,
+                                            &ngram_index_location,
+                                            ngram_index_size,
+                                            column_ids,
+                                        )
+                                        .await
+                                }
+                                None => true,
+                            };
+                            let mut search_scores = None;
+                            if keep {
+                                if let Some(inverted_index_pruner) = &inverted_index_pruner {
+                                    (keep, search_scores) = inverted_index_pruner
+                                        .should_keep(&inverted_index_location, inverted_index_size)
+                                        .await;
+                                }
+                            }
+                            let keep = keep && limit_pruner.within_limit(row_count);
 
                             if keep {
                                 // Perf.
@@ -158,9 +195,15 @@ impl BlockPruner {
 
                                 let (keep, range) =
                                     page_pruner.should_keep(&block_meta.cluster_stats);
-                                (block_idx, keep, range, block_meta.location.0.clone())
+                                (
+                                    block_idx,
+                                    keep,
+                                    range,
+                                    block_meta.location.0.clone(),
+                                    search_scores,
+                                )
                             } else {
-                                (block_idx, keep, None, block_meta.location.0.clone())
+                                (block_idx, keep, None, block_meta.location.0.clone(), None)
                             }
                         })
                     });
@@ -169,7 +212,7 @@ impl BlockPruner {
                     let v: BlockPruningFuture = Box::new(move |permit: OwnedSemaphorePermit| {
                         Box::pin(async move {
                             let _permit = permit;
-                            (block_idx, false, None, block_meta.location.0.clone())
+                            (block_idx, false, None, block_meta.location.0.clone(), None)
                         })
                     });
                     v
@@ -190,7 +233,7 @@ impl BlockPruner {
         let mut result = Vec::with_capacity(joint.len());
         let block_num = segment_info.summary.block_count as usize;
         for item in joint {
-            let (block_idx, keep, range, block_location) = item;
+            let (block_idx, keep, range, block_location, search_scores) = item;
             if keep {
                 let block = segment_block_metas[block_idx].clone();
 
@@ -206,6 +249,7 @@ impl BlockPruner {
                         block_location: block_location.clone(),
                         segment_location: segment_location.location.0.clone(),
                         snapshot_location: segment_location.snapshot_loc.clone(),
+                        search_scores,
                     },
                     block,
                 ))
@@ -260,7 +304,7 @@ impl BlockPruner {
             if limit_pruner.exceeded() {
                 break;
             }
-            let row_count = block_meta.row_count;
+            let row_count = block_meta.live_row_count();
             if range_pruner.should_keep(&block_meta.col_stats, Some(&block_meta.col_metas))
                 && limit_pruner.within_limit(row_count)
             {
@@ -284,6 +328,7 @@ impl BlockPruner {
                             block_location: block_meta.as_ref().location.0.clone(),
                             segment_location: segment_location.location.0.clone(),
                             snapshot_location: segment_location.snapshot_loc.clone(),
+                            search_scores: None,
                         },
                         block_meta.clone(),
                     ))
@@ -29,6 +29,7 @@ use log::warn;
 use opendal::Operator;
 use storages_common_index::BloomIndex;
 use storages_common_index::FilterEvalResult;
+use storages_common_index::NgramIndex;
 use storages_common_table_meta::meta::Location;
 
 use crate::io::BloomBlockFilterReader;
@@ -40,6 +41,8 @@ pub trait BloomPruner {
         &self,
         index_location: &Option<Location>,
         index_length: u64,
+        ngram_index_location: &Option<Location>,
+        ngram_index_length: u64,
         column_ids: Vec<ColumnId>,
     ) -> bool;
 }
@@ -50,6 +53,9 @@ pub struct BloomPrunerCreator {
     /// indices that should be loaded from filter block
     index_fields: Vec<TableField>,
 
+    /// indices that should be loaded from ngram filter block
+    ngram_index_fields: Vec<TableField>,
+
     /// the expression that would be evaluate
     filter_expression: Expr<String>,
 
@@ -70,6 +76,7 @@ impl BloomPrunerCreator {
         dal: Operator,
         filter_expr: Option<&Expr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: BloomIndexColumns,
     ) -> Result<Option<Arc<dyn BloomPruner + Send + Sync>>> {
         if let Some(expr) = filter_expr {
             let bloom_columns_map =
@@ -77,7 +84,12 @@ impl BloomPrunerCreator {
             let bloom_column_fields = bloom_columns_map.values().cloned().collect::<Vec<_>>();
             let point_query_cols = BloomIndex::find_eq_columns(expr, bloom_column_fields)?;
 
-            if !point_query_cols.is_empty() {
+            let ngram_columns_map =
+                ngram_index_cols.bloom_index_fields(schema.clone(), NgramIndex::supported_type)?;
+            let ngram_column_fields = ngram_columns_map.values().cloned().collect::<Vec<_>>();
+            let ngram_index_fields = NgramIndex::find_substring_columns(expr, ngram_column_fields)?;
+
+            if !point_query_cols.is_empty() || !ngram_index_fields.is_empty() {
                 // convert to filter column names
                 let mut filter_fields = Vec::with_capacity(point_query_cols.len());
                 let mut scalar_map = HashMap::<Scalar, u64>::new();
@@ -92,6 +104,7 @@ impl BloomPrunerCreator {
                 let creator = BloomPrunerCreator {
                     func_ctx,
                     index_fields: filter_fields,
+                    ngram_index_fields,
                     filter_expression: expr.clone(),
                     scalar_map,
                     dal,
@@ -149,6 +162,39 @@ impl BloomPrunerCreator {
             Err(e) => Err(e),
         }
     }
+
+    // Check a location file is hit or not by ngram filter.
+    #[async_backtrace::framed]
+    pub async fn apply_ngram(
+        &self,
+        index_location: &Location,
+        index_length: u64,
+        column_ids_of_indexed_block: &[ColumnId],
+    ) -> Result<bool> {
+        // filter out columns that no longer exist in the indexed block
+        let index_columns = self
+            .ngram_index_fields
+            .iter()
+            .filter(|field| column_ids_of_indexed_block.contains(&field.column_id()))
+            .map(NgramIndex::build_filter_column_name)
+            .collect::<Vec<_>>();
+        if index_columns.is_empty() {
+            return Ok(true);
+        }
+
+        // load the relevant index columns
+        let filter = index_location
+            .read_block_filter(self.dal.clone(), &index_columns, index_length)
+            .await?;
+
+        Ok(NgramIndex::from_filter_block(
+            self.func_ctx.clone(),
+            filter.filter_schema,
+            filter.filters,
+        )
+        .apply(self.filter_expression.clone(), self.data_schema.clone())?
+            != FilterEvalResult::MustFalse)
+    }
 }
 
 #[async_trait::async_trait]
@@ -158,20 +204,35 @@ impl BloomPruner for BloomPrunerCreator {
         &self,
         index_location: &Option<Location>,
         index_length: u64,
+        ngram_index_location: &Option<Location>,
+        ngram_index_length: u64,
         column_ids: Vec<ColumnId>,
     ) -> bool {
         if let Some(loc) = index_location {
-            // load filter, and try pruning according to filter expression
-            match self.apply(loc, index_length, column_ids).await {
-                Ok(v) => v,
-                Err(e) => {
-                    // swallow exceptions intentionally, corrupted index should not prevent execution
-                    warn!("failed to apply bloom pruner, returning true. {}", e);
-                    true
+            if !self.index_fields.is_empty() {
+                // load filter, and try pruning according to filter expression
+                match self.apply(loc, index_length, column_ids.clone()).await {
+                    Ok(false) => return false,
+                    Ok(true) => {}
+                    Err(e) => {
+                        // swallow exceptions intentionally, corrupted index should not prevent execution
+                        warn!("failed to apply bloom pruner, returning true. {}", e);
+                    }
+                }
+            }
+        }
+
+        if let Some(loc) = ngram_index_location {
+            if !self.ngram_index_fields.is_empty() {
+                match self.apply_ngram(loc, ngram_index_length, &column_ids).await {
+                    Ok(v) => return v,
+                    Err(e) => {
+                        warn!("failed to apply ngram pruner, returning true. {}", e);
+                    }
                 }
             }
-        } else {
-            true
         }
+
+        true
     }
 }
@@ -48,12 +48,15 @@ use storages_common_table_meta::meta::ColumnStatistics;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::Statistics;
 use storages_common_table_meta::meta::StatisticsOfColumns;
+use storages_common_table_meta::meta::TableSnapshotStatistics;
 
 use crate::pruning::segment_pruner::SegmentPruner;
 use crate::pruning::BlockPruner;
 use crate::pruning::BloomPruner;
 use crate::pruning::BloomPrunerCreator;
 use crate::pruning::FusePruningStatistics;
+use crate::pruning::InvertedIndexPruner;
+use crate::pruning::PartitionPruner;
 use crate::pruning::SegmentLocation;
 
 pub struct PruningContext {
@@ -65,6 +68,8 @@ pub struct PruningContext {
     pub limit_pruner: Arc<dyn Limiter + Send + Sync>,
     pub range_pruner: Arc<dyn RangePruner + Send + Sync>,
     pub bloom_pruner: Option<Arc<dyn BloomPruner + Send + Sync>>,
+    pub inverted_index_pruner: Option<Arc<InvertedIndexPruner>>,
+    pub partition_pruner: Option<Arc<PartitionPruner>>,
     pub page_pruner: Arc<dyn PagePruner + Send + Sync>,
     pub internal_column_pruner: Option<Arc<InternalColumnPruner>>,
 
@@ -104,6 +109,7 @@ impl FusePruner {
         table_schema: TableSchemaRef,
         push_down: &Option<PushDownInfo>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: BloomIndexColumns,
     ) -> Result<Self> {
         Self::create_with_pages(
             ctx,
@@ -112,11 +118,15 @@ impl FusePruner {
             push_down,
             None,
             vec![],
+            vec![],
             bloom_index_cols,
+            ngram_index_cols,
+            None,
         )
     }
 
     // Create fuse pruner with pages.
+    #[allow(clippy::too_many_arguments)]
     pub fn create_with_pages(
         ctx: &Arc<dyn TableContext>,
         dal: Operator,
@@ -124,7 +134,10 @@ impl FusePruner {
         push_down: &Option<PushDownInfo>,
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
+        partition_keys: Vec<RemoteExpr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: BloomIndexColumns,
+        table_statistics: Option<Arc<TableSnapshotStatistics>>,
     ) -> Result<Self> {
         let func_ctx = ctx.get_function_context()?;
 
@@ -173,8 +186,22 @@ impl FusePruner {
             dal.clone(),
             filter_expr.as_ref(),
             bloom_index_cols,
+            ngram_index_cols,
         )?;
 
+        // Inverted index pruner, if there are `match` predicates.
+        let inverted_index_pruner = InvertedIndexPruner::try_create(
+            func_ctx.clone(),
+            &table_schema,
+            dal.clone(),
+            filter_expr.as_ref(),
+            table_statistics,
+        )?;
+
+        // Partition pruner, if the table is partitioned and the filter refers to the partition key.
+        let partition_pruner =
+            PartitionPruner::try_create(func_ctx.clone(), filter_expr.as_ref(), &partition_keys);
+
         // Page pruner, used in native format
         let page_pruner = PagePrunerCreator::try_create(
             func_ctx.clone(),
@@ -220,6 +247,8 @@ impl FusePruner {
             limit_pruner,
             range_pruner,
             bloom_pruner,
+            inverted_index_pruner,
+            partition_pruner,
             page_pruner,
             internal_column_pruner,
             pruning_stats,
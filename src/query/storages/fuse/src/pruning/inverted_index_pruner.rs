@@ -0,0 +1,120 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::F32;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::TableSchemaRef;
+use log::warn;
+use opendal::Operator;
+use storages_common_index::FilterEvalResult;
+use storages_common_index::InvertedIndex;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::TableSnapshotStatistics;
+
+use crate::io::InvertedIndexReader;
+
+/// Prunes the blocks by the `match` predicates of the filter, using the inverted index
+/// of each block, and scores the matched rows of the kept blocks with the statistics
+/// of the table collected by `ANALYZE TABLE`.
+pub struct InvertedIndexPruner {
+    func_ctx: FunctionContext,
+
+    /// the expression that would be evaluate
+    filter_expression: Expr<String>,
+
+    /// the data accessor
+    dal: Operator,
+
+    /// the schema of data being indexed
+    data_schema: TableSchemaRef,
+
+    /// the statistics of the table, with the BM25 statistics of the index columns
+    table_statistics: Option<Arc<TableSnapshotStatistics>>,
+}
+
+impl InvertedIndexPruner {
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        schema: &TableSchemaRef,
+        dal: Operator,
+        filter_expr: Option<&Expr<String>>,
+        table_statistics: Option<Arc<TableSnapshotStatistics>>,
+    ) -> Result<Option<Arc<InvertedIndexPruner>>> {
+        if let Some(expr) = filter_expr {
+            let match_columns = InvertedIndex::find_match_columns(expr, schema.fields().clone())?;
+            if !match_columns.is_empty() {
+                return Ok(Some(Arc::new(InvertedIndexPruner {
+                    func_ctx,
+                    filter_expression: expr.clone(),
+                    dal,
+                    data_schema: schema.clone(),
+                    table_statistics,
+                })));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns false if the block should be pruned. Otherwise the offsets and the
+    /// BM25 scores of the matched rows are also returned, if they are known.
+    #[async_backtrace::framed]
+    pub async fn should_keep(
+        &self,
+        index_location: &Option<Location>,
+        index_length: u64,
+    ) -> (bool, Option<Vec<(usize, F32)>>) {
+        let Some(loc) = index_location else {
+            return (true, None);
+        };
+        match self.apply(loc, index_length).await {
+            Ok(v) => v,
+            Err(e) => {
+                // swallow exceptions intentionally, corrupted index should not prevent execution
+                warn!(
+                    "failed to apply inverted index pruner, returning true. {}",
+                    e
+                );
+                (true, None)
+            }
+        }
+    }
+
+    async fn apply(
+        &self,
+        index_location: &Location,
+        index_length: u64,
+    ) -> Result<(bool, Option<Vec<(usize, F32)>>)> {
+        let index = index_location
+            .read_inverted_index(self.dal.clone(), self.func_ctx.clone(), index_length)
+            .await?;
+
+        if index.apply(self.filter_expression.clone(), self.data_schema.clone())?
+            == FilterEvalResult::MustFalse
+        {
+            return Ok((false, None));
+        }
+        let scores = index.search_scores(
+            &self.filter_expression,
+            self.data_schema.clone(),
+            self.table_statistics
+                .as_ref()
+                .map(|stats| stats.get_inverted_index_statistics()),
+        )?;
+        Ok((true, scores))
+    }
+}
@@ -15,6 +15,8 @@
 mod block_pruner;
 mod bloom_pruner;
 mod fuse_pruner;
+mod inverted_index_pruner;
+mod partition_pruner;
 mod pruner_location;
 mod pruning_statistics;
 mod segment_pruner;
@@ -25,6 +27,8 @@ pub use bloom_pruner::BloomPrunerCreator;
 pub use fuse_pruner::DeletedSegmentInfo;
 pub use fuse_pruner::FusePruner;
 pub use fuse_pruner::PruningContext;
+pub use inverted_index_pruner::InvertedIndexPruner;
+pub use partition_pruner::PartitionPruner;
 pub use pruner_location::create_segment_location_vector;
 pub use pruner_location::SegmentLocation;
 pub use pruning_statistics::FusePruningStatistics;
@@ -0,0 +1,186 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_expression::ConstantFolder;
+use common_expression::Domain;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::RemoteExpr;
+use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
+
+/// Prunes the segments of a partitioned table by their partition value.
+///
+/// The occurrences of the partition key expressions in the filter are replaced by the
+/// partition value of the segment, a segment is pruned if the filter folds to false.
+pub struct PartitionPruner {
+    func_ctx: FunctionContext,
+    filter_expression: Expr<String>,
+    partition_keys: Vec<Expr<String>>,
+    /// full domains of the columns not covered by the partition keys
+    input_domains: HashMap<String, Domain>,
+}
+
+impl PartitionPruner {
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        filter_expr: Option<&Expr<String>>,
+        partition_keys: &[RemoteExpr<String>],
+    ) -> Option<Arc<PartitionPruner>> {
+        let filter_expr = filter_expr?;
+        let partition_keys: Vec<Expr<String>> = partition_keys
+            .iter()
+            .map(|key| key.as_expr(&BUILTIN_FUNCTIONS))
+            .collect();
+        if !partition_keys
+            .iter()
+            .any(|key| contains_expr(filter_expr, key))
+        {
+            return None;
+        }
+
+        let input_domains = filter_expr
+            .column_refs()
+            .into_iter()
+            .map(|(name, data_type)| (name, Domain::full(&data_type)))
+            .collect();
+        Some(Arc::new(PartitionPruner {
+            func_ctx,
+            filter_expression: filter_expr.clone(),
+            partition_keys,
+            input_domains,
+        }))
+    }
+
+    pub fn should_keep(&self, partition: &Option<Vec<Scalar>>) -> bool {
+        let Some(partition) = partition else {
+            return true;
+        };
+        if partition.len() != self.partition_keys.len() {
+            return true;
+        }
+
+        let expr = self.substitute(&self.filter_expression, partition);
+        let (folded, _) = ConstantFolder::fold_with_domain(
+            &expr,
+            &self.input_domains,
+            &self.func_ctx,
+            &BUILTIN_FUNCTIONS,
+        );
+        !matches!(folded, Expr::Constant {
+            scalar: Scalar::Boolean(false) | Scalar::Null,
+            ..
+        })
+    }
+
+    fn substitute(&self, expr: &Expr<String>, partition: &[Scalar]) -> Expr<String> {
+        if let Some(pos) = self
+            .partition_keys
+            .iter()
+            .position(|key| same_expr(key, expr))
+        {
+            return Expr::Constant {
+                span: None,
+                scalar: partition[pos].clone(),
+                data_type: expr.data_type().clone(),
+            };
+        }
+
+        match expr {
+            Expr::Cast {
+                span,
+                is_try,
+                expr,
+                dest_type,
+            } => Expr::Cast {
+                span: *span,
+                is_try: *is_try,
+                expr: Box::new(self.substitute(expr, partition)),
+                dest_type: dest_type.clone(),
+            },
+            Expr::FunctionCall {
+                span,
+                id,
+                function,
+                generics,
+                args,
+                return_type,
+            } => Expr::FunctionCall {
+                span: *span,
+                id: id.clone(),
+                function: function.clone(),
+                generics: generics.clone(),
+                args: args
+                    .iter()
+                    .map(|arg| self.substitute(arg, partition))
+                    .collect(),
+                return_type: return_type.clone(),
+            },
+            _ => expr.clone(),
+        }
+    }
+}
+
+fn contains_expr(expr: &Expr<String>, target: &Expr<String>) -> bool {
+    if same_expr(expr, target) {
+        return true;
+    }
+    match expr {
+        Expr::Cast { expr, .. } => contains_expr(expr, target),
+        Expr::FunctionCall { args, .. } => args.iter().any(|arg| contains_expr(arg, target)),
+        _ => false,
+    }
+}
+
+// Compare two expressions, ignoring the display names of the columns.
+fn same_expr(l: &Expr<String>, r: &Expr<String>) -> bool {
+    match (l, r) {
+        (Expr::Constant { scalar: l, .. }, Expr::Constant { scalar: r, .. }) => l == r,
+        (Expr::ColumnRef { id: l, .. }, Expr::ColumnRef { id: r, .. }) => l == r,
+        (
+            Expr::Cast {
+                is_try: l_try,
+                expr: l,
+                dest_type: l_type,
+                ..
+            },
+            Expr::Cast {
+                is_try: r_try,
+                expr: r,
+                dest_type: r_type,
+                ..
+            },
+        ) => l_try == r_try && l_type == r_type && same_expr(l, r),
+        (
+            Expr::FunctionCall {
+                id: l_id,
+                args: l_args,
+                ..
+            },
+            Expr::FunctionCall {
+                id: r_id,
+                args: r_args,
+                ..
+            },
+        ) => {
+            l_id == r_id
+                && l_args.len() == r_args.len()
+                && l_args.iter().zip(r_args).all(|(l, r)| same_expr(l, r))
+        }
+        _ => false,
+    }
+}
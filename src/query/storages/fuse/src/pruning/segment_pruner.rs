@@ -56,6 +56,7 @@ impl SegmentPruner {
 
         let pruning_stats = self.pruning_ctx.pruning_stats.clone();
         let range_pruner = self.pruning_ctx.range_pruner.clone();
+        let partition_pruner = self.pruning_ctx.partition_pruner.clone();
 
         for segment_location in segment_locs {
             let info = self.read_segment_info(&segment_location).await?;
@@ -69,7 +70,11 @@ impl SegmentPruner {
                 pruning_stats.set_segments_range_pruning_before(1);
             }
 
-            if range_pruner.should_keep(&info.summary.col_stats, None) {
+            if range_pruner.should_keep(&info.summary.col_stats, None)
+                && partition_pruner
+                    .as_ref()
+                    .map_or(true, |p| p.should_keep(&info.summary.partition))
+            {
                 // Perf.
                 {
                     metrics_inc_segments_range_pruning_after(1);
@@ -14,7 +14,10 @@
 
 use std::collections::HashMap;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::geometry::column_bbox;
+use common_expression::types::DataType;
 use common_expression::types::NumberType;
 use common_expression::types::ValueType;
 use common_expression::Column;
@@ -50,6 +53,13 @@ pub fn gen_columns_statistics(
     let leaves = get_traverse_columns_dfs(&data_block)?;
     let leaf_column_ids = schema.to_leaf_column_ids();
     for ((col_idx, col, data_type), column_id) in leaves.iter().zip(leaf_column_ids) {
+        if data_type.remove_nullable() == DataType::Geometry {
+            if let Some(col_stats) = gen_geometry_statistics(col, rows)? {
+                statistics.insert(column_id, col_stats);
+            }
+            continue;
+        }
+
         // Ignore the range index does not supported type.
         if !RangeIndex::supported_type(data_type) {
             continue;
@@ -124,6 +134,27 @@ pub fn gen_columns_statistics(
     Ok(statistics)
 }
 
+/// The statistics of geometry keep the bounding box of the column, see [`GeometryDomain`].
+fn gen_geometry_statistics(col: &Column, rows: usize) -> Result<Option<ColumnStatistics>> {
+    let (geometries, unset_bits) = match col {
+        Column::Geometry(c) => (c, 0),
+        Column::Nullable(c) => (c.column.as_geometry().unwrap(), c.validity.unset_bits()),
+        _ => return Ok(None),
+    };
+    let Some(bbox) = column_bbox(geometries) else {
+        return Ok(None);
+    };
+    let (min, max) = bbox.to_statistics().map_err(ErrorCode::Internal)?;
+    let distinct_of_values = calc_column_distinct_of_values(col, rows)?;
+    Ok(Some(ColumnStatistics::new(
+        min,
+        max,
+        unset_bits as u64,
+        col.memory_size() as u64,
+        Some(distinct_of_values),
+    )))
+}
+
 pub mod traverse {
     use common_expression::types::map::KvPair;
     use common_expression::types::AnyType;
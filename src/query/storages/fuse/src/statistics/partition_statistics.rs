@@ -0,0 +1,101 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::AnyType;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+
+/// Evaluates the partition key of a partitioned table over the blocks being written.
+#[derive(Clone, Default)]
+pub struct PartitionGenerator {
+    exprs: Vec<Expr>,
+    func_ctx: FunctionContext,
+}
+
+impl PartitionGenerator {
+    pub fn new(exprs: Vec<Expr>, func_ctx: FunctionContext) -> Self {
+        Self { exprs, func_ctx }
+    }
+
+    pub fn is_partitioned(&self) -> bool {
+        !self.exprs.is_empty()
+    }
+
+    /// Split the block into blocks holding a single partition each.
+    pub fn split(&self, data_block: DataBlock) -> Result<Vec<DataBlock>> {
+        if !self.is_partitioned() || data_block.num_rows() == 0 {
+            return Ok(vec![data_block]);
+        }
+
+        let values = self.evaluate(&data_block)?;
+        let mut partitions: BTreeMap<Vec<Scalar>, Vec<u32>> = BTreeMap::new();
+        for row in 0..data_block.num_rows() {
+            partitions
+                .entry(Self::partition_at(&values, row))
+                .or_default()
+                .push(row as u32);
+        }
+
+        if partitions.len() == 1 {
+            return Ok(vec![data_block]);
+        }
+        partitions
+            .values()
+            .map(|indices| data_block.take(indices))
+            .collect()
+    }
+
+    /// The partition value of a block, which must have been split by [`Self::split`].
+    pub fn partition_of(&self, data_block: &DataBlock) -> Result<Option<Vec<Scalar>>> {
+        if !self.is_partitioned() || data_block.num_rows() == 0 {
+            return Ok(None);
+        }
+
+        let values = self.evaluate(data_block)?;
+        let partition = Self::partition_at(&values, 0);
+        for row in 1..data_block.num_rows() {
+            let same = values
+                .iter()
+                .zip(partition.iter())
+                .all(|(value, scalar)| value.index(row) == Some(scalar.as_ref()));
+            if !same {
+                return Err(ErrorCode::Internal(
+                    "data block spans more than one partition, it's a bug",
+                ));
+            }
+        }
+        Ok(Some(partition))
+    }
+
+    fn evaluate(&self, data_block: &DataBlock) -> Result<Vec<Value<AnyType>>> {
+        let evaluator = Evaluator::new(data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        self.exprs.iter().map(|expr| evaluator.run(expr)).collect()
+    }
+
+    fn partition_at(values: &[Value<AnyType>], row: usize) -> Vec<Scalar> {
+        values
+            .iter()
+            .map(|value| value.index(row).unwrap().to_owned())
+            .collect()
+    }
+}
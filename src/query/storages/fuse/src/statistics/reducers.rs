@@ -15,6 +15,7 @@
 use std::borrow::Borrow;
 use std::collections::HashMap;
 
+use common_expression::types::geometry::GeometryDomain;
 use common_expression::BlockThresholds;
 use common_expression::ColumnId;
 use common_expression::Scalar;
@@ -60,6 +61,15 @@ pub fn reduce_block_statistics<T: Borrow<StatisticsOfColumns>>(
                 in_memory_size += col_stats.in_memory_size;
             }
 
+            if let Some(Scalar::Geometry(_)) = min_stats.iter().find(|s| !s.is_null()) {
+                let (min, max) = reduce_geometry_statistics(&min_stats, &max_stats);
+                acc.insert(
+                    *id,
+                    ColumnStatistics::new(min, max, null_count, in_memory_size, None),
+                );
+                return acc;
+            }
+
             let min = min_stats
                 .into_iter()
                 .filter(|s| !s.is_null())
@@ -80,6 +90,24 @@ pub fn reduce_block_statistics<T: Borrow<StatisticsOfColumns>>(
         })
 }
 
+/// Merge the bounding boxes kept in the statistics of geometry columns.
+fn reduce_geometry_statistics(min_stats: &[Scalar], max_stats: &[Scalar]) -> (Scalar, Scalar) {
+    let bbox = min_stats
+        .iter()
+        .zip(max_stats.iter())
+        .filter_map(|(min, max)| match (min, max) {
+            (Scalar::Geometry(min), Scalar::Geometry(max)) => {
+                GeometryDomain::from_statistics(min, max)
+            }
+            _ => None,
+        })
+        .reduce(|a, b| a.merge(&b));
+    match bbox.map(|bbox| bbox.to_statistics()) {
+        Some(Ok((min, max))) => (min, max),
+        _ => (Scalar::Null, Scalar::Null),
+    }
+}
+
 pub fn reduce_cluster_statistics<T: Borrow<Option<ClusterStatistics>>>(
     blocks_cluster_stats: &[T],
     default_cluster_key_id: Option<u32>,
@@ -139,12 +167,14 @@ pub fn merge_statistics_mut(
     if l.row_count == 0 {
         l.col_stats = r.col_stats.clone();
         l.cluster_stats = r.cluster_stats.clone();
+        l.partition = r.partition.clone();
     } else {
         l.col_stats = reduce_block_statistics(&[&l.col_stats, &r.col_stats]);
         l.cluster_stats = reduce_cluster_statistics(
             &[&l.cluster_stats, &r.cluster_stats],
             default_cluster_key_id,
         );
+        l.partition = reduce_partition(&[&l.partition, &r.partition]);
     }
 
     l.row_count += r.row_count;
@@ -195,19 +225,24 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
     let len = block_metas.len();
     let mut col_stats = Vec::with_capacity(len);
     let mut cluster_stats = Vec::with_capacity(len);
+    let mut partitions = Vec::with_capacity(len);
 
     block_metas.iter().for_each(|b| {
         let b = b.borrow();
-        row_count += b.row_count;
+        row_count += b.live_row_count();
         block_count += 1;
         uncompressed_byte_size += b.block_size;
         compressed_byte_size += b.file_size;
         index_size += b.bloom_filter_index_size;
-        if thresholds.check_large_enough(b.row_count as usize, b.block_size as usize) {
+        // a block carrying a deletion vector still needs to be compacted
+        if b.deleted_row_count == 0
+            && thresholds.check_large_enough(b.row_count as usize, b.block_size as usize)
+        {
             perfect_block_count += 1;
         }
         col_stats.push(&b.col_stats);
         cluster_stats.push(&b.cluster_stats);
+        partitions.push(&b.partition);
     });
 
     let merged_col_stats = reduce_block_statistics(&col_stats);
@@ -222,5 +257,16 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
         index_size,
         col_stats: merged_col_stats,
         cluster_stats: merged_cluster_stats,
+        partition: reduce_partition(&partitions),
+    }
+}
+
+/// The partition value is kept only if all the inputs belong to the same partition.
+pub fn reduce_partition(partitions: &[&Option<Vec<Scalar>>]) -> Option<Vec<Scalar>> {
+    let (first, rest) = partitions.split_first()?;
+    if rest.iter().all(|p| p == first) {
+        (*first).clone()
+    } else {
+        None
     }
 }
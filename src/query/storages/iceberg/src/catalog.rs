@@ -60,6 +60,8 @@ use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
 use common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use common_meta_app::schema::SetTableRowAccessPolicyReply;
+use common_meta_app::schema::SetTableRowAccessPolicyReq;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
@@ -69,27 +71,45 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateDatabaseCommentReply;
+use common_meta_app::schema::UpdateDatabaseCommentReq;
 use common_meta_app::schema::UpdateIndexReply;
 use common_meta_app::schema::UpdateIndexReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReply;
 use common_meta_app::schema::UpdateVirtualColumnReq;
+use common_meta_app::schema::UpsertDatabaseOptionReply;
+use common_meta_app::schema::UpsertDatabaseOptionReq;
 use common_meta_app::schema::UpsertTableOptionReply;
 use common_meta_app::schema::UpsertTableOptionReq;
 use common_meta_app::schema::VirtualColumnMeta;
+use common_meta_kvapi::kvapi;
+use common_meta_types::MetaError;
 use common_meta_types::MetaId;
 use common_storage::DataOperator;
 use futures::TryStreamExt;
 use opendal::Metakey;
 
+use crate::commit::CommitClaimer;
 use crate::database::IcebergDatabase;
 use crate::table::IcebergTable;
 
 pub const ICEBERG_CATALOG: &str = "iceberg";
 
 #[derive(Debug)]
-pub struct IcebergCreator;
+pub struct IcebergCreator {
+    claimer: CommitClaimer,
+}
+
+impl IcebergCreator {
+    /// The commits of the iceberg tables are coordinated through `kv_api`.
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>) -> Self {
+        Self {
+            claimer: CommitClaimer::create(kv_api),
+        }
+    }
+}
 
 impl CatalogCreator for IcebergCreator {
     fn try_create(&self, info: &CatalogInfo) -> Result<Arc<dyn Catalog>> {
@@ -101,8 +121,11 @@ impl CatalogCreator for IcebergCreator {
         };
 
         let data_operator = DataOperator::try_new(&opt.storage_params)?;
-        let catalog: Arc<dyn Catalog> =
-            Arc::new(IcebergCatalog::try_create(info.clone(), data_operator)?);
+        let catalog: Arc<dyn Catalog> = Arc::new(IcebergCatalog::try_create(
+            info.clone(),
+            data_operator,
+            self.claimer.clone(),
+        )?);
 
         Ok(catalog)
     }
@@ -121,6 +144,9 @@ pub struct IcebergCatalog {
 
     /// underlying storage access operator
     operator: DataOperator,
+
+    /// claims the versions of the tables on commit
+    claimer: CommitClaimer,
 }
 
 impl IcebergCatalog {
@@ -139,8 +165,16 @@ impl IcebergCatalog {
     /// Such catalog will be seen as an `flatten` catalogs,
     /// a `default` database will be generated directly
     #[minitrace::trace]
-    pub fn try_create(info: CatalogInfo, operator: DataOperator) -> Result<Self> {
-        Ok(Self { info, operator })
+    pub fn try_create(
+        info: CatalogInfo,
+        operator: DataOperator,
+        claimer: CommitClaimer,
+    ) -> Result<Self> {
+        Ok(Self {
+            info,
+            operator,
+            claimer,
+        })
     }
 
     /// list read databases
@@ -200,6 +234,7 @@ impl Catalog for IcebergCatalog {
             &self.name(),
             db_name,
             db_root,
+            self.claimer.clone(),
         )))
     }
 
@@ -228,6 +263,22 @@ impl Catalog for IcebergCatalog {
         unimplemented!()
     }
 
+    #[async_backtrace::framed]
+    async fn update_database_comment(
+        &self,
+        _req: UpdateDatabaseCommentReq,
+    ) -> Result<UpdateDatabaseCommentReply> {
+        unimplemented!()
+    }
+
+    #[async_backtrace::framed]
+    async fn upsert_database_option(
+        &self,
+        _req: UpsertDatabaseOptionReq,
+    ) -> Result<UpsertDatabaseOptionReply> {
+        unimplemented!()
+    }
+
     fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
         let table_sp = table_info
             .meta
@@ -238,7 +289,7 @@ impl Catalog for IcebergCatalog {
             ))?;
 
         let op = DataOperator::try_new(&table_sp)?;
-        let table = IcebergTable::try_new(op, table_info.clone())?;
+        let table = IcebergTable::try_new(op, table_info.clone(), self.claimer.clone())?;
 
         Ok(Arc::new(table))
     }
@@ -337,6 +388,14 @@ impl Catalog for IcebergCatalog {
         unimplemented!()
     }
 
+    #[async_backtrace::framed]
+    async fn set_table_row_access_policy(
+        &self,
+        _req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply> {
+        unimplemented!()
+    }
+
     #[async_backtrace::framed]
     async fn count_tables(&self, _req: CountTablesReq) -> Result<CountTablesReply> {
         unimplemented!()
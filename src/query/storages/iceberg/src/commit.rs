@@ -0,0 +1,374 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use common_base::base::escape_for_key;
+use common_base::base::tokio::time::sleep;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::KVMeta;
+use common_meta_types::MatchSeq;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+use icelake::types::parse_manifest_list;
+use icelake::types::serialize_table_meta;
+use icelake::types::DataFile;
+use icelake::types::ManifestContentType;
+use icelake::types::ManifestEntry;
+use icelake::types::ManifestFile;
+use icelake::types::ManifestList;
+use icelake::types::ManifestListWriter;
+use icelake::types::ManifestMetadata;
+use icelake::types::ManifestStatus;
+use icelake::types::ManifestWriter;
+use icelake::types::Snapshot;
+use icelake::types::SnapshotLog;
+use icelake::types::TableMetadata;
+use log::info;
+use log::warn;
+use opendal::Operator;
+use uuid::Uuid;
+
+const MAX_COMMIT_RETRIES: usize = 10;
+const METADATA_DIR: &str = "metadata";
+/// Prefix of the meta service keys that claim the versions of the iceberg tables.
+const COMMIT_CLAIM_PREFIX: &str = "__fd_iceberg_commits";
+/// A claimed version is released after it, in case the claiming query crashed.
+const COMMIT_CLAIM_TTL_SECS: u64 = 300;
+
+/// Claims the versions of the iceberg tables in the meta service, it's injected
+/// by the catalog that the tables belong to.
+#[derive(Clone)]
+pub struct CommitClaimer {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+}
+
+impl CommitClaimer {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>) -> Self {
+        Self { kv_api }
+    }
+
+    // Claim the version by creating its key, returns the seq of the key if it is created.
+    async fn claim_version(&self, key: &str) -> Result<Option<u64>> {
+        let expire_at = SeqV::<()>::now_ms() / 1000 + COMMIT_CLAIM_TTL_SECS;
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(
+                key,
+                MatchSeq::Exact(0),
+                Operation::Update(vec![]),
+                Some(KVMeta {
+                    expire_at: Some(expire_at),
+                }),
+            ))
+            .await?;
+        Ok(match (res.prev, res.result) {
+            (None, Some(v)) => Some(v.seq),
+            _ => None,
+        })
+    }
+
+    // Release the claimed version if the metadata is not written, the error is only logged
+    // as the claim expires anyway.
+    async fn release_version(&self, key: &str, seq: u64) {
+        if let Err(e) = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(
+                key,
+                MatchSeq::Exact(seq),
+                Operation::Delete,
+                None,
+            ))
+            .await
+        {
+            warn!("failed to release iceberg metadata version {}: {}", key, e);
+        }
+    }
+}
+
+impl Debug for CommitClaimer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CommitClaimer").finish_non_exhaustive()
+    }
+}
+
+/// Commit the written data files as a new snapshot of the table.
+///
+/// Object storages can't create a file only if it does not exist, so the next version of
+/// the metadata is claimed in the meta service first, and only the query that claims it
+/// writes the metadata file. A query that loses the claim, or finds the version already
+/// written by a writer outside of databend, retries on top of the latest snapshot when
+/// appending, while overwriting gives up if the table has been changed since
+/// `base_snapshot_id` was read.
+#[async_backtrace::framed]
+pub async fn commit_data_files(
+    claimer: &CommitClaimer,
+    op: &Operator,
+    data_files: Vec<DataFile>,
+    overwrite: bool,
+    base_snapshot_id: Option<i64>,
+) -> Result<()> {
+    for retry in 0..MAX_COMMIT_RETRIES {
+        if retry > 0 {
+            sleep(Duration::from_millis(100 << retry.min(5))).await;
+        }
+
+        // The version is read before the metadata, a newer metadata is detected below.
+        let version = current_version(op).await?;
+        let table = icelake::Table::open_with_op(op.clone())
+            .await
+            .map_err(parse_icelake_error)?;
+        let metadata = table.current_table_metadata().clone();
+        if overwrite && metadata.current_snapshot_id != base_snapshot_id {
+            return Err(ErrorCode::UnresolvableConflict(
+                "iceberg table has been changed by other writers during overwrite",
+            ));
+        }
+
+        let next_version = version + 1;
+        let claim_key = format!(
+            "{COMMIT_CLAIM_PREFIX}/{}/{next_version}",
+            escape_for_key(&metadata.location)?
+        );
+        let Some(claim_seq) = claimer.claim_version(&claim_key).await? else {
+            info!(
+                "iceberg metadata version {} is claimed by another query, retry the commit({})",
+                next_version, retry
+            );
+            continue;
+        };
+        if current_version(op).await? != version {
+            claimer.release_version(&claim_key, claim_seq).await;
+            info!(
+                "iceberg metadata version {} is written by another writer, retry the commit({})",
+                next_version, retry
+            );
+            continue;
+        }
+
+        let mut written_files = vec![];
+        let res = write_metadata(
+            &table,
+            op,
+            metadata,
+            data_files.clone(),
+            overwrite,
+            next_version,
+            &mut written_files,
+        )
+        .await;
+        if res.is_err() {
+            remove_files(op, &written_files).await;
+            claimer.release_version(&claim_key, claim_seq).await;
+        }
+        return res;
+    }
+
+    Err(ErrorCode::UnresolvableConflict(format!(
+        "commit iceberg table failed after {} retries",
+        MAX_COMMIT_RETRIES
+    )))
+}
+
+// Remove the manifest files of a failed commit, which are not referenced by any metadata.
+async fn remove_files(op: &Operator, paths: &[String]) {
+    for path in paths {
+        if let Err(e) = op.delete(path).await {
+            warn!("failed to remove orphan iceberg file {}: {}", path, e);
+        }
+    }
+}
+
+// Write the manifests and the metadata file of the new snapshot at `version`,
+// the paths of the written manifests are added to `written_files`.
+async fn write_metadata(
+    table: &icelake::Table,
+    op: &Operator,
+    metadata: TableMetadata,
+    data_files: Vec<DataFile>,
+    overwrite: bool,
+    version: u64,
+    written_files: &mut Vec<String>,
+) -> Result<()> {
+    let new_metadata =
+        build_metadata(table, op, metadata, data_files, overwrite, written_files).await?;
+    let metadata_path = format!(
+        "{METADATA_DIR}/{:05}-{}.metadata.json",
+        version,
+        Uuid::new_v4()
+    );
+    let bs = serialize_table_meta(new_metadata).map_err(parse_icelake_error)?;
+    op.write(&metadata_path, bs).await?;
+    Ok(())
+}
+
+// Write the manifest and manifest list of the new snapshot, and add it to the metadata.
+async fn build_metadata(
+    table: &icelake::Table,
+    op: &Operator,
+    mut metadata: TableMetadata,
+    data_files: Vec<DataFile>,
+    overwrite: bool,
+    written_files: &mut Vec<String>,
+) -> Result<TableMetadata> {
+    let snapshot_id = (Uuid::new_v4().as_u64_pair().0 & i64::MAX as u64) as i64;
+    let sequence_number = metadata.last_sequence_number + 1;
+    let parent = metadata
+        .current_snapshot()
+        .map_err(parse_icelake_error)?
+        .cloned();
+
+    let added_records: i64 = data_files.iter().map(|f| f.record_count).sum();
+    let added_files = data_files.len();
+
+    // 1. the manifest of the added data files.
+    let schema = metadata
+        .current_schema()
+        .map_err(parse_icelake_error)?
+        .clone();
+    let partition_spec = metadata
+        .current_partition_spec()
+        .map_err(parse_icelake_error)?
+        .clone();
+    let manifest_file = ManifestFile {
+        metadata: ManifestMetadata {
+            schema_id: schema.schema_id,
+            schema,
+            partition_spec,
+            format_version: Some(metadata.format_version),
+            content: ManifestContentType::Data,
+        },
+        entries: data_files
+            .into_iter()
+            .map(|data_file| ManifestEntry {
+                status: ManifestStatus::Added,
+                snapshot_id: Some(snapshot_id),
+                sequence_number: Some(sequence_number),
+                file_sequence_number: Some(sequence_number),
+                data_file,
+            })
+            .collect(),
+    };
+    let manifest_path = format!("{METADATA_DIR}/{}-m0.avro", Uuid::new_v4());
+    written_files.push(manifest_path.clone());
+    let manifest = ManifestWriter::new(
+        op.clone(),
+        &metadata.location,
+        &manifest_path,
+        snapshot_id,
+        sequence_number,
+    )
+    .write(manifest_file)
+    .await
+    .map_err(parse_icelake_error)?;
+
+    // 2. the manifest list, which keeps the manifests of the parent snapshot when appending.
+    let mut manifests = vec![manifest];
+    if let (Some(parent), false) = (&parent, overwrite) {
+        let path = table
+            .rel_path(&parent.manifest_list)
+            .map_err(parse_icelake_error)?;
+        let bs = op.read(&path).await?;
+        let parent_manifests = parse_manifest_list(&bs).map_err(parse_icelake_error)?;
+        manifests.extend(parent_manifests.entries);
+    }
+    let manifest_list_path = format!(
+        "{METADATA_DIR}/snap-{}-1-{}.avro",
+        snapshot_id,
+        Uuid::new_v4()
+    );
+    written_files.push(manifest_list_path.clone());
+    ManifestListWriter::new(
+        op.clone(),
+        &manifest_list_path,
+        snapshot_id,
+        parent.as_ref().map(|p| p.snapshot_id),
+        sequence_number,
+    )
+    .write(ManifestList { entries: manifests })
+    .await
+    .map_err(parse_icelake_error)?;
+
+    // 3. the snapshot.
+    let timestamp_ms = Utc::now().timestamp_millis();
+    let operation = if overwrite { "overwrite" } else { "append" };
+    let summary = HashMap::from([
+        ("operation".to_string(), operation.to_string()),
+        ("added-data-files".to_string(), added_files.to_string()),
+        ("added-records".to_string(), added_records.to_string()),
+    ]);
+    let snapshot = Snapshot {
+        snapshot_id,
+        parent_snapshot_id: parent.as_ref().map(|p| p.snapshot_id),
+        sequence_number,
+        timestamp_ms,
+        manifest_list: format!("{}/{}", metadata.location, manifest_list_path),
+        summary,
+        schema_id: Some(metadata.current_schema_id as i64),
+    };
+
+    metadata.last_sequence_number = sequence_number;
+    metadata.last_updated_ms = timestamp_ms;
+    metadata.current_snapshot_id = Some(snapshot_id);
+    metadata
+        .snapshots
+        .get_or_insert_with(Vec::new)
+        .push(snapshot);
+    metadata
+        .snapshot_log
+        .get_or_insert_with(Vec::new)
+        .push(SnapshotLog {
+            timestamp_ms,
+            snapshot_id,
+        });
+    Ok(metadata)
+}
+
+// The version of the current metadata file, which is named as `<version>-<uuid>.metadata.json`.
+async fn current_version(op: &Operator) -> Result<u64> {
+    let versions = list_versions(op).await?;
+    versions.into_iter().max().ok_or_else(|| {
+        ErrorCode::ReadTableDataError("Iceberg table has no metadata file".to_string())
+    })
+}
+
+async fn list_versions(op: &Operator) -> Result<Vec<u64>> {
+    let mut versions = vec![];
+    let mut lister = op.list(&format!("{METADATA_DIR}/")).await?;
+    while let Some(page) = lister.next_page().await? {
+        for entry in page {
+            let name = entry.name();
+            if !name.ends_with(".metadata.json") {
+                continue;
+            }
+            if let Some(Ok(version)) = name.split('-').next().map(|v| v.parse::<u64>()) {
+                versions.push(version);
+            }
+        }
+    }
+    Ok(versions)
+}
+
+fn parse_icelake_error(err: icelake::Error) -> ErrorCode {
+    ErrorCode::StorageOther(format!("Cannot commit iceberg table: {:?}", err))
+}
@@ -29,6 +29,7 @@ use common_storage::DataOperator;
 use opendal::EntryMode;
 use opendal::Metakey;
 
+use crate::commit::CommitClaimer;
 use crate::table::IcebergTable;
 
 #[derive(Clone, Debug)]
@@ -39,11 +40,18 @@ pub struct IcebergDatabase {
     db_root: DataOperator,
     /// database information
     info: DatabaseInfo,
+    /// claims the versions of the tables on commit
+    claimer: CommitClaimer,
 }
 
 impl IcebergDatabase {
     /// create a new database, but from reading
-    pub fn create(ctl_name: &str, db_name: &str, db_root: DataOperator) -> Self {
+    pub fn create(
+        ctl_name: &str,
+        db_name: &str,
+        db_root: DataOperator,
+        claimer: CommitClaimer,
+    ) -> Self {
         let info = DatabaseInfo {
             ident: DatabaseIdent { db_id: 0, seq: 0 },
             name_ident: DatabaseNameIdent {
@@ -61,6 +69,7 @@ impl IcebergDatabase {
             ctl_name: ctl_name.to_string(),
             db_root,
             info,
+            claimer,
         }
     }
 }
@@ -94,6 +103,7 @@ impl Database for IcebergDatabase {
             &self.info.name_ident.db_name,
             table_name,
             tbl_root,
+            self.claimer.clone(),
         )
         .await?;
         let tbl = Arc::new(tbl) as Arc<dyn Table>;
@@ -0,0 +1,165 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use futures::StreamExt;
+use icelake::io::parquet::ParquetStreamBuilder;
+use opendal::Operator;
+
+use crate::partition::IcebergPartInfo;
+
+/// The rows of a data file removed by delete files.
+#[derive(Default)]
+pub struct IcebergDeletes {
+    /// Positions of the deleted rows in the data file.
+    positions: HashSet<u64>,
+    /// The offsets of the equality columns in the data block, and the deleted values of them.
+    equalities: Vec<(Vec<usize>, HashSet<Vec<Scalar>>)>,
+}
+
+impl IcebergDeletes {
+    /// Load the delete files which apply to the data file of the part.
+    #[async_backtrace::framed]
+    pub async fn load(dal: &Operator, part: &IcebergPartInfo) -> Result<Self> {
+        let mut deletes = IcebergDeletes::default();
+
+        // A position delete file holds the `file_path` and `pos` columns.
+        for path in &part.position_deletes {
+            for block in read_blocks(dal, path).await? {
+                let num_rows = block.num_rows();
+                let file_paths = block
+                    .get_by_offset(0)
+                    .value
+                    .convert_to_full_column(&block.get_by_offset(0).data_type, num_rows);
+                let positions = block
+                    .get_by_offset(1)
+                    .value
+                    .convert_to_full_column(&block.get_by_offset(1).data_type, num_rows);
+                for row in 0..num_rows {
+                    if file_paths.index(row) != Some(ScalarRef::String(part.file_path.as_bytes())) {
+                        continue;
+                    }
+                    if let Some(pos) = positions.index(row).and_then(|v| v.as_number().cloned()) {
+                        if let Some(pos) = pos.as_int64() {
+                            deletes.positions.insert(*pos as u64);
+                        }
+                    }
+                }
+            }
+        }
+
+        // An equality delete file holds the equality columns. The column id in iceberg
+        // is 1-based while the column offset in the data block is 0-based.
+        for (path, equality_ids) in &part.equality_deletes {
+            let offsets: Vec<usize> = equality_ids.iter().map(|id| *id as usize - 1).collect();
+            let mut values = HashSet::new();
+            for block in read_blocks(dal, path).await? {
+                let num_rows = block.num_rows();
+                let columns = block
+                    .columns()
+                    .iter()
+                    .map(|entry| {
+                        entry
+                            .value
+                            .convert_to_full_column(&entry.data_type, num_rows)
+                    })
+                    .collect::<Vec<_>>();
+                for row in 0..num_rows {
+                    values.insert(
+                        columns
+                            .iter()
+                            .map(|column| column.index(row).unwrap().to_owned())
+                            .collect::<Vec<_>>(),
+                    );
+                }
+            }
+            deletes.equalities.push((offsets, values));
+        }
+
+        Ok(deletes)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty() && self.equalities.is_empty()
+    }
+
+    /// Remove the deleted rows from a block, which starts at row `offset` of the data file.
+    pub fn apply(&self, block: DataBlock, offset: u64) -> Result<DataBlock> {
+        if self.is_empty() {
+            return Ok(block);
+        }
+
+        let num_rows = block.num_rows();
+        let mut bitmap = MutableBitmap::from_len_set(num_rows);
+        for row in 0..num_rows {
+            if self.positions.contains(&(offset + row as u64)) {
+                bitmap.set(row, false);
+            }
+        }
+
+        for (offsets, values) in &self.equalities {
+            let columns = offsets
+                .iter()
+                .map(|offset| {
+                    let entry = block.get_by_offset(*offset);
+                    entry
+                        .value
+                        .convert_to_full_column(&entry.data_type, num_rows)
+                })
+                .collect::<Vec<_>>();
+            for row in 0..num_rows {
+                let key = columns
+                    .iter()
+                    .map(|column| column.index(row).unwrap().to_owned())
+                    .collect::<Vec<_>>();
+                if values.contains(&key) {
+                    bitmap.set(row, false);
+                }
+            }
+        }
+
+        block.filter_with_bitmap(&bitmap.into())
+    }
+}
+
+async fn read_blocks(dal: &Operator, path: &str) -> Result<Vec<DataBlock>> {
+    let reader = dal.reader(path).await?;
+    let mut stream = ParquetStreamBuilder::new(reader)
+        .build()
+        .await
+        .map_err(parse_icelake_error)?;
+
+    let mut blocks = vec![];
+    while let Some(batch) = stream.next().await {
+        let batch = batch.map_err(parse_icelake_error)?;
+        let (block, _) = DataBlock::from_record_batch(&batch).map_err(|err| {
+            ErrorCode::ReadTableDataError(format!(
+                "Cannot convert arrow record batch to data block: {err:?}"
+            ))
+        })?;
+        blocks.push(block);
+    }
+    Ok(blocks)
+}
+
+fn parse_icelake_error(err: icelake::Error) -> ErrorCode {
+    ErrorCode::ReadTableDataError(format!("Cannot read iceberg delete file: {:?}", err))
+}
@@ -86,12 +86,26 @@
 //! ```sql
 //! SELECT * FROM icb_ctl.default.icbg_tbl_0;
 //! ```
+//!
+//! ## Writing and Time Travel
+//!
+//! `INSERT INTO` and `INSERT OVERWRITE` write parquet data files and commit a new
+//! snapshot, and historical snapshots can be read with `AT (SNAPSHOT => id)` or
+//! `AT (TIMESTAMP => ts)`:
+//! ```sql
+//! INSERT INTO icb_ctl.db0.tbl1 VALUES (1, 'a');
+//! SELECT * FROM icb_ctl.db0.tbl1 AT (SNAPSHOT => '8111683045919920282');
+//! ```
 
 #![feature(lazy_cell)]
 
 mod catalog;
+mod commit;
 mod database;
+mod delete;
 mod partition;
+mod scan;
+mod sink;
 mod stats;
 mod table;
 mod table_source;
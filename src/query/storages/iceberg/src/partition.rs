@@ -29,6 +29,13 @@ use common_exception::Result;
 pub struct IcebergPartInfo {
     pub path: String,
     pub size: u64,
+    /// The location of the data file recorded in the manifest, which is referred by
+    /// position delete files.
+    pub file_path: String,
+    /// Relative paths of the position delete files to apply.
+    pub position_deletes: Vec<String>,
+    /// Relative paths of the equality delete files to apply, with their equality field ids.
+    pub equality_deletes: Vec<(String, Vec<i32>)>,
 }
 
 impl IcebergPartInfo {
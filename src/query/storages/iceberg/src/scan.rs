@@ -0,0 +1,129 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use icelake::types::parse_manifest_file;
+use icelake::types::parse_manifest_list;
+use icelake::types::DataContentType;
+use icelake::types::DataFile;
+use icelake::types::ManifestStatus;
+use icelake::types::Snapshot;
+use opendal::Operator;
+
+/// A live file of a snapshot, along with its data sequence number.
+pub struct IcebergFileEntry {
+    pub data_file: DataFile,
+    pub sequence_number: i64,
+}
+
+/// A data file to scan, with the delete files which apply to it.
+pub struct IcebergScanTask {
+    pub data_file: DataFile,
+    pub position_deletes: Vec<String>,
+    pub equality_deletes: Vec<(String, Vec<i32>)>,
+}
+
+/// Load the live data files and delete files of a snapshot.
+///
+/// Unlike `icelake::Table::current_data_files`, the sequence numbers of the files are kept,
+/// which are required to decide whether a delete file applies to a data file.
+#[async_backtrace::framed]
+pub async fn load_snapshot_files(
+    table: &icelake::Table,
+    op: &Operator,
+    snapshot: &Snapshot,
+) -> Result<Vec<IcebergFileEntry>> {
+    let manifest_list_path = table
+        .rel_path(&snapshot.manifest_list)
+        .map_err(parse_error)?;
+    let bs = op.read(&manifest_list_path).await?;
+    let manifest_list = parse_manifest_list(&bs).map_err(parse_error)?;
+
+    let mut entries = vec![];
+    for manifest in manifest_list.entries {
+        let manifest_path = table
+            .rel_path(&manifest.manifest_path)
+            .map_err(parse_error)?;
+        let bs = op.read(&manifest_path).await?;
+        let manifest_file = parse_manifest_file(&bs).map_err(parse_error)?;
+        for entry in manifest_file.entries {
+            if entry.status == ManifestStatus::Deleted {
+                continue;
+            }
+            // The entries added by the snapshot of the manifest inherit its sequence number.
+            let sequence_number = entry.sequence_number.unwrap_or(manifest.sequence_number);
+            entries.push(IcebergFileEntry {
+                data_file: entry.data_file,
+                sequence_number,
+            });
+        }
+    }
+    Ok(entries)
+}
+
+/// Pair the data files with the delete files according to the
+/// [scan planning](https://iceberg.apache.org/spec/#scan-planning) of the spec:
+///
+/// - a position delete file applies to the data files whose sequence number is not greater
+///   than its own.
+/// - an equality delete file applies to the data files whose sequence number is strictly
+///   less than its own.
+pub fn plan_scan_tasks(
+    table: &icelake::Table,
+    entries: Vec<IcebergFileEntry>,
+) -> Result<Vec<IcebergScanTask>> {
+    let mut data_files = vec![];
+    let mut position_deletes = vec![];
+    let mut equality_deletes = vec![];
+    for entry in entries {
+        match entry.data_file.content {
+            DataContentType::Data => data_files.push(entry),
+            DataContentType::PositionDeletes => {
+                let path = table
+                    .rel_path(&entry.data_file.file_path)
+                    .map_err(parse_error)?;
+                position_deletes.push((path, entry.sequence_number));
+            }
+            DataContentType::EqualityDeletes => {
+                let path = table
+                    .rel_path(&entry.data_file.file_path)
+                    .map_err(parse_error)?;
+                let equality_ids = entry.data_file.equality_ids.clone().unwrap_or_default();
+                equality_deletes.push((path, equality_ids, entry.sequence_number));
+            }
+        }
+    }
+
+    Ok(data_files
+        .into_iter()
+        .map(|entry| IcebergScanTask {
+            position_deletes: position_deletes
+                .iter()
+                .filter(|(_, seq)| *seq >= entry.sequence_number)
+                .map(|(path, _)| path.clone())
+                .collect(),
+            equality_deletes: equality_deletes
+                .iter()
+                .filter(|(_, _, seq)| *seq > entry.sequence_number)
+                .map(|(path, ids, _)| (path.clone(), ids.clone()))
+                .collect(),
+            data_file: entry.data_file,
+        })
+        .collect())
+}
+
+fn parse_error(e: icelake::Error) -> ErrorCode {
+    ErrorCode::ReadTableDataError(format!("Cannot load iceberg manifests: {e:?}"))
+}
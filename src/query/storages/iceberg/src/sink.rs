@@ -0,0 +1,211 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::TableSchemaRef;
+use common_functions::aggregates::eval_aggr;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sinks::AsyncSink;
+use common_pipeline_sinks::AsyncSinker;
+use icelake::types::DataContentType;
+use icelake::types::DataFile;
+use icelake::types::DataFileFormat;
+use icelake::types::StructValue;
+use opendal::Operator;
+use parquet::arrow::ArrowWriter;
+use uuid::Uuid;
+
+use crate::commit::commit_data_files;
+use crate::commit::CommitClaimer;
+use crate::stats::serialize_binary_value;
+
+/// The size of the buffered blocks to be written as a data file.
+const MAX_BUFFER_SIZE: usize = 64 * 1024 * 1024;
+
+/// Write the inserted blocks as parquet data files, and commit them on finish.
+pub struct IcebergTableSink {
+    claimer: CommitClaimer,
+    op: Operator,
+    schema: TableSchemaRef,
+    overwrite: bool,
+    // The location of the table and the snapshot the insertion is based on, loaded on start.
+    table_location: String,
+    base_snapshot_id: Option<i64>,
+
+    blocks: Vec<DataBlock>,
+    buffered_size: usize,
+    data_files: Vec<DataFile>,
+}
+
+impl IcebergTableSink {
+    pub fn create(
+        input: Arc<InputPort>,
+        claimer: CommitClaimer,
+        op: Operator,
+        schema: TableSchemaRef,
+        overwrite: bool,
+    ) -> ProcessorPtr {
+        ProcessorPtr::create(AsyncSinker::create(input, IcebergTableSink {
+            claimer,
+            op,
+            schema,
+            overwrite,
+            table_location: String::new(),
+            base_snapshot_id: None,
+            blocks: vec![],
+            buffered_size: 0,
+            data_files: vec![],
+        }))
+    }
+
+    #[async_backtrace::framed]
+    async fn flush(&mut self) -> Result<()> {
+        if self.blocks.is_empty() {
+            return Ok(());
+        }
+        let block = DataBlock::concat(&std::mem::take(&mut self.blocks))?;
+        self.buffered_size = 0;
+        if block.num_rows() == 0 {
+            return Ok(());
+        }
+
+        let data_schema = DataSchema::from(self.schema.as_ref());
+        let batch = block.clone().to_record_batch(&data_schema).map_err(|e| {
+            ErrorCode::Internal(format!("Cannot convert data block to record batch: {e:?}"))
+        })?;
+        let mut buf = vec![];
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None)
+            .map_err(|e| ErrorCode::Internal(format!("Cannot write parquet file: {e:?}")))?;
+        writer
+            .write(&batch)
+            .map_err(|e| ErrorCode::Internal(format!("Cannot write parquet file: {e:?}")))?;
+        writer
+            .close()
+            .map_err(|e| ErrorCode::Internal(format!("Cannot write parquet file: {e:?}")))?;
+
+        let path = format!("data/{}.parquet", Uuid::new_v4());
+        let file_size = buf.len();
+        self.op.write(&path, buf).await?;
+        self.data_files
+            .push(self.build_data_file(&path, file_size, &block)?);
+        Ok(())
+    }
+
+    fn build_data_file(&self, path: &str, file_size: usize, block: &DataBlock) -> Result<DataFile> {
+        let num_rows = block.num_rows();
+        let mut value_counts = HashMap::new();
+        let mut null_value_counts = HashMap::new();
+        let mut lower_bounds = HashMap::new();
+        let mut upper_bounds = HashMap::new();
+        for (field, entry) in self.schema.fields().iter().zip(block.columns()) {
+            // The column id in iceberg is 1-based while the column id in Databend is 0-based.
+            let iceberg_col_id = field.column_id as i32 + 1;
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, num_rows);
+            let null_count = column
+                .validity()
+                .1
+                .map_or(0, |validity| validity.unset_bits());
+            value_counts.insert(iceberg_col_id, num_rows as i64);
+            null_value_counts.insert(iceberg_col_id, null_count as i64);
+
+            let (mins, _) = eval_aggr("min", vec![], &[column.clone()], num_rows)?;
+            let (maxs, _) = eval_aggr("max", vec![], &[column], num_rows)?;
+            let min = mins
+                .index(0)
+                .and_then(|v| serialize_binary_value(&v.to_owned()));
+            let max = maxs
+                .index(0)
+                .and_then(|v| serialize_binary_value(&v.to_owned()));
+            if let (Some(min), Some(max)) = (min, max) {
+                lower_bounds.insert(iceberg_col_id, min);
+                upper_bounds.insert(iceberg_col_id, max);
+            }
+        }
+
+        Ok(DataFile {
+            content: DataContentType::Data,
+            file_path: format!("{}/{}", self.table_location, path),
+            file_format: DataFileFormat::Parquet,
+            partition: StructValue::default(),
+            record_count: num_rows as i64,
+            file_size_in_bytes: file_size as i64,
+            column_sizes: None,
+            value_counts: Some(value_counts),
+            null_value_counts: Some(null_value_counts),
+            nan_value_counts: None,
+            distinct_counts: None,
+            lower_bounds: Some(lower_bounds),
+            upper_bounds: Some(upper_bounds),
+            key_metadata: None,
+            split_offsets: None,
+            equality_ids: None,
+            sort_order_id: None,
+        })
+    }
+}
+
+#[async_trait]
+impl AsyncSink for IcebergTableSink {
+    const NAME: &'static str = "IcebergTableSink";
+
+    #[async_backtrace::framed]
+    async fn on_start(&mut self) -> Result<()> {
+        let table = icelake::Table::open_with_op(self.op.clone())
+            .await
+            .map_err(|e| ErrorCode::ReadTableDataError(format!("Cannot load metadata: {e:?}")))?;
+        let meta = table.current_table_metadata();
+        self.table_location = meta.location.trim_end_matches('/').to_string();
+        self.base_snapshot_id = meta.current_snapshot_id;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        self.flush().await?;
+        if self.data_files.is_empty() && !self.overwrite {
+            return Ok(());
+        }
+
+        commit_data_files(
+            &self.claimer,
+            &self.op,
+            std::mem::take(&mut self.data_files),
+            self.overwrite,
+            self.base_snapshot_id,
+        )
+        .await
+    }
+
+    #[unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        self.buffered_size += data_block.memory_size();
+        self.blocks.push(data_block);
+        if self.buffered_size >= MAX_BUFFER_SIZE {
+            self.flush().await?;
+        }
+        Ok(false)
+    }
+}
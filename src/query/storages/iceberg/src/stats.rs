@@ -16,6 +16,7 @@ use std::collections::HashMap;
 
 use common_expression::types::Number;
 use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
 use common_expression::types::F32;
 use common_expression::types::F64;
 use common_expression::with_integer_mapped_type;
@@ -112,3 +113,19 @@ fn parse_binary_value(ty: &TableDataType, data: &[u8]) -> Option<Scalar> {
         _ => None, // Not supported.
     }
 }
+
+/// Serialize [`Scalar`] to binary value, the reverse of [`parse_binary_value`].
+pub fn serialize_binary_value(value: &Scalar) -> Option<Vec<u8>> {
+    match value {
+        Scalar::Boolean(v) => Some(vec![*v as u8]),
+        Scalar::Number(v) => with_integer_mapped_type!(|NUM_TYPE| match v {
+            NumberScalar::NUM_TYPE(v) => Some(v.to_le_bytes().to_vec()),
+            NumberScalar::Float32(v) => Some(v.0.to_le_bytes().to_vec()),
+            NumberScalar::Float64(v) => Some(v.0.to_le_bytes().to_vec()),
+        }),
+        Scalar::Date(v) => Some(v.to_le_bytes().to_vec()),
+        Scalar::Timestamp(v) => Some(v.to_le_bytes().to_vec()),
+        Scalar::String(v) => Some(v.clone()),
+        _ => None,
+    }
+}
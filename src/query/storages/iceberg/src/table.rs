@@ -26,6 +26,9 @@ use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::PartitionsShuffleKind;
 use common_catalog::plan::PushDownInfo;
+use common_catalog::table::AppendMode;
+use common_catalog::table::NavigationDescriptor;
+use common_catalog::table::NavigationPoint;
 use common_catalog::table::Table;
 use common_catalog::table_args::TableArgs;
 use common_catalog::table_context::TableContext;
@@ -39,14 +42,23 @@ use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::Pipeline;
 use common_pipeline_core::SourcePipeBuilder;
 use common_storage::DataOperator;
+use icelake::types::Snapshot;
+use icelake::types::TableMetadata;
 use storages_common_pruner::RangePrunerCreator;
+use storages_common_table_meta::meta::SnapshotId;
 use tokio::sync::OnceCell;
 
+use crate::commit::CommitClaimer;
 use crate::partition::IcebergPartInfo;
+use crate::scan::load_snapshot_files;
+use crate::scan::plan_scan_tasks;
+use crate::scan::IcebergScanTask;
+use crate::sink::IcebergTableSink;
 use crate::stats::get_stats_of_data_file;
 use crate::table_source::IcebergTableSource;
 
@@ -56,6 +68,9 @@ use crate::table_source::IcebergTableSource;
 pub struct IcebergTable {
     info: TableInfo,
     op: opendal::Operator,
+    claimer: CommitClaimer,
+    /// The snapshot to read, the current snapshot is read if it's None.
+    snapshot_id: Option<i64>,
 
     table: OnceCell<icelake::Table>,
 }
@@ -63,10 +78,16 @@ pub struct IcebergTable {
 impl IcebergTable {
     /// create a new table on the table directory
     #[async_backtrace::framed]
-    pub fn try_new(dop: DataOperator, info: TableInfo) -> Result<IcebergTable> {
+    pub fn try_new(
+        dop: DataOperator,
+        info: TableInfo,
+        claimer: CommitClaimer,
+    ) -> Result<IcebergTable> {
         Ok(Self {
             info,
             op: dop.operator(),
+            claimer,
+            snapshot_id: None,
             table: OnceCell::new(),
         })
     }
@@ -78,6 +99,7 @@ impl IcebergTable {
         database: &str,
         table_name: &str,
         dop: DataOperator,
+        claimer: CommitClaimer,
     ) -> Result<IcebergTable> {
         let op = dop.operator();
         let table = icelake::Table::open_with_op(op.clone())
@@ -128,6 +150,8 @@ impl IcebergTable {
         Ok(Self {
             info,
             op,
+            claimer,
+            snapshot_id: None,
             table: OnceCell::new_with(Some(table)),
         })
     }
@@ -200,9 +224,14 @@ impl IcebergTable {
     ) -> Result<(PartStatistics, Partitions)> {
         let table = self.table().await?;
 
-        let data_files = table.current_data_files().await.map_err(|e| {
-            ErrorCode::ReadTableDataError(format!("Cannot get current data files: {e:?}"))
-        })?;
+        let meta = table.current_table_metadata();
+        let Some(snapshot_id) = self.snapshot_id.or(meta.current_snapshot_id) else {
+            // The table has no data yet.
+            return Ok((PartStatistics::default(), Partitions::default()));
+        };
+        let snapshot = find_snapshot(meta, snapshot_id)?;
+        let entries = load_snapshot_files(table, &self.op, snapshot).await?;
+        let scan_tasks = plan_scan_tasks(table, entries)?;
 
         let filter = push_downs
             .as_ref()
@@ -213,21 +242,22 @@ impl IcebergTable {
         let pruner =
             RangePrunerCreator::try_create(ctx.get_function_context()?, &schema, filter.as_ref())?;
 
-        let partitions_total = data_files.len();
+        let partitions_total = scan_tasks.len();
         let mut read_rows = 0;
         let mut read_bytes = 0;
 
-        let partitions = data_files
+        let partitions = scan_tasks
             .into_iter()
-            .filter(|df| {
-                if let Some(stats) = get_stats_of_data_file(&schema, df) {
+            .filter(|task| {
+                if let Some(stats) = get_stats_of_data_file(&schema, &task.data_file) {
                     pruner.should_keep(&stats, None)
                 } else {
                     true
                 }
             })
-            .map(|v: icelake::types::DataFile| match v.file_format {
+            .map(|task: IcebergScanTask| match task.data_file.file_format {
                 icelake::types::DataFileFormat::Parquet => {
+                    let v = task.data_file;
                     read_rows += v.record_count as usize;
                     read_bytes += v.file_size_in_bytes as usize;
                     Ok(Arc::new(Box::new(IcebergPartInfo {
@@ -235,6 +265,9 @@ impl IcebergTable {
                             .rel_path(&v.file_path)
                             .expect("file path must be rel to table"),
                         size: v.file_size_in_bytes as u64,
+                        file_path: v.file_path,
+                        position_deletes: task.position_deletes,
+                        equality_deletes: task.equality_deletes,
                     }) as Box<dyn PartInfo>))
                 }
                 _ => Err(ErrorCode::Unimplemented(
@@ -250,6 +283,57 @@ impl IcebergTable {
             Partitions::create_nolazy(PartitionsShuffleKind::Seq, partitions),
         ))
     }
+
+    #[async_backtrace::framed]
+    async fn do_navigate(&self, navigation: &NavigationDescriptor) -> Result<IcebergTable> {
+        let table = self.table().await?;
+        let meta = table.current_table_metadata();
+        let snapshots = meta.snapshots.as_deref().unwrap_or_default();
+
+        let snapshot_id = match &navigation.point {
+            NavigationPoint::SnapshotID(snapshot_id) => {
+                let snapshot_id = snapshot_id.parse::<i64>().map_err(|_| {
+                    ErrorCode::BadArguments(format!(
+                        "Invalid iceberg snapshot id '{snapshot_id}', it must be an integer"
+                    ))
+                })?;
+                find_snapshot(meta, snapshot_id)?.snapshot_id
+            }
+            NavigationPoint::TimePoint(time_point) => snapshots
+                .iter()
+                .filter(|s| s.timestamp_ms <= time_point.timestamp_millis())
+                .max_by_key(|s| s.timestamp_ms)
+                .map(|s| s.snapshot_id)
+                .ok_or_else(|| {
+                    ErrorCode::TableHistoricalDataNotFound(format!(
+                        "No snapshot of table {} is found at {}",
+                        self.name(),
+                        time_point
+                    ))
+                })?,
+        };
+
+        Ok(IcebergTable {
+            info: self.info.clone(),
+            op: self.op.clone(),
+            claimer: self.claimer.clone(),
+            snapshot_id: Some(snapshot_id),
+            table: OnceCell::new(),
+        })
+    }
+}
+
+fn find_snapshot(meta: &TableMetadata, snapshot_id: i64) -> Result<&Snapshot> {
+    meta.snapshots
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .find(|s| s.snapshot_id == snapshot_id)
+        .ok_or_else(|| {
+            ErrorCode::TableHistoricalDataNotFound(format!(
+                "Snapshot {snapshot_id} of iceberg table is not found"
+            ))
+        })
 }
 
 #[async_trait]
@@ -293,4 +377,49 @@ impl Table for IcebergTable {
     fn table_args(&self) -> Option<TableArgs> {
         None
     }
+
+    fn append_data(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _pipeline: &mut Pipeline,
+        _append_mode: AppendMode,
+    ) -> Result<()> {
+        if self.snapshot_id.is_some() {
+            return Err(ErrorCode::Unimplemented(
+                "Cannot write to a historical snapshot of iceberg table",
+            ));
+        }
+        // The blocks are written as data files by the sink added in `commit_insertion`.
+        Ok(())
+    }
+
+    fn commit_insertion(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _copied_files: Option<UpsertTableCopiedFileReq>,
+        overwrite: bool,
+        _prev_snapshot_id: Option<SnapshotId>,
+    ) -> Result<()> {
+        pipeline.try_resize(1)?;
+
+        pipeline.add_sink(|input| {
+            Ok(IcebergTableSink::create(
+                input,
+                self.claimer.clone(),
+                self.op.clone(),
+                self.schema(),
+                overwrite,
+            ))
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn navigate_to(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        navigation: &NavigationDescriptor,
+    ) -> Result<Arc<dyn Table>> {
+        Ok(Arc::new(self.do_navigate(navigation).await?))
+    }
 }
@@ -32,6 +32,7 @@ use icelake::io::parquet::ParquetStream;
 use icelake::io::parquet::ParquetStreamBuilder;
 use opendal::Operator;
 
+use crate::delete::IcebergDeletes;
 use crate::partition::IcebergPartInfo;
 
 pub struct IcebergTableSource {
@@ -45,6 +46,11 @@ pub struct IcebergTableSource {
     source_schema: DataSchemaRef,
     /// The final output schema
     _output_schema: DataSchemaRef,
+
+    /// The rows deleted from the data file being read.
+    deletes: IcebergDeletes,
+    /// The position of the next row to read in the data file.
+    row_offset: u64,
 }
 
 enum State {
@@ -76,6 +82,8 @@ impl IcebergTableSource {
             state: State::ReadMeta(None),
             source_schema,
             _output_schema: output_schema,
+            deletes: IcebergDeletes::default(),
+            row_offset: 0,
         })))
     }
 }
@@ -110,12 +118,15 @@ impl Processor for IcebergTableSource {
             if let State::ReadData(ps, mut data) = std::mem::replace(&mut self.state, State::Finish)
             {
                 if let Some(arrow_block) = data.take() {
-                    let (mut data_block, _) =
+                    let (data_block, _) =
                         DataBlock::from_record_batch(&arrow_block).map_err(|err| {
                             ErrorCode::ReadTableDataError(format!(
                                 "Cannot convert arrow record batch to data block: {err:?}"
                             ))
                         })?;
+                    let num_rows = data_block.num_rows() as u64;
+                    let mut data_block = self.deletes.apply(data_block, self.row_offset)?;
+                    self.row_offset += num_rows;
                     // Check if the schema of the data block is matched with the schema of the table.
                     if data_block.num_columns() != self.source_schema.num_fields() {
                         return Err(ErrorCode::TableSchemaMismatch(format!(
@@ -187,6 +198,8 @@ impl Processor for IcebergTableSource {
         match std::mem::replace(&mut self.state, State::Finish) {
             State::ReadMeta(Some(part)) => {
                 let part = IcebergPartInfo::from_part(&part)?;
+                self.deletes = IcebergDeletes::load(&self.dal, part).await?;
+                self.row_offset = 0;
                 let r = self.dal.reader(&part.path).await?;
                 let s = ParquetStreamBuilder::new(r)
                     .build()
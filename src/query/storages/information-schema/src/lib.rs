@@ -18,6 +18,7 @@ mod keywords_table;
 mod schemata_table;
 mod statistics_table;
 mod tables_table;
+mod tag_references_table;
 mod views_table;
 
 pub use columns_table::ColumnsTable;
@@ -26,4 +27,5 @@ pub use keywords_table::KeywordsTable;
 pub use schemata_table::SchemataTable;
 pub use statistics_table::StatisticsTable;
 pub use tables_table::TablesTable;
+pub use tag_references_table::TagReferencesTable;
 pub use views_table::ViewsTable;
@@ -0,0 +1,182 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use chrono::NaiveDateTime;
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::NumberDataType;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+use serde::Serialize;
+use serde::Serializer;
+
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+/// The category of an audited statement.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+pub enum AuditCategory {
+    /// Creates, alters or drops an object.
+    #[serde(rename = "DDL")]
+    Ddl,
+    /// Manages users, roles and privileges.
+    #[serde(rename = "DCL")]
+    Dcl,
+    /// Reads or writes the data of a table.
+    #[serde(rename = "DATA_ACCESS")]
+    DataAccess,
+}
+
+impl Display for AuditCategory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuditCategory::Ddl => write!(f, "DDL"),
+            AuditCategory::Dcl => write!(f, "DCL"),
+            AuditCategory::DataAccess => write!(f, "DATA_ACCESS"),
+        }
+    }
+}
+
+fn datetime_str<S>(dt: &i64, s: S) -> Result<S::Ok, S::Error>
+where S: Serializer {
+    let t = NaiveDateTime::from_timestamp_opt(
+        dt / 1_000_000,
+        TryFrom::try_from((dt % 1_000_000) * 1000).unwrap_or(0),
+    )
+    .unwrap();
+    s.serialize_str(t.format("%Y-%m-%d %H:%M:%S%.6f").to_string().as_str())
+}
+
+#[derive(Clone, Serialize)]
+pub struct AuditLogElement {
+    #[serde(serialize_with = "datetime_str")]
+    pub event_time: i64,
+
+    // Who.
+    pub tenant_id: String,
+    pub cluster_id: String,
+    pub node_id: String,
+    pub user: String,
+    pub role: String,
+    pub client_address: String,
+
+    // What.
+    pub query_id: String,
+    pub category: AuditCategory,
+    pub statement_kind: String,
+    pub objects: Vec<String>,
+
+    // Result.
+    pub success: bool,
+    pub error_code: i32,
+    pub error_message: String,
+}
+
+impl SystemLogElement for AuditLogElement {
+    const TABLE_NAME: &'static str = "audit_log";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("event_time", TableDataType::Timestamp),
+            TableField::new("tenant_id", TableDataType::String),
+            TableField::new("cluster_id", TableDataType::String),
+            TableField::new("node_id", TableDataType::String),
+            TableField::new("user", TableDataType::String),
+            TableField::new("role", TableDataType::String),
+            TableField::new("client_address", TableDataType::String),
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("category", TableDataType::String),
+            TableField::new("statement_kind", TableDataType::String),
+            TableField::new("objects", TableDataType::String),
+            TableField::new("success", TableDataType::Boolean),
+            TableField::new("error_code", TableDataType::Number(NumberDataType::Int32)),
+            TableField::new("error_message", TableDataType::String),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.event_time).as_ref());
+        // Who.
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.tenant_id.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.cluster_id.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.node_id.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.user.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.role.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.client_address.as_bytes().to_vec()).as_ref());
+        // What.
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.query_id.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.category.to_string().as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.statement_kind.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.objects.join(", ").as_bytes().to_vec()).as_ref());
+        // Result.
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Boolean(self.success).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::Int32(self.error_code)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.error_message.as_bytes().to_vec()).as_ref());
+        Ok(())
+    }
+}
+
+pub type AuditLogQueue = SystemLogQueue<AuditLogElement>;
+pub type AuditLogTable = SystemLogTable<AuditLogElement>;
@@ -94,6 +94,10 @@ impl AsyncSystemTable for BackgroundTaskTable {
                 database_ids.push(compact_stats.db_id);
                 table_ids.push(compact_stats.table_id);
                 task_run_secs.push(compact_stats.total_compaction_time.map(|s| s.as_secs()));
+            } else if let Some(vacuum_stats) = task.vacuum_stats.as_ref() {
+                database_ids.push(vacuum_stats.db_id);
+                table_ids.push(vacuum_stats.table_id);
+                task_run_secs.push(vacuum_stats.total_vacuum_time.map(|s| s.as_secs()));
             } else {
                 database_ids.push(0);
                 table_ids.push(0);
@@ -73,7 +73,7 @@ impl AsyncSystemTable for ColumnsTable {
         let mut default_exprs: Vec<Vec<u8>> = Vec::with_capacity(rows.len());
         let mut is_nullables: Vec<Vec<u8>> = Vec::with_capacity(rows.len());
         let mut comments: Vec<Vec<u8>> = Vec::with_capacity(rows.len());
-        for (database_name, table_name, field) in rows.into_iter() {
+        for (database_name, table_name, field, comment) in rows.into_iter() {
             names.push(field.name().clone().into_bytes());
             tables.push(table_name.into_bytes());
             databases.push(database_name.into_bytes());
@@ -95,7 +95,7 @@ impl AsyncSystemTable for ColumnsTable {
                 is_nullables.push("NO".to_string().into_bytes());
             }
 
-            comments.push("".to_string().into_bytes());
+            comments.push(comment.into_bytes());
         }
 
         Ok(DataBlock::new_from_columns(vec![
@@ -148,7 +148,7 @@ impl ColumnsTable {
         &self,
         ctx: Arc<dyn TableContext>,
         push_downs: Option<PushDownInfo>,
-    ) -> Result<Vec<(String, String, TableField)>> {
+    ) -> Result<Vec<(String, String, TableField, String)>> {
         let tenant = ctx.get_tenant();
         let catalog = ctx.get_catalog(CATALOG_DEFAULT).await?;
 
@@ -194,7 +194,7 @@ impl ColumnsTable {
             .cloned()
             .collect();
 
-        let mut rows: Vec<(String, String, TableField)> = vec![];
+        let mut rows: Vec<(String, String, TableField, String)> = vec![];
         for database in final_dbs {
             let tables = if tables.is_empty() {
                 if let Ok(table) = catalog.list_tables(tenant.as_str(), &database).await {
@@ -219,8 +219,17 @@ impl ColumnsTable {
                     table.name(),
                 ) {
                     let fields = generate_fields(&ctx, &table).await?;
-                    for field in fields {
-                        rows.push((database.clone(), table.name().into(), field.clone()))
+                    // Comments are only kept for the columns of the table schema,
+                    // the columns of a view are inferred from its query.
+                    let field_comments = table.field_comments();
+                    let has_comments = field_comments.len() == fields.len();
+                    for (idx, field) in fields.into_iter().enumerate() {
+                        let comment = if has_comments {
+                            field_comments[idx].clone()
+                        } else {
+                            "".to_string()
+                        };
+                        rows.push((database.clone(), table.name().into(), field, comment))
                     }
                 }
             }
@@ -115,6 +115,8 @@ impl SyncSystemTable for ConfigsTable {
         storage_config.azblob.account_key = mask_string(&storage_config.azblob.account_key, 3);
         storage_config.webhdfs.webhdfs_delegation =
             mask_string(&storage_config.webhdfs.webhdfs_delegation, 3);
+        storage_config.encryption_master_key =
+            mask_string(&storage_config.encryption_master_key, 3);
 
         let storage_config_value = serde_json::to_value(storage_config)?;
         ConfigsTable::extract_config(
@@ -66,6 +66,7 @@ impl AsyncSystemTable for DatabasesTable {
         let mut catalog_names = vec![];
         let mut db_names = vec![];
         let mut db_id = vec![];
+        let mut comments = vec![];
 
         let user = ctx.get_current_user()?;
         let roles = ctx.get_current_available_roles().await?;
@@ -84,6 +85,7 @@ impl AsyncSystemTable for DatabasesTable {
                 db_names.push(db_name);
                 let id = db.get_db_info().ident.db_id;
                 db_id.push(id);
+                comments.push(db.get_db_info().meta.comment.clone().into_bytes());
             }
         }
 
@@ -91,6 +93,7 @@ impl AsyncSystemTable for DatabasesTable {
             StringType::from_data(catalog_names),
             StringType::from_data(db_names),
             UInt64Type::from_data(db_id),
+            StringType::from_data(comments),
         ]))
     }
 }
@@ -101,6 +104,7 @@ impl DatabasesTable {
             TableField::new("catalog", TableDataType::String),
             TableField::new("name", TableDataType::String),
             TableField::new("database_id", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("comment", TableDataType::String),
         ]);
 
         let table_info = TableInfo {
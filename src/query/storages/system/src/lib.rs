@@ -18,6 +18,7 @@
 
 extern crate core;
 
+mod audit_log_table;
 mod background_jobs_table;
 mod background_tasks_table;
 mod backtrace_table;
@@ -49,10 +50,16 @@ mod stages_table;
 mod table;
 mod table_functions_table;
 mod tables_table;
+mod tag_references_table;
+mod tags_table;
 mod tracing_table;
 mod users_table;
 mod util;
 
+pub use audit_log_table::AuditCategory;
+pub use audit_log_table::AuditLogElement;
+pub use audit_log_table::AuditLogQueue;
+pub use audit_log_table::AuditLogTable;
 pub use background_jobs_table::BackgroundJobTable;
 pub use background_tasks_table::BackgroundTaskTable;
 pub use backtrace_table::BacktraceTable;
@@ -94,5 +101,7 @@ pub use table_functions_table::TableFunctionsTable;
 pub use tables_table::TablesTable;
 pub use tables_table::TablesTableWithHistory;
 pub use tables_table::TablesTableWithoutHistory;
+pub use tag_references_table::TagReferencesTable;
+pub use tags_table::TagsTable;
 pub use tracing_table::TracingTable;
 pub use users_table::UsersTable;
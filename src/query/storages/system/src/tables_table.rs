@@ -263,6 +263,10 @@ where TablesTable<T>: HistoryAware
                 }
             })
             .collect();
+        let comments: Vec<Vec<u8>> = database_tables
+            .iter()
+            .map(|v| v.get_table_info().meta.comment.as_bytes().to_vec())
+            .collect();
         Ok(DataBlock::new_from_columns(vec![
             StringType::from_data(catalogs),
             StringType::from_data(databases),
@@ -281,6 +285,7 @@ where TablesTable<T>: HistoryAware
             UInt64Type::from_opt_data(index_size),
             UInt64Type::from_opt_data(number_of_segments),
             UInt64Type::from_opt_data(number_of_blocks),
+            StringType::from_data(comments),
         ]))
     }
 }
@@ -325,6 +330,7 @@ where TablesTable<T>: HistoryAware
                 "number_of_blocks",
                 TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
             ),
+            TableField::new("comment", TableDataType::String),
         ])
     }
 
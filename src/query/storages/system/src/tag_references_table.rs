@@ -0,0 +1,105 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct TagReferencesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for TagReferencesTable {
+    const NAME: &'static str = "system.tag_references";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let references = UserApiProvider::instance()
+            .get_tag_references(&tenant)
+            .await?;
+
+        let mut tag_names = Vec::with_capacity(references.len());
+        let mut tag_values = Vec::with_capacity(references.len());
+        let mut object_domains = Vec::with_capacity(references.len());
+        let mut object_names = Vec::with_capacity(references.len());
+        let mut created_on = Vec::with_capacity(references.len());
+        for reference in references {
+            tag_names.push(reference.tag_name.as_bytes().to_vec());
+            tag_values.push(reference.tag_value.as_bytes().to_vec());
+            object_domains.push(reference.object_domain.as_bytes().to_vec());
+            object_names.push(reference.object_name.as_bytes().to_vec());
+            created_on.push(reference.create_on.timestamp_micros());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(tag_names),
+            StringType::from_data(tag_values),
+            StringType::from_data(object_domains),
+            StringType::from_data(object_names),
+            TimestampType::from_data(created_on),
+        ]))
+    }
+}
+
+impl TagReferencesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("tag_name", TableDataType::String),
+            TableField::new("tag_value", TableDataType::String),
+            TableField::new("object_domain", TableDataType::String),
+            TableField::new("object_name", TableDataType::String),
+            TableField::new("created_on", TableDataType::Timestamp),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'tag_references'".to_string(),
+            name: "tag_references".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTagReferences".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(TagReferencesTable { table_info })
+    }
+}
@@ -21,6 +21,7 @@ extern crate core;
 mod jwt;
 mod network_policy;
 mod role_mgr;
+mod tag;
 mod user;
 mod user_api;
 mod user_mgr;
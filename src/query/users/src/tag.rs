@@ -0,0 +1,133 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_management::TagApi;
+use common_meta_app::principal::Tag;
+use common_meta_app::principal::TagReference;
+use common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+impl UserApiProvider {
+    // Add a new tag.
+    #[async_backtrace::framed]
+    pub async fn add_tag(&self, tenant: &str, tag: Tag, if_not_exists: bool) -> Result<u64> {
+        let client = self.get_tag_api_client(tenant)?;
+        match client.add_tag(tag).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::TAG_ALREADY_EXISTS {
+                    Ok(0)
+                } else {
+                    Err(e.add_message_back(" (while add tag)"))
+                }
+            }
+        }
+    }
+
+    // Drop a tag by name, the values of the tag set on objects are dropped too.
+    #[async_backtrace::framed]
+    pub async fn drop_tag(&self, tenant: &str, name: &str, if_exists: bool) -> Result<()> {
+        let client = self.get_tag_api_client(tenant)?;
+        match client.drop_tag(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_TAG {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop tag)"))
+                }
+            }
+        }
+    }
+
+    // Get all tags by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_tags(&self, tenant: &str) -> Result<Vec<Tag>> {
+        let client = self.get_tag_api_client(tenant)?;
+        client
+            .get_tags()
+            .await
+            .map_err(|e| e.add_message_back(" (while get tags)."))
+    }
+
+    // Set the value of a tag on an object.
+    #[async_backtrace::framed]
+    pub async fn set_tag_reference(&self, tenant: &str, reference: TagReference) -> Result<u64> {
+        let client = self.get_tag_api_client(tenant)?;
+        client
+            .set_tag_reference(reference)
+            .await
+            .map_err(|e| e.add_message_back(" (while set tag)."))
+    }
+
+    // Unset the value of a tag on an object.
+    #[async_backtrace::framed]
+    pub async fn unset_tag_reference(
+        &self,
+        tenant: &str,
+        tag_name: &str,
+        object_domain: &str,
+        object_name: &str,
+    ) -> Result<()> {
+        let client = self.get_tag_api_client(tenant)?;
+        client
+            .unset_tag_reference(tag_name, object_domain, object_name)
+            .await
+            .map_err(|e| e.add_message_back(" (while unset tag)."))
+    }
+
+    // Get the values of all tags set on objects by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_tag_references(&self, tenant: &str) -> Result<Vec<TagReference>> {
+        let client = self.get_tag_api_client(tenant)?;
+        client
+            .get_tag_references()
+            .await
+            .map_err(|e| e.add_message_back(" (while get tag references)."))
+    }
+
+    // Drop the values of all tags set on an object and the objects nested in it.
+    #[async_backtrace::framed]
+    pub async fn drop_object_tag_references(
+        &self,
+        tenant: &str,
+        object_domains: &[&str],
+        object_name: &str,
+    ) -> Result<()> {
+        let client = self.get_tag_api_client(tenant)?;
+        client
+            .drop_object_tag_references(object_domains, object_name)
+            .await
+            .map_err(|e| e.add_message_back(" (while drop object tag references)."))
+    }
+
+    // Move the values of all tags set on an object to its new name.
+    #[async_backtrace::framed]
+    pub async fn rename_object_tag_references(
+        &self,
+        tenant: &str,
+        object_domains: &[&str],
+        object_name: &str,
+        new_object_name: &str,
+    ) -> Result<()> {
+        let client = self.get_tag_api_client(tenant)?;
+        client
+            .rename_object_tag_references(object_domains, object_name, new_object_name)
+            .await
+            .map_err(|e| e.add_message_back(" (while rename object tag references)."))
+    }
+}
@@ -30,6 +30,8 @@ use common_management::SettingApi;
 use common_management::SettingMgr;
 use common_management::StageApi;
 use common_management::StageMgr;
+use common_management::TagApi;
+use common_management::TagMgr;
 use common_management::UdfApi;
 use common_management::UdfMgr;
 use common_management::UserApi;
@@ -130,6 +132,10 @@ impl UserApiProvider {
         )?))
     }
 
+    pub fn get_tag_api_client(&self, tenant: &str) -> Result<Arc<impl TagApi>> {
+        Ok(Arc::new(TagMgr::create(self.client.clone(), tenant)?))
+    }
+
     pub fn get_meta_store_client(&self) -> Arc<MetaStore> {
         Arc::new(self.meta.clone())
     }
@@ -51,6 +51,26 @@ impl UserApiProvider {
         Ok(get_stage.await?.data)
     }
 
+    // Update the comment of a stage.
+    #[async_backtrace::framed]
+    pub async fn update_stage_comment(
+        &self,
+        tenant: &str,
+        stage_name: &str,
+        comment: String,
+    ) -> Result<u64> {
+        let stage_api_provider = self.get_stage_api_client(tenant)?;
+        let seq_stage = stage_api_provider
+            .get_stage(stage_name, MatchSeq::GE(0))
+            .await?;
+        let mut stage = seq_stage.data;
+        stage.comment = comment;
+        stage_api_provider
+            .update_stage(stage, MatchSeq::Exact(seq_stage.seq))
+            .await
+            .map_err(|e| e.add_message_back(" (while update stage)"))
+    }
+
     // Get the tenant all stage list.
     #[async_backtrace::framed]
     pub async fn get_stages(&self, tenant: &str) -> Result<Vec<StageInfo>> {
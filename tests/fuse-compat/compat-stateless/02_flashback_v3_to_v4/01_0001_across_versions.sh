@@ -136,15 +136,15 @@ echo "optimize table t2 compact segment" | $MYSQL_CLIENT_CONNECT
 
 # according to the table options segment_per_block=3,
 # v3 segment_2 and v4 segment_3 should be compacted -- the mixed version segments compaction,
-# into a new segment of version 4.
+# into a new segment of the current version (5).
 #
 # the new snapshot s6 produced by this compaction should contains 2 segments:
-# - v3 segment_1: {1,2}, v4 segment_2: {3, 4}
+# - v3 segment_1: {1,2}, v5 segment_2: {3, 4}
 
 # grab the id of s6
 FST_SNAPSHOT_S6_ID=$(echo "select snapshot_id from fuse_snapshot('default','t2') limit 1" | $MYSQL_CLIENT_CONNECT)
 
-echo "check segments after compaction, there should be 2 segments, a version v3 and a version v4"
+echo "check segments after compaction, there should be 2 segments, a version v3 and a version v5"
 echo "select count() c, format_version v from fuse_segment('default', 't2', '$FST_SNAPSHOT_S6_ID') group by v order by v " | $MYSQL_CLIENT_CONNECT
 
 echo "check table contains {1,2,3,4} after compaction"
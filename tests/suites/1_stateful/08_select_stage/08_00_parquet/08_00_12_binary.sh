@@ -0,0 +1,16 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../../shell_env.sh
+DATADIR_PATH="$CURDIR/../../../../data/"
+
+echo "drop stage if exists data;" | $MYSQL_CLIENT_CONNECT
+echo "create stage data url = 'fs://$DATADIR_PATH' " | $MYSQL_CLIENT_CONNECT
+
+# alltypes_plain.parquet keeps its strings as byte arrays without a UTF8 annotation.
+for USE_PARQEUT2 in 0 1
+do
+	echo "==="
+	echo "set use_parquet2 = ${USE_PARQEUT2} ; select column_name, type from infer_schema(location => '@data/alltypes_plain.parquet') where column_name like '%string_col';" | $MYSQL_CLIENT_CONNECT
+	echo "set use_parquet2 = ${USE_PARQEUT2} ; select id, string_col, to_string(date_string_col) from @data/alltypes_plain.parquet order by id limit 2;" | $MYSQL_CLIENT_CONNECT
+done
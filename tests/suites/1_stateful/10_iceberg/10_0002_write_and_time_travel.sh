@@ -0,0 +1,38 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+## Copy the table to a local filesystem warehouse, since it will be written.
+WAREHOUSE=/tmp/iceberg_write_warehouse
+rm -rf ${WAREHOUSE}
+mkdir -p ${WAREHOUSE}
+cp -r "$CURDIR"/../../../data/iceberg/iceberg_ctl/* ${WAREHOUSE}/
+
+echo "DROP CATALOG IF EXISTS iceberg_fs_ctl" | $MYSQL_CLIENT_CONNECT
+
+cat <<EOF | $MYSQL_CLIENT_CONNECT
+CREATE CATALOG iceberg_fs_ctl
+TYPE=ICEBERG
+CONNECTION=(
+    URL='fs://${WAREHOUSE}/'
+);
+EOF
+
+## time travel
+echo "SELECT count(*) FROM iceberg_fs_ctl.iceberg_db.iceberg_tbl AT (SNAPSHOT => '8111683045919920282');" | $MYSQL_CLIENT_CONNECT
+echo "SELECT count(*) FROM iceberg_fs_ctl.iceberg_db.iceberg_tbl AT (TIMESTAMP => '2023-06-29 15:01:28'::TIMESTAMP);" | $MYSQL_CLIENT_CONNECT
+echo "SELECT count(*) FROM iceberg_fs_ctl.iceberg_db.iceberg_tbl AT (SNAPSHOT => '1');" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -c "is not found"
+
+## append
+echo "INSERT INTO iceberg_fs_ctl.iceberg_db.iceberg_tbl VALUES (7, 'g'), (8, 'h');" | $MYSQL_CLIENT_CONNECT
+echo "SELECT * FROM iceberg_fs_ctl.iceberg_db.iceberg_tbl WHERE id > 5 ORDER BY id;" | $MYSQL_CLIENT_CONNECT
+echo "SELECT count(*) FROM iceberg_fs_ctl.iceberg_db.iceberg_tbl AT (SNAPSHOT => '3459088509799438599');" | $MYSQL_CLIENT_CONNECT
+
+## overwrite
+echo "INSERT OVERWRITE iceberg_fs_ctl.iceberg_db.iceberg_tbl VALUES (10, 'x');" | $MYSQL_CLIENT_CONNECT
+echo "SELECT * FROM iceberg_fs_ctl.iceberg_db.iceberg_tbl;" | $MYSQL_CLIENT_CONNECT
+echo "SELECT count(*) FROM iceberg_fs_ctl.iceberg_db.iceberg_tbl AT (SNAPSHOT => '3459088509799438599');" | $MYSQL_CLIENT_CONNECT
+
+echo "DROP CATALOG iceberg_fs_ctl" | $MYSQL_CLIENT_CONNECT
+rm -rf ${WAREHOUSE}
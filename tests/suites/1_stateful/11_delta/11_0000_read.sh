@@ -0,0 +1,25 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+TABLE_DIR=$(cd "$CURDIR"/../../../data/delta/delta_tbl && pwd)
+
+echo "DROP TABLE IF EXISTS delta_tbl" | $MYSQL_CLIENT_CONNECT
+
+## The columns are loaded from the delta log
+echo "CREATE TABLE delta_tbl ENGINE = DELTA LOCATION = 'fs://${TABLE_DIR}/';" | $MYSQL_CLIENT_CONNECT
+
+## The file of year 2024 is removed, and two rows of year 2023 are deleted by the deletion vector
+echo "SELECT count(*) FROM delta_tbl;" | $MYSQL_CLIENT_CONNECT
+echo "SELECT year, count(*) FROM delta_tbl GROUP BY year ORDER BY year;" | $MYSQL_CLIENT_CONNECT
+echo "SELECT id, int_col, bigint_col, string_col FROM delta_tbl WHERE year = 2023 ORDER BY id;" | $MYSQL_CLIENT_CONNECT
+
+## Pruning by partition values and statistics
+echo "SELECT count(*) FROM delta_tbl WHERE year = 2022 AND id > 5;" | $MYSQL_CLIENT_CONNECT
+echo "SELECT count(*) FROM delta_tbl WHERE id > 100;" | $MYSQL_CLIENT_CONNECT
+
+## The table is read only
+echo "INSERT INTO delta_tbl VALUES (1, 1, 1, 'a', 2023);" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -c "not implemented"
+
+echo "DROP TABLE delta_tbl" | $MYSQL_CLIENT_CONNECT